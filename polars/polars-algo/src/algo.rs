@@ -71,7 +71,7 @@ pub fn hist(s: &Series, bins: Option<&Series>, bin_count: Option<usize>) -> Resu
     let cuts = cuts_df
         .lazy()
         .with_columns([
-            col(category_str).cast(DataType::Categorical(None)),
+            col(category_str).cast(DataType::Categorical(None, Default::default())),
             col(breakpoint_str).cast(s.dtype().to_owned()),
         ])
         .collect()?;
@@ -185,7 +185,7 @@ pub fn cut(
 
     let cuts = cuts_df
         .lazy()
-        .with_columns([col(category_str).cast(DataType::Categorical(None))])
+        .with_columns([col(category_str).cast(DataType::Categorical(None, Default::default()))])
         .collect()?;
 
     let mut s = s.cast(&DataType::Float64)?;
@@ -0,0 +1,91 @@
+//! Compare the strategies of the filter kernel over the selectivity and the run length of the
+//! mask, to find the points where [`filter_strategy`] should switch between them.
+use arrow::array::{PrimitiveArray, Utf8Array};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::DataType;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polars_arrow::compute::filter::{filter_strategy, filter_with_strategy, FilterStrategy};
+use polars_arrow::prelude::*;
+
+const LEN: usize = 1 << 20;
+
+const STRATEGIES: [FilterStrategy; 4] = [
+    FilterStrategy::Gather,
+    FilterStrategy::Drop,
+    FilterStrategy::Slices,
+    FilterStrategy::Generic,
+];
+
+/// A mask that keeps every `keep_one_in`th row.
+fn spread_mask(keep_one_in: usize) -> Bitmap {
+    (0..LEN).map(|i| i % keep_one_in == 0).collect()
+}
+
+/// A mask that drops every `drop_one_in`th row.
+fn inverted_mask(drop_one_in: usize) -> Bitmap {
+    (0..LEN).map(|i| i % drop_one_in != 0).collect()
+}
+
+/// A mask of alternating runs of kept and dropped rows.
+fn runs_mask(run_len: usize) -> Bitmap {
+    (0..LEN).map(|i| (i / run_len) % 2 == 0).collect()
+}
+
+fn arrays() -> Vec<(&'static str, ArrayRef)> {
+    let ints = PrimitiveArray::from_vec((0..LEN as i64).collect());
+    let strings = (0..LEN)
+        .map(|i| Some(format!("value-{i}")))
+        .collect::<Utf8Array<i64>>();
+    // Safety:
+    // the size hint of a range is correct
+    let lists = unsafe {
+        LargeListArray::from_iter_primitive_trusted_len::<i64, _, _>(
+            (0..LEN).map(|i| Some((0..(i % 4) as i64).map(Some))),
+            DataType::Int64,
+        )
+    };
+    vec![
+        ("i64", ints.boxed()),
+        ("utf8", strings.boxed()),
+        ("list", lists.boxed()),
+    ]
+}
+
+fn bench_masks(c: &mut Criterion, group: &str, masks: Vec<(usize, Bitmap)>) {
+    for (name, array) in arrays() {
+        let mut group = c.benchmark_group(format!("filter_{group}_{name}"));
+        for (param, mask) in &masks {
+            for strategy in STRATEGIES {
+                let id = BenchmarkId::new(format!("{strategy:?}"), param);
+                group.bench_with_input(id, mask, |b, mask| {
+                    b.iter(|| filter_with_strategy(array.as_ref(), mask, strategy))
+                });
+            }
+            // the strategy that is picked, including the check of the mask
+            let id = BenchmarkId::new("picked", param);
+            group.bench_with_input(id, mask, |b, mask| {
+                b.iter(|| filter_with_strategy(array.as_ref(), mask, filter_strategy(mask)))
+            });
+        }
+        group.finish();
+    }
+}
+
+fn bench_filter(c: &mut Criterion) {
+    let ratios = [2, 4, 8, 16, 32, 64, 128, 1024];
+    // low selectivity: `Gather` against `Generic`
+    let masks = ratios.iter().map(|&n| (n, spread_mask(n))).collect();
+    bench_masks(c, "keep_one_in", masks);
+    // high selectivity: `Drop` against `Generic`
+    let masks = ratios.iter().map(|&n| (n, inverted_mask(n))).collect();
+    bench_masks(c, "drop_one_in", masks);
+    // half of the rows in runs: `Slices` against `Generic`
+    let masks = [1, 4, 8, 16, 32, 64, 256]
+        .iter()
+        .map(|&n| (n, runs_mask(n)))
+        .collect();
+    bench_masks(c, "run_len", masks);
+}
+
+criterion_group!(benches, bench_filter);
+criterion_main!(benches);
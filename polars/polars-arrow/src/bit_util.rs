@@ -1,3 +1,8 @@
+use arrow::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use arrow::bitmap::Bitmap;
+
+use crate::index::IdxSize;
+
 /// Forked from Arrow until their API stabilizes.
 ///
 /// Note that the bound checks are optimized away.
@@ -79,3 +84,56 @@ pub fn ceil(value: usize, divisor: usize) -> usize {
         quot
     }
 }
+
+/// The mask of the bits of the remainder of a bitmap of length `len` that are in bounds.
+///
+/// The remainder of the bit chunks may contain the bits that follow a sliced bitmap.
+#[inline]
+pub(crate) fn remainder_mask(len: usize) -> u64 {
+    (1u64 << (len % 64)) - 1
+}
+
+/// Push the indices of the set bits in `mask_chunks` to `out`, offset by `offset`.
+///
+/// Every word is consumed with `trailing_zeros`, so the cost scales with the number
+/// of set bits and not with the number of rows.
+fn set_bit_indices_impl<I>(mut mask_chunks: I, len: usize, offset: usize, out: &mut Vec<IdxSize>)
+where
+    I: BitChunkIterExact<u64>,
+{
+    let size = 64;
+    let mut base = offset;
+    for chunk in &mut mask_chunks {
+        if chunk == u64::MAX {
+            out.extend(base as IdxSize..(base + size) as IdxSize);
+        } else {
+            let mut chunk = chunk;
+            while chunk != 0 {
+                out.push((base + chunk.trailing_zeros() as usize) as IdxSize);
+                // clear the lowest set bit
+                chunk &= chunk - 1;
+            }
+        }
+        base += size;
+    }
+    let mut remainder = mask_chunks.remainder() & remainder_mask(len);
+    while remainder != 0 {
+        out.push((base + remainder.trailing_zeros() as usize) as IdxSize);
+        remainder &= remainder - 1;
+    }
+}
+
+/// Push the indices of the set bits of `mask` to `out`, offset by `offset`.
+pub fn set_bit_indices(mask: &Bitmap, offset: usize, out: &mut Vec<IdxSize>) {
+    if mask.unset_bits() == mask.len() {
+        return;
+    }
+    let (slice, bit_offset, length) = mask.as_slice();
+    if bit_offset == 0 {
+        let mask_chunks = BitChunksExact::<u64>::new(slice, length);
+        set_bit_indices_impl(mask_chunks, length, offset, out)
+    } else {
+        let mask_chunks = mask.chunks::<u64>();
+        set_bit_indices_impl(mask_chunks, length, offset, out)
+    }
+}
@@ -0,0 +1,312 @@
+//! A filter kernel that picks its strategy from the set bits of the mask.
+//!
+//! The generic kernel visits every row. A mask that keeps or drops only a few rows, or that
+//! consists of long runs, is filtered faster by a strategy that skips the rows in between,
+//! see [`FilterStrategy`].
+use arrow::array::growable::make_growable;
+use arrow::array::{new_empty_array, Array, BooleanArray};
+use arrow::bitmap::utils::{BitChunkIterExact, BitChunksExact, SlicesIterator};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::DataType;
+
+use crate::bit_util::{remainder_mask, set_bit_indices};
+use crate::compute::take::take_unchecked;
+use crate::prelude::*;
+
+/// A mask that keeps, or drops, at most one in `SPARSE_RATIO` rows is handled by index.
+pub const SPARSE_RATIO: usize = 32;
+/// The runs of kept rows are copied as slices if they are this long on average.
+pub const MIN_AVG_RUN_LEN: usize = 32;
+
+/// How [`filter`] selects the rows that are kept by a mask.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FilterStrategy {
+    /// The mask keeps no row.
+    Empty,
+    /// The mask keeps every row.
+    All,
+    /// Collect the indices of the set bits and gather them.
+    Gather,
+    /// Collect the indices of the unset bits and copy the slices in between.
+    Drop,
+    /// Copy the runs of set bits as slices.
+    Slices,
+    /// Visit every row with the generic arrow kernel.
+    Generic,
+}
+
+/// Count the runs of set bits in `mask_chunks`, but stop counting once `max_runs` is exceeded.
+fn count_runs_impl<I>(mut mask_chunks: I, len: usize, max_runs: usize) -> usize
+where
+    I: BitChunkIterExact<u64>,
+{
+    let mut runs = 0;
+    // the last bit of the previous word
+    let mut carry = 0u64;
+    for chunk in &mut mask_chunks {
+        // a run starts at every set bit whose preceding bit is unset
+        let starts = chunk & !((chunk << 1) | carry);
+        runs += starts.count_ones() as usize;
+        if runs > max_runs {
+            return runs;
+        }
+        carry = chunk >> 63;
+    }
+    let remainder = mask_chunks.remainder() & remainder_mask(len);
+    runs + (remainder & !((remainder << 1) | carry)).count_ones() as usize
+}
+
+fn count_runs(mask: &Bitmap, max_runs: usize) -> usize {
+    let (slice, bit_offset, length) = mask.as_slice();
+    if bit_offset == 0 {
+        count_runs_impl(BitChunksExact::<u64>::new(slice, length), length, max_runs)
+    } else {
+        count_runs_impl(mask.chunks::<u64>(), length, max_runs)
+    }
+}
+
+/// Pick the strategy to filter with `mask`.
+///
+/// The set bits are counted on construction of the mask, so only the check for long runs
+/// has to visit the words of the mask.
+pub fn filter_strategy(mask: &Bitmap) -> FilterStrategy {
+    let len = mask.len();
+    let unset_bits = mask.unset_bits();
+    let set_bits = len - unset_bits;
+    if set_bits == 0 {
+        FilterStrategy::Empty
+    } else if unset_bits == 0 {
+        FilterStrategy::All
+    } else if set_bits * SPARSE_RATIO <= len {
+        FilterStrategy::Gather
+    } else if unset_bits * SPARSE_RATIO <= len {
+        FilterStrategy::Drop
+    } else {
+        let max_runs = set_bits / MIN_AVG_RUN_LEN;
+        if count_runs(mask, max_runs) <= max_runs {
+            FilterStrategy::Slices
+        } else {
+            FilterStrategy::Generic
+        }
+    }
+}
+
+/// Copy the slices `(start, len)` of `array` into a new array of length `capacity`.
+fn extend_slices<I>(array: &dyn Array, slices: I, capacity: usize) -> ArrayRef
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut growable = make_growable(&[array], false, capacity);
+    for (start, len) in slices {
+        growable.extend(0, start, len)
+    }
+    growable.as_box()
+}
+
+fn filter_gather(array: &dyn Array, mask: &Bitmap) -> ArrayRef {
+    let mut idx = Vec::with_capacity(mask.len() - mask.unset_bits());
+    set_bit_indices(mask, 0, &mut idx);
+    let idx = IdxArr::from_vec(idx);
+    // Safety:
+    // the indices are smaller than the length of the mask, which is the length of the array
+    unsafe { take_unchecked(array, &idx) }
+}
+
+fn filter_drop(array: &dyn Array, mask: &Bitmap) -> ArrayRef {
+    let mut dropped = Vec::with_capacity(mask.unset_bits());
+    set_bit_indices(&!mask, 0, &mut dropped);
+
+    let mut start = 0;
+    let mut slices = Vec::with_capacity(dropped.len() + 1);
+    for idx in dropped {
+        let idx = idx as usize;
+        if idx > start {
+            slices.push((start, idx - start));
+        }
+        start = idx + 1;
+    }
+    if start < mask.len() {
+        slices.push((start, mask.len() - start));
+    }
+    extend_slices(array, slices, mask.len() - mask.unset_bits())
+}
+
+/// Filter `array` with `mask` using `strategy`.
+///
+/// Every strategy gives the same result, this is exposed to compare their performance.
+pub fn filter_with_strategy(
+    array: &dyn Array,
+    mask: &Bitmap,
+    strategy: FilterStrategy,
+) -> ArrayRef {
+    assert_eq!(array.len(), mask.len());
+    match strategy {
+        // for other masks these fall back to the generic kernel
+        FilterStrategy::Empty if mask.unset_bits() == mask.len() => {
+            new_empty_array(array.data_type().clone())
+        }
+        FilterStrategy::All if mask.unset_bits() == 0 => array.to_boxed(),
+        FilterStrategy::Gather => filter_gather(array, mask),
+        FilterStrategy::Drop => filter_drop(array, mask),
+        FilterStrategy::Slices => extend_slices(
+            array,
+            SlicesIterator::new(mask),
+            mask.len() - mask.unset_bits(),
+        ),
+        _ => {
+            let mask = BooleanArray::new(DataType::Boolean, mask.clone(), None);
+            arrow::compute::filter::filter(array, &mask).unwrap()
+        }
+    }
+}
+
+/// Filter `array` with `mask`, the nulls of `mask` count as `false`.
+///
+/// The strategy is picked with [`filter_strategy`].
+pub fn filter(array: &dyn Array, mask: &BooleanArray) -> ArrayRef {
+    let mask = match mask.validity() {
+        Some(validity) if validity.unset_bits() > 0 => validity & mask.values(),
+        _ => mask.values().clone(),
+    };
+    filter_with_strategy(array, &mask, filter_strategy(&mask))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{PrimitiveArray, Utf8Array};
+
+    use super::*;
+
+    const STRATEGIES: [FilterStrategy; 6] = [
+        FilterStrategy::Empty,
+        FilterStrategy::All,
+        FilterStrategy::Gather,
+        FilterStrategy::Drop,
+        FilterStrategy::Slices,
+        FilterStrategy::Generic,
+    ];
+
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// `true` with a probability of `1 / n`.
+        fn one_in(&mut self, n: u64) -> bool {
+            self.next_u64() % n == 0
+        }
+    }
+
+    /// A mask that switches between runs of set and unset bits with a probability of
+    /// `1 / switch` and that sets a single bit against the run with a probability of `1 / noise`.
+    fn random_mask(rng: &mut XorShift, len: usize, switch: u64, noise: u64) -> Bitmap {
+        let mut value = rng.one_in(2);
+        (0..len)
+            .map(|_| {
+                if rng.one_in(switch) {
+                    value = !value
+                }
+                value ^ rng.one_in(noise)
+            })
+            .collect()
+    }
+
+    fn naive_filter(array: &dyn Array, mask: &Bitmap) -> ArrayRef {
+        let kept = mask.iter().enumerate().filter(|(_, keep)| *keep);
+        extend_slices(array, kept.map(|(i, _)| (i, 1)), mask.len())
+    }
+
+    fn check(array: &dyn Array, mask: &Bitmap) {
+        let expected = naive_filter(array, mask);
+        for strategy in STRATEGIES {
+            let out = filter_with_strategy(array, mask, strategy);
+            assert!(
+                out.as_ref() == expected.as_ref(),
+                "{:?} differs for {:?}",
+                strategy,
+                filter_strategy(mask)
+            );
+        }
+        let out = filter(
+            array,
+            &BooleanArray::new(DataType::Boolean, mask.clone(), None),
+        );
+        assert!(out.as_ref() == expected.as_ref());
+    }
+
+    fn arrays(rng: &mut XorShift, len: usize) -> Vec<ArrayRef> {
+        let ints = (0..len)
+            .map(|_| (!rng.one_in(5)).then(|| rng.next_u64() as i32))
+            .collect::<PrimitiveArray<i32>>();
+        let strings = (0..len)
+            .map(|i| (!rng.one_in(5)).then(|| "x".repeat(i % 7)))
+            .collect::<Utf8Array<i64>>();
+        let lists = (0..len)
+            .map(|i| (!rng.one_in(5)).then(|| (0..i % 4).map(|j| Some(j as i32))))
+            .collect::<Vec<_>>();
+        // Safety:
+        // the size hint of a vec is correct
+        let lists = unsafe {
+            LargeListArray::from_iter_primitive_trusted_len::<i32, _, _>(lists, DataType::Int32)
+        };
+        vec![ints.boxed(), strings.boxed(), lists.boxed()]
+    }
+
+    #[test]
+    fn test_filter_strategies() {
+        let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+        // (switch, noise) of the masks: sparse, dense, long runs and random bits
+        let masks = [(1 << 20, 64), (1 << 20, 2000), (100, 1 << 20), (2, 1 << 20)];
+        for len in [1, 63, 64, 65, 300, 2000] {
+            let arrays = arrays(&mut rng, len + 10);
+            for (switch, noise) in masks {
+                let mask = random_mask(&mut rng, len + 10, switch, noise);
+                for array in &arrays {
+                    check(array.as_ref(), &mask);
+                    // offsets that are not aligned to a byte
+                    let offset = (rng.next_u64() % 10) as usize;
+                    check(
+                        array.sliced(offset, len).as_ref(),
+                        &mask.clone().sliced(offset, len),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_strategy() {
+        let mask = |f: fn(usize) -> bool| (0..1024).map(f).collect::<Bitmap>();
+        assert_eq!(filter_strategy(&mask(|_| false)), FilterStrategy::Empty);
+        assert_eq!(filter_strategy(&mask(|_| true)), FilterStrategy::All);
+        assert_eq!(
+            filter_strategy(&mask(|i| i % 100 == 0)),
+            FilterStrategy::Gather
+        );
+        assert_eq!(
+            filter_strategy(&mask(|i| i % 100 != 0)),
+            FilterStrategy::Drop
+        );
+        assert_eq!(
+            filter_strategy(&mask(|i| i % 128 < 64)),
+            FilterStrategy::Slices
+        );
+        assert_eq!(
+            filter_strategy(&mask(|i| i % 2 == 0)),
+            FilterStrategy::Generic
+        );
+    }
+
+    #[test]
+    fn test_filter_null_mask() {
+        let array = PrimitiveArray::from_slice([1i32, 2, 3, 4]);
+        let mask = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+        let out = filter(&array, &mask);
+        assert!(out.as_ref() == &PrimitiveArray::from_slice([1i32, 4]) as &dyn Array);
+    }
+}
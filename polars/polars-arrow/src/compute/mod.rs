@@ -1,3 +1,5 @@
 #[cfg(feature = "compute")]
 pub mod cast;
+#[cfg(feature = "compute")]
+pub mod filter;
 pub mod take;
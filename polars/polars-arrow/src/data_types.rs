@@ -28,7 +28,15 @@ unsafe impl IsFloat for u64 {}
 unsafe impl IsFloat for &str {}
 unsafe impl IsFloat for &[u8] {}
 unsafe impl IsFloat for bool {}
-unsafe impl<T: IsFloat> IsFloat for Option<T> {}
+unsafe impl<T: IsFloat> IsFloat for Option<T> {
+    fn is_float() -> bool {
+        T::is_float()
+    }
+
+    fn is_nan(&self) -> bool {
+        matches!(self, Some(v) if v.is_nan())
+    }
+}
 
 mod private {
     pub trait Sealed {}
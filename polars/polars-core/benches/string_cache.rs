@@ -0,0 +1,57 @@
+//! Build categorical columns under the global string cache, from a single thread and from all
+//! the threads of the pool, to check that the construction scales with the number of threads.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polars_core::prelude::*;
+use polars_core::{reset_string_cache, StringCacheHolder, POOL};
+use rayon::prelude::*;
+
+const N_COLUMNS: usize = 32;
+const LEN: usize = 100_000;
+
+fn columns(n_unique: usize) -> Vec<Series> {
+    (0..N_COLUMNS)
+        .map(|i| {
+            let values = (0..LEN)
+                .map(|j| format!("category-{}", (j * 31 + i) % n_unique))
+                .collect::<Vec<_>>();
+            Utf8Chunked::from_slice("a", &values).into_series()
+        })
+        .collect()
+}
+
+fn to_categorical(s: &Series) -> Series {
+    s.cast(&DataType::Categorical(None, Default::default()))
+        .unwrap()
+}
+
+fn bench_string_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("string_cache");
+    for n_unique in [100, 10_000, LEN] {
+        let columns = columns(n_unique);
+        group.bench_with_input(
+            BenchmarkId::new("serial", n_unique),
+            &columns,
+            |b, columns| {
+                b.iter(|| {
+                    let _holder = StringCacheHolder::new();
+                    columns.iter().map(to_categorical).collect::<Vec<_>>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new(format!("parallel_{}", POOL.current_num_threads()), n_unique),
+            &columns,
+            |b, columns| {
+                b.iter(|| {
+                    let _holder = StringCacheHolder::new();
+                    POOL.install(|| columns.par_iter().map(to_categorical).collect::<Vec<_>>())
+                })
+            },
+        );
+    }
+    group.finish();
+    reset_string_cache();
+}
+
+criterion_group!(benches, bench_string_cache);
+criterion_main!(benches);
@@ -444,6 +444,113 @@ impl ListBuilderTrait for LargeListNullBuilder {
     }
 }
 
+/// Builds a list of categoricals with different rev-maps. Categoricals under the global string
+/// cache keep their ids and their rev-maps are merged; local categoricals are re-encoded into
+/// one local rev-map with the categories in the order of their first appearance.
+#[cfg(feature = "dtype-categorical")]
+pub struct ListCategoricalChunkedBuilder {
+    inner: ListPrimitiveChunkedBuilder<UInt32Type>,
+    global: Option<Arc<RevMapping>>,
+    local: MutableUtf8Array<i64>,
+    local_idx: PlHashMap<String, u32>,
+    // maps the ids of the last appended local rev-map to the merged categories
+    remap: Option<(Arc<RevMapping>, Vec<u32>)>,
+    ordering: CategoricalOrdering,
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl ListCategoricalChunkedBuilder {
+    pub fn new(name: &str, capacity: usize, values_capacity: usize) -> Self {
+        Self {
+            inner: ListPrimitiveChunkedBuilder::new(
+                name,
+                capacity,
+                values_capacity,
+                DataType::UInt32,
+            ),
+            global: None,
+            local: MutableUtf8Array::new(),
+            local_idx: Default::default(),
+            remap: None,
+            ordering: Default::default(),
+        }
+    }
+
+    fn local_id(&mut self, category: &str) -> u32 {
+        match self.local_idx.get(category) {
+            Some(id) => *id,
+            None => {
+                let id = self.local.len() as u32;
+                self.local.push(Some(category));
+                self.local_idx.insert(category.to_string(), id);
+                id
+            }
+        }
+    }
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl ListBuilderTrait for ListCategoricalChunkedBuilder {
+    fn append_series(&mut self, s: &Series) {
+        let ca = s.categorical().unwrap();
+        self.ordering = ca.get_ordering();
+        let rev_map = ca.get_rev_map();
+        match &**rev_map {
+            RevMapping::Global(_, _, _) => {
+                assert!(
+                    self.local_idx.is_empty(),
+                    "{}",
+                    string_cache_mismatch_err("list builder")
+                );
+                let merged = match self.global.take() {
+                    Some(merged) if !Arc::ptr_eq(&merged, rev_map) => {
+                        merge_categorical_map(&merged, rev_map, "list builder").unwrap()
+                    }
+                    _ => rev_map.clone(),
+                };
+                self.global = Some(merged);
+                self.inner.append_series(s)
+            }
+            RevMapping::Local(categories) => {
+                assert!(
+                    self.global.is_none(),
+                    "{}",
+                    string_cache_mismatch_err("list builder")
+                );
+                if !matches!(&self.remap, Some((last, _)) if Arc::ptr_eq(last, rev_map)) {
+                    let remap = categories
+                        .values_iter()
+                        .map(|category| self.local_id(category))
+                        .collect();
+                    self.remap = Some((rev_map.clone(), remap));
+                }
+                let remap = &self.remap.as_ref().unwrap().1;
+                self.inner.append_iter(
+                    ca.logical()
+                        .into_iter()
+                        .map(|opt_id| opt_id.map(|id| remap[id as usize])),
+                )
+            }
+        }
+    }
+
+    fn append_null(&mut self) {
+        self.inner.append_null()
+    }
+
+    fn finish(&mut self) -> ListChunked {
+        let rev_map = match self.global.take() {
+            Some(rev_map) => rev_map,
+            None => Arc::new(RevMapping::Local(std::mem::take(&mut self.local).into())),
+        };
+        self.local_idx.clear();
+        self.remap = None;
+        let mut ca = self.inner.finish();
+        ca.set_inner_dtype(DataType::Categorical(Some(rev_map), self.ordering));
+        ca
+    }
+}
+
 pub fn get_list_builder(
     inner_type_logical: &DataType,
     value_capacity: usize,
@@ -452,6 +559,15 @@ pub fn get_list_builder(
 ) -> PolarsResult<Box<dyn ListBuilderTrait>> {
     let physical_type = inner_type_logical.to_physical();
 
+    #[cfg(feature = "dtype-categorical")]
+    if let DataType::Categorical(_, _) = inner_type_logical {
+        return Ok(Box::new(ListCategoricalChunkedBuilder::new(
+            name,
+            list_capacity,
+            value_capacity,
+        )));
+    }
+
     match &physical_type {
         #[cfg(feature = "object")]
         DataType::Object(_) => polars_bail!(opq = list_builder, &physical_type),
@@ -263,4 +263,37 @@ mod test {
         assert_eq!(out.len(), 7);
         assert_eq!(out.get(6).unwrap(), AnyValue::Null);
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_list_categorical_builder() {
+        // both series have a different local rev-map
+        let s1 = Series::new("a", &["b", "a", "b"])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        let s2 = Series::new("a", &[Some("c"), None, Some("a")])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+
+        let mut builder = get_list_builder(s1.dtype(), 6, 2, "a").unwrap();
+        builder.append_series(&s1);
+        builder.append_null();
+        builder.append_series(&s2);
+        let out = builder.finish().explode().unwrap();
+
+        let out = out.categorical().unwrap();
+        assert_eq!(
+            out.iter_str().collect::<Vec<_>>(),
+            &[
+                Some("b"),
+                Some("a"),
+                Some("b"),
+                None,
+                Some("c"),
+                None,
+                Some("a")
+            ]
+        );
+        assert_eq!(out.get_rev_map().len(), 3);
+    }
 }
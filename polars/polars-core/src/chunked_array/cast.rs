@@ -82,7 +82,7 @@ where
     fn cast_impl(&self, data_type: &DataType, checked: bool) -> PolarsResult<Series> {
         match data_type {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_, ordering) => {
                 polars_ensure!(
                     self.dtype() == &DataType::UInt32,
                     ComputeError: "cannot cast numeric types to 'Categorical'"
@@ -90,7 +90,9 @@ where
                 // SAFETY
                 // we are guarded by the type system
                 let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt32Chunked) };
-                CategoricalChunked::from_global_indices(ca.clone()).map(|ca| ca.into_series())
+                let mut out = CategoricalChunked::from_global_indices(ca.clone())?;
+                out.set_ordering(*ordering);
+                Ok(out.into_series())
             }
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => cast_single_to_struct(self.name(), &self.chunks, fields),
@@ -121,18 +123,19 @@ where
     unsafe fn cast_unchecked(&self, data_type: &DataType) -> PolarsResult<Series> {
         match data_type {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(Some(rev_map)) => {
+            DataType::Categorical(Some(rev_map), ordering) => {
                 if self.dtype() == &DataType::UInt32 {
                     // safety:
                     // we are guarded by the type system.
                     let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt32Chunked) };
-                    Ok(unsafe {
+                    let mut out = unsafe {
                         CategoricalChunked::from_cats_and_rev_map_unchecked(
                             ca.clone(),
                             rev_map.clone(),
                         )
-                    }
-                    .into_series())
+                    };
+                    out.set_ordering(*ordering);
+                    Ok(out.into_series())
                 } else {
                     polars_bail!(ComputeError: "cannot cast numeric types to 'Categorical'");
                 }
@@ -146,11 +149,12 @@ impl ChunkCast for Utf8Chunked {
     fn cast(&self, data_type: &DataType) -> PolarsResult<Series> {
         match data_type {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_, ordering) => {
                 let iter = self.into_iter();
                 let mut builder = CategoricalChunkedBuilder::new(self.name(), self.len());
                 builder.drain_iter(iter);
-                let ca = builder.finish();
+                let mut ca = builder.finish();
+                ca.set_ordering(*ordering);
                 Ok(ca.into_series())
             }
             #[cfg(feature = "dtype-struct")]
@@ -258,7 +262,7 @@ impl ChunkCast for ListChunked {
             List(child_type) => {
                 match (self.inner_dtype(), &**child_type) {
                     #[cfg(feature = "dtype-categorical")]
-                    (dt, Categorical(None)) if !matches!(dt, Utf8) => {
+                    (dt, Categorical(None, _)) if !matches!(dt, Utf8) => {
                         polars_bail!(ComputeError: "cannot cast list inner type: '{:?}' to Categorical", dt)
                     }
                     _ => {
@@ -334,8 +338,12 @@ mod test {
     fn test_cast_noop() {
         // check if we can cast categorical twice without panic
         let ca = Utf8Chunked::new("foo", &["bar", "ham"]);
-        let out = ca.cast(&DataType::Categorical(None)).unwrap();
-        let out = out.cast(&DataType::Categorical(None)).unwrap();
-        assert!(matches!(out.dtype(), &DataType::Categorical(_)))
+        let out = ca
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        let out = out
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        assert!(matches!(out.dtype(), &DataType::Categorical(_, _)))
     }
 }
@@ -795,8 +795,9 @@ impl ChunkCompare<&str> for Utf8Chunked {
 impl ChunkCompare<&ListChunked> for ListChunked {
     type Item = BooleanChunked;
     fn equal(&self, rhs: &ListChunked) -> BooleanChunked {
-        self.amortized_iter()
-            .zip(rhs.amortized_iter())
+        // Safety:
+        // the items are only used to compute the output of their iteration
+        unsafe { self.amortized_iter().zip(rhs.amortized_iter()) }
             .map(|(left, right)| match (left, right) {
                 (None, None) => true,
                 (Some(l), Some(r)) => l.as_ref().series_equal_missing(r.as_ref()),
@@ -806,8 +807,9 @@ impl ChunkCompare<&ListChunked> for ListChunked {
     }
 
     fn not_equal(&self, rhs: &ListChunked) -> BooleanChunked {
-        self.amortized_iter()
-            .zip(rhs.amortized_iter())
+        // Safety:
+        // the items are only used to compute the output of their iteration
+        unsafe { self.amortized_iter().zip(rhs.amortized_iter()) }
             .map(|(left, right)| {
                 let out = match (left, right) {
                     (None, None) => true,
@@ -1 +1,2 @@
 pub(crate) mod take;
+pub(crate) mod take_par;
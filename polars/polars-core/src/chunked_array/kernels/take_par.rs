@@ -0,0 +1,211 @@
+//! Gather kernels that split the indices over the threads of the pool and write into output
+//! buffers that are allocated up front, so that the parts don't have to be concatenated.
+use std::mem::MaybeUninit;
+
+use arrow::bitmap::Bitmap;
+use arrow::buffer::Buffer;
+use arrow::datatypes::DataType as ArrowDataType;
+use arrow::offset::Offsets;
+use arrow::types::NativeType;
+use polars_arrow::bit_util::set_bit_raw;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+use crate::POOL;
+
+/// Below this number of indices the serial kernels are used.
+pub(crate) const PARALLEL_TAKE_THRESHOLD: usize = 1 << 20;
+
+/// Whether a gather of `idx` runs on the threads of the pool.
+pub(crate) fn take_in_parallel(idx: &IdxArr) -> bool {
+    idx.len() >= PARALLEL_TAKE_THRESHOLD && POOL.current_num_threads() > 1
+}
+
+/// The number of indices per thread, a multiple of 8 so that every thread writes whole bytes of
+/// the validity.
+fn part_size(len: usize) -> usize {
+    let n_threads = POOL.current_num_threads();
+    let size = (len + n_threads - 1) / n_threads;
+    std::cmp::max((size + 7) & !7, 8)
+}
+
+/// Gather the validity of `validity` at `idx`, where a null index gives a null.
+///
+/// # Safety
+/// The valid indices must be in bounds of `validity`.
+unsafe fn take_validity(
+    validity: Option<&Bitmap>,
+    idx: &IdxArr,
+    part_size: usize,
+) -> Option<Bitmap> {
+    if validity.is_none() && idx.validity().is_none() {
+        return None;
+    }
+    let idx_values = idx.values().as_slice();
+    let mut bytes = vec![0u8; (idx.len() + 7) / 8];
+    bytes
+        .par_chunks_mut(part_size / 8)
+        .enumerate()
+        .for_each(|(part, bytes)| {
+            let offset = part * part_size;
+            let end = std::cmp::min(offset + part_size, idx_values.len());
+            for (i, out_i) in (offset..end).zip(0..) {
+                let is_valid = idx.is_valid(i) && {
+                    let src = *idx_values.get_unchecked(i) as usize;
+                    validity.map_or(true, |validity| validity.get_bit_unchecked(src))
+                };
+                if is_valid {
+                    set_bit_raw(bytes.as_mut_ptr(), out_i)
+                }
+            }
+        });
+    Some(Bitmap::from_u8_vec(bytes, idx.len()))
+}
+
+/// Gather the values of `arr` at `idx` on the threads of the pool.
+///
+/// # Safety
+/// The valid indices must be in bounds of `arr`.
+pub(crate) unsafe fn take_primitive_par_unchecked<T: NativeType>(
+    arr: &PrimitiveArray<T>,
+    idx: &IdxArr,
+) -> Box<PrimitiveArray<T>> {
+    let src = arr.values().as_slice();
+    let idx_values = idx.values().as_slice();
+    let len = idx.len();
+    let part_size = part_size(len);
+
+    let (values, validity) = POOL.install(|| {
+        let mut values = Vec::<T>::with_capacity(len);
+        values.spare_capacity_mut()[..len]
+            .par_chunks_mut(part_size)
+            .zip(idx_values.par_chunks(part_size))
+            .enumerate()
+            .for_each(|(part, (out, part_idx))| {
+                let offset = part * part_size;
+                for (i, (out, src_i)) in out.iter_mut().zip(part_idx).enumerate() {
+                    // the value under a null index may be out of bounds
+                    let v = if idx.is_valid(offset + i) {
+                        *src.get_unchecked(*src_i as usize)
+                    } else {
+                        T::default()
+                    };
+                    out.write(v);
+                }
+            });
+        // Safety:
+        // every slot is written
+        values.set_len(len);
+        (values, take_validity(arr.validity(), idx, part_size))
+    });
+    Box::new(PrimitiveArray::new(
+        arr.data_type().clone(),
+        values.into(),
+        validity,
+    ))
+}
+
+/// Gather the values of `arr` at `idx` on the threads of the pool.
+///
+/// The first pass computes the length of the output values of every thread, so that the second
+/// pass can write the offsets and the values of every thread to its own part of the buffers.
+///
+/// # Safety
+/// The valid indices must be in bounds of `arr`.
+pub(crate) unsafe fn take_binary_par_unchecked(
+    arr: &BinaryArray<i64>,
+    idx: &IdxArr,
+) -> Box<BinaryArray<i64>> {
+    let src_offsets = arr.offsets().buffer().as_slice();
+    let src_values = arr.values().as_slice();
+    let idx_values = idx.values().as_slice();
+    let len = idx.len();
+    let part_size = part_size(len);
+
+    // a null index or a null value gives an empty slot
+    let value_len = |i: usize| -> usize {
+        if !idx.is_valid(i) {
+            return 0;
+        }
+        let src_i = *idx_values.get_unchecked(i) as usize;
+        if arr.is_null(src_i) {
+            0
+        } else {
+            (*src_offsets.get_unchecked(src_i + 1) - *src_offsets.get_unchecked(src_i)) as usize
+        }
+    };
+
+    let (offsets, values, validity) = POOL.install(|| {
+        // first pass: the length of the values of every part
+        let n_parts = (len + part_size - 1) / part_size;
+        let part_lens = (0..n_parts)
+            .into_par_iter()
+            .map(|part| {
+                let offset = part * part_size;
+                let end = std::cmp::min(offset + part_size, len);
+                (offset..end).map(value_len).sum::<usize>()
+            })
+            .collect::<Vec<_>>();
+        let total_len = part_lens.iter().sum::<usize>();
+
+        // split the output buffers over the parts
+        let mut offsets = Vec::<i64>::with_capacity(len + 1);
+        offsets.push(0);
+        let mut values = Vec::<u8>::with_capacity(total_len);
+        let mut values_parts = Vec::with_capacity(part_lens.len());
+        let mut part_starts = Vec::with_capacity(part_lens.len());
+        let mut remaining: &mut [MaybeUninit<u8>] = &mut values.spare_capacity_mut()[..total_len];
+        let mut start = 0;
+        for &part_len in &part_lens {
+            let (part, rest) = std::mem::take(&mut remaining).split_at_mut(part_len);
+            values_parts.push(part);
+            part_starts.push(start);
+            remaining = rest;
+            start += part_len;
+        }
+
+        // second pass: write the offsets and the values
+        offsets.spare_capacity_mut()[..len]
+            .par_chunks_mut(part_size)
+            .zip(values_parts)
+            .zip(part_starts)
+            .enumerate()
+            .for_each(|(part, ((out_offsets, out_values), part_start))| {
+                let offset = part * part_size;
+                let mut written = 0;
+                for (i, out_offset) in (offset..).zip(out_offsets.iter_mut()) {
+                    let n_bytes = value_len(i);
+                    if n_bytes > 0 {
+                        let src_start = *src_offsets
+                            .get_unchecked(*idx_values.get_unchecked(i) as usize)
+                            as usize;
+                        let src = src_values.get_unchecked(src_start..src_start + n_bytes);
+                        let dst = out_values.get_unchecked_mut(written..written + n_bytes);
+                        std::ptr::copy_nonoverlapping(
+                            src.as_ptr(),
+                            dst.as_mut_ptr() as *mut u8,
+                            n_bytes,
+                        );
+                        written += n_bytes;
+                    }
+                    out_offset.write((part_start + written) as i64);
+                }
+            });
+        // Safety:
+        // every slot is written
+        offsets.set_len(len + 1);
+        values.set_len(total_len);
+        (
+            offsets,
+            values,
+            take_validity(arr.validity(), idx, part_size),
+        )
+    });
+
+    Box::new(BinaryArray::new(
+        ArrowDataType::LargeBinary,
+        Offsets::new_unchecked(offsets).into(),
+        Buffer::from(values),
+        validity,
+    ))
+}
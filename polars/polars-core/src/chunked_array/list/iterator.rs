@@ -47,6 +47,15 @@ impl<'a, I: Iterator<Item = Option<ArrayBox>>> Iterator for AmortizedListIter<'a
                     }
                 }
 
+                // a Series that was cloned from a previous item shares the container,
+                // so the container gets its own copy before the inner state is updated
+                let container = &mut *self.series_container;
+                if Arc::strong_count(&container.0) + Arc::weak_count(&container.0) != 1 {
+                    container._get_inner_mut();
+                    let ptr = container.array_ref(0) as *const ArrayRef as *mut ArrayRef;
+                    self.inner = NonNull::new(ptr).unwrap();
+                }
+
                 // update the inner state
                 unsafe { *self.inner.as_mut() = array_ref };
 
@@ -87,18 +96,27 @@ impl ListChunked {
     /// 1. Arc<..>
     /// 2. Vec<...>
     ///
-    /// # Warning
-    /// Though memory safe in the sense that it will not read unowned memory, UB, or memory leaks
-    /// this function still needs precautions. The returned should never be cloned or taken longer
-    /// than a single iteration, as every call on `next` of the iterator will change the contents of
-    /// that Series.
+    /// Prefer the safe [`ListChunked::apply_amortized`], [`ListChunked::for_each_amortized`]
+    /// and their variants, which don't let the items escape.
+    ///
+    /// # Safety
+    /// Every item refers to the same Series, whose contents are replaced on every call to `next`.
+    /// An item must not be used after the next call to `next` or after the iterator is dropped,
+    /// so it must not be stored, e.g. by collecting the items. Clone the Series of an item to
+    /// keep it, the clone keeps its contents.
     #[cfg(feature = "private")]
-    pub fn amortized_iter(&self) -> AmortizedListIter<impl Iterator<Item = Option<ArrayBox>> + '_> {
+    pub unsafe fn amortized_iter(
+        &self,
+    ) -> AmortizedListIter<impl Iterator<Item = Option<ArrayBox>> + '_> {
         self.amortized_iter_with_name("")
     }
 
+    /// See [`ListChunked::amortized_iter`].
+    ///
+    /// # Safety
+    /// The same invariants as for [`ListChunked::amortized_iter`] hold.
     #[cfg(feature = "private")]
-    pub fn amortized_iter_with_name(
+    pub unsafe fn amortized_iter_with_name(
         &self,
         name: &str,
     ) -> AmortizedListIter<impl Iterator<Item = Option<ArrayBox>> + '_> {
@@ -119,13 +137,11 @@ impl ListChunked {
 
         // Safety:
         // inner type passed as physical type
-        let series_container = unsafe {
-            Box::new(Series::from_chunks_and_dtype_unchecked(
-                name,
-                vec![inner_values.clone()],
-                &iter_dtype,
-            ))
-        };
+        let series_container = Box::new(Series::from_chunks_and_dtype_unchecked(
+            name,
+            vec![inner_values.clone()],
+            &iter_dtype,
+        ));
 
         let ptr = series_container.array_ref(0) as *const ArrayRef as *mut ArrayRef;
 
@@ -139,19 +155,110 @@ impl ListChunked {
         }
     }
 
+    /// Call a closure `F` on every sublist.
+    ///
+    /// The sublist is only valid during the call, so it cannot escape the closure:
+    ///
+    /// ```compile_fail
+    /// # use polars_core::prelude::*;
+    /// let ca: ListChunked = [Some(Series::new("", [1, 2]))].into_iter().collect();
+    /// let mut stash = vec![];
+    /// ca.for_each_amortized(|opt_s| stash.push(opt_s));
+    /// ```
+    ///
+    /// Compute on the sublists instead:
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let ca: ListChunked = [Some(Series::new("", [1, 2]))].into_iter().collect();
+    /// let mut lengths = vec![];
+    /// ca.for_each_amortized(|opt_s| lengths.push(opt_s.map(|s| s.as_ref().len())));
+    /// assert_eq!(lengths, [Some(2)]);
+    /// ```
+    #[cfg(feature = "private")]
+    pub fn for_each_amortized<F>(&self, f: F)
+    where
+        F: FnMut(Option<UnstableSeries<'_>>),
+    {
+        // Safety:
+        // the items are only borrowed by `f`, which cannot store them
+        unsafe { self.amortized_iter() }.for_each(f)
+    }
+
+    /// Apply a closure `F` to every sublist and collect the outputs into a `ChunkedArray`
+    /// with the name of `self`.
+    ///
+    /// The output cannot borrow from the sublist:
+    ///
+    /// ```compile_fail
+    /// # use polars_core::prelude::*;
+    /// let ca: ListChunked = [Some(Series::new("", [1, 2]))].into_iter().collect();
+    /// let out: ListChunked = ca.apply_amortized_generic(|opt_s| opt_s.map(|s| s.as_ref()));
+    /// ```
+    #[cfg(feature = "private")]
+    pub fn apply_amortized_generic<F, K, V>(&self, f: F) -> ChunkedArray<V>
+    where
+        V: PolarsDataType,
+        F: FnMut(Option<UnstableSeries<'_>>) -> Option<K>,
+        ChunkedArray<V>: FromIterator<Option<K>>,
+    {
+        // Safety:
+        // the outputs of `f` cannot borrow from the items
+        let mut ca: ChunkedArray<V> = unsafe { self.amortized_iter() }.map(f).collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Apply a fallible closure `F` to every sublist and collect the outputs into a
+    /// `ChunkedArray` with the name of `self`.
+    #[cfg(feature = "private")]
+    pub fn try_apply_amortized_generic<F, K, V>(&self, f: F) -> PolarsResult<ChunkedArray<V>>
+    where
+        V: PolarsDataType,
+        F: FnMut(Option<UnstableSeries<'_>>) -> PolarsResult<Option<K>>,
+        ChunkedArray<V>: FromIterator<Option<K>>,
+    {
+        // Safety:
+        // the outputs of `f` cannot borrow from the items
+        let mut ca: ChunkedArray<V> = unsafe { self.amortized_iter() }
+            .map(f)
+            .collect::<PolarsResult<_>>()?;
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Apply a fallible closure `F` to every sublist together with the next element of
+    /// `other`, and collect the outputs into a `ListChunked` with the name of `self`.
+    #[cfg(feature = "private")]
+    pub fn try_zip_and_apply_amortized<T, I, F>(&self, other: I, mut f: F) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut(Option<UnstableSeries<'_>>, T) -> PolarsResult<Option<Series>>,
+    {
+        // Safety:
+        // the outputs of `f` cannot borrow from the items
+        let mut ca: ListChunked = unsafe { self.amortized_iter() }
+            .zip(other)
+            .map(|(opt_s, other)| f(opt_s, other))
+            .collect::<PolarsResult<_>>()?;
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Apply a closure `F` elementwise.
     #[cfg(feature = "private")]
     #[must_use]
-    pub fn apply_amortized<'a, F>(&'a self, mut f: F) -> Self
+    pub fn apply_amortized<F>(&self, mut f: F) -> Self
     where
-        F: FnMut(UnstableSeries<'a>) -> Series,
+        F: FnMut(UnstableSeries<'_>) -> Series,
     {
         if self.is_empty() {
             return self.clone();
         }
         let mut fast_explode = self.null_count() == 0;
-        let mut ca: ListChunked = self
-            .amortized_iter()
+        // Safety:
+        // the outputs of `f` cannot borrow from the items
+        let mut ca: ListChunked = unsafe { self.amortized_iter() }
             .map(|opt_v| {
                 opt_v.map(|v| {
                     let out = f(v);
@@ -170,16 +277,17 @@ impl ListChunked {
         ca
     }
 
-    pub fn try_apply_amortized<'a, F>(&'a self, mut f: F) -> PolarsResult<Self>
+    pub fn try_apply_amortized<F>(&self, mut f: F) -> PolarsResult<Self>
     where
-        F: FnMut(UnstableSeries<'a>) -> PolarsResult<Series>,
+        F: FnMut(UnstableSeries<'_>) -> PolarsResult<Series>,
     {
         if self.is_empty() {
             return Ok(self.clone());
         }
         let mut fast_explode = self.null_count() == 0;
-        let mut ca: ListChunked = self
-            .amortized_iter()
+        // Safety:
+        // the outputs of `f` cannot borrow from the items
+        let mut ca: ListChunked = unsafe { self.amortized_iter() }
             .map(|opt_v| {
                 opt_v
                     .map(|v| {
@@ -215,10 +323,10 @@ mod test {
         builder.append_series(&Series::new("", &[1, 1]));
         let ca = builder.finish();
 
-        ca.amortized_iter()
-            .zip(ca.into_iter())
-            .for_each(|(s1, s2)| {
-                assert!(s1.unwrap().as_ref().series_equal(&s2.unwrap()));
-            });
+        let mut expected = ca.into_iter();
+        ca.for_each_amortized(|s1| {
+            let s2 = expected.next().unwrap();
+            assert!(s1.unwrap().as_ref().series_equal(&s2.unwrap()));
+        });
     }
 }
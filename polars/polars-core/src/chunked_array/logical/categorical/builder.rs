@@ -3,7 +3,6 @@ use std::hash::{Hash, Hasher};
 
 use arrow::array::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
-use polars_arrow::trusted_len::PushUnchecked;
 use polars_utils::HashSingle;
 
 use crate::datatypes::PlHashMap;
@@ -74,8 +73,7 @@ impl Default for RevMapping {
         let slice: &[Option<&str>] = &[];
         let cats = Utf8Array::<i64>::from(slice);
         if using_string_cache() {
-            let cache = &mut crate::STRING_CACHE.lock_map();
-            let id = cache.uuid;
+            let id = crate::STRING_CACHE.read_map().uuid;
             RevMapping::Global(Default::default(), cats, id)
         } else {
             RevMapping::Local(cats)
@@ -291,31 +289,21 @@ impl<'a> CategoricalChunkedBuilder<'a> {
 
     /// Build a global string cached `CategoricalChunked` from a local `Dictionary`.
     pub(super) fn global_map_from_local(&mut self, keys: &UInt32Array, values: Utf8Array<i64>) {
-        // locally we don't need a hashmap because we all categories are 1 integer apart
-        // so the index is local, and the values is global
-        let mut local_to_global: Vec<u32> = Vec::with_capacity(values.len());
-        let id;
+        let hash_builder = StringCache::get_hash_builder();
+        let hashes = values
+            .values_iter()
+            .map(|s| hash_builder.hash_single(s))
+            .collect::<Vec<_>>();
 
-        // now we have to lock the global string cache.
+        // now we insert into the global string cache.
         // we will create a mapping from our local categoricals to global categoricals
         // and a mapping from global categoricals to our local categoricals
-
-        // in a separate scope so that we drop the global cache as soon as we are finished
-        {
-            let cache = &mut crate::STRING_CACHE.lock_map();
-            id = cache.uuid;
-
-            for s in values.values_iter() {
-                let global_idx = cache.insert(s);
-
-                // safety:
-                // we allocated enough
-                unsafe { local_to_global.push_unchecked(global_idx) }
-            }
-            if cache.len() > u32::MAX as usize {
-                panic!("not more than {} categories supported", u32::MAX)
-            };
-        }
+        // locally we don't need a hashmap because we all categories are 1 integer apart
+        // so the index is local, and the values is global
+        let (local_to_global, id) = {
+            let cache = crate::STRING_CACHE.read_map();
+            (cache.insert_many(&values, &hashes), cache.uuid)
+        };
         // we now know the exact size
         // no reallocs
         let mut global_to_local = PlHashMap::with_capacity(local_to_global.len());
@@ -349,39 +337,23 @@ impl<'a> CategoricalChunkedBuilder<'a> {
         // `hashes.len()` is equal to to the number of unique values.
         let hashes = self.build_local_map(i, true);
 
-        // locally we don't need a hashmap because we all categories are 1 integer apart
-        // so the index is local, and the values is global
-        let mut local_to_global: Vec<u32>;
-        let id;
-
-        // now we have to lock the global string cache.
-        // we will create a mapping from our local categoricals to global categoricals
-        // and a mapping from global categoricals to our local categoricals
         let values: Utf8Array<_> =
             if let RevMappingBuilder::Local(values) = &mut self.reverse_mapping {
                 debug_assert_eq!(hashes.len(), values.len());
-                // resize local now that we know the size of the mapping.
-                local_to_global = Vec::with_capacity(values.len());
                 std::mem::take(values).into()
             } else {
                 unreachable!()
             };
 
-        // in a separate scope so that we drop the global cache as soon as we are finished
-        {
-            let cache = &mut crate::STRING_CACHE.lock_map();
-            id = cache.uuid;
-
-            for (s, h) in values.values_iter().zip(hashes.into_iter()) {
-                let global_idx = cache.insert_from_hash(h, s);
-                // safety:
-                // we allocated enough
-                unsafe { local_to_global.push_unchecked(global_idx) }
-            }
-            if cache.len() > u32::MAX as usize {
-                panic!("not more than {} categories supported", u32::MAX)
-            };
-        }
+        // now we insert into the global string cache.
+        // we will create a mapping from our local categoricals to global categoricals
+        // and a mapping from global categoricals to our local categoricals
+        // locally we don't need a hashmap because we all categories are 1 integer apart
+        // so the index is local, and the values is global
+        let (local_to_global, id) = {
+            let cache = crate::STRING_CACHE.read_map();
+            (cache.insert_many(&values, &hashes), cache.uuid)
+        };
         // we now know the exact size
         // no reallocs
         let mut global_to_local = PlHashMap::with_capacity(local_to_global.len());
@@ -406,7 +378,7 @@ impl<'a> CategoricalChunkedBuilder<'a> {
         self.reverse_mapping = RevMappingBuilder::GlobalFinished(global_to_local, values, id)
     }
 
-    /// Appends all the values in a single lock of the global string cache.
+    /// Appends all the values in a single lock of every shard of the global string cache.
     pub fn drain_iter<I>(&mut self, i: I)
     where
         I: IntoIterator<Item = Option<&'a str>>,
@@ -468,8 +440,9 @@ impl CategoricalChunked {
     /// This does not do any bound checks
     pub unsafe fn from_global_indices_unchecked(cats: UInt32Chunked) -> CategoricalChunked {
         let cache = crate::STRING_CACHE.read_map();
+        let reader = cache.reader();
 
-        let cap = std::cmp::min(std::cmp::min(cats.len(), cache.len()), HASHMAP_INIT_SIZE);
+        let cap = std::cmp::min(std::cmp::min(cats.len(), reader.len()), HASHMAP_INIT_SIZE);
         let mut rev_map = PlHashMap::with_capacity(cap);
         let mut str_values = MutableUtf8Array::with_capacities(cap, cap * 24);
 
@@ -479,7 +452,7 @@ impl CategoricalChunked {
 
                 if let Entry::Vacant(entry) = rev_map.entry(cat) {
                     entry.insert(offset);
-                    let str_val = cache.get_unchecked(cat);
+                    let str_val = reader.get_unchecked(cat);
                     str_values.push(Some(str_val))
                 }
             }
@@ -510,27 +483,29 @@ mod test {
             Some("bar"),
         ];
         let ca = Utf8Chunked::new("a", slice);
-        let out = ca.cast(&DataType::Categorical(None))?;
+        let out = ca.cast(&DataType::Categorical(None, Default::default()))?;
         let out = out.categorical().unwrap().clone();
         assert_eq!(out.get_rev_map().len(), 2);
 
         // test the global branch
         enable_string_cache(true);
         // empty global cache
-        let out = ca.cast(&DataType::Categorical(None))?;
+        let out = ca.cast(&DataType::Categorical(None, Default::default()))?;
         let out = out.categorical().unwrap().clone();
         assert_eq!(out.get_rev_map().len(), 2);
         // full global cache
-        let out = ca.cast(&DataType::Categorical(None))?;
+        let out = ca.cast(&DataType::Categorical(None, Default::default()))?;
         let out = out.categorical().unwrap().clone();
         assert_eq!(out.get_rev_map().len(), 2);
 
         // Check that we don't panic if we append two categorical arrays
         // build under the same string cache
         // https://github.com/pola-rs/polars/issues/1115
-        let ca1 = Utf8Chunked::new("a", slice).cast(&DataType::Categorical(None))?;
+        let ca1 =
+            Utf8Chunked::new("a", slice).cast(&DataType::Categorical(None, Default::default()))?;
         let mut ca1 = ca1.categorical().unwrap().clone();
-        let ca2 = Utf8Chunked::new("a", slice).cast(&DataType::Categorical(None))?;
+        let ca2 =
+            Utf8Chunked::new("a", slice).cast(&DataType::Categorical(None, Default::default()))?;
         let ca2 = ca2.categorical().unwrap();
         ca1.append(ca2).unwrap();
 
@@ -563,4 +538,61 @@ mod test {
             assert_eq!(s.str_value(2).unwrap(), "world");
         }
     }
+
+    #[test]
+    fn test_string_cache_parallel_builders() -> PolarsResult<()> {
+        use rayon::prelude::*;
+
+        use crate::POOL;
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        enable_string_cache(true);
+
+        let n_unique = 5000;
+        // every column has the strings in a different order, half of them are built by a
+        // builder and half by a cast
+        let columns = POOL.install(|| {
+            (0..64)
+                .into_par_iter()
+                .map(|i| {
+                    let values = (0..n_unique)
+                        .map(|j| format!("cat-{}", (j * 7 + i * 131) % n_unique))
+                        .collect::<Vec<_>>();
+                    let ca = if i % 2 == 0 {
+                        let mut builder = CategoricalChunkedBuilder::new("a", values.len());
+                        for v in &values {
+                            builder.append_value(v)
+                        }
+                        builder.finish()
+                    } else {
+                        let s = Utf8Chunked::from_slice("a", &values)
+                            .cast(&DataType::Categorical(None, Default::default()))
+                            .unwrap();
+                        s.categorical().unwrap().clone()
+                    };
+                    (values, ca)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        // equal strings have equal ids and the ids are dense
+        let mut ids = PlHashMap::new();
+        for (values, ca) in &columns {
+            for (v, id) in values.iter().zip(ca.logical().into_no_null_iter()) {
+                assert_eq!(*ids.entry(v.as_str()).or_insert(id), id);
+                assert_eq!(ca.get_rev_map().get(id), v);
+            }
+        }
+        let mut unique_ids = ids.values().copied().collect::<Vec<_>>();
+        unique_ids.sort_unstable();
+        assert_eq!(unique_ids, (0..n_unique as u32).collect::<Vec<_>>());
+
+        let cats = UInt32Chunked::from_vec("a", unique_ids);
+        let ca = CategoricalChunked::from_global_indices(cats)?;
+        for (v, id) in &ids {
+            assert_eq!(ca.get_rev_map().get(*id), *v);
+        }
+        reset_string_cache();
+        Ok(())
+    }
 }
@@ -13,8 +13,19 @@ impl From<&CategoricalChunked> for DictionaryArray<u32> {
         let dtype = ArrowDataType::Dictionary(
             IntegerType::UInt32,
             Box::new(ArrowDataType::LargeUtf8),
-            false,
+            ca.use_lexical_sort(),
         );
+        if ca.use_lexical_sort() {
+            // arrow defines the order of an ordered dictionary by the order of its values
+            let (keys, values) = ca.to_lexical_dictionary();
+            let keys = keys.rechunk();
+            let keys = keys.downcast_iter().next().unwrap();
+            // Safety:
+            // the ranks are in bounds of the sorted categories
+            return unsafe {
+                DictionaryArray::try_new_unchecked(dtype, keys.clone(), Box::new(values)).unwrap()
+            };
+        }
         match map {
             RevMapping::Local(arr) => {
                 // Safety:
@@ -48,8 +59,29 @@ impl From<&CategoricalChunked> for DictionaryArray<i64> {
         let dtype = ArrowDataType::Dictionary(
             IntegerType::UInt32,
             Box::new(ArrowDataType::LargeUtf8),
-            false,
+            ca.use_lexical_sort(),
         );
+        if ca.use_lexical_sort() {
+            // arrow defines the order of an ordered dictionary by the order of its values
+            let (keys, values) = ca.to_lexical_dictionary();
+            let keys = keys.rechunk();
+            let keys = keys.downcast_iter().next().unwrap();
+            // Safety:
+            // the ranks are in bounds of the sorted categories
+            return unsafe {
+                DictionaryArray::try_new_unchecked(
+                    dtype,
+                    cast(keys, &ArrowDataType::Int64)
+                        .unwrap()
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<i64>>()
+                        .unwrap()
+                        .clone(),
+                    Box::new(values),
+                )
+                .unwrap()
+            };
+        }
         match map {
             // Safety:
             // the keys are in bounds
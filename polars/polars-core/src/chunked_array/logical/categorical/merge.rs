@@ -5,17 +5,17 @@ use arrow::offset::Offsets;
 
 use super::*;
 
+/// Merge the rev-maps of two categoricals that are combined in `operation`.
 pub(crate) fn merge_categorical_map(
     left: &Arc<RevMapping>,
     right: &Arc<RevMapping>,
+    operation: &str,
 ) -> PolarsResult<Arc<RevMapping>> {
     match (&**left, &**right) {
         (RevMapping::Global(l_map, l_slots, l_id), RevMapping::Global(r_map, r_slots, r_id)) => {
-            polars_ensure!(
-                l_id == r_id,
-                ComputeError: "unable to merge categorical arrays created under different global \
-                string caches (try setting a global string cache)"
-            );
+            if l_id != r_id {
+                return Err(string_cache_mismatch_err(operation));
+            }
             let mut new_map = (*l_map).clone();
 
             // safety: invariants don't change, just the type
@@ -72,16 +72,17 @@ pub(crate) fn merge_categorical_map(
 
             Ok(Arc::new(RevMapping::Local(arr)))
         }
-        _ => polars_bail!(
-            ComputeError:
-            "unable to merge categorical under a global string cache with a non-cached one"
-        ),
+        _ => Err(string_cache_mismatch_err(operation)),
     }
 }
 
 impl CategoricalChunked {
-    pub(crate) fn merge_categorical_map(&self, other: &Self) -> PolarsResult<Arc<RevMapping>> {
-        merge_categorical_map(self.get_rev_map(), other.get_rev_map())
+    pub(crate) fn merge_categorical_map(
+        &self,
+        other: &Self,
+        operation: &str,
+    ) -> PolarsResult<Arc<RevMapping>> {
+        merge_categorical_map(self.get_rev_map(), other.get_rev_map(), operation)
     }
 }
 
@@ -104,7 +105,7 @@ mod test {
         builder2.drain_iter(vec![Some("hello"), None, Some("world"), Some("bar")].into_iter());
         let ca1 = builder1.finish();
         let ca2 = builder2.finish();
-        let rev_map = ca1.merge_categorical_map(&ca2).unwrap();
+        let rev_map = ca1.merge_categorical_map(&ca2, "merge").unwrap();
 
         let mut ca = UInt32Chunked::new("", &[0, 1, 2, 3]);
         ca.categorical_map = Some(rev_map);
@@ -12,12 +12,12 @@ use polars_utils::sync::SyncPtr;
 
 use super::*;
 use crate::prelude::*;
+use crate::using_string_cache;
 
 bitflags! {
     #[derive(Default)]
     struct BitSettings: u8 {
     const ORIGINAL = 0x01;
-    const LEXICAL_SORT = 0x02;
 }}
 
 #[derive(Clone)]
@@ -25,10 +25,19 @@ pub struct CategoricalChunked {
     logical: Logical<CategoricalType, UInt32Type>,
     /// 1st bit: original local categorical
     ///             meaning that n_unique is the same as the cat map length
-    /// 2nd bit: use lexical sorting
     bit_settings: BitSettings,
 }
 
+/// How the values of a [`CategoricalChunked`] are ordered when sorting and comparing.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CategoricalOrdering {
+    /// Use the physical categories for sorting
+    #[default]
+    Physical,
+    /// Use the string value for sorting
+    Lexical,
+}
+
 impl CategoricalChunked {
     pub(crate) fn field(&self) -> Field {
         let name = self.logical().name();
@@ -65,7 +74,10 @@ impl CategoricalChunked {
     ) -> Self {
         let ca = unsafe { UInt32Chunked::from_chunks(name, chunks) };
         let mut logical = Logical::<UInt32Type, _>::new_logical::<CategoricalType>(ca);
-        logical.2 = Some(DataType::Categorical(Some(Arc::new(rev_map))));
+        logical.2 = Some(DataType::Categorical(
+            Some(Arc::new(rev_map)),
+            Default::default(),
+        ));
 
         let mut bit_settings = BitSettings::default();
         bit_settings.insert(BitSettings::ORIGINAL);
@@ -77,14 +89,29 @@ impl CategoricalChunked {
 
     pub fn set_lexical_sorted(&mut self, toggle: bool) {
         if toggle {
-            self.bit_settings.insert(BitSettings::LEXICAL_SORT);
+            self.set_ordering(CategoricalOrdering::Lexical)
         } else {
-            self.bit_settings.remove(BitSettings::LEXICAL_SORT);
+            self.set_ordering(CategoricalOrdering::Physical)
         }
     }
 
     pub(crate) fn use_lexical_sort(&self) -> bool {
-        self.bit_settings.contains(BitSettings::LEXICAL_SORT)
+        self.get_ordering() == CategoricalOrdering::Lexical
+    }
+
+    /// Set the ordering used by sorting, `min`/`max` and comparisons.
+    pub fn set_ordering(&mut self, ordering: CategoricalOrdering) {
+        if let Some(DataType::Categorical(_, current)) = &mut self.logical.2 {
+            *current = ordering
+        }
+    }
+
+    /// The ordering used by sorting, `min`/`max` and comparisons.
+    pub fn get_ordering(&self) -> CategoricalOrdering {
+        match &self.logical.2 {
+            Some(DataType::Categorical(_, ordering)) => *ordering,
+            _ => panic!("implementation error"),
+        }
     }
 
     /// Create a [`CategoricalChunked`] from an array of `idx` and an existing [`RevMapping`]:  `rev_map`.
@@ -96,7 +123,7 @@ impl CategoricalChunked {
         rev_map: Arc<RevMapping>,
     ) -> Self {
         let mut logical = Logical::<UInt32Type, _>::new_logical::<CategoricalType>(idx);
-        logical.2 = Some(DataType::Categorical(Some(rev_map)));
+        logical.2 = Some(DataType::Categorical(Some(rev_map), Default::default()));
         Self {
             logical,
             bit_settings: Default::default(),
@@ -106,7 +133,7 @@ impl CategoricalChunked {
     /// # Safety
     /// The existing index values must be in bounds of the new [`RevMapping`].
     pub(crate) unsafe fn set_rev_map(&mut self, rev_map: Arc<RevMapping>, keep_fast_unique: bool) {
-        self.logical.2 = Some(DataType::Categorical(Some(rev_map)));
+        self.logical.2 = Some(DataType::Categorical(Some(rev_map), self.get_ordering()));
         if !keep_fast_unique {
             self.set_fast_unique(false)
         }
@@ -126,7 +153,7 @@ impl CategoricalChunked {
 
     /// Get a reference to the mapping of categorical types to the string values.
     pub fn get_rev_map(&self) -> &Arc<RevMapping> {
-        if let DataType::Categorical(Some(rev_map)) = &self.logical.2.as_ref().unwrap() {
+        if let DataType::Categorical(Some(rev_map), _) = &self.logical.2.as_ref().unwrap() {
             rev_map
         } else {
             panic!("implementation error")
@@ -141,6 +168,71 @@ impl CategoricalChunked {
             iter,
         }
     }
+
+    /// Convert to a categorical with a local [`RevMapping`], that doesn't depend on the global
+    /// string cache.
+    pub fn to_local(&self) -> Self {
+        match &**self.get_rev_map() {
+            RevMapping::Local(_) => self.clone(),
+            RevMapping::Global(map, values, _) => {
+                // null slots may hold any value, so only the valid categories are mapped
+                let cats = self
+                    .logical()
+                    .apply_on_opt(|cat| cat.map(|cat| *map.get(&cat).unwrap()));
+                // safety:
+                // the global map points into `values`
+                let mut out = unsafe {
+                    Self::from_cats_and_rev_map_unchecked(
+                        cats,
+                        Arc::new(RevMapping::Local(values.clone())),
+                    )
+                };
+                out.set_lexical_sorted(self.use_lexical_sort());
+                out
+            }
+        }
+    }
+
+    /// Convert to a categorical under the current global string cache, so that it can be
+    /// combined with other categoricals created under that cache.
+    ///
+    /// # Errors
+    /// Fails if the global string cache is not enabled.
+    pub fn to_global(&self) -> PolarsResult<Self> {
+        polars_ensure!(
+            using_string_cache(),
+            ComputeError: "`to_global` requires the global string cache to be enabled; \
+            hold a `StringCacheHolder` or use `with_string_cache`"
+        );
+        if let RevMapping::Global(_, _, id) = &**self.get_rev_map() {
+            if *id == crate::STRING_CACHE.read_map().uuid {
+                return Ok(self.clone());
+            }
+        }
+        let local = self.to_local();
+        let keys = local.logical().rechunk();
+        let keys = keys.downcast_iter().next().unwrap();
+        let values = match &**local.get_rev_map() {
+            RevMapping::Local(values) => values,
+            RevMapping::Global(_, _, _) => unreachable!(),
+        };
+        let mut builder = CategoricalChunkedBuilder::new(self.name(), self.len());
+        builder.global_map_from_local(keys, values.clone());
+        let mut out = builder.finish();
+        out.set_lexical_sorted(self.use_lexical_sort());
+        Ok(out)
+    }
+}
+
+/// The error for an `operation` combining categoricals that were not created under the same
+/// global string cache.
+pub(crate) fn string_cache_mismatch_err(operation: &str) -> PolarsError {
+    polars_err!(
+        ComputeError: "{} on categoricals requires them to be created under the same global \
+        string cache; create them while a `StringCacheHolder` is alive (or within \
+        `with_string_cache`), or convert them with `CategoricalChunked::to_global`",
+        operation
+    )
 }
 
 impl LogicalType for CategoricalChunked {
@@ -190,7 +282,11 @@ impl LogicalType for CategoricalChunked {
                 Ok(ca.into_series())
             }
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => Ok(self.clone().into_series()),
+            DataType::Categorical(_, ordering) => {
+                let mut out = self.clone();
+                out.set_ordering(*ordering);
+                Ok(out.into_series())
+            }
             _ => self.logical.cast(dtype),
         }
     }
@@ -228,7 +324,9 @@ mod test {
     use std::convert::TryFrom;
 
     use super::*;
-    use crate::{enable_string_cache, reset_string_cache, SINGLE_LOCK};
+    use crate::{
+        enable_string_cache, reset_string_cache, with_string_cache, StringCacheHolder, SINGLE_LOCK,
+    };
 
     #[test]
     fn test_categorical_round_trip() -> PolarsResult<()> {
@@ -243,12 +341,12 @@ mod test {
             Some("bar"),
         ];
         let ca = Utf8Chunked::new("a", slice);
-        let ca = ca.cast(&DataType::Categorical(None))?;
+        let ca = ca.cast(&DataType::Categorical(None, Default::default()))?;
         let ca = ca.categorical().unwrap();
 
         let arr: DictionaryArray<u32> = (ca).into();
         let s = Series::try_from(("foo", Box::new(arr) as ArrayRef))?;
-        assert!(matches!(s.dtype(), &DataType::Categorical(_)));
+        assert!(matches!(s.dtype(), &DataType::Categorical(_, _)));
         assert_eq!(s.null_count(), 1);
         assert_eq!(s.len(), 6);
 
@@ -262,10 +360,10 @@ mod test {
         enable_string_cache(true);
 
         let mut s1 = Series::new("1", vec!["a", "b", "c"])
-            .cast(&DataType::Categorical(None))
+            .cast(&DataType::Categorical(None, Default::default()))
             .unwrap();
         let s2 = Series::new("2", vec!["a", "x", "y"])
-            .cast(&DataType::Categorical(None))
+            .cast(&DataType::Categorical(None, Default::default()))
             .unwrap();
         let appended = s1.append(&s2).unwrap();
         assert_eq!(appended.str_value(0).unwrap(), "a");
@@ -278,7 +376,7 @@ mod test {
     fn test_fast_unique() {
         let _lock = SINGLE_LOCK.lock();
         let s = Series::new("1", vec!["a", "b", "c"])
-            .cast(&DataType::Categorical(None))
+            .cast(&DataType::Categorical(None, Default::default()))
             .unwrap();
 
         assert_eq!(s.n_unique().unwrap(), 3);
@@ -296,11 +394,12 @@ mod test {
         enable_string_cache(false);
 
         // tests several things that may loose the dtype information
-        let s = Series::new("a", vec!["a", "b", "c"]).cast(&DataType::Categorical(None))?;
+        let s = Series::new("a", vec!["a", "b", "c"])
+            .cast(&DataType::Categorical(None, Default::default()))?;
 
         assert_eq!(
             s.field().into_owned(),
-            Field::new("a", DataType::Categorical(None))
+            Field::new("a", DataType::Categorical(None, Default::default()))
         );
         assert!(matches!(
             s.get(0)?,
@@ -311,7 +410,7 @@ mod test {
         let aggregated = unsafe { s.agg_list(&groups?) };
         match aggregated.get(0)? {
             AnyValue::List(s) => {
-                assert!(matches!(s.dtype(), DataType::Categorical(_)));
+                assert!(matches!(s.dtype(), DataType::Categorical(_, _)));
                 let str_s = s.cast(&DataType::Utf8).unwrap();
                 assert_eq!(str_s.get(0)?, AnyValue::Utf8("a"));
                 assert_eq!(s.len(), 1);
@@ -324,4 +423,40 @@ mod test {
         assert_eq!(vals, &["a", "b", "c"]);
         Ok(())
     }
+
+    #[test]
+    fn test_string_cache_holder_to_local_to_global() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        assert!(with_string_cache(using_string_cache));
+        assert!(!using_string_cache());
+
+        // created without the string cache
+        let local = Series::new("a", [Some("b"), None, Some("a")])
+            .cast(&DataType::Categorical(None, Default::default()))?;
+        let local = local.categorical()?;
+        assert!(local.to_global().is_err());
+
+        let _holder = StringCacheHolder::new();
+        {
+            let _nested = StringCacheHolder::new();
+        }
+        // the cache is kept as long as the outer holder is alive
+        assert!(using_string_cache());
+
+        let global =
+            Series::new("b", ["a", "c"]).cast(&DataType::Categorical(None, Default::default()))?;
+        let mut global = global.categorical()?.clone();
+        let err = global.append(local).unwrap_err();
+        assert!(err.to_string().contains("append"));
+
+        global.append(&local.to_global()?)?;
+        let vals = global.iter_str().collect::<Vec<_>>();
+        assert_eq!(vals, &[Some("a"), Some("c"), Some("b"), None, Some("a")]);
+
+        let local = global.to_local();
+        assert!(matches!(&**local.get_rev_map(), RevMapping::Local(_)));
+        assert_eq!(local.iter_str().collect::<Vec<_>>(), vals);
+        Ok(())
+    }
 }
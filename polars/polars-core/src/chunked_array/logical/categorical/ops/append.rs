@@ -17,13 +17,10 @@ impl CategoricalChunked {
             };
 
         if is_local_different_source {
-            polars_bail!(
-                ComputeError:
-                "cannot concat categoricals coming from a different source; consider setting a global StringCache"
-            );
+            return Err(string_cache_mismatch_err("append"));
         } else {
             let len = self.len();
-            let new_rev_map = self.merge_categorical_map(other)?;
+            let new_rev_map = self.merge_categorical_map(other, "append")?;
             unsafe { self.set_rev_map(new_rev_map, false) };
 
             self.logical_mut().length += other.len() as IdxSize;
@@ -4,6 +4,7 @@ use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use ahash::RandomState;
+use arrow::array::Utf8Array;
 use hashbrown::hash_map::RawEntryMut;
 use once_cell::sync::Lazy;
 use polars_utils::HashSingle;
@@ -11,45 +12,51 @@ use smartstring::{LazyCompact, SmartString};
 
 use crate::datatypes::PlIdHashMap;
 use crate::frame::groupby::hashing::HASHMAP_INIT_SIZE;
-use crate::prelude::InitHashMaps;
 
 /// We use atomic reference counting
 /// to determine how many threads use the string cache
 /// if the refcount is zero, we may clear the string cache.
 pub(crate) static USE_STRING_CACHE: AtomicU32 = AtomicU32::new(0);
 
-/// RAII for the string cache
-pub struct IUseStringCache {
+/// RAII guard for the global string cache.
+///
+/// The string cache is enabled while any holder is alive. Holders can be nested; the cache is
+/// cleared when the last one is dropped.
+pub struct StringCacheHolder {
     // only added so that it will never be constructed directly
     #[allow(dead_code)]
     private_zst: (),
 }
 
-impl Default for IUseStringCache {
+impl Default for StringCacheHolder {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl IUseStringCache {
+impl StringCacheHolder {
     /// Hold the StringCache
-    pub fn new() -> IUseStringCache {
+    pub fn new() -> StringCacheHolder {
         enable_string_cache(true);
-        IUseStringCache { private_zst: () }
+        StringCacheHolder { private_zst: () }
     }
 }
 
-impl Drop for IUseStringCache {
+impl Drop for StringCacheHolder {
     fn drop(&mut self) {
         enable_string_cache(false)
     }
 }
 
+#[deprecated(note = "renamed to `StringCacheHolder`")]
+pub type IUseStringCache = StringCacheHolder;
+
+/// Run `func` with the global string cache enabled.
+///
+/// The cache is released when `func` returns or panics.
 pub fn with_string_cache<F: FnOnce() -> T, T>(func: F) -> T {
-    enable_string_cache(true);
-    let out = func();
-    enable_string_cache(false);
-    out
+    let _holder = StringCacheHolder::new();
+    func()
 }
 
 /// Use a global string cache for the Categorical Types.
@@ -79,7 +86,19 @@ pub fn using_string_cache() -> bool {
     USE_STRING_CACHE.load(Ordering::Acquire) > 0
 }
 
-// This is the hash and the Index offset in the linear buffer
+/// The number of shards of the global string cache, a power of two.
+const N_SHARDS: usize = 64;
+
+/// The shard of a string with hash `h`.
+///
+/// The low bits select the bucket in the hashmap of a shard and the high bits are the tag of
+/// the bucket, so the shard is taken from the bits in between.
+#[inline]
+fn shard_of(h: u64) -> usize {
+    (h >> 32) as usize & (N_SHARDS - 1)
+}
+
+// This is the hash and the Index offset in the linear buffer of the shard
 #[derive(Copy, Clone)]
 struct Key {
     pub(super) hash: u64,
@@ -100,55 +119,18 @@ impl Hash for Key {
     }
 }
 
-pub(crate) struct SCacheInner {
+/// The strings whose hashes fall in a single shard.
+#[derive(Default)]
+struct Shard {
     map: PlIdHashMap<Key, ()>,
-    pub(crate) uuid: u128,
     payloads: Vec<StrHashGlobal>,
+    /// The global id of every payload.
+    global_ids: Vec<u32>,
 }
 
-impl SCacheInner {
-    #[inline]
-    pub(crate) unsafe fn get_unchecked(&self, cat: u32) -> &str {
-        self.payloads.get_unchecked(cat as usize).as_str()
-    }
-
-    pub(crate) fn len(&self) -> usize {
-        self.map.len()
-    }
-
+impl Shard {
     #[inline]
-    pub(crate) fn insert_from_hash(&mut self, h: u64, s: &str) -> u32 {
-        let mut global_idx = self.payloads.len() as u32;
-        // Note that we don't create the StrHashGlobal to search the key in the hashmap
-        // as StrHashGlobal may allocate a string
-        let entry = self.map.raw_entry_mut().from_hash(h, |key| {
-            (key.hash == h) && {
-                let pos = key.idx as usize;
-                let value = unsafe { self.payloads.get_unchecked(pos) };
-                s == value.as_str()
-            }
-        });
-
-        match entry {
-            RawEntryMut::Occupied(entry) => {
-                global_idx = entry.key().idx;
-            }
-            RawEntryMut::Vacant(entry) => {
-                let idx = self.payloads.len() as u32;
-                let key = Key::new(h, idx);
-                entry.insert_hashed_nocheck(h, key, ());
-
-                // only just now we allocate the string
-                self.payloads.push(s.into());
-            }
-        }
-        global_idx
-    }
-
-    #[inline]
-    pub(crate) fn get_cat(&self, s: &str) -> Option<u32> {
-        let h = StringCache::get_hash_builder().hash_single(s);
-        // as StrHashGlobal may allocate a string
+    fn get(&self, h: u64, s: &str) -> Option<u32> {
         self.map
             .raw_entry()
             .from_hash(h, |key| {
@@ -158,20 +140,143 @@ impl SCacheInner {
                     s == value.as_str()
                 }
             })
-            .map(|(k, _)| k.idx)
+            .map(|(k, _)| unsafe { *self.global_ids.get_unchecked(k.idx as usize) })
+    }
+
+    /// Insert the `values` at `positions` and write their indices in the shard to `out`.
+    ///
+    /// The position of every inserted value is pushed to `new`. The inserted values get their
+    /// global ids in [`SCacheInner::insert_many`].
+    fn insert_many(
+        &mut self,
+        positions: &[usize],
+        values: &Utf8Array<i64>,
+        hashes: &[u64],
+        out: &mut [u32],
+        new: &mut Vec<usize>,
+    ) {
+        for &i in positions {
+            let h = hashes[i];
+            // Safety:
+            // the positions are in bounds of the values
+            let s = unsafe { values.value_unchecked(i) };
+            // Note that we don't create the StrHashGlobal to search the key in the hashmap
+            // as StrHashGlobal may allocate a string
+            let entry = self.map.raw_entry_mut().from_hash(h, |key| {
+                (key.hash == h) && {
+                    let pos = key.idx as usize;
+                    let value = unsafe { self.payloads.get_unchecked(pos) };
+                    s == value.as_str()
+                }
+            });
+            out[i] = match entry {
+                RawEntryMut::Occupied(entry) => entry.key().idx,
+                RawEntryMut::Vacant(entry) => {
+                    let idx = self.payloads.len() as u32;
+                    entry.insert_hashed_nocheck(h, Key::new(h, idx), ());
+                    // only just now we allocate the string
+                    self.payloads.push(s.into());
+                    new.push(i);
+                    idx
+                }
+            };
+        }
+    }
+}
+
+/// The global string cache is split in shards by the hash of the strings, so that columns
+/// that are built in parallel only wait on each other if they insert into the same shard at
+/// the same moment. The global ids are still handed out in order of insertion.
+pub(crate) struct SCacheInner {
+    shards: Vec<RwLock<Shard>>,
+    /// The shard and the index in that shard of every global id.
+    ids: RwLock<Vec<(u32, u32)>>,
+    pub(crate) uuid: u128,
+}
+
+impl SCacheInner {
+    pub(crate) fn len(&self) -> usize {
+        self.ids.read().unwrap().len()
     }
 
     #[inline]
-    pub(crate) fn insert(&mut self, s: &str) -> u32 {
+    pub(crate) fn get_cat(&self, s: &str) -> Option<u32> {
         let h = StringCache::get_hash_builder().hash_single(s);
-        self.insert_from_hash(h, s)
+        self.shards[shard_of(h)].read().unwrap().get(h, s)
+    }
+
+    /// Get the global ids of `values`, inserting the values that are not in the cache yet.
+    ///
+    /// `hashes` are the hashes of the values by [`StringCache::get_hash_builder`]. Every shard
+    /// is locked once, for all the values that belong to it. The new values get consecutive
+    /// global ids in the order of their first appearance in `values`.
+    pub(crate) fn insert_many(&self, values: &Utf8Array<i64>, hashes: &[u64]) -> Vec<u32> {
+        debug_assert_eq!(values.len(), hashes.len());
+        let mut positions = vec![vec![]; N_SHARDS];
+        for (i, h) in hashes.iter().enumerate() {
+            positions[shard_of(*h)].push(i)
+        }
+
+        // `out` gets the indices in the shards, which are mapped to global ids at the end
+        let mut out = vec![0; hashes.len()];
+        let mut new = vec![];
+        // the shards stay locked until their new values have global ids
+        let mut shards = positions
+            .iter()
+            .enumerate()
+            .map(|(shard_idx, positions)| {
+                (!positions.is_empty()).then(|| {
+                    let mut shard = self.shards[shard_idx].write().unwrap();
+                    shard.insert_many(positions, values, hashes, &mut out, &mut new);
+                    shard
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if !new.is_empty() {
+            // the positions within a shard are ascending, so every shard gets the global ids
+            // of its new values in the order of their indices
+            new.sort_unstable();
+            let mut ids = self.ids.write().unwrap();
+            if ids.len() + new.len() > u32::MAX as usize {
+                panic!("not more than {} categories supported", u32::MAX)
+            };
+            for i in new {
+                let shard_idx = shard_of(hashes[i]);
+                let shard = shards[shard_idx].as_mut().unwrap();
+                debug_assert_eq!(shard.global_ids.len(), out[i] as usize);
+                shard.global_ids.push(ids.len() as u32);
+                ids.push((shard_idx as u32, out[i]));
+            }
+        }
+
+        for (i, h) in hashes.iter().enumerate() {
+            // Safety:
+            // the shards of all the values are locked and the indices are in bounds
+            out[i] = unsafe {
+                let shard = shards.get_unchecked(shard_of(*h)).as_ref().unwrap();
+                *shard.global_ids.get_unchecked(out[i] as usize)
+            };
+        }
+        out
+    }
+
+    /// Lock all the shards for reading, to get the strings of global ids.
+    pub(crate) fn reader(&self) -> SCacheReader<'_> {
+        // the shards are locked before the ids, as in `insert_many`
+        let shards = self.shards.iter().map(|s| s.read().unwrap()).collect();
+        SCacheReader {
+            shards,
+            ids: self.ids.read().unwrap(),
+        }
     }
 }
 
 impl Default for SCacheInner {
     fn default() -> Self {
         Self {
-            map: PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE),
+            shards: (0..N_SHARDS).map(|_| Default::default()).collect(),
+            ids: RwLock::new(Vec::with_capacity(HASHMAP_INIT_SIZE)),
             #[cfg(not(target_family = "wasm"))]
             uuid: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -182,11 +287,34 @@ impl Default for SCacheInner {
                 .duration_since(wasm_timer::UNIX_EPOCH)
                 .unwrap()
                 .as_nanos(),
-            payloads: Vec::with_capacity(HASHMAP_INIT_SIZE),
         }
     }
 }
 
+/// Maps global ids to their strings while it holds a read lock on every shard.
+pub(crate) struct SCacheReader<'a> {
+    shards: Vec<RwLockReadGuard<'a, Shard>>,
+    ids: RwLockReadGuard<'a, Vec<(u32, u32)>>,
+}
+
+impl SCacheReader<'_> {
+    pub(crate) fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// # Safety
+    /// `cat` must be smaller than [`SCacheReader::len`].
+    #[inline]
+    pub(crate) unsafe fn get_unchecked(&self, cat: u32) -> &str {
+        let (shard_idx, idx) = *self.ids.get_unchecked(cat as usize);
+        self.shards
+            .get_unchecked(shard_idx as usize)
+            .payloads
+            .get_unchecked(idx as usize)
+            .as_str()
+    }
+}
+
 /// Used by categorical data that need to share global categories.
 /// In *eager* you need to specifically toggle global string cache to have a global effect.
 /// In *lazy* it is toggled on at the start of a computation run and turned of (deleted) when a
@@ -220,3 +348,40 @@ impl StringCache {
 pub(crate) static STRING_CACHE: Lazy<StringCache> = Lazy::new(Default::default);
 
 type StrHashGlobal = SmartString<LazyCompact>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_many_ids_in_order() {
+        let cache = SCacheInner::default();
+        let hash_builder = StringCache::get_hash_builder();
+        let insert = |values: &[&str]| {
+            let values = Utf8Array::<i64>::from_slice(values);
+            let hashes = values
+                .values_iter()
+                .map(|s| hash_builder.hash_single(s))
+                .collect::<Vec<_>>();
+            cache.insert_many(&values, &hashes)
+        };
+
+        // enough strings to fall in many shards
+        let words = (0..200).map(|i| format!("w{i}")).collect::<Vec<_>>();
+        let mut values = words.iter().map(|s| s.as_str()).rev().collect::<Vec<_>>();
+        values.push("w199");
+        let out = insert(&values);
+        let mut expected = (0..200).collect::<Vec<u32>>();
+        expected.push(0);
+        assert_eq!(out, expected);
+
+        // known strings keep their ids and new strings follow in order of appearance
+        let out = insert(&["new_b", "w0", "new_a", "new_b"]);
+        assert_eq!(out, &[200, 199, 201, 200]);
+        assert_eq!(cache.len(), 202);
+
+        let reader = cache.reader();
+        assert_eq!(unsafe { reader.get_unchecked(201) }, "new_a");
+        assert_eq!(unsafe { reader.get_unchecked(0) }, "w199");
+    }
+}
@@ -47,6 +47,8 @@ use std::slice::Iter;
 use bitflags::bitflags;
 use polars_arrow::kernels::concatenate::concatenate_owned_unchecked;
 use polars_arrow::prelude::*;
+#[cfg(feature = "random")]
+pub use random::SampleSize;
 
 use crate::series::IsSorted;
 use crate::utils::{first_non_null, last_non_null, CustomIterTools};
@@ -731,7 +733,9 @@ pub(crate) mod test {
         let _lock = SINGLE_LOCK.lock();
         reset_string_cache();
         let ca = Utf8Chunked::new("", &[Some("foo"), None, Some("bar"), Some("ham")]);
-        let ca = ca.cast(&DataType::Categorical(None)).unwrap();
+        let ca = ca
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
         let ca = ca.categorical().unwrap();
         let v: Vec<_> = ca.logical().into_iter().collect();
         assert_eq!(v, &[Some(0), None, Some(1), Some(2)]);
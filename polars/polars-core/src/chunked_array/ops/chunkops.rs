@@ -72,17 +72,18 @@ impl<T: PolarsDataType> ChunkedArray<T> {
                 _ => chunks.iter().fold(0, |acc, arr| acc + arr.len()),
             }
         }
-        self.length = inner(&self.chunks) as IdxSize;
+        let len = inner(&self.chunks);
         #[cfg(feature = "python")]
         assert!(
-            self.length < IdxSize::MAX,
+            len < IdxSize::MAX as usize,
             "Polars' maximum length reached. Consider installing 'polars-u64-idx'."
         );
         #[cfg(not(feature = "python"))]
         assert!(
-            self.length < IdxSize::MAX,
+            len < IdxSize::MAX as usize,
             "Polars' maximum length reached. Consider compiling with 'bigidx' feature."
         );
+        self.length = len as IdxSize;
     }
 
     pub fn rechunk(&self) -> Self {
@@ -196,7 +197,9 @@ mod test {
     #[cfg(feature = "dtype-categorical")]
     fn test_categorical_map_after_rechunk() {
         let s = Series::new("", &["foo", "bar", "spam"]);
-        let mut a = s.cast(&DataType::Categorical(None)).unwrap();
+        let mut a = s
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
 
         a.append(&a.slice(0, 2)).unwrap();
         let a = a.rechunk();
@@ -38,7 +38,7 @@ macro_rules! impl_traits {
             unsafe fn cmp_element_unchecked(&self, idx_a: usize, idx_b: usize) -> Ordering {
                 let a = self.get(idx_a);
                 let b = self.get(idx_b);
-                a.partial_cmp(&b).unwrap_or_else(|| fallback(a))
+                a.partial_cmp(&b).unwrap_or_else(|| fallback(a, b))
             }
         }
     };
@@ -62,7 +62,7 @@ macro_rules! impl_traits {
                 // nulls so we can not do unchecked
                 let a = self.get(idx_a);
                 let b = self.get(idx_b);
-                a.partial_cmp(&b).unwrap_or_else(|| fallback(a))
+                a.partial_cmp(&b).unwrap_or_else(|| fallback(a, b))
             }
         }
     };
@@ -185,15 +185,17 @@ impl<'a> IntoPartialEqInner<'a> for &'a BooleanChunked {
 
 // Partial ordering implementations
 
-fn fallback<T: PartialEq>(a: T) -> Ordering {
+fn fallback<T: PartialEq>(a: T, b: T) -> Ordering {
     // nan != nan
     // this is a simple way to check if it is nan
     // without convincing the compiler we deal with floats
     #[allow(clippy::eq_op)]
-    if a != a {
-        Ordering::Less
-    } else {
-        Ordering::Greater
+    let nans = (a != a, b != b);
+    match nans {
+        // nans are equal and larger than any other value, as in `compare_fn_nan_max`
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        _ => Ordering::Less,
     }
 }
 
@@ -205,7 +207,7 @@ where
         // no nulls so we can do unchecked
         let a = self.get_unchecked(idx_a);
         let b = self.get_unchecked(idx_b);
-        a.partial_cmp(&b).unwrap_or_else(|| fallback(a))
+        a.partial_cmp(&b).unwrap_or_else(|| fallback(a, b))
     }
 }
 /// Create a type that implements PartialOrdInner
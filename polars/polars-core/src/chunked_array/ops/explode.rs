@@ -2,7 +2,7 @@ use std::convert::TryFrom;
 
 use arrow::array::*;
 use arrow::bitmap::{Bitmap, MutableBitmap};
-use arrow::offset::OffsetsBuffer;
+use arrow::offset::{Offsets, OffsetsBuffer};
 use polars_arrow::array::PolarsArray;
 use polars_arrow::bit_util::unset_bit_raw;
 use polars_arrow::prelude::*;
@@ -10,6 +10,7 @@ use polars_arrow::prelude::*;
 use crate::chunked_array::builder::AnonymousOwnedListBuilder;
 use crate::prelude::*;
 use crate::series::implementations::null::NullChunked;
+use crate::utils::_check_idx_len;
 
 pub(crate) trait ExplodeByOffsets {
     fn explode_by_offsets(&self, offsets: &[i64]) -> Series;
@@ -348,69 +349,93 @@ pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<IdxSiz
     idx
 }
 
+/// Whether the values of `arr` are already exploded: it has no null or empty lists.
+fn is_dense(arr: &LargeListArray) -> bool {
+    arr.null_count() == 0 && arr.offsets().as_slice().windows(2).all(|w| w[0] < w[1])
+}
+
+/// Explode a single chunk of a list.
+fn explode_chunk(name: &str, arr: &LargeListArray, inner_dtype: &DataType, dense: bool) -> Series {
+    let offsets = arr.offsets().as_slice();
+    let mut values = arr.values().clone();
+    if dense {
+        // ensure that the value array is sliced
+        // as a list only slices its offsets on a slice operation
+
+        // we only do this for dense chunks as for the other
+        // chunks the offsets must coincide with the values.
+        let start = offsets[0] as usize;
+        let len = offsets[offsets.len() - 1] as usize - start;
+        // safety:
+        // we are in bounds
+        values = unsafe { values.sliced_unchecked(start, len) };
+    }
+    // safety: inner_dtype should be correct
+    let values =
+        unsafe { Series::from_chunks_and_dtype_unchecked(name, vec![values], inner_dtype) };
+    if dense {
+        values
+    } else {
+        values.explode_by_offsets(offsets)
+    }
+}
+
 impl ChunkExplode for ListChunked {
     fn explode_and_offsets(&self) -> PolarsResult<(Series, OffsetsBuffer<i64>)> {
         // A list array's memory layout is actually already 'exploded', so we can just take the values array
         // of the list. And we also return a slice of the offsets. This slice can be used to find the old
         // list layout or indexes to expand the DataFrame in the same manner as the 'explode' operation
-        let ca = self.rechunk();
-        let listarr: &LargeListArray = ca
+        polars_ensure!(!self.chunks.is_empty(), NoData: "cannot explode empty list");
+        let inner_dtype = self.inner_dtype().to_physical();
+        let can_fast_explode = self._can_fast_explode();
+
+        let dense = self
             .downcast_iter()
-            .next()
-            .ok_or_else(|| polars_err!(NoData: "cannot explode empty list"))?;
-        let offsets_buf = listarr.offsets().clone();
-        let offsets = listarr.offsets().as_slice();
-        let mut values = listarr.values().clone();
-
-        let mut s = if ca._can_fast_explode() {
-            // ensure that the value array is sliced
-            // as a list only slices its offsets on a slice operation
-
-            // we only do this in fast-explode as for the other
-            // branch the offsets must coincide with the values.
-            if !offsets.is_empty() {
-                let start = offsets[0] as usize;
-                let len = offsets[offsets.len() - 1] as usize - start;
-                // safety:
-                // we are in bounds
-                values = unsafe { values.sliced_unchecked(start, len) };
-            }
-            // safety: inner_dtype should be correct
-            unsafe {
-                Series::from_chunks_and_dtype_unchecked(
-                    self.name(),
-                    vec![values],
-                    &self.inner_dtype().to_physical(),
-                )
-            }
-        } else {
-            // during tests
-            // test that this code branch is not hit with list arrays that could be fast exploded
-            #[cfg(test)]
-            {
-                let mut last = offsets[0];
-                let mut has_empty = false;
-                for &o in &offsets[1..] {
-                    if o == last {
-                        has_empty = true;
-                    }
-                    last = o;
-                }
-                if !has_empty && offsets[0] == 0 {
-                    panic!("could have fast exploded")
+            .map(|arr| can_fast_explode || is_dense(arr))
+            .collect::<Vec<_>>();
+        let len = self
+            .downcast_iter()
+            .zip(&dense)
+            .map(|(arr, dense)| {
+                let offsets = arr.offsets().as_slice();
+                let n_values = (offsets[offsets.len() - 1] - offsets[0]) as usize;
+                if *dense {
+                    n_values
+                } else {
+                    // an empty list gives a null row
+                    n_values + offsets.windows(2).filter(|w| w[0] == w[1]).count()
                 }
-            }
+            })
+            .sum();
+        _check_idx_len(len)?;
 
-            // safety: inner_dtype should be correct
-            let values = unsafe {
-                Series::from_chunks_and_dtype_unchecked(
-                    self.name(),
-                    vec![values],
-                    &self.inner_dtype().to_physical(),
-                )
-            };
-            values.explode_by_offsets(offsets)
+        // every chunk is exploded on its own, so that the chunks without null or empty lists
+        // keep their values zero-copy
+        let mut chunks = self
+            .downcast_iter()
+            .zip(dense)
+            .map(|(arr, dense)| explode_chunk(self.name(), arr, &inner_dtype, dense));
+        let mut s = chunks.next().unwrap();
+        for chunk in chunks {
+            s.append(&chunk)?;
+        }
+
+        let offsets_buf = if self.chunks.len() == 1 {
+            self.downcast_iter().next().unwrap().offsets().clone()
+        } else {
+            // concatenate the offsets of the chunks
+            let mut offsets = Vec::with_capacity(self.len() + 1);
+            offsets.push(0i64);
+            for arr in self.downcast_iter() {
+                let arr_offsets = arr.offsets().as_slice();
+                let base = offsets[offsets.len() - 1] - arr_offsets[0];
+                offsets.extend(arr_offsets[1..].iter().map(|o| o + base));
+            }
+            // safety:
+            // the offsets of every chunk are monotonically increasing
+            unsafe { Offsets::new_unchecked(offsets).into() }
         };
+
         debug_assert_eq!(s.name(), self.name());
         // restore logical type
         unsafe {
@@ -419,7 +444,7 @@ impl ChunkExplode for ListChunked {
         // // make sure we restore the logical type
         // match self.inner_dtype() {
         //     #[cfg(feature = "dtype-categorical")]
-        //     DataType::Categorical(rev_map) => {
+        //     DataType::Categorical(rev_map, _) => {
         //         let cats = s.u32().unwrap().clone();
         //         // safety:
         //         // rev_map is from same array, so we are still in bounds
@@ -504,6 +529,7 @@ impl ChunkExplode for Utf8Chunked {
                 old_offset = offset;
             }
 
+            _check_idx_len(new_offsets.len() - 1)?;
             (new_offsets.into(), bitmap.into())
         } else {
             // fast(er) explode
@@ -543,6 +569,7 @@ impl ChunkExplode for Utf8Chunked {
                 old_offset = offset;
             }
 
+            _check_idx_len(new_offsets.len() - 1)?;
             (new_offsets.into(), None)
         };
 
@@ -586,6 +613,52 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_explode_list_chunks() -> PolarsResult<()> {
+        let mut builder = get_list_builder(&DataType::Int32, 5, 5, "a")?;
+        builder.append_series(&Series::new("", &[1, 2, 3]));
+        builder.append_series(&Series::new("", &[4]));
+        let dense = builder.finish();
+
+        let mut builder = get_list_builder(&DataType::Int32, 5, 5, "a")?;
+        builder.append_series(&Series::new("", &[5]));
+        builder.append_series(&Series::new("", &[] as &[i32]));
+        builder.append_series(&Series::new("", &[6, 7]));
+        let mut ca = dense.clone();
+        ca.append(&builder.finish())?;
+        assert!(!ca._can_fast_explode());
+
+        let (exploded, offsets) = ca.explode_and_offsets()?;
+        assert_eq!(
+            Vec::from(exploded.i32()?),
+            &[
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(5),
+                None,
+                Some(6),
+                Some(7)
+            ]
+        );
+        assert_eq!(offsets.as_slice(), &[0, 3, 4, 5, 5, 7]);
+
+        // the dense chunk is not copied
+        let values = dense.downcast_iter().next().unwrap().values();
+        let values = values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap();
+        let out = exploded.i32()?.downcast_iter().next().unwrap();
+        assert_eq!(out.values().as_ptr(), values.values().as_ptr());
+
+        // a sliced dense chunk
+        let exploded = ca.slice(1, 2).explode()?;
+        assert_eq!(Vec::from(exploded.i32()?), &[Some(4), Some(5)]);
+        Ok(())
+    }
+
     #[test]
     fn test_explode_list_nulls() -> PolarsResult<()> {
         let ca = Int32Chunked::from_slice_options("", &[None, Some(1), Some(2)]);
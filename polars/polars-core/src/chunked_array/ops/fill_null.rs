@@ -17,6 +17,7 @@ impl Series {
     /// Replace None values with one of the following strategies:
     /// * Forward fill (replace None with the previous value)
     /// * Backward fill (replace None with the next value)
+    /// * Forward then backward fill (forward fill, then backward fill the remaining Nones)
     /// * Mean fill (replace None with the mean of the whole array)
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
@@ -37,6 +38,13 @@ impl Series {
     ///     let filled = s.fill_null(FillNullStrategy::Backward(None))?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(2), Some(2)]);
     ///
+    ///     let gaps = Series::new("gaps", &[None, Some(1), None, None, Some(2)]);
+    ///     let filled = gaps.fill_null(FillNullStrategy::ForwardThenBackward(Some(1)))?;
+    ///     assert_eq!(
+    ///         Vec::from(filled.i32()?),
+    ///         &[Some(1), Some(1), Some(1), Some(2), Some(2)]
+    ///     );
+    ///
     ///     let filled = s.fill_null(FillNullStrategy::Min)?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(1), Some(2)]);
     ///
@@ -51,6 +59,11 @@ impl Series {
     /// example();
     /// ```
     pub fn fill_null(&self, strategy: FillNullStrategy) -> PolarsResult<Series> {
+        if let FillNullStrategy::ForwardThenBackward(limit) = strategy {
+            return self
+                .fill_null(FillNullStrategy::Forward(limit))?
+                .fill_null(FillNullStrategy::Backward(limit));
+        }
         let logical_type = self.dtype();
         let s = self.to_physical_repr();
 
@@ -298,6 +311,7 @@ where
         FillNullStrategy::Zero => return ca.fill_null_with_values(Zero::zero()),
         FillNullStrategy::MinBound => return ca.fill_null_with_values(Bounded::min_value()),
         FillNullStrategy::MaxBound => return ca.fill_null_with_values(Bounded::max_value()),
+        FillNullStrategy::ForwardThenBackward(_) => unreachable!(),
     };
     out.rename(ca.name());
     Ok(out)
@@ -338,6 +352,7 @@ fn fill_null_bool(ca: &BooleanChunked, strategy: FillNullStrategy) -> PolarsResu
         FillNullStrategy::Zero | FillNullStrategy::MinBound => {
             ca.fill_null_with_values(false).map(|ca| ca.into_series())
         }
+        FillNullStrategy::ForwardThenBackward(_) => unreachable!(),
     }
 }
 
@@ -413,3 +428,50 @@ impl ChunkFillNullValue<&[u8]> for BinaryChunked {
         self.set(&self.is_null(), Some(value))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fill_null_limit() -> PolarsResult<()> {
+        // the run of nulls spans the chunk boundary
+        let mut s = Series::new("a", [Some(1i32), None, None]);
+        s.append(&Series::new("a", [None, None, Some(2), None]))?;
+        assert_eq!(s.n_chunks(), 2);
+
+        let out = s.fill_null(FillNullStrategy::Forward(Some(3)))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(1), Some(1), Some(1), None, Some(2), Some(2)]
+        );
+        let out = s.fill_null(FillNullStrategy::Backward(Some(1)))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), None, None, None, Some(2), Some(2), None]
+        );
+        let out = s.fill_null(FillNullStrategy::ForwardThenBackward(Some(2)))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(2)
+            ]
+        );
+
+        let s = Series::new("b", [None, Some("x"), None, None, None]);
+        let out = s.fill_null(FillNullStrategy::ForwardThenBackward(Some(1)))?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("x"), Some("x"), Some("x"), None, None]
+        );
+        let out = s.fill_null(FillNullStrategy::ForwardThenBackward(None))?;
+        assert_eq!(out.null_count(), 0);
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "object")]
 use arrow::array::Array;
-use arrow::compute::filter::filter as filter_fn;
+use polars_arrow::compute::filter::filter as filter_fn;
 
 #[cfg(feature = "object")]
 use crate::chunked_array::object::builder::ObjectChunkedBuilder;
@@ -35,7 +35,7 @@ where
         let chunks = left
             .downcast_iter()
             .zip(filter.downcast_iter())
-            .map(|(left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(left, mask)| filter_fn(left, mask))
             .collect::<Vec<_>>();
         Ok(self.copy_with_chunks(chunks, true, true))
     }
@@ -56,7 +56,7 @@ impl ChunkFilter<BooleanType> for BooleanChunked {
         let chunks = left
             .downcast_iter()
             .zip(filter.downcast_iter())
-            .map(|(left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(left, mask)| filter_fn(left, mask))
             .collect::<Vec<_>>();
         Ok(self.copy_with_chunks(chunks, true, true))
     }
@@ -84,7 +84,7 @@ impl ChunkFilter<BinaryType> for BinaryChunked {
         let chunks = left
             .downcast_iter()
             .zip(filter.downcast_iter())
-            .map(|(left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(left, mask)| filter_fn(left, mask))
             .collect::<Vec<_>>();
 
         Ok(self.copy_with_chunks(chunks, true, true))
@@ -110,7 +110,7 @@ impl ChunkFilter<ListType> for ListChunked {
         let chunks = left
             .downcast_iter()
             .zip(filter.downcast_iter())
-            .map(|(left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(left, mask)| filter_fn(left, mask))
             .collect::<Vec<_>>();
 
         // inner type may be categorical or logical type so we clone the state.
@@ -54,25 +54,29 @@ where
                 let mut ca: BooleanChunked = if self.len() == 1 && other.len() != 1 {
                     let value = self.get(0);
 
-                    other
-                        .list()?
-                        .amortized_iter()
+                    // Safety:
+                    // the items are only used within their iteration
+                    unsafe { other.list()?.amortized_iter() }
                         .map(|opt_s| {
-                            opt_s.map(|s| {
-                                let ca = s.as_ref().unpack::<T>().unwrap();
-                                ca.into_iter().any(|a| a == value)
-                            }) == Some(true)
+                            value.and_then(|value| {
+                                opt_s.map(|s| {
+                                    let ca = s.as_ref().unpack::<T>().unwrap();
+                                    ca.into_iter().any(|a| a == Some(value))
+                                })
+                            })
                         })
                         .collect_trusted()
                 } else {
+                    // Safety:
+                    // the items are only used within their iteration
                     self.into_iter()
-                        .zip(other.list()?.amortized_iter())
+                        .zip(unsafe { other.list()?.amortized_iter() })
                         .map(|(value, series)| match (value, series) {
-                            (val, Some(series)) => {
+                            (Some(val), Some(series)) => {
                                 let ca = series.as_ref().unpack::<T>().unwrap();
-                                ca.into_iter().any(|a| a == val)
+                                Some(ca.into_iter().any(|a| a == Some(val)))
                             }
-                            _ => false,
+                            _ => None,
                         })
                         .collect_trusted()
                 };
@@ -117,51 +121,48 @@ impl IsIn for Utf8Chunked {
     fn is_in(&self, other: &Series) -> PolarsResult<BooleanChunked> {
         match other.dtype() {
             #[cfg(feature = "dtype-categorical")]
-            DataType::List(dt) if matches!(&**dt, DataType::Categorical(_)) => {
-                if let DataType::Categorical(Some(rev_map)) = &**dt {
+            DataType::List(dt)
+                if matches!(&**dt, DataType::Categorical(_, _)) && self.len() == 1 =>
+            {
+                if let DataType::Categorical(Some(rev_map), _) = &**dt {
                     let opt_val = self.get(0);
 
                     let other = other.list()?;
                     match opt_val {
-                        None => {
-                            let mut ca: BooleanChunked = other
-                                .amortized_iter()
+                        None => Ok(BooleanChunked::full_null(self.name(), other.len())),
+                        Some(value) => {
+                            let idx = rev_map.find(value);
+                            // Safety:
+                            // the items are only used within their iteration
+                            let mut ca: BooleanChunked = unsafe { other.amortized_iter() }
                                 .map(|opt_s| {
-                                    opt_s.map(|s| s.as_ref().null_count() > 0) == Some(true)
+                                    opt_s.map(|s| {
+                                        // not in the categories, so in none of the lists
+                                        let Some(idx) = idx else {
+                                            return false;
+                                        };
+                                        let s = s.as_ref().to_physical_repr();
+                                        let ca = s.as_ref().u32().unwrap();
+                                        if ca.null_count() == 0 {
+                                            ca.into_no_null_iter().any(|a| a == idx)
+                                        } else {
+                                            ca.into_iter().any(|a| a == Some(idx))
+                                        }
+                                    })
                                 })
                                 .collect_trusted();
                             ca.rename(self.name());
                             Ok(ca)
                         }
-                        Some(value) => {
-                            match rev_map.find(value) {
-                                // all false
-                                None => Ok(BooleanChunked::full(self.name(), false, other.len())),
-                                Some(idx) => {
-                                    let mut ca: BooleanChunked = other
-                                        .amortized_iter()
-                                        .map(|opt_s| {
-                                            opt_s.map(|s| {
-                                                let s = s.as_ref().to_physical_repr();
-                                                let ca = s.as_ref().u32().unwrap();
-                                                if ca.null_count() == 0 {
-                                                    ca.into_no_null_iter().any(|a| a == idx)
-                                                } else {
-                                                    ca.into_iter().any(|a| a == Some(idx))
-                                                }
-                                            }) == Some(true)
-                                        })
-                                        .collect_trusted();
-                                    ca.rename(self.name());
-                                    Ok(ca)
-                                }
-                            }
-                        }
                     }
                 } else {
                     unreachable!()
                 }
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::List(dt) if matches!(&**dt, DataType::Categorical(_, _)) => {
+                self.is_in(&other.cast(&DataType::List(Box::new(DataType::Utf8)))?)
+            }
             DataType::List(dt) if DataType::Utf8 == **dt => self.as_binary().is_in(
                 &other
                     .cast(&DataType::List(Box::new(DataType::Binary)))
@@ -181,25 +182,29 @@ impl IsIn for BinaryChunked {
             DataType::List(dt) if DataType::Binary == **dt => {
                 let mut ca: BooleanChunked = if self.len() == 1 && other.len() != 1 {
                     let value = self.get(0);
-                    other
-                        .list()?
-                        .amortized_iter()
+                    // Safety:
+                    // the items are only used within their iteration
+                    unsafe { other.list()?.amortized_iter() }
                         .map(|opt_b| {
-                            opt_b.map(|s| {
-                                let ca = s.as_ref().unpack::<BinaryType>().unwrap();
-                                ca.into_iter().any(|a| a == value)
-                            }) == Some(true)
+                            value.and_then(|value| {
+                                opt_b.map(|s| {
+                                    let ca = s.as_ref().unpack::<BinaryType>().unwrap();
+                                    ca.into_iter().any(|a| a == Some(value))
+                                })
+                            })
                         })
                         .collect_trusted()
                 } else {
+                    // Safety:
+                    // the items are only used within their iteration
                     self.into_iter()
-                        .zip(other.list()?.amortized_iter())
+                        .zip(unsafe { other.list()?.amortized_iter() })
                         .map(|(value, series)| match (value, series) {
-                            (val, Some(series)) => {
+                            (Some(val), Some(series)) => {
                                 let ca = series.as_ref().unpack::<BinaryType>().unwrap();
-                                ca.into_iter().any(|a| a == val)
+                                Some(ca.into_iter().any(|a| a == Some(val)))
                             }
-                            _ => false,
+                            _ => None,
                         })
                         .collect_trusted()
                 };
@@ -237,29 +242,34 @@ impl IsIn for BooleanChunked {
             DataType::List(dt) if self.dtype() == &**dt => {
                 let mut ca: BooleanChunked = if self.len() == 1 && other.len() != 1 {
                     let value = self.get(0);
-                    // safety: we know the iterators len
+                    // safety: we know the iterators len and the items are only used within
+                    // their iteration
                     unsafe {
                         other
                             .list()?
                             .amortized_iter()
                             .map(|opt_s| {
-                                opt_s.map(|s| {
-                                    let ca = s.as_ref().unpack::<BooleanType>().unwrap();
-                                    ca.into_iter().any(|a| a == value)
-                                }) == Some(true)
+                                value.and_then(|value| {
+                                    opt_s.map(|s| {
+                                        let ca = s.as_ref().unpack::<BooleanType>().unwrap();
+                                        ca.into_iter().any(|a| a == Some(value))
+                                    })
+                                })
                             })
                             .trust_my_length(other.len())
                             .collect_trusted()
                     }
                 } else {
+                    // Safety:
+                    // the items are only used within their iteration
                     self.into_iter()
-                        .zip(other.list()?.amortized_iter())
+                        .zip(unsafe { other.list()?.amortized_iter() })
                         .map(|(value, series)| match (value, series) {
-                            (val, Some(series)) => {
+                            (Some(val), Some(series)) => {
                                 let ca = series.as_ref().unpack::<BooleanType>().unwrap();
-                                ca.into_iter().any(|a| a == val)
+                                Some(ca.into_iter().any(|a| a == Some(val)))
                             }
-                            _ => false,
+                            _ => None,
                         })
                         .collect_trusted()
                 };
@@ -281,6 +291,12 @@ impl IsIn for BooleanChunked {
     }
 }
 
+/// A struct value is null if all of its fields are null.
+#[cfg(feature = "dtype-struct")]
+fn is_null_struct(fields: &[AnyValue]) -> bool {
+    fields.iter().all(|av| matches!(av, AnyValue::Null))
+}
+
 #[cfg(feature = "dtype-struct")]
 impl IsIn for StructChunked {
     fn is_in(&self, other: &Series) -> PolarsResult<BooleanChunked> {
@@ -293,25 +309,30 @@ impl IsIn for StructChunked {
                     if let AnyValue::Struct(_, _, _) = av {
                         av._materialize_struct_av(&mut value);
                     }
-                    other
-                        .list()?
-                        .amortized_iter()
+                    if is_null_struct(&value) {
+                        return Ok(BooleanChunked::full_null(self.name(), other.len()));
+                    }
+                    // Safety:
+                    // the items are only used within their iteration
+                    unsafe { other.list()?.amortized_iter() }
                         .map(|opt_s| {
                             opt_s.map(|s| {
                                 let ca = s.as_ref().struct_().unwrap();
                                 ca.into_iter().any(|a| a == value)
-                            }) == Some(true)
+                            })
                         })
                         .collect()
                 } else {
+                    // Safety:
+                    // the items are only used within their iteration
                     self.into_iter()
-                        .zip(other.list()?.amortized_iter())
+                        .zip(unsafe { other.list()?.amortized_iter() })
                         .map(|(value, series)| match (value, series) {
-                            (val, Some(series)) => {
+                            (val, Some(series)) if !is_null_struct(val) => {
                                 let ca = series.as_ref().struct_().unwrap();
-                                ca.into_iter().any(|a| a == val)
+                                Some(ca.into_iter().any(|a| a == val))
                             }
-                            _ => false,
+                            _ => None,
                         })
                         .collect()
                 };
@@ -382,4 +403,56 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_is_in_list_per_row() -> PolarsResult<()> {
+        let a = Int32Chunked::new("a", &[Some(1), Some(2), None, Some(4), Some(5)]);
+        let b = Series::new(
+            "b",
+            [
+                Some(Series::new("", [1i64, 3])),
+                Some(Series::new("", [3i64])),
+                Some(Series::new("", [1i64])),
+                None,
+                Some(Series::new("", Vec::<i64>::new())),
+            ],
+        );
+
+        // the lists are cast to the supertype
+        let out = a.is_in(&b)?;
+        assert_eq!(
+            Vec::from(&out),
+            [Some(true), Some(false), None, None, Some(false)]
+        );
+
+        let a = Utf8Chunked::new("a", &["x", "y"]);
+        let b = Series::new(
+            "b",
+            [Series::new("", ["x", "z"]), Series::new("", ["x", "z"])],
+        );
+        let out = a.is_in(&b)?;
+        assert_eq!(Vec::from(&out), [Some(true), Some(false)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_is_in_list_per_row_struct() -> PolarsResult<()> {
+        let a = StructChunked::new("a", &[Series::new("x", [Some(1), None])])?;
+        let b = Series::new(
+            "b",
+            [
+                StructChunked::new("", &[Series::new("x", [Some(1)])])?.into_series(),
+                StructChunked::new("", &[Series::new("x", [None::<i32>])])?.into_series(),
+            ],
+        );
+
+        // a null struct is not tested against the list
+        let out = a.is_in(&b)?;
+        assert_eq!(Vec::from(&out), [Some(true), None]);
+        let null = StructChunked::new("a", &[Series::new("x", [None::<i32>])])?;
+        let out = null.is_in(&b)?;
+        assert_eq!(Vec::from(&out), [None, None]);
+        Ok(())
+    }
 }
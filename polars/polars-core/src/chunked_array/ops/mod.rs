@@ -509,6 +509,8 @@ pub enum FillNullStrategy {
     Backward(FillNullLimit),
     /// next value in array
     Forward(FillNullLimit),
+    /// previous value in array, then next value in array for the remaining nulls
+    ForwardThenBackward(FillNullLimit),
     /// mean value of array
     Mean,
     /// minimal value in array
@@ -709,7 +711,7 @@ pub trait IsIn {
 #[cfg(feature = "repeat_by")]
 pub trait RepeatBy {
     /// Repeat the values `n` times, where `n` is determined by the values in `by`.
-    fn repeat_by(&self, _by: &IdxCa) -> ListChunked {
+    fn repeat_by(&self, _by: &IdxCa) -> PolarsResult<ListChunked> {
         unimplemented!()
     }
 }
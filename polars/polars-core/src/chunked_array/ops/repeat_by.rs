@@ -1,90 +1,130 @@
 use arrow::array::ListArray;
-use polars_arrow::array::ListFromIter;
+use arrow::offset::Offsets;
 
 use super::RepeatBy;
 use crate::prelude::*;
 
 type LargeListArray = ListArray<i64>;
 
+/// Repeat every value of `s` `n` times, where `n` is given by `by`. A `by` of length 1
+/// is broadcast to the length of `s`.
+///
+/// The list offsets are the cumulative sum of `by` and the values are gathered in a single
+/// `take`. A null `n` produces a null list and a zero `n` an empty list.
+fn repeat_by_impl(s: &Series, by: &IdxCa) -> PolarsResult<ListChunked> {
+    let len = s.len();
+    let by = if by.len() == 1 && len != 1 {
+        by.new_from_index(0, len)
+    } else {
+        polars_ensure!(
+            by.len() == len,
+            ShapeMismatch: "`repeat_by` expected `by` of length {} or 1, got {}", len, by.len()
+        );
+        by.rechunk()
+    };
+
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut total = 0usize;
+    let mut has_empty = false;
+    for opt_n in by.into_iter() {
+        let n = opt_n.unwrap_or(0) as usize;
+        has_empty |= n == 0;
+        // checked, so that an absurd `n` errors instead of trying to allocate
+        total = total
+            .checked_add(n)
+            .filter(|total| *total <= IdxSize::MAX as usize)
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "`repeat_by` would produce more than {} values", IdxSize::MAX
+                )
+            })?;
+        offsets.push(total as i64);
+    }
+
+    let mut idx = Vec::new();
+    idx.try_reserve_exact(total).map_err(
+        |_| polars_err!(ComputeError: "`repeat_by` could not allocate {} values", total),
+    )?;
+    for (i, w) in offsets.windows(2).enumerate() {
+        idx.extend(std::iter::repeat(i as IdxSize).take((w[1] - w[0]) as usize));
+    }
+    let idx = IdxCa::from_vec("", idx);
+    // Safety:
+    // every index is smaller than the length of `s`
+    let values = unsafe { s.take_unchecked(&idx)? }.rechunk();
+    let values = values.to_arrow(0);
+
+    let validity = by.downcast_iter().next().unwrap().validity().cloned();
+    // Safety:
+    // offsets are monotonically increasing
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.data_type().clone()),
+        unsafe { Offsets::new_unchecked(offsets) }.into(),
+        values,
+        validity,
+    );
+    let mut ca = unsafe { ListChunked::from_chunks(s.name(), vec![Box::new(arr)]) };
+    if !has_empty {
+        ca.set_fast_explode()
+    }
+    Ok(ca)
+}
+
 impl<T> RepeatBy for ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        let iter = self
-            .into_iter()
-            .zip(by.into_iter())
-            .map(|(opt_v, opt_by)| opt_by.map(|by| std::iter::repeat(opt_v).take(by as usize)));
-
-        // Safety:
-        // Length of iter is trusted
-        unsafe {
-            ListChunked::from_chunks(
-                self.name(),
-                vec![Box::new(LargeListArray::from_iter_primitive_trusted_len::<
-                    T::Native,
-                    _,
-                    _,
-                >(iter, T::get_dtype().to_arrow()))],
-            )
-        }
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
+        repeat_by_impl(&self.clone().into_series(), by)
     }
 }
 impl RepeatBy for BooleanChunked {
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        let iter = self
-            .into_iter()
-            .zip(by.into_iter())
-            .map(|(opt_v, opt_by)| opt_by.map(|by| std::iter::repeat(opt_v).take(by as usize)));
-
-        // Safety:
-        // Length of iter is trusted
-        unsafe {
-            ListChunked::from_chunks(
-                self.name(),
-                vec![Box::new(LargeListArray::from_iter_bool_trusted_len(iter))],
-            )
-        }
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
+        repeat_by_impl(&self.clone().into_series(), by)
     }
 }
 impl RepeatBy for Utf8Chunked {
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        // TODO! dispatch via binary.
-        let iter = self
-            .into_iter()
-            .zip(by.into_iter())
-            .map(|(opt_v, opt_by)| opt_by.map(|by| std::iter::repeat(opt_v).take(by as usize)));
-
-        // Safety:
-        // Length of iter is trusted
-        unsafe {
-            ListChunked::from_chunks(
-                self.name(),
-                vec![Box::new(LargeListArray::from_iter_utf8_trusted_len(
-                    iter,
-                    self.len(),
-                ))],
-            )
-        }
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
+        repeat_by_impl(&self.clone().into_series(), by)
     }
 }
 impl RepeatBy for BinaryChunked {
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        let iter = self
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
+        repeat_by_impl(&self.clone().into_series(), by)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_repeat_by() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(1), None, Some(3), Some(4)]);
+        let by = IdxCa::new("", &[Some(2), Some(1), Some(0), None]);
+        let out = ca.repeat_by(&by)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.null_count(), 1);
+        let lens = out
             .into_iter()
-            .zip(by.into_iter())
-            .map(|(opt_v, opt_by)| opt_by.map(|by| std::iter::repeat(opt_v).take(by as usize)));
+            .map(|opt_s| opt_s.map(|s| s.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(lens, &[Some(2), Some(1), Some(0), None]);
+        let exploded = out.explode()?;
+        let values = exploded.i32()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(values, &[Some(1), Some(1), None, None, None]);
+
+        // a scalar `by` is broadcast
+        let ca = Utf8Chunked::new("a", &["x", "y"]);
+        let out = ca.repeat_by(&IdxCa::new("", &[2]))?;
+        let exploded = out.explode()?;
+        let values = exploded.utf8()?.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(values, &["x", "x", "y", "y"]);
 
-        // Safety:
-        // Length of iter is trusted
-        unsafe {
-            ListChunked::from_chunks(
-                self.name(),
-                vec![Box::new(LargeListArray::from_iter_binary_trusted_len(
-                    iter,
-                    self.len(),
-                ))],
-            )
-        }
+        assert!(ca.repeat_by(&IdxCa::new("", &[1, 2, 3])).is_err());
+        let by = IdxCa::new("", &[IdxSize::MAX, IdxSize::MAX]);
+        assert!(ca.repeat_by(&by).is_err());
+        Ok(())
     }
 }
@@ -69,7 +69,7 @@ pub fn _get_rows_encoded_compat_array(by: &Series) -> PolarsResult<ArrayRef> {
 
     let out = match by.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical(_) => {
+        DataType::Categorical(_, _) => {
             let ca = by.categorical().unwrap();
             if ca.use_lexical_sort() {
                 by.to_arrow(0)
@@ -108,16 +108,42 @@ pub fn _get_rows_encoded(
     by: &[Series],
     descending: &[bool],
     nulls_last: bool,
+) -> PolarsResult<RowsEncoded> {
+    get_rows_encoded(by, descending, &vec![nulls_last; by.len()])
+}
+
+/// Encode `by` to rows that compare in the order of a sort by multiple columns.
+///
+/// The nulls of ascending columns are placed first and those of descending columns last, as
+/// the comparator of [`ChunkSort::arg_sort_multiple`] places them. With `nulls_last`, the nulls
+/// of every column are placed last.
+pub fn _get_rows_encoded_sort(
+    by: &[Series],
+    descending: &[bool],
+    nulls_last: bool,
+) -> PolarsResult<RowsEncoded> {
+    let nulls_last = descending
+        .iter()
+        .map(|&descending| nulls_last || descending)
+        .collect::<Vec<_>>();
+    get_rows_encoded(by, descending, &nulls_last)
+}
+
+fn get_rows_encoded(
+    by: &[Series],
+    descending: &[bool],
+    nulls_last: &[bool],
 ) -> PolarsResult<RowsEncoded> {
     debug_assert_eq!(by.len(), descending.len());
+    debug_assert_eq!(by.len(), nulls_last.len());
     let mut cols = Vec::with_capacity(by.len());
     let mut fields = Vec::with_capacity(by.len());
-    for (by, descending) in by.iter().zip(descending) {
+    for ((by, descending), nulls_last) in by.iter().zip(descending).zip(nulls_last) {
         let arr = _get_rows_encoded_compat_array(by)?;
 
         let sort_field = SortField {
             descending: *descending,
-            nulls_last,
+            nulls_last: *nulls_last,
         };
         match arr.data_type() {
             // flatten the struct fields
@@ -155,7 +181,7 @@ pub(crate) fn argsort_multiple_row_fmt(
 ) -> PolarsResult<IdxCa> {
     _broadcast_descending(by.len(), &mut descending);
 
-    let rows_encoded = _get_rows_encoded(by, &descending, nulls_last)?;
+    let rows_encoded = _get_rows_encoded_sort(by, &descending, nulls_last)?;
     let mut items: Vec<_> = rows_encoded.iter().enumerate_idx().collect();
 
     if parallel {
@@ -167,3 +193,135 @@ pub(crate) fn argsort_multiple_row_fmt(
     let ca: NoNull<IdxCa> = items.into_iter().map(|tpl| tpl.0).collect();
     Ok(ca.into_inner())
 }
+
+#[cfg(test)]
+#[cfg(feature = "random")]
+mod test {
+    use polars_arrow::kernels::rolling::compare_fn_nan_max;
+    use rand::rngs::SmallRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    fn valid(rng: &mut SmallRng) -> bool {
+        rng.gen_bool(0.8)
+    }
+
+    /// Random columns with nulls and few distinct values, so that the later sort columns break
+    /// ties.
+    fn random_columns(rng: &mut SmallRng, len: usize) -> Vec<Series> {
+        const FLOATS: [f64; 6] = [-1.5, -0.0, 0.0, 2.0, f64::INFINITY, f64::NEG_INFINITY];
+        const WORDS: [&str; 5] = ["", "a", "ab", "b", "ba"];
+
+        let ints: Vec<Option<i32>> = (0..len)
+            .map(|_| valid(rng).then(|| rng.gen_range(-2..3)))
+            .collect();
+        let floats: Vec<Option<f64>> = (0..len)
+            .map(|_| {
+                valid(rng).then(|| {
+                    if rng.gen_bool(0.1) {
+                        if rng.gen() {
+                            f64::NAN
+                        } else {
+                            -f64::NAN
+                        }
+                    } else {
+                        *FLOATS.choose(rng).unwrap()
+                    }
+                })
+            })
+            .collect();
+        let words: Vec<Option<&str>> = (0..len)
+            .map(|_| valid(rng).then(|| *WORDS.choose(rng).unwrap()))
+            .collect();
+        let bools: Vec<Option<bool>> = (0..len).map(|_| valid(rng).then(|| rng.gen())).collect();
+        vec![
+            Series::new("int", ints),
+            Series::new("float", floats),
+            Series::new("str", words),
+            Series::new("bool", bools),
+        ]
+    }
+
+    fn cmp_valid(s: &Series, a: usize, b: usize) -> Ordering {
+        match s.dtype() {
+            DataType::Int32 => {
+                let ca = s.i32().unwrap();
+                ca.get(a).cmp(&ca.get(b))
+            }
+            DataType::Float64 => {
+                let ca = s.f64().unwrap();
+                compare_fn_nan_max(&ca.get(a).unwrap(), &ca.get(b).unwrap())
+            }
+            DataType::Utf8 => {
+                let ca = s.utf8().unwrap();
+                ca.get(a).cmp(&ca.get(b))
+            }
+            DataType::Boolean => {
+                let ca = s.bool().unwrap();
+                ca.get(a).cmp(&ca.get(b))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Stable sort of the row indices that compares the rows column by column, with NaN as the
+    /// largest value. The nulls of a column are placed last if `nulls_last` is set or the column
+    /// is descending, and first otherwise.
+    fn reference_arg_sort(by: &[Series], descending: &[bool], nulls_last: bool) -> Vec<IdxSize> {
+        let validity = by
+            .iter()
+            .map(|s| s.is_not_null().into_no_null_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut idx = (0..by[0].len()).collect::<Vec<_>>();
+        idx.sort_by(|&a, &b| {
+            for ((s, validity), &descending) in by.iter().zip(&validity).zip(descending) {
+                let nulls_last = nulls_last || descending;
+                let ord = match (validity[a], validity[b]) {
+                    (true, true) if descending => cmp_valid(s, a, b).reverse(),
+                    (true, true) => cmp_valid(s, a, b),
+                    (false, false) => Ordering::Equal,
+                    (false, true) if nulls_last => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (true, false) if nulls_last => Ordering::Less,
+                    (true, false) => Ordering::Greater,
+                };
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            Ordering::Equal
+        });
+        idx.into_iter().map(|i| i as IdxSize).collect()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_row_fmt_arg_sort_random() -> PolarsResult<()> {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..50 {
+            let n_by = rng.gen_range(2..=4);
+            let descending = (0..n_by).map(|_| rng.gen()).collect::<Vec<bool>>();
+            let nulls_last = rng.gen();
+
+            let mut by = random_columns(&mut rng, 200);
+            by.shuffle(&mut rng);
+            let by = &by[..n_by];
+            let out = argsort_multiple_row_fmt(by, descending.clone(), nulls_last, true)?;
+            assert_eq!(
+                out.into_no_null_iter().collect::<Vec<_>>(),
+                reference_arg_sort(by, &descending, nulls_last)
+            );
+
+            let columns = by
+                .iter()
+                .map(|s| convert_sort_column_multi_sort(s, false))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let comparator = columns[0].arg_sort_multiple(&columns[1..], &descending)?;
+            let row_fmt = argsort_multiple_row_fmt(by, descending.clone(), false, false)?;
+            assert_eq!(Vec::from(&row_fmt), Vec::from(&comparator));
+        }
+        Ok(())
+    }
+}
@@ -1,27 +1,66 @@
 use super::*;
-use crate::utils::NoNull;
-
-/// Sort with null values, to reverse, swap the arguments.
-fn sort_with_nulls<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Ordering {
-    match (a, b) {
-        (Some(a), Some(b)) => a.partial_cmp(b).unwrap(),
-        (None, Some(_)) => Ordering::Less,
-        (Some(_), None) => Ordering::Greater,
-        (None, None) => Ordering::Equal,
+
+impl CategoricalChunked {
+    /// The categories of the rev-map in lexical order and the rank of every category of the
+    /// rev-map in that order.
+    fn sort_categories(&self) -> (Utf8Chunked, Vec<u32>) {
+        let values = match &**self.get_rev_map() {
+            RevMapping::Local(arr) => arr,
+            RevMapping::Global(_, arr, _) => arr,
+        };
+        // we don't use arrow2 sort here because its not activated
+        // that saves compilation
+        let values = unsafe { Utf8Chunked::from_chunks("", vec![Box::from(values.clone())]) };
+        let order = values.arg_sort(SortOptions::default());
+        let mut local_ranks = vec![0u32; values.len()];
+        for (rank, idx) in order.into_no_null_iter().enumerate() {
+            local_ranks[idx as usize] = rank as u32;
+        }
+        // safety:
+        // the indexes of an arg_sort are in bounds
+        let sorted = unsafe { values.take_unchecked((&order).into()) };
+        (sorted, local_ranks)
     }
-}
 
-/// Default sorting nulls
-pub fn order_ascending_null<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Ordering {
-    sort_with_nulls(a, b)
-}
+    /// Map every value to the rank of its category, given the `local_ranks` of the categories
+    /// of the rev-map.
+    fn map_to_ranks(&self, local_ranks: &[u32]) -> UInt32Chunked {
+        let mut ranks = match &**self.get_rev_map() {
+            // null slots may hold any value, so only the valid categories are mapped
+            RevMapping::Local(_) => self
+                .logical()
+                .apply_on_opt(|cat| cat.map(|cat| local_ranks[cat as usize])),
+            RevMapping::Global(map, _, _) => {
+                let global_ranks = map
+                    .iter()
+                    .map(|(global, local)| (*global, local_ranks[*local as usize]))
+                    .collect::<PlHashMap<_, _>>();
+                self.logical()
+                    .apply_on_opt(|cat| cat.map(|cat| *global_ranks.get(&cat).unwrap()))
+            }
+        };
+        ranks.rename(self.name());
+        ranks
+    }
 
-/// Default sorting nulls
-pub fn order_descending_null<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Ordering {
-    sort_with_nulls(b, a)
-}
+    /// The rank of every value in the lexical order of the categories.
+    ///
+    /// The rev-map is sorted once and the categories are mapped through that permutation,
+    /// so no strings are materialized per row.
+    pub(crate) fn lexical_ranks(&self) -> UInt32Chunked {
+        let (_, local_ranks) = self.sort_categories();
+        self.map_to_ranks(&local_ranks)
+    }
+
+    /// The keys and values of a dictionary whose values are sorted lexically, so that the
+    /// order of the dictionary is the lexical order of the categories.
+    pub(crate) fn to_lexical_dictionary(&self) -> (UInt32Chunked, Utf8Array<i64>) {
+        let (sorted, local_ranks) = self.sort_categories();
+        let keys = self.map_to_ranks(&local_ranks);
+        let values = sorted.rechunk().downcast_iter().next().unwrap().clone();
+        (keys, values)
+    }
 
-impl CategoricalChunked {
     #[must_use]
     pub fn sort_with(&self, options: SortOptions) -> CategoricalChunked {
         assert!(
@@ -30,55 +69,20 @@ impl CategoricalChunked {
         );
 
         if self.use_lexical_sort() {
-            match &**self.get_rev_map() {
-                RevMapping::Local(arr) => {
-                    // we don't use arrow2 sort here because its not activated
-                    // that saves compilation
-                    let ca = unsafe { Utf8Chunked::from_chunks("", vec![Box::from(arr.clone())]) };
-                    let sorted = ca.sort(options.descending);
-                    let arr = sorted.downcast_iter().next().unwrap().clone();
-                    let rev_map = RevMapping::Local(arr);
-                    // safety:
-                    // we only reordered the indexes so we are still in bounds
-                    unsafe {
-                        CategoricalChunked::from_cats_and_rev_map_unchecked(
-                            self.logical().clone(),
-                            Arc::new(rev_map),
-                        )
-                    }
-                }
-                RevMapping::Global(_, _, _) => {
-                    // a global rev map must always point to the same string values
-                    // so we cannot sort the categories.
-
-                    let mut vals = self
-                        .logical()
-                        .into_no_null_iter()
-                        .zip(self.iter_str())
-                        .collect_trusted::<Vec<_>>();
-
-                    arg_sort_branch(
-                        vals.as_mut_slice(),
-                        options.descending,
-                        |(_, a), (_, b)| order_ascending_null(a, b),
-                        |(_, a), (_, b)| order_descending_null(a, b),
-                        options.multithreaded,
-                    );
-                    let cats: NoNull<UInt32Chunked> =
-                        vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
-                    let mut cats = cats.into_inner();
-                    cats.rename(self.name());
-
-                    // safety:
-                    // we only reordered the indexes so we are still in bounds
-                    unsafe {
-                        CategoricalChunked::from_cats_and_rev_map_unchecked(
-                            cats,
-                            self.get_rev_map().clone(),
-                        )
-                    }
-                }
-            }
+            let idx = self.lexical_ranks().arg_sort(options);
+            // safety:
+            // the indexes of an arg_sort are in bounds
+            let cats = unsafe { self.logical().take_unchecked((&idx).into()) };
+            // safety:
+            // we only reordered the indexes so we are still in bounds
+            let mut out = unsafe {
+                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    cats,
+                    self.get_rev_map().clone(),
+                )
+            };
+            out.set_lexical_sorted(true);
+            out
         } else {
             let cats = self.logical().sort_with(options);
             // safety:
@@ -105,14 +109,7 @@ impl CategoricalChunked {
     /// Retrieve the indexes needed to sort this array.
     pub fn arg_sort(&self, options: SortOptions) -> IdxCa {
         if self.use_lexical_sort() {
-            let iters = [self.iter_str()];
-            arg_sort::arg_sort(
-                self.name(),
-                iters,
-                options,
-                self.logical().null_count(),
-                self.len(),
-            )
+            self.lexical_ranks().arg_sort(options)
         } else {
             self.logical().arg_sort(options)
         }
@@ -126,18 +123,7 @@ impl CategoricalChunked {
         descending: &[bool],
     ) -> PolarsResult<IdxCa> {
         if self.use_lexical_sort() {
-            args_validate(self.logical(), other, descending)?;
-            let mut count: IdxSize = 0;
-            let vals: Vec<_> = self
-                .iter_str()
-                .map(|v| {
-                    let i = count;
-                    count += 1;
-                    (i, v)
-                })
-                .collect_trusted();
-
-            arg_sort_multiple_impl(vals, other, descending)
+            self.lexical_ranks().arg_sort_multiple(other, descending)
         } else {
             self.logical().arg_sort_multiple(other, descending)
         }
@@ -147,6 +133,7 @@ impl CategoricalChunked {
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use crate::series::IsSorted;
     use crate::{enable_string_cache, reset_string_cache, SINGLE_LOCK};
 
     fn assert_order(ca: &CategoricalChunked, cmp: &[&str]) {
@@ -163,7 +150,7 @@ mod test {
         for toggle in [true, false] {
             reset_string_cache();
             enable_string_cache(toggle);
-            let s = Series::new("", init).cast(&DataType::Categorical(None))?;
+            let s = Series::new("", init).cast(&DataType::Categorical(None, Default::default()))?;
             let ca = s.categorical()?;
             let mut ca_lexical = ca.clone();
             ca_lexical.set_lexical_sorted(true);
@@ -191,7 +178,7 @@ mod test {
         let _lock = SINGLE_LOCK.lock();
         for enable in [true, false] {
             enable_string_cache(enable);
-            let s = Series::new("", init).cast(&DataType::Categorical(None))?;
+            let s = Series::new("", init).cast(&DataType::Categorical(None, Default::default()))?;
             let ca = s.categorical()?;
             let mut ca_lexical: CategoricalChunked = ca.clone();
             ca_lexical.set_lexical_sorted(true);
@@ -215,4 +202,138 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_cat_lexical_ordering() -> PolarsResult<()> {
+        let init = &[Some("c"), None, Some("a"), Some("c"), Some("b"), Some("a")];
+
+        let _lock = SINGLE_LOCK.lock();
+        for toggle in [true, false] {
+            reset_string_cache();
+            enable_string_cache(toggle);
+            let s = Series::new("", init).cast(&DataType::Categorical(None, Default::default()))?;
+            let mut ca = s.categorical()?.clone();
+            assert_eq!(ca.get_ordering(), CategoricalOrdering::Physical);
+            ca.set_ordering(CategoricalOrdering::Lexical);
+
+            let out = ca.sort(false);
+            assert_eq!(out.get_ordering(), CategoricalOrdering::Lexical);
+            let out = out.cast(&DataType::Utf8)?;
+            assert_eq!(
+                Vec::from(out.utf8()?),
+                &[None, Some("a"), Some("a"), Some("b"), Some("c"), Some("c")]
+            );
+            let out = ca.arg_sort(SortOptions {
+                descending: true,
+                ..Default::default()
+            });
+            let s = ca.into_series();
+            let out = s.take(&out)?.cast(&DataType::Utf8)?;
+            assert_eq!(
+                Vec::from(out.utf8()?),
+                &[Some("c"), Some("c"), Some("b"), Some("a"), Some("a"), None]
+            );
+
+            let min = s.min_as_series().cast(&DataType::Utf8)?;
+            assert_eq!(min.utf8()?.get(0), Some("a"));
+            let max = s.max_as_series().cast(&DataType::Utf8)?;
+            assert_eq!(max.utf8()?.get(0), Some("c"));
+            // the sorted flag of the physical categories doesn't describe the lexical order
+            assert_eq!(s.is_sorted_flag(), IsSorted::Not);
+
+            let mask = s.lt("b")?;
+            assert_eq!(
+                Vec::from(&mask),
+                &[
+                    Some(false),
+                    None,
+                    Some(true),
+                    Some(false),
+                    Some(false),
+                    Some(true)
+                ]
+            );
+            let mask = s.gt_eq(&s.head(Some(1)))?;
+            assert_eq!(
+                Vec::from(&mask),
+                &[
+                    Some(true),
+                    None,
+                    Some(false),
+                    Some(true),
+                    Some(false),
+                    Some(false)
+                ]
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cat_lexical_cast_and_arrow() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        enable_string_cache(false);
+
+        let lexical = DataType::Categorical(None, CategoricalOrdering::Lexical);
+        let s = Series::new("", &[Some("c"), None, Some("a"), Some("b")]).cast(&lexical)?;
+        let ca = s.categorical()?;
+        assert_eq!(ca.get_ordering(), CategoricalOrdering::Lexical);
+        let out = ca.sort(false).cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[None, Some("a"), Some("b"), Some("c")]
+        );
+        let physical = s.cast(&DataType::Categorical(None, CategoricalOrdering::Physical))?;
+        assert_eq!(
+            physical.categorical()?.get_ordering(),
+            CategoricalOrdering::Physical
+        );
+
+        // a lexical categorical is exported as an ordered dictionary with sorted values
+        let arr = s.to_arrow(0);
+        let dict = arr.as_any().downcast_ref::<DictionaryArray<u32>>().unwrap();
+        assert!(matches!(
+            dict.data_type(),
+            ArrowDataType::Dictionary(_, _, true)
+        ));
+        let values = dict
+            .values()
+            .as_any()
+            .downcast_ref::<Utf8Array<i64>>()
+            .unwrap();
+        assert_eq!(values.values_iter().collect::<Vec<_>>(), &["a", "b", "c"]);
+        let out = Series::try_from(("", arr))?;
+        assert_eq!(
+            out.categorical()?.get_ordering(),
+            CategoricalOrdering::Lexical
+        );
+        assert_eq!(
+            Vec::from(out.cast(&DataType::Utf8)?.utf8()?),
+            &[Some("c"), None, Some("a"), Some("b")]
+        );
+
+        // a physical categorical is exported as an unordered dictionary
+        let arr = physical.to_arrow(0);
+        assert!(matches!(
+            arr.data_type(),
+            ArrowDataType::Dictionary(_, _, false)
+        ));
+
+        // an ordered dictionary with unsorted values is ordered by its values
+        let keys = PrimitiveArray::from_vec(vec![0u32, 1, 2]);
+        let values = Utf8Array::<i64>::from_slice(["c", "a", "b"]);
+        let dtype = ArrowDataType::Dictionary(
+            arrow::datatypes::IntegerType::UInt32,
+            Box::new(ArrowDataType::LargeUtf8),
+            true,
+        );
+        let arr = DictionaryArray::try_new(dtype, keys, Box::new(values)).unwrap();
+        let out = Series::try_from(("", Box::new(arr) as ArrayRef))?;
+        let ca = out.categorical()?;
+        assert_eq!(ca.get_ordering(), CategoricalOrdering::Physical);
+        let out = ca.sort(false).cast(&DataType::Utf8)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("c"), Some("a"), Some("b")]);
+        Ok(())
+    }
 }
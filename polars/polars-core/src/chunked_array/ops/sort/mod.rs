@@ -385,6 +385,28 @@ impl ChunkSort<Float64Type> for Float64Chunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl ChunkSort<Int128Type> for Int128Chunked {
+    fn sort_with(&self, options: SortOptions) -> Int128Chunked {
+        sort_with_numeric(self, options, order_ascending, order_descending)
+    }
+
+    fn sort(&self, descending: bool) -> Int128Chunked {
+        self.sort_with(SortOptions {
+            descending,
+            ..Default::default()
+        })
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        arg_sort_numeric(self, options)
+    }
+
+    fn arg_sort_multiple(&self, other: &[Series], descending: &[bool]) -> PolarsResult<IdxCa> {
+        arg_sort_multiple_numeric(self, other, descending)
+    }
+}
+
 fn ordering_other_columns<'a>(
     compare_inner: &'a [Box<dyn PartialOrdInner + 'a>],
     descending: &[bool],
@@ -729,14 +751,36 @@ impl ChunkSort<BooleanType> for BooleanChunked {
     }
 }
 
+/// The row encoding orders floats by their bits, which would put `-0.0` before `0.0` and a
+/// NaN with the sign bit set before `-inf`. Map those to the value the comparator sorts them as.
+fn canonicalize_floats<T>(ca: &ChunkedArray<T>) -> Series
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+    ChunkedArray<T>: IntoSeries,
+{
+    ca.apply(|v| {
+        if v.is_nan() {
+            T::Native::nan()
+        } else if v == T::Native::zero() {
+            T::Native::zero()
+        } else {
+            v
+        }
+    })
+    .into_series()
+}
+
 pub(crate) fn convert_sort_column_multi_sort(
     s: &Series,
     row_ordering: bool,
 ) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
+        Float32 if row_ordering => canonicalize_floats(s.f32().unwrap()),
+        Float64 if row_ordering => canonicalize_floats(s.f64().unwrap()),
         #[cfg(feature = "dtype-categorical")]
-        Categorical(_) => s.rechunk(),
+        Categorical(_, _) => s.rechunk(),
         Binary => s.clone(),
         Utf8 => s.cast(&Binary).unwrap(),
         Boolean => {
@@ -776,24 +820,6 @@ pub fn _broadcast_descending(n_cols: usize, descending: &mut Vec<bool>) {
     }
 }
 
-pub(crate) fn prepare_arg_sort(
-    columns: Vec<Series>,
-    mut descending: Vec<bool>,
-) -> PolarsResult<(Series, Vec<Series>, Vec<bool>)> {
-    let n_cols = columns.len();
-
-    let mut columns = columns
-        .iter()
-        .map(|s| convert_sort_column_multi_sort(s, false))
-        .collect::<PolarsResult<Vec<_>>>()?;
-
-    let first = columns.remove(0);
-
-    // broadcast ordering
-    _broadcast_descending(n_cols, &mut descending);
-    Ok((first, columns, descending))
-}
-
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -10,6 +10,7 @@ pub use take_random::*;
 pub use traits::*;
 
 use crate::chunked_array::kernels::take::*;
+use crate::chunked_array::kernels::take_par::*;
 use crate::prelude::*;
 use crate::utils::NoNull;
 
@@ -85,6 +86,10 @@ where
                     return Self::full_null(self.name(), array.len());
                 }
                 let array = match (self.null_count(), self.chunks.len()) {
+                    (_, 1) if take_in_parallel(array) => {
+                        take_primitive_par_unchecked::<T::Native>(chunks.next().unwrap(), array)
+                            as ArrayRef
+                    }
                     (0, 1) => {
                         take_no_null_primitive_unchecked::<T::Native>(chunks.next().unwrap(), array)
                             as ArrayRef
@@ -288,6 +293,9 @@ impl ChunkTake for BinaryChunked {
                     return Self::full_null(self.name(), array.len());
                 }
                 let array = match self.chunks.len() {
+                    1 if take_in_parallel(array) => {
+                        take_binary_par_unchecked(chunks.next().unwrap(), array) as ArrayRef
+                    }
                     1 => take_binary_unchecked(chunks.next().unwrap(), array) as ArrayRef,
                     _ => {
                         return if !array.has_validity() {
@@ -544,4 +552,40 @@ mod test {
         assert_eq!(ca.get(1), Some("b"));
         assert_eq!(ca.get(2), Some("c"));
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_take_parallel() -> PolarsResult<()> {
+        use crate::chunked_array::kernels::take_par::PARALLEL_TAKE_THRESHOLD;
+
+        let n = PARALLEL_TAKE_THRESHOLD + 100;
+        let ints = (0..n)
+            .map(|i| (i % 7 != 0).then_some(i as i32))
+            .collect::<Int32Chunked>();
+        let strs = (0..n)
+            .map(|i| (i % 5 != 0).then(|| "x".repeat(i % 4)))
+            .collect::<Utf8Chunked>();
+        // reversed, with null indices
+        let idx = (0..n)
+            .rev()
+            .map(|i| (i % 11 != 0).then_some(i as IdxSize))
+            .collect::<IdxCa>();
+
+        let out = ints.clone().into_series().take(&idx)?;
+        let expected = idx
+            .into_iter()
+            .map(|i| i.and_then(|i| ints.get(i as usize)));
+        assert!(out.i32()?.into_iter().eq(expected));
+
+        let out = strs.clone().into_series().take(&idx)?;
+        let expected = idx
+            .into_iter()
+            .map(|i| i.and_then(|i| strs.get(i as usize)));
+        assert!(out.utf8()?.into_iter().eq(expected));
+
+        // out of bounds indices still error
+        let idx = IdxCa::from_vec("", (1..=n as IdxSize).rev().collect());
+        assert!(ints.into_series().take(&idx).is_err());
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use arrow::compute::if_then_else::if_then_else;
 use polars_arrow::array::default_arrays::FromData;
 
@@ -173,7 +175,10 @@ impl ChunkZip<ListType> for ListChunked {
         mask: &BooleanChunked,
         other: &ChunkedArray<ListType>,
     ) -> PolarsResult<ChunkedArray<ListType>> {
-        let (left, right, mask) = align_chunks_ternary(self, other, mask);
+        let left = broadcast_list(self, mask.len())?;
+        let right = broadcast_list(other, mask.len())?;
+        let (left, right, mask) = align_chunks_ternary(left.as_ref(), right.as_ref(), mask);
+        // the kernel picks the sublists of either side and rebuilds the offsets and values
         let chunks = left
             .downcast_iter()
             .zip(right.downcast_iter())
@@ -184,7 +189,19 @@ impl ChunkZip<ListType> for ListChunked {
                 Ok(arr)
             })
             .collect::<PolarsResult<Vec<_>>>()?;
-        unsafe { Ok(ChunkedArray::from_chunks(self.name(), chunks)) }
+        // keep the logical inner dtype of `self`
+        unsafe { Ok(self.with_chunks(chunks)) }
+    }
+}
+
+/// Broadcast a unit length list branch, e.g. a literal, to the length of the mask.
+fn broadcast_list(ca: &ListChunked, len: usize) -> PolarsResult<Cow<ListChunked>> {
+    match ca.len() {
+        n if n == len => Ok(Cow::Borrowed(ca)),
+        1 => Ok(Cow::Owned(ca.new_from_index(0, len))),
+        _ => Err(polars_err!(
+            ShapeMismatch: "shapes of `mask` and `other` are not suitable for `zip_with` operation"
+        )),
     }
 }
 
@@ -211,3 +228,143 @@ impl<T: PolarsObject> ChunkZip<ObjectType<T>> for ObjectChunked<T> {
         Ok(ca)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list_values(s: &Series) -> Vec<Option<Vec<Option<f64>>>> {
+        s.list()
+            .unwrap()
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| Vec::from(s.f64().unwrap())))
+            .collect()
+    }
+
+    fn lists(name_a: &str, name_b: &str) -> (Series, Series) {
+        let a = Series::new(
+            name_a,
+            [
+                Series::new("", [1i32, 2]),
+                Series::new("", [3i32]),
+                Series::new("", [4i32]),
+            ],
+        );
+        let b = Series::new(
+            name_b,
+            [
+                Series::new("", [1.5f64]),
+                Series::new("", [2.5f64, 3.5]),
+                Series::new("", [4.5f64]),
+            ],
+        );
+        (a, b)
+    }
+
+    #[test]
+    fn test_zip_with_list() -> PolarsResult<()> {
+        let mask = BooleanChunked::new("", &[true, false, true]);
+        let (a, b) = lists("a", "b");
+
+        // the inner dtypes are coerced to their supertype
+        let out = a.zip_with(&mask, &b)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Float64)));
+        assert_eq!(
+            list_values(&out),
+            &[
+                Some(vec![Some(1.0), Some(2.0)]),
+                Some(vec![Some(2.5), Some(3.5)]),
+                Some(vec![Some(4.0)])
+            ]
+        );
+
+        // a null literal
+        let out = b.zip_with(&mask, &Series::new_null("lit", 1))?;
+        assert_eq!(
+            list_values(&out),
+            &[Some(vec![Some(1.5)]), None, Some(vec![Some(4.5)])]
+        );
+
+        // a unit length branch
+        let lit = Series::new("lit", [Series::new("", [0.0f64])]);
+        let out = lit.zip_with(&mask, &b)?;
+        assert_eq!(
+            list_values(&out),
+            &[
+                Some(vec![Some(0.0)]),
+                Some(vec![Some(2.5), Some(3.5)]),
+                Some(vec![Some(0.0)])
+            ]
+        );
+
+        let short = b.slice(0, 2);
+        assert!(a.zip_with(&mask, &short).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_zip_with_struct() -> PolarsResult<()> {
+        let mask = BooleanChunked::new("", &[true, false, true]);
+        let (a, b) = lists("y", "y");
+        let s1 = StructChunked::new("s", &[Series::new("x", [1i32, 2, 3]), a])?.into_series();
+        let s2 = StructChunked::new("s", &[Series::new("x", [1.5f64, 2.5, 3.5]), b])?.into_series();
+
+        // the fields are coerced to their supertype, including the inner dtype of a list
+        let out = s1.zip_with(&mask, &s2)?;
+        let expected_dtype = DataType::Struct(vec![
+            Field::new("x", DataType::Float64),
+            Field::new("y", DataType::List(Box::new(DataType::Float64))),
+        ]);
+        assert_eq!(out.dtype(), &expected_dtype);
+        let fields = out.struct_()?.fields();
+        assert_eq!(
+            Vec::from(fields[0].f64()?),
+            &[Some(1.0), Some(2.5), Some(3.0)]
+        );
+        assert_eq!(
+            list_values(&fields[1]),
+            &[
+                Some(vec![Some(1.0), Some(2.0)]),
+                Some(vec![Some(2.5), Some(3.5)]),
+                Some(vec![Some(4.0)])
+            ]
+        );
+
+        // a null literal
+        let out = s2.zip_with(&mask, &Series::new_null("lit", 1))?;
+        let fields = out.struct_()?.fields();
+        assert_eq!(Vec::from(fields[0].f64()?), &[Some(1.5), None, Some(3.5)]);
+        assert_eq!(
+            list_values(&fields[1]),
+            &[Some(vec![Some(1.5)]), None, Some(vec![Some(4.5)])]
+        );
+
+        // a unit length branch
+        let lit = StructChunked::new(
+            "lit",
+            &[
+                Series::new("x", [0i32]),
+                Series::new("y", [Series::new("", [0i32])]),
+            ],
+        )?
+        .into_series();
+        let out = s2.zip_with(&mask, &lit)?;
+        assert_eq!(out.dtype(), s2.dtype());
+        let fields = out.struct_()?.fields();
+        assert_eq!(
+            Vec::from(fields[0].f64()?),
+            &[Some(1.5), Some(0.0), Some(3.5)]
+        );
+        assert_eq!(
+            list_values(&fields[1]),
+            &[
+                Some(vec![Some(1.5)]),
+                Some(vec![Some(0.0)]),
+                Some(vec![Some(4.5)])
+            ]
+        );
+        Ok(())
+    }
+}
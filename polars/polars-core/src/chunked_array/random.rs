@@ -1,12 +1,24 @@
+use std::borrow::Cow;
+
 use num_traits::{Float, NumCast};
 use polars_error::to_compute_err;
 use rand::distributions::Bernoulli;
 use rand::prelude::*;
-use rand_distr::{Distribution, Normal, Standard, StandardNormal, Uniform};
+use rand_distr::{Distribution, Normal, Standard, StandardNormal, Uniform, WeightedAliasIndex};
 
+use crate::frame::groupby::GroupsIndicator;
 use crate::prelude::*;
 use crate::utils::{CustomIterTools, NoNull};
 
+/// The number of rows to sample from every group in [`DataFrame::sample_stratified`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SampleSize {
+    /// Sample `n` rows.
+    N(usize),
+    /// Sample a fraction of the rows.
+    Frac(f64),
+}
+
 fn get_random_seed() -> u64 {
     let mut rng = SmallRng::from_entropy();
 
@@ -37,6 +49,71 @@ fn create_rand_index_no_replacement(
     IdxCa::new_vec("", buf)
 }
 
+/// The weights as `f64` values in row order, so that the sample doesn't depend on the chunks.
+fn prepare_weights(weights: &Series, len: usize) -> PolarsResult<Vec<f64>> {
+    polars_ensure!(
+        weights.len() == len,
+        ShapeMismatch: "sample weights have length {}, expected {}", weights.len(), len
+    );
+    polars_ensure!(
+        weights.null_count() == 0,
+        ComputeError: "sample weights should not contain null values"
+    );
+    let weights = weights.cast(&DataType::Float64)?;
+    let weights = weights
+        .f64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        weights.iter().all(|w| w.is_finite() && *w > 0.0),
+        ComputeError: "sample weights should be positive and finite"
+    );
+    Ok(weights)
+}
+
+/// Weighted sampling with an alias table, which draws every index in constant time.
+fn create_weighted_index_with_replacement(
+    n: usize,
+    weights: Vec<f64>,
+    seed: Option<u64>,
+) -> PolarsResult<IdxCa> {
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
+    let dist = WeightedAliasIndex::new(weights).map_err(to_compute_err)?;
+    Ok((0..n)
+        .map(|_| dist.sample(&mut rng) as IdxSize)
+        .collect_trusted::<NoNull<IdxCa>>()
+        .into_inner())
+}
+
+/// Weighted sampling with the method of Efraimidis and Spirakis: every index gets the key
+/// `u^(1/w)` for a uniform `u`, and the sample consists of the `n` largest keys.
+fn create_weighted_index_no_replacement(
+    n: usize,
+    weights: &[f64],
+    seed: Option<u64>,
+    shuffle: bool,
+) -> IdxCa {
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
+    // compare the logarithms of the keys, which don't underflow for small weights
+    let mut keys = weights
+        .iter()
+        .enumerate()
+        .map(|(i, w)| ((1.0 - rng.gen::<f64>()).ln() / w, i as IdxSize))
+        .collect::<Vec<_>>();
+    if n < keys.len() {
+        keys.select_nth_unstable_by(n, |a, b| b.0.total_cmp(&a.0));
+        keys.truncate(n);
+    }
+    let mut idx = keys.into_iter().map(|(_, i)| i).collect::<Vec<_>>();
+    if shuffle {
+        idx.shuffle(&mut rng)
+    } else {
+        idx.sort_unstable()
+    }
+    IdxCa::from_vec("", idx)
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -193,6 +270,97 @@ impl DataFrame {
         let n = (self.height() as f64 * frac) as usize;
         self.sample_n(n, with_replacement, shuffle, seed)
     }
+
+    /// Sample n datapoints from this DataFrame, where the probability to sample a row is
+    /// proportional to its weight in `weights`.
+    ///
+    /// The weights must be positive. Unless `shuffle` is set, the sampled rows are in the order
+    /// of this DataFrame.
+    pub fn sample_n_weighted(
+        &self,
+        n: usize,
+        weights: &Series,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        ensure_shape(n, self.height(), with_replacement)?;
+        let weights = prepare_weights(weights, self.height())?;
+        if n == 0 {
+            return Ok(self.clear());
+        }
+        let idx = match with_replacement {
+            true => create_weighted_index_with_replacement(n, weights, seed)?,
+            false => create_weighted_index_no_replacement(n, &weights, seed, shuffle),
+        };
+        // Safety:
+        // indices are within bounds
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
+    /// Sample a fraction between 0.0-1.0 of this DataFrame, where the probability to sample a
+    /// row is proportional to its weight in `weights`.
+    pub fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        weights: &Series,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        let n = (self.height() as f64 * frac) as usize;
+        self.sample_n_weighted(n, weights, with_replacement, shuffle, seed)
+    }
+
+    /// Sample every group of the `by` columns separately, without replacement.
+    ///
+    /// With [`SampleSize::Frac`] every group is sampled with the same fraction, which keeps the
+    /// proportions of the groups. A group with fewer rows than the sample size is an error,
+    /// unless `keep_small_groups` is set, in which case the whole group is sampled. The sampled
+    /// rows are in the order of this DataFrame.
+    pub fn sample_stratified(
+        &self,
+        by: &[&str],
+        size: SampleSize,
+        keep_small_groups: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        // the groups are in the order of their first row, which doesn't depend on the chunks
+        let groups = self.groupby_stable(by)?.take_groups();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
+        let mut idx = vec![];
+        let mut buf = vec![];
+        for group in groups.iter() {
+            let members: Cow<[IdxSize]> = match group {
+                GroupsIndicator::Idx((_, members)) => Cow::Borrowed(members),
+                GroupsIndicator::Slice([first, len]) => Cow::Owned((first..first + len).collect()),
+            };
+            let len = members.len();
+            let n = match size {
+                SampleSize::N(n) => n,
+                SampleSize::Frac(frac) => (len as f64 * frac) as usize,
+            };
+            if n > len {
+                polars_ensure!(
+                    keep_small_groups,
+                    ShapeMismatch: "cannot take a sample of {} rows from a group of {} rows", n, len
+                );
+                idx.extend_from_slice(&members);
+            } else {
+                buf.clear();
+                buf.resize(n, 0);
+                members
+                    .iter()
+                    .copied()
+                    .choose_multiple_fill(&mut rng, &mut buf);
+                idx.extend_from_slice(&buf);
+            }
+        }
+        idx.sort_unstable();
+        // Safety:
+        // indices are within bounds
+        Ok(unsafe { self.take_unchecked(&IdxCa::from_vec("", idx)) })
+    }
 }
 
 impl<T> ChunkedArray<T>
@@ -277,4 +445,68 @@ mod test {
         // with replacement can sample more than 100%
         assert!(df.sample_frac(2.0, true, false, Some(0)).is_ok());
     }
+
+    #[test]
+    fn test_sample_weighted() -> PolarsResult<()> {
+        let df = df![
+            "foo" => (0..100).collect::<Vec<i32>>()
+        ]?;
+        let mut weights = Series::new("w", vec![1e-9; 50]);
+        weights.append(&Series::new("w", vec![1.0; 50]))?;
+
+        let out = df.sample_n_weighted(20, &weights, false, false, Some(0))?;
+        let foo = out.column("foo")?.i32()?;
+        assert_eq!(foo.n_unique()?, 20);
+        // negligible weights are practically never sampled
+        assert!(foo.into_no_null_iter().all(|v| v >= 50));
+        // the sample doesn't depend on the chunks
+        let rechunked = df.sample_n_weighted(20, &weights.rechunk(), false, false, Some(0))?;
+        assert!(out.frame_equal(&rechunked));
+
+        let out = df.sample_n_weighted(200, &weights, true, false, Some(0))?;
+        assert_eq!(out.height(), 200);
+        assert!(out
+            .column("foo")?
+            .i32()?
+            .into_no_null_iter()
+            .all(|v| v >= 50));
+
+        let zero = Series::new("w", vec![0.0; 100]);
+        assert!(df
+            .sample_n_weighted(1, &zero, true, false, Some(0))
+            .is_err());
+        assert!(df
+            .sample_n_weighted(1, &weights.slice(0, 10), false, false, Some(0))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_stratified() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "a", "b", "a", "c", "a"],
+            "v" => [0, 1, 2, 3, 4, 5, 6, 7]
+        ]?;
+
+        let out = df.sample_stratified(&["g"], SampleSize::Frac(0.5), false, Some(0))?;
+        let g = out.column("g")?.utf8()?;
+        // "a" has 5 rows, "b" 2 and "c" 1
+        assert_eq!(g.into_no_null_iter().filter(|g| *g == "a").count(), 2);
+        assert_eq!(g.into_no_null_iter().filter(|g| *g == "b").count(), 1);
+        assert_eq!(out.height(), 3);
+        // the rows keep their order
+        let v = out
+            .column("v")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert!(v.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(df
+            .sample_stratified(&["g"], SampleSize::N(2), false, Some(0))
+            .is_err());
+        let out = df.sample_stratified(&["g"], SampleSize::N(2), true, Some(0))?;
+        assert_eq!(out.height(), 5);
+        Ok(())
+    }
 }
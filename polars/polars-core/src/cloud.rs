@@ -63,6 +63,20 @@ where
         .collect::<PolarsResult<Configs<T>>>()
 }
 
+#[allow(dead_code)]
+/// Collect the configuration from the environment variables starting with `prefix`, e.g.
+/// `AWS_ACCESS_KEY_ID`. Variables that are not a known configuration key are ignored.
+fn env_configs<T: FromStr>(prefix: &str) -> Configs<T> {
+    std::env::vars()
+        .filter(|(key, _)| key.starts_with(prefix))
+        .filter_map(|(key, val)| {
+            T::from_str(&key.to_ascii_lowercase())
+                .ok()
+                .map(|typed_key| (typed_key, val))
+        })
+        .collect()
+}
+
 pub enum CloudType {
     Aws,
     Azure,
@@ -79,7 +93,7 @@ impl FromStr for CloudType {
         Ok(match parsed.scheme() {
             "s3" => Self::Aws,
             "az" | "adl" | "abfs" => Self::Azure,
-            "gs" | "gcp" => Self::Gcp,
+            "gs" | "gcs" | "gcp" => Self::Gcp,
             "file" => Self::File,
             _ => polars_bail!(ComputeError: "unknown url scheme"),
         })
@@ -108,14 +122,16 @@ impl CloudOptions {
     }
 
     /// Build the ObjectStore implementation for AWS.
+    /// The configuration is read from the `AWS_*` environment variables, the options that are
+    /// set explicitly take precedence.
     #[cfg(feature = "aws")]
     pub fn build_aws(&self, bucket_name: &str) -> PolarsResult<impl ObjectStore> {
-        let options = self
-            .aws
-            .as_ref()
-            .ok_or_else(|| polars_err!(ComputeError: "`aws` configuration missing"))?;
+        let mut options = env_configs::<AmazonS3ConfigKey>("AWS_");
+        if let Some(aws) = &self.aws {
+            options.extend(aws.iter().cloned());
+        }
         AmazonS3Builder::new()
-            .try_with_options(options.clone().into_iter())
+            .try_with_options(options)
             .and_then(|b| b.with_bucket_name(bucket_name).build())
             .map_err(polars_error::to_compute_err)
     }
@@ -136,14 +152,16 @@ impl CloudOptions {
     }
 
     /// Build the ObjectStore implementation for Azure.
+    /// The configuration is read from the `AZURE_*` environment variables, the options that are
+    /// set explicitly take precedence.
     #[cfg(feature = "azure")]
     pub fn build_azure(&self, container_name: &str) -> PolarsResult<impl ObjectStore> {
-        let options = self
-            .azure
-            .as_ref()
-            .ok_or_else(|| polars_err!(ComputeError: "`azure` configuration missing"))?;
+        let mut options = env_configs::<AzureConfigKey>("AZURE_");
+        if let Some(azure) = &self.azure {
+            options.extend(azure.iter().cloned());
+        }
         MicrosoftAzureBuilder::new()
-            .try_with_options(options.clone().into_iter())
+            .try_with_options(options)
             .and_then(|b| b.with_container_name(container_name).build())
             .map_err(polars_error::to_compute_err)
     }
@@ -164,14 +182,16 @@ impl CloudOptions {
     }
 
     /// Build the ObjectStore implementation for GCP.
+    /// The configuration is read from the `GOOGLE_*` environment variables, the options that are
+    /// set explicitly take precedence.
     #[cfg(feature = "gcp")]
     pub fn build_gcp(&self, bucket_name: &str) -> PolarsResult<impl ObjectStore> {
-        let options = self
-            .gcp
-            .as_ref()
-            .ok_or_else(|| polars_err!(ComputeError: "`gcp` configuration missing"))?;
+        let mut options = env_configs::<GoogleConfigKey>("GOOGLE_");
+        if let Some(gcp) = &self.gcp {
+            options.extend(gcp.iter().cloned());
+        }
         GoogleCloudStorageBuilder::new()
-            .try_with_options(options.clone().into_iter())
+            .try_with_options(options)
             .and_then(|b| b.with_bucket_name(bucket_name).build())
             .map_err(polars_error::to_compute_err)
     }
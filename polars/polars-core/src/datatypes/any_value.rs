@@ -361,7 +361,7 @@ impl<'a> AnyValue<'a> {
             Boolean(_) => DataType::Boolean,
             Utf8(_) => DataType::Utf8,
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_, _, _) => DataType::Categorical(None),
+            Categorical(_, _, _) => DataType::Categorical(None, Default::default()),
             List(s) => DataType::List(Box::new(s.dtype().clone())),
             #[cfg(feature = "dtype-struct")]
             Struct(_, _, fields) => DataType::Struct(fields.to_vec()),
@@ -763,6 +763,8 @@ impl PartialEq for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l, scale_l), Decimal(r, scale_r)) => l == r && scale_l == scale_r,
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => {
                 let l = &*l.0;
@@ -915,7 +917,7 @@ mod test {
             ),
             (
                 ArrowDataType::Dictionary(IntegerType::UInt32, ArrowDataType::Utf8.into(), false),
-                DataType::Categorical(None),
+                DataType::Categorical(None, Default::default()),
             ),
             (
                 ArrowDataType::Dictionary(
@@ -923,7 +925,7 @@ mod test {
                     ArrowDataType::LargeUtf8.into(),
                     false,
                 ),
-                DataType::Categorical(None),
+                DataType::Categorical(None, Default::default()),
             ),
             (
                 ArrowDataType::Dictionary(
@@ -931,7 +933,7 @@ mod test {
                     ArrowDataType::LargeUtf8.into(),
                     false,
                 ),
-                DataType::Categorical(None),
+                DataType::Categorical(None, Default::default()),
             ),
         ];
 
@@ -41,7 +41,8 @@ pub enum DataType {
     #[cfg(feature = "dtype-categorical")]
     // The RevMapping has the internal state.
     // This is ignored with casts, comparisons, hashing etc.
-    Categorical(Option<Arc<RevMapping>>),
+    // The ordering is ignored with comparisons and hashing.
+    Categorical(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
     // some logical types we cannot know statically, e.g. Datetime
@@ -64,9 +65,9 @@ impl PartialEq for DataType {
         use DataType::*;
         {
             match (self, other) {
-                // Don't include rev maps in comparisons
+                // Don't include rev maps and orderings in comparisons
                 #[cfg(feature = "dtype-categorical")]
-                (Categorical(_), Categorical(_)) => true,
+                (Categorical(_, _), Categorical(_, _)) => true,
                 (Datetime(tu_l, tz_l), Datetime(tu_r, tz_r)) => tu_l == tu_r && tz_l == tz_r,
                 (List(left_inner), List(right_inner)) => left_inner == right_inner,
                 #[cfg(feature = "dtype-duration")]
@@ -120,7 +121,7 @@ impl DataType {
             Duration(_) => Int64,
             Time => Int64,
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => UInt32,
+            Categorical(_, _) => UInt32,
             List(dt) => List(Box::new(dt.to_physical())),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
@@ -169,7 +170,7 @@ impl DataType {
             #[cfg(feature = "object")]
             DataType::Object(_) => false,
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => false,
+            DataType::Categorical(_, _) => false,
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => false,
             _ => true,
@@ -181,7 +182,12 @@ impl DataType {
     }
 
     pub fn is_integer(&self) -> bool {
-        self.is_numeric() && !matches!(self, DataType::Float32 | DataType::Float64)
+        match self {
+            DataType::Float32 | DataType::Float64 => false,
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => false,
+            dt => dt.is_numeric(),
+        }
     }
 
     pub fn is_signed(&self) -> bool {
@@ -237,10 +243,11 @@ impl DataType {
             #[cfg(feature = "object")]
             Object(_) => panic!("cannot convert object to arrow"),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => ArrowDataType::Dictionary(
+            // lexically ordered categoricals are exported with a sorted dictionary
+            Categorical(_, ordering) => ArrowDataType::Dictionary(
                 IntegerType::UInt32,
                 Box::new(ArrowDataType::LargeUtf8),
-                false,
+                *ordering == CategoricalOrdering::Lexical,
             ),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
@@ -311,7 +318,7 @@ impl Display for DataType {
             #[cfg(feature = "object")]
             DataType::Object(s) => s,
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => "cat",
+            DataType::Categorical(_, _) => "cat",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
             DataType::Unknown => unreachable!(),
@@ -325,9 +332,9 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
     use DataType::*;
     Ok(match (left, right) {
         #[cfg(feature = "dtype-categorical")]
-        (Categorical(Some(rev_map_l)), Categorical(Some(rev_map_r))) => {
-            let rev_map = merge_categorical_map(rev_map_l, rev_map_r)?;
-            Categorical(Some(rev_map))
+        (Categorical(Some(rev_map_l), ordering), Categorical(Some(rev_map_r), _)) => {
+            let rev_map = merge_categorical_map(rev_map_l, rev_map_r, "merging dtypes")?;
+            Categorical(Some(rev_map), *ordering)
         }
         (List(inner_l), List(inner_r)) => {
             let merged = merge_dtypes(inner_l, inner_r)?;
@@ -136,7 +136,7 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::Time64(_) | ArrowDataType::Time32(_) => DataType::Time,
             #[cfg(feature = "dtype-categorical")]
-            ArrowDataType::Dictionary(_, _, _) => DataType::Categorical(None),
+            ArrowDataType::Dictionary(_, _, _) => DataType::Categorical(None, Default::default()),
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(fields) => {
                 DataType::Struct(fields.iter().map(|fld| fld.into()).collect())
@@ -152,7 +152,10 @@ impl From<&ArrowDataType> for DataType {
                 }
             }
             #[cfg(feature = "dtype-decimal")]
-            ArrowDataType::Decimal(precision, scale) => DataType::Decimal(Some(*precision), Some(*scale)),
+            ArrowDataType::Decimal(precision, scale) | ArrowDataType::Decimal256(precision, scale) => DataType::Decimal(Some(*precision), Some(*scale)),
+            // the arrays are read as Float64, see `Series::try_from`
+            #[cfg(not(feature = "dtype-decimal"))]
+            ArrowDataType::Decimal(_, _) | ArrowDataType::Decimal256(_, _) => DataType::Float64,
             dt => panic!("Arrow datatype {dt:?} not supported by Polars. You probably need to activate that data-type feature."),
         }
     }
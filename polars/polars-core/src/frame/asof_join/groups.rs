@@ -621,7 +621,7 @@ fn dispatch_join<T: PolarsNumericType>(
         for (lhs, rhs) in left_by.get_columns().iter().zip(right_by.get_columns()) {
             check_asof_columns(lhs, rhs)?;
             #[cfg(feature = "dtype-categorical")]
-            _check_categorical_src(lhs.dtype(), rhs.dtype())?;
+            _check_categorical_src(lhs.dtype(), rhs.dtype(), "asof join")?;
         }
         asof_join_by_multiple(
             left_by, right_by, left_asof, right_asof, tolerance, strategy,
@@ -662,7 +662,7 @@ impl DataFrame {
                 .zip(right_by.get_columns_mut().iter_mut())
             {
                 #[cfg(feature = "dtype-categorical")]
-                _check_categorical_src(l.dtype(), r.dtype())?;
+                _check_categorical_src(l.dtype(), r.dtype(), "asof join")?;
                 *l = l.to_physical_repr().into_owned();
                 *r = r.to_physical_repr().into_owned();
             }
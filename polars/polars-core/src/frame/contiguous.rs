@@ -0,0 +1,173 @@
+use rayon::prelude::*;
+
+use crate::prelude::*;
+use crate::POOL;
+
+/// The order in which the values of several columns are laid out in a single buffer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BufferLayout {
+    /// The values of every column are contiguous.
+    #[default]
+    ColumnMajor,
+    /// The values of every row are contiguous.
+    RowMajor,
+}
+
+impl DataFrame {
+    /// Write the values of the numeric `columns` into a single buffer of type `N`, for handing
+    /// the data to linear algebra code.
+    ///
+    /// The columns are cast to `N` if needed and written straight into the buffer, so no
+    /// intermediate per-column copies are made. Values that don't fit in `N` are an error. Null
+    /// values are replaced by `null_value`; if it is `None`, null values are an error.
+    ///
+    /// ```rust
+    /// use polars_core::df;
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 2], "b" => [Some(3.0), None]).unwrap();
+    /// let buf = df
+    ///     .to_contiguous_buffer::<Float64Type, _, _>(["a", "b"], BufferLayout::RowMajor, Some(0.0))
+    ///     .unwrap();
+    /// assert_eq!(buf, &[1.0, 3.0, 2.0, 0.0]);
+    /// ```
+    pub fn to_contiguous_buffer<N, I, S>(
+        &self,
+        columns: I,
+        layout: BufferLayout,
+        null_value: Option<N::Native>,
+    ) -> PolarsResult<Vec<N::Native>>
+    where
+        N: PolarsNumericType,
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let columns = self.columns(columns)?;
+        for s in &columns {
+            polars_ensure!(
+                s.dtype().is_numeric(),
+                InvalidOperation: "cannot write column '{}' of dtype {} to a numeric buffer",
+                s.name(), s.dtype()
+            );
+            polars_ensure!(
+                null_value.is_some() || s.null_count() == 0,
+                ComputeError: "column '{}' has null values; provide a value to replace them with",
+                s.name()
+            );
+        }
+
+        let height = self.height();
+        let width = columns.len();
+        let mut buf = Vec::<N::Native>::with_capacity(height * width);
+        let ptr = buf.as_mut_ptr() as usize;
+        let null_value = null_value.unwrap_or_default();
+
+        POOL.install(|| {
+            columns.par_iter().enumerate().try_for_each(|(col_idx, s)| {
+                // this is an Arc clone if already of type N
+                // a value that doesn't fit in N is an error instead of a null
+                let s = s.strict_cast(&N::get_dtype())?;
+                let ca = s.unpack::<N>()?;
+                let ptr = ptr as *mut N::Native;
+
+                let mut row = 0;
+                for arr in ca.downcast_iter() {
+                    // Safety:
+                    // every column only writes its own positions, which are in bounds of
+                    // the allocated `height * width` values
+                    unsafe {
+                        match layout {
+                            BufferLayout::ColumnMajor if arr.null_count() == 0 => {
+                                let dst = ptr.add(col_idx * height + row);
+                                std::ptr::copy_nonoverlapping(
+                                    arr.values().as_ptr(),
+                                    dst,
+                                    arr.len(),
+                                );
+                            }
+                            BufferLayout::ColumnMajor => {
+                                let dst = ptr.add(col_idx * height + row);
+                                for (i, v) in arr.iter().enumerate() {
+                                    dst.add(i).write(v.copied().unwrap_or(null_value));
+                                }
+                            }
+                            BufferLayout::RowMajor => {
+                                for (i, v) in arr.iter().enumerate() {
+                                    ptr.add((row + i) * width + col_idx)
+                                        .write(v.copied().unwrap_or(null_value));
+                                }
+                            }
+                        }
+                    }
+                    row += arr.len();
+                }
+                Ok(())
+            })
+        })?;
+
+        // Safety:
+        // all columns have written all their values
+        unsafe { buf.set_len(height * width) };
+        Ok(buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_contiguous_buffer() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1i32, 2, 3],
+            "b" => [Some(4.0f64), None, Some(6.0)],
+            "c" => ["x", "y", "z"],
+        ]?;
+        df.vstack_mut(&df.clone())?;
+        assert_eq!(df.n_chunks(), 2);
+
+        let out = df.to_contiguous_buffer::<Float64Type, _, _>(
+            ["a", "b"],
+            BufferLayout::ColumnMajor,
+            Some(-1.0),
+        )?;
+        assert_eq!(
+            out,
+            &[1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 4.0, -1.0, 6.0, 4.0, -1.0, 6.0]
+        );
+        let out =
+            df.to_contiguous_buffer::<Int32Type, _, _>(["a"], BufferLayout::RowMajor, None)?;
+        assert_eq!(out, &[1, 2, 3, 1, 2, 3]);
+        let out = df.to_contiguous_buffer::<Float64Type, _, _>(
+            ["b", "a"],
+            BufferLayout::RowMajor,
+            Some(0.0),
+        )?;
+        assert_eq!(
+            out,
+            &[4.0, 1.0, 0.0, 2.0, 6.0, 3.0, 4.0, 1.0, 0.0, 2.0, 6.0, 3.0]
+        );
+
+        // nulls need a fill value, and only numeric columns can be written
+        assert!(df
+            .to_contiguous_buffer::<Float64Type, _, _>(["b"], BufferLayout::ColumnMajor, None)
+            .is_err());
+        assert!(df
+            .to_contiguous_buffer::<Float64Type, _, _>(["c"], BufferLayout::ColumnMajor, None)
+            .is_err());
+        // values that don't fit are not written as nulls
+        let big = df!("a" => [1i64, i64::MAX])?;
+        assert!(big
+            .to_contiguous_buffer::<Int32Type, _, _>(["a"], BufferLayout::ColumnMajor, Some(0))
+            .is_err());
+
+        let s = df.column("a")?;
+        assert!(s.try_as_slice::<Int32Type>().is_none());
+        let s = s.rechunk();
+        assert_eq!(
+            s.try_as_slice::<Int32Type>(),
+            Some([1, 2, 3, 1, 2, 3].as_slice())
+        );
+        assert!(s.try_as_slice::<Int64Type>().is_none());
+        Ok(())
+    }
+}
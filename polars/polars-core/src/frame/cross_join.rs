@@ -2,28 +2,29 @@ use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
 use crate::series::IsSorted;
-use crate::utils::{concat_df_unchecked, slice_offsets, CustomIterTools, NoNull};
+use crate::utils::{_check_idx_len, concat_df_unchecked, slice_offsets, CustomIterTools, NoNull};
 use crate::POOL;
 
+// the row numbers of the product are `usize`, only the take indices have to fit in `IdxSize`
 fn slice_take(
-    total_rows: IdxSize,
-    n_rows_right: IdxSize,
+    total_rows: usize,
+    n_rows_right: usize,
     slice: Option<(i64, usize)>,
-    inner: fn(IdxSize, IdxSize, IdxSize) -> IdxCa,
+    inner: fn(usize, usize, usize) -> IdxCa,
 ) -> IdxCa {
     match slice {
         None => inner(0, total_rows, n_rows_right),
         Some((offset, len)) => {
-            let (offset, len) = slice_offsets(offset, len, total_rows as usize);
-            inner(offset as IdxSize, (len + offset) as IdxSize, n_rows_right)
+            let (offset, len) = slice_offsets(offset, len, total_rows);
+            inner(offset, len + offset, n_rows_right)
         }
     }
 }
 
-fn take_left(total_rows: IdxSize, n_rows_right: IdxSize, slice: Option<(i64, usize)>) -> IdxCa {
-    fn inner(offset: IdxSize, total_rows: IdxSize, n_rows_right: IdxSize) -> IdxCa {
+fn take_left(total_rows: usize, n_rows_right: usize, slice: Option<(i64, usize)>) -> IdxCa {
+    fn inner(offset: usize, total_rows: usize, n_rows_right: usize) -> IdxCa {
         let mut take: NoNull<IdxCa> = (offset..total_rows)
-            .map(|i| i / n_rows_right)
+            .map(|i| (i / n_rows_right) as IdxSize)
             .collect_trusted();
         take.set_sorted_flag(IsSorted::Ascending);
         take.into_inner()
@@ -31,10 +32,10 @@ fn take_left(total_rows: IdxSize, n_rows_right: IdxSize, slice: Option<(i64, usi
     slice_take(total_rows, n_rows_right, slice, inner)
 }
 
-fn take_right(total_rows: IdxSize, n_rows_right: IdxSize, slice: Option<(i64, usize)>) -> IdxCa {
-    fn inner(offset: IdxSize, total_rows: IdxSize, n_rows_right: IdxSize) -> IdxCa {
+fn take_right(total_rows: usize, n_rows_right: usize, slice: Option<(i64, usize)>) -> IdxCa {
+    fn inner(offset: usize, total_rows: usize, n_rows_right: usize) -> IdxCa {
         let take: NoNull<IdxCa> = (offset..total_rows)
-            .map(|i| i % n_rows_right)
+            .map(|i| (i % n_rows_right) as IdxSize)
             .collect_trusted();
         take.into_inner()
     }
@@ -48,14 +49,15 @@ impl DataFrame {
         slice: Option<(i64, usize)>,
         parallel: bool,
     ) -> PolarsResult<(DataFrame, DataFrame)> {
-        let n_rows_left = self.height() as IdxSize;
-        let n_rows_right = other.height() as IdxSize;
-        let Some(total_rows) = n_rows_left.checked_mul(n_rows_right) else {
-            polars_bail!(
-                ComputeError: "cross joins would produce more rows than fits into 2^32; \
-                consider comping with polars-big-idx feature, or set 'streaming'"
-            );
+        let n_rows_left = self.height();
+        let n_rows_right = other.height();
+        let total_rows = n_rows_left.saturating_mul(n_rows_right);
+        // only the rows of the slice are materialized, so only those have to fit in `IdxSize`
+        let out_rows = match slice {
+            Some((offset, len)) => slice_offsets(offset, len, total_rows).1,
+            None => total_rows,
         };
+        _check_idx_len(out_rows)?;
         if n_rows_left == 0 || n_rows_right == 0 {
             return Ok((self.clear(), other.clear()));
         }
@@ -159,4 +161,19 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cross_join_idx_overflow() -> PolarsResult<()> {
+        // the tests use a small maximum length
+        let df = df!["a" => (0..300).collect::<Vec<i32>>()]?;
+        assert!(df.cross_join(&df, None, None).is_err());
+        // the slice is applied before the rows are counted
+        let out = df.cross_join(&df, None, Some((-10, 5)))?;
+        let expected = df![
+            "a" => [299; 5],
+            "a_right" => [290, 291, 292, 293, 294],
+        ]?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
+use arrow::bitmap::MutableBitmap;
 use arrow::offset::OffsetsBuffer;
+use once_cell::sync::OnceCell;
 use polars_arrow::kernels::concatenate::concatenate_owned_unchecked;
 use rayon::prelude::*;
 #[cfg(feature = "serde-lazy")]
@@ -19,6 +21,69 @@ fn get_exploded(series: &Series) -> PolarsResult<(Series, OffsetsBuffer<i64>)> {
     }
 }
 
+/// Repeat the values of `ca` as often as their row has elements in `offsets`.
+fn repeat_numeric<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    offsets: &[i64],
+    capacity: usize,
+) -> ChunkedArray<T> {
+    // an empty list still gives a single row
+    let mut counts = offsets
+        .windows(2)
+        .map(|w| std::cmp::max(w[1] - w[0], 1) as usize);
+    let mut values = Vec::with_capacity(capacity);
+    let mut validity = (ca.null_count() > 0).then(|| MutableBitmap::with_capacity(capacity));
+    for arr in ca.downcast_iter() {
+        for (i, (v, count)) in arr.values().iter().zip(&mut counts).enumerate() {
+            values.extend(std::iter::repeat(*v).take(count));
+            if let Some(validity) = &mut validity {
+                validity.extend_constant(count, arr.is_valid(i))
+            }
+        }
+    }
+    ChunkedArray::new_from_owned_with_null_bitmap(
+        ca.name(),
+        values,
+        validity.map(|validity| validity.into()),
+    )
+}
+
+/// Repeat the rows of a column that is not exploded as often as the exploded column has
+/// elements in that row.
+///
+/// Columns of integers and floats are repeated directly, the other columns are gathered with
+/// the row indices, which are created on first use.
+fn repeat_by_offsets(
+    s: &Series,
+    offsets: &[i64],
+    capacity: usize,
+    row_idx: &OnceCell<IdxCa>,
+) -> Series {
+    let physical = s.dtype().to_physical();
+    // a categorical would lose its ordering, decimals have no numeric dispatch
+    if (physical.is_integer() || physical.is_float())
+        && !matches!(s.dtype(), DataType::Categorical(_, _))
+    {
+        let phys = s.to_physical_repr();
+        let out = with_match_physical_numeric_polars_type!(physical, |$T| {
+            let ca: &ChunkedArray<$T> = phys.as_ref().as_ref().as_ref();
+            repeat_numeric(ca, offsets, capacity).into_series()
+        });
+        // Safety:
+        // the values are of the physical type of `s`
+        unsafe { out.cast_unchecked(s.dtype()).unwrap() }
+    } else {
+        let row_idx = row_idx.get_or_init(|| {
+            let mut row_idx = IdxCa::from_vec("", offsets_to_indexes(offsets, capacity));
+            row_idx.set_sorted_flag(IsSorted::Ascending);
+            row_idx
+        });
+        // Safety
+        // We just created indices that are in bounds.
+        unsafe { s.take_unchecked(row_idx).unwrap() }
+    }
+}
+
 /// Arguments for `[DataFrame::melt]` function
 #[derive(Clone, Default, Debug, PartialEq)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
@@ -78,25 +143,38 @@ impl DataFrame {
             Ok(())
         }
 
+        // the columns explode in lockstep, so every row must have the same number of elements in
+        // all columns. We compare the lengths, as the offsets of a sliced column don't start at 0.
         let check_offsets = || {
-            let first_offsets = exploded_columns[0].1.as_slice();
-            for (_, offsets) in &exploded_columns[1..] {
-                polars_ensure!(first_offsets == offsets.as_slice(),
-                    ShapeMismatch: "exploded columns must have matching element counts"
-                )
+            let (first, first_offsets) = &exploded_columns[0];
+            for (exploded, offsets) in &exploded_columns[1..] {
+                let mismatch = first_offsets
+                    .as_slice()
+                    .windows(2)
+                    .zip(offsets.as_slice().windows(2))
+                    .position(|(a, b)| a[1] - a[0] != b[1] - b[0]);
+                if let Some(row) = mismatch {
+                    polars_bail!(
+                        ShapeMismatch: "exploded columns must have matching element counts, but \
+                        {:?} and {:?} differ in row {}", first.name(), exploded.name(), row
+                    );
+                }
             }
             Ok(())
         };
         let process_first = || {
             let (exploded, offsets) = &exploded_columns[0];
-
-            let row_idx = offsets_to_indexes(offsets.as_slice(), exploded.len());
-            let mut row_idx = IdxCa::from_vec("", row_idx);
-            row_idx.set_sorted_flag(IsSorted::Ascending);
-
-            // Safety
-            // We just created indices that are in bounds.
-            let mut df = unsafe { df.take_unchecked(&row_idx) };
+            let offsets = offsets.as_slice();
+
+            // the indices are only created for the columns that are gathered
+            let row_idx = OnceCell::new();
+            let columns = POOL.install(|| {
+                df.get_columns()
+                    .par_iter()
+                    .map(|s| repeat_by_offsets(s, offsets, exploded.len(), &row_idx))
+                    .collect::<Vec<_>>()
+            });
+            let mut df = DataFrame::new_no_checks(columns);
             process_column(self, &mut df, exploded.clone())?;
             PolarsResult::Ok(df)
         };
@@ -112,6 +190,10 @@ impl DataFrame {
     }
     /// Explode `DataFrame` to long format by exploding a column with Lists.
     ///
+    /// Multiple columns are exploded in lockstep: every row must have the same number of elements
+    /// in all of them, and the other columns are repeated once per element. An empty or null list
+    /// gives a single row with a null value, so that no row disappears.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -283,7 +365,7 @@ impl DataFrame {
         let mut iter = value_vars.iter().map(|v| {
             schema
                 .get(v)
-                .ok_or_else(|| polars_err!(ColumnNotFound: "{}", v))
+                .ok_or_else(|| polars_err!(ColumnNotFound: "{}", v; column = v))
         });
         let mut st = iter.next().unwrap()?.clone();
         for dt in iter {
@@ -411,6 +493,107 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_lockstep() -> PolarsResult<()> {
+        let a = Series::new("", [1, 2, 3]);
+        let b = Series::new("", [10, 20, 30]);
+        let c = Series::new("", [4]);
+        let d = Series::new("", [40]);
+        let df = DataFrame::new(vec![
+            Series::new("a", [a.clone(), a.clear(), c.clone(), a.clone()]),
+            Series::new("c", ["x", "y", "z", "w"]),
+            Series::new("b", [b.clone(), b.clear(), d.clone(), d.clone()]),
+        ])?;
+
+        // the lengths differ in the last row
+        let err = df.explode(["a", "b"]).unwrap_err();
+        assert!(err.to_string().contains("row 3"));
+
+        let out = df.head(Some(3)).explode(["b", "a"])?;
+        let expected = df![
+            "a" => [Some(1), Some(2), Some(3), None, Some(4)],
+            "c" => ["x", "x", "x", "y", "z"],
+            "b" => [Some(10), Some(20), Some(30), None, Some(40)]
+        ]?;
+        assert!(out.frame_equal_missing(&expected));
+
+        // the offsets of the sliced column don't start at 0
+        let df = DataFrame::new(vec![
+            df.column("a")?.slice(1, 2),
+            Series::new("b", [b.clear(), d.clone()]),
+        ])?;
+        let out = df.explode(["a", "b"])?;
+        let expected = df![
+            "a" => [None, Some(4)],
+            "b" => [None, Some(40)]
+        ]?;
+        assert!(out.frame_equal_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_chunked_list() -> PolarsResult<()> {
+        let a = Series::new("", [1, 2, 3]);
+        let mut list = Series::new("foo", [a.clone(), a.clear()]);
+        list.append(&Series::new("foo", [a.slice(1, 1), a.clone()]))?;
+        let df = DataFrame::new(vec![
+            list,
+            Series::new("b", [Some(1i64), None, Some(3), Some(4)]),
+            Series::new("c", ["w", "x", "y", "z"]),
+        ])?;
+
+        let out = df.explode(["foo"])?;
+        let expected = df.agg_chunks().explode(["foo"])?;
+        assert!(out.frame_equal_missing(&expected));
+        assert_eq!(out.column("foo")?.n_chunks(), 2);
+        assert_eq!(
+            Vec::from(out.column("b")?.i64()?),
+            &[
+                Some(1),
+                Some(1),
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                Some(4),
+                Some(4)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_explode_decimal_sibling() -> PolarsResult<()> {
+        let dtype = DataType::Decimal(Some(10), Some(2));
+        let a = Series::new("", [1, 2]);
+        let df = DataFrame::new(vec![
+            Series::new("foo", [a.clone(), a.clear()]),
+            Series::new("d", [Some(1i64), None]).cast(&dtype)?,
+        ])?;
+        let out = df.explode(["foo"])?;
+        let d = out.column("d")?;
+        assert_eq!(d.dtype(), &dtype);
+        assert_eq!(Vec::from(&**d.decimal()?), &[Some(100), Some(100), None]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_idx_overflow() -> PolarsResult<()> {
+        // the tests use a small maximum length
+        let a = Series::new("", vec![1i32; 40_000]);
+        let df = DataFrame::new(vec![
+            Series::new("foo", [a.clone(), a.clear(), a]),
+            Series::new("b", [1, 2, 3]),
+        ])?;
+        assert!(df.explode(["foo"]).is_err());
+        assert!(df.slice(1, 2).explode(["foo"]).is_ok());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_explode_single_col() -> PolarsResult<()> {
@@ -0,0 +1,195 @@
+//! Exchange [`DataFrame`]s with other libraries through the
+//! [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+//!
+//! A `DataFrame` is exported as a stream of struct arrays, one per chunk, whose fields are the
+//! columns. The buffers are shared, not copied: the consumer releases them through the
+//! release callbacks of the arrays and of the stream.
+use arrow::array::StructArray;
+use arrow::ffi::{export_iterator, ArrowArrayStream, ArrowArrayStreamReader};
+
+use crate::prelude::*;
+
+/// Error on the dtypes that have no arrow representation.
+pub(crate) fn ensure_exportable(name: &str, dtype: &DataType) -> PolarsResult<()> {
+    match dtype {
+        #[cfg(feature = "object")]
+        DataType::Object(_) => {
+            polars_bail!(InvalidOperation: "cannot export column '{}' of dtype object", name)
+        }
+        DataType::Unknown => {
+            polars_bail!(InvalidOperation: "cannot export column '{}' of unknown dtype", name)
+        }
+        DataType::List(inner) => ensure_exportable(name, inner),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => fields
+            .iter()
+            .try_for_each(|fld| ensure_exportable(name, fld.data_type())),
+        _ => Ok(()),
+    }
+}
+
+impl DataFrame {
+    /// Export this `DataFrame` as an Arrow C stream of struct arrays, one array per chunk.
+    ///
+    /// Categoricals are exported as dictionary arrays and datetimes keep their time zone.
+    /// Object columns cannot be exported.
+    pub fn export_to_c(&self) -> PolarsResult<ArrowArrayStream> {
+        for s in &self.columns {
+            ensure_exportable(s.name(), s.dtype())?;
+        }
+        let mut df = self.clone();
+        df.rechunk();
+
+        let fields = df
+            .columns
+            .iter()
+            .map(|s| s.field().to_arrow())
+            .collect::<Vec<_>>();
+        let data_type = ArrowDataType::Struct(fields);
+        let batches = df
+            .iter_chunks()
+            .map(|chunk| {
+                let arr = StructArray::new(data_type.clone(), chunk.into_arrays(), None);
+                Ok(Box::new(arr) as ArrayRef)
+            })
+            .collect::<Vec<_>>();
+        let field = ArrowField::new("", data_type, false);
+        Ok(export_iterator(Box::new(batches.into_iter()), field))
+    }
+
+    /// Import a `DataFrame` from an Arrow C stream of struct arrays, such as the one created by
+    /// [`DataFrame::export_to_c`].
+    ///
+    /// Every array of the stream becomes a chunk of the `DataFrame`. The stream is released when
+    /// this function returns.
+    ///
+    /// # Safety
+    ///
+    /// `stream` must be a valid Arrow C stream that was not released yet.
+    pub unsafe fn import_from_c(stream: Box<ArrowArrayStream>) -> PolarsResult<DataFrame> {
+        let mut reader = ArrowArrayStreamReader::try_new(stream)?;
+        let fields = match reader.field().data_type() {
+            ArrowDataType::Struct(fields) => fields.clone(),
+            dt => polars_bail!(
+                ComputeError: "expected an arrow stream of struct arrays, got {:?}", dt
+            ),
+        };
+        // categorical chunks can only be appended when they share a rev-map
+        #[cfg(feature = "dtype-categorical")]
+        let _cache = crate::StringCacheHolder::new();
+
+        let mut out: Option<DataFrame> = None;
+        while let Some(arr) = reader.next() {
+            let arr = arr?;
+            let arr = arr
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .ok_or_else(|| polars_err!(ComputeError: "expected a struct array"))?
+                .clone();
+            let df = DataFrame::try_from(arr)?;
+            match out.as_mut() {
+                Some(out) => {
+                    out.vstack_mut(&df)?;
+                }
+                None => out = Some(df),
+            }
+        }
+        match out {
+            Some(df) => Ok(df),
+            None => {
+                let schema = fields.iter().map(Field::from).collect::<Schema>();
+                Ok(DataFrame::from(&schema))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn round_trip(df: &DataFrame) -> PolarsResult<DataFrame> {
+        let stream = Box::into_raw(Box::new(df.export_to_c()?));
+        // Safety:
+        // the pointer was created from a box right above
+        unsafe { DataFrame::import_from_c(Box::from_raw(stream)) }
+    }
+
+    #[test]
+    fn test_ffi_round_trip() -> PolarsResult<()> {
+        // categoricals of both directions share a rev-map
+        #[cfg(feature = "dtype-categorical")]
+        let _cache = crate::StringCacheHolder::new();
+
+        let mut df = df![
+            "int" => [Some(1i32), None, Some(3)],
+            "str" => ["a", "b", "c"],
+        ]?;
+        let list = Series::new(
+            "list",
+            (0..3)
+                .map(|i| Series::new("", (0..i).collect::<Vec<i64>>()))
+                .collect::<Vec<_>>(),
+        );
+        df.with_column(list)?;
+        #[cfg(feature = "dtype-struct")]
+        {
+            let fields = [df.column("int")?.clone(), df.column("str")?.clone()];
+            df.with_column(StructChunked::new("struct", &fields)?.into_series())?;
+        }
+        #[cfg(feature = "dtype-categorical")]
+        {
+            let cat = df
+                .column("str")?
+                .cast(&DataType::Categorical(None, Default::default()))?;
+            df.with_column(cat.with_name("cat"))?;
+        }
+        #[cfg(feature = "dtype-datetime")]
+        {
+            let dt = Series::new("dt", [0i64, 1, 2]).cast(&DataType::Datetime(
+                TimeUnit::Milliseconds,
+                Some("Europe/Amsterdam".into()),
+            ))?;
+            df.with_column(dt)?;
+        }
+        df.vstack_mut(&df.clone())?;
+
+        let out = round_trip(&df)?;
+        assert_eq!(out.schema(), df.schema());
+        assert_eq!(out.n_chunks(), 2);
+        assert!(out.frame_equal_missing(&df));
+
+        // an empty stream keeps the schema
+        let out = round_trip(&df.clear())?;
+        assert_eq!(out.schema(), df.schema());
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
+
+    // `make leak-check-ffi` runs these tests under LeakSanitizer to check the release paths
+    #[test]
+    fn test_ffi_release() -> PolarsResult<()> {
+        let df = df!["a" => [1, 2, 3]]?;
+        // a stream that is dropped without being consumed releases its buffers
+        drop(df.export_to_c()?);
+
+        // the imported frame owns the buffers after the stream is released
+        let out = round_trip(&df)?;
+        drop(df);
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "object")]
+    fn test_ffi_object_error() {
+        use crate::chunked_array::object::ObjectChunked;
+
+        let s = ObjectChunked::new_from_vec("obj", vec![1i32, 2]).into_series();
+        let err = DataFrame::new(vec![s]).unwrap().export_to_c().unwrap_err();
+        assert!(err.to_string().contains("object"));
+    }
+}
@@ -59,7 +59,35 @@ where
         if verbose() {
             eprintln!("groupby keys are sorted; running sorted key fast path");
         }
-        let arr = self.downcast_iter().next().unwrap();
+        // the groups are created per chunk, so that chunked keys don't have to be rechunked
+        let mut arrays = self.downcast_iter().filter(|arr| !arr.is_empty());
+        let Some(mut prev) = arrays.next() else {
+            return GroupsSlice::default();
+        };
+        let mut groups = self.create_groups_from_sorted_arr(prev, multithreaded);
+        let mut offset = prev.len() as IdxSize;
+        for arr in arrays {
+            let mut arr_groups = self
+                .create_groups_from_sorted_arr(arr, multithreaded)
+                .into_iter()
+                .map(move |[first, len]| [first + offset, len]);
+            // a group that crosses the chunk boundary is continued by the first group of `arr`
+            if prev.get(prev.len() - 1) == arr.get(0) {
+                let [_, len] = arr_groups.next().unwrap();
+                groups.last_mut().unwrap()[1] += len;
+            }
+            groups.extend(arr_groups);
+            offset += arr.len() as IdxSize;
+            prev = arr;
+        }
+        groups
+    }
+
+    fn create_groups_from_sorted_arr(
+        &self,
+        arr: &PrimitiveArray<T::Native>,
+        multithreaded: bool,
+    ) -> GroupsSlice {
         if arr.is_empty() {
             return GroupsSlice::default();
         }
@@ -144,7 +172,7 @@ where
         if self.is_sorted_ascending_flag() || self.is_sorted_descending_flag() {
             // don't have to pass `sorted` arg, GroupSlice is always sorted.
             return Ok(GroupsProxy::Slice {
-                groups: self.rechunk().create_groups_from_sorted(multithreaded),
+                groups: self.create_groups_from_sorted(multithreaded),
                 rolling: false,
             });
         }
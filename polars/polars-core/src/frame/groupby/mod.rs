@@ -31,7 +31,7 @@ fn prepare_dataframe_unsorted(by: &[Series]) -> DataFrame {
         by.iter()
             .map(|s| match s.dtype() {
                 #[cfg(feature = "dtype-categorical")]
-                DataType::Categorical(_) => s.cast(&DataType::UInt32).unwrap(),
+                DataType::Categorical(_, _) => s.cast(&DataType::UInt32).unwrap(),
                 _ => {
                     if s.dtype().to_physical().is_numeric() {
                         let s = s.to_physical_repr();
@@ -829,6 +829,73 @@ impl<'df> GroupBy<'df> {
         df.as_single_chunk();
         Ok(df)
     }
+
+    /// Apply a closure over the groups as a new DataFrame in parallel, where the result of every
+    /// group must have the given `schema`.
+    ///
+    /// The results are concatenated in the order of the groups, so a stable groupby keeps the
+    /// order in which the groups first occur. Without groups an empty DataFrame of `schema` is
+    /// returned. An error of any group aborts and names the keys of that group.
+    pub fn map_groups_par<F>(&self, schema: Schema, f: F) -> PolarsResult<DataFrame>
+    where
+        F: Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        let df = self.prepare_apply()?;
+        let dfs = POOL.install(|| {
+            self.get_groups()
+                .par_iter()
+                .map(|g| {
+                    let first = g.first();
+                    // safety
+                    // groups are in bounds
+                    let sub_df = unsafe { take_df(&df, g) };
+                    f(sub_df)
+                        .and_then(|out| {
+                            let out_schema = out.schema();
+                            polars_ensure!(
+                                out_schema == schema,
+                                SchemaMismatch: "group result has schema [{}], expected [{}]",
+                                fmt_schema(&out_schema), fmt_schema(&schema)
+                            );
+                            Ok(out)
+                        })
+                        .map_err(|err| {
+                            let keys = self.fmt_group_keys(first);
+                            err.wrap_msg(&|msg| format!("{msg}\n\nin the group with keys: {keys}"))
+                        })
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        if dfs.is_empty() {
+            return Ok(DataFrame::from(&schema));
+        }
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        df.as_single_chunk_par();
+        Ok(df)
+    }
+
+    /// The key values of the group whose first row is `first`.
+    fn fmt_group_keys(&self, first: IdxSize) -> String {
+        self.selected_keys
+            .iter()
+            .map(|s| {
+                // Safety:
+                // the first row of a group is in bounds of the keys
+                let av = unsafe { s.get_unchecked(first as usize) };
+                format!("{} = {}", s.name(), av)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+fn fmt_schema(schema: &Schema) -> String {
+    schema
+        .iter()
+        .map(|(name, dtype)| format!("{name}: {dtype}"))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 unsafe fn take_df(df: &DataFrame, g: GroupsIndicator) -> DataFrame {
@@ -911,6 +978,7 @@ mod test {
     use num_traits::FloatConst;
 
     use crate::prelude::*;
+    use crate::series::IsSorted;
 
     #[test]
     #[cfg(feature = "dtype-date")]
@@ -1092,8 +1160,11 @@ mod test {
         }
         .unwrap();
 
-        df.apply("foo", |s| s.cast(&DataType::Categorical(None)).unwrap())
-            .unwrap();
+        df.apply("foo", |s| {
+            s.cast(&DataType::Categorical(None, Default::default()))
+                .unwrap()
+        })
+        .unwrap();
 
         // Use of deprecated `sum()` for testing purposes
         #[allow(deprecated)]
@@ -1111,6 +1182,59 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_map_groups_par() -> PolarsResult<()> {
+        let df = df!(
+            "a" => ["x", "y", "x", "z", "y"],
+            "b" => [1, 2, 3, 4, 5]
+        )?;
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::Utf8),
+            Field::new("b", DataType::Int32),
+        ]);
+        let sum = |df: DataFrame| {
+            let keys = df.column("a")?.head(Some(1));
+            let sum = df.column("b")?.sum_as_series()?;
+            DataFrame::new(vec![keys, sum])
+        };
+
+        // the groups keep their order
+        let out = df
+            .groupby_stable(["a"])?
+            .map_groups_par(schema.clone(), sum)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.utf8()?),
+            &[Some("x"), Some("y"), Some("z")]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(4), Some(7), Some(4)]
+        );
+
+        // the declared schema is used without groups
+        let out = df
+            .head(Some(0))
+            .groupby(["a"])?
+            .map_groups_par(schema.clone(), sum)?;
+        assert_eq!(out.schema(), schema);
+
+        // a result that does not match the schema fails and names the group
+        let err = df
+            .groupby(["a"])?
+            .map_groups_par(schema, |df| {
+                if df.column("a")?.utf8()?.get(0) == Some("y") {
+                    df.select(["b"])
+                } else {
+                    sum(df)
+                }
+            })
+            .unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::SchemaMismatch);
+        assert!(err.to_string().contains("a = \"y\""));
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_groupby_null_handling() -> PolarsResult<()> {
@@ -1164,11 +1288,47 @@ mod test {
             "int" => [1, 2, 3, 1, 1]
         ]?;
 
-        df.try_apply("g", |s| s.cast(&DataType::Categorical(None)))?;
+        df.try_apply("g", |s| {
+            s.cast(&DataType::Categorical(None, Default::default()))
+        })?;
 
         // Use of deprecated `sum()` for testing purposes
         #[allow(deprecated)]
         let _ = df.groupby(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    fn test_groupby_sorted_chunked_keys() -> PolarsResult<()> {
+        let chunked = |chunks: &[&[Option<i32>]]| {
+            let mut ca = Int32Chunked::new("", chunks[0]);
+            for chunk in &chunks[1..] {
+                ca.append(&Int32Chunked::new("", *chunk));
+            }
+            ca.set_sorted_flag(IsSorted::Ascending);
+            ca
+        };
+        let nulls_first = chunked(&[
+            &[None, None],
+            &[None, Some(1), Some(1)],
+            &[Some(1), Some(2)],
+            &[Some(2), Some(2)],
+            &[Some(3)],
+        ]);
+        let nulls_last = chunked(&[&[Some(1), Some(1)], &[Some(2), None], &[None]]);
+
+        for (ca, expected) in [
+            (nulls_first, vec![[0, 3], [3, 3], [6, 3], [9, 1]]),
+            (nulls_last, vec![[0, 2], [2, 1], [3, 2]]),
+        ] {
+            assert!(ca.chunks().len() > 1);
+            for multithreaded in [false, true] {
+                match ca.group_tuples(multithreaded, false)? {
+                    GroupsProxy::Slice { groups, .. } => assert_eq!(groups, expected),
+                    _ => panic!("expected slice groups for sorted keys"),
+                }
+            }
+        }
+        Ok(())
+    }
 }
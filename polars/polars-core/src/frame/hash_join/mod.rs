@@ -46,7 +46,7 @@ use crate::hashing::{
     AsU64, BytesHash,
 };
 use crate::prelude::*;
-use crate::utils::{_set_partition_size, slice_slice, split_ca};
+use crate::utils::{_check_idx_len, _set_partition_size, slice_offsets, slice_slice, split_ca};
 use crate::POOL;
 
 pub type LeftJoinIds = (JoinIds, JoinOptIds);
@@ -77,9 +77,14 @@ pub fn default_join_ids() -> JoinOptIds {
 }
 
 macro_rules! det_hash_prone_order {
-    ($self:expr, $other:expr) => {{
-        // The shortest relation will be used to create a hash table.
-        let left_first = $self.len() > $other.len();
+    ($self:expr, $other:expr, $build_side:expr) => {{
+        // The shortest relation will be used to create a hash table, unless the build side is
+        // given.
+        let left_first = match $build_side {
+            JoinBuildSide::Auto => $self.len() > $other.len(),
+            JoinBuildSide::Left => false,
+            JoinBuildSide::Right => true,
+        };
         let a;
         let b;
         if left_first {
@@ -102,14 +107,14 @@ use crate::series::IsSorted;
 
 /// If Categorical types are created without a global string cache or under
 /// a different global string cache the mapping will be incorrect.
+///
+/// `operation` names the operation that needs the categoricals to share a source.
 #[cfg(feature = "dtype-categorical")]
-pub fn _check_categorical_src(l: &DataType, r: &DataType) -> PolarsResult<()> {
-    if let (DataType::Categorical(Some(l)), DataType::Categorical(Some(r))) = (l, r) {
-        polars_ensure!(
-            l.same_src(r),
-            ComputeError: "joins/or comparisons on categoricals can only happen if they were \
-            created under the same global string cache"
-        );
+pub fn _check_categorical_src(l: &DataType, r: &DataType, operation: &str) -> PolarsResult<()> {
+    if let (DataType::Categorical(Some(l), _), DataType::Categorical(Some(r), _)) = (l, r) {
+        if !l.same_src(r) {
+            return Err(string_cache_mismatch_err(operation));
+        }
     }
     Ok(())
 }
@@ -154,6 +159,45 @@ impl Debug for JoinType {
     }
 }
 
+/// The input of a hash join that the hash table is built on. The other input probes the table.
+///
+/// Building on the smaller input is faster. Left joins on multiple keys and semi/anti joins
+/// always build on the right input.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinBuildSide {
+    /// Build on the input with the fewest rows.
+    #[default]
+    Auto,
+    Left,
+    Right,
+}
+
+impl JoinBuildSide {
+    /// Whether a left join of `left_len` rows with `right_len` rows builds on its left input.
+    pub(crate) fn left_join_builds_left(self, left_len: usize, right_len: usize) -> bool {
+        match self {
+            JoinBuildSide::Auto => left_len < right_len,
+            JoinBuildSide::Left => true,
+            JoinBuildSide::Right => false,
+        }
+    }
+}
+
+/// The minimal number of build rows per hash table.
+const MIN_BUILD_ROWS_PER_PARTITION: usize = 1 << 14;
+
+/// The number of hash tables to build on `n_rows` rows, always a power of two.
+///
+/// Every table is built by a thread that traverses all rows, so a small build side is split
+/// into fewer tables than there are threads.
+pub(super) fn n_build_partitions(n_rows: usize) -> usize {
+    let n_partitions = (n_rows / MIN_BUILD_ROWS_PER_PARTITION)
+        .max(1)
+        .next_power_of_two();
+    std::cmp::min(n_partitions, _set_partition_size())
+}
+
 pub(crate) unsafe fn get_hash_tbl_threaded_join_partitioned<Item>(
     h: u64,
     hash_tables: &[Item],
@@ -292,6 +336,13 @@ pub fn _join_suffix_name(name: &str, suffix: &str) -> String {
     format!("{name}{suffix}")
 }
 
+/// The number of rows of `len` join tuples after `slice` is applied.
+fn sliced_len(len: usize, slice: Option<(i64, usize)>) -> usize {
+    slice.map_or(len, |(offset, slice_len)| {
+        slice_offsets(offset, slice_len, len).1
+    })
+}
+
 /// Utility method to finish a join.
 #[doc(hidden)]
 pub fn _finish_join(
@@ -372,6 +423,7 @@ impl DataFrame {
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
         let (left_idx, right_idx) = ids;
+        _check_idx_len(sliced_len(left_idx.len(), slice))?;
         let materialize_left = || {
             let mut left_idx = &*left_idx;
             if let Some((offset, len)) = slice {
@@ -405,6 +457,11 @@ impl DataFrame {
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
         let (left_idx, right_idx) = ids;
+        let len = match &left_idx {
+            JoinIds::Left(left_idx) => left_idx.len(),
+            JoinIds::Right(left_idx) => left_idx.len(),
+        };
+        _check_idx_len(sliced_len(len, slice))?;
         let materialize_left = || match left_idx {
             JoinIds::Left(left_idx) => {
                 let mut left_idx = &*left_idx;
@@ -447,6 +504,7 @@ impl DataFrame {
         _finish_join(df_left, df_right, suffix.as_deref())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn _left_join_from_series(
         &self,
         other: &DataFrame,
@@ -455,9 +513,10 @@ impl DataFrame {
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         verbose: bool,
+        build_side: JoinBuildSide,
     ) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
-        _check_categorical_src(s_left.dtype(), s_right.dtype())?;
+        _check_categorical_src(s_left.dtype(), s_right.dtype(), "left join")?;
 
         // ensure that the chunks are aligned otherwise we go OOB
         let mut left = self.clone();
@@ -472,7 +531,7 @@ impl DataFrame {
             right.as_single_chunk_par();
             s_right = s_right.rechunk();
         }
-        let ids = sort_or_hash_left(&s_left, &s_right, verbose);
+        let ids = sort_or_hash_left(&s_left, &s_right, verbose, build_side);
         left._finish_left_join(ids, &right.drop(s_right.name()).unwrap(), suffix, slice)
     }
 
@@ -500,7 +559,7 @@ impl DataFrame {
         anti: bool,
     ) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
-        _check_categorical_src(s_left.dtype(), s_right.dtype())?;
+        _check_categorical_src(s_left.dtype(), s_right.dtype(), "semi/anti join")?;
 
         let idx = s_left.hash_join_semi_anti(s_right, anti);
         // Safety:
@@ -514,20 +573,22 @@ impl DataFrame {
         s_right: &Series,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        build_side: JoinBuildSide,
     ) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
-        _check_categorical_src(s_left.dtype(), s_right.dtype())?;
+        _check_categorical_src(s_left.dtype(), s_right.dtype(), "outer join")?;
 
         // store this so that we can keep original column order.
         let join_column_index = self.iter().position(|s| s.name() == s_left.name()).unwrap();
 
         // Get the indexes of the joined relations
-        let opt_join_tuples = s_left.hash_join_outer(s_right);
+        let opt_join_tuples = s_left.hash_join_outer(s_right, build_side);
         let mut opt_join_tuples = &*opt_join_tuples;
 
         if let Some((offset, len)) = slice {
             opt_join_tuples = slice_slice(opt_join_tuples, offset, len);
         }
+        _check_idx_len(opt_join_tuples.len())?;
 
         // Take the left and right dataframes by join tuples
         let (mut df_left, df_right) = POOL.join(
@@ -553,9 +614,10 @@ impl DataFrame {
         s.rename(s_left.name());
         let s = match s_left.dtype() {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_, _) => {
                 let ca_left = s_left.categorical().unwrap();
-                let new_rev_map = ca_left.merge_categorical_map(s_right.categorical().unwrap())?;
+                let new_rev_map =
+                    ca_left.merge_categorical_map(s_right.categorical().unwrap(), "outer join")?;
                 let logical = s.u32().unwrap().clone();
                 // safety:
                 // categorical maps are merged
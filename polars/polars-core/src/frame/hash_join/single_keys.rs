@@ -7,7 +7,8 @@ where
     T: Send + Hash + Eq + Sync + Copy + AsU64,
     IntoSlice: AsRef<[T]> + Send + Sync,
 {
-    let n_partitions = _set_partition_size();
+    let n_rows = keys.iter().map(|keys| keys.as_ref().len()).sum();
+    let n_partitions = n_build_partitions(n_rows);
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
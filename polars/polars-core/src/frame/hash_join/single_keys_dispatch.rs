@@ -8,7 +8,7 @@ use crate::utils::create_chunked_index_mapping;
 impl Series {
     #[cfg(feature = "private")]
     #[doc(hidden)]
-    pub fn hash_join_left(&self, other: &Series) -> LeftJoinIds {
+    pub fn hash_join_left(&self, other: &Series, build_side: JoinBuildSide) -> LeftJoinIds {
         let (lhs, rhs) = (self.to_physical_repr(), other.to_physical_repr());
 
         use DataType::*;
@@ -19,22 +19,22 @@ impl Series {
 
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_left(rhs)
+                lhs.hash_join_left(rhs, build_side)
             }
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_left(rhs)
+                lhs.hash_join_left(rhs, build_side)
             }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = lhs.bit_repr_large();
                     let rhs = rhs.bit_repr_large();
-                    num_group_join_left(&lhs, &rhs)
+                    num_group_join_left(&lhs, &rhs, build_side)
                 } else {
                     let lhs = lhs.bit_repr_small();
                     let rhs = rhs.bit_repr_small();
-                    num_group_join_left(&lhs, &rhs)
+                    num_group_join_left(&lhs, &rhs, build_side)
                 }
             }
         }
@@ -74,7 +74,11 @@ impl Series {
     }
 
     // returns the join tuples and whether or not the lhs tuples are sorted
-    pub(super) fn hash_join_inner(&self, other: &Series) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
+    pub(super) fn hash_join_inner(
+        &self,
+        other: &Series,
+        build_side: JoinBuildSide,
+    ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
         let (lhs, rhs) = (self.to_physical_repr(), other.to_physical_repr());
 
         use DataType::*;
@@ -85,22 +89,22 @@ impl Series {
 
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_inner(rhs)
+                lhs.hash_join_inner(rhs, build_side)
             }
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_inner(rhs)
+                lhs.hash_join_inner(rhs, build_side)
             }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    num_group_join_inner(&lhs, &rhs)
+                    num_group_join_inner(&lhs, &rhs, build_side)
                 } else {
                     let lhs = self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    num_group_join_inner(&lhs, &rhs)
+                    num_group_join_inner(&lhs, &rhs, build_side)
                 }
             }
         }
@@ -109,6 +113,7 @@ impl Series {
     pub(super) fn hash_join_outer(
         &self,
         other: &Series,
+        build_side: JoinBuildSide,
     ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
         let (lhs, rhs) = (self.to_physical_repr(), other.to_physical_repr());
 
@@ -120,22 +125,22 @@ impl Series {
 
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_outer(rhs)
+                lhs.hash_join_outer(rhs, build_side)
             }
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_outer(rhs)
+                lhs.hash_join_outer(rhs, build_side)
             }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    lhs.hash_join_outer(&rhs)
+                    lhs.hash_join_outer(&rhs, build_side)
                 } else {
                     let lhs = self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    lhs.hash_join_outer(&rhs)
+                    lhs.hash_join_outer(&rhs, build_side)
                 }
             }
         }
@@ -175,6 +180,7 @@ where
 fn num_group_join_inner<T>(
     left: &ChunkedArray<T>,
     right: &ChunkedArray<T>,
+    build_side: JoinBuildSide,
 ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool)
 where
     T: PolarsIntegerType,
@@ -182,7 +188,7 @@ where
     Option<T::Native>: AsU64,
 {
     let n_threads = POOL.current_num_threads();
-    let (a, b, swap) = det_hash_prone_order!(left, right);
+    let (a, b, swap) = det_hash_prone_order!(left, right, build_side);
     let splitted_a = split_ca(a, n_threads).unwrap();
     let splitted_b = split_ca(b, n_threads).unwrap();
     match (
@@ -245,12 +251,17 @@ fn create_mappings(
     (None, None)
 }
 
-fn num_group_join_left<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> LeftJoinIds
+fn num_group_join_left<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    build_side: JoinBuildSide,
+) -> LeftJoinIds
 where
     T: PolarsIntegerType,
     T::Native: Hash + Eq + Send + AsU64,
     Option<T::Native>: AsU64,
 {
+    let build_left = build_side.left_join_builds_left(left.len(), right.len());
     let n_threads = POOL.current_num_threads();
     let splitted_a = split_ca(left, n_threads).unwrap();
     let splitted_b = split_ca(right, n_threads).unwrap();
@@ -263,7 +274,7 @@ where
         (0, 0, 1, 1) => {
             let keys_a = splitted_to_slice(&splitted_a);
             let keys_b = splitted_to_slice(&splitted_b);
-            hash_join_tuples_left(keys_a, keys_b, None, None)
+            hash_join_tuples_left(keys_a, keys_b, None, None, build_left)
         }
         (0, 0, _, _) => {
             let keys_a = splitted_by_chunks(&splitted_a);
@@ -276,6 +287,7 @@ where
                 keys_b,
                 mapping_left.as_deref(),
                 mapping_right.as_deref(),
+                build_left,
             )
         }
         _ => {
@@ -288,6 +300,7 @@ where
                 keys_b,
                 mapping_left.as_deref(),
                 mapping_right.as_deref(),
+                build_left,
             )
         }
     }
@@ -298,12 +311,16 @@ where
     T: PolarsIntegerType + Sync,
     T::Native: Eq + Hash + NumCast,
 {
-    fn hash_join_outer(&self, other: &ChunkedArray<T>) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
+    fn hash_join_outer(
+        &self,
+        other: &ChunkedArray<T>,
+        build_side: JoinBuildSide,
+    ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+        let (a, b, swap) = det_hash_prone_order!(self, other, build_side);
 
-        let n_partitions = _set_partition_size();
-        let splitted_a = split_ca(a, n_partitions).unwrap();
-        let splitted_b = split_ca(b, n_partitions).unwrap();
+        // every split of the build side `b` becomes a hash table
+        let splitted_a = split_ca(a, _set_partition_size()).unwrap();
+        let splitted_b = split_ca(b, n_build_partitions(b.len())).unwrap();
 
         match (a.null_count(), b.null_count()) {
             (0, 0) => {
@@ -357,15 +374,11 @@ impl BinaryChunked {
     fn prepare(
         &self,
         other: &BinaryChunked,
-        swapped: bool,
+        build_side: JoinBuildSide,
     ) -> (Vec<Self>, Vec<Self>, bool, RandomState) {
         let n_threads = POOL.current_num_threads();
 
-        let (a, b, swap) = if swapped {
-            det_hash_prone_order!(self, other)
-        } else {
-            (self, other, false)
-        };
+        let (a, b, swap) = det_hash_prone_order!(self, other, build_side);
 
         let hb = RandomState::default();
         let splitted_a = split_ca(a, n_threads).unwrap();
@@ -375,8 +388,12 @@ impl BinaryChunked {
     }
 
     // returns the join tuples and whether or not the lhs tuples are sorted
-    fn hash_join_inner(&self, other: &BinaryChunked) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
-        let (splitted_a, splitted_b, swap, hb) = self.prepare(other, true);
+    fn hash_join_inner(
+        &self,
+        other: &BinaryChunked,
+        build_side: JoinBuildSide,
+    ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
+        let (splitted_a, splitted_b, swap, hb) = self.prepare(other, build_side);
         let str_hashes_a = prepare_bytes(&splitted_a, &hb);
         let str_hashes_b = prepare_bytes(&splitted_b, &hb);
         (
@@ -385,8 +402,9 @@ impl BinaryChunked {
         )
     }
 
-    fn hash_join_left(&self, other: &BinaryChunked) -> LeftJoinIds {
-        let (splitted_a, splitted_b, _, hb) = self.prepare(other, false);
+    fn hash_join_left(&self, other: &BinaryChunked, build_side: JoinBuildSide) -> LeftJoinIds {
+        let build_left = build_side.left_join_builds_left(self.len(), other.len());
+        let (splitted_a, splitted_b, _, hb) = self.prepare(other, JoinBuildSide::Right);
         let str_hashes_a = prepare_bytes(&splitted_a, &hb);
         let str_hashes_b = prepare_bytes(&splitted_b, &hb);
 
@@ -397,12 +415,13 @@ impl BinaryChunked {
             str_hashes_b,
             mapping_left.as_deref(),
             mapping_right.as_deref(),
+            build_left,
         )
     }
 
     #[cfg(feature = "semi_anti_join")]
     fn hash_join_semi_anti(&self, other: &BinaryChunked, anti: bool) -> Vec<IdxSize> {
-        let (splitted_a, splitted_b, _, hb) = self.prepare(other, false);
+        let (splitted_a, splitted_b, _, hb) = self.prepare(other, JoinBuildSide::Right);
         let str_hashes_a = prepare_bytes(&splitted_a, &hb);
         let str_hashes_b = prepare_bytes(&splitted_b, &hb);
         if anti {
@@ -412,12 +431,16 @@ impl BinaryChunked {
         }
     }
 
-    fn hash_join_outer(&self, other: &BinaryChunked) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
+    fn hash_join_outer(
+        &self,
+        other: &BinaryChunked,
+        build_side: JoinBuildSide,
+    ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+        let (a, b, swap) = det_hash_prone_order!(self, other, build_side);
 
-        let n_partitions = _set_partition_size();
-        let splitted_a = split_ca(a, n_partitions).unwrap();
-        let splitted_b = split_ca(b, n_partitions).unwrap();
+        // every split of the build side `b` becomes a hash table
+        let splitted_a = split_ca(a, _set_partition_size()).unwrap();
+        let splitted_b = split_ca(b, n_build_partitions(b.len())).unwrap();
 
         match (a.has_validity(), b.has_validity()) {
             (false, false) => {
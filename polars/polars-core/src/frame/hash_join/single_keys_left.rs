@@ -1,6 +1,7 @@
 use polars_utils::flatten;
 
 use super::single_keys::create_probe_table;
+use super::single_keys_inner::probe_inner;
 use super::*;
 use crate::frame::hash_join::single_keys::probe_to_offsets;
 
@@ -104,11 +105,22 @@ pub(super) fn hash_join_tuples_left<T, IntoSlice>(
     // only needed if we have non contiguous memory
     chunk_mapping_left: Option<&[ChunkId]>,
     chunk_mapping_right: Option<&[ChunkId]>,
+    // build the hash table on the left relation instead, see `hash_join_tuples_left_build_left`
+    build_left: bool,
 ) -> LeftJoinIds
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
     T: Send + Hash + Eq + Sync + Copy + AsU64,
 {
+    if build_left {
+        return hash_join_tuples_left_build_left(
+            probe,
+            build,
+            chunk_mapping_left,
+            chunk_mapping_right,
+        );
+    }
+
     // first we hash one relation
     let hash_tbls = create_probe_table(build);
 
@@ -169,3 +181,76 @@ where
 
     flatten_left_join_ids(result)
 }
+
+/// Left join that builds the hash table on the left relation and probes it with the right
+/// relation, which is faster if the left relation is much smaller.
+///
+/// The matches are grouped by their left index afterwards, so that the output is equal to the
+/// output of a left join that builds on the right relation.
+fn hash_join_tuples_left_build_left<T, IntoSlice>(
+    left: Vec<IntoSlice>,
+    right: Vec<IntoSlice>,
+    chunk_mapping_left: Option<&[ChunkId]>,
+    chunk_mapping_right: Option<&[ChunkId]>,
+) -> LeftJoinIds
+where
+    IntoSlice: AsRef<[T]> + Send + Sync,
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+{
+    let n_left = left.iter().map(|keys| keys.as_ref().len()).sum::<usize>();
+    let hash_tbls = create_probe_table(left);
+    let n_tables = hash_tbls.len() as u64;
+    let offsets = probe_to_offsets(&right);
+
+    // the (left, right) matches of every thread, in the order of the right relation
+    let matches: Vec<Vec<(IdxSize, IdxSize)>> = POOL.install(|| {
+        right
+            .into_par_iter()
+            .zip(offsets)
+            .map(|(probe, offset)| {
+                let mut results = vec![];
+                probe_inner(
+                    probe.as_ref(),
+                    &hash_tbls,
+                    &mut results,
+                    offset as IdxSize,
+                    n_tables,
+                    |idx_right, idx_left| (idx_left, idx_right),
+                );
+                results
+            })
+            .collect()
+    });
+
+    // every left row gets an output row per match, or a single row without a right side
+    let mut counts = vec![0usize; n_left];
+    for &(idx_left, _) in matches.iter().flatten() {
+        counts[idx_left as usize] += 1;
+    }
+    let len = counts.iter().map(|&count| std::cmp::max(count, 1)).sum();
+    let mut result_idx_left = Vec::with_capacity(len);
+    let mut result_idx_right = vec![None; len];
+
+    // turn the counts into the offsets to write the matches of every left row to
+    let mut offset = 0;
+    for (idx_left, count) in counts.iter_mut().enumerate() {
+        let n_rows = std::cmp::max(*count, 1);
+        result_idx_left.extend(std::iter::repeat(idx_left as IdxSize).take(n_rows));
+        *count = offset;
+        offset += n_rows;
+    }
+    // the matches are visited in the order of the right relation,
+    // so the right indexes of every left row stay ascending
+    for (idx_left, idx_right) in matches.into_iter().flatten() {
+        let offset = &mut counts[idx_left as usize];
+        result_idx_right[*offset] = Some(idx_right);
+        *offset += 1;
+    }
+
+    finish_left_join_mappings(
+        result_idx_left,
+        result_idx_right,
+        chunk_mapping_left,
+        chunk_mapping_right,
+    )
+}
@@ -178,8 +178,9 @@ pub fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
     _verbose: bool,
+    build_side: JoinBuildSide,
 ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
-    s_left.hash_join_inner(s_right)
+    s_left.hash_join_inner(s_right, build_side)
 }
 
 #[cfg(feature = "performant")]
@@ -187,6 +188,7 @@ pub fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
     verbose: bool,
+    build_side: JoinBuildSide,
 ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
@@ -260,17 +262,27 @@ pub fn _sort_or_hash_inner(
             // set sorted to `false` as we descending sorted the left key.
             ((left, right), false)
         }
-        _ => s_left.hash_join_inner(s_right),
+        _ => s_left.hash_join_inner(s_right, build_side),
     }
 }
 
 #[cfg(not(feature = "performant"))]
-pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, _verbose: bool) -> LeftJoinIds {
-    s_left.hash_join_left(s_right)
+pub(super) fn sort_or_hash_left(
+    s_left: &Series,
+    s_right: &Series,
+    _verbose: bool,
+    build_side: JoinBuildSide,
+) -> LeftJoinIds {
+    s_left.hash_join_left(s_right, build_side)
 }
 
 #[cfg(feature = "performant")]
-pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, verbose: bool) -> LeftJoinIds {
+pub(super) fn sort_or_hash_left(
+    s_left: &Series,
+    s_right: &Series,
+    verbose: bool,
+    build_side: JoinBuildSide,
+) -> LeftJoinIds {
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
     let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
         .map(|s| s.parse::<f32>().unwrap())
@@ -315,6 +327,6 @@ pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, verbose: bool
             to_left_join_ids(left, right)
         }
         // don't reverse sort a left join key yet. Have to figure out how to set sorted flag
-        _ => s_left.hash_join_left(s_right),
+        _ => s_left.hash_join_left(s_right, build_side),
     }
 }
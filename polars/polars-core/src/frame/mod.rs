@@ -9,27 +9,32 @@ use rayon::prelude::*;
 
 use crate::chunked_array::ops::unique::is_unique_helper;
 use crate::prelude::*;
-#[cfg(feature = "describe")]
-use crate::utils::concat_df_unchecked;
-use crate::utils::{slice_offsets, split_ca, split_df, try_get_supertype, NoNull};
+use crate::utils::{_check_idx_len, slice_offsets, split_ca, split_df, try_get_supertype, NoNull};
 
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 #[cfg(feature = "asof_join")]
 pub(crate) mod asof_join;
 mod chunks;
+mod contiguous;
 #[cfg(feature = "cross_join")]
 pub(crate) mod cross_join;
 pub mod explode;
+pub(crate) mod ffi;
 mod from;
 pub mod groupby;
 pub mod hash_join;
+#[cfg(feature = "partition_by")]
+mod partition;
 #[cfg(feature = "rows")]
 pub mod row;
 mod top_k;
 mod upstream_traits;
 
 pub use chunks::*;
+pub use contiguous::BufferLayout;
+#[cfg(feature = "partition_by")]
+pub use partition::PartitionIter;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -37,7 +42,7 @@ use smartstring::alias::String as SmartString;
 use crate::frame::groupby::GroupsIndicator;
 #[cfg(feature = "row_hash")]
 use crate::hashing::df_rows_to_hashes_threaded_vertical;
-use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
+use crate::prelude::sort::argsort_multiple_row_fmt;
 use crate::series::IsSorted;
 use crate::POOL;
 
@@ -55,13 +60,91 @@ pub enum UniqueKeepStrategy {
     First,
     /// Keep the last unique row.
     Last,
-    /// Keep None of the unique rows.
+    /// Keep none of the rows whose key occurs more than once.
     None,
     /// Keep any of the unique rows
     /// This allows more optimizations
     Any,
 }
 
+/// How [`DataFrame::unnest_with`] names the fields that have the same name as another column of
+/// the output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnnestCollision {
+    /// Raise an error that lists the duplicate names.
+    #[default]
+    Error,
+    /// Prefix the colliding fields with the name of their struct column, as `"{struct}.{field}"`.
+    Prefix,
+    /// Keep the first column with a name and drop the later ones.
+    KeepFirst,
+}
+
+/// Resolve the output names of an unnest. `columns` yields the name of every output column
+/// together with the name of the struct column it is a field of, or `None` if it is not
+/// unnested.
+///
+/// Returns the name of every column, or `None` if the column is dropped.
+pub fn _unnest_names<'a, I>(
+    columns: I,
+    collision: UnnestCollision,
+) -> PolarsResult<Vec<Option<SmartString>>>
+where
+    I: IntoIterator<Item = (Option<&'a str>, &'a str)>,
+{
+    fn check_duplicates<'b>(names: impl Iterator<Item = &'b str>) -> PolarsResult<()> {
+        let mut seen = PlHashSet::new();
+        let mut duplicates = vec![];
+        for name in names {
+            if !seen.insert(name) && !duplicates.contains(&name) {
+                duplicates.push(name)
+            }
+        }
+        polars_ensure!(
+            duplicates.is_empty(),
+            Duplicate: "unnest would give duplicate column names: {:?}; \
+            use another `UnnestCollision` to resolve them", duplicates;
+            columns = &duplicates
+        );
+        Ok(())
+    }
+
+    let columns = columns.into_iter().collect::<Vec<_>>();
+    match collision {
+        UnnestCollision::Error => {
+            check_duplicates(columns.iter().map(|(_, name)| *name))?;
+            Ok(columns
+                .iter()
+                .map(|(_, name)| Some((*name).into()))
+                .collect())
+        }
+        UnnestCollision::Prefix => {
+            let mut counts = PlHashMap::with_capacity(columns.len());
+            for (_, name) in &columns {
+                *counts.entry(*name).or_insert(0usize) += 1;
+            }
+            let names = columns
+                .iter()
+                .map(|(parent, name)| match parent {
+                    Some(parent) if counts[name] > 1 => format!("{parent}.{name}").into(),
+                    _ => SmartString::from(*name),
+                })
+                .collect::<Vec<_>>();
+            // a prefixed name can still collide with a column that has that name
+            check_duplicates(names.iter().map(|name| name.as_str()))?;
+            Ok(names.into_iter().map(Some).collect())
+        }
+        UnnestCollision::KeepFirst => {
+            let mut seen = PlHashSet::with_capacity(columns.len());
+            Ok(columns
+                .iter()
+                .map(|(_, name)| seen.insert(*name).then(|| (*name).into()))
+                .collect())
+        }
+    }
+}
+
 /// A contiguous growable collection of `Series` that have the same length.
 ///
 /// ## Use declarations
@@ -142,6 +225,25 @@ pub struct DataFrame {
     pub(crate) columns: Vec<Series>,
 }
 
+/// Whether the row counts `offset..offset + len` all fit below `max`.
+fn row_count_fits(offset: u64, len: usize, max: u64) -> bool {
+    // the exclusive end of the range may overflow, the last row count may not
+    len == 0 || (offset <= max && (len - 1) as u64 <= max - offset)
+}
+
+/// A sorted [`IDX_DTYPE`] column counting `len` rows from `offset`.
+fn row_count(name: &str, offset: Option<IdxSize>, len: usize) -> PolarsResult<Series> {
+    let offset = offset.unwrap_or(0);
+    polars_ensure!(
+        row_count_fits(offset as u64, len, IdxSize::MAX as u64),
+        ComputeError: "row count with offset {} overflows {} for {} rows; use a UInt64 row count",
+        offset, IDX_DTYPE, len
+    );
+    let mut ca = IdxCa::from_vec(name, (0..len).map(|i| offset + i as IdxSize).collect());
+    ca.set_sorted_flag(IsSorted::Ascending);
+    Ok(ca.into_series())
+}
+
 impl DataFrame {
     /// Returns an estimation of the total (heap) allocated size of the `DataFrame` in bytes.
     ///
@@ -155,8 +257,14 @@ impl DataFrame {
     /// the visible size of the buffer, not its total capacity.
     ///
     /// FFI buffers are included in this estimation.
+    ///
+    /// A categorical rev-map that is shared between columns is included once.
     pub fn estimated_size(&self) -> usize {
-        self.columns.iter().map(|s| s.estimated_size()).sum()
+        let mut counted_rev_maps = PlHashSet::new();
+        self.columns
+            .iter()
+            .map(|s| s.estimated_size_impl(&mut counted_rev_maps))
+            .sum()
     }
 
     // reduce monomorphization
@@ -188,7 +296,7 @@ impl DataFrame {
     /// Get the index of the column.
     fn check_name_to_idx(&self, name: &str) -> PolarsResult<usize> {
         self.find_idx_by_name(name)
-            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))
+            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name; column = name))
     }
 
     fn check_already_present(&self, name: &str) -> PolarsResult<()> {
@@ -375,30 +483,56 @@ impl DataFrame {
     /// ```
     pub fn with_row_count(&self, name: &str, offset: Option<IdxSize>) -> PolarsResult<Self> {
         let mut columns = Vec::with_capacity(self.columns.len() + 1);
-        let offset = offset.unwrap_or(0);
+        columns.push(row_count(name, offset, self.height())?);
+        columns.extend_from_slice(&self.columns);
+        DataFrame::new(columns)
+    }
 
-        let mut ca = IdxCa::from_vec(
+    /// Add a new column at index 0 that counts the rows, of dtype `UInt32` or `UInt64`. Use
+    /// `UInt64` when the row count may not fit in [`IDX_DTYPE`].
+    ///
+    /// Errors if the last row count does not fit in `dtype`.
+    pub fn with_row_count_and_dtype(
+        &self,
+        name: &str,
+        offset: Option<u64>,
+        dtype: &DataType,
+    ) -> PolarsResult<Self> {
+        let offset = offset.unwrap_or(0);
+        let max = match dtype {
+            DataType::UInt32 => u32::MAX as u64,
+            DataType::UInt64 => u64::MAX,
+            dt => polars_bail!(InvalidOperation: "row count cannot be of dtype {}", dt),
+        };
+        polars_ensure!(
+            row_count_fits(offset, self.height(), max),
+            ComputeError: "row count with offset {} overflows {} for {} rows",
+            offset, dtype, self.height()
+        );
+        let ca = UInt64Chunked::from_vec(
             name,
-            (offset..(self.height() as IdxSize) + offset).collect(),
+            (0..self.height()).map(|i| offset + i as u64).collect(),
         );
-        ca.set_sorted_flag(IsSorted::Ascending);
-        columns.push(ca.into_series());
+        let mut s = ca.into_series().cast(dtype)?;
+        s.set_sorted_flag(IsSorted::Ascending);
 
+        let mut columns = Vec::with_capacity(self.columns.len() + 1);
+        columns.push(s);
         columns.extend_from_slice(&self.columns);
         DataFrame::new(columns)
     }
 
     /// Add a row count in place.
-    pub fn with_row_count_mut(&mut self, name: &str, offset: Option<IdxSize>) -> &mut Self {
-        let offset = offset.unwrap_or(0);
-        let mut ca = IdxCa::from_vec(
-            name,
-            (offset..(self.height() as IdxSize) + offset).collect(),
-        );
-        ca.set_sorted_flag(IsSorted::Ascending);
-
-        self.columns.insert(0, ca.into_series());
-        self
+    ///
+    /// Errors if the last row count does not fit in [`IdxSize`].
+    pub fn with_row_count_mut(
+        &mut self,
+        name: &str,
+        offset: Option<IdxSize>,
+    ) -> PolarsResult<&mut Self> {
+        let s = row_count(name, offset, self.height())?;
+        self.columns.insert(0, s);
+        Ok(self)
     }
 
     /// Create a new `DataFrame` but does not check the length or duplicate occurrence of the `Series`.
@@ -913,6 +1047,7 @@ impl DataFrame {
             self.columns = other.columns.clone();
             return Ok(self);
         }
+        _check_idx_len(self.height() + other.height())?;
 
         self.columns
             .iter_mut()
@@ -1352,7 +1487,7 @@ impl DataFrame {
     /// Get column index of a `Series` by name.
     pub fn try_find_idx_by_name(&self, name: &str) -> PolarsResult<usize> {
         self.find_idx_by_name(name)
-            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))
+            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name; column = name))
     }
 
     /// Select a single column by name.
@@ -1371,7 +1506,7 @@ impl DataFrame {
     pub fn column(&self, name: &str) -> PolarsResult<&Series> {
         let idx = self
             .find_idx_by_name(name)
-            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))?;
+            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name; column = name))?;
         Ok(self.select_at_idx(idx).unwrap())
     }
 
@@ -1492,7 +1627,7 @@ impl DataFrame {
                 .map(|name| {
                     let idx = *name_to_idx
                         .get(name.as_str())
-                        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))?;
+                        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name; column = name))?;
                     Ok(self
                         .select_at_idx(idx)
                         .unwrap()
@@ -1520,7 +1655,7 @@ impl DataFrame {
                 .map(|name| {
                     let idx = *name_to_idx
                         .get(name.as_str())
-                        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))?;
+                        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name; column = name))?;
                     Ok(self.select_at_idx(idx).unwrap().clone())
                 })
                 .collect::<PolarsResult<Vec<_>>>()?
@@ -1759,7 +1894,7 @@ impl DataFrame {
     /// ```
     pub fn rename(&mut self, column: &str, name: &str) -> PolarsResult<&mut Self> {
         self.select_mut(column)
-            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", column))
+            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", column; column = column))
             .map(|s| s.rename(name))?;
         let unique_names: AHashSet<&str, ahash::RandomState> =
             AHashSet::from_iter(self.columns.iter().map(|s| s.name()));
@@ -1842,14 +1977,10 @@ impl DataFrame {
                 }
                 s.arg_sort(options)
             }
-            _ => {
-                if nulls_last || has_struct || std::env::var("POLARS_ROW_FMT_SORT").is_ok() {
-                    argsort_multiple_row_fmt(&by_column, descending, nulls_last, parallel)?
-                } else {
-                    let (first, by_column, descending) = prepare_arg_sort(by_column, descending)?;
-                    first.arg_sort_multiple(&by_column, &descending)?
-                }
-            }
+            // encode the sort columns to bytes that compare in the requested order, so that
+            // sorting is a single memcmp per comparison instead of a dynamic dispatch per
+            // column on ties
+            _ => argsort_multiple_row_fmt(&by_column, descending, nulls_last, parallel)?,
         };
 
         if let Some((offset, len)) = slice {
@@ -2213,7 +2344,7 @@ impl DataFrame {
     {
         let idx = self
             .find_idx_by_name(column)
-            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", column))?;
+            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", column; column = column))?;
         self.try_apply_at_idx(idx, f)
     }
 
@@ -2431,8 +2562,13 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(col))
     }
 
-    /// Summary statistics for a DataFrame. Only summarizes numeric datatypes at the moment and returns nulls for non numeric datatypes.
-    /// Try in keep output similar to pandas
+    /// Summary statistics for a DataFrame. Try in keep output similar to pandas.
+    ///
+    /// Numeric and boolean columns report all statistics. Utf8 and categorical columns report
+    /// the count, null count, number of unique values and the lexical minimum and maximum.
+    /// Temporal columns report the mean, minimum and maximum in their own dtype, formatted as
+    /// strings. Every statistic is formatted as a string, see [`DataFrame::describe_with`] to keep
+    /// the statistics of numeric columns as floats.
     ///
     /// # Example
     ///
@@ -2444,7 +2580,7 @@ impl DataFrame {
     /// assert_eq!(df1.shape(), (3, 3));
     ///
     /// let df2: DataFrame = df1.describe(None)?;
-    /// assert_eq!(df2.shape(), (9, 4));
+    /// assert_eq!(df2.shape(), (10, 4));
     /// println!("{}", df2);
     /// # Ok::<(), PolarsError>(())
     /// ```
@@ -2452,17 +2588,18 @@ impl DataFrame {
     /// Output:
     ///
     /// ```text
-    /// shape: (8, 4)
+    /// shape: (10, 4)
     /// ┌────────────┬─────────────┬─────────┬────────┐
-    /// │ describe   ┆ categorical ┆ numeric ┆ object │
+    /// │ statistic  ┆ categorical ┆ numeric ┆ object │
     /// │ ---        ┆ ---         ┆ ---     ┆ ---    │
-    /// │ str        ┆ str         ┆ f64     ┆ str    │
+    /// │ str        ┆ str         ┆ str     ┆ str    │
     /// ╞════════════╪═════════════╪═════════╪════════╡
-    /// │ count      ┆ 3           ┆ 3.0     ┆ 3      │
-    /// │ null_count ┆ 0           ┆ 0.0     ┆ 0      │
+    /// │ count      ┆ 3           ┆ 3       ┆ 3      │
+    /// │ null_count ┆ 0           ┆ 0       ┆ 0      │
+    /// │ n_unique   ┆ 3           ┆ null    ┆ 3      │
     /// │ mean       ┆ null        ┆ 2.0     ┆ null   │
     /// │ std        ┆ null        ┆ 1.0     ┆ null   │
-    /// │ min        ┆ d           ┆ 1,0     ┆ a      │
+    /// │ min        ┆ d           ┆ 1.0     ┆ a      │
     /// │ 25%        ┆ null        ┆ 1.5     ┆ null   │
     /// │ 50%        ┆ null        ┆ 2.0     ┆ null   │
     /// │ 75%        ┆ null        ┆ 2.5     ┆ null   │
@@ -2471,29 +2608,26 @@ impl DataFrame {
     /// ```
     #[cfg(feature = "describe")]
     pub fn describe(&self, percentiles: Option<&[f64]>) -> PolarsResult<Self> {
-        fn describe_cast(df: &DataFrame, original_schema: &Schema) -> PolarsResult<DataFrame> {
-            let columns = df
-                .columns
-                .iter()
-                .zip(original_schema.iter_dtypes())
-                .map(|(s, original_dt)| {
-                    if original_dt.is_numeric() | matches!(original_dt, DataType::Boolean) {
-                        s.cast(&DataType::Float64)
-                    }
-                    // for dates, strings, etc, we cast to string so that all
-                    // statistics can be shown
-                    else {
-                        s.cast(&DataType::Utf8)
-                    }
-                })
-                .collect::<PolarsResult<Vec<Series>>>()?;
-
-            DataFrame::new(columns)
-        }
+        self.describe_with(percentiles, false)
+    }
 
-        fn count(df: &DataFrame) -> DataFrame {
-            let columns = df.apply_columns_par(&|s| Series::new(s.name(), [s.len() as IdxSize]));
-            DataFrame::new_no_checks(columns)
+    /// Summary statistics for a DataFrame, see [`DataFrame::describe`].
+    ///
+    /// If `keep_native` is set, the statistics of numeric and boolean columns are `Float64`
+    /// instead of strings.
+    #[cfg(feature = "describe")]
+    pub fn describe_with(
+        &self,
+        percentiles: Option<&[f64]>,
+        keep_native: bool,
+    ) -> PolarsResult<Self> {
+        /// Format the single value of `s`, which shows temporal values in their own dtype.
+        fn format_value(s: Series) -> PolarsResult<Series> {
+            let value = match s.get(0)? {
+                AnyValue::Null => None,
+                av => Some(av.to_string()),
+            };
+            Ok(Series::new(s.name(), [value]))
         }
 
         let percentiles = percentiles.unwrap_or(&[0.25, 0.5, 0.75]);
@@ -2501,36 +2635,83 @@ impl DataFrame {
         let mut headers: Vec<String> = vec![
             "count".to_string(),
             "null_count".to_string(),
+            "n_unique".to_string(),
             "mean".to_string(),
             "std".to_string(),
             "min".to_string(),
         ];
-
-        let original_schema = self.schema();
-
-        let mut tmp: Vec<DataFrame> = vec![
-            describe_cast(&count(self), &original_schema)?,
-            describe_cast(&self.null_count(), &original_schema)?,
-            describe_cast(&self.mean(), &original_schema)?,
-            describe_cast(&self.std(1), &original_schema)?,
-            describe_cast(&self.min(), &original_schema)?,
-        ];
-
         for p in percentiles {
-            tmp.push(describe_cast(
-                &self.quantile(*p, QuantileInterpolOptions::Linear)?,
-                &original_schema,
-            )?);
             headers.push(format!("{}%", *p * 100.0));
         }
-
         // Keep order same as pandas
-        tmp.push(describe_cast(&self.max(), &original_schema)?);
         headers.push("max".to_string());
 
-        let mut summary = concat_df_unchecked(&tmp);
+        let columns = self
+            .columns
+            .iter()
+            .map(|s| {
+                let dtype = s.dtype();
+                let is_numeric = dtype.is_numeric() || matches!(dtype, DataType::Boolean);
+                let is_lexical = match dtype {
+                    DataType::Utf8 => true,
+                    #[cfg(feature = "dtype-categorical")]
+                    DataType::Categorical(_, _) => true,
+                    _ => false,
+                };
+                let out_dtype = if keep_native && is_numeric {
+                    DataType::Float64
+                } else {
+                    DataType::Utf8
+                };
+                let null = || Series::full_null(s.name(), 1, &out_dtype);
+                let null_percentiles = || percentiles.iter().map(|_| null());
+
+                let mut stats = Vec::with_capacity(headers.len());
+                stats.push(Series::new(s.name(), [s.len() as IdxSize]));
+                stats.push(Series::new(s.name(), [s.null_count() as IdxSize]));
+                if is_numeric {
+                    let s = s.cast(&DataType::Float64)?;
+                    stats.push(null());
+                    stats.push(s.mean_as_series());
+                    stats.push(s.std_as_series(1));
+                    stats.push(s.min_as_series());
+                    for p in percentiles {
+                        stats.push(s.quantile_as_series(*p, QuantileInterpolOptions::Linear)?);
+                    }
+                    stats.push(s.max_as_series());
+                } else if is_lexical {
+                    // categoricals are compared by their strings, not by their physical values
+                    let s = s.cast(&DataType::Utf8)?;
+                    stats.push(Series::new(s.name(), [s.n_unique()? as IdxSize]));
+                    stats.extend([null(), null()]);
+                    stats.push(s.min_as_series());
+                    stats.extend(null_percentiles());
+                    stats.push(s.max_as_series());
+                } else if dtype.is_temporal() {
+                    let mean = s.to_physical_repr().mean().map(|v| v.round() as i64);
+                    let mean = Series::new(s.name(), [mean])
+                        .cast(&dtype.to_physical())?
+                        .cast(dtype)?;
+                    stats.push(null());
+                    stats.push(format_value(mean)?);
+                    stats.push(null());
+                    stats.push(format_value(s.min_as_series())?);
+                    stats.extend(null_percentiles());
+                    stats.push(format_value(s.max_as_series())?);
+                } else {
+                    stats.extend((2..headers.len()).map(|_| null()));
+                }
+
+                let mut out = stats[0].cast(&out_dtype)?;
+                for stat in &stats[1..] {
+                    out.append(&stat.cast(&out_dtype)?)?;
+                }
+                Ok(out)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
 
-        summary.insert_at_idx(0, Series::new("describe", headers))?;
+        let mut summary = DataFrame::new_no_checks(columns);
+        summary.insert_at_idx(0, Series::new("statistic", headers))?;
 
         Ok(summary)
     }
@@ -2682,7 +2863,7 @@ impl DataFrame {
     ///                          "Die n°2" => &[3, 2, 3, 5, 3])?;
     /// assert_eq!(df1.shape(), (5, 2));
     ///
-    /// let df2: DataFrame = df1.sum();
+    /// let df2: DataFrame = df1.sum()?;
     /// assert_eq!(df2.shape(), (1, 2));
     /// println!("{}", df2);
     /// # Ok::<(), PolarsError>(())
@@ -2700,10 +2881,9 @@ impl DataFrame {
     /// | 16      | 16      |
     /// +---------+---------+
     /// ```
-    #[must_use]
-    pub fn sum(&self) -> Self {
-        let columns = self.apply_columns_par(&|s| s.sum_as_series());
-        DataFrame::new_no_checks(columns)
+    pub fn sum(&self) -> PolarsResult<Self> {
+        let columns = self.try_apply_columns_par(&|s| s.sum_as_series())?;
+        Ok(DataFrame::new_no_checks(columns))
     }
 
     /// Aggregate the columns to their mean values.
@@ -3063,13 +3243,14 @@ impl DataFrame {
                 df.apply_columns_par(&|s| unsafe { s.agg_last(&groups) })
             }
             (UniqueKeepStrategy::None, _) => {
+                // the mask is computed from the group counts, which keeps the order of the rows
                 let df_part = df.select(names)?;
                 let mask = df_part.is_unique()?;
-                let mask = match slice {
-                    None => mask,
-                    Some((offset, len)) => mask.slice(offset, len),
-                };
-                return df.filter(&mask);
+                let out = df.filter(&mask)?;
+                return Ok(match slice {
+                    None => out,
+                    Some((offset, len)) => out.slice(offset, len),
+                });
             }
         };
         Ok(DataFrame::new_no_checks(columns))
@@ -3261,12 +3442,18 @@ impl DataFrame {
         &self,
         cols: &[String],
         stable: bool,
+        include_key: bool,
     ) -> PolarsResult<Vec<DataFrame>> {
         let groups = if stable {
             self.groupby_stable(cols)?.take_groups()
         } else {
             self.groupby(cols)?.take_groups()
         };
+        let df = if include_key {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.drop_many(cols))
+        };
 
         // don't parallelize this
         // there is a lot of parallelization in take and this may easily SO
@@ -3277,52 +3464,102 @@ impl DataFrame {
                         .into_par_iter()
                         .map(|(_, group)| {
                             // groups are in bounds
-                            unsafe { self._take_unchecked_slice(&group, false) }
+                            unsafe { df._take_unchecked_slice(&group, false) }
                         })
                         .collect())
                 }
                 GroupsProxy::Slice { groups, .. } => Ok(groups
                     .into_par_iter()
-                    .map(|[first, len]| self.slice(first as i64, len as usize))
+                    .map(|[first, len]| df.slice(first as i64, len as usize))
                     .collect()),
             }
         })
     }
 
     /// Split into multiple DataFrames partitioned by groups
+    ///
+    /// If `maintain_order` is set, the partitions are in the order of their first row. If
+    /// `include_key` is not set, the partitions don't contain the columns they are partitioned by.
     #[cfg(feature = "partition_by")]
-    pub fn partition_by(&self, cols: impl IntoVec<String>) -> PolarsResult<Vec<DataFrame>> {
+    pub fn partition_by(
+        &self,
+        cols: impl IntoVec<String>,
+        maintain_order: bool,
+        include_key: bool,
+    ) -> PolarsResult<Vec<DataFrame>> {
         let cols = cols.into_vec();
-        self._partition_by_impl(&cols, false)
+        self._partition_by_impl(&cols, maintain_order, include_key)
     }
 
     /// Split into multiple DataFrames partitioned by groups
     /// Order of the groups are maintained.
     #[cfg(feature = "partition_by")]
-    pub fn partition_by_stable(&self, cols: impl IntoVec<String>) -> PolarsResult<Vec<DataFrame>> {
+    pub fn partition_by_stable(
+        &self,
+        cols: impl IntoVec<String>,
+        include_key: bool,
+    ) -> PolarsResult<Vec<DataFrame>> {
         let cols = cols.into_vec();
-        self._partition_by_impl(&cols, true)
+        self._partition_by_impl(&cols, true, include_key)
+    }
+
+    /// Split into DataFrames partitioned by groups, which are gathered one at a time when the
+    /// iterator is advanced. This only holds a single partition in memory, next to the group
+    /// indices.
+    ///
+    /// The iterator yields the key of every partition with the partition. The partitions are in
+    /// the order of their first row, or in the order of their keys if `sorted` is set.
+    #[cfg(feature = "partition_by")]
+    pub fn partition_by_iter(
+        &self,
+        cols: impl IntoVec<String>,
+        include_key: bool,
+        sorted: bool,
+    ) -> PolarsResult<PartitionIter<'_>> {
+        let cols = cols.into_vec();
+        PartitionIter::new(self, &cols, include_key, sorted)
     }
 
     /// Unnest the given `Struct` columns. This means that the fields of the `Struct` type will be
     /// inserted as columns.
+    ///
+    /// Fails if a field has the same name as another column of the output, see
+    /// [`DataFrame::unnest_with`] to resolve these.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoVec<String>>(&self, cols: I) -> PolarsResult<DataFrame> {
+        self.unnest_with(cols, UnnestCollision::Error)
+    }
+
+    /// Unnest the given `Struct` columns and name the fields that collide with another column of
+    /// the output by `collision`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with<I: IntoVec<String>>(
+        &self,
+        cols: I,
+        collision: UnnestCollision,
+    ) -> PolarsResult<DataFrame> {
         let cols = cols.into_vec();
-        self.unnest_impl(cols.into_iter().collect())
+        self.unnest_impl(cols.into_iter().collect(), collision)
     }
 
     #[cfg(feature = "dtype-struct")]
-    fn unnest_impl(&self, cols: PlHashSet<String>) -> PolarsResult<DataFrame> {
+    fn unnest_impl(
+        &self,
+        cols: PlHashSet<String>,
+        collision: UnnestCollision,
+    ) -> PolarsResult<DataFrame> {
         let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
+        let mut parents = Vec::with_capacity(new_cols.capacity());
         let mut count = 0;
         for s in &self.columns {
             if cols.contains(s.name()) {
                 let ca = s.struct_()?;
                 new_cols.extend_from_slice(ca.fields());
+                parents.extend(std::iter::repeat(Some(s.name())).take(ca.fields().len()));
                 count += 1;
             } else {
-                new_cols.push(s.clone())
+                new_cols.push(s.clone());
+                parents.push(None);
             }
         }
         if count != cols.len() {
@@ -3332,9 +3569,26 @@ impl DataFrame {
             for col in cols {
                 let _ = schema
                     .get(&col)
-                    .ok_or_else(|| polars_err!(ColumnNotFound: "{}", col))?;
+                    .ok_or_else(|| polars_err!(ColumnNotFound: "{}", col; column = col))?;
             }
         }
+        let names = _unnest_names(
+            parents.into_iter().zip(new_cols.iter().map(|s| s.name())),
+            collision,
+        )?;
+        let new_cols = new_cols
+            .iter()
+            .zip(names)
+            .filter_map(|(s, name)| {
+                name.map(|name| {
+                    let mut s = s.clone();
+                    if s.name() != name.as_str() {
+                        s.rename(&name);
+                    }
+                    s
+                })
+            })
+            .collect();
         DataFrame::new(new_cols)
     }
 }
@@ -3407,12 +3661,14 @@ fn ensure_can_extend(left: &Series, right: &Series) -> PolarsResult<()> {
     polars_ensure!(
         left.name() == right.name(),
         ShapeMismatch: "unable to vstack, column names don't match: {:?} and {:?}",
-        left.name(), right.name(),
+        left.name(), right.name();
+        columns = [left.name(), right.name()], operation = "vstack"
     );
     polars_ensure!(
         left.dtype() == right.dtype(),
         ShapeMismatch: "unable to vstack, dtypes for column {:?} don't match: `{}` and `{}`",
-        left.name(), left.dtype(), right.dtype(),
+        left.name(), left.dtype(), right.dtype();
+        column = left.name(), operation = "vstack"
     );
     Ok(())
 }
@@ -3542,6 +3798,35 @@ mod test {
         assert!(df.frame_equal(&valid));
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_unique_keep_none() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1, 2, 1, 3, 4, 4],
+            "b" => ["x", "y", "z", "x", "y", "y"]
+        }?;
+
+        let out = df.unique_stable(None, UniqueKeepStrategy::None, None)?;
+        let expected = df! {
+            "a" => [1, 2, 1, 3],
+            "b" => ["x", "y", "z", "x"]
+        }?;
+        assert!(out.frame_equal(&expected));
+
+        let subset = ["a".to_string()];
+        let out = df.unique(Some(&subset), UniqueKeepStrategy::None, None)?;
+        let expected = df! {
+            "a" => [2, 3],
+            "b" => ["y", "x"]
+        }?;
+        assert!(out.frame_equal(&expected));
+
+        // the slice applies to the rows that are kept
+        let out = df.unique_stable(Some(&subset), UniqueKeepStrategy::None, Some((1, 5)))?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3)]);
+        Ok(())
+    }
+
     #[test]
     fn test_vstack() {
         // check that it does not accidentally rechunks
@@ -3556,6 +3841,18 @@ mod test {
         assert_eq!(df.n_chunks(), 2)
     }
 
+    #[test]
+    fn test_vstack_idx_overflow() -> PolarsResult<()> {
+        // the tests use a small maximum length
+        let df = df!("a" => vec![1i32; 40_000])?;
+        let err = df.vstack(&df).unwrap_err();
+        assert!(err.to_string().contains("80000 rows"));
+        let mut s = df.column("a")?.clone();
+        assert!(s.append(&df.column("a")?.clone()).is_err());
+        assert_eq!(s.len(), 40_000);
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "zip_with")]
     #[cfg_attr(miri, ignore)]
@@ -3641,17 +3938,118 @@ mod test {
 
         let df2: DataFrame = df1.describe(None)?;
 
-        assert_eq!(df2.shape(), (9, 4));
+        assert_eq!(df2.shape(), (10, 4));
 
         let expected = df!(
-            "describe" => ["count", "null_count", "mean", "std", "min", "25%", "50%", "75%", "max"],
-            "categorical" => [Some("3"), Some("0"), None, None, Some("d"), None, None, None, Some("f")],
-            "numeric" => [3.0, 0.0, 2.0, 1.0, 1.0, 1.5, 2.0, 2.5, 3.0],
-            "object" => [Some("3"), Some("0"), None, None, Some("a"), None, None, None, Some("c")],
+            "statistic" => ["count", "null_count", "n_unique", "mean", "std", "min", "25%", "50%", "75%", "max"],
+            "categorical" => [Some("3"), Some("0"), Some("3"), None, None, Some("d"), None, None, None, Some("f")],
+            "numeric" => [Some("3"), Some("0"), None, Some("2.0"), Some("1.0"), Some("1.0"), Some("1.5"), Some("2.0"), Some("2.5"), Some("3.0")],
+            "object" => [Some("3"), Some("0"), Some("3"), None, None, Some("a"), None, None, None, Some("c")],
         ).unwrap();
 
         assert_eq!(df2, expected);
 
+        let df2 = df1.select(["numeric"])?.describe_with(Some(&[0.5]), true)?;
+        let expected = df!(
+            "statistic" => ["count", "null_count", "n_unique", "mean", "std", "min", "50%", "max"],
+            "numeric" => [Some(3.0), Some(0.0), None, Some(2.0), Some(1.0), Some(1.0), Some(2.0), Some(3.0)],
+        )?;
+        assert!(df2.frame_equal_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "describe", feature = "dtype-date"))]
+    fn test_df_describe_temporal() -> PolarsResult<()> {
+        // 2021-01-01, 2021-01-03 and a null
+        let date = Int32Chunked::new("date", &[Some(18628), Some(18630), None]).into_date();
+        let df = DataFrame::new(vec![date.into_series()])?;
+
+        let out = df.describe(Some(&[]))?;
+        let expected = df!(
+            "statistic" => ["count", "null_count", "n_unique", "mean", "std", "min", "max"],
+            "date" => [Some("3"), Some("1"), None, Some("2021-01-02"), None, Some("2021-01-01"), Some("2021-01-03")],
+        )?;
+        assert!(out.frame_equal_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_row_count_overflow() -> PolarsResult<()> {
+        let df = df!["a" => [1, 2, 3]]?;
+        let out = df.with_row_count("rc", Some(IdxSize::MAX - 2))?;
+        assert_eq!(out.column("rc")?.idx()?.get(2), Some(IdxSize::MAX));
+        assert!(df.with_row_count("rc", Some(IdxSize::MAX - 1)).is_err());
+
+        let offset = u32::MAX as u64;
+        let out = df.with_row_count_and_dtype("rc", Some(offset), &DataType::UInt64)?;
+        assert_eq!(
+            Vec::from(out.column("rc")?.u64()?),
+            &[Some(offset), Some(offset + 1), Some(offset + 2)]
+        );
+        assert!(df
+            .with_row_count_and_dtype("rc", Some(offset), &DataType::UInt32)
+            .is_err());
+        assert!(df
+            .with_row_count_and_dtype("rc", None, &DataType::Int64)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_context() -> PolarsResult<()> {
+        use crate::error::ErrorKind;
+
+        let df = create_frame();
+        let err = df.column("missing").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ColumnNotFound);
+        assert_eq!(err.columns(), &["missing"]);
+
+        let other = df!["days" => [1.0], "temp" => [1.0]]?;
+        let err = df.vstack(&other).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ShapeMismatch);
+        assert_eq!(err.columns(), &["days"]);
+        assert_eq!(err.operation(), Some("vstack"));
+
+        // context survives wrapping the message, and IO errors keep their variant until their
+        // message is wrapped
+        let err = err.wrap_msg(&|msg| format!("in query: {msg}"));
+        assert_eq!(err.operation(), Some("vstack"));
+        let err = PolarsError::from(std::io::Error::from(std::io::ErrorKind::NotFound))
+            .with_operation("read_csv");
+        assert!(matches!(err, PolarsError::Io(_)));
+        assert_eq!(err.kind(), ErrorKind::Io);
+        assert_eq!(err.kind().code(), "io");
+        assert_eq!(err.operation(), Some("read_csv"));
+        let err = err.wrap_msg(&|msg| format!("in query: {msg}"));
+        assert_eq!(err.kind(), ErrorKind::Io);
+        assert_eq!(err.operation(), Some("read_csv"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_unnest_collision() -> PolarsResult<()> {
+        let a = StructChunked::new("a", &[Series::new("x", [1, 2]), Series::new("y", [3, 4])])?;
+        let b = StructChunked::new("b", &[Series::new("x", [5, 6]), Series::new("z", [7, 8])])?;
+        let df = DataFrame::new(vec![
+            Series::new("y", [0, 0]),
+            a.into_series(),
+            b.into_series(),
+        ])?;
+
+        let err = df.unnest(["a", "b"]).unwrap_err();
+        assert_eq!(err.columns(), &["y", "x"]);
+
+        let out = df.unnest_with(["a", "b"], UnnestCollision::Prefix)?;
+        assert_eq!(out.get_column_names(), &["y", "a.x", "a.y", "b.x", "z"]);
+        assert_eq!(Vec::from(out.column("b.x")?.i32()?), &[Some(5), Some(6)]);
+
+        let out = df.unnest_with(["a", "b"], UnnestCollision::KeepFirst)?;
+        assert_eq!(out.get_column_names(), &["y", "x", "z"]);
+        assert_eq!(Vec::from(out.column("y")?.i32()?), &[Some(0), Some(0)]);
+        assert_eq!(Vec::from(out.column("x")?.i32()?), &[Some(1), Some(2)]);
         Ok(())
     }
 }
@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+
+use crate::frame::groupby::GroupsIndicator;
+use crate::prelude::*;
+
+/// An iterator over the partitions of a `DataFrame`, see [`DataFrame::partition_by_iter`].
+pub struct PartitionIter<'a> {
+    keys: Vec<&'a Series>,
+    // the frame the partitions are gathered from, without the keys if they are not included
+    values: Cow<'a, DataFrame>,
+    groups: GroupsProxy,
+    // the order of the groups if they are sorted by their keys
+    order: Option<Vec<IdxSize>>,
+    position: usize,
+}
+
+impl<'a> PartitionIter<'a> {
+    pub(super) fn new(
+        df: &'a DataFrame,
+        cols: &[String],
+        include_key: bool,
+        sorted: bool,
+    ) -> PolarsResult<Self> {
+        let keys = cols
+            .iter()
+            .map(|name| df.column(name))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let groups = df.groupby_stable(cols)?.take_groups();
+
+        let order = if sorted && !groups.is_empty() {
+            let firsts = IdxCa::from_vec("", groups.iter().map(|g| g.first()).collect());
+            let group_keys = keys
+                .iter()
+                .map(|s| s.take(&firsts))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let order = if group_keys.len() == 1 {
+                group_keys[0].arg_sort(SortOptions::default())
+            } else {
+                let descending = vec![false; group_keys.len()];
+                group_keys[0].arg_sort_multiple(&group_keys[1..], &descending)?
+            };
+            Some(order.into_no_null_iter().collect())
+        } else {
+            None
+        };
+
+        let values = if include_key {
+            Cow::Borrowed(df)
+        } else {
+            Cow::Owned(df.drop_many(cols))
+        };
+        Ok(Self {
+            keys,
+            values,
+            groups,
+            order,
+            position: 0,
+        })
+    }
+}
+
+impl<'a> Iterator for PartitionIter<'a> {
+    type Item = (Vec<AnyValue<'a>>, DataFrame);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let group_idx = match &self.order {
+            Some(order) => *order.get(self.position)? as usize,
+            None if self.position < self.groups.len() => self.position,
+            None => return None,
+        };
+        self.position += 1;
+
+        let group = self.groups.get(group_idx);
+        let key = self
+            .keys
+            .iter()
+            .map(|s| s.get(group.first() as usize).unwrap())
+            .collect();
+        let partition = match group {
+            // Safety:
+            // groups are in bounds
+            GroupsIndicator::Idx((_, idx)) => unsafe {
+                self.values._take_unchecked_slice(idx, true)
+            },
+            GroupsIndicator::Slice([first, len]) => self.values.slice(first as i64, len as usize),
+        };
+        Some((key, partition))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.groups.len() - self.position;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for PartitionIter<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_partition_by_iter() -> PolarsResult<()> {
+        let df = df![
+            "a" => ["b", "a", "b", "c", "a"],
+            "b" => [1, 2, 1, 3, 3],
+            "c" => [5, 4, 3, 2, 1]
+        ]?;
+
+        let partitions = df.partition_by_iter(["a"], false, false)?;
+        assert_eq!(partitions.len(), 3);
+        let keys = partitions
+            .map(|(key, partition)| {
+                assert_eq!(partition.get_column_names(), &["b", "c"]);
+                key[0].clone()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            &[
+                AnyValue::Utf8("b"),
+                AnyValue::Utf8("a"),
+                AnyValue::Utf8("c")
+            ]
+        );
+
+        let partitions = df
+            .partition_by_iter(["a", "b"], true, true)?
+            .collect::<Vec<_>>();
+        let keys = partitions
+            .iter()
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            &[
+                vec![AnyValue::Utf8("a"), AnyValue::Int32(2)],
+                vec![AnyValue::Utf8("a"), AnyValue::Int32(3)],
+                vec![AnyValue::Utf8("b"), AnyValue::Int32(1)],
+                vec![AnyValue::Utf8("c"), AnyValue::Int32(3)],
+            ]
+        );
+        let expected = df![
+            "a" => ["b", "b"],
+            "b" => [1, 1],
+            "c" => [5, 3]
+        ]?;
+        assert!(partitions[2].1.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_by_maintain_order() -> PolarsResult<()> {
+        let df = df![
+            "a" => ["b", "a", "b", "c", "a"],
+            "c" => [5, 4, 3, 2, 1]
+        ]?;
+
+        let partitions = df.partition_by(["a"], true, false)?;
+        let firsts = partitions
+            .iter()
+            .map(|partition| {
+                assert_eq!(partition.get_column_names(), &["c"]);
+                partition.column("c").unwrap().get(0).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            firsts,
+            &[AnyValue::Int32(5), AnyValue::Int32(4), AnyValue::Int32(2)]
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 mod av_buffer;
 mod dataframe;
 mod transpose;
+mod typed;
 
 use std::borrow::Borrow;
 use std::fmt::Debug;
@@ -9,6 +10,7 @@ use std::hint::unreachable_unchecked;
 use arrow::bitmap::Bitmap;
 pub use av_buffer::*;
 use rayon::prelude::*;
+pub use typed::*;
 
 use crate::prelude::*;
 use crate::utils::try_get_supertype;
@@ -0,0 +1,382 @@
+//! Typed row access. Every column is read and written through its concrete
+//! `ChunkedArray` iterator or builder, so that no `AnyValue` is created per value.
+use super::*;
+
+/// A value that can be read from a column of a `DataFrame`.
+pub trait FromRowValue<'a>: Sized {
+    /// Whether the column may contain nulls.
+    const NULLABLE: bool;
+
+    /// The dtype of the columns this value can be read from.
+    fn dtype() -> DataType;
+
+    /// Iterate over the values of `s`, which has dtype [`FromRowValue::dtype`] and doesn't contain
+    /// nulls unless the value is [`FromRowValue::NULLABLE`].
+    fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a>;
+}
+
+/// A value that can be written to a column of a `DataFrame`.
+pub trait IntoRowValue {
+    type Builder;
+
+    fn builder(name: &str, capacity: usize) -> Self::Builder;
+
+    fn append(self, builder: &mut Self::Builder);
+
+    fn finish(builder: Self::Builder) -> Series;
+}
+
+macro_rules! impl_row_value {
+    ($native:ty, $polars_type:ty, $dtype:expr, $method:ident) => {
+        impl<'a> FromRowValue<'a> for $native {
+            const NULLABLE: bool = false;
+
+            fn dtype() -> DataType {
+                $dtype
+            }
+
+            fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+                Box::new(s.$method().unwrap().into_no_null_iter())
+            }
+        }
+
+        impl<'a> FromRowValue<'a> for Option<$native> {
+            const NULLABLE: bool = true;
+
+            fn dtype() -> DataType {
+                $dtype
+            }
+
+            fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+                Box::new(s.$method().unwrap().into_iter())
+            }
+        }
+
+        impl IntoRowValue for $native {
+            type Builder = PrimitiveChunkedBuilder<$polars_type>;
+
+            fn builder(name: &str, capacity: usize) -> Self::Builder {
+                PrimitiveChunkedBuilder::new(name, capacity)
+            }
+
+            fn append(self, builder: &mut Self::Builder) {
+                builder.append_value(self)
+            }
+
+            fn finish(builder: Self::Builder) -> Series {
+                builder.finish().into_series()
+            }
+        }
+
+        impl IntoRowValue for Option<$native> {
+            type Builder = PrimitiveChunkedBuilder<$polars_type>;
+
+            fn builder(name: &str, capacity: usize) -> Self::Builder {
+                PrimitiveChunkedBuilder::new(name, capacity)
+            }
+
+            fn append(self, builder: &mut Self::Builder) {
+                builder.append_option(self)
+            }
+
+            fn finish(builder: Self::Builder) -> Series {
+                builder.finish().into_series()
+            }
+        }
+    };
+}
+
+impl_row_value!(i32, Int32Type, DataType::Int32, i32);
+impl_row_value!(i64, Int64Type, DataType::Int64, i64);
+impl_row_value!(u32, UInt32Type, DataType::UInt32, u32);
+impl_row_value!(u64, UInt64Type, DataType::UInt64, u64);
+impl_row_value!(f32, Float32Type, DataType::Float32, f32);
+impl_row_value!(f64, Float64Type, DataType::Float64, f64);
+
+impl<'a> FromRowValue<'a> for bool {
+    const NULLABLE: bool = false;
+
+    fn dtype() -> DataType {
+        DataType::Boolean
+    }
+
+    fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+        Box::new(s.bool().unwrap().into_no_null_iter())
+    }
+}
+
+impl<'a> FromRowValue<'a> for Option<bool> {
+    const NULLABLE: bool = true;
+
+    fn dtype() -> DataType {
+        DataType::Boolean
+    }
+
+    fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+        Box::new(s.bool().unwrap().into_iter())
+    }
+}
+
+impl<'a> FromRowValue<'a> for &'a str {
+    const NULLABLE: bool = false;
+
+    fn dtype() -> DataType {
+        DataType::Utf8
+    }
+
+    fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+        Box::new(s.utf8().unwrap().into_no_null_iter())
+    }
+}
+
+impl<'a> FromRowValue<'a> for Option<&'a str> {
+    const NULLABLE: bool = true;
+
+    fn dtype() -> DataType {
+        DataType::Utf8
+    }
+
+    fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+        Box::new(s.utf8().unwrap().into_iter())
+    }
+}
+
+impl<'a> FromRowValue<'a> for String {
+    const NULLABLE: bool = false;
+
+    fn dtype() -> DataType {
+        DataType::Utf8
+    }
+
+    fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+        Box::new(s.utf8().unwrap().into_no_null_iter().map(String::from))
+    }
+}
+
+impl<'a> FromRowValue<'a> for Option<String> {
+    const NULLABLE: bool = true;
+
+    fn dtype() -> DataType {
+        DataType::Utf8
+    }
+
+    fn iter_column(s: &'a Series) -> Box<dyn Iterator<Item = Self> + 'a> {
+        Box::new(s.utf8().unwrap().into_iter().map(|v| v.map(String::from)))
+    }
+}
+
+impl IntoRowValue for bool {
+    type Builder = BooleanChunkedBuilder;
+
+    fn builder(name: &str, capacity: usize) -> Self::Builder {
+        BooleanChunkedBuilder::new(name, capacity)
+    }
+
+    fn append(self, builder: &mut Self::Builder) {
+        builder.append_value(self)
+    }
+
+    fn finish(builder: Self::Builder) -> Series {
+        builder.finish().into_series()
+    }
+}
+
+impl IntoRowValue for Option<bool> {
+    type Builder = BooleanChunkedBuilder;
+
+    fn builder(name: &str, capacity: usize) -> Self::Builder {
+        BooleanChunkedBuilder::new(name, capacity)
+    }
+
+    fn append(self, builder: &mut Self::Builder) {
+        builder.append_option(self)
+    }
+
+    fn finish(builder: Self::Builder) -> Series {
+        builder.finish().into_series()
+    }
+}
+
+macro_rules! impl_into_row_value_str {
+    ($native:ty, $opt:expr) => {
+        impl IntoRowValue for $native {
+            type Builder = Utf8ChunkedBuilder;
+
+            fn builder(name: &str, capacity: usize) -> Self::Builder {
+                // assume short strings
+                Utf8ChunkedBuilder::new(name, capacity, capacity * 8)
+            }
+
+            fn append(self, builder: &mut Self::Builder) {
+                builder.append_option($opt(self))
+            }
+
+            fn finish(builder: Self::Builder) -> Series {
+                builder.finish().into_series()
+            }
+        }
+    };
+}
+
+impl_into_row_value_str!(&str, Some);
+impl_into_row_value_str!(Option<&str>, |v| v);
+impl_into_row_value_str!(String, Some);
+impl_into_row_value_str!(Option<String>, |v| v);
+
+/// A row type that can be read from a `DataFrame`, see [`DataFrame::rows_iter`].
+pub trait FromDataFrameRow<'a>: Sized {
+    /// Check the schema of `df` once, and iterate over its rows.
+    fn rows_iter(df: &'a DataFrame) -> PolarsResult<Box<dyn Iterator<Item = Self> + 'a>>;
+}
+
+/// A row type that can be written to a `DataFrame`, see [`DataFrame::from_rows_iter`].
+pub trait IntoDataFrameRow: Sized {
+    type Builders;
+
+    fn builders(capacity: usize) -> Self::Builders;
+
+    fn append(self, builders: &mut Self::Builders);
+
+    fn finish(builders: Self::Builders) -> Vec<Series>;
+}
+
+/// Check that the column at `idx` can be read as `T`.
+fn check_column<'a, T: FromRowValue<'a>>(df: &DataFrame, idx: usize) -> PolarsResult<()> {
+    let s = &df.get_columns()[idx];
+    polars_ensure!(
+        s.dtype() == &T::dtype(),
+        SchemaMismatch: "row field {} expects column '{}' to have dtype {}, got {}",
+        idx, s.name(), T::dtype(), s.dtype()
+    );
+    polars_ensure!(
+        T::NULLABLE || s.null_count() == 0,
+        ComputeError: "column '{}' contains nulls, read it as an `Option` instead", s.name()
+    );
+    Ok(())
+}
+
+macro_rules! impl_row_tuple {
+    ($n:expr, $($T:ident $idx:tt),+) => {
+        impl<'a, $($T: FromRowValue<'a> + 'a),+> FromDataFrameRow<'a> for ($($T,)+) {
+            #[allow(non_snake_case)]
+            fn rows_iter(df: &'a DataFrame) -> PolarsResult<Box<dyn Iterator<Item = Self> + 'a>> {
+                polars_ensure!(
+                    df.width() == $n,
+                    ShapeMismatch: "row type has {} fields, but the DataFrame has {} columns",
+                    $n, df.width()
+                );
+                $(check_column::<$T>(df, $idx)?;)+
+                let columns = df.get_columns();
+                $(let mut $T = $T::iter_column(&columns[$idx]);)+
+                Ok(Box::new(std::iter::from_fn(move || {
+                    Some(($($T.next()?,)+))
+                })))
+            }
+        }
+
+        impl<$($T: IntoRowValue),+> IntoDataFrameRow for ($($T,)+) {
+            type Builders = ($($T::Builder,)+);
+
+            fn builders(capacity: usize) -> Self::Builders {
+                ($($T::builder(&format!("column_{}", $idx), capacity),)+)
+            }
+
+            fn append(self, builders: &mut Self::Builders) {
+                $(self.$idx.append(&mut builders.$idx);)+
+            }
+
+            fn finish(builders: Self::Builders) -> Vec<Series> {
+                vec![$($T::finish(builders.$idx)),+]
+            }
+        }
+    };
+}
+
+impl_row_tuple!(1, A 0);
+impl_row_tuple!(2, A 0, B 1);
+impl_row_tuple!(3, A 0, B 1, C 2);
+impl_row_tuple!(4, A 0, B 1, C 2, D 3);
+impl_row_tuple!(5, A 0, B 1, C 2, D 3, E 4);
+impl_row_tuple!(6, A 0, B 1, C 2, D 3, E 4, F 5);
+impl_row_tuple!(7, A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_row_tuple!(8, A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+impl DataFrame {
+    /// Iterate over the rows of this DataFrame as typed tuples.
+    ///
+    /// The column dtypes are checked once before iterating: the `DataFrame` must have a column per
+    /// field of `R`, in order, of the dtype of that field. Columns with nulls must be read as an
+    /// `Option`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1i64, 2], "b" => [Some("x"), None])?;
+    /// let rows = df.rows_iter::<(i64, Option<&str>)>()?.collect::<Vec<_>>();
+    /// assert_eq!(rows, &[(1, Some("x")), (2, None)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn rows_iter<'a, R: FromDataFrameRow<'a>>(
+        &'a self,
+    ) -> PolarsResult<impl Iterator<Item = R> + 'a> {
+        R::rows_iter(self)
+    }
+
+    /// Create a DataFrame from typed rows. Every field is collected with the builder of its type,
+    /// and the columns are named `column_0`, `column_1`, etc.
+    pub fn from_rows_iter<R, I>(rows: I) -> PolarsResult<Self>
+    where
+        R: IntoDataFrameRow,
+        I: IntoIterator<Item = R>,
+    {
+        let rows = rows.into_iter();
+        let mut builders = R::builders(rows.size_hint().0);
+        for row in rows {
+            row.append(&mut builders);
+        }
+        DataFrame::new(R::finish(builders))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typed_rows() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1i64, 2],
+            "b" => [Some("x"), None],
+            "c" => [0.5, 1.5]
+        ]?;
+        df.vstack_mut(&df![
+            "a" => [3i64],
+            "b" => [Some("z")],
+            "c" => [2.5]
+        ]?)?;
+
+        let rows = df
+            .rows_iter::<(i64, Option<&str>, f64)>()?
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            &[(1, Some("x"), 0.5), (2, None, 1.5), (3, Some("z"), 2.5)]
+        );
+
+        // the nulls of "b" need an `Option`
+        assert!(df.rows_iter::<(i64, &str, f64)>().is_err());
+        assert!(df.rows_iter::<(i32, Option<&str>, f64)>().is_err());
+        assert!(df.rows_iter::<(i64, Option<&str>)>().is_err());
+
+        let out = DataFrame::from_rows_iter(rows)?;
+        assert_eq!(
+            out.get_column_names(),
+            &["column_0", "column_1", "column_2"]
+        );
+        let mut expected = df.clone();
+        expected.set_column_names(&["column_0", "column_1", "column_2"])?;
+        assert!(out.frame_equal_missing(&expected));
+        Ok(())
+    }
+}
@@ -12,14 +12,17 @@ use num_traits::{Float, NumCast, ToPrimitive};
 #[cfg(feature = "concat_str")]
 use polars_arrow::prelude::ValueSize;
 
-use crate::chunked_array::ops::sort::prepare_arg_sort;
+use crate::chunked_array::ops::sort::argsort_multiple_row_fmt;
 use crate::prelude::*;
 use crate::utils::coalesce_nulls;
 #[cfg(feature = "diagonal_concat")]
 use crate::utils::concat_df;
 
 /// Compute the covariance between two columns.
-pub fn cov_f<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>) -> Option<T::Native>
+///
+/// Rows where either side is null are excluded and the sum of products is divided by
+/// `n - ddof`, where `n` is the number of remaining rows.
+pub fn cov_f<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>, ddof: u8) -> Option<T::Native>
 where
     T: PolarsFloatType,
     T::Native: Float,
@@ -30,14 +33,22 @@ where
     if a.len() != b.len() {
         None
     } else {
+        let (a, b) = coalesce_nulls(a, b);
+        let (a, b) = (a.as_ref(), b.as_ref());
+        let n = a.len() - a.null_count();
+        if n <= ddof as usize {
+            return None;
+        }
         let tmp = (a - a.mean()?) * (b - b.mean()?);
-        let n = tmp.len() - tmp.null_count();
-        Some(tmp.sum()? / NumCast::from(n - 1).unwrap())
+        Some(tmp.sum()? / NumCast::from(n - ddof as usize).unwrap())
     }
 }
 
 /// Compute the covariance between two columns.
-pub fn cov_i<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>) -> Option<f64>
+///
+/// Rows where either side is null are excluded and the sum of products is divided by
+/// `n - ddof`, where `n` is the number of remaining rows.
+pub fn cov_i<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>, ddof: u8) -> Option<f64>
 where
     T: PolarsIntegerType,
     T::Native: ToPrimitive,
@@ -48,14 +59,19 @@ where
     if a.len() != b.len() {
         None
     } else {
+        let (a, b) = coalesce_nulls(a, b);
+        let (a, b) = (a.as_ref(), b.as_ref());
+        let n = a.len() - a.null_count();
+        if n <= ddof as usize {
+            return None;
+        }
         let a_mean = a.mean()?;
         let b_mean = b.mean()?;
         let a = a.apply_cast_numeric::<_, Float64Type>(|a| a.to_f64().unwrap() - a_mean);
         let b = b.apply_cast_numeric(|b| b.to_f64().unwrap() - b_mean);
 
         let tmp = a * b;
-        let n = tmp.len() - tmp.null_count();
-        Some(tmp.sum()? / (n - 1) as f64)
+        Some(tmp.sum()? / (n - ddof as usize) as f64)
     }
 }
 
@@ -73,7 +89,7 @@ where
     let a = a.as_ref();
     let b = b.as_ref();
 
-    Some(cov_i(a, b)? / (a.std(ddof)? * b.std(ddof)?))
+    Some(cov_i(a, b, ddof)? / (a.std(ddof)? * b.std(ddof)?))
 }
 
 /// Compute the pearson correlation between two columns.
@@ -90,21 +106,37 @@ where
     let a = a.as_ref();
     let b = b.as_ref();
 
-    Some(cov_f(a, b)? / (a.std(ddof)? * b.std(ddof)?))
+    Some(cov_f(a, b, ddof)? / (a.std(ddof)? * b.std(ddof)?))
 }
 
 /// Find the indexes that would sort these series in order of appearance.
 /// That means that the first `Series` will be used to determine the ordering
 /// until duplicates are found. Once duplicates are found, the next `Series` will
 /// be used and so on.
+///
+/// The nulls of ascending columns are placed first and those of descending columns last, as in
+/// [`DataFrame::sort`]. Multiple columns are sorted by their row encoding.
 pub fn arg_sort_by(by: &[Series], descending: &[bool]) -> PolarsResult<IdxCa> {
     polars_ensure!(
         by.len() == descending.len(),
         ComputeError: "the number of ordering booleans: {} does not match the number of series: {}",
         descending.len(), by.len()
     );
-    let (first, by, descending) = prepare_arg_sort(by.to_vec(), descending.to_vec()).unwrap();
-    first.arg_sort_multiple(&by, &descending)
+    polars_ensure!(!by.is_empty(), ComputeError: "`arg_sort_by` expects at least one series");
+    if by.len() == 1 {
+        return Ok(by[0].arg_sort(SortOptions {
+            descending: descending[0],
+            ..Default::default()
+        }));
+    }
+    for s in &by[1..] {
+        polars_ensure!(
+            s.len() == by[0].len(),
+            ShapeMismatch: "`arg_sort_by` expects series of equal length, got {} and {}",
+            by[0].len(), s.len()
+        );
+    }
+    argsort_multiple_row_fmt(by, descending.to_vec(), false, true)
 }
 
 // utility to be able to also add literals to concat_str function
@@ -274,6 +306,46 @@ pub fn diag_concat_df(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
     concat_df(&dfs)
 }
 
+/// Concat `[DataFrame]`s vertically, casting every column to the supertype of its dtypes instead
+/// of erroring on mismatching dtypes.
+///
+/// If `diagonal` is set, the output has the union of the columns in the order of their first
+/// appearance, and the columns that a `DataFrame` misses are filled with nulls. Otherwise all
+/// `DataFrame`s must have the same columns. Categorical columns of different `DataFrame`s can only
+/// be combined under the global string cache, in which case their rev-maps are merged.
+#[cfg(feature = "diagonal_concat")]
+pub fn concat_df_relaxed(dfs: &[DataFrame], diagonal: bool) -> PolarsResult<DataFrame> {
+    let (first, rest) = dfs
+        .split_first()
+        .ok_or_else(|| polars_err!(NoData: "cannot concat empty dataframes"))?;
+    let mut schema = first.schema();
+    for df in rest {
+        let other = df.schema();
+        polars_ensure!(
+            diagonal
+                || (other.len() == schema.len()
+                    && other.iter_names().all(|name| schema.contains(name))),
+            SchemaMismatch: "cannot vertically concat dataframes with different columns, consider a diagonal concat"
+        );
+        schema.union_with(&other, true)?;
+    }
+
+    let dfs = dfs
+        .iter()
+        .map(|df| {
+            let columns = schema
+                .iter()
+                .map(|(name, dtype)| match df.column(name) {
+                    Ok(s) => s.cast(dtype),
+                    Err(_) => Ok(Series::full_null(name, df.height(), dtype)),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok(DataFrame::new_no_checks(columns))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    concat_df(&dfs)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -282,11 +354,19 @@ mod test {
     fn test_cov() {
         let a = Series::new("a", &[1.0f32, 2.0, 5.0]);
         let b = Series::new("b", &[1.0f32, 2.0, -3.0]);
-        let out = cov_f(a.f32().unwrap(), b.f32().unwrap());
+        let out = cov_f(a.f32().unwrap(), b.f32().unwrap(), 1);
         assert_eq!(out, Some(-5.0));
         let a = a.cast(&DataType::Int32).unwrap();
         let b = b.cast(&DataType::Int32).unwrap();
-        let out = cov_i(a.i32().unwrap(), b.i32().unwrap());
+        let out = cov_i(a.i32().unwrap(), b.i32().unwrap(), 1);
+        assert_eq!(out, Some(-5.0));
+        let out = cov_i(a.i32().unwrap(), b.i32().unwrap(), 0);
+        assert_eq!(out, Some(-10.0 / 3.0));
+
+        // rows with a null on either side are dropped
+        let a = Series::new("a", &[Some(1.0f64), Some(2.0), None, Some(5.0)]);
+        let b = Series::new("b", &[Some(1.0f64), Some(2.0), Some(8.0), Some(-3.0)]);
+        let out = cov_f(a.f64().unwrap(), b.f64().unwrap(), 1);
         assert_eq!(out, Some(-5.0));
     }
 
@@ -294,7 +374,7 @@ mod test {
     fn test_pearson_corr() {
         let a = Series::new("a", &[1.0f32, 2.0]);
         let b = Series::new("b", &[1.0f32, 2.0]);
-        assert!((cov_f(a.f32().unwrap(), b.f32().unwrap()).unwrap() - 0.5).abs() < 0.001);
+        assert!((cov_f(a.f32().unwrap(), b.f32().unwrap(), 1).unwrap() - 0.5).abs() < 0.001);
         assert!(
             (pearson_corr_f(a.f32().unwrap(), b.f32().unwrap(), 1).unwrap() - 1.0).abs() < 0.001
         );
@@ -349,4 +429,40 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "diagonal_concat")]
+    fn test_concat_df_relaxed() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1i32, 2],
+            "b" => [1.5f32, 2.5]
+        ]?;
+        let b = df![
+            "b" => [1.0f64, 2.0],
+            "a" => [3i64, 4]
+        ]?;
+
+        // the columns are in the order of the first frame
+        let out = concat_df_relaxed(&[a.clone(), b], false)?;
+        let expected = df![
+            "a" => [1i64, 2, 3, 4],
+            "b" => [1.5f64, 2.5, 1.0, 2.0]
+        ]?;
+        assert!(out.frame_equal(&expected));
+
+        let c = df![
+            "c" => ["x"],
+            "a" => [5i64]
+        ]?;
+        assert!(concat_df_relaxed(&[a.clone(), c.clone()], false).is_err());
+        let out = concat_df_relaxed(&[a, c], true)?;
+        let expected = df![
+            "a" => [1i64, 2, 5],
+            "b" => [Some(1.5f32), Some(2.5), None],
+            "c" => [None, None, Some("x")]
+        ]?;
+        assert!(out.frame_equal_missing(&expected));
+
+        Ok(())
+    }
 }
@@ -17,6 +17,7 @@ pub mod fmt;
 pub mod frame;
 pub mod functions;
 pub mod hashing;
+pub mod mem;
 mod named_from;
 pub mod prelude;
 pub mod schema;
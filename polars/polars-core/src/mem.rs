@@ -0,0 +1,252 @@
+//! Accounting of the heap memory that is in use.
+//!
+//! The accounting is opt-in: install [`TrackingAllocator`] as the global allocator of the
+//! binary. Without it, [`current_usage`] and [`peak_usage`] stay `0`.
+//!
+//! ```rust
+//! use polars_core::mem::TrackingAllocator;
+//!
+//! #[global_allocator]
+//! static ALLOC: TrackingAllocator = TrackingAllocator::system();
+//! ```
+//!
+//! The allocator counts every allocation of the process, so the usage includes the memory
+//! of concurrently running queries and of other libraries that use the global allocator.
+//! A [`PeakScope`] or [`MemoryLimit`] measures the usage relative to its creation instead.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+use crate::prelude::*;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// Every active `PeakScope` owns one slot, whose bit is set in `ACTIVE_SCOPES`.
+const N_SCOPES: usize = 64;
+static ACTIVE_SCOPES: AtomicU64 = AtomicU64::new(0);
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicUsize = AtomicUsize::new(0);
+static SCOPE_PEAKS: [AtomicUsize; N_SCOPES] = [ZERO; N_SCOPES];
+
+/// A global allocator that accounts the memory that is in use, see the [module docs](self).
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl TrackingAllocator<System> {
+    /// Track the allocations of the system allocator.
+    pub const fn system() -> Self {
+        Self::new(System)
+    }
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Track the allocations of `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+#[inline]
+fn on_alloc(size: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+
+    let mut active = ACTIVE_SCOPES.load(Ordering::Relaxed);
+    while active != 0 {
+        let slot = active.trailing_zeros() as usize;
+        SCOPE_PEAKS[slot].fetch_max(current, Ordering::Relaxed);
+        active &= active - 1;
+    }
+}
+
+#[inline]
+fn on_dealloc(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size())
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        on_dealloc(layout.size())
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size())
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            on_dealloc(layout.size());
+            on_alloc(new_size)
+        }
+        new_ptr
+    }
+}
+
+/// Whether [`TrackingAllocator`] is installed as the global allocator.
+pub fn is_tracking() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// The bytes that are currently allocated.
+pub fn current_usage() -> usize {
+    CURRENT.load(Ordering::Relaxed)
+}
+
+/// The highest number of bytes that were allocated at once since the start of the process,
+/// or since the last call to [`reset_peak_usage`].
+pub fn peak_usage() -> usize {
+    PEAK.load(Ordering::Relaxed)
+}
+
+/// Reset the peak that is returned by [`peak_usage`] to the current usage.
+pub fn reset_peak_usage() {
+    PEAK.store(current_usage(), Ordering::Relaxed)
+}
+
+/// Measures the peak usage from its creation until it is dropped, e.g. for a single query.
+///
+/// A limited number of scopes can be active at once. A scope that finds no free slot falls
+/// back to the usage at the moments [`PeakScope::peak`] is called.
+pub struct PeakScope {
+    slot: Option<usize>,
+    start: usize,
+}
+
+impl PeakScope {
+    pub fn new() -> Self {
+        let mut active = ACTIVE_SCOPES.load(Ordering::Relaxed);
+        loop {
+            if active == u64::MAX {
+                return Self {
+                    slot: None,
+                    start: current_usage(),
+                };
+            }
+            let slot = active.trailing_ones() as usize;
+            match ACTIVE_SCOPES.compare_exchange_weak(
+                active,
+                active | (1 << slot),
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    // Clear the peak of the previous owner of the slot before the usage at the
+                    // start is read. The allocations from then on raise the peak of the slot,
+                    // so none is lost between the reset and the read.
+                    SCOPE_PEAKS[slot].store(0, Ordering::Relaxed);
+                    let start = current_usage();
+                    SCOPE_PEAKS[slot].fetch_max(start, Ordering::Relaxed);
+                    return Self {
+                        slot: Some(slot),
+                        start,
+                    };
+                }
+                Err(current) => active = current,
+            }
+        }
+    }
+
+    /// The usage when this scope was created.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The highest usage since this scope was created.
+    pub fn peak(&self) -> usize {
+        let current = current_usage();
+        match self.slot {
+            Some(slot) => SCOPE_PEAKS[slot].load(Ordering::Relaxed).max(current),
+            None => self.start.max(current),
+        }
+    }
+
+    /// The highest usage since this scope was created, above the usage at its creation.
+    pub fn peak_increase(&self) -> usize {
+        self.peak().saturating_sub(self.start)
+    }
+}
+
+impl Default for PeakScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for PeakScope {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot {
+            ACTIVE_SCOPES.fetch_and(!(1 << slot), Ordering::AcqRel);
+        }
+    }
+}
+
+/// A soft limit on the memory that a single query uses: the bytes that are in use above the
+/// usage when the limit was created, so memory that was allocated before the query started
+/// doesn't count towards it.
+pub struct MemoryLimit {
+    limit: usize,
+    scope: PeakScope,
+}
+
+impl MemoryLimit {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            scope: PeakScope::new(),
+        }
+    }
+
+    /// The limit in bytes.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The bytes that are in use above the usage when the limit was created.
+    pub fn usage(&self) -> usize {
+        current_usage().saturating_sub(self.scope.start())
+    }
+
+    /// The highest usage since the limit was created, above the usage at its creation.
+    pub fn peak_usage(&self) -> usize {
+        self.scope.peak_increase()
+    }
+
+    pub fn exceeded(&self) -> bool {
+        self.usage() > self.limit
+    }
+
+    /// Raise a `ResourceExhausted` error if the limit is exceeded.
+    pub fn check(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            is_tracking(),
+            InvalidOperation: "a memory limit requires the `TrackingAllocator` to be installed \
+            as the global allocator"
+        );
+        let usage = self.usage();
+        polars_ensure!(
+            usage <= self.limit,
+            ResourceExhausted: "memory limit of {} bytes exceeded: the query uses {} bytes",
+            self.limit, usage
+        );
+        Ok(())
+    }
+}
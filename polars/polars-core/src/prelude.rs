@@ -25,6 +25,8 @@ pub use crate::chunked_array::ops::rolling_window::RollingOptionsFixedWindow;
 #[cfg(feature = "rank")]
 pub use crate::chunked_array::ops::unique::rank::{RankMethod, RankOptions};
 pub use crate::chunked_array::ops::*;
+#[cfg(feature = "random")]
+pub use crate::chunked_array::SampleSize;
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
 pub use crate::chunked_array::ChunkedArray;
@@ -36,9 +38,9 @@ pub use crate::frame::asof_join::*;
 pub use crate::frame::explode::MeltArgs;
 pub(crate) use crate::frame::groupby::aggregations::*;
 pub use crate::frame::groupby::{GroupsIdx, GroupsProxy, GroupsSlice, IntoGroupsProxy};
-pub use crate::frame::hash_join::JoinType;
+pub use crate::frame::hash_join::{JoinBuildSide, JoinType};
 pub(crate) use crate::frame::hash_join::*;
-pub use crate::frame::{DataFrame, UniqueKeepStrategy};
+pub use crate::frame::{DataFrame, UniqueKeepStrategy, UnnestCollision};
 pub use crate::hashing::{FxHash, VecHash};
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
 pub use crate::schema::*;
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
+use crate::utils::try_get_supertype;
 
 /// A map from field/column name (`String`) to the type of that field/column (`DataType`)
 #[derive(Eq, Clone, Default)]
@@ -190,7 +191,7 @@ impl Schema {
     /// Get a reference to the dtype of the field named `name`, or `Err(PolarsErr)` if the field doesn't exist
     pub fn try_get(&self, name: &str) -> PolarsResult<&DataType> {
         self.get(name)
-            .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name))
+            .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name; column = name))
     }
 
     /// Return all data about the field named `name`: its index in the schema, its name, and its dtype
@@ -206,7 +207,7 @@ impl Schema {
     pub fn try_get_full(&self, name: &str) -> PolarsResult<(usize, &SmartString, &DataType)> {
         self.inner
             .get_full(name)
-            .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name))
+            .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name; column = name))
     }
 
     /// Look up the name in the schema and return an owned [`Field`] by cloning the data
@@ -230,7 +231,7 @@ impl Schema {
     pub fn try_get_field(&self, name: &str) -> PolarsResult<Field> {
         self.inner
             .get(name)
-            .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name))
+            .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name; column = name))
             .map(|dtype| Field::new(name, dtype.clone()))
     }
 
@@ -327,6 +328,30 @@ impl Schema {
         self.inner.extend(other.inner)
     }
 
+    /// Append the fields of `other` that don't occur in `self`, so that the fields are in the order of their
+    /// first appearance.
+    ///
+    /// If `to_supertypes` is set, fields that occur in both `self` and `other` get the supertype of both dtypes.
+    /// Otherwise their dtypes must be equal.
+    pub fn union_with(&mut self, other: &Self, to_supertypes: bool) -> PolarsResult<()> {
+        for (name, dtype) in other.iter() {
+            match self.inner.get_mut(name) {
+                Some(current) if current != dtype => {
+                    polars_ensure!(
+                        to_supertypes,
+                        SchemaMismatch: "column {} has the dtypes {} and {}", name, current, dtype
+                    );
+                    *current = try_get_supertype(current, dtype)?;
+                }
+                Some(_) => {}
+                None => {
+                    self.inner.insert(name.clone(), dtype.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Convert self to `ArrowSchema` by cloning the fields
     pub fn to_arrow(&self) -> ArrowSchema {
         let fields: Vec<_> = self
@@ -3,7 +3,7 @@ use std::cell::RefCell;
 use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
 
-use super::DeDataType;
+use super::{DeDataType, FORMAT_VERSION};
 use crate::prelude::*;
 
 pub struct IterSer<I>
@@ -19,7 +19,7 @@ where
     I: IntoIterator,
     <I as IntoIterator>::Item: Serialize,
 {
-    fn new(iter: I) -> Self {
+    pub(super) fn new(iter: I) -> Self {
         IterSer {
             iter: RefCell::new(Some(iter)),
         }
@@ -43,25 +43,40 @@ where
     }
 }
 
-fn serialize_impl<T, S>(
+/// Serialize a `Series` as a map of the format version, its name, its dtype and its values.
+pub(super) fn serialize_series<S, V>(
     serializer: S,
     name: &str,
     dtype: &DataType,
-    ca: &ChunkedArray<T>,
+    values: &V,
 ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
 where
-    T: PolarsNumericType,
-    T::Native: Serialize,
     S: Serializer,
+    V: Serialize + ?Sized,
 {
-    let mut state = serializer.serialize_map(Some(3))?;
+    let mut state = serializer.serialize_map(Some(4))?;
+    state.serialize_entry("version", &FORMAT_VERSION)?;
     state.serialize_entry("name", name)?;
     let dtype: DeDataType = dtype.into();
     state.serialize_entry("datatype", &dtype)?;
-    state.serialize_entry("values", &IterSer::new(ca.into_iter()))?;
+    state.serialize_entry("values", values)?;
     state.end()
 }
 
+fn serialize_impl<T, S>(
+    serializer: S,
+    name: &str,
+    dtype: &DataType,
+    ca: &ChunkedArray<T>,
+) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+where
+    T: PolarsNumericType,
+    T::Native: Serialize,
+    S: Serializer,
+{
+    serialize_series(serializer, name, dtype, &IterSer::new(ca.into_iter()))
+}
+
 impl<T> Serialize for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -105,12 +120,12 @@ macro_rules! impl_serialize {
             where
                 S: Serializer,
             {
-                let mut state = serializer.serialize_map(Some(3))?;
-                state.serialize_entry("name", self.name())?;
-                let dtype: DeDataType = self.dtype().into();
-                state.serialize_entry("datatype", &dtype)?;
-                state.serialize_entry("values", &IterSer::new(self.into_iter()))?;
-                state.end()
+                serialize_series(
+                    serializer,
+                    self.name(),
+                    self.dtype(),
+                    &IterSer::new(self.into_iter()),
+                )
             }
         }
     };
@@ -130,14 +145,17 @@ impl Serialize for CategoricalChunked {
     where
         S: Serializer,
     {
-        {
-            let mut state = serializer.serialize_map(Some(3))?;
-            state.serialize_entry("name", self.name())?;
-            let dtype: DeDataType = self.dtype().into();
-            state.serialize_entry("datatype", &dtype)?;
-            state.serialize_entry("values", &IterSer::new(self.iter_str()))?;
-            state.end()
-        }
+        // the values are the categories of a local rev-map and the codes into them
+        let ca = self.to_local();
+        let categories = match &**ca.get_rev_map() {
+            RevMapping::Local(categories) => categories,
+            RevMapping::Global(_, _, _) => unreachable!(),
+        };
+        let values = (
+            IterSer::new(categories.values_iter()),
+            IterSer::new(ca.logical().into_iter()),
+        );
+        serialize_series(serializer, self.name(), self.dtype(), &values)
     }
 }
 
@@ -150,13 +168,6 @@ impl Serialize for StructChunked {
     where
         S: Serializer,
     {
-        {
-            let mut state = serializer.serialize_map(Some(3))?;
-            state.serialize_entry("name", self.name())?;
-            let dtype: DeDataType = self.dtype().into();
-            state.serialize_entry("datatype", &dtype)?;
-            state.serialize_entry("values", self.fields())?;
-            state.end()
-        }
+        serialize_series(serializer, self.name(), self.dtype(), self.fields())
     }
 }
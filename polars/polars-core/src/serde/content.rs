@@ -0,0 +1,202 @@
+//! A buffer for the values of a serialized [`Series`](crate::prelude::Series) that are read
+//! before the fields they depend on.
+//!
+//! Self-describing formats, such as json, don't need to keep the order of the fields of a map,
+//! so the values of a `Series` may come before its version and dtype. Those values are read into
+//! [`Content`] and deserialized from it once the dtype is known.
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{self, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer};
+
+pub(super) enum Content {
+    Unit,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Seq(Vec<Content>),
+    Map(Vec<(Content, Content)>),
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ContentVisitor;
+
+        impl<'de> Visitor<'de> for ContentVisitor {
+            type Value = Content;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("any value")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Content, E> {
+                Ok(Content::Bool(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Content, E> {
+                Ok(Content::I64(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Content, E> {
+                Ok(Content::U64(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Content, E> {
+                Ok(Content::F64(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Content, E> {
+                Ok(Content::String(v.to_string()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Content, E> {
+                Ok(Content::String(v))
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Content, E> {
+                Ok(Content::Bytes(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Content, E> {
+                Ok(Content::Bytes(v))
+            }
+
+            fn visit_none<E>(self) -> Result<Content, E> {
+                Ok(Content::Unit)
+            }
+
+            fn visit_unit<E>(self) -> Result<Content, E> {
+                Ok(Content::Unit)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Content, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Content::deserialize(deserializer)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Content, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(Content::Seq(values))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Content, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(Content::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+pub(super) struct ContentDeserializer<E> {
+    content: Content,
+    err: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for Content {
+    type Deserializer = ContentDeserializer<E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ContentDeserializer {
+            content: self,
+            err: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for ContentDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::Unit => visitor.visit_unit(),
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::String(v) => visitor.visit_string(v),
+            Content::Bytes(v) => visitor.visit_byte_buf(v),
+            Content::Seq(values) => {
+                let mut seq = SeqDeserializer::new(values.into_iter());
+                let out = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(out)
+            }
+            Content::Map(entries) => {
+                let mut map = MapDeserializer::new(entries.into_iter());
+                let out = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(out)
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::Unit => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            // a unit variant
+            Content::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            // a variant with data is a map of the variant to its data
+            Content::Map(entries) if entries.len() == 1 => {
+                let map = MapDeserializer::new(entries.into_iter());
+                visitor.visit_enum(MapAccessDeserializer::new(map))
+            }
+            _ => Err(de::Error::custom("expected an enum variant")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
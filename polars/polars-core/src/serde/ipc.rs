@@ -0,0 +1,100 @@
+//! Serialize a [`DataFrame`] as Arrow IPC bytes instead of the columnar layout of its
+//! `Serialize` implementation.
+//!
+//! This is more compact and faster for large frames in binary formats such as bincode. Use it
+//! on a field with `#[serde(with = "...")]`:
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct State {
+//!     #[serde(with = "polars_core::serde::ipc")]
+//!     df: DataFrame,
+//! }
+//! ```
+use std::io::Cursor;
+
+use arrow::io::ipc::{read, write};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{check_format_version, FORMAT_VERSION};
+use crate::frame::ffi::ensure_exportable;
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize)]
+struct IpcFrame {
+    version: u32,
+    ipc: Vec<u8>,
+}
+
+fn to_ipc_bytes(df: &DataFrame) -> PolarsResult<Vec<u8>> {
+    for s in df.get_columns() {
+        ensure_exportable(s.name(), s.dtype())?;
+    }
+    // a single record batch, so that every categorical is written with a single dictionary
+    let mut df = df.clone();
+    df.as_single_chunk_par();
+
+    let mut buf = vec![];
+    let mut writer = write::FileWriter::try_new(
+        &mut buf,
+        df.schema().to_arrow(),
+        None,
+        write::WriteOptions { compression: None },
+    )?;
+    for batch in df.iter_chunks() {
+        writer.write(&batch, None)?;
+    }
+    writer.finish()?;
+    Ok(buf)
+}
+
+fn from_ipc_bytes(bytes: &[u8]) -> PolarsResult<DataFrame> {
+    let mut reader = Cursor::new(bytes);
+    let metadata = read::read_file_metadata(&mut reader)?;
+    let fields = metadata.schema.fields.clone();
+    let reader = read::FileReader::new(reader, metadata, None, None);
+
+    // categorical chunks can only be appended when they share a rev-map
+    #[cfg(feature = "dtype-categorical")]
+    let _cache = crate::StringCacheHolder::new();
+
+    let mut out: Option<DataFrame> = None;
+    for batch in reader {
+        let df = DataFrame::try_from((batch?, fields.as_slice()))?;
+        match out.as_mut() {
+            Some(out) => {
+                out.vstack_mut(&df)?;
+            }
+            None => out = Some(df),
+        }
+    }
+    Ok(out.unwrap_or_else(|| {
+        let schema = fields.iter().map(Field::from).collect::<Schema>();
+        DataFrame::from(&schema)
+    }))
+}
+
+/// Serialize `df` as the format version and the Arrow IPC bytes of `df`.
+pub fn serialize<S>(df: &DataFrame, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let frame = IpcFrame {
+        version: FORMAT_VERSION,
+        ipc: to_ipc_bytes(df).map_err(ser::Error::custom)?,
+    };
+    frame.serialize(serializer)
+}
+
+/// Deserialize a [`DataFrame`] that was serialized with [`serialize`].
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DataFrame, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let frame = IpcFrame::deserialize(deserializer)?;
+    check_format_version(frame.version)?;
+    from_ipc_bytes(&frame.ipc).map_err(de::Error::custom)
+}
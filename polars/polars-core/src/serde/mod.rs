@@ -3,8 +3,29 @@ use serde::{Deserialize, Serialize};
 use crate::prelude::*;
 
 pub mod chunked_array;
+mod content;
+#[cfg(feature = "serde-ipc")]
+pub mod ipc;
 pub mod series;
 
+/// The version of the serialized format of [`Series`] and [`DataFrame`].
+///
+/// It is stored with every serialized `Series` and bumped on every change of the format, so that
+/// data written by a newer version of polars fails to deserialize with an error. A `Series`
+/// without a version was written before the format was versioned, and is read as version 0.
+pub const FORMAT_VERSION: u32 = 1;
+
+fn check_format_version<E: serde::de::Error>(version: u32) -> Result<(), E> {
+    if version <= FORMAT_VERSION {
+        Ok(())
+    } else {
+        Err(E::custom(format!(
+            "unsupported serialization format version {version}, \
+            this version of polars reads up to version {FORMAT_VERSION}"
+        )))
+    }
+}
+
 /// Intermediate enum. Needed because [crate::datatypes::DataType] has
 /// a &static str and thus requires Deserialize<&static>
 #[derive(Serialize, Deserialize, Debug)]
@@ -26,7 +47,9 @@ enum DeDataType<'a> {
     Datetime(TimeUnit, Option<TimeZone>),
     Duration(TimeUnit),
     Time,
+    /// A list of an unknown inner dtype, written by format version 0.
     List,
+    ListOf(#[serde(borrow)] Box<DeDataType<'a>>),
     Object(&'a str),
     Null,
     Categorical,
@@ -36,6 +59,10 @@ enum DeDataType<'a> {
 impl From<&DataType> for DeDataType<'_> {
     fn from(dt: &DataType) -> Self {
         match dt {
+            DataType::Int8 => DeDataType::Int8,
+            DataType::Int16 => DeDataType::Int16,
+            DataType::UInt8 => DeDataType::UInt8,
+            DataType::UInt16 => DeDataType::UInt16,
             DataType::Int32 => DeDataType::Int32,
             DataType::UInt32 => DeDataType::UInt32,
             DataType::Int64 => DeDataType::Int64,
@@ -49,19 +76,51 @@ impl From<&DataType> for DeDataType<'_> {
             DataType::Utf8 => DeDataType::Utf8,
             DataType::Boolean => DeDataType::Boolean,
             DataType::Null => DeDataType::Null,
-            DataType::List(_) => DeDataType::List,
+            DataType::List(inner) => DeDataType::ListOf(Box::new(inner.as_ref().into())),
             DataType::Binary => DeDataType::Binary,
             #[cfg(feature = "object")]
             DataType::Object(s) => DeDataType::Object(s),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => DeDataType::Struct,
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => DeDataType::Categorical,
+            DataType::Categorical(_, _) => DeDataType::Categorical,
             _ => unimplemented!(),
         }
     }
 }
 
+impl DeDataType<'_> {
+    /// The [`DataType`] this describes, if it carries enough information to restore it.
+    fn to_dtype(&self) -> Option<DataType> {
+        let dt = match self {
+            DeDataType::Boolean => DataType::Boolean,
+            DeDataType::UInt8 => DataType::UInt8,
+            DeDataType::UInt16 => DataType::UInt16,
+            DeDataType::UInt32 => DataType::UInt32,
+            DeDataType::UInt64 => DataType::UInt64,
+            DeDataType::Int8 => DataType::Int8,
+            DeDataType::Int16 => DataType::Int16,
+            DeDataType::Int32 => DataType::Int32,
+            DeDataType::Int64 => DataType::Int64,
+            DeDataType::Float32 => DataType::Float32,
+            DeDataType::Float64 => DataType::Float64,
+            DeDataType::Utf8 => DataType::Utf8,
+            DeDataType::Binary => DataType::Binary,
+            DeDataType::Date => DataType::Date,
+            DeDataType::Datetime(tu, tz) => DataType::Datetime(*tu, tz.clone()),
+            DeDataType::Duration(tu) => DataType::Duration(*tu),
+            DeDataType::Time => DataType::Time,
+            DeDataType::ListOf(inner) => DataType::List(Box::new(inner.to_dtype()?)),
+            DeDataType::Null => DataType::Null,
+            #[cfg(feature = "dtype-categorical")]
+            DeDataType::Categorical => DataType::Categorical(None, Default::default()),
+            // the fields and the object type are only known from the values
+            _ => return None,
+        };
+        Some(dt)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -193,4 +252,135 @@ mod test {
         let out = bincode::deserialize_from::<_, DataFrame>(bytes.as_slice()).unwrap(); // uses `DeserializeOwned`
         assert!(df.frame_equal_missing(&out));
     }
+
+    #[test]
+    fn test_serde_dtypes_round_trip() -> PolarsResult<()> {
+        // deserialized categoricals are created under the string cache when it is enabled
+        #[cfg(feature = "dtype-categorical")]
+        let _cache = crate::StringCacheHolder::new();
+
+        let mut columns = vec![
+            Series::new("int", [Some(1i32), None]),
+            Series::new_null("null", 2),
+            // the inner dtype of all-null lists is kept
+            Series::full_null("null_list", 2, &DataType::List(Box::new(DataType::Float32))),
+        ];
+        #[cfg(feature = "dtype-i8")]
+        columns.push(Series::new("i8", [Some(1i8), None]));
+        #[cfg(feature = "dtype-categorical")]
+        columns.push(
+            Series::new("cat", [Some("b"), None])
+                .cast(&DataType::Categorical(None, Default::default()))?,
+        );
+        #[cfg(feature = "dtype-datetime")]
+        {
+            let dt = DataType::Datetime(TimeUnit::Microseconds, Some("Europe/Amsterdam".into()));
+            let s = Series::new("dt", [Some(0i64), None]).cast(&dt)?;
+            columns.push(Series::new("dt_list", &[s.clone(), s.clone()]));
+            columns.push(s);
+        }
+        let df = DataFrame::new(columns)?;
+        let check = |out: DataFrame| -> PolarsResult<()> {
+            assert_eq!(out.schema(), df.schema());
+            assert_eq!(out.column("null")?.null_count(), 2);
+            assert_eq!(out.column("null_list")?.null_count(), 2);
+            let drop_nulls = |df: &DataFrame| df.drop_many(&["null", "null_list"]);
+            assert!(drop_nulls(&df).frame_equal_missing(&drop_nulls(&out)));
+            Ok(())
+        };
+
+        let json = serde_json::to_string(&df).unwrap();
+        check(serde_json::from_str::<DataFrame>(&json).unwrap())?;
+        let bytes = bincode::serialize(&df).unwrap();
+        check(bincode::deserialize::<DataFrame>(&bytes).unwrap())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_version_mismatch() {
+        let s = Series::new("a", [1i32, 2]);
+        let json = serde_json::to_string(&s).unwrap().replace(
+            &format!("\"version\":{FORMAT_VERSION}"),
+            &format!("\"version\":{}", FORMAT_VERSION + 1),
+        );
+        let err = serde_json::from_str::<Series>(&json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("format version {}", FORMAT_VERSION + 1)));
+    }
+
+    #[test]
+    fn test_serde_version_0() -> PolarsResult<()> {
+        // written before the format was versioned
+        let json = r#"{"columns":[
+            {"name":"a","datatype":"Int32","values":[1,null]},
+            {"name":"l","datatype":"List","values":[
+                {"name":"","datatype":"Int64","values":[1,2]},
+                null
+            ]}
+        ]}"#;
+        let out = serde_json::from_str::<DataFrame>(json).unwrap();
+        let expected = df![
+            "a" => [Some(1i32), None],
+            "l" => [Some(Series::new("", [1i64, 2])), None],
+        ]?;
+        assert!(out.frame_equal_missing(&expected));
+
+        #[cfg(feature = "dtype-categorical")]
+        {
+            let json = r#"{"name":"c","datatype":"Categorical","values":["b",null,"b"]}"#;
+            let out = serde_json::from_str::<Series>(json).unwrap();
+            assert_eq!(
+                out.dtype(),
+                &DataType::Categorical(None, Default::default())
+            );
+            let out = out.cast(&DataType::Utf8)?;
+            assert_eq!(Vec::from(out.utf8()?), &[Some("b"), None, Some("b")]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_field_order() -> PolarsResult<()> {
+        let json = r#"{"values":[1,null],"datatype":"Int32","version":1,"name":"a"}"#;
+        let out = serde_json::from_str::<Series>(json).unwrap();
+        assert!(out.series_equal_missing(&Series::new("a", [Some(1i32), None])));
+
+        #[cfg(feature = "dtype-categorical")]
+        {
+            // the values of a categorical depend on the version that follows them
+            let json = r#"{
+                "datatype":"Categorical","values":[["a","b"],[1,null,0]],"name":"c","version":1
+            }"#;
+            let out = serde_json::from_str::<Series>(json).unwrap();
+            assert_eq!(out.name(), "c");
+            let out = out.cast(&DataType::Utf8)?;
+            assert_eq!(Vec::from(out.utf8()?), &[Some("b"), None, Some("a")]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde-ipc")]
+    fn test_serde_ipc() {
+        #[derive(Serialize, Deserialize)]
+        struct State {
+            #[serde(with = "crate::serde::ipc")]
+            df: DataFrame,
+            n: usize,
+        }
+
+        let state = State {
+            df: sample_dataframe(),
+            n: 1,
+        };
+        let bytes = bincode::serialize(&state).unwrap();
+        let out = bincode::deserialize::<State>(&bytes).unwrap();
+        assert!(state.df.frame_equal_missing(&out.df));
+        assert_eq!(out.n, 1);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let out = serde_json::from_str::<State>(&json).unwrap();
+        assert!(state.df.frame_equal_missing(&out.df));
+    }
 }
@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 use std::fmt::Formatter;
 
-use serde::de::{MapAccess, Visitor};
+use serde::de::{DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::prelude::*;
-use crate::serde::DeDataType;
+use crate::serde::chunked_array::{serialize_series, IterSer};
+use crate::serde::content::Content;
+use crate::serde::{check_format_version, DeDataType};
 
 impl Serialize for Series {
     fn serialize<S>(
@@ -55,7 +57,7 @@ impl Serialize for Series {
                     ca.serialize(serializer)
                 }
                 #[cfg(feature = "dtype-categorical")]
-                DataType::Categorical(_) => {
+                DataType::Categorical(_, _) => {
                     let ca = self.categorical().unwrap();
                     ca.serialize(serializer)
                 }
@@ -69,6 +71,18 @@ impl Serialize for Series {
                     let ca = self.time().unwrap();
                     ca.serialize(serializer)
                 }
+                #[cfg(feature = "dtype-i8")]
+                DataType::Int8 => self.i8().unwrap().serialize(serializer),
+                #[cfg(feature = "dtype-i16")]
+                DataType::Int16 => self.i16().unwrap().serialize(serializer),
+                #[cfg(feature = "dtype-u8")]
+                DataType::UInt8 => self.u8().unwrap().serialize(serializer),
+                #[cfg(feature = "dtype-u16")]
+                DataType::UInt16 => self.u16().unwrap().serialize(serializer),
+                DataType::Null => {
+                    let values = IterSer::new(std::iter::repeat(None::<()>).take(self.len()));
+                    serialize_series(serializer, self.name(), self.dtype(), &values)
+                }
                 _ => {
                     // cast small integers to i32
                     self.cast(&DataType::Int32).unwrap().serialize(serializer)
@@ -78,12 +92,172 @@ impl Serialize for Series {
     }
 }
 
+/// Deserializes the values of a `Series` of `dtype`, written by format `version`.
+struct ValuesSeed<'a, 'b> {
+    name: &'a str,
+    dtype: &'a DeDataType<'b>,
+    version: u32,
+}
+
+impl<'de> DeserializeSeed<'de> for ValuesSeed<'_, '_> {
+    type Value = Series;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Series, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = self.name;
+        match self.dtype {
+            #[cfg(feature = "dtype-i8")]
+            DeDataType::Int8 => {
+                let values: Vec<Option<i8>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            #[cfg(feature = "dtype-i16")]
+            DeDataType::Int16 => {
+                let values: Vec<Option<i16>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            #[cfg(feature = "dtype-u8")]
+            DeDataType::UInt8 => {
+                let values: Vec<Option<u8>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            #[cfg(feature = "dtype-u16")]
+            DeDataType::UInt16 => {
+                let values: Vec<Option<u16>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::Int32 => {
+                let values: Vec<Option<i32>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::UInt32 => {
+                let values: Vec<Option<u32>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::Int64 => {
+                let values: Vec<Option<i64>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::UInt64 => {
+                let values: Vec<Option<u64>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            #[cfg(feature = "dtype-date")]
+            DeDataType::Date => {
+                let values: Vec<Option<i32>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values).cast(&DataType::Date).unwrap())
+            }
+            #[cfg(feature = "dtype-datetime")]
+            DeDataType::Datetime(tu, tz) => {
+                let values: Vec<Option<i64>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values)
+                    .cast(&DataType::Datetime(*tu, tz.clone()))
+                    .unwrap())
+            }
+            #[cfg(feature = "dtype-duration")]
+            DeDataType::Duration(tu) => {
+                let values: Vec<Option<i64>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values)
+                    .cast(&DataType::Duration(*tu))
+                    .unwrap())
+            }
+            #[cfg(feature = "dtype-time")]
+            DeDataType::Time => {
+                let values: Vec<Option<i64>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values).cast(&DataType::Time).unwrap())
+            }
+            DeDataType::Boolean => {
+                let values: Vec<Option<bool>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::Float32 => {
+                let values: Vec<Option<f32>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::Float64 => {
+                let values: Vec<Option<f64>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::Utf8 => {
+                let values: Vec<Option<Cow<str>>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::List => {
+                let values: Vec<Option<Series>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            DeDataType::ListOf(inner) => {
+                let values: Vec<Option<Series>> = Deserialize::deserialize(deserializer)?;
+                let s = Series::new(name, values);
+                // restores the inner dtype of empty and all-null lists
+                match inner.to_dtype() {
+                    Some(inner) if s.dtype() != &DataType::List(Box::new(inner.clone())) => s
+                        .cast(&DataType::List(Box::new(inner)))
+                        .map_err(de::Error::custom),
+                    _ => Ok(s),
+                }
+            }
+            DeDataType::Null => {
+                let values: Vec<Option<()>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new_null(name, values.len()))
+            }
+            DeDataType::Binary => {
+                let values: Vec<Option<Cow<[u8]>>> = Deserialize::deserialize(deserializer)?;
+                Ok(Series::new(name, values))
+            }
+            #[cfg(feature = "dtype-struct")]
+            DeDataType::Struct => {
+                let values: Vec<Series> = Deserialize::deserialize(deserializer)?;
+                let ca = StructChunked::new(name, &values).unwrap();
+                let mut s = ca.into_series();
+                s.rename(name);
+                Ok(s)
+            }
+            // version 0 stored the categories of every value
+            #[cfg(feature = "dtype-categorical")]
+            DeDataType::Categorical if self.version == 0 => {
+                let values: Vec<Option<Cow<str>>> = Deserialize::deserialize(deserializer)?;
+                Series::new(name, values)
+                    .cast(&DataType::Categorical(None, Default::default()))
+                    .map_err(de::Error::custom)
+            }
+            #[cfg(feature = "dtype-categorical")]
+            DeDataType::Categorical => {
+                let (categories, codes): (Vec<Cow<str>>, Vec<Option<u32>>) =
+                    Deserialize::deserialize(deserializer)?;
+                if let Some(code) = codes
+                    .iter()
+                    .flatten()
+                    .find(|&&code| code as usize >= categories.len())
+                {
+                    return Err(de::Error::custom(format!(
+                        "categorical code {code} is out of bounds for {} categories",
+                        categories.len()
+                    )));
+                }
+                let categories = Utf8Array::<i64>::from_slice(categories);
+                let codes = PrimitiveArray::<u32>::from(codes);
+                // Safety:
+                // the codes are checked to be in bounds of the categories
+                let ca =
+                    unsafe { CategoricalChunked::from_keys_and_values(name, &codes, &categories) };
+                Ok(ca.into_series())
+            }
+            dt => {
+                panic!("{dt:?} dtype deserialization not yet implemented")
+            }
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Series {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, <D as Deserializer<'de>>::Error>
     where
         D: Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["name", "datatype", "values"];
+        const FIELDS: &[&str] = &["version", "name", "datatype", "values"];
 
         struct SeriesVisitor;
 
@@ -91,21 +265,28 @@ impl<'de> Deserialize<'de> for Series {
             type Value = Series;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                formatter
-                    .write_str("struct {name: <name>, datatype: <dtype>, values: <values array>}")
+                formatter.write_str(
+                    "struct {version: <version>, name: <name>, datatype: <dtype>, \
+                    values: <values array>}",
+                )
             }
 
             fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
             where
                 A: MapAccess<'de>,
             {
+                let mut version = None;
                 let mut name: Option<Cow<'de, str>> = None;
                 let mut dtype = None;
-                let mut values_set = false;
-                let mut count = 0;
-                while let Some(key) = map.next_key::<Cow<str>>().unwrap() {
-                    count += 1;
+                let mut values = None;
+                let mut buffered = None;
+                while let Some(key) = map.next_key::<Cow<str>>()? {
                     match key.as_ref() {
+                        "version" => {
+                            let v = map.next_value()?;
+                            check_format_version(v)?;
+                            version = Some(v);
+                        }
                         "name" => {
                             name = match map.next_value::<Cow<str>>() {
                                 Ok(s) => Some(s),
@@ -115,119 +296,43 @@ impl<'de> Deserialize<'de> for Series {
                         "datatype" => {
                             dtype = Some(map.next_value()?);
                         }
-                        "values" => {
-                            // we delay calling next_value until we know the dtype
-                            values_set = true;
-                            if count != 3 {
-                                return Err(de::Error::custom(
-                                    "field values should be behind name and datatype",
-                                ));
-                            }
-                            break;
+                        "values" if values.is_some() || buffered.is_some() => {
+                            return Err(de::Error::duplicate_field("values"));
                         }
+                        "values" => match &dtype {
+                            // only the values of categoricals differ between the versions
+                            Some(dtype)
+                                if version.is_some()
+                                    || !matches!(dtype, DeDataType::Categorical) =>
+                            {
+                                values = Some(map.next_value_seed(ValuesSeed {
+                                    name: "",
+                                    dtype,
+                                    version: version.unwrap_or(0),
+                                })?);
+                            }
+                            // the values come before the fields they depend on
+                            _ => buffered = Some(map.next_value::<Content>()?),
+                        },
                         fld => return Err(de::Error::unknown_field(fld, FIELDS)),
                     }
                 }
-                if !values_set {
-                    return Err(de::Error::missing_field("values"));
-                }
+                // the format was versioned from version 1 on
+                let version = version.unwrap_or(0);
                 let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
                 let dtype = dtype.ok_or_else(|| de::Error::missing_field("datatype"))?;
-
-                match dtype {
-                    #[cfg(feature = "dtype-i8")]
-                    DeDataType::Int8 => {
-                        let values: Vec<Option<i8>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    #[cfg(feature = "dtype-u8")]
-                    DeDataType::UInt8 => {
-                        let values: Vec<Option<u8>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::Int32 => {
-                        let values: Vec<Option<i32>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::UInt32 => {
-                        let values: Vec<Option<u32>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::Int64 => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                let mut s = match (values, buffered) {
+                    (Some(s), _) => s,
+                    (None, Some(content)) => ValuesSeed {
+                        name: "",
+                        dtype: &dtype,
+                        version,
                     }
-                    DeDataType::UInt64 => {
-                        let values: Vec<Option<u64>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    #[cfg(feature = "dtype-date")]
-                    DeDataType::Date => {
-                        let values: Vec<Option<i32>> = map.next_value()?;
-                        Ok(Series::new(&name, values).cast(&DataType::Date).unwrap())
-                    }
-                    #[cfg(feature = "dtype-datetime")]
-                    DeDataType::Datetime(tu, tz) => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values)
-                            .cast(&DataType::Datetime(tu, tz))
-                            .unwrap())
-                    }
-                    #[cfg(feature = "dtype-duration")]
-                    DeDataType::Duration(tu) => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values)
-                            .cast(&DataType::Duration(tu))
-                            .unwrap())
-                    }
-                    #[cfg(feature = "dtype-time")]
-                    DeDataType::Time => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values).cast(&DataType::Time).unwrap())
-                    }
-                    DeDataType::Boolean => {
-                        let values: Vec<Option<bool>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::Float32 => {
-                        let values: Vec<Option<f32>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::Float64 => {
-                        let values: Vec<Option<f64>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::Utf8 => {
-                        let values: Vec<Option<Cow<str>>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::List => {
-                        let values: Vec<Option<Series>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    DeDataType::Binary => {
-                        let values: Vec<Option<Cow<[u8]>>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    }
-                    #[cfg(feature = "dtype-struct")]
-                    DeDataType::Struct => {
-                        let values: Vec<Series> = map.next_value()?;
-                        let ca = StructChunked::new(&name, &values).unwrap();
-                        let mut s = ca.into_series();
-                        s.rename(&name);
-                        Ok(s)
-                    }
-                    #[cfg(feature = "dtype-categorical")]
-                    DeDataType::Categorical => {
-                        let values: Vec<Option<Cow<str>>> = map.next_value()?;
-                        Ok(Series::new(&name, values)
-                            .cast(&DataType::Categorical(None))
-                            .unwrap())
-                    }
-                    dt => {
-                        panic!("{dt:?} dtype deserialization not yet implemented")
-                    }
-                }
+                    .deserialize(content.into_deserializer())?,
+                    (None, None) => return Err(de::Error::missing_field("values")),
+                };
+                s.rename(&name);
+                Ok(s)
             }
         }
 
@@ -65,10 +65,8 @@ fn any_values_to_decimal(
     }
     let Some((s_min, s_max)) = scale_range else {
         // empty array or all nulls, return a decimal array with given scale (or 0 if inferring)
-        return Ok(
-            Int128Chunked::full_null("", avs.len())
-                .into_decimal_unchecked(precision, scale.unwrap_or(0))
-        );
+        return Ok(Int128Chunked::full_null("", avs.len())
+            .into_decimal_unchecked(precision, scale.unwrap_or(0)));
     };
     let scale = scale.unwrap_or(s_max);
     if s_max > scale {
@@ -330,7 +328,7 @@ impl Series {
             }
             DataType::Null => Series::full_null(name, av.len(), &DataType::Null),
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_, _) => {
                 let ca = if let Some(single_av) = av.first() {
                     match single_av {
                         AnyValue::Utf8(_) | AnyValue::Utf8Owned(_) => {
@@ -346,7 +344,8 @@ impl Series {
                     Utf8Chunked::full("", "", 0)
                 };
 
-                ca.cast(&DataType::Categorical(None)).unwrap()
+                ca.cast(&DataType::Categorical(None, Default::default()))
+                    .unwrap()
             }
             dt => panic!("{dt:?} not supported"),
         };
@@ -426,11 +425,11 @@ impl<'a> From<&AnyValue<'a>> for DataType {
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, rev_map, arr) => {
                 if arr.is_null() {
-                    DataType::Categorical(Some(Arc::new((*rev_map).clone())))
+                    DataType::Categorical(Some(Arc::new((*rev_map).clone())), Default::default())
                 } else {
                     let array = unsafe { arr.deref_unchecked().clone() };
                     let rev_map = RevMapping::Local(array);
-                    DataType::Categorical(Some(Arc::new(rev_map)))
+                    DataType::Categorical(Some(Arc::new(rev_map)), Default::default())
                 }
             }
             #[cfg(feature = "object")]
@@ -441,33 +441,47 @@ impl Sub for &Series {
     type Output = Series;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.sub(b))
-            }
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.subtract(rhs.as_ref()).expect("data types don't match")
-            }
-        }
+        self.try_sub(rhs).unwrap()
     }
 }
 
-impl Series {
-    pub fn try_add(&self, rhs: &Series) -> PolarsResult<Series> {
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                Ok(_struct_arithmetic(self, rhs, |a, b| a.add(b)))
-            }
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
-                lhs.add_to(rhs.as_ref())
+/// Whether both operands are decimals or integers, with at least one decimal.
+#[cfg(feature = "dtype-decimal")]
+fn is_decimal_arithmetic(lhs: &DataType, rhs: &DataType) -> bool {
+    (matches!(lhs, DataType::Decimal(_, _)) || matches!(rhs, DataType::Decimal(_, _)))
+        && decimal::is_decimal_operand(lhs)
+        && decimal::is_decimal_operand(rhs)
+}
+
+macro_rules! impl_try_arithmetic {
+    ($try_method:ident, $method:ident, $dispatch:ident) => {
+        pub fn $try_method(&self, rhs: &Series) -> PolarsResult<Series> {
+            match (self.dtype(), rhs.dtype()) {
+                #[cfg(feature = "dtype-struct")]
+                (DataType::Struct(_), DataType::Struct(_)) => {
+                    Ok(_struct_arithmetic(self, rhs, |a, b| a.$method(b)))
+                }
+                #[cfg(feature = "dtype-decimal")]
+                (l, r) if is_decimal_arithmetic(l, r) => {
+                    let (lhs, rhs) = decimal::coerce_decimal_lhs_rhs(self, rhs)?;
+                    lhs.$dispatch(rhs.as_ref())
+                }
+                _ => {
+                    let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                    lhs.$dispatch(rhs.as_ref())
+                }
             }
         }
-    }
+    };
+}
+
+impl Series {
+    impl_try_arithmetic!(try_add, add, add_to);
+    impl_try_arithmetic!(try_sub, sub, subtract);
+    impl_try_arithmetic!(try_mul, mul, multiply);
+    impl_try_arithmetic!(try_div, div, divide);
 }
+
 impl Add for &Series {
     type Output = Series;
 
@@ -485,16 +499,7 @@ impl Mul for &Series {
     /// let out = &s * &s;
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.mul(b))
-            }
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.multiply(rhs.as_ref()).expect("data types don't match")
-            }
-        }
+        self.try_mul(rhs).unwrap()
     }
 }
 
@@ -507,16 +512,7 @@ impl Div for &Series {
     /// let out = &s / &s;
     /// ```
     fn div(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.div(b))
-            }
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.divide(rhs.as_ref()).expect("data types don't match")
-            }
-        }
+        self.try_div(rhs).unwrap()
     }
 }
 
@@ -0,0 +1,338 @@
+//! Checked arithmetic on decimals.
+//!
+//! The precision and scale of the result follow the SQL rules. If that precision exceeds 38
+//! digits, it is capped and the scale is reduced to keep the integer digits, but no further
+//! than 6 digits. A result that doesn't fit the output precision is an error rather than a
+//! wrapped around `i128`.
+use std::ops::Deref;
+
+use super::*;
+use crate::utils::{integer_decimal_precision, MAX_DECIMAL_PRECISION};
+
+/// An arithmetic operation between decimals.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecimalOp {
+    /// Result scale: `max(s1, s2)`, precision: `max(p1 - s1, p2 - s2) + max(s1, s2) + 1`.
+    Add,
+    /// Result scale: `max(s1, s2)`, precision: `max(p1 - s1, p2 - s2) + max(s1, s2) + 1`.
+    Sub,
+    /// Result scale: `s1 + s2`, precision: `p1 + p2 + 1`.
+    Mul,
+    /// Result scale: `max(6, s1 + p2 + 1)`, precision: `p1 - s1 + s2 + scale`. The quotient
+    /// is truncated towards zero.
+    Div,
+}
+
+impl DecimalOp {
+    fn name(self) -> &'static str {
+        match self {
+            DecimalOp::Add => "add",
+            DecimalOp::Sub => "sub",
+            DecimalOp::Mul => "mul",
+            DecimalOp::Div => "div",
+        }
+    }
+
+    /// The precision and scale of the result, given those of the operands.
+    fn precision_scale(self, (p1, s1): (usize, usize), (p2, s2): (usize, usize)) -> (usize, usize) {
+        let (precision, scale) = match self {
+            DecimalOp::Add | DecimalOp::Sub => {
+                let scale = s1.max(s2);
+                let int_digits = p1.saturating_sub(s1).max(p2.saturating_sub(s2));
+                (int_digits + scale + 1, scale)
+            }
+            DecimalOp::Mul => (p1 + p2 + 1, s1 + s2),
+            DecimalOp::Div => {
+                let scale = std::cmp::max(6, s1 + p2 + 1);
+                (p1.saturating_sub(s1) + s2 + scale, scale)
+            }
+        };
+        adjust_precision_scale(precision, scale)
+    }
+}
+
+/// Cap `precision` at 38 digits, reducing `scale` to keep the integer digits, but keeping at
+/// least `min(scale, 6)` fractional digits.
+fn adjust_precision_scale(precision: usize, scale: usize) -> (usize, usize) {
+    if precision <= MAX_DECIMAL_PRECISION {
+        (precision, scale)
+    } else {
+        let int_digits = precision - scale;
+        let min_scale = std::cmp::min(scale, 6);
+        let scale = std::cmp::max(MAX_DECIMAL_PRECISION.saturating_sub(int_digits), min_scale);
+        (MAX_DECIMAL_PRECISION, scale)
+    }
+}
+
+/// The precision and scale of a decimal operand, an integer is a decimal of scale zero.
+fn operand_precision_scale(dtype: &DataType) -> Option<(usize, usize)> {
+    match dtype {
+        DataType::Decimal(precision, scale) => Some((
+            precision.unwrap_or(MAX_DECIMAL_PRECISION),
+            scale.unwrap_or(0),
+        )),
+        dt => integer_decimal_precision(dt).map(|digits| (digits, 0)),
+    }
+}
+
+/// The dtype of `lhs <op> rhs` if both are decimals or integers and at least one of them is
+/// a decimal.
+pub fn decimal_arithmetic_dtype(lhs: &DataType, rhs: &DataType, op: DecimalOp) -> Option<DataType> {
+    if !matches!(lhs, DataType::Decimal(_, _)) && !matches!(rhs, DataType::Decimal(_, _)) {
+        return None;
+    }
+    let (precision, scale) =
+        op.precision_scale(operand_precision_scale(lhs)?, operand_precision_scale(rhs)?);
+    Some(DataType::Decimal(Some(precision), Some(scale)))
+}
+
+/// Cast integer operands to a decimal of scale zero. Unlike [`coerce_lhs_rhs`] the scales of
+/// the operands are kept, as the scale of a product or a quotient depends on both of them.
+pub(crate) fn coerce_decimal_lhs_rhs<'a>(
+    lhs: &'a Series,
+    rhs: &'a Series,
+) -> PolarsResult<(Cow<'a, Series>, Cow<'a, Series>)> {
+    fn to_decimal(s: &Series) -> PolarsResult<Cow<Series>> {
+        match integer_decimal_precision(s.dtype()) {
+            Some(digits) => Ok(Cow::Owned(
+                s.cast(&DataType::Decimal(Some(digits), Some(0)))?,
+            )),
+            None => Ok(Cow::Borrowed(s)),
+        }
+    }
+    Ok((to_decimal(lhs)?, to_decimal(rhs)?))
+}
+
+fn precision_scale(ca: &DecimalChunked) -> (usize, usize) {
+    (ca.precision().unwrap_or(MAX_DECIMAL_PRECISION), ca.scale())
+}
+
+fn pow10(exp: usize) -> PolarsResult<i128> {
+    10_i128
+        .checked_pow(exp as u32)
+        .ok_or_else(|| polars_err!(ComputeError: "decimal scale {} is out of range", exp))
+}
+
+fn overflow(op: DecimalOp) -> PolarsError {
+    polars_err!(ComputeError: "decimal overflow in {}", op.name())
+}
+
+/// Apply `f` to every pair of values and check that the results fit in `precision` digits.
+/// An input of length 1 is broadcast.
+fn apply_binary<F>(
+    lhs: &DecimalChunked,
+    rhs: &DecimalChunked,
+    op: DecimalOp,
+    f: F,
+) -> PolarsResult<Series>
+where
+    F: Fn(i128, i128) -> PolarsResult<i128>,
+{
+    let (precision, scale) = op.precision_scale(precision_scale(lhs), precision_scale(rhs));
+    let max = pow10(precision)? as u128;
+    let apply = |a: Option<i128>, b: Option<i128>| match (a, b) {
+        (Some(a), Some(b)) => {
+            let v = f(a, b)?;
+            polars_ensure!(
+                v.unsigned_abs() < max,
+                ComputeError: "decimal overflow in {}: the result doesn't fit in {} digits",
+                op.name(), precision
+            );
+            Ok(Some(v))
+        }
+        _ => Ok(None),
+    };
+
+    let name = lhs.name();
+    let (lhs, rhs) = (lhs.deref(), rhs.deref());
+    let mut out: Int128Chunked = match (lhs.len(), rhs.len()) {
+        (_, 1) => {
+            let b = rhs.get(0);
+            lhs.into_iter()
+                .map(|a| apply(a, b))
+                .collect::<PolarsResult<_>>()?
+        }
+        (1, _) => {
+            let a = lhs.get(0);
+            rhs.into_iter()
+                .map(|b| apply(a, b))
+                .collect::<PolarsResult<_>>()?
+        }
+        (l, r) => {
+            polars_ensure!(
+                l == r,
+                ShapeMismatch: "cannot {} decimals of length {} and {}", op.name(), l, r
+            );
+            lhs.into_iter()
+                .zip(rhs.into_iter())
+                .map(|(a, b)| apply(a, b))
+                .collect::<PolarsResult<_>>()?
+        }
+    };
+    out.rename(name);
+    Ok(out
+        .into_decimal_unchecked(Some(precision), scale)
+        .into_series())
+}
+
+fn add_sub(lhs: &DecimalChunked, rhs: &DecimalChunked, op: DecimalOp) -> PolarsResult<Series> {
+    let ((_, s1), (_, s2)) = (precision_scale(lhs), precision_scale(rhs));
+    let (_, scale) = op.precision_scale(precision_scale(lhs), precision_scale(rhs));
+    // if the scale was reduced, the extra fractional digits are truncated
+    let rescale = |v: i128, s: usize| -> Option<i128> {
+        if s <= scale {
+            v.checked_mul(10_i128.pow((scale - s) as u32))
+        } else {
+            Some(v / 10_i128.pow((s - scale) as u32))
+        }
+    };
+    let f = match op {
+        DecimalOp::Add => i128::checked_add,
+        _ => i128::checked_sub,
+    };
+    apply_binary(lhs, rhs, op, |a, b| {
+        rescale(a, s1)
+            .zip(rescale(b, s2))
+            .and_then(|(a, b)| f(a, b))
+            .ok_or_else(|| overflow(op))
+    })
+}
+
+pub(crate) fn add(lhs: &DecimalChunked, rhs: &DecimalChunked) -> PolarsResult<Series> {
+    add_sub(lhs, rhs, DecimalOp::Add)
+}
+
+pub(crate) fn sub(lhs: &DecimalChunked, rhs: &DecimalChunked) -> PolarsResult<Series> {
+    add_sub(lhs, rhs, DecimalOp::Sub)
+}
+
+pub(crate) fn mul(lhs: &DecimalChunked, rhs: &DecimalChunked) -> PolarsResult<Series> {
+    let ((_, s1), (_, s2)) = (precision_scale(lhs), precision_scale(rhs));
+    let (_, scale) = DecimalOp::Mul.precision_scale(precision_scale(lhs), precision_scale(rhs));
+    // the product has scale `s1 + s2`, which is at least `scale`
+    let divisor = pow10(s1 + s2 - scale)?;
+    apply_binary(lhs, rhs, DecimalOp::Mul, |a, b| {
+        a.checked_mul(b)
+            .map(|v| v / divisor)
+            .ok_or_else(|| overflow(DecimalOp::Mul))
+    })
+}
+
+pub(crate) fn div(lhs: &DecimalChunked, rhs: &DecimalChunked) -> PolarsResult<Series> {
+    let ((_, s1), (_, s2)) = (precision_scale(lhs), precision_scale(rhs));
+    let (_, scale) = DecimalOp::Div.precision_scale(precision_scale(lhs), precision_scale(rhs));
+    // `a / b` has scale `s1 - s2`, so the dividend is scaled up to end with `scale`
+    let factor = pow10((scale + s2).saturating_sub(s1))?;
+    apply_binary(lhs, rhs, DecimalOp::Div, |a, b| {
+        polars_ensure!(b != 0, ComputeError: "decimal division by zero");
+        a.checked_mul(factor)
+            .and_then(|a| a.checked_div(b))
+            .ok_or_else(|| overflow(DecimalOp::Div))
+    })
+}
+
+/// The checked sum of the values, or `None` if they are all null.
+pub(crate) fn sum(ca: &DecimalChunked) -> PolarsResult<Option<i128>> {
+    if ca.null_count() == ca.len() {
+        return Ok(None);
+    }
+    let max = pow10(MAX_DECIMAL_PRECISION)? as u128;
+    let mut acc = 0i128;
+    for v in ca.deref().into_iter().flatten() {
+        acc = acc
+            .checked_add(v)
+            .filter(|v| v.unsigned_abs() < max)
+            .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in sum"))?;
+    }
+    Ok(Some(acc))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn decimal(values: &[Option<i128>], precision: usize, scale: usize) -> Series {
+        Int128Chunked::from_slice_options("a", values)
+            .into_decimal_unchecked(Some(precision), scale)
+            .into_series()
+    }
+
+    fn values(s: &Series) -> Vec<Option<i128>> {
+        s.decimal().unwrap().deref().into_iter().collect()
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() -> PolarsResult<()> {
+        // 1.50, -2.25, null
+        let a = decimal(&[Some(150), Some(-225), None], 5, 2);
+        // 0.5
+        let b = decimal(&[Some(5)], 3, 1);
+
+        let out = a.try_add(&b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(6), Some(2)));
+        assert_eq!(values(&out), &[Some(200), Some(-175), None]);
+
+        let out = a.try_sub(&b)?;
+        assert_eq!(values(&out), &[Some(100), Some(-275), None]);
+
+        let out = a.try_mul(&b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(9), Some(3)));
+        assert_eq!(values(&out), &[Some(750), Some(-1125), None]);
+
+        let out = a.try_div(&b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(10), Some(6)));
+        assert_eq!(values(&out), &[Some(3_000_000), Some(-4_500_000), None]);
+
+        // integers are decimals of scale zero
+        let out = a.try_mul(&Series::new("b", [2i32]))?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(16), Some(2)));
+        assert_eq!(values(&out), &[Some(300), Some(-450), None]);
+
+        // overflow is an error, not a wrap around
+        let big = decimal(&[Some(10_i128.pow(37))], 38, 0);
+        assert!(big.try_mul(&big).is_err());
+        assert!(a.try_div(&decimal(&[Some(0)], 3, 1)).is_err());
+
+        assert_eq!(
+            decimal_arithmetic_dtype(
+                &DataType::Decimal(Some(5), Some(2)),
+                &DataType::Int8,
+                DecimalOp::Add
+            ),
+            Some(DataType::Decimal(Some(6), Some(2)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_aggregations() -> PolarsResult<()> {
+        let a = decimal(&[Some(150), Some(-225), None, Some(1000)], 5, 2);
+        let sum = a.sum_as_series()?;
+        assert_eq!(sum.dtype(), &DataType::Decimal(Some(38), Some(2)));
+        assert_eq!(values(&sum), &[Some(925)]);
+        assert_eq!(values(&a.min_as_series()), &[Some(-225)]);
+        assert_eq!(values(&a.max_as_series()), &[Some(1000)]);
+        assert_eq!(a.mean(), Some(9.25 / 3.0));
+
+        // a sum that doesn't fit in 38 digits errors
+        let big = decimal(
+            &[Some(10_i128.pow(37) * 6), Some(10_i128.pow(37) * 6)],
+            38,
+            0,
+        );
+        let err = big.sum_as_series().unwrap_err();
+        assert!(matches!(err, PolarsError::ComputeError(_)));
+
+        let sorted = a.sort(false);
+        assert_eq!(sorted.dtype(), a.dtype());
+        assert_eq!(values(&sorted), &[None, Some(-225), Some(150), Some(1000)]);
+
+        let b = decimal(&[Some(15), Some(-3), Some(5), Some(10)], 3, 1);
+        let mask = a.gt(&b)?;
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(false), Some(false), None, Some(true)]
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 mod borrowed;
+#[cfg(feature = "dtype-decimal")]
+pub mod decimal;
 mod owned;
 
 use std::borrow::Cow;
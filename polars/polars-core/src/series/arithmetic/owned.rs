@@ -34,6 +34,19 @@ where
     op(lhs, rhs).into_series()
 }
 
+#[cfg(feature = "performant")]
+#[allow(unused_variables)]
+fn is_decimal(dtype: &DataType) -> bool {
+    #[cfg(feature = "dtype-decimal")]
+    {
+        matches!(dtype, DataType::Decimal(_, _))
+    }
+    #[cfg(not(feature = "dtype-decimal"))]
+    {
+        false
+    }
+}
+
 macro_rules! impl_operation {
     ($operation:ident, $method:ident, $function:expr) => {
         impl $operation for Series {
@@ -46,6 +59,8 @@ macro_rules! impl_operation {
                     if !self.dtype().is_logical()
                         && self.dtype().to_physical().is_numeric()
                         && rhs.dtype().to_physical().is_numeric()
+                        && !is_decimal(self.dtype())
+                        && !is_decimal(rhs.dtype())
                     {
                         let (lhs, rhs) = coerce_lhs_rhs_owned(self, rhs).unwrap();
                         let (lhs, rhs) = align_chunks_binary_owned_series(lhs, rhs);
@@ -5,7 +5,8 @@
     feature = "dtype-datetime",
     feature = "dtype-date",
     feature = "dtype-time",
-    feature = "dtype-struct"
+    feature = "dtype-struct",
+    feature = "dtype-decimal"
 ))]
 use std::ops::Deref;
 
@@ -39,6 +40,13 @@ macro_rules! impl_compare {
                 .unwrap()
                 .$method(rhs.duration().unwrap().deref()),
             DataType::List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
+            // the scales are equal after coercion, so the integers can be compared
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => lhs
+                .decimal()
+                .unwrap()
+                .deref()
+                .$method(rhs.decimal().unwrap().deref()),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => lhs
                 .struct_()
@@ -89,12 +97,31 @@ where
     }
 }
 
+/// If one of the operands is a lexically ordered categorical, the string values of both
+/// operands, so that ordering comparisons follow the lexical order.
+#[cfg(feature = "dtype-categorical")]
+fn lexical_operands(lhs: &Series, rhs: &Series) -> PolarsResult<Option<(Series, Series)>> {
+    let is_lexical = |s: &Series| {
+        s.categorical()
+            .map(|ca| ca.get_ordering() == CategoricalOrdering::Lexical)
+            .unwrap_or(false)
+    };
+    if is_lexical(lhs) || is_lexical(rhs) {
+        Ok(Some((
+            lhs.cast(&DataType::Utf8)?,
+            rhs.cast(&DataType::Utf8)?,
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
 fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
     #[cfg(feature = "dtype-categorical")]
     {
-        let mismatch = matches!(left, Utf8 | Categorical(_)) && right.is_numeric()
-            || left.is_numeric() && matches!(right, Utf8 | Categorical(_));
+        let mismatch = matches!(left, Utf8 | Categorical(_, _)) && right.is_numeric()
+            || left.is_numeric() && matches!(right, Utf8 | Categorical(_, _));
         polars_ensure!(!mismatch, ComputeError: "cannot compare utf-8 with numeric data");
     }
     #[cfg(not(feature = "dtype-categorical"))]
@@ -115,7 +142,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(_), Utf8, _, 1) => {
+            (Categorical(_, _), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -125,7 +152,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical(_), 1, _) => {
+            (Utf8, Categorical(_, _), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -135,7 +162,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(Some(rev_map_l)), Categorical(Some(rev_map_r)), _, _) => {
+            (Categorical(Some(rev_map_l), _), Categorical(Some(rev_map_r), _), _, _) => {
                 if rev_map_l.same_src(rev_map_r) {
                     let rhs = rhs.categorical().unwrap().logical();
 
@@ -149,11 +176,7 @@ impl ChunkCompare<&Series> for Series {
 
                     self.categorical().unwrap().logical().equal(rhs)
                 } else {
-                    polars_bail!(
-                        ComputeError:
-                        "cannot compare categoricals originating from different sources; \
-                        consider setting a global string cache"
-                    );
+                    return Err(string_cache_mismatch_err("equality comparison"));
                 }
             }
             (Null, Null, _, _) => BooleanChunked::full(self.name(), true, self.len()),
@@ -171,7 +194,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(_), Utf8, _, 1) => {
+            (Categorical(_, _), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -181,7 +204,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical(_), 1, _) => {
+            (Utf8, Categorical(_, _), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -191,7 +214,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(Some(rev_map_l)), Categorical(Some(rev_map_r)), _, _) => {
+            (Categorical(Some(rev_map_l), _), Categorical(Some(rev_map_r), _), _, _) => {
                 if rev_map_l.same_src(rev_map_r) {
                     let rhs = rhs.categorical().unwrap().logical();
 
@@ -205,11 +228,7 @@ impl ChunkCompare<&Series> for Series {
 
                     self.categorical().unwrap().logical().not_equal(rhs)
                 } else {
-                    polars_bail!(
-                        ComputeError:
-                        "cannot compare categoricals originating from different sources; \
-                        consider setting a global string cache"
-                    );
+                    return Err(string_cache_mismatch_err("equality comparison"));
                 }
             }
             (Null, Null, _, _) => BooleanChunked::full(self.name(), false, self.len()),
@@ -224,6 +243,10 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self > rhs.
     fn gt(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = lexical_operands(self, rhs)? {
+            return lhs.gt(&rhs);
+        }
         let mut out = impl_compare!(self, rhs, gt);
         out.rename(self.name());
         Ok(out)
@@ -232,6 +255,10 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self >= rhs.
     fn gt_eq(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = lexical_operands(self, rhs)? {
+            return lhs.gt_eq(&rhs);
+        }
         let mut out = impl_compare!(self, rhs, gt_eq);
         out.rename(self.name());
         Ok(out)
@@ -240,6 +267,10 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self < rhs.
     fn lt(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = lexical_operands(self, rhs)? {
+            return lhs.lt(&rhs);
+        }
         let mut out = impl_compare!(self, rhs, lt);
         out.rename(self.name());
         Ok(out)
@@ -248,6 +279,10 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self <= rhs.
     fn lt_eq(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = lexical_operands(self, rhs)? {
+            return lhs.lt_eq(&rhs);
+        }
         let mut out = impl_compare!(self, rhs, lt_eq);
         out.rename(self.name());
         Ok(out)
@@ -303,6 +338,13 @@ fn compare_series_str(
     op: impl Fn(&Utf8Chunked, &str) -> BooleanChunked,
 ) -> PolarsResult<BooleanChunked> {
     validate_types(lhs.dtype(), &DataType::Utf8)?;
+    #[cfg(feature = "dtype-categorical")]
+    if let Ok(ca) = lhs.categorical() {
+        if ca.get_ordering() == CategoricalOrdering::Lexical {
+            let lhs = lhs.cast(&DataType::Utf8)?;
+            return Ok(op(lhs.utf8().unwrap(), rhs));
+        }
+    }
     lhs.utf8().map(|ca| op(ca, rhs)).map_err(|_| {
         polars_err!(
             ComputeError: "cannot compare str value to series of type {}", lhs.dtype(),
@@ -319,7 +361,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => Ok(self.utf8().unwrap().equal(rhs)),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => {
+            Categorical(_, _) => {
                 compare_cat_to_str_value(self, rhs, self.name(), |lhs, idx| lhs.equal(idx), false)
             }
             _ => Ok(BooleanChunked::full(self.name(), false, self.len())),
@@ -332,7 +374,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => Ok(self.utf8().unwrap().not_equal(rhs)),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => compare_cat_to_str_value(
+            Categorical(_, _) => compare_cat_to_str_value(
                 self,
                 rhs,
                 self.name(),
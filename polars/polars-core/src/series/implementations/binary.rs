@@ -274,8 +274,8 @@ impl SeriesTrait for SeriesWrap<BinaryChunked> {
         ChunkShift::shift(&self.0, periods).into_series()
     }
 
-    fn _sum_as_series(&self) -> Series {
-        ChunkAggSeries::sum_as_series(&self.0)
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        Ok(ChunkAggSeries::sum_as_series(&self.0))
     }
     fn max_as_series(&self) -> Series {
         ChunkAggSeries::max_as_series(&self.0)
@@ -292,7 +292,7 @@ impl SeriesTrait for SeriesWrap<BinaryChunked> {
         IsIn::is_in(&self.0, other)
     }
     #[cfg(feature = "repeat_by")]
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
         RepeatBy::repeat_by(&self.0, by)
     }
 
@@ -41,6 +41,29 @@ impl SeriesWrap<CategoricalChunked> {
         self.finish_with_state(keep_fast_unique, cats)
     }
 
+    /// The smallest, or largest if `max`, value in the ordering of the categorical.
+    fn extreme_as_series(&self, max: bool) -> Series {
+        let cats = self.0.logical();
+        let cat = if self.0.use_lexical_sort() {
+            let ranks = self.0.lexical_ranks();
+            let pairs = cats.into_iter().zip(ranks.into_iter());
+            let extreme = if max {
+                pairs.max_by_key(|(_, rank)| *rank)
+            } else {
+                pairs
+                    .filter(|(_, rank)| rank.is_some())
+                    .min_by_key(|(_, rank)| *rank)
+            };
+            extreme.and_then(|(cat, _)| cat)
+        } else if max {
+            cats.max()
+        } else {
+            cats.min()
+        };
+        let cats = UInt32Chunked::from_slice_options(cats.name(), &[cat]);
+        self.finish_with_state(false, cats).into_series()
+    }
+
     fn try_with_state<'a, F>(
         &'a self,
         keep_fast_unique: bool,
@@ -74,7 +97,10 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
     }
 
     fn _set_sorted_flag(&mut self, is_sorted: IsSorted) {
-        self.0.logical_mut().set_sorted_flag(is_sorted)
+        // the flag is kept on the physical categories, so it can't express a lexical order
+        if !self.0.use_lexical_sort() {
+            self.0.logical_mut().set_sorted_flag(is_sorted)
+        }
     }
 
     unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
@@ -120,7 +146,7 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
     ) -> Series {
         let new_rev_map = self
             .0
-            .merge_categorical_map(right_column.categorical().unwrap())
+            .merge_categorical_map(right_column.categorical().unwrap(), "outer join")
             .unwrap();
         let left = self.0.logical();
         let right = right_column
@@ -155,7 +181,9 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
 
 impl SeriesTrait for SeriesWrap<CategoricalChunked> {
     fn is_sorted_flag(&self) -> IsSorted {
-        if self.0.logical().is_sorted_ascending_flag() {
+        if self.0.use_lexical_sort() {
+            IsSorted::Not
+        } else if self.0.logical().is_sorted_ascending_flag() {
             IsSorted::Ascending
         } else if self.0.logical().is_sorted_descending_flag() {
             IsSorted::Descending
@@ -196,7 +224,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         polars_ensure!(self.0.dtype() == other.dtype(), extend);
         let other = other.categorical()?;
         self.0.logical_mut().extend(other.logical());
-        let new_rev_map = self.0.merge_categorical_map(other)?;
+        let new_rev_map = self.0.merge_categorical_map(other, "extend")?;
         // SAFETY
         // rev_maps are merged
         unsafe { self.0.set_rev_map(new_rev_map, false) };
@@ -342,14 +370,14 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         self.with_state(false, |ca| ca.shift(periods)).into_series()
     }
 
-    fn _sum_as_series(&self) -> Series {
-        CategoricalChunked::full_null(self.0.logical().name(), 1).into_series()
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        Ok(CategoricalChunked::full_null(self.0.logical().name(), 1).into_series())
     }
     fn max_as_series(&self) -> Series {
-        CategoricalChunked::full_null(self.0.logical().name(), 1).into_series()
+        self.extreme_as_series(true)
     }
     fn min_as_series(&self) -> Series {
-        CategoricalChunked::full_null(self.0.logical().name(), 1).into_series()
+        self.extreme_as_series(false)
     }
     fn median_as_series(&self) -> Series {
         CategoricalChunked::full_null(self.0.logical().name(), 1).into_series()
@@ -374,16 +402,14 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
 
     #[cfg(feature = "is_in")]
     fn is_in(&self, other: &Series) -> PolarsResult<BooleanChunked> {
-        _check_categorical_src(self.dtype(), other.dtype())?;
+        _check_categorical_src(self.dtype(), other.dtype(), "is_in")?;
         self.0.logical().is_in(&other.to_physical_repr())
     }
     #[cfg(feature = "repeat_by")]
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        let out = self.0.logical().repeat_by(by);
-        let casted = out
-            .cast(&DataType::List(Box::new(self.dtype().clone())))
-            .unwrap();
-        casted.list().unwrap().clone()
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
+        let out = self.0.logical().repeat_by(by)?;
+        let casted = out.cast(&DataType::List(Box::new(self.dtype().clone())))?;
+        Ok(casted.list().unwrap().clone())
     }
 
     #[cfg(feature = "mode")]
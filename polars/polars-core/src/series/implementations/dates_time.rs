@@ -386,11 +386,8 @@ macro_rules! impl_dyn_series {
                 self.0.shift(periods).$into_logical().into_series()
             }
 
-            fn _sum_as_series(&self) -> Series {
-                Int32Chunked::full_null(self.name(), 1)
-                    .cast(self.dtype())
-                    .unwrap()
-                    .into()
+            fn _sum_as_series(&self) -> PolarsResult<Series> {
+                Int32Chunked::full_null(self.name(), 1).cast(self.dtype())
             }
             fn max_as_series(&self) -> Series {
                 self.0.max_as_series().$into_logical()
@@ -421,10 +418,7 @@ macro_rules! impl_dyn_series {
                 _quantile: f64,
                 _interpol: QuantileInterpolOptions,
             ) -> PolarsResult<Series> {
-                Ok(Int32Chunked::full_null(self.name(), 1)
-                    .cast(self.dtype())
-                    .unwrap()
-                    .into())
+                Int32Chunked::full_null(self.name(), 1).cast(self.dtype())
             }
 
             fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
@@ -443,26 +437,11 @@ macro_rules! impl_dyn_series {
                 self.0.is_in(other)
             }
             #[cfg(feature = "repeat_by")]
-            fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-                match self.0.dtype() {
-                    DataType::Date => self
-                        .0
-                        .repeat_by(by)
-                        .cast(&DataType::List(Box::new(DataType::Date)))
-                        .unwrap()
-                        .list()
-                        .unwrap()
-                        .clone(),
-                    DataType::Time => self
-                        .0
-                        .repeat_by(by)
-                        .cast(&DataType::List(Box::new(DataType::Time)))
-                        .unwrap()
-                        .list()
-                        .unwrap()
-                        .clone(),
-                    _ => unreachable!(),
-                }
+            fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
+                self.0
+                    .repeat_by(by)?
+                    .cast(&DataType::List(Box::new(self.dtype().clone())))
+                    .map(|s| s.list().unwrap().clone())
             }
             #[cfg(feature = "mode")]
             fn mode(&self) -> PolarsResult<Series> {
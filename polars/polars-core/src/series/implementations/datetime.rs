@@ -405,10 +405,8 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
             .into_series()
     }
 
-    fn _sum_as_series(&self) -> Series {
-        Int32Chunked::full_null(self.name(), 1)
-            .cast(self.dtype())
-            .unwrap()
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        Int32Chunked::full_null(self.name(), 1).cast(self.dtype())
     }
     fn max_as_series(&self) -> Series {
         self.0
@@ -440,9 +438,7 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
         _quantile: f64,
         _interpol: QuantileInterpolOptions,
     ) -> PolarsResult<Series> {
-        Ok(Int32Chunked::full_null(self.name(), 1)
-            .cast(self.dtype())
-            .unwrap())
+        Int32Chunked::full_null(self.name(), 1).cast(self.dtype())
     }
 
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
@@ -461,17 +457,14 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
         self.0.is_in(other)
     }
     #[cfg(feature = "repeat_by")]
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
         self.0
-            .repeat_by(by)
+            .repeat_by(by)?
             .cast(&DataType::List(Box::new(DataType::Datetime(
                 self.0.time_unit(),
                 self.0.time_zone().clone(),
             ))))
-            .unwrap()
-            .list()
-            .unwrap()
-            .clone()
+            .map(|s| s.list().unwrap().clone())
     }
     #[cfg(feature = "mode")]
     fn mode(&self) -> PolarsResult<Series> {
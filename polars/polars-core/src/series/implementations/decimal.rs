@@ -1,5 +1,7 @@
 use super::{private, IntoSeries, SeriesTrait, SeriesWrap, *};
 use crate::prelude::*;
+use crate::series::arithmetic::decimal as decimal_arithmetic;
+use crate::utils::MAX_DECIMAL_PRECISION;
 
 unsafe impl IntoSeries for DecimalChunked {
     fn into_series(self) -> Series {
@@ -37,6 +39,23 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
             .into_decimal_unchecked(self.0.precision(), self.0.scale())
             .into_series())
     }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        decimal_arithmetic::sub(&self.0, rhs.decimal()?)
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        decimal_arithmetic::add(&self.0, rhs.decimal()?)
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        decimal_arithmetic::mul(&self.0, rhs.decimal()?)
+    }
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        decimal_arithmetic::div(&self.0, rhs.decimal()?)
+    }
+
+    fn arg_sort_multiple(&self, by: &[Series], descending: &[bool]) -> PolarsResult<IdxCa> {
+        self.0.deref().arg_sort_multiple(by, descending)
+    }
 }
 
 impl SeriesTrait for SeriesWrap<DecimalChunked> {
@@ -194,6 +213,46 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
         self.apply_logical(|ca| ca.shift(periods))
     }
 
+    fn sort_with(&self, options: SortOptions) -> Series {
+        self.apply_logical(|ca| ca.sort_with(options))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.arg_sort(options)
+    }
+
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        let sum = decimal_arithmetic::sum(&self.0)?;
+        Ok(Int128Chunked::from_slice_options(self.name(), &[sum])
+            .into_decimal_unchecked(Some(MAX_DECIMAL_PRECISION), self.0.scale())
+            .into_series())
+    }
+
+    fn max_as_series(&self) -> Series {
+        Int128Chunked::from_slice_options(self.name(), &[self.0.max()])
+            .into_decimal_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn min_as_series(&self) -> Series {
+        Int128Chunked::from_slice_options(self.name(), &[self.0.min()])
+            .into_decimal_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        let count = self.0.len() - self.0.null_count();
+        if count == 0 {
+            return None;
+        }
+        let sum = match decimal_arithmetic::sum(&self.0) {
+            Ok(Some(sum)) => sum as f64,
+            // the decimal sum overflowed, so sum as floats instead
+            _ => self.0.deref().into_iter().flatten().map(|v| v as f64).sum(),
+        };
+        Some(sum / 10f64.powi(self.0.scale() as i32) / count as f64)
+    }
+
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
         Arc::new(SeriesWrap(Clone::clone(&self.0)))
     }
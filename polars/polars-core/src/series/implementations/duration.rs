@@ -418,8 +418,8 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
             .into_series()
     }
 
-    fn _sum_as_series(&self) -> Series {
-        self.0.sum_as_series().into_duration(self.0.time_unit())
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        Ok(self.0.sum_as_series().into_duration(self.0.time_unit()))
     }
 
     fn max_as_series(&self) -> Series {
@@ -469,16 +469,13 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
         self.0.is_in(other)
     }
     #[cfg(feature = "repeat_by")]
-    fn repeat_by(&self, by: &IdxCa) -> ListChunked {
+    fn repeat_by(&self, by: &IdxCa) -> PolarsResult<ListChunked> {
         self.0
-            .repeat_by(by)
+            .repeat_by(by)?
             .cast(&DataType::List(Box::new(DataType::Duration(
                 self.0.time_unit(),
             ))))
-            .unwrap()
-            .list()
-            .unwrap()
-            .clone()
+            .map(|s| s.list().unwrap().clone())
     }
     #[cfg(feature = "mode")]
     fn mode(&self) -> PolarsResult<Series> {
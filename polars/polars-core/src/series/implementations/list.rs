@@ -186,8 +186,8 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
         ChunkShift::shift(&self.0, periods).into_series()
     }
 
-    fn _sum_as_series(&self) -> Series {
-        ChunkAggSeries::sum_as_series(&self.0)
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        Ok(ChunkAggSeries::sum_as_series(&self.0))
     }
     fn max_as_series(&self) -> Series {
         ChunkAggSeries::max_as_series(&self.0)
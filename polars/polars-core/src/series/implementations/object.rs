@@ -228,8 +228,8 @@ where
         &self.0
     }
 
-    fn _sum_as_series(&self) -> Series {
-        ObjectChunked::<T>::full_null(self.name(), 1).into_series()
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        Ok(ObjectChunked::<T>::full_null(self.name(), 1).into_series())
     }
     fn max_as_series(&self) -> Series {
         ObjectChunked::<T>::full_null(self.name(), 1).into_series()
@@ -43,12 +43,18 @@ impl private::PrivateSeries for SeriesWrap<StructChunked> {
     #[cfg(feature = "zip_with")]
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
         let other = other.struct_()?;
+        polars_ensure!(
+            self.0.fields().len() == other.fields().len(),
+            SchemaMismatch: "cannot zip struct with {} fields with struct with {} fields",
+            self.0.fields().len(), other.fields().len()
+        );
+        // recurse with `zip_with`, so that nested fields are coerced and unit lengths broadcast
         let fields = self
             .0
             .fields()
             .iter()
             .zip(other.fields())
-            .map(|(lhs, rhs)| lhs.zip_with_same_type(mask, rhs))
+            .map(|(lhs, rhs)| lhs.zip_with(mask, rhs))
             .collect::<PolarsResult<Vec<_>>>()?;
         Ok(StructChunked::new_unchecked(self.0.name(), &fields).into_series())
     }
@@ -58,7 +58,7 @@ impl Series {
                 Box::new(arr)
             }
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_, _) => {
                 let ca = self.categorical().unwrap();
                 let arr = ca.logical().chunks()[chunk_idx].clone();
                 let cats = unsafe { UInt32Chunked::from_chunks("", vec![arr]) };
@@ -32,7 +32,7 @@ pub use series_trait::{IsSorted, *};
 use crate::prelude::unique::rank::rank;
 #[cfg(feature = "zip_with")]
 use crate::series::arithmetic::coerce_lhs_rhs;
-use crate::utils::{_split_offsets, split_ca, split_series, Wrap};
+use crate::utils::{_check_idx_len, _split_offsets, split_ca, split_series, Wrap};
 use crate::POOL;
 
 /// # Series
@@ -153,6 +153,27 @@ impl Hash for Wrap<Series> {
     }
 }
 
+#[cfg(feature = "dtype-categorical")]
+fn rev_maps_estimated_size(dtype: &DataType, counted_rev_maps: &mut PlHashSet<usize>) -> usize {
+    match dtype {
+        DataType::Categorical(Some(rev_map), _) => {
+            if !counted_rev_maps.insert(Arc::as_ptr(rev_map) as usize) {
+                return 0;
+            }
+            match &**rev_map {
+                RevMapping::Local(arr) => estimated_bytes_size(arr),
+                RevMapping::Global(map, arr, _) => {
+                    // a bucket holds a key, a value and a control byte
+                    map.capacity() * (std::mem::size_of::<(u32, u32)>() + 1)
+                        + estimated_bytes_size(arr)
+                }
+            }
+        }
+        DataType::List(inner) => rev_maps_estimated_size(inner, counted_rev_maps),
+        _ => 0,
+    }
+}
+
 impl Series {
     /// Create a new empty Series
     pub fn new_empty(name: &str, dtype: &DataType) -> Series {
@@ -218,6 +239,7 @@ impl Series {
     ///
     /// See [`ChunkedArray::append`] and [`ChunkedArray::extend`].
     pub fn append(&mut self, other: &Series) -> PolarsResult<&mut Self> {
+        _check_idx_len(self.len() + other.len())?;
         self._get_inner_mut().append(other)?;
         Ok(self)
     }
@@ -226,6 +248,7 @@ impl Series {
     ///
     /// See [`ChunkedArray::extend`] and [`ChunkedArray::append`].
     pub fn extend(&mut self, other: &Series) -> PolarsResult<&mut Self> {
+        _check_idx_len(self.len() + other.len())?;
         self._get_inner_mut().extend(other)?;
         Ok(self)
     }
@@ -242,6 +265,18 @@ impl Series {
         self._get_inner_mut().as_single_ptr()
     }
 
+    /// A zero copy view of the values if the dtype of the `Series` is the dtype of `N`, and it
+    /// consists of a single chunk without null values.
+    pub fn try_as_slice<N>(&self) -> Option<&[N::Native]>
+    where
+        N: PolarsNumericType,
+    {
+        if self.dtype() != &N::get_dtype() {
+            return None;
+        }
+        self.unpack::<N>().ok()?.cont_slice().ok()
+    }
+
     /// Cast `[Series]` to another `[DataType]`
     pub fn cast(&self, dtype: &DataType) -> PolarsResult<Self> {
         // best leave as is.
@@ -303,7 +338,7 @@ impl Series {
         T: NumCast,
     {
         self.sum_as_series()
-            .cast(&DataType::Float64)
+            .and_then(|s| s.cast(&DataType::Float64))
             .ok()
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
@@ -391,7 +426,18 @@ impl Series {
     /// from `other` where the mask evaluates `false`
     #[cfg(feature = "zip_with")]
     pub fn zip_with(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
-        let (lhs, rhs) = coerce_lhs_rhs(self, other)?;
+        let (lhs, rhs) = match (self.dtype(), other.dtype()) {
+            // arithmetic doesn't coerce structs, but both branches need the same fields
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(_), DataType::Struct(_)) if self.dtype() != other.dtype() => {
+                let dtype = crate::utils::try_get_supertype(self.dtype(), other.dtype())?;
+                (
+                    Cow::Owned(self.cast(&dtype)?),
+                    Cow::Owned(other.cast(&dtype)?),
+                )
+            }
+            _ => coerce_lhs_rhs(self, other)?,
+        };
         lhs.zip_with_same_type(mask, rhs.as_ref())
     }
 
@@ -409,7 +455,7 @@ impl Series {
             Date => Cow::Owned(self.cast(&Int32).unwrap()),
             Datetime(_, _) | Duration(_) | Time => Cow::Owned(self.cast(&Int64).unwrap()),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => Cow::Owned(self.cast(&UInt32).unwrap()),
+            Categorical(_, _) => Cow::Owned(self.cast(&UInt32).unwrap()),
             _ => Cow::Borrowed(self),
         }
     }
@@ -547,19 +593,19 @@ impl Series {
     /// Returns a Series with a single zeroed entry if self is an empty numeric series.
     ///
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
-    /// first cast to `Int64` to prevent overflow issues.
-    pub fn sum_as_series(&self) -> Series {
+    /// first cast to `Int64` to prevent overflow issues. A `Decimal` sum that doesn't
+    /// fit in 38 digits returns a `ComputeError`.
+    pub fn sum_as_series(&self) -> PolarsResult<Series> {
         use DataType::*;
         if self.is_empty()
             && (self.dtype().is_numeric() || matches!(self.dtype(), DataType::Boolean))
         {
             return Series::new(self.name(), [0])
-                .cast(self.dtype())
-                .unwrap()
+                .cast(self.dtype())?
                 .sum_as_series();
         }
         match self.dtype() {
-            Int8 | UInt8 | Int16 | UInt16 => self.cast(&Int64).unwrap().sum_as_series(),
+            Int8 | UInt8 | Int16 | UInt16 => self.cast(&Int64)?.sum_as_series(),
             _ => self._sum_as_series(),
         }
     }
@@ -948,26 +994,39 @@ impl Series {
     /// the visible size of the buffer, not its total capacity.
     ///
     /// FFI buffers are included in this estimation.
+    ///
+    /// The rev-map of a categorical column, also a nested one, is included once.
     pub fn estimated_size(&self) -> usize {
-        #[allow(unused_mut)]
-        let mut size = self
-            .chunks()
-            .iter()
-            .map(|arr| estimated_bytes_size(&**arr))
-            .sum();
+        self.estimated_size_impl(&mut PlHashSet::new())
+    }
+
+    /// `counted_rev_maps` holds the addresses of the categorical rev-maps that are already
+    /// counted, so that a rev-map that is shared between columns is counted once.
+    pub(crate) fn estimated_size_impl(&self, counted_rev_maps: &mut PlHashSet<usize>) -> usize {
         match self.dtype() {
-            #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(Some(rv)) => match &**rv {
-                RevMapping::Local(arr) => size += estimated_bytes_size(arr),
-                RevMapping::Global(map, arr, _) => {
-                    size +=
-                        map.capacity() * std::mem::size_of::<u32>() * 2 + estimated_bytes_size(arr);
+            // the struct arrays may hold converted copies of the fields, so we count the fields
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => self
+                .struct_()
+                .unwrap()
+                .fields()
+                .iter()
+                .map(|s| s.estimated_size_impl(counted_rev_maps))
+                .sum(),
+            _ => {
+                #[allow(unused_mut)]
+                let mut size = self
+                    .chunks()
+                    .iter()
+                    .map(|arr| estimated_bytes_size(&**arr))
+                    .sum();
+                #[cfg(feature = "dtype-categorical")]
+                {
+                    size += rev_maps_estimated_size(self.dtype(), counted_rev_maps);
                 }
-            },
-            _ => {}
+                size
+            }
         }
-
-        size
     }
 
     /// Packs every element into a list
@@ -130,7 +130,7 @@ impl Series {
     /// Unpack to ChunkedArray of dtype categorical
     #[cfg(feature = "dtype-categorical")]
     pub fn categorical(&self) -> PolarsResult<&CategoricalChunked> {
-        unpack_chunked!(self, DataType::Categorical(_) => CategoricalChunked, "Categorical")
+        unpack_chunked!(self, DataType::Categorical(_, _) => CategoricalChunked, "Categorical")
     }
 
     /// Unpack to ChunkedArray of dtype struct
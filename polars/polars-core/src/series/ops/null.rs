@@ -8,7 +8,7 @@ impl Series {
                 ListChunked::full_null_with_dtype(name, size, inner_dtype).into_series()
             }
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => CategoricalChunked::full_null(name, size).into_series(),
+            DataType::Categorical(_, _) => CategoricalChunked::full_null(name, size).into_series(),
             #[cfg(feature = "dtype-date")]
             DataType::Date => Int32Chunked::full_null(name, size)
                 .into_date()
@@ -432,8 +432,8 @@ pub trait SeriesTrait:
     ///
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
     /// first cast to `Int64` to prevent overflow issues.
-    fn _sum_as_series(&self) -> Series {
-        Series::full_null(self.name(), 1, self.dtype())
+    fn _sum_as_series(&self) -> PolarsResult<Series> {
+        Ok(Series::full_null(self.name(), 1, self.dtype()))
     }
     /// Get the max of the Series as a new Series of length 1.
     fn max_as_series(&self) -> Series {
@@ -501,8 +501,8 @@ pub trait SeriesTrait:
         polars_bail!(opq = is_in, self._dtype());
     }
     #[cfg(feature = "repeat_by")]
-    fn repeat_by(&self, _by: &IdxCa) -> ListChunked {
-        invalid_operation_panic!(repeat_by, self)
+    fn repeat_by(&self, _by: &IdxCa) -> PolarsResult<ListChunked> {
+        polars_bail!(opq = repeat_by, self._dtype());
     }
     #[cfg(feature = "checked_arithmetic")]
     fn checked_div(&self, _rhs: &Series) -> PolarsResult<Series> {
@@ -1,7 +1,9 @@
 //! Testing utilities.
+use std::borrow::Cow;
 use std::ops::Deref;
 
 use crate::prelude::*;
+use crate::utils::try_get_supertype;
 
 impl Series {
     /// Check if series are equal. Note that `None == None` evaluates to `false`
@@ -179,6 +181,194 @@ impl PartialEq for DataFrame {
     }
 }
 
+/// Options for [`assert_series_equal`] and [`assert_frame_equal`].
+#[derive(Clone, Debug)]
+pub struct AssertEqualOptions {
+    /// Whether the columns of the frames have to be in the same order.
+    pub check_column_order: bool,
+    /// Whether the dtypes have to be equal. If not, the values are compared after casting both
+    /// sides to their supertype, so that e.g. `Int32` and `Int64` compare equal.
+    pub check_dtype: bool,
+    /// Whether the names of the series have to be equal.
+    pub check_names: bool,
+    /// Absolute tolerance of float comparisons.
+    pub atol: f64,
+    /// Relative tolerance of float comparisons, relative to the right-hand value.
+    pub rtol: f64,
+    /// Whether `NaN` compares equal to `NaN`.
+    pub nans_equal: bool,
+    /// Whether `null` compares equal to `null`.
+    pub nulls_equal: bool,
+    /// Compare categoricals by their string values instead of their physical representation.
+    pub categorical_as_str: bool,
+}
+
+impl Default for AssertEqualOptions {
+    fn default() -> Self {
+        Self {
+            check_column_order: true,
+            check_dtype: true,
+            check_names: true,
+            atol: 1e-8,
+            rtol: 1e-5,
+            nans_equal: true,
+            nulls_equal: true,
+            categorical_as_str: false,
+        }
+    }
+}
+
+fn floats_equal(a: Option<f64>, b: Option<f64>, options: &AssertEqualOptions) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) if a.is_nan() || b.is_nan() => {
+            options.nans_equal && a.is_nan() && b.is_nan()
+        }
+        (Some(a), Some(b)) if a.is_infinite() || b.is_infinite() => a == b,
+        (Some(a), Some(b)) => (a - b).abs() <= options.atol + options.rtol * b.abs(),
+        (None, None) => options.nulls_equal,
+        _ => false,
+    }
+}
+
+/// The index of the first row at which `left` and `right` differ. Both have the same length
+/// and dtype.
+fn first_difference(
+    left: &Series,
+    right: &Series,
+    options: &AssertEqualOptions,
+) -> PolarsResult<Option<usize>> {
+    if left.dtype().is_float() {
+        let left = left.cast(&DataType::Float64)?;
+        let right = right.cast(&DataType::Float64)?;
+        return Ok(left
+            .f64()?
+            .into_iter()
+            .zip(right.f64()?)
+            .position(|(a, b)| !floats_equal(a, b, options)));
+    }
+
+    let left_valid = left.is_not_null();
+    let right_valid = right.is_not_null();
+    let nulls_differ = |l: Option<bool>, r: Option<bool>| match (l, r) {
+        (Some(true), Some(true)) => None,
+        (Some(false), Some(false)) => Some(!options.nulls_equal),
+        _ => Some(true),
+    };
+    match left.equal(right) {
+        Ok(eq) => Ok(eq
+            .into_iter()
+            .zip(&left_valid)
+            .zip(&right_valid)
+            .position(|((eq, l), r)| nulls_differ(l, r).unwrap_or(eq != Some(true)))),
+        // e.g. nested dtypes, fall back to comparing the values one by one
+        Err(_) => {
+            for (i, (l, r)) in left_valid.into_iter().zip(&right_valid).enumerate() {
+                let differ = match nulls_differ(l, r) {
+                    Some(differ) => differ,
+                    None => left.get(i)? != right.get(i)?,
+                };
+                if differ {
+                    return Ok(Some(i));
+                }
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Assert that two series are equal under the given options.
+///
+/// Returns an error that names the first differing row and both values otherwise. Floats are
+/// compared with the tolerances of `options`, floats in nested dtypes are compared exactly.
+pub fn assert_series_equal(
+    left: &Series,
+    right: &Series,
+    options: &AssertEqualOptions,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        left.len() == right.len(),
+        ShapeMismatch: "series lengths differ: left = {}, right = {}", left.len(), right.len()
+    );
+    polars_ensure!(
+        !options.check_names || left.name() == right.name(),
+        ComputeError: "series names differ: left = '{}', right = '{}'", left.name(), right.name()
+    );
+
+    let mut left = Cow::Borrowed(left);
+    let mut right = Cow::Borrowed(right);
+    #[cfg(feature = "dtype-categorical")]
+    {
+        if options.categorical_as_str {
+            for s in [&mut left, &mut right] {
+                if matches!(s.dtype(), DataType::Categorical(_, _)) {
+                    *s = Cow::Owned(s.cast(&DataType::Utf8)?);
+                }
+            }
+        }
+    }
+    if left.dtype() != right.dtype() {
+        polars_ensure!(
+            !options.check_dtype,
+            SchemaMismatch: "series dtypes differ: left = {}, right = {}",
+            left.dtype(), right.dtype()
+        );
+        let dtype = try_get_supertype(left.dtype(), right.dtype())?;
+        left = Cow::Owned(left.cast(&dtype)?);
+        right = Cow::Owned(right.cast(&dtype)?);
+    }
+
+    if let Some(i) = first_difference(&left, &right, options)? {
+        polars_bail!(
+            ComputeError: "values differ at row {}: left = {}, right = {}",
+            i, left.get(i)?, right.get(i)?
+        );
+    }
+    Ok(())
+}
+
+/// Assert that two frames are equal under the given options.
+///
+/// Returns an error that names the first differing column, and for differing values also the
+/// row and both values.
+pub fn assert_frame_equal(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: &AssertEqualOptions,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        left.height() == right.height(),
+        ShapeMismatch: "frame heights differ: left = {}, right = {}", left.height(), right.height()
+    );
+    let left_names = left.get_column_names();
+    let right_names = right.get_column_names();
+    if options.check_column_order {
+        polars_ensure!(
+            left_names == right_names,
+            ComputeError: "frame columns differ: left = {:?}, right = {:?}", left_names, right_names
+        );
+    } else {
+        for name in &left_names {
+            polars_ensure!(
+                right_names.contains(name),
+                ComputeError: "column '{}' is missing on the right", name
+            );
+        }
+        for name in &right_names {
+            polars_ensure!(
+                left_names.contains(name),
+                ComputeError: "column '{}' is missing on the left", name
+            );
+        }
+    }
+
+    for s in left.get_columns() {
+        let other = right.column(s.name())?;
+        assert_series_equal(s, other, options)
+            .map_err(|e| polars_err!(ComputeError: "column '{}': {}", s.name(), e))?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -245,4 +435,75 @@ mod test {
         assert_eq!(df3, df3);
         assert_ne!(df4, df4);
     }
+
+    #[test]
+    fn test_assert_series_equal() -> PolarsResult<()> {
+        let options = AssertEqualOptions::default();
+        let a = Series::new("a", [Some(1.0), None, Some(f64::NAN), Some(3.0)]);
+        let b = Series::new("a", [Some(1.0 + 1e-9), None, Some(f64::NAN), Some(3.0)]);
+        assert_series_equal(&a, &b, &options)?;
+
+        let b = Series::new("a", [Some(1.0), None, Some(f64::NAN), Some(3.1)]);
+        let err = assert_series_equal(&a, &b, &options).unwrap_err();
+        assert!(err.to_string().contains("row 3"));
+        assert!(err.to_string().contains("3.1"));
+
+        let opts = AssertEqualOptions {
+            nans_equal: false,
+            ..Default::default()
+        };
+        assert!(assert_series_equal(&a, &a, &opts).is_err());
+        let opts = AssertEqualOptions {
+            nulls_equal: false,
+            ..Default::default()
+        };
+        let err = assert_series_equal(&a, &a, &opts).unwrap_err();
+        assert!(err.to_string().contains("row 1"));
+
+        let a = Series::new("a", [1i32, 2, 3]);
+        let b = Series::new("a", [1i64, 2, 3]);
+        assert!(assert_series_equal(&a, &b, &options).is_err());
+        let opts = AssertEqualOptions {
+            check_dtype: false,
+            ..Default::default()
+        };
+        assert_series_equal(&a, &b, &opts)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_frame_equal() -> PolarsResult<()> {
+        let options = AssertEqualOptions::default();
+        let left = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+        let right = df!("b" => ["x", "y", "w"], "a" => [1, 2, 3])?;
+        assert!(assert_frame_equal(&left, &right, &options).is_err());
+
+        let opts = AssertEqualOptions {
+            check_column_order: false,
+            ..Default::default()
+        };
+        let err = assert_frame_equal(&left, &right, &opts).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("column 'b'"));
+        assert!(msg.contains("row 2"));
+
+        assert_frame_equal(&left, &left, &options)?;
+        let err = assert_frame_equal(&left, &left.head(Some(2)), &options).unwrap_err();
+        assert!(err.to_string().contains("heights differ"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_assert_series_equal_categorical() -> PolarsResult<()> {
+        let a = Series::new("a", ["x", "y"]);
+        let b = a.cast(&DataType::Categorical(None, Default::default()))?;
+        let opts = AssertEqualOptions {
+            categorical_as_str: true,
+            ..Default::default()
+        };
+        assert_series_equal(&a, &b, &opts)?;
+        assert!(assert_series_equal(&a, &b, &AssertEqualOptions::default()).is_err());
+        Ok(())
+    }
 }
@@ -218,6 +218,30 @@ pub fn slice_offsets(offset: i64, length: usize, array_len: usize) -> (usize, us
     }
 }
 
+/// The number of rows of a column is limited by its [`IdxSize`] indices.
+#[cfg(not(test))]
+const MAX_IDX_LEN: usize = IdxSize::MAX as usize;
+// small, so that the checks can be tested without allocating billions of rows
+#[cfg(test)]
+const MAX_IDX_LEN: usize = 1 << 16;
+
+#[cfg(feature = "python")]
+const BIGIDX_HINT: &str = "consider installing 'polars-u64-idx'";
+#[cfg(not(feature = "python"))]
+const BIGIDX_HINT: &str = "consider compiling with the 'bigidx' feature";
+
+/// Error if a result of `len` rows can't be indexed with [`IdxSize`], where the length would
+/// otherwise wrap around.
+#[inline]
+pub fn _check_idx_len(len: usize) -> PolarsResult<()> {
+    polars_ensure!(
+        len < MAX_IDX_LEN,
+        ComputeError: "the result would have {} rows, more than fit in {}; {}",
+        len, IDX_DTYPE, BIGIDX_HINT
+    );
+    Ok(())
+}
+
 /// Apply a macro on the Series
 #[macro_export]
 macro_rules! match_dtype_to_physical_apply_macro {
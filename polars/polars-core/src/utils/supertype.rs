@@ -262,13 +262,26 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
                 Some(Struct(new_fields))
             }
             #[cfg(feature = "dtype-decimal")]
-            (d @ Decimal(_, _), dt) if dt.is_signed() || dt.is_unsigned() => Some(d.clone()),
-            #[cfg(feature = "dtype-decimal")]
             (Decimal(p1, s1), Decimal(p2, s2)) => {
-                Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+                // keep enough integer digits for both sides after rescaling to the larger scale
+                let precision = match (p1, s1, p2, s2) {
+                    (Some(p1), Some(s1), Some(p2), Some(s2)) => Some(std::cmp::min(
+                        p1.saturating_sub(*s1).max(p2.saturating_sub(*s2)) + s1.max(s2),
+                        MAX_DECIMAL_PRECISION,
+                    )),
+                    _ => None,
+                };
+                Some(Decimal(precision, (*s1).max(*s2)))
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(p, s), dt) => integer_decimal_precision(dt).map(|digits| {
+                let precision = p.zip(*s).map(|(p, s)| {
+                    std::cmp::min(p.saturating_sub(s).max(digits) + s, MAX_DECIMAL_PRECISION)
+                });
+                Decimal(precision, *s)
+            }),
             _ => None,
         }
     }
@@ -276,6 +289,25 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
     inner(l, r).or_else(|| inner(r, l))
 }
 
+/// The maximum number of significant digits of a decimal, which is backed by an `i128`.
+#[cfg(feature = "dtype-decimal")]
+pub(crate) const MAX_DECIMAL_PRECISION: usize = 38;
+
+/// The number of decimal digits needed to hold any value of the integer type `dtype`.
+#[cfg(feature = "dtype-decimal")]
+pub(crate) fn integer_decimal_precision(dtype: &DataType) -> Option<usize> {
+    use DataType::*;
+    let digits = match dtype {
+        Int8 | UInt8 => 3,
+        Int16 | UInt16 => 5,
+        Int32 | UInt32 => 10,
+        Int64 => 19,
+        UInt64 => 20,
+        _ => return None,
+    };
+    Some(digits)
+}
+
 #[cfg(feature = "dtype-struct")]
 fn union_struct_fields(fields_a: &[Field], fields_b: &[Field]) -> Option<DataType> {
     let (longest, shortest) = {
@@ -4,7 +4,10 @@ use std::ops::Deref;
 use std::{env, io};
 
 #[derive(Debug)]
-pub struct ErrString(Cow<'static, str>);
+pub struct ErrString {
+    msg: Cow<'static, str>,
+    context: Option<Box<ErrorContext>>,
+}
 
 impl<T> From<T> for ErrString
 where
@@ -14,7 +17,20 @@ where
         if env::var("POLARS_PANIC_ON_ERR").is_ok() {
             panic!("{}", msg.into())
         } else {
-            ErrString(msg.into())
+            ErrString {
+                msg: msg.into(),
+                context: None,
+            }
+        }
+    }
+}
+
+impl ErrString {
+    /// Replace the message, keeping the context.
+    fn map(&self, func: &dyn Fn(&str) -> String) -> Self {
+        ErrString {
+            msg: func(&self.msg).into(),
+            context: self.context.clone(),
         }
     }
 }
@@ -23,20 +39,142 @@ impl Deref for ErrString {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.msg
     }
 }
 
 impl Display for ErrString {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.msg)
+    }
+}
+
+/// An error raised by another crate, with the [`ErrorContext`] that was added to it.
+#[derive(Debug)]
+pub struct ForeignErr<E> {
+    err: E,
+    context: Option<Box<ErrorContext>>,
+}
+
+impl<E> ForeignErr<E> {
+    fn new(err: E) -> Self {
+        ForeignErr { err, context: None }
+    }
+
+    /// Convert to a [`PolarsError::ComputeError`] with message `msg`, keeping the context.
+    fn to_compute_err(&self, msg: String, kind: ErrorKind) -> PolarsError {
+        let mut msg = ErrString::from(msg);
+        msg.context = Some(
+            self.context
+                .clone()
+                .unwrap_or_else(|| Box::new(ErrorContext::new(kind))),
+        );
+        PolarsError::ComputeError(msg)
+    }
+}
+
+impl<E> Deref for ForeignErr<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.err
+    }
+}
+
+impl<E: Display> Display for ForeignErr<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.err, f)
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for ForeignErr<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.err.source()
+    }
+}
+
+/// The kind of a [`PolarsError`], to handle errors without inspecting their message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    Arrow,
+    ColumnNotFound,
+    Compute,
+    Duplicate,
+    InvalidOperation,
+    Io,
+    NoData,
+    ResourceExhausted,
+    SchemaFieldNotFound,
+    SchemaMismatch,
+    ShapeMismatch,
+    StructFieldNotFound,
+}
+
+impl ErrorKind {
+    /// A stable code for this kind, e.g. to report errors across language boundaries.
+    pub fn code(&self) -> &'static str {
+        use ErrorKind::*;
+        match self {
+            Arrow => "arrow",
+            ColumnNotFound => "column_not_found",
+            Compute => "compute",
+            Duplicate => "duplicate",
+            InvalidOperation => "invalid_operation",
+            Io => "io",
+            NoData => "no_data",
+            ResourceExhausted => "resource_exhausted",
+            SchemaFieldNotFound => "schema_field_not_found",
+            SchemaMismatch => "schema_mismatch",
+            ShapeMismatch => "shape_mismatch",
+            StructFieldNotFound => "struct_field_not_found",
+        }
+    }
+}
+
+/// Structured information about a [`PolarsError`], next to its message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorContext {
+    kind: ErrorKind,
+    columns: Vec<String>,
+    operation: Option<Cow<'static, str>>,
+    plan_node: Option<Cow<'static, str>>,
+}
+
+impl ErrorContext {
+    fn new(kind: ErrorKind) -> Self {
+        ErrorContext {
+            kind,
+            columns: vec![],
+            operation: None,
+            plan_node: None,
+        }
+    }
+
+    /// The kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The columns involved in the error.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// The operation that failed.
+    pub fn operation(&self) -> Option<&str> {
+        self.operation.as_deref()
+    }
+
+    /// The node of the query plan that failed, for errors raised while executing a lazy query.
+    pub fn plan_node(&self) -> Option<&str> {
+        self.plan_node.as_deref()
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum PolarsError {
     #[error(transparent)]
-    ArrowError(Box<ArrowError>),
+    ArrowError(ForeignErr<Box<ArrowError>>),
     #[error("not found: {0}")]
     ColumnNotFound(ErrString),
     #[error("{0}")]
@@ -46,9 +184,11 @@ pub enum PolarsError {
     #[error("invalid operation: {0}")]
     InvalidOperation(ErrString),
     #[error(transparent)]
-    Io(#[from] io::Error),
+    Io(ForeignErr<io::Error>),
     #[error("no data: {0}")]
     NoData(ErrString),
+    #[error("resources exhausted: {0}")]
+    ResourceExhausted(ErrString),
     #[error("field not found: {0}")]
     SchemaFieldNotFound(ErrString),
     #[error("data types don't match: {0}")]
@@ -61,7 +201,13 @@ pub enum PolarsError {
 
 impl From<ArrowError> for PolarsError {
     fn from(err: ArrowError) -> Self {
-        Self::ArrowError(Box::new(err))
+        Self::ArrowError(ForeignErr::new(Box::new(err)))
+    }
+}
+
+impl From<io::Error> for PolarsError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(ForeignErr::new(err))
     }
 }
 
@@ -80,23 +226,172 @@ impl PolarsError {
     pub fn wrap_msg(&self, func: &dyn Fn(&str) -> String) -> Self {
         use PolarsError::*;
         match self {
-            ArrowError(err) => ComputeError(func(&format!("ArrowError: {err}")).into()),
-            ColumnNotFound(msg) => ColumnNotFound(func(msg).into()),
-            ComputeError(msg) => ComputeError(func(msg).into()),
-            Duplicate(msg) => Duplicate(func(msg).into()),
-            InvalidOperation(msg) => InvalidOperation(func(msg).into()),
-            Io(err) => ComputeError(func(&format!("IO: {err}")).into()),
-            NoData(msg) => NoData(func(msg).into()),
-            SchemaFieldNotFound(msg) => SchemaFieldNotFound(func(msg).into()),
-            SchemaMismatch(msg) => SchemaMismatch(func(msg).into()),
-            ShapeMismatch(msg) => ShapeMismatch(func(msg).into()),
-            StructFieldNotFound(msg) => StructFieldNotFound(func(msg).into()),
+            ArrowError(err) => {
+                err.to_compute_err(func(&format!("ArrowError: {err}")), ErrorKind::Arrow)
+            }
+            ColumnNotFound(msg) => ColumnNotFound(msg.map(func)),
+            ComputeError(msg) => ComputeError(msg.map(func)),
+            Duplicate(msg) => Duplicate(msg.map(func)),
+            InvalidOperation(msg) => InvalidOperation(msg.map(func)),
+            Io(err) => err.to_compute_err(func(&format!("IO: {err}")), ErrorKind::Io),
+            NoData(msg) => NoData(msg.map(func)),
+            ResourceExhausted(msg) => ResourceExhausted(msg.map(func)),
+            SchemaFieldNotFound(msg) => SchemaFieldNotFound(msg.map(func)),
+            SchemaMismatch(msg) => SchemaMismatch(msg.map(func)),
+            ShapeMismatch(msg) => ShapeMismatch(msg.map(func)),
+            StructFieldNotFound(msg) => StructFieldNotFound(msg.map(func)),
+        }
+    }
+
+    /// The kind of this error. Errors that were converted to another variant when their message
+    /// was wrapped, such as IO errors, keep their original kind.
+    pub fn kind(&self) -> ErrorKind {
+        use PolarsError::*;
+        if let Some(context) = self.context() {
+            return context.kind;
+        }
+        match self {
+            ArrowError(_) => ErrorKind::Arrow,
+            ColumnNotFound(_) => ErrorKind::ColumnNotFound,
+            ComputeError(_) => ErrorKind::Compute,
+            Duplicate(_) => ErrorKind::Duplicate,
+            InvalidOperation(_) => ErrorKind::InvalidOperation,
+            Io(_) => ErrorKind::Io,
+            NoData(_) => ErrorKind::NoData,
+            ResourceExhausted(_) => ErrorKind::ResourceExhausted,
+            SchemaFieldNotFound(_) => ErrorKind::SchemaFieldNotFound,
+            SchemaMismatch(_) => ErrorKind::SchemaMismatch,
+            ShapeMismatch(_) => ErrorKind::ShapeMismatch,
+            StructFieldNotFound(_) => ErrorKind::StructFieldNotFound,
+        }
+    }
+
+    fn context_slot(&self) -> &Option<Box<ErrorContext>> {
+        use PolarsError::*;
+        match self {
+            ArrowError(err) => &err.context,
+            Io(err) => &err.context,
+            ColumnNotFound(msg)
+            | ComputeError(msg)
+            | Duplicate(msg)
+            | InvalidOperation(msg)
+            | NoData(msg)
+            | ResourceExhausted(msg)
+            | SchemaFieldNotFound(msg)
+            | SchemaMismatch(msg)
+            | ShapeMismatch(msg)
+            | StructFieldNotFound(msg) => &msg.context,
+        }
+    }
+
+    /// The structured context of this error, if any was added.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        self.context_slot().as_deref()
+    }
+
+    /// The columns involved in this error.
+    pub fn columns(&self) -> &[String] {
+        self.context().map_or(&[], |context| context.columns())
+    }
+
+    /// The operation that failed.
+    pub fn operation(&self) -> Option<&str> {
+        self.context().and_then(|context| context.operation())
+    }
+
+    /// The node of the query plan that failed, for errors raised while executing a lazy query.
+    pub fn plan_node(&self) -> Option<&str> {
+        self.context().and_then(|context| context.plan_node())
+    }
+
+    fn context_slot_mut(&mut self) -> &mut Option<Box<ErrorContext>> {
+        use PolarsError::*;
+        match self {
+            ArrowError(err) => &mut err.context,
+            Io(err) => &mut err.context,
+            ColumnNotFound(msg)
+            | ComputeError(msg)
+            | Duplicate(msg)
+            | InvalidOperation(msg)
+            | NoData(msg)
+            | ResourceExhausted(msg)
+            | SchemaFieldNotFound(msg)
+            | SchemaMismatch(msg)
+            | ShapeMismatch(msg)
+            | StructFieldNotFound(msg) => &mut msg.context,
         }
     }
+
+    fn context_mut(&mut self) -> &mut ErrorContext {
+        let kind = self.kind();
+        self.context_slot_mut()
+            .get_or_insert_with(|| Box::new(ErrorContext::new(kind)))
+    }
+
+    /// Add a column that is involved in this error.
+    #[must_use]
+    pub fn with_column(mut self, name: impl AsRef<str>) -> Self {
+        self.context_mut().columns.push(name.as_ref().to_string());
+        self
+    }
+
+    /// Add the columns that are involved in this error.
+    #[must_use]
+    pub fn with_columns<I>(mut self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let context = self.context_mut();
+        context
+            .columns
+            .extend(names.into_iter().map(|name| name.as_ref().to_string()));
+        self
+    }
+
+    /// Set the operation that failed.
+    #[must_use]
+    pub fn with_operation(mut self, operation: impl Into<Cow<'static, str>>) -> Self {
+        self.context_mut().operation = Some(operation.into());
+        self
+    }
+
+    /// Set the node of the query plan that failed.
+    #[must_use]
+    pub fn with_plan_node(mut self, node: impl Into<Cow<'static, str>>) -> Self {
+        self.context_mut().plan_node = Some(node.into());
+        self
+    }
 }
 
+/// Create a [`PolarsError`].
+///
+/// Structured context can be added after a `;` with `column = ..`, `columns = [..]`,
+/// `operation = ..` and `plan_node = ..`, e.g.
+/// `polars_err!(ColumnNotFound: "{}", name; column = name, operation = "select")`.
 #[macro_export]
 macro_rules! polars_err {
+    (@ctx $err:expr;) => {
+        $err
+    };
+    (@ctx $err:expr; column = $name:expr $(, $($rest:tt)*)?) => {
+        $crate::polars_err!(@ctx $err.with_column(&$name); $($($rest)*)?)
+    };
+    (@ctx $err:expr; columns = $names:expr $(, $($rest:tt)*)?) => {
+        $crate::polars_err!(@ctx $err.with_columns($names); $($($rest)*)?)
+    };
+    (@ctx $err:expr; operation = $op:expr $(, $($rest:tt)*)?) => {
+        $crate::polars_err!(@ctx $err.with_operation($op); $($($rest)*)?)
+    };
+    (@ctx $err:expr; plan_node = $node:expr $(, $($rest:tt)*)?) => {
+        $crate::polars_err!(@ctx $err.with_plan_node($node); $($($rest)*)?)
+    };
+    ($variant:ident: $fmt:literal $(, $arg:expr)* $(,)? ; $($ctx:tt)+) => {
+        $crate::polars_err!(@ctx $crate::polars_err!($variant: $fmt $(, $arg)*); $($ctx)+)
+    };
+    ($variant:ident: $err:expr $(,)? ; $($ctx:tt)+) => {
+        $crate::polars_err!(@ctx $crate::polars_err!($variant: $err); $($ctx)+)
+    };
     ($variant:ident: $err:expr $(,)?) => {
         $crate::__private::must_use(
             $crate::PolarsError::$variant($err.into())
@@ -154,7 +449,10 @@ macro_rules! polars_err {
         polars_err!(SchemaMismatch: "cannot unpack series, data types don't match")
     };
     (duplicate = $name:expr) => {
-        polars_err!(Duplicate: "column with name '{}' has more than one occurrences", $name)
+        polars_err!(
+            Duplicate: "column with name '{}' has more than one occurrences", $name;
+            column = $name
+        )
     };
     (oob = $idx:expr, $len:expr) => {
         polars_err!(ComputeError: "index {} is out of bounds for sequence of size {}", $idx, $len)
@@ -98,4 +98,246 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-categorical",
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-struct"
+    ))]
+    fn test_write_and_read_dtypes() -> PolarsResult<()> {
+        let datetime = |name: &str, tu| {
+            Series::new(name, &[Some(1_000_000i64), None]).cast(&DataType::Datetime(tu, None))
+        };
+        let columns = vec![
+            Series::new("bool", &[Some(true), None]),
+            Series::new("i32", &[Some(1i32), None]),
+            Series::new("i64", &[Some(-1i64), None]),
+            Series::new("f32", &[Some(0.5f32), None]),
+            Series::new("f64", &[Some(0.5f64), None]),
+            Series::new("utf8", &[Some("a"), None]),
+            Series::new("binary", &[Some("a"), None]).cast(&DataType::Binary)?,
+            Series::new("date", &[Some(1i32), None]).cast(&DataType::Date)?,
+            datetime("datetime_ms", TimeUnit::Milliseconds)?,
+            datetime("datetime_us", TimeUnit::Microseconds)?,
+            Series::new(
+                "list",
+                &[Series::new("", &[1i64, 2]), Series::new("", &[3i64])],
+            ),
+            StructChunked::new(
+                "struct",
+                &[
+                    Series::new("a", &[Some(1i64), None]),
+                    Series::new("b", &[Some("x"), Some("y")]),
+                ],
+            )?
+            .into_series(),
+        ];
+        // written as another dtype
+        let cast_columns = vec![
+            (Series::new("i8", &[Some(1i8), None]), DataType::Int32),
+            (Series::new("u32", &[Some(1u32), None]), DataType::Int64),
+            (
+                Series::new("cat", &[Some("a"), None])
+                    .cast(&DataType::Categorical(None, Default::default()))?,
+                DataType::Utf8,
+            ),
+            (
+                datetime("datetime_ns", TimeUnit::Nanoseconds)?,
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            ),
+        ];
+
+        let mut df = DataFrame::new(columns.clone())?;
+        let mut expected = DataFrame::new(columns)?;
+        for (s, dtype) in cast_columns {
+            expected.with_column(s.cast(&dtype)?)?;
+            df.with_column(s)?;
+        }
+        // multiple chunks are written as multiple blocks
+        let mut df = df.vstack(&df)?;
+        let expected = expected.vstack(&expected)?;
+
+        for compression in [
+            None,
+            Some(write::Compression::Deflate),
+            Some(write::Compression::Snappy),
+        ] {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            AvroWriter::new(&mut buf)
+                .with_compression(compression)
+                .finish(&mut df)?;
+            buf.set_position(0);
+
+            let read_df = AvroReader::new(buf).finish()?;
+            assert_eq!(read_df.schema(), expected.schema());
+            assert!(expected.frame_equal_missing(&read_df));
+        }
+
+        Ok(())
+    }
+
+    /// A random frame with a column of every dtype that round-trips through avro unchanged.
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-struct"
+    ))]
+    fn random_frame(rng: &mut rand::rngs::SmallRng) -> PolarsResult<DataFrame> {
+        use rand::Rng;
+
+        let len = rng.gen_range(0..50);
+        let mut values = |null_probability: f64| {
+            (0..len)
+                .map(|_| {
+                    (!rng.gen_bool(null_probability))
+                        .then(|| rng.gen_range(-1_000_000i64..1_000_000))
+                })
+                .collect::<Vec<_>>()
+        };
+        let ints = values(0.2);
+        let floats = values(0.2);
+        let strings = values(0.2)
+            .into_iter()
+            .map(|v| v.map(|v| v.to_string()))
+            .collect::<Vec<_>>();
+        let lists = values(0.2)
+            .into_iter()
+            .zip(values(0.0))
+            .map(|(v, n)| {
+                v.map(|v| Series::new("", (v..v + n.unwrap().rem_euclid(4)).collect::<Vec<_>>()))
+            })
+            .collect::<Vec<_>>();
+        let datetime =
+            |name: &str, tu| Series::new(name, &ints).cast(&DataType::Datetime(tu, None));
+
+        let columns = vec![
+            Series::new(
+                "bool",
+                ints.iter()
+                    .map(|v| v.map(|v| v % 2 == 0))
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "i32",
+                ints.iter().map(|v| v.map(|v| v as i32)).collect::<Vec<_>>(),
+            ),
+            Series::new("i64", &ints),
+            Series::new(
+                "f32",
+                floats
+                    .iter()
+                    .map(|v| v.map(|v| v as f32 / 8.0))
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "f64",
+                floats
+                    .iter()
+                    .map(|v| v.map(|v| v as f64 / 8.0))
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new("utf8", &strings),
+            Series::new("binary", &strings).cast(&DataType::Binary)?,
+            Series::new(
+                "date",
+                ints.iter().map(|v| v.map(|v| v as i32)).collect::<Vec<_>>(),
+            )
+            .cast(&DataType::Date)?,
+            datetime("datetime_ms", TimeUnit::Milliseconds)?,
+            datetime("datetime_us", TimeUnit::Microseconds)?,
+            // all the lists may be null, so the inner dtype cannot be inferred
+            Series::new("list", lists).cast(&DataType::List(Box::new(DataType::Int64)))?,
+            StructChunked::new(
+                "struct",
+                &[Series::new("a", &ints), Series::new("b", &strings)],
+            )?
+            .into_series(),
+        ];
+        DataFrame::new(columns)
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-struct"
+    ))]
+    fn test_write_and_read_random_frames() -> PolarsResult<()> {
+        use rand::rngs::SmallRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..100 {
+            // every vstacked frame is a chunk and is written as its own block
+            let mut df = random_frame(&mut rng)?;
+            for _ in 0..rng.gen_range(0..3) {
+                df.vstack_mut(&random_frame(&mut rng)?)?;
+            }
+            let compression = match rng.gen_range(0..3) {
+                0 => None,
+                1 => Some(write::Compression::Deflate),
+                _ => Some(write::Compression::Snappy),
+            };
+
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            AvroWriter::new(&mut buf)
+                .with_compression(compression)
+                .finish(&mut df)?;
+            buf.set_position(0);
+
+            let read_df = AvroReader::new(buf).finish()?;
+            assert_eq!(read_df.schema(), df.schema());
+            assert!(df.frame_equal_missing(&read_df));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_write_and_read_decimal() -> PolarsResult<()> {
+        let dtype = DataType::Decimal(Some(10), Some(2));
+        let s = Series::new("decimal", &[Some(1i64), None, Some(-3)]).cast(&dtype)?;
+        let mut df = DataFrame::new(vec![s])?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let read_df = AvroReader::new(buf).finish()?;
+        assert_eq!(read_df.column("decimal")?.dtype(), &dtype);
+        assert!(df.frame_equal_missing(&read_df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_read_enum() -> PolarsResult<()> {
+        use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+        use arrow::io::avro::avro_schema::schema::{Enum, Field, Record, Schema};
+        use arrow::io::avro::avro_schema::write::{compress, write_block, write_metadata};
+        use polars_core::error::to_compute_err;
+
+        let symbols = vec!["a".to_string(), "b".to_string()];
+        let record = Record::new(
+            "",
+            vec![Field::new("e", Schema::Enum(Enum::new("e", symbols)))],
+        );
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        write_metadata(&mut buf, record, None).map_err(to_compute_err)?;
+        // the zigzag encoded indices of the symbols of the rows
+        let mut block = Block::new(3, vec![2, 0, 2]);
+        let mut compressed_block = CompressedBlock::default();
+        compress(&mut block, &mut compressed_block, None).map_err(to_compute_err)?;
+        write_block(&mut buf, &compressed_block).map_err(to_compute_err)?;
+        buf.set_position(0);
+
+        let read_df = AvroReader::new(buf).finish()?;
+        let e = read_df.column("e")?;
+        assert!(matches!(e.dtype(), DataType::Categorical(_, _)));
+        let e = e.cast(&DataType::Utf8)?;
+        assert_eq!(Vec::from(e.utf8()?), &[Some("b"), Some("a"), Some("b")]);
+        Ok(())
+    }
 }
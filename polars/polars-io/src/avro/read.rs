@@ -9,6 +9,9 @@ use crate::prelude::*;
 
 /// Read Apache Avro format into a DataFrame
 ///
+/// Enums are read as `Categorical`, decimals as `Decimal`, arrays as `List` and records as
+/// `Struct`. Timestamps are read as `Datetime` without a time zone, in UTC.
+///
 /// # Example
 /// ```
 /// use std::fs::File;
@@ -118,13 +121,25 @@ where
         let avro_reader =
             avro::read::Reader::new(&mut self.reader, metadata, schema.fields, projection);
 
-        finish_reader(
+        let df = finish_reader(
             avro_reader,
             rechunk,
             self.n_rows,
             None,
             &projected_schema,
             None,
-        )
+        )?;
+        // avro timestamps are in UTC
+        #[cfg(feature = "dtype-datetime")]
+        let df = DataFrame::new_no_checks(
+            df.get_columns()
+                .iter()
+                .map(|s| match s.dtype() {
+                    DataType::Datetime(tu, Some(_)) => s.cast(&DataType::Datetime(*tu, None)),
+                    _ => Ok(s.clone()),
+                })
+                .collect::<PolarsResult<_>>()?,
+        );
+        Ok(df)
     }
 }
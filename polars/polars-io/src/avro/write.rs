@@ -8,6 +8,11 @@ use super::*;
 
 /// Write a DataFrame to Apache Avro format
 ///
+/// Avro has no small or unsigned integers or nanosecond timestamps, so these are written as
+/// `Int32`/`Int64` and microsecond timestamps. Timestamps are written in UTC and decimals
+/// with the decimal logical type. Categoricals are written as strings, not as avro enums, as
+/// the categories of an enum are fixed in the schema of the file.
+///
 /// # Example
 ///
 /// ```
@@ -52,8 +57,23 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| {
+                let dtype = avro_dtype(s.dtype());
+                if &dtype == s.dtype() {
+                    Ok(s.clone())
+                } else {
+                    s.strict_cast(&dtype)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let df = DataFrame::new_no_checks(columns);
         let schema = df.schema().to_arrow();
         let record = write::to_record(&schema)?;
+        avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
+            .map_err(to_compute_err)?;
 
         let mut data = vec![];
         let mut compressed_block = avro_schema::file::CompressedBlock::default();
@@ -71,9 +91,6 @@ where
                 avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
                     .map_err(to_compute_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
             avro_schema::write::write_block(&mut self.writer, &compressed_block)
                 .map_err(to_compute_err)?;
             // reuse block for next iteration.
@@ -88,3 +105,27 @@ where
         Ok(())
     }
 }
+
+/// The dtype that values of `dtype` are written as.
+fn avro_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        Int8 | Int16 | UInt8 | UInt16 => Int32,
+        UInt32 | UInt64 => Int64,
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) => Utf8,
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(TimeUnit::Nanoseconds, _) => Datetime(TimeUnit::Microseconds, None),
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(tu, _) => Datetime(*tu, None),
+        List(inner) => List(Box::new(avro_dtype(inner))),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => Struct(
+            fields
+                .iter()
+                .map(|field| Field::new(field.name(), avro_dtype(field.data_type())))
+                .collect(),
+        ),
+        dtype => dtype.clone(),
+    }
+}
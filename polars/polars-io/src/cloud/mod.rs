@@ -21,16 +21,14 @@ fn err_missing_feature(feature: &str, scheme: &str) -> BuildResult {
         "feature '{}' must be enabled in order to use '{}' cloud urls", feature, scheme,
     );
 }
-#[cfg(any(feature = "azure", feature = "aws", feature = "gcp"))]
-fn err_missing_configuration(feature: &str, scheme: &str) -> BuildResult {
-    polars_bail!(
-        ComputeError:
-        "configuration '{}' must be provided in order to use '{}' cloud urls", feature, scheme,
-    );
-}
 /// Build an ObjectStore based on the URL and passed in url. Return the cloud location and an implementation of the object store.
+/// Without options, the credentials are taken from the environment.
 pub fn build(url: &str, _options: Option<&CloudOptions>) -> BuildResult {
     let cloud_location = CloudLocation::new(url)?;
+    #[allow(unused_variables)]
+    let default_options = CloudOptions::default();
+    #[allow(unused_variables)]
+    let options = _options.unwrap_or(&default_options);
     let store = match CloudType::from_str(url)? {
         CloudType::File => {
             let local = LocalFileSystem::new();
@@ -38,38 +36,27 @@ pub fn build(url: &str, _options: Option<&CloudOptions>) -> BuildResult {
         }
         CloudType::Aws => {
             #[cfg(feature = "aws")]
-            match _options {
-                Some(options) => {
-                    let store = options.build_aws(&cloud_location.bucket)?;
-                    Ok::<_, PolarsError>(Box::new(store) as Box<dyn ObjectStore>)
-                }
-                _ => return err_missing_configuration("aws", &cloud_location.scheme),
+            {
+                let store = options.build_aws(&cloud_location.bucket)?;
+                Ok::<_, PolarsError>(Box::new(store) as Box<dyn ObjectStore>)
             }
             #[cfg(not(feature = "aws"))]
             return err_missing_feature("aws", &cloud_location.scheme);
         }
         CloudType::Gcp => {
             #[cfg(feature = "gcp")]
-            match _options {
-                Some(options) => {
-                    let store = options.build_gcp(&cloud_location.bucket)?;
-                    Ok::<_, PolarsError>(Box::new(store) as Box<dyn ObjectStore>)
-                }
-                _ => return err_missing_configuration("gcp", &cloud_location.scheme),
+            {
+                let store = options.build_gcp(&cloud_location.bucket)?;
+                Ok::<_, PolarsError>(Box::new(store) as Box<dyn ObjectStore>)
             }
             #[cfg(not(feature = "gcp"))]
             return err_missing_feature("gcp", &cloud_location.scheme);
         }
         CloudType::Azure => {
+            #[cfg(feature = "azure")]
             {
-                #[cfg(feature = "azure")]
-                match _options {
-                    Some(options) => {
-                        let store = options.build_azure(&cloud_location.bucket)?;
-                        Ok::<_, PolarsError>(Box::new(store) as Box<dyn ObjectStore>)
-                    }
-                    _ => return err_missing_configuration("azure", &cloud_location.scheme),
-                }
+                let store = options.build_azure(&cloud_location.bucket)?;
+                Ok::<_, PolarsError>(Box::new(store) as Box<dyn ObjectStore>)
             }
             #[cfg(not(feature = "azure"))]
             return err_missing_feature("azure", &cloud_location.scheme);
@@ -546,7 +546,7 @@ pub(crate) fn init_buffers<'a>(
                 #[cfg(feature = "dtype-date")]
                 &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity)),
                 #[cfg(feature = "dtype-categorical")]
-                &DataType::Categorical(_) => {
+                &DataType::Categorical(_, _) => {
                     Buffer::Categorical(CategoricalField::new(name, capacity, quote_char))
                 }
                 dt => polars_bail!(
@@ -707,10 +707,10 @@ impl<'a> Buffer<'a> {
             Buffer::Datetime { tu, .. } => DataType::Datetime(*tu, None),
             #[cfg(feature = "dtype-date")]
             Buffer::Date(_) => DataType::Date,
-            Buffer::Categorical(_) => {
+            Buffer::Categorical(_, _) => {
                 #[cfg(feature = "dtype-categorical")]
                 {
-                    DataType::Categorical(None)
+                    DataType::Categorical(None, Default::default())
                 }
 
                 #[cfg(not(feature = "dtype-categorical"))]
@@ -62,10 +62,11 @@ use polars_core::prelude::*;
 use polars_time::prelude::*;
 #[cfg(feature = "temporal")]
 use rayon::prelude::*;
-pub use read::{CsvEncoding, CsvReader, NullValues};
+pub use read::{CsvEncoding, CsvReader, DateFormats, NullValues, RaggedLineMode};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use write::CsvWriter;
+pub use write_impl::QuoteStyle;
 
 use crate::csv::read_impl::CoreReader;
 use crate::csv::utils::get_reader_bytes;
@@ -1,9 +1,11 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use memchr::memchr2_iter;
 use num_traits::Pow;
 use polars_core::prelude::*;
 
 use super::buffer::*;
-use crate::csv::read::NullValuesCompiled;
+use crate::csv::read::{NullValuesCompiled, RaggedLineMode};
 use crate::csv::splitfields::SplitFields;
 
 /// Skip the utf-8 Byte Order Mark.
@@ -31,6 +33,7 @@ pub(crate) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
     delimiter: u8,
+    comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<usize> {
@@ -89,7 +92,10 @@ pub(crate) fn next_line_position(
         }
         debug_assert!(pos <= input.len());
         let new_input = unsafe { input.get_unchecked(pos..) };
-        let mut lines = SplitLines::new(new_input, quote_char.unwrap_or(b'"'), eol_char);
+        // comment lines don't have the expected number of fields, but they are
+        // valid line starts as the parser skips them
+        let mut lines = SplitLines::new(new_input, quote_char.unwrap_or(b'"'), eol_char)
+            .filter(|line| !is_comment_line(line, comment_char));
         let line = lines.next();
 
         match (line, expected_fields) {
@@ -124,6 +130,14 @@ pub(crate) fn next_line_position(
     }
 }
 
+#[inline]
+pub(crate) fn is_comment_line(line: &[u8], comment_char: Option<u8>) -> bool {
+    match comment_char {
+        Some(c) => line.first() == Some(&c),
+        None => false,
+    }
+}
+
 pub(crate) fn is_line_ending(b: u8, eol_char: u8) -> bool {
     b == eol_char || b == b'\r'
 }
@@ -355,6 +369,8 @@ fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
 /// * `projection` - Indices of the columns to project.
 /// * `buffers` - Parsed output will be written to these buffers. Except for UTF8 data. The offsets of the
 ///               fields are written to the buffers. The UTF8 data will be parsed later.
+/// * `ragged_lines` - How lines with a different number of fields than the schema are handled.
+/// * `skipped_rows` - Incremented for every line skipped with [`RaggedLineMode::SkipRow`].
 #[allow(clippy::too_many_arguments)]
 pub(super) fn parse_lines<'a>(
     mut bytes: &'a [u8],
@@ -365,6 +381,8 @@ pub(super) fn parse_lines<'a>(
     eol_char: u8,
     null_values: Option<&NullValuesCompiled>,
     missing_is_null: bool,
+    ragged_lines: RaggedLineMode,
+    skipped_rows: &AtomicUsize,
     projection: &[usize],
     buffers: &mut [Buffer<'a>],
     ignore_errors: bool,
@@ -411,6 +429,30 @@ pub(super) fn parse_lines<'a>(
             }
         }
 
+        if ragged_lines != RaggedLineMode::Truncate {
+            let n_fields = SplitFields::new(bytes, delimiter, quote_char, eol_char).count();
+            if n_fields != schema_len
+                && !(ragged_lines == RaggedLineMode::PadNull && n_fields < schema_len)
+            {
+                if ragged_lines == RaggedLineMode::SkipRow {
+                    bytes = skip_this_line(bytes, quote_char, eol_char);
+                    skipped_rows.fetch_add(1, Ordering::Relaxed);
+                    line_count += 1;
+                    continue;
+                }
+                let bytes_offset = offset + bytes.as_ptr() as usize - start;
+                polars_bail!(
+                    ComputeError:
+                    "found {} fields in a line, but the schema has {} fields.\n\
+                    The current offset in the file is {} bytes.\n\
+                    \n\
+                    Consider setting `truncate_ragged_lines` or the ragged line mode to \
+                    `SkipRow` to read this file.",
+                    n_fields, schema_len, bytes_offset,
+                );
+            }
+        }
+
         // Every line we only need to parse the columns that are projected.
         // Therefore we check if the idx of the field is in our projected columns.
         // If it is not, we skip the field.
@@ -1,9 +1,18 @@
+use std::sync::atomic::AtomicUsize;
+
+#[cfg(feature = "temporal")]
+use chrono::format::{Item, Parsed, StrftimeItems};
+
 use super::*;
 use crate::csv::read_impl::{
     to_batched_owned_mmap, to_batched_owned_read, BatchedCsvReaderMmap, BatchedCsvReaderRead,
     OwnedBatchedCsvReader, OwnedBatchedCsvReaderMmap,
 };
-use crate::csv::utils::infer_file_schema;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::csv::utils::decompress;
+use crate::csv::utils::{infer_file_schema, is_compressed};
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::mmap::ReaderBytes;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -21,17 +30,172 @@ pub enum NullValues {
     AllColumnsSingle(String),
     /// Multiple values that are used for all columns
     AllColumns(Vec<String>),
-    /// Tuples that map column names to null value of that column
+    /// Tuples that map column names to null value of that column.
+    /// A column can have multiple null values by repeating its name.
     Named(Vec<(String, String)>),
 }
 
+/// How lines that don't have the same number of fields as the schema are handled.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RaggedLineMode {
+    /// Raise an error on lines with missing or extra fields.
+    Error,
+    /// Drop extra fields and set missing fields to null.
+    #[default]
+    Truncate,
+    /// Set missing fields to null and raise an error on lines with extra fields.
+    PadNull,
+    /// Skip lines with missing or extra fields.
+    SkipRow,
+}
+
+/// `chrono` format strings that the date columns of a CSV file are inferred and parsed with.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DateFormats {
+    /// Every value may match any of the formats.
+    pub formats: Vec<String>,
+    /// Raise an error if a value of a date column doesn't match any of the formats.
+    pub strict: bool,
+}
+
+#[cfg(feature = "temporal")]
+impl DateFormats {
+    /// The dtype of the columns that are parsed with these formats.
+    pub fn dtype(&self) -> DataType {
+        if self.formats.iter().any(|fmt| fmt_has_time(fmt)) {
+            DataType::Datetime(TimeUnit::Microseconds, None)
+        } else {
+            DataType::Date
+        }
+    }
+
+    pub(super) fn parse(&self, ca: &Utf8Chunked, dtype: &DataType) -> PolarsResult<Series> {
+        let datetime = DataType::Datetime(TimeUnit::Microseconds, None);
+
+        // every format is compiled once for the whole column,
+        // the values that don't match are tried with the next format
+        let mut out: Option<Int64Chunked> = None;
+        for fmt in &self.formats {
+            let parsed = if fmt_has_time(fmt) {
+                ca.as_datetime(Some(fmt), TimeUnit::Microseconds, true, false, false, None)?
+                    .into_series()
+            } else {
+                ca.as_date(Some(fmt), true)?.into_series()
+            };
+            let parsed = parsed.cast(&datetime)?.cast(&DataType::Int64)?;
+            let parsed = parsed.i64().unwrap();
+            let combined = match out {
+                None => parsed.clone(),
+                Some(out) => out.zip_with(&out.is_not_null(), parsed)?,
+            };
+            let all_matched = combined.null_count() == ca.null_count();
+            out = Some(combined);
+            if all_matched {
+                break;
+            }
+        }
+        let out = out.unwrap_or_else(|| Int64Chunked::full_null(ca.name(), ca.len()));
+        polars_ensure!(
+            !self.strict || out.null_count() == ca.null_count(),
+            ComputeError: "column '{}' has values that don't match the date formats {:?}",
+            ca.name(), self.formats
+        );
+        out.into_series().cast(&datetime)?.cast(dtype)
+    }
+}
+
+impl DateFormats {
+    /// Parse the format strings once, to match the values of many fields.
+    pub(super) fn compile(&self) -> CompiledDateFormats<'_> {
+        CompiledDateFormats {
+            #[cfg(feature = "temporal")]
+            formats: self
+                .formats
+                .iter()
+                .map(|fmt| (fmt_has_time(fmt), StrftimeItems::new(fmt).collect()))
+                .collect(),
+            #[cfg(feature = "temporal")]
+            dtype: self.dtype(),
+            date_formats: self,
+        }
+    }
+}
+
+/// [`DateFormats`] with the format strings parsed into `chrono` items.
+#[cfg_attr(not(feature = "temporal"), allow(dead_code))]
+pub(super) struct CompiledDateFormats<'a> {
+    /// Whether the format has time components and its items.
+    #[cfg(feature = "temporal")]
+    formats: Vec<(bool, Vec<Item<'a>>)>,
+    #[cfg(feature = "temporal")]
+    dtype: DataType,
+    pub(super) date_formats: &'a DateFormats,
+}
+
+#[cfg(feature = "temporal")]
+impl CompiledDateFormats<'_> {
+    pub(super) fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub(super) fn matches(&self, val: &str) -> bool {
+        self.formats.iter().any(|(has_time, items)| {
+            let mut parsed = Parsed::new();
+            chrono::format::parse(&mut parsed, val, items.iter()).is_ok()
+                && if *has_time {
+                    parsed.to_naive_datetime_with_offset(0).is_ok()
+                } else {
+                    parsed.to_naive_date().is_ok()
+                }
+        })
+    }
+}
+
+/// Whether a `chrono` format string has time components.
+#[cfg(feature = "temporal")]
+fn fmt_has_time(fmt: &str) -> bool {
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        // skip the padding and width modifiers
+        let spec = (&mut chars).find(|c| !matches!(c, '-' | '_' | '0'..='9' | '.' | ':' | '#'));
+        if matches!(
+            spec,
+            Some(
+                'H' | 'k'
+                    | 'I'
+                    | 'l'
+                    | 'M'
+                    | 'S'
+                    | 'f'
+                    | 'p'
+                    | 'P'
+                    | 'T'
+                    | 'R'
+                    | 'X'
+                    | 'r'
+                    | 'c'
+                    | 's'
+                    | '+'
+            )
+        ) {
+            return true;
+        }
+    }
+    false
+}
+
 pub(super) enum NullValuesCompiled {
     /// A single value that's used for all columns
     AllColumnsSingle(String),
     // Multiple null values that are null for all columns
     AllColumns(Vec<String>),
-    /// A different null value per column, computed from `NullValues::Named`
-    Columns(Vec<String>),
+    /// Different null values per column, computed from `NullValues::Named`
+    Columns(Vec<Vec<String>>),
 }
 
 impl NullValuesCompiled {
@@ -55,7 +219,7 @@ impl NullValuesCompiled {
             AllColumns(v) => v.iter().any(|v| v.as_bytes() == field),
             Columns(v) => {
                 debug_assert!(index < v.len());
-                v.get_unchecked(index).as_bytes() == field
+                v.get_unchecked(index).iter().any(|v| v.as_bytes() == field)
             }
         }
     }
@@ -67,10 +231,14 @@ impl NullValues {
             NullValues::AllColumnsSingle(v) => NullValuesCompiled::AllColumnsSingle(v),
             NullValues::AllColumns(v) => NullValuesCompiled::AllColumns(v),
             NullValues::Named(v) => {
-                let mut null_values = vec!["".to_string(); schema.len()];
+                let mut null_values = vec![vec![]; schema.len()];
                 for (name, null_value) in v {
                     let i = schema.try_index_of(&name)?;
-                    null_values[i] = null_value;
+                    null_values[i].push(null_value);
+                }
+                // columns without a null value only treat empty fields as null
+                for nv in null_values.iter_mut().filter(|nv| nv.is_empty()) {
+                    nv.push(String::new())
                 }
                 NullValuesCompiled::Columns(null_values)
             }
@@ -127,11 +295,16 @@ where
     eol_char: u8,
     null_values: Option<NullValues>,
     missing_is_null: bool,
+    ragged_lines: RaggedLineMode,
+    skipped_rows: Arc<AtomicUsize>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     quote_char: Option<u8>,
     skip_rows_after_header: usize,
     try_parse_dates: bool,
+    date_formats: Option<Vec<String>>,
+    strict_date_formats: bool,
     row_count: Option<RowCount>,
+    cached_schema: Option<SchemaRef>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -232,6 +405,29 @@ where
         self
     }
 
+    /// Set how lines with missing or extra fields are handled.
+    pub fn with_ragged_lines(mut self, ragged_lines: RaggedLineMode) -> Self {
+        self.ragged_lines = ragged_lines;
+        self
+    }
+
+    /// Drop the extra fields of lines that are longer than the schema. If `false`,
+    /// such lines raise an error. Lines that are too short are padded with nulls.
+    pub fn with_truncate_ragged_lines(mut self, toggle: bool) -> Self {
+        self.ragged_lines = if toggle {
+            RaggedLineMode::Truncate
+        } else {
+            RaggedLineMode::PadNull
+        };
+        self
+    }
+
+    /// Get a handle to the number of lines skipped with [`RaggedLineMode::SkipRow`].
+    /// The count is complete once the reader is finished.
+    pub fn skipped_rows(&self) -> Arc<AtomicUsize> {
+        self.skipped_rows.clone()
+    }
+
     /// Overwrite the schema with the dtypes in this given Schema. The given schema may be a subset
     /// of the total schema.
     pub fn with_dtypes(mut self, schema: Option<SchemaRef>) -> Self {
@@ -250,7 +446,7 @@ where
     ///
     /// # Arguments
     /// * `max_records` - Maximum number of rows read for schema inference.
-    ///                   Setting this to `None` will do a full table scan (slow).
+    ///                   Setting this to `None` will scan the whole file in parallel.
     pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
         // used by error ignore logic
         self.max_records = max_records;
@@ -313,6 +509,24 @@ where
         self
     }
 
+    /// Infer and parse the date columns with these `chrono` format strings. Every value may match
+    /// any of the formats. The columns whose values all match are of dtype [`DataType::Datetime`]
+    /// if one of the formats has time components, otherwise of dtype [`DataType::Date`]. Columns
+    /// that are given as date columns in the schema are parsed with these formats as well.
+    ///
+    /// This takes precedence over [`with_try_parse_dates`](Self::with_try_parse_dates).
+    pub fn with_date_formats(mut self, formats: Option<Vec<String>>) -> Self {
+        self.date_formats = formats;
+        self
+    }
+
+    /// Raise an error if a value of a date column doesn't match any of the formats
+    /// set with [`with_date_formats`](Self::with_date_formats).
+    pub fn with_strict_date_formats(mut self, toggle: bool) -> Self {
+        self.strict_date_formats = toggle;
+        self
+    }
+
     #[cfg(feature = "private")]
     pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
         self.predicate = predicate;
@@ -330,6 +544,89 @@ impl<'a> CsvReader<'a, File> {
 }
 
 impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
+    /// Infer the schema of the file with the current options, without reading the data.
+    /// The number of rows used for inference is set by [`infer_schema`](Self::infer_schema);
+    /// if that is `None` the whole file is scanned. The inferred schema is reused when the
+    /// file is read.
+    ///
+    /// Readers that cannot be memory mapped or borrowed are consumed by this method.
+    pub fn inferred_schema(&mut self) -> PolarsResult<SchemaRef> {
+        if let Some(schema) = &self.schema {
+            return Ok(schema.clone());
+        }
+        let (read_schema, to_cast) = match self.schema_overwrite.clone() {
+            Some(schema) => {
+                let (schema, to_cast, _) = self.prepare_schema_overwrite(&schema);
+                (self.infer_read_schema(Some(&schema))?, to_cast)
+            }
+            None => (self.infer_read_schema(None)?, vec![]),
+        };
+
+        // the dtypes that are cast after reading and the overwritten dtypes
+        let mut schema = read_schema.as_ref().clone();
+        for fld in to_cast {
+            schema.set_dtype(fld.name(), fld.data_type().clone());
+        }
+        if let Some(dtypes) = self.dtype_overwrite {
+            for (index, dt) in dtypes.iter().enumerate() {
+                schema.set_dtype_at_index(index, dt.clone());
+            }
+        }
+        Ok(Arc::new(schema))
+    }
+
+    /// Infer the schema that the file is read with. The schema is cached, unless the file is
+    /// compressed; the reader infers the schema of the decompressed file again.
+    fn infer_read_schema(&mut self, schema_overwrite: Option<&Schema>) -> PolarsResult<SchemaRef> {
+        if let Some(schema) = &self.cached_schema {
+            return Ok(schema.clone());
+        }
+        let date_formats = self.date_formats();
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let compressed = is_compressed(&reader_bytes);
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let reader_bytes = match decompress(
+            &reader_bytes,
+            self.n_rows,
+            self.delimiter.unwrap_or(b','),
+            self.quote_char,
+            self.eol_char,
+        ) {
+            Some(bytes) => ReaderBytes::Owned(bytes),
+            None => reader_bytes,
+        };
+        let mut skip_rows = self.skip_rows_before_header;
+        let (schema, _, _) = infer_file_schema(
+            &reader_bytes,
+            self.delimiter.unwrap_or(b','),
+            self.max_records,
+            self.has_header,
+            schema_overwrite,
+            &mut skip_rows,
+            self.skip_rows_after_header,
+            self.comment_char,
+            self.quote_char,
+            self.eol_char,
+            self.null_values.as_ref(),
+            self.try_parse_dates,
+            date_formats.as_ref(),
+        )?;
+        let schema = Arc::new(schema);
+        if !compressed {
+            // the inference skips the comment lines before the header
+            self.skip_rows_before_header = skip_rows;
+            self.cached_schema = Some(schema.clone());
+        }
+        Ok(schema)
+    }
+
+    fn date_formats(&self) -> Option<DateFormats> {
+        self.date_formats.clone().map(|formats| DateFormats {
+            formats,
+            strict: self.strict_date_formats,
+        })
+    }
+
     fn core_reader<'b>(
         &'b mut self,
         schema: Option<SchemaRef>,
@@ -338,6 +635,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
     where
         'a: 'b,
     {
+        let date_formats = self.date_formats();
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
         CoreReader::new(
             reader_bytes,
@@ -348,7 +646,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.delimiter,
             self.has_header,
             self.ignore_errors,
-            self.schema.clone(),
+            self.schema.clone().or_else(|| self.cached_schema.clone()),
             std::mem::take(&mut self.columns),
             self.encoding,
             self.n_threads,
@@ -362,11 +660,14 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.eol_char,
             std::mem::take(&mut self.null_values),
             self.missing_is_null,
+            self.ragged_lines,
+            self.skipped_rows.clone(),
             std::mem::take(&mut self.predicate),
             to_cast,
             self.skip_rows_after_header,
             std::mem::take(&mut self.row_count),
             self.try_parse_dates,
+            date_formats,
         )
     }
 
@@ -394,7 +695,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
                         Some(fld)
                     }
                     #[cfg(feature = "dtype-categorical")]
-                    Categorical(_) => {
+                    Categorical(_, _) => {
                         _has_categorical = true;
                         Some(fld)
                     }
@@ -440,6 +741,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
         match schema {
             Some(schema) => Ok(to_batched_owned_mmap(self, schema)),
             None => {
+                let date_formats = self.date_formats();
                 let reader_bytes = get_reader_bytes(&mut self.reader)?;
 
                 let (inferred_schema, _, _) = infer_file_schema(
@@ -455,6 +757,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
+                    date_formats.as_ref(),
                 )?;
                 let schema = Arc::new(inferred_schema);
                 Ok(to_batched_owned_mmap(self, schema))
@@ -468,6 +771,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
         match schema {
             Some(schema) => Ok(to_batched_owned_read(self, schema)),
             None => {
+                let date_formats = self.date_formats();
                 let reader_bytes = get_reader_bytes(&mut self.reader)?;
 
                 let (inferred_schema, _, _) = infer_file_schema(
@@ -483,6 +787,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
+                    date_formats.as_ref(),
                 )?;
                 let schema = Arc::new(inferred_schema);
                 Ok(to_batched_owned_read(self, schema))
@@ -521,10 +826,15 @@ where
             eol_char: b'\n',
             null_values: None,
             missing_is_null: true,
+            ragged_lines: RaggedLineMode::default(),
+            skipped_rows: Arc::new(AtomicUsize::new(0)),
             predicate: None,
             quote_char: Some(b'"'),
             skip_rows_after_header: 0,
             try_parse_dates: false,
+            date_formats: None,
+            strict_date_formats: false,
+            cached_schema: None,
             row_count: None,
         }
     }
@@ -543,7 +853,7 @@ where
 
             #[cfg(feature = "dtype-categorical")]
             if _has_cat {
-                _cat_lock = Some(polars_core::IUseStringCache::new())
+                _cat_lock = Some(polars_core::StringCacheHolder::new())
             }
 
             let mut csv_reader = self.core_reader(Some(Arc::new(schema)), to_cast)?;
@@ -557,11 +867,11 @@ where
                     .map(|schema| {
                         schema
                             .iter_dtypes()
-                            .any(|dtype| matches!(dtype, DataType::Categorical(_)))
+                            .any(|dtype| matches!(dtype, DataType::Categorical(_, _)))
                     })
                     .unwrap_or(false);
                 if has_cat {
-                    _cat_lock = Some(polars_core::IUseStringCache::new())
+                    _cat_lock = Some(polars_core::StringCacheHolder::new())
                 }
             }
             let mut csv_reader = self.core_reader(self.schema.clone(), vec![])?;
@@ -580,7 +890,8 @@ where
 
         #[cfg(feature = "temporal")]
         // only needed until we also can parse time columns in place
-        if self.try_parse_dates {
+        // the date formats take precedence over the date inference
+        if self.try_parse_dates && self.date_formats.is_none() {
             // determine the schema that's given by the user. That should not be changed
             let fixed_schema = match (schema_overwrite, self.dtype_overwrite) {
                 (Some(schema), _) => schema,
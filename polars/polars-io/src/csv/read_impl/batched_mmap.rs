@@ -14,6 +14,7 @@ pub(crate) fn get_file_chunks_iterator(
     bytes: &[u8],
     expected_fields: usize,
     delimiter: u8,
+    comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
@@ -28,6 +29,7 @@ pub(crate) fn get_file_chunks_iterator(
             &bytes[search_pos..],
             Some(expected_fields),
             delimiter,
+            comment_char,
             quote_char,
             eol_char,
         ) {
@@ -50,6 +52,7 @@ struct ChunkOffsetIter<'a> {
     rows_per_batch: usize,
     expected_fields: usize,
     delimiter: u8,
+    comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 }
@@ -69,6 +72,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                         &self.bytes[self.last_offset + 2..],
                         Some(self.expected_fields),
                         self.delimiter,
+                        self.comment_char,
                         self.quote_char,
                         self.eol_char,
                     )
@@ -85,6 +89,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     self.bytes,
                     self.expected_fields,
                     self.delimiter,
+                    self.comment_char,
                     self.quote_char,
                     self.eol_char,
                 );
@@ -124,6 +129,7 @@ impl<'a> CoreReader<'a> {
             rows_per_batch: self.chunk_size,
             expected_fields: self.schema.len(),
             delimiter: self.delimiter,
+            comment_char: self.comment_char,
             quote_char: self.quote_char,
             eol_char: self.eol_char,
         };
@@ -135,7 +141,7 @@ impl<'a> CoreReader<'a> {
         // RAII structure that will ensure we maintain a global stringcache
         #[cfg(feature = "dtype-categorical")]
         let _cat_lock = if _has_cat {
-            Some(polars_core::IUseStringCache::new())
+            Some(polars_core::StringCacheHolder::new())
         } else {
             None
         };
@@ -158,7 +164,10 @@ impl<'a> CoreReader<'a> {
             eol_char: self.eol_char,
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
+            ragged_lines: self.ragged_lines,
+            skipped_rows: self.skipped_rows,
             to_cast: self.to_cast,
+            date_formats: self.date_formats,
             ignore_errors: self.ignore_errors,
             n_rows: self.n_rows,
             encoding: self.encoding,
@@ -185,7 +194,10 @@ pub struct BatchedCsvReaderMmap<'a> {
     eol_char: u8,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
+    ragged_lines: RaggedLineMode,
+    skipped_rows: Arc<AtomicUsize>,
     to_cast: Vec<Field>,
+    date_formats: Option<DateFormats>,
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
@@ -193,7 +205,7 @@ pub struct BatchedCsvReaderMmap<'a> {
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
-    _cat_lock: Option<polars_core::IUseStringCache>,
+    _cat_lock: Option<polars_core::StringCacheHolder>,
     #[cfg(not(feature = "dtype-categorical"))]
     _cat_lock: Option<u8>,
 }
@@ -243,16 +255,18 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                         self.encoding,
                         self.null_values.as_ref(),
                         self.missing_is_null,
+                        self.ragged_lines,
+                        &self.skipped_rows,
                         self.chunk_size,
                         stop_at_nbytes,
                         self.starting_point_offset,
                     )?;
 
-                    cast_columns(&mut df, &self.to_cast, false)?;
+                    cast_columns(&mut df, &self.to_cast, false, self.date_formats.as_ref())?;
 
                     update_string_stats(&self.str_capacities, &self.str_columns, &df)?;
                     if let Some(rc) = &self.row_count {
-                        df.with_row_count_mut(&rc.name, Some(rc.offset));
+                        df.with_row_count_mut(&rc.name, Some(rc.offset))?;
                     }
                     Ok(df)
                 })
@@ -261,7 +275,7 @@ impl<'a> BatchedCsvReaderMmap<'a> {
         self.file_chunks.clear();
 
         if self.row_count.is_some() {
-            update_row_counts2(&mut chunks, self.rows_read)
+            update_row_counts2(&mut chunks, self.rows_read)?;
         }
         for df in &chunks {
             self.rows_read += df.height() as IdxSize;
@@ -15,6 +15,7 @@ pub(crate) fn get_offsets(
     bytes: &[u8],
     expected_fields: usize,
     delimiter: u8,
+    comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
@@ -30,6 +31,7 @@ pub(crate) fn get_offsets(
             &bytes[search_pos..],
             Some(expected_fields),
             delimiter,
+            comment_char,
             quote_char,
             eol_char,
         ) {
@@ -58,6 +60,7 @@ struct ChunkReader<'a> {
     rows_per_batch: usize,
     expected_fields: usize,
     delimiter: u8,
+    comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 }
@@ -68,6 +71,7 @@ impl<'a> ChunkReader<'a> {
         rows_per_batch: usize,
         expected_fields: usize,
         delimiter: u8,
+        comment_char: Option<u8>,
         quote_char: Option<u8>,
         eol_char: u8,
         page_size: u64,
@@ -86,6 +90,7 @@ impl<'a> ChunkReader<'a> {
             rows_per_batch,
             expected_fields,
             delimiter,
+            comment_char,
             quote_char,
             eol_char,
         }
@@ -133,6 +138,7 @@ impl<'a> ChunkReader<'a> {
                     &self.buf[2..],
                     Some(self.expected_fields),
                     self.delimiter,
+                    self.comment_char,
                     self.quote_char,
                     self.eol_char,
                 );
@@ -180,6 +186,7 @@ impl<'a> ChunkReader<'a> {
             &self.buf,
             self.expected_fields,
             self.delimiter,
+            self.comment_char,
             self.quote_char,
             self.eol_char,
         );
@@ -204,6 +211,7 @@ impl<'a> CoreReader<'a> {
             self.chunk_size,
             self.schema.len(),
             self.delimiter,
+            self.comment_char,
             self.quote_char,
             self.eol_char,
             4096,
@@ -216,7 +224,7 @@ impl<'a> CoreReader<'a> {
         // RAII structure that will ensure we maintain a global stringcache
         #[cfg(feature = "dtype-categorical")]
         let _cat_lock = if _has_cat {
-            Some(polars_core::IUseStringCache::new())
+            Some(polars_core::StringCacheHolder::new())
         } else {
             None
         };
@@ -239,7 +247,10 @@ impl<'a> CoreReader<'a> {
             eol_char: self.eol_char,
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
+            ragged_lines: self.ragged_lines,
+            skipped_rows: self.skipped_rows,
             to_cast: self.to_cast,
+            date_formats: self.date_formats,
             ignore_errors: self.ignore_errors,
             n_rows: self.n_rows,
             encoding: self.encoding,
@@ -266,7 +277,10 @@ pub struct BatchedCsvReaderRead<'a> {
     eol_char: u8,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
+    ragged_lines: RaggedLineMode,
+    skipped_rows: Arc<AtomicUsize>,
     to_cast: Vec<Field>,
+    date_formats: Option<DateFormats>,
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
@@ -274,7 +288,7 @@ pub struct BatchedCsvReaderRead<'a> {
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
-    _cat_lock: Option<polars_core::IUseStringCache>,
+    _cat_lock: Option<polars_core::StringCacheHolder>,
     #[cfg(not(feature = "dtype-categorical"))]
     _cat_lock: Option<u8>,
 }
@@ -338,16 +352,18 @@ impl<'a> BatchedCsvReaderRead<'a> {
                         self.encoding,
                         self.null_values.as_ref(),
                         self.missing_is_null,
+                        self.ragged_lines,
+                        &self.skipped_rows,
                         self.chunk_size,
                         stop_at_n_bytes,
                         self.starting_point_offset,
                     )?;
 
-                    cast_columns(&mut df, &self.to_cast, false)?;
+                    cast_columns(&mut df, &self.to_cast, false, self.date_formats.as_ref())?;
 
                     update_string_stats(&self.str_capacities, &self.str_columns, &df)?;
                     if let Some(rc) = &self.row_count {
-                        df.with_row_count_mut(&rc.name, Some(rc.offset));
+                        df.with_row_count_mut(&rc.name, Some(rc.offset))?;
                     }
                     Ok(df)
                 })
@@ -356,7 +372,7 @@ impl<'a> BatchedCsvReaderRead<'a> {
         self.file_chunks.clear();
 
         if self.row_count.is_some() {
-            update_row_counts2(&mut chunks, self.rows_read)
+            update_row_counts2(&mut chunks, self.rows_read)?;
         }
         for df in &chunks {
             self.rows_read += df.height() as IdxSize;
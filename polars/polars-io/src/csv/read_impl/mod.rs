@@ -21,7 +21,7 @@ use rayon::ThreadPoolBuilder;
 
 use crate::csv::buffer::*;
 use crate::csv::parser::*;
-use crate::csv::read::NullValuesCompiled;
+use crate::csv::read::{DateFormats, NullValuesCompiled, RaggedLineMode};
 use crate::csv::utils::*;
 use crate::csv::{CsvEncoding, NullValues};
 use crate::mmap::ReaderBytes;
@@ -33,21 +33,26 @@ pub(crate) fn cast_columns(
     df: &mut DataFrame,
     to_cast: &[Field],
     parallel: bool,
+    date_formats: Option<&DateFormats>,
 ) -> PolarsResult<()> {
-    let cast_fn = |s: &Series, fld: &Field| match (s.dtype(), fld.data_type()) {
+    let cast_fn = |s: &Series, fld: &Field| match (s.dtype(), fld.data_type(), date_formats) {
         #[cfg(feature = "temporal")]
-        (DataType::Utf8, DataType::Date) => s
+        (DataType::Utf8, DataType::Date | DataType::Datetime(_, _), Some(date_formats)) => {
+            date_formats.parse(s.utf8().unwrap(), fld.data_type())
+        }
+        #[cfg(feature = "temporal")]
+        (DataType::Utf8, DataType::Date, _) => s
             .utf8()
             .unwrap()
             .as_date(None, false)
             .map(|ca| ca.into_series()),
         #[cfg(feature = "temporal")]
-        (DataType::Utf8, DataType::Datetime(tu, _)) => s
+        (DataType::Utf8, DataType::Datetime(tu, _), _) => s
             .utf8()
             .unwrap()
             .as_datetime(None, *tu, false, false, false, None)
             .map(|ca| ca.into_series()),
-        (_, dt) => s.cast(dt),
+        (_, dt, _) => s.cast(dt),
     };
 
     if parallel {
@@ -98,9 +103,12 @@ pub(crate) struct CoreReader<'a> {
     eol_char: u8,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
+    ragged_lines: RaggedLineMode,
+    skipped_rows: Arc<AtomicUsize>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_count: Option<RowCount>,
+    date_formats: Option<DateFormats>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -186,11 +194,14 @@ impl<'a> CoreReader<'a> {
         eol_char: u8,
         null_values: Option<NullValues>,
         missing_is_null: bool,
+        ragged_lines: RaggedLineMode,
+        skipped_rows: Arc<AtomicUsize>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         to_cast: Vec<Field>,
         skip_rows_after_header: usize,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        date_formats: Option<DateFormats>,
     ) -> PolarsResult<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
@@ -233,6 +244,7 @@ impl<'a> CoreReader<'a> {
                         eol_char,
                         null_values.as_ref(),
                         try_parse_dates,
+                        date_formats.as_ref(),
                     )?;
                     Arc::new(inferred_schema)
                 }
@@ -246,7 +258,7 @@ impl<'a> CoreReader<'a> {
         }
 
         // create a null value for every column
-        let mut null_values = null_values.map(|nv| nv.compile(&schema)).transpose()?;
+        let null_values = null_values.map(|nv| nv.compile(&schema)).transpose()?;
 
         if let Some(cols) = columns {
             let mut prj = Vec::with_capacity(cols.len());
@@ -255,14 +267,29 @@ impl<'a> CoreReader<'a> {
                 prj.push(i);
             }
 
-            // update null values with projection
-            if let Some(nv) = null_values.as_mut() {
-                nv.apply_projection(&prj);
-            }
-
             projection = Some(prj);
         }
 
+        // the date columns are read as utf8 and parsed with the date formats afterwards
+        #[cfg(feature = "temporal")]
+        let mut to_cast = to_cast;
+        #[cfg(feature = "temporal")]
+        if date_formats.is_some() {
+            let date_columns = schema
+                .iter_fields()
+                .enumerate()
+                .filter(|(i, fld)| {
+                    matches!(fld.data_type(), DataType::Date | DataType::Datetime(_, _))
+                        && projection.as_ref().map_or(true, |prj| prj.contains(i))
+                })
+                .collect::<Vec<_>>();
+            let s = Arc::make_mut(&mut schema);
+            for (i, fld) in date_columns {
+                s.set_dtype_at_index(i, DataType::Utf8).unwrap();
+                to_cast.push(fld);
+            }
+        }
+
         Ok(CoreReader {
             reader_bytes: Some(reader_bytes),
             schema,
@@ -284,9 +311,12 @@ impl<'a> CoreReader<'a> {
             eol_char,
             null_values,
             missing_is_null,
+            ragged_lines,
+            skipped_rows,
             predicate,
             to_cast,
             row_count,
+            date_formats,
         })
     }
 
@@ -319,15 +349,27 @@ impl<'a> CoreReader<'a> {
         }
 
         if self.skip_rows_after_header > 0 {
-            for _ in 0..self.skip_rows_after_header {
+            let mut skipped = 0;
+            while skipped < self.skip_rows_after_header {
                 let pos = match bytes.first() {
+                    // comment lines don't count as rows
                     Some(first) if Some(*first) == self.comment_char => {
                         next_line_position_naive(bytes, eol_char)
                     }
                     // we don't pass expected fields
                     // as we want to skip all rows
                     // no matter the no. of fields
-                    _ => next_line_position(bytes, None, self.delimiter, self.quote_char, eol_char),
+                    _ => {
+                        skipped += 1;
+                        next_line_position(
+                            bytes,
+                            None,
+                            self.delimiter,
+                            self.comment_char,
+                            self.quote_char,
+                            eol_char,
+                        )
+                    }
                 }
                 .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
 
@@ -389,6 +431,7 @@ impl<'a> CoreReader<'a> {
                         &bytes[n_bytes..],
                         Some(self.schema.len()),
                         self.delimiter,
+                        self.comment_char,
                         self.quote_char,
                         self.eol_char,
                     ) {
@@ -444,6 +487,7 @@ impl<'a> CoreReader<'a> {
             n_file_chunks,
             self.schema.len(),
             self.delimiter,
+            self.comment_char,
             self.quote_char,
             self.eol_char,
         );
@@ -467,13 +511,20 @@ impl<'a> CoreReader<'a> {
     fn get_projection(&mut self) -> Vec<usize> {
         // we also need to sort the projection to have predictable output.
         // the `parse_lines` function expects this.
-        self.projection
+        let projection = self
+            .projection
             .take()
             .map(|mut v| {
                 v.sort_unstable();
                 v
             })
-            .unwrap_or_else(|| (0..self.schema.len()).collect())
+            .unwrap_or_else(|| (0..self.schema.len()).collect());
+
+        // the parser indexes the null values by the position in the projection
+        if let Some(nv) = self.null_values.as_mut() {
+            nv.apply_projection(&projection);
+        }
+        projection
     }
 
     fn get_string_columns(&self, projection: &[usize]) -> PolarsResult<StringColumns> {
@@ -611,6 +662,8 @@ impl<'a> CoreReader<'a> {
                                 self.eol_char,
                                 self.null_values.as_ref(),
                                 self.missing_is_null,
+                                self.ragged_lines,
+                                &self.skipped_rows,
                                 projection,
                                 &mut buffers,
                                 ignore_errors,
@@ -627,10 +680,15 @@ impl<'a> CoreReader<'a> {
                             );
                             let current_row_count = local_df.height() as IdxSize;
                             if let Some(rc) = &self.row_count {
-                                local_df.with_row_count_mut(&rc.name, Some(rc.offset));
+                                local_df.with_row_count_mut(&rc.name, Some(rc.offset))?;
                             };
 
-                            cast_columns(&mut local_df, &self.to_cast, false)?;
+                            cast_columns(
+                                &mut local_df,
+                                &self.to_cast,
+                                false,
+                                self.date_formats.as_ref(),
+                            )?;
                             let s = predicate.evaluate(&local_df)?;
                             let mask = s.bool()?;
                             local_df = local_df.filter(mask)?;
@@ -647,7 +705,7 @@ impl<'a> CoreReader<'a> {
             })?;
             let mut dfs = flatten(&dfs, None);
             if self.row_count.is_some() {
-                update_row_counts(&mut dfs, 0)
+                update_row_counts(&mut dfs, 0)?;
             }
             accumulate_dataframes_vertical(dfs.into_iter().map(|t| t.0))
         } else {
@@ -683,6 +741,8 @@ impl<'a> CoreReader<'a> {
                             self.encoding,
                             self.null_values.as_ref(),
                             self.missing_is_null,
+                            self.ragged_lines,
+                            &self.skipped_rows,
                             usize::MAX,
                             stop_at_nbytes,
                             starting_point_offset,
@@ -693,9 +753,9 @@ impl<'a> CoreReader<'a> {
                             update_string_stats(&str_capacities, &str_columns, &df)?;
                         }
 
-                        cast_columns(&mut df, &self.to_cast, false)?;
+                        cast_columns(&mut df, &self.to_cast, false, self.date_formats.as_ref())?;
                         if let Some(rc) = &self.row_count {
-                            df.with_row_count_mut(&rc.name, Some(rc.offset));
+                            df.with_row_count_mut(&rc.name, Some(rc.offset))?;
                         }
                         let n_read = df.height() as IdxSize;
                         Ok((df, n_read))
@@ -727,6 +787,8 @@ impl<'a> CoreReader<'a> {
                                 self.eol_char,
                                 self.null_values.as_ref(),
                                 self.missing_is_null,
+                                self.ragged_lines,
+                                &self.skipped_rows,
                                 &projection,
                                 &mut buffers,
                                 self.ignore_errors,
@@ -743,9 +805,9 @@ impl<'a> CoreReader<'a> {
                             )
                         };
 
-                        cast_columns(&mut df, &self.to_cast, false)?;
+                        cast_columns(&mut df, &self.to_cast, false, self.date_formats.as_ref())?;
                         if let Some(rc) = &self.row_count {
-                            df.with_row_count_mut(&rc.name, Some(rc.offset));
+                            df.with_row_count_mut(&rc.name, Some(rc.offset))?;
                         }
                         let n_read = df.height() as IdxSize;
                         (df, n_read)
@@ -753,7 +815,7 @@ impl<'a> CoreReader<'a> {
                 }
             }
             if self.row_count.is_some() {
-                update_row_counts(&mut dfs, 0)
+                update_row_counts(&mut dfs, 0)?;
             }
             accumulate_dataframes_vertical(dfs.into_iter().map(|t| t.0))
         }
@@ -811,6 +873,8 @@ fn read_chunk(
     encoding: CsvEncoding,
     null_values: Option<&NullValuesCompiled>,
     missing_is_null: bool,
+    ragged_lines: RaggedLineMode,
+    skipped_rows: &AtomicUsize,
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
@@ -844,6 +908,8 @@ fn read_chunk(
             eol_char,
             null_values,
             missing_is_null,
+            ragged_lines,
+            skipped_rows,
             projection,
             &mut buffers,
             ignore_errors,
@@ -5,26 +5,32 @@ use std::mem::MaybeUninit;
 use once_cell::sync::Lazy;
 use polars_core::datatypes::PlHashSet;
 use polars_core::prelude::*;
+use polars_core::POOL;
 #[cfg(feature = "polars-time")]
 use polars_time::chunkedarray::utf8::infer as date_infer;
 use polars_time::chunkedarray::utf8::PatternWithOffset;
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::utf8::Pattern;
+use rayon::prelude::*;
 use regex::{Regex, RegexBuilder};
 
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use crate::csv::parser::next_line_position_naive;
-use crate::csv::parser::{next_line_position, skip_bom, skip_line_ending, SplitLines};
+use crate::csv::parser::{
+    is_comment_line, next_line_position, skip_bom, skip_line_ending, SplitLines,
+};
+use crate::csv::read::CompiledDateFormats;
 use crate::csv::splitfields::SplitFields;
 use crate::csv::CsvEncoding;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
-use crate::prelude::NullValues;
+use crate::prelude::{DateFormats, NullValues};
 
 pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
     expected_fields: usize,
     delimiter: u8,
+    comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
@@ -43,6 +49,7 @@ pub(crate) fn get_file_chunks(
             &bytes[search_pos..],
             Some(expected_fields),
             delimiter,
+            comment_char,
             quote_char,
             eol_char,
         ) {
@@ -103,7 +110,18 @@ static BOOLEAN_RE: Lazy<Regex> = Lazy::new(|| {
 });
 
 /// Infer the data type of a record
-fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
+#[cfg_attr(not(feature = "temporal"), allow(unused_variables))]
+fn infer_field_schema(
+    string: &str,
+    try_parse_dates: bool,
+    date_formats: Option<&CompiledDateFormats>,
+) -> DataType {
+    #[cfg(feature = "temporal")]
+    if let Some(date_formats) = date_formats {
+        if date_formats.matches(string) {
+            return date_formats.dtype().clone();
+        }
+    }
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // Utf8 for them
     if string.starts_with('"') {
@@ -188,6 +206,90 @@ pub(crate) fn parse_bytes_with_encoding(
     })
 }
 
+/// Add the data types of the fields of a single line to the possible data types of the columns.
+#[allow(clippy::too_many_arguments)]
+fn infer_line_types(
+    mut line: &[u8],
+    headers: &[String],
+    column_types: &mut [PlHashSet<DataType>],
+    nulls: &mut [bool],
+    delimiter: u8,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    encoding: CsvEncoding,
+    null_values: Option<&NullValues>,
+    try_parse_dates: bool,
+    date_formats: Option<&CompiledDateFormats>,
+) -> PolarsResult<()> {
+    let len = line.len();
+    if len > 1 {
+        // remove carriage return
+        let trailing_byte = line[len - 1];
+        if trailing_byte == b'\r' {
+            line = &line[..len - 1];
+        }
+    }
+
+    let mut record = SplitFields::new(line, delimiter, quote_char, eol_char);
+
+    for i in 0..headers.len() {
+        if let Some((slice, needs_escaping)) = record.next() {
+            if slice.is_empty() {
+                nulls[i] = true;
+            } else {
+                let slice_escaped = if needs_escaping && (slice.len() >= 2) {
+                    &slice[1..(slice.len() - 1)]
+                } else {
+                    slice
+                };
+                let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
+                match &null_values {
+                    None => {
+                        column_types[i].insert(infer_field_schema(
+                            &s,
+                            try_parse_dates,
+                            date_formats,
+                        ));
+                    }
+                    Some(NullValues::AllColumns(names)) => {
+                        if !names.iter().any(|nv| nv == s.as_ref()) {
+                            column_types[i].insert(infer_field_schema(
+                                &s,
+                                try_parse_dates,
+                                date_formats,
+                            ));
+                        }
+                    }
+                    Some(NullValues::AllColumnsSingle(name)) => {
+                        if s.as_ref() != name {
+                            column_types[i].insert(infer_field_schema(
+                                &s,
+                                try_parse_dates,
+                                date_formats,
+                            ));
+                        }
+                    }
+                    Some(NullValues::Named(names)) => {
+                        let current_name = &headers[i];
+                        let is_null = names
+                            .iter()
+                            .any(|(name, nv)| name == current_name && nv == s.as_ref());
+
+                        if !is_null {
+                            column_types[i].insert(infer_field_schema(
+                                &s,
+                                try_parse_dates,
+                                date_formats,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Infer the schema of a CSV file by reading through the first n rows of the file,
 /// with `max_read_rows` controlling the maximum number of rows to read.
 ///
@@ -213,6 +315,7 @@ pub fn infer_file_schema(
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
+    date_formats: Option<&DateFormats>,
 ) -> PolarsResult<(Schema, usize, usize)> {
     // keep track so that we can determine the amount of bytes read
     let start_ptr = reader_bytes.as_ptr() as usize;
@@ -220,6 +323,10 @@ pub fn infer_file_schema(
     // We use lossy utf8 here because we don't want the schema inference to fail on utf8.
     // It may later.
     let encoding = CsvEncoding::LossyUtf8;
+    // the date formats take precedence over the date inference
+    let try_parse_dates = try_parse_dates && date_formats.is_none();
+    // the formats are parsed once and not for every field
+    let compiled_date_formats = date_formats.map(DateFormats::compile);
 
     let bytes = skip_line_ending(skip_bom(reader_bytes), eol_char);
     polars_ensure!(!bytes.is_empty(), NoData: "empty CSV");
@@ -321,6 +428,7 @@ pub fn infer_file_schema(
             eol_char,
             null_values,
             try_parse_dates,
+            date_formats,
         );
     } else {
         polars_bail!(NoData: "empty CSV");
@@ -344,83 +452,92 @@ pub fn infer_file_schema(
     let records_ref = &mut lines;
 
     let mut end_ptr = start_ptr;
-    for mut line in records_ref
-        .take(match max_read_rows {
-            Some(max_read_rows) => {
-                if max_read_rows <= (usize::MAX - skip_rows_after_header) {
-                    // read skip_rows_after_header more rows for inferring
-                    // the correct schema as the first skip_rows_after_header
-                    // rows will be skipped
-                    max_read_rows + skip_rows_after_header
-                } else {
-                    max_read_rows
-                }
-            }
-            None => usize::MAX,
-        })
-        .skip(skip_rows_after_header)
-    {
-        rows_count += 1;
-        // keep track so that we can determine the amount of bytes read
-        end_ptr = line.as_ptr() as usize + line.len();
-
-        if let Some(c) = comment_char {
-            // line is a comment -> skip
-            if line[0] == c {
-                continue;
-            }
-        }
-
-        let len = line.len();
-        if len > 1 {
-            // remove carriage return
-            let trailing_byte = line[len - 1];
-            if trailing_byte == b'\r' {
-                line = &line[..len - 1];
+    // comment lines don't count as rows
+    let mut records = records_ref.filter(|line| !is_comment_line(line, comment_char));
+    match max_read_rows {
+        Some(max_read_rows) => {
+            // read skip_rows_after_header more rows for inferring
+            // the correct schema as the first skip_rows_after_header
+            // rows will be skipped
+            for line in (&mut records)
+                .take(max_read_rows.saturating_add(skip_rows_after_header))
+                .skip(skip_rows_after_header)
+            {
+                rows_count += 1;
+                // keep track so that we can determine the amount of bytes read
+                end_ptr = line.as_ptr() as usize + line.len();
+
+                infer_line_types(
+                    line,
+                    &headers,
+                    &mut column_types,
+                    &mut nulls,
+                    delimiter,
+                    quote_char,
+                    eol_char,
+                    encoding,
+                    null_values,
+                    try_parse_dates,
+                    compiled_date_formats.as_ref(),
+                )?;
             }
         }
-
-        let mut record = SplitFields::new(line, delimiter, quote_char, eol_char);
-
-        for i in 0..header_length {
-            if let Some((slice, needs_escaping)) = record.next() {
-                if slice.is_empty() {
-                    nulls[i] = true;
-                } else {
-                    let slice_escaped = if needs_escaping && (slice.len() >= 2) {
-                        &slice[1..(slice.len() - 1)]
-                    } else {
-                        slice
-                    };
-                    let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
-                    match &null_values {
-                        None => {
-                            column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                        }
-                        Some(NullValues::AllColumns(names)) => {
-                            if !names.iter().any(|nv| nv == s.as_ref()) {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                            }
-                        }
-                        Some(NullValues::AllColumnsSingle(name)) => {
-                            if s.as_ref() != name {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                            }
-                        }
-                        Some(NullValues::Named(names)) => {
-                            let current_name = &headers[i];
-                            let null_name = &names.iter().find(|name| &name.0 == current_name);
-
-                            if let Some(null_name) = null_name {
-                                if null_name.1 != s.as_ref() {
-                                    column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                                }
-                            } else {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
+        // scan the whole file, we split it in chunks that are inferred in parallel
+        None => {
+            if let Some(first_line) = (&mut records).nth(skip_rows_after_header) {
+                let offset = first_line.as_ptr() as usize - bytes.as_ptr() as usize;
+                let remaining = &bytes[offset..];
+                let quote = quote_char.unwrap_or(b'"');
+
+                let chunks = get_file_chunks(
+                    remaining,
+                    POOL.current_num_threads(),
+                    header_length,
+                    delimiter,
+                    comment_char,
+                    quote_char,
+                    eol_char,
+                );
+                let results = POOL.install(|| {
+                    chunks
+                        .into_par_iter()
+                        .map(|(start, stop)| {
+                            let mut column_types = vec![PlHashSet::with_capacity(4); header_length];
+                            let mut nulls = vec![false; header_length];
+                            let mut rows_count = 0;
+                            for line in SplitLines::new(&remaining[start..stop], quote, eol_char)
+                                .filter(|line| !is_comment_line(line, comment_char))
+                            {
+                                rows_count += 1;
+                                infer_line_types(
+                                    line,
+                                    &headers,
+                                    &mut column_types,
+                                    &mut nulls,
+                                    delimiter,
+                                    quote_char,
+                                    eol_char,
+                                    encoding,
+                                    null_values,
+                                    try_parse_dates,
+                                    compiled_date_formats.as_ref(),
+                                )?;
                             }
-                        }
+                            Ok((column_types, nulls, rows_count))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()
+                })?;
+
+                for (chunk_types, chunk_nulls, chunk_rows) in results {
+                    for (types, chunk_types) in column_types.iter_mut().zip(chunk_types) {
+                        types.extend(chunk_types)
+                    }
+                    for (null, chunk_null) in nulls.iter_mut().zip(chunk_nulls) {
+                        *null |= chunk_null
                     }
+                    rows_count += chunk_rows;
                 }
+                end_ptr = remaining.as_ptr() as usize + remaining.len();
             }
         }
     }
@@ -446,6 +563,21 @@ pub fn infer_file_schema(
             }
         }
 
+        // a date column has values that don't match any of the date formats
+        #[cfg(feature = "temporal")]
+        if let Some(compiled) = &compiled_date_formats {
+            if possibilities.len() > 1 && possibilities.contains(compiled.dtype()) {
+                let date_formats = compiled.date_formats;
+                polars_ensure!(
+                    !date_formats.strict,
+                    ComputeError: "column '{}' has values that don't match the date formats {:?}",
+                    field_name, date_formats.formats
+                );
+                fields.push(Field::new(field_name, DataType::Utf8));
+                continue;
+            }
+        }
+
         // determine data type based on possible types
         // if there are incompatible types, use DataType::Utf8
         match possibilities.len() {
@@ -503,6 +635,7 @@ pub fn infer_file_schema(
             eol_char,
             null_values,
             try_parse_dates,
+            date_formats,
         );
     }
 
@@ -573,6 +706,7 @@ fn decompress_impl<R: Read>(
                     &out[buf_pos + 1..],
                     Some(expected_fields),
                     delimiter,
+                    None,
                     quote_char,
                     eol_char,
                 ) {
@@ -677,7 +811,11 @@ mod test {
         let s = std::fs::read_to_string(path).unwrap();
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
-        assert!((get_file_chunks(bytes, 10, 4, b',', None, b'\n').len() as i32 - 10).abs() <= 1);
-        assert!((get_file_chunks(bytes, 8, 4, b',', None, b'\n').len() as i32 - 8).abs() <= 1);
+        assert!(
+            (get_file_chunks(bytes, 10, 4, b',', None, None, b'\n').len() as i32 - 10).abs() <= 1
+        );
+        assert!(
+            (get_file_chunks(bytes, 8, 4, b',', None, None, b'\n').len() as i32 - 8).abs() <= 1
+        );
     }
 }
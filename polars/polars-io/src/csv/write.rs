@@ -99,6 +99,21 @@ where
         self
     }
 
+    /// Set the CSV file's float precision for a single column. This overrides
+    /// the precision set by [`with_float_precision`](Self::with_float_precision).
+    pub fn with_column_float_precision(mut self, column: &str, precision: usize) -> Self {
+        self.options
+            .column_float_precision
+            .insert(column.to_string(), precision);
+        self
+    }
+
+    /// Set when fields should be quoted
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.options.quote_style = quote_style;
+        self
+    }
+
     /// Set the CSV file's null value representation
     pub fn with_null_value(mut self, null_value: String) -> Self {
         self.options.null = null_value;
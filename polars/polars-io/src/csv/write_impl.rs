@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::io::Write;
 
 #[cfg(any(
@@ -11,37 +12,52 @@ use chrono::TimeZone;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
 use lexical_core::{FormattedSize, ToLexical};
-use memchr::{memchr, memchr2};
+use memchr::{memchr, memchr2, memchr3};
 use polars_core::prelude::*;
 use polars_core::series::SeriesIter;
 use polars_core::POOL;
 use polars_utils::contention_pool::LowContentionPool;
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 fn fmt_and_escape_str(f: &mut Vec<u8>, v: &str, options: &SerializeOptions) -> std::io::Result<()> {
+    let quote = char::from(options.quote);
+    if options.quote_style == QuoteStyle::Never {
+        if memchr2(options.delimiter, b'\n', v.as_bytes()).is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("field '{v}' contains the separator or a newline, but quoting is disabled"),
+            ));
+        }
+        return write!(f, "{v}");
+    }
     if v.is_empty() {
-        write!(f, "\"\"")
-    } else {
-        let needs_escaping = memchr(options.quote, v.as_bytes()).is_some();
+        return write!(f, "{quote}{quote}");
+    }
+    let needs_escaping = memchr(options.quote, v.as_bytes()).is_some();
 
-        if needs_escaping {
-            let replaced = unsafe {
-                // replace from single quote "
-                // to double quote ""
-                v.replace(
-                    std::str::from_utf8_unchecked(&[options.quote]),
-                    std::str::from_utf8_unchecked(&[options.quote, options.quote]),
-                )
-            };
-            return write!(f, "\"{replaced}\"");
-        }
-        let surround_with_quotes = memchr2(options.delimiter, b'\n', v.as_bytes()).is_some();
+    if needs_escaping {
+        let replaced = unsafe {
+            // replace from single quote "
+            // to double quote ""
+            v.replace(
+                std::str::from_utf8_unchecked(&[options.quote]),
+                std::str::from_utf8_unchecked(&[options.quote, options.quote]),
+            )
+        };
+        return write!(f, "{quote}{replaced}{quote}");
+    }
+    let surround_with_quotes = match options.quote_style {
+        QuoteStyle::Necessary => memchr3(options.delimiter, b'\n', b'\r', v.as_bytes()).is_some(),
+        QuoteStyle::Always | QuoteStyle::NonNumeric => true,
+        QuoteStyle::Never => unreachable!(),
+    };
 
-        if surround_with_quotes {
-            write!(f, "\"{v}\"")
-        } else {
-            write!(f, "{v}")
-        }
+    if surround_with_quotes {
+        write!(f, "{quote}{v}{quote}")
+    } else {
+        write!(f, "{v}")
     }
 }
 
@@ -61,7 +77,34 @@ fn write_anyvalue(
     value: AnyValue,
     options: &SerializeOptions,
     #[allow(unused_variables)] datetime_format: Option<&str>,
+    float_precision: Option<usize>,
 ) -> PolarsResult<()> {
+    // strings are quoted by `fmt_and_escape_str`
+    let quote = match &value {
+        AnyValue::Null | AnyValue::Utf8(_) => false,
+        #[cfg(feature = "dtype-categorical")]
+        AnyValue::Categorical(..) => false,
+        v => match options.quote_style {
+            QuoteStyle::Always => true,
+            QuoteStyle::NonNumeric => !matches!(
+                v,
+                AnyValue::Int8(_)
+                    | AnyValue::Int16(_)
+                    | AnyValue::Int32(_)
+                    | AnyValue::Int64(_)
+                    | AnyValue::UInt8(_)
+                    | AnyValue::UInt16(_)
+                    | AnyValue::UInt32(_)
+                    | AnyValue::UInt64(_)
+                    | AnyValue::Float32(_)
+                    | AnyValue::Float64(_)
+            ),
+            QuoteStyle::Necessary | QuoteStyle::Never => false,
+        },
+    };
+    if quote {
+        f.push(options.quote);
+    }
     match value {
         AnyValue::Null => write!(f, "{}", &options.null),
         AnyValue::Int8(v) => write!(f, "{v}"),
@@ -72,11 +115,11 @@ fn write_anyvalue(
         AnyValue::UInt16(v) => write!(f, "{v}"),
         AnyValue::UInt32(v) => write!(f, "{v}"),
         AnyValue::UInt64(v) => write!(f, "{v}"),
-        AnyValue::Float32(v) => match &options.float_precision {
+        AnyValue::Float32(v) => match &float_precision {
             None => fast_float_write(f, v, f32::FORMATTED_SIZE_DECIMAL),
             Some(precision) => write!(f, "{v:.precision$}"),
         },
-        AnyValue::Float64(v) => match &options.float_precision {
+        AnyValue::Float64(v) => match &float_precision {
             None => fast_float_write(f, v, f64::FORMATTED_SIZE_DECIMAL),
             Some(precision) => write!(f, "{v:.precision$}"),
         },
@@ -146,7 +189,28 @@ fn write_anyvalue(
             )
         }
         _ => polars_err!(ComputeError: "error writing value {}: {}", value, err),
-    })
+    })?;
+    if quote {
+        f.push(options.quote);
+    }
+    Ok(())
+}
+
+/// Quote style indicating when to insert quotes around a field.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuoteStyle {
+    /// Quote fields only when necessary, e.g. when they contain the quote character,
+    /// the separator or a newline.
+    #[default]
+    Necessary,
+    /// Quote every field. Nulls are never quoted.
+    Always,
+    /// Quote every field that is not an integer or a float.
+    NonNumeric,
+    /// Never quote a field. Writing a field that contains the separator or a newline
+    /// is an error.
+    Never,
 }
 
 /// Options to serialize logical types to CSV
@@ -161,12 +225,16 @@ pub struct SerializeOptions {
     pub datetime_format: Option<String>,
     /// used for [`DataType::Float64`] and [`DataType::Float32`]
     pub float_precision: Option<usize>,
+    /// overrides `float_precision` for the given columns
+    pub column_float_precision: BTreeMap<String, usize>,
     /// used as separator/delimiter
     pub delimiter: u8,
     /// quoting character
     pub quote: u8,
     /// null value representation
     pub null: String,
+    /// when to quote fields
+    pub quote_style: QuoteStyle,
 }
 
 impl Default for SerializeOptions {
@@ -176,9 +244,11 @@ impl Default for SerializeOptions {
             time_format: None,
             datetime_format: None,
             float_precision: None,
+            column_float_precision: BTreeMap::new(),
             delimiter: b',',
             quote: b'"',
             null: String::new(),
+            quote_style: QuoteStyle::default(),
         }
     }
 }
@@ -243,6 +313,18 @@ pub(crate) fn write<W: Write>(
         Some(_) => None,
     };
 
+    let float_precisions = df
+        .get_columns()
+        .iter()
+        .map(|col| {
+            options
+                .column_float_precision
+                .get(col.name())
+                .copied()
+                .or(options.float_precision)
+        })
+        .collect::<Vec<_>>();
+
     let len = df.height();
     let n_threads = POOL.current_num_threads();
     let total_rows_per_pool_iter = n_threads * chunk_size;
@@ -293,7 +375,14 @@ pub(crate) fn write<W: Write>(
                     };
                     match col.next() {
                         Some(value) => {
-                            write_anyvalue(&mut write_buffer, value, options, datetime_format)?;
+                            let float_precision = unsafe { *float_precisions.get_unchecked(i) };
+                            write_anyvalue(
+                                &mut write_buffer,
+                                value,
+                                options,
+                                datetime_format,
+                                float_precision,
+                            )?;
                         }
                         None => {
                             finished = true;
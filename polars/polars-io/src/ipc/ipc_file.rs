@@ -68,6 +68,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     /// Aggregates chunks afterwards to a single chunk.
     rechunk: bool,
     pub(super) n_rows: Option<usize>,
+    pub(super) skip_rows: usize,
     pub(super) projection: Option<Vec<usize>>,
     pub(crate) columns: Option<Vec<String>>,
     pub(super) row_count: Option<RowCount>,
@@ -75,9 +76,47 @@ pub struct IpcReader<R: MmapBytesReader> {
     metadata: Option<read::FileMetadata>,
 }
 
+/// Skips the first rows of the record batches of the inner reader. Batches that end before
+/// the offset are dropped as a whole, the first remaining batch is sliced.
+pub(super) struct SkipRows<R> {
+    inner: R,
+    skip_rows: usize,
+}
+
+impl<R> SkipRows<R> {
+    pub(super) fn new(inner: R, skip_rows: usize) -> Self {
+        Self { inner, skip_rows }
+    }
+}
+
+impl<R: ArrowReader> ArrowReader for SkipRows<R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        while let Some(chunk) = self.inner.next_record_batch()? {
+            if self.skip_rows == 0 {
+                return Ok(Some(chunk));
+            }
+            let len = chunk.len();
+            if len <= self.skip_rows {
+                self.skip_rows -= len;
+                continue;
+            }
+            // slicing is zero-copy, memory mapped arrays keep pointing into the file
+            let offset = std::mem::take(&mut self.skip_rows);
+            let arrays = chunk
+                .into_arrays()
+                .into_iter()
+                .map(|arr| arr.sliced(offset, len - offset))
+                .collect();
+            return Ok(Some(ArrowChunk::new(arrays)));
+        }
+        Ok(None)
+    }
+}
+
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
     if let PolarsError::ArrowError(ref e) = err {
-        if let arrow::error::Error::NotYetImplemented(s) = e.as_ref() {
+        let e: &arrow::error::Error = e;
+        if let arrow::error::Error::NotYetImplemented(s) = e {
             if s == "mmap can only be done on uncompressed IPC files" {
                 eprintln!(
                     "Could not mmap compressed IPC file, defaulting to normal read. \
@@ -101,6 +140,29 @@ impl<R: MmapBytesReader> IpcReader<R> {
         // this magic number 10 is computed from the yellow trip dataset
         Ok((metadata.size as usize) / n_cols / 10)
     }
+
+    /// The exact number of rows in the file.
+    ///
+    /// If the file can be memory mapped only the headers of the record batches are read,
+    /// otherwise the first column is read.
+    pub fn num_rows(&mut self) -> PolarsResult<usize> {
+        if let Some(file) = self.reader.to_file() {
+            // compressed files cannot be memory mapped
+            if let Ok(num_rows) = super::mmap::num_rows_memmapped(file) {
+                return Ok(num_rows);
+            }
+        }
+        let metadata = self.get_metadata()?.clone();
+        if metadata.schema.fields.is_empty() {
+            return Ok(0);
+        }
+        let reader = read::FileReader::new(&mut self.reader, metadata, Some(vec![0]), None);
+        let mut num_rows = 0;
+        for chunk in reader {
+            num_rows += chunk?.len();
+        }
+        Ok(num_rows)
+    }
     fn get_metadata(&mut self) -> PolarsResult<&read::FileMetadata> {
         if self.metadata.is_none() {
             self.metadata = Some(read::read_file_metadata(&mut self.reader)?);
@@ -125,6 +187,13 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Skip the first `n` rows of the file. With memory mapping, the record batches before the
+    /// offset are not read.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
     /// Columns to select/ project
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
@@ -175,9 +244,19 @@ impl<R: MmapBytesReader> IpcReader<R> {
             metadata.schema.clone()
         };
 
-        let reader = read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+        let limit = self.n_rows.map(|n| n + self.skip_rows);
+        let reader = read::FileReader::new(self.reader, metadata, self.projection, limit);
+        let reader = SkipRows::new(reader, self.skip_rows);
+        let row_count = self.row_count_after_skip();
+        finish_reader(reader, rechunk, None, predicate, &schema, row_count)
+    }
 
-        finish_reader(reader, rechunk, None, predicate, &schema, self.row_count)
+    /// The row count continues at the first row that is read.
+    pub(super) fn row_count_after_skip(&self) -> Option<RowCount> {
+        self.row_count.clone().map(|mut rc| {
+            rc.offset += self.skip_rows as IdxSize;
+            rc
+        })
     }
 }
 
@@ -196,6 +275,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             reader,
             rechunk: true,
             n_rows: None,
+            skip_rows: 0,
             columns: None,
             projection: None,
             row_count: None,
@@ -231,8 +311,11 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             metadata.schema.clone()
         };
 
+        let limit = self.n_rows.map(|n| n + self.skip_rows);
         let ipc_reader =
-            read::FileReader::new(self.reader, metadata.clone(), self.projection, self.n_rows);
-        finish_reader(ipc_reader, rechunk, None, None, &schema, self.row_count)
+            read::FileReader::new(self.reader, metadata.clone(), self.projection, limit);
+        let ipc_reader = SkipRows::new(ipc_reader, self.skip_rows);
+        let row_count = self.row_count_after_skip();
+        finish_reader(ipc_reader, rechunk, None, None, &schema, row_count)
     }
 }
@@ -42,6 +42,7 @@ use arrow::io::ipc::{read, write};
 use polars_core::prelude::*;
 
 use crate::prelude::*;
+use crate::utils::next_row_count;
 use crate::{finish_reader, ArrowReader, ArrowResult, WriterFactory};
 
 /// Read Arrows Stream IPC format into a DataFrame
@@ -108,6 +109,59 @@ impl<R: Read> IpcStreamReader<R> {
         self
     }
 
+    /// Read the stream one record batch at a time. The returned iterator yields a
+    /// [`DataFrame`] per record batch, so the stream can be processed incrementally.
+    ///
+    /// # Example
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use polars_io::ipc::IpcStreamReader;
+    /// use polars_io::SerReader;
+    ///
+    /// fn example(stdin: std::io::Stdin) -> PolarsResult<()> {
+    ///     for df in IpcStreamReader::new(stdin.lock()).batched()? {
+    ///         println!("{}", df?);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn batched(mut self) -> PolarsResult<BatchedIpcStreamReader<R>> {
+        let metadata = self.metadata()?;
+        let (sorted_projection, schema) = self.prepare_projection(&metadata)?;
+        let reader = read::StreamReader::new(self.reader, metadata, sorted_projection);
+        Ok(BatchedIpcStreamReader {
+            reader,
+            schema,
+            projection: self.projection,
+            row_count: self.row_count,
+            n_rows: self.n_rows,
+            rows_read: 0,
+        })
+    }
+
+    /// Returns the sorted projection and the projected schema.
+    fn prepare_projection(
+        &mut self,
+        metadata: &StreamMetadata,
+    ) -> PolarsResult<(Option<Vec<usize>>, ArrowSchema)> {
+        if let Some(columns) = self.columns.take() {
+            let prj = columns_to_projection(&columns, &metadata.schema)?;
+            self.projection = Some(prj);
+        }
+
+        let sorted_projection = self.projection.clone().map(|mut proj| {
+            proj.sort_unstable();
+            proj
+        });
+
+        let schema = if let Some(projection) = &sorted_projection {
+            apply_projection(&metadata.schema, projection)
+        } else {
+            metadata.schema.clone()
+        };
+        Ok((sorted_projection, schema))
+    }
+
     fn metadata(&mut self) -> PolarsResult<StreamMetadata> {
         match &self.metadata {
             None => {
@@ -159,23 +213,7 @@ where
     fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
         let metadata = self.metadata()?;
-        let schema = &metadata.schema;
-
-        if let Some(columns) = self.columns {
-            let prj = columns_to_projection(&columns, schema)?;
-            self.projection = Some(prj);
-        }
-
-        let sorted_projection = self.projection.clone().map(|mut proj| {
-            proj.sort_unstable();
-            proj
-        });
-
-        let schema = if let Some(projection) = &sorted_projection {
-            apply_projection(&metadata.schema, projection)
-        } else {
-            metadata.schema.clone()
-        };
+        let (sorted_projection, schema) = self.prepare_projection(&metadata)?;
 
         let include_row_count = self.row_count.is_some();
         let ipc_reader =
@@ -192,6 +230,59 @@ where
     }
 }
 
+/// Iterator over the record batches of an Arrow IPC stream, see [`IpcStreamReader::batched`].
+pub struct BatchedIpcStreamReader<R: Read> {
+    reader: read::StreamReader<R>,
+    /// Schema of the projected columns.
+    schema: ArrowSchema,
+    projection: Option<Vec<usize>>,
+    row_count: Option<RowCount>,
+    n_rows: Option<usize>,
+    rows_read: usize,
+}
+
+impl<R: Read> BatchedIpcStreamReader<R> {
+    /// Arrow schema of the batches that are read, the `row_count` column is not included.
+    pub fn arrow_schema(&self) -> &ArrowSchema {
+        &self.schema
+    }
+
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if matches!(self.n_rows, Some(n_rows) if self.rows_read >= n_rows) {
+            return Ok(None);
+        }
+        let batch = match self.reader.next_record_batch()? {
+            Some(batch) => batch,
+            None => return Ok(None),
+        };
+        let offset = self.rows_read as IdxSize;
+        self.rows_read += batch.len();
+        let mut df = DataFrame::try_from((batch, self.schema.fields.as_slice()))?;
+        if let Some(n_rows) = self.n_rows {
+            if self.rows_read > n_rows {
+                let len = df.height() - (self.rows_read - n_rows);
+                df = df.slice(0, len);
+            }
+        }
+        if let Some(rc) = &self.row_count {
+            df.with_row_count_mut(&rc.name, Some(next_row_count(rc.offset, offset)?))?;
+        }
+        Ok(Some(fix_column_order(
+            df,
+            self.projection.clone(),
+            self.row_count.is_some(),
+        )))
+    }
+}
+
+impl<R: Read> Iterator for BatchedIpcStreamReader<R> {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
+
 fn fix_column_order(df: DataFrame, projection: Option<Vec<usize>>, row_count: bool) -> DataFrame {
     if let Some(proj) = projection {
         let offset = usize::from(row_count);
@@ -253,6 +344,46 @@ impl<W> IpcStreamWriter<W> {
     }
 }
 
+impl<W: Write> IpcStreamWriter<W> {
+    /// Write the schema, the returned writer writes every [`DataFrame`] as soon as it is
+    /// passed, so that the consumer of the stream can process it incrementally.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedIpcStreamWriter<W>> {
+        let mut writer = write::StreamWriter::new(
+            self.writer,
+            WriteOptions {
+                compression: self.compression,
+            },
+        );
+        writer.start(&schema.to_arrow(), None)?;
+
+        Ok(BatchedIpcStreamWriter { writer })
+    }
+}
+
+pub struct BatchedIpcStreamWriter<W: Write> {
+    writer: write::StreamWriter<W>,
+}
+
+impl<W: Write> BatchedIpcStreamWriter<W> {
+    /// Write a batch to the ipc stream.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let iter = df.iter_chunks();
+        for batch in iter {
+            self.writer.write(&batch, None)?
+        }
+        Ok(())
+    }
+
+    /// Writes the end of stream marker.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()?;
+        Ok(())
+    }
+}
+
 impl<W> SerWriter<W> for IpcStreamWriter<W>
 where
     W: Write,
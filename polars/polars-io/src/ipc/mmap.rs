@@ -4,6 +4,7 @@ use arrow::io::ipc::read::{Dictionaries, FileMetadata};
 use arrow::mmap::{mmap_dictionaries_unchecked, mmap_unchecked};
 use memmap::Mmap;
 
+use super::ipc_file::SkipRows;
 use super::*;
 use crate::mmap::MmapBytesReader;
 use crate::utils::{apply_projection, columns_to_projection};
@@ -67,6 +68,19 @@ impl ArrowReader for MMapChunkIter<'_> {
     }
 }
 
+/// Count the rows of a memory mapped file. Mapping a record batch only reads its header, so
+/// the data buffers are not touched.
+pub(super) fn num_rows_memmapped(file: &std::fs::File) -> PolarsResult<usize> {
+    let mmap = unsafe { memmap::Mmap::map(file)? };
+    let metadata = read::read_file_metadata(&mut std::io::Cursor::new(mmap.as_ref()))?;
+    let mut reader = MMapChunkIter::new(mmap, metadata, &None)?;
+    let mut num_rows = 0;
+    while let Some(chunk) = reader.next_record_batch()? {
+        num_rows += chunk.len();
+    }
+    Ok(num_rows)
+}
+
 impl<R: MmapBytesReader> IpcReader<R> {
     pub(super) fn finish_memmapped(
         &mut self,
@@ -89,7 +103,10 @@ impl<R: MmapBytesReader> IpcReader<R> {
                     metadata.schema.clone()
                 };
 
+                // mapping a record batch only reads its header, so the batches before the
+                // offset are skipped without touching their buffers.
                 let reader = MMapChunkIter::new(mmap, metadata, &self.projection)?;
+                let reader = SkipRows::new(reader, self.skip_rows);
 
                 finish_reader(
                     reader,
@@ -98,7 +115,7 @@ impl<R: MmapBytesReader> IpcReader<R> {
                     self.n_rows,
                     predicate,
                     &schema,
-                    self.row_count.clone(),
+                    self.row_count_after_skip(),
                 )
             }
             None => polars_bail!(ComputeError: "cannot memory-map, you must provide a file"),
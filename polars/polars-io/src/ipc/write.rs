@@ -117,7 +117,8 @@ impl<W: Write> BatchedWriter<W> {
 pub enum IpcCompression {
     /// LZ4 (framed)
     LZ4,
-    /// ZSTD
+    /// ZSTD, compressed with the default level of zstd as the IPC writer
+    /// of arrow doesn't support setting a compression level.
     #[default]
     ZSTD,
 }
@@ -218,6 +219,58 @@ mod test {
         df_read.frame_equal(&expected);
     }
 
+    #[test]
+    fn test_read_ipc_with_skip_rows() -> PolarsResult<()> {
+        use tempdir::TempDir;
+
+        use crate::RowCount;
+
+        // three record batches
+        let mut df = df!("a" => [0, 1, 2])?;
+        df.vstack_mut(&df!("a" => [3, 4, 5])?)?;
+        df.vstack_mut(&df!("a" => [6, 7, 8])?)?;
+        let tempdir = TempDir::new("ipc-skip-rows")?;
+        let path = tempdir.path().join("data.ipc");
+        IpcWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+        let expected = df!("idx" => [4 as IdxSize, 5, 6], "a" => [4, 5, 6])?;
+        for memmap in [true, false] {
+            let df_read = IpcReader::new(std::fs::File::open(&path)?)
+                .memory_mapped(memmap)
+                .with_skip_rows(4)
+                .with_n_rows(Some(3))
+                .with_row_count(Some(RowCount {
+                    name: "idx".into(),
+                    offset: 0,
+                }))
+                .finish()?;
+            assert!(df_read.frame_equal(&expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_row_count_overflow() -> PolarsResult<()> {
+        use crate::RowCount;
+
+        // two record batches
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [0, 1])?;
+        df.vstack_mut(&df!("a" => [2, 3])?)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        // the second batch starts at `IdxSize::MAX + 1`
+        buf.set_position(0);
+        let out = IpcReader::new(buf)
+            .with_row_count(Some(RowCount {
+                name: "idx".into(),
+                offset: IdxSize::MAX - 1,
+            }))
+            .finish();
+        assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+        Ok(())
+    }
+
     #[test]
     fn test_read_ipc_with_columns() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -301,4 +354,29 @@ mod test {
         let df_read = IpcReader::new(buf).finish().unwrap();
         assert!(df.frame_equal(&df_read));
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn write_and_read_ipc_categorical_ordering() -> PolarsResult<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let lexical = Series::new("lexical", ["b", "a", "c"])
+            .cast(&DataType::Categorical(None, CategoricalOrdering::Lexical))?;
+        let physical = Series::new("physical", ["b", "a", "c"])
+            .cast(&DataType::Categorical(None, CategoricalOrdering::Physical))?;
+        let mut df = DataFrame::new(vec![lexical, physical])?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish()?;
+        let ca = df_read.column("lexical")?.categorical()?;
+        assert_eq!(ca.get_ordering(), CategoricalOrdering::Lexical);
+        assert_eq!(
+            ca.iter_str().collect::<Vec<_>>(),
+            &[Some("b"), Some("a"), Some("c")]
+        );
+        let ca = df_read.column("physical")?.categorical()?;
+        assert_eq!(ca.get_ordering(), CategoricalOrdering::Physical);
+        Ok(())
+    }
 }
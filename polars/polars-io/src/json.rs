@@ -166,6 +166,7 @@ where
     projection: Option<Vec<String>>,
     schema: Option<ArrowSchema>,
     json_format: JsonFormat,
+    nesting: JsonNesting,
 }
 
 impl<R> SerReader<R> for JsonReader<R>
@@ -181,6 +182,7 @@ where
             projection: None,
             schema: None,
             json_format: JsonFormat::Json,
+            nesting: JsonNesting::default(),
         }
     }
 
@@ -197,7 +199,11 @@ where
     fn finish(self) -> PolarsResult<DataFrame> {
         let rb: ReaderBytes = (&self.reader).into();
 
+        let nested = self.nesting.max_depth.is_some() || self.nesting.flatten_separator.is_some();
         let out = match self.json_format {
+            JsonFormat::Json if nested => {
+                parse_json_objects(rb.deref(), self.infer_schema_len, &self.nesting)
+            }
             JsonFormat::Json => {
                 use arrow::io::json::read::json_deserializer::Value;
                 let bytes = rb.deref();
@@ -237,10 +243,15 @@ where
                     None,
                     None,
                     None,
+                    None,
+                    None,
                     1024, // sample size
                     1 << 18,
                     false,
                     self.infer_schema_len,
+                    false,
+                    true,
+                    self.nesting,
                 )?;
                 let mut df: DataFrame = json_reader.as_df()?;
                 if self.rechunk {
@@ -303,4 +314,19 @@ where
         self.json_format = format;
         self
     }
+
+    /// Read objects that are nested deeper than `depth` as json strings. With a depth of `0`
+    /// all objects are read as json strings.
+    pub fn max_nesting_depth(mut self, depth: usize) -> Self {
+        self.nesting.max_depth = Some(depth);
+        self
+    }
+
+    /// Flatten nested objects into columns named by joining the keys with `separator`,
+    /// e.g. `{"a": {"b": 1}}` is read into column `a.b` with separator `"."`.
+    /// Objects in arrays are not flattened.
+    pub fn flatten_separator(mut self, separator: Option<&str>) -> Self {
+        self.nesting.flatten_separator = separator.map(|s| s.to_string());
+        self
+    }
 }
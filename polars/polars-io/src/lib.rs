@@ -58,6 +58,13 @@ use polars_core::prelude::*;
     feature = "ipc_streaming",
 ))]
 use crate::predicates::PhysicalIoExpr;
+#[cfg(any(
+    feature = "ipc",
+    feature = "json",
+    feature = "avro",
+    feature = "ipc_streaming",
+))]
+use crate::utils::next_row_count;
 
 pub trait SerReader<R>
 where
@@ -124,7 +131,7 @@ pub(crate) fn finish_reader<R: ArrowReader>(
         let mut df = DataFrame::try_from((batch, arrow_schema.fields.as_slice()))?;
 
         if let Some(rc) = &row_count {
-            df.with_row_count_mut(&rc.name, Some(current_num_rows + rc.offset));
+            df.with_row_count_mut(&rc.name, Some(next_row_count(rc.offset, current_num_rows)?))?;
         }
 
         if let Some(predicate) = &predicate {
@@ -177,4 +184,9 @@ pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
     p.as_ref().starts_with("s3://")
         || p.as_ref().starts_with("file://")
         || p.as_ref().starts_with("gcs://")
+        || p.as_ref().starts_with("gs://")
+        || p.as_ref().starts_with("gcp://")
+        || p.as_ref().starts_with("az://")
+        || p.as_ref().starts_with("abfs://")
+        || p.as_ref().starts_with("adl://")
 }
@@ -1,3 +1,4 @@
+use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
 
 use arrow::types::NativeType;
@@ -8,6 +9,7 @@ use polars_core::prelude::*;
 use polars_time::prelude::utf8::infer::{infer_pattern_single, DatetimeInfer};
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 use polars_time::prelude::utf8::Pattern;
+use simd_json::prelude::Writable;
 use simd_json::{BorrowedValue as Value, KnownKey, StaticNode};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,120 +22,180 @@ impl<'a> Hash for BufferKey<'a> {
     }
 }
 
-pub(crate) struct Buffer<'a>(&'a str, AnyValueBuffer<'a>);
+impl<'a> Borrow<str> for BufferKey<'a> {
+    fn borrow(&self) -> &str {
+        self.0.key()
+    }
+}
+
+pub(crate) struct Buffer<'a> {
+    name: &'a str,
+    dtype: &'a DataType,
+    buf: AnyValueBuffer<'a>,
+    /// The values are parsed to another dtype and must be cast to `dtype`.
+    cast: bool,
+    /// Set values that can't be parsed to null instead of raising an error.
+    ignore_errors: bool,
+}
 
 impl Buffer<'_> {
-    pub fn into_series(self) -> Series {
-        let mut s = self.1.into_series();
-        s.rename(self.0);
-        s
+    pub fn into_series(self) -> PolarsResult<Series> {
+        let mut s = self.buf.into_series();
+        if self.cast {
+            s = s.cast(self.dtype)?;
+        }
+        s.rename(self.name);
+        Ok(s)
     }
 
     #[inline]
     pub(crate) fn add(&mut self, value: &Value) -> PolarsResult<()> {
         use AnyValueBuffer::*;
-        match &mut self.1 {
-            Boolean(buf) => {
-                match value {
-                    Value::Static(StaticNode::Bool(b)) => buf.append_value(*b),
-                    _ => buf.append_null(),
+        if let Value::Static(StaticNode::Null) = value {
+            self.add_null();
+            return Ok(());
+        }
+        let parsed = match &mut self.buf {
+            Boolean(buf) => match value {
+                Value::Static(StaticNode::Bool(b)) => {
+                    buf.append_value(*b);
+                    true
                 }
-                Ok(())
-            }
-            Int32(buf) => {
-                let n = deserialize_number::<i32>(value);
-                match n {
-                    Some(v) => buf.append_value(v),
-                    None => buf.append_null(),
+                _ => false,
+            },
+            Int32(buf) => append_number(buf, value),
+            Int64(buf) => append_number(buf, value),
+            UInt64(buf) => append_number(buf, value),
+            UInt32(buf) => append_number(buf, value),
+            Float32(buf) => append_number(buf, value),
+            Float64(buf) => append_number(buf, value),
+            Utf8(buf) => match value {
+                Value::String(v) => {
+                    buf.append_value(v);
+                    true
                 }
-                Ok(())
-            }
-            Int64(buf) => {
-                let n = deserialize_number::<i64>(value);
-                match n {
-                    Some(v) => buf.append_value(v),
-                    None => buf.append_null(),
+                // the column was inferred as utf8 because of mixed types
+                Value::Static(StaticNode::Bool(v)) => {
+                    buf.append_value(v.to_string());
+                    true
                 }
-                Ok(())
-            }
-            UInt64(buf) => {
-                let n = deserialize_number::<u64>(value);
-                match n {
-                    Some(v) => buf.append_value(v),
-                    None => buf.append_null(),
+                Value::Static(StaticNode::I64(v)) => {
+                    buf.append_value(v.to_string());
+                    true
                 }
-                Ok(())
-            }
-            UInt32(buf) => {
-                let n = deserialize_number::<u32>(value);
-                match n {
-                    Some(v) => buf.append_value(v),
-                    None => buf.append_null(),
-                }
-                Ok(())
-            }
-            Float32(buf) => {
-                let n = deserialize_number::<f32>(value);
-                match n {
-                    Some(v) => buf.append_value(v),
-                    None => buf.append_null(),
+                Value::Static(StaticNode::U64(v)) => {
+                    buf.append_value(v.to_string());
+                    true
                 }
-                Ok(())
-            }
-            Float64(buf) => {
-                let n = deserialize_number::<f64>(value);
-                match n {
-                    Some(v) => buf.append_value(v),
-                    None => buf.append_null(),
+                Value::Static(StaticNode::F64(v)) => {
+                    buf.append_value(v.to_string());
+                    true
                 }
-                Ok(())
-            }
-
-            Utf8(buf) => {
-                match value {
-                    Value::String(v) => buf.append_value(v),
-                    _ => buf.append_null(),
+                // the value is nested deeper than the max nesting depth
+                Value::Array(_) | Value::Object(_) => {
+                    buf.append_value(value.encode());
+                    true
                 }
-                Ok(())
-            }
+                _ => false,
+            },
             #[cfg(feature = "dtype-datetime")]
-            Datetime(buf, _, _) => {
-                let v = deserialize_datetime::<Int64Type>(value);
-                buf.append_option(v);
-                Ok(())
-            }
+            Datetime(buf, _, _) => match deserialize_datetime::<Int64Type>(value) {
+                Some(v) => {
+                    buf.append_value(v);
+                    true
+                }
+                None => false,
+            },
             #[cfg(feature = "dtype-date")]
-            Date(buf) => {
-                let v = deserialize_datetime::<Int32Type>(value);
-                buf.append_option(v);
-                Ok(())
-            }
-            All(dtype, buf) => {
-                let av = deserialize_all(value, dtype)?;
-                buf.push(av);
-                Ok(())
-            }
+            Date(buf) => match deserialize_datetime::<Int32Type>(value) {
+                Some(v) => {
+                    buf.append_value(v);
+                    true
+                }
+                None => false,
+            },
+            All(dtype, buf) => match deserialize_all(value, dtype) {
+                Ok(av) => {
+                    buf.push(av);
+                    true
+                }
+                Err(e) if !self.ignore_errors => return Err(e),
+                Err(_) => false,
+            },
             _ => panic!("unexpected dtype when deserializing ndjson"),
+        };
+        if !parsed {
+            polars_ensure!(
+                self.ignore_errors,
+                ComputeError: "could not parse json value {} of column '{}' as {}; \
+                set `ignore_errors` to parse it as null",
+                value, self.name, self.dtype
+            );
+            self.add_null();
         }
+        Ok(())
     }
+
     pub fn add_null(&mut self) {
-        self.1.add(AnyValue::Null).expect("should not fail");
+        self.buf.add(AnyValue::Null).expect("should not fail");
     }
 }
+
+/// The dtype that the values of a column of `dtype` are parsed to.
+fn parse_dtype(dtype: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match dtype {
+        Int8 | Int16 | UInt8 | UInt16 => Some(Int64),
+        #[cfg(feature = "dtype-duration")]
+        Duration(_) => Some(Int64),
+        #[cfg(feature = "dtype-time")]
+        Time => Some(Int64),
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) => Some(Utf8),
+        _ => None,
+    }
+}
+
 pub(crate) fn init_buffers(
     schema: &Schema,
     capacity: usize,
+    ignore_errors: bool,
 ) -> PolarsResult<PlIndexMap<BufferKey, Buffer>> {
     schema
         .iter()
         .map(|(name, dtype)| {
-            let av_buf = (dtype, capacity).into();
+            let (buf, cast) = match parse_dtype(dtype) {
+                Some(parse_dtype) => ((&parse_dtype, capacity).into(), true),
+                None => ((dtype, capacity).into(), false),
+            };
             let key = KnownKey::from(name.as_str());
-            Ok((BufferKey(key), Buffer(name, av_buf)))
+            Ok((
+                BufferKey(key),
+                Buffer {
+                    name,
+                    dtype,
+                    buf,
+                    cast,
+                    ignore_errors,
+                },
+            ))
         })
         .collect()
 }
 
+fn append_number<T: PolarsNumericType>(
+    buf: &mut PrimitiveChunkedBuilder<T>,
+    value: &Value,
+) -> bool {
+    match deserialize_number::<T::Native>(value) {
+        Some(v) => {
+            buf.append_value(v);
+            true
+        }
+        None => false,
+    }
+}
+
 fn deserialize_number<T: NativeType + NumCast>(value: &Value) -> Option<T> {
     match value {
         Value::Static(StaticNode::F64(f)) => num_traits::cast(*f),
@@ -163,6 +225,10 @@ where
 }
 
 fn deserialize_all<'a>(json: &Value, dtype: &DataType) -> PolarsResult<AnyValue<'a>> {
+    if dtype == &DataType::Utf8 && !matches!(json, Value::String(_) | Value::Static(_)) {
+        // the value is nested deeper than the max nesting depth
+        return Ok(AnyValue::Utf8Owned(json.encode().into()));
+    }
     let out = match json {
         Value::Static(StaticNode::Bool(b)) => AnyValue::Boolean(*b),
         Value::Static(StaticNode::I64(i)) => AnyValue::Int64(*i),
@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 use std::fs::File;
-use std::io::Cursor;
 use std::path::PathBuf;
 
 pub use arrow::array::StructArray;
+use arrow::io::json;
+use arrow::io::json::read::json_deserializer;
 pub use arrow::io::ndjson as arrow_ndjson;
 use num_traits::pow::Pow;
+use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
@@ -31,8 +33,13 @@ where
     infer_schema_len: Option<usize>,
     chunk_size: usize,
     schema: Option<&'a Schema>,
+    schema_overwrite: Option<&'a Schema>,
+    projection: Option<Vec<String>>,
     path: Option<PathBuf>,
     low_memory: bool,
+    ignore_errors: bool,
+    ignore_unknown_keys: bool,
+    nesting: JsonNesting,
 }
 
 impl<'a, R> JsonLineReader<'a, R>
@@ -47,6 +54,48 @@ where
         self.schema = Some(schema);
         self
     }
+    /// Overwrite the dtypes of these columns in the inferred schema. Columns that are not
+    /// found during inference are added.
+    pub fn with_schema_overwrite(mut self, schema: Option<&'a Schema>) -> Self {
+        self.schema_overwrite = schema;
+        self
+    }
+
+    /// Only read these columns.
+    pub fn with_projection(mut self, projection: Option<Vec<String>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Parse values that don't match the dtype of their column as null instead of
+    /// raising an error.
+    pub fn with_ignore_errors(mut self, ignore: bool) -> Self {
+        self.ignore_errors = ignore;
+        self
+    }
+
+    /// Ignore keys that are not in the schema, e.g. keys that only appear after the lines
+    /// that are used for inference. If `false` such keys raise an error. Defaults to `true`.
+    pub fn with_ignore_unknown_keys(mut self, ignore: bool) -> Self {
+        self.ignore_unknown_keys = ignore;
+        self
+    }
+
+    /// Read objects that are nested deeper than `depth` as json strings. With a depth of `0`
+    /// all objects are read as json strings.
+    pub fn max_nesting_depth(mut self, depth: usize) -> Self {
+        self.nesting.max_depth = Some(depth);
+        self
+    }
+
+    /// Flatten nested objects into columns named by joining the keys with `separator`,
+    /// e.g. `{"a": {"b": 1}}` is read into column `a.b` with separator `"."`.
+    /// Objects in arrays are not flattened.
+    pub fn flatten_separator(mut self, separator: Option<&str>) -> Self {
+        self.nesting.flatten_separator = separator.map(|s| s.to_string());
+        self
+    }
+
     pub fn with_rechunk(mut self, rechunk: bool) -> Self {
         self.rechunk = rechunk;
         self
@@ -88,6 +137,110 @@ impl<'a> JsonLineReader<'a, File> {
         let f = std::fs::File::open(&path)?;
         Ok(Self::new(f).with_path(Some(path)))
     }
+
+    /// Read the file in batches of `chunk_size` lines.
+    pub fn batched(self, chunk_size: usize) -> PolarsResult<BatchedJsonLineReader> {
+        let bytes = unsafe { memmap::Mmap::map(&self.reader)? };
+        let mut schema = match self.schema {
+            Some(schema) => schema.clone(),
+            None => infer_schema(&bytes, self.infer_schema_len, &self.nesting)?,
+        };
+        if let (None, Some(overwrite)) = (self.schema, self.schema_overwrite) {
+            overwrite_schema(&mut schema, overwrite);
+        }
+        let projected_schema = self
+            .projection
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|name| Ok(Field::new(name, schema.try_get(name)?.clone())))
+                    .collect::<PolarsResult<Schema>>()
+            })
+            .transpose()?;
+        Ok(BatchedJsonLineReader {
+            bytes,
+            offset: 0,
+            chunk_size,
+            schema,
+            projected_schema,
+            n_rows: self.n_rows,
+            rows_read: 0,
+            ignore_errors: self.ignore_errors,
+            ignore_unknown_keys: self.ignore_unknown_keys,
+            nesting: self.nesting,
+        })
+    }
+}
+
+/// Reads a json lines file in batches, see [`JsonLineReader::batched`].
+pub struct BatchedJsonLineReader {
+    bytes: memmap::Mmap,
+    offset: usize,
+    chunk_size: usize,
+    schema: Schema,
+    /// The projected columns of `schema`.
+    projected_schema: Option<Schema>,
+    n_rows: Option<usize>,
+    rows_read: usize,
+    ignore_errors: bool,
+    ignore_unknown_keys: bool,
+    nesting: JsonNesting,
+}
+
+impl BatchedJsonLineReader {
+    /// The schema of the batches.
+    pub fn schema(&self) -> &Schema {
+        self.projected_schema.as_ref().unwrap_or(&self.schema)
+    }
+
+    /// Parse the next `n` batches of `chunk_size` lines in parallel.
+    pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        let bytes: &[u8] = &self.bytes;
+        let mut chunks = Vec::with_capacity(n);
+        while chunks.len() < n && self.offset < bytes.len() {
+            if matches!(self.n_rows, Some(n_rows) if self.rows_read >= n_rows) {
+                break;
+            }
+            let start = self.offset;
+            let end = match memchr::memchr_iter(NEWLINE, &bytes[start..]).nth(self.chunk_size - 1) {
+                Some(pos) => start + pos + 1,
+                None => bytes.len(),
+            };
+            chunks.push((start, end));
+            self.offset = end;
+        }
+        if chunks.is_empty() {
+            return Ok(None);
+        }
+
+        let known_keys = (!self.ignore_unknown_keys).then_some(&self.schema);
+        let schema = self.projected_schema.as_ref().unwrap_or(&self.schema);
+        let mut dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|(start, end)| {
+                    parse_ndjson(
+                        &bytes[start..end],
+                        schema,
+                        self.chunk_size,
+                        self.ignore_errors,
+                        known_keys,
+                        &self.nesting,
+                    )
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        for df in dfs.iter_mut() {
+            if let Some(n_rows) = self.n_rows {
+                let remaining = n_rows.saturating_sub(self.rows_read);
+                if df.height() > remaining {
+                    *df = df.slice(0, remaining);
+                }
+            }
+            self.rows_read += df.height();
+        }
+        Ok(Some(dfs))
+    }
 }
 impl<'a, R> SerReader<R> for JsonLineReader<'a, R>
 where
@@ -102,9 +255,14 @@ where
             n_threads: None,
             infer_schema_len: Some(128),
             schema: None,
+            schema_overwrite: None,
+            projection: None,
             path: None,
             chunk_size: 1 << 18,
             low_memory: false,
+            ignore_errors: false,
+            ignore_unknown_keys: true,
+            nesting: JsonNesting::default(),
         }
     }
     fn finish(mut self) -> PolarsResult<DataFrame> {
@@ -114,11 +272,16 @@ where
             reader_bytes,
             self.n_rows,
             self.schema,
+            self.schema_overwrite,
+            self.projection,
             self.n_threads,
             1024, // sample size
             self.chunk_size,
             self.low_memory,
             self.infer_schema_len,
+            self.ignore_errors,
+            self.ignore_unknown_keys,
+            self.nesting,
         )?;
 
         let mut df: DataFrame = json_reader.as_df()?;
@@ -133,10 +296,15 @@ pub(crate) struct CoreJsonReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
     n_rows: Option<usize>,
     schema: Cow<'a, Schema>,
+    /// The projected columns of `schema`.
+    projected_schema: Option<Schema>,
     n_threads: Option<usize>,
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    ignore_errors: bool,
+    ignore_unknown_keys: bool,
+    nesting: JsonNesting,
 }
 impl<'a> CoreJsonReader<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -144,34 +312,52 @@ impl<'a> CoreJsonReader<'a> {
         reader_bytes: ReaderBytes<'a>,
         n_rows: Option<usize>,
         schema: Option<&'a Schema>,
+        schema_overwrite: Option<&Schema>,
+        projection: Option<Vec<String>>,
         n_threads: Option<usize>,
         sample_size: usize,
         chunk_size: usize,
         low_memory: bool,
         infer_schema_len: Option<usize>,
+        ignore_errors: bool,
+        ignore_unknown_keys: bool,
+        nesting: JsonNesting,
     ) -> PolarsResult<CoreJsonReader<'a>> {
         let reader_bytes = reader_bytes;
 
         let schema = match schema {
             Some(schema) => Cow::Borrowed(schema),
             None => {
-                let bytes: &[u8] = &reader_bytes;
-                let mut cursor = Cursor::new(bytes);
-
-                let data_type = arrow_ndjson::read::infer(&mut cursor, infer_schema_len)?;
-                let schema = StructArray::get_fields(&data_type).iter().collect();
-
+                let mut schema = infer_schema(&reader_bytes, infer_schema_len, &nesting)?;
+                if let Some(overwrite) = schema_overwrite {
+                    overwrite_schema(&mut schema, overwrite);
+                }
                 Cow::Owned(schema)
             }
         };
+        let projected_schema = projection
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|name| {
+                        let dtype = schema.try_get(name)?;
+                        Ok(Field::new(name, dtype.clone()))
+                    })
+                    .collect::<PolarsResult<Schema>>()
+            })
+            .transpose()?;
         Ok(CoreJsonReader {
             reader_bytes: Some(reader_bytes),
             schema,
+            projected_schema,
             sample_size,
             n_rows,
             n_threads,
             chunk_size,
             low_memory,
+            ignore_errors,
+            ignore_unknown_keys,
+            nesting,
         })
     }
     fn parse_json(&mut self, mut n_threads: usize, bytes: &[u8]) -> PolarsResult<DataFrame> {
@@ -212,13 +398,13 @@ impl<'a> CoreJsonReader<'a> {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut buffers = init_buffers(&self.schema, capacity)?;
-                    let _ = parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers);
-                    DataFrame::new(
-                        buffers
-                            .into_values()
-                            .map(|buf| buf.into_series())
-                            .collect::<_>(),
+                    parse_ndjson(
+                        &bytes[start_pos..stop_at_nbytes],
+                        self.projected_schema.as_ref().unwrap_or(&self.schema),
+                        capacity,
+                        self.ignore_errors,
+                        (!self.ignore_unknown_keys).then_some(self.schema.as_ref()),
+                        &self.nesting,
                     )
                 })
                 .collect::<PolarsResult<Vec<_>>>()
@@ -243,10 +429,161 @@ impl<'a> CoreJsonReader<'a> {
     }
 }
 
+/// Controls how nested json objects are read.
+#[derive(Clone, Debug, Default)]
+pub struct JsonNesting {
+    /// Objects that are nested deeper than this are read as json strings.
+    pub max_depth: Option<usize>,
+    /// Flatten nested objects into columns named by joining the keys with this separator.
+    pub flatten_separator: Option<String>,
+}
+
+impl JsonNesting {
+    /// Add the columns that a key with the inferred `dtype` is read into to `columns`.
+    fn add_columns(
+        &self,
+        name: String,
+        dtype: DataType,
+        depth: Option<usize>,
+        columns: &mut Vec<(String, DataType)>,
+    ) {
+        match (dtype, &self.flatten_separator) {
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(fields), Some(separator)) if depth != Some(0) => {
+                for field in fields {
+                    let name = format!("{name}{separator}{}", field.name());
+                    self.add_columns(name, field.dtype, depth.map(|d| d - 1), columns)
+                }
+            }
+            (dtype, _) => columns.push((name, truncate_dtype(dtype, depth))),
+        }
+    }
+}
+
+/// Replace the structs that are nested deeper than `depth` with utf8.
+fn truncate_dtype(dtype: DataType, depth: Option<usize>) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) if depth == Some(0) => DataType::Utf8,
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .into_iter()
+                .map(|field| {
+                    let dtype = truncate_dtype(field.dtype, depth.map(|d| d - 1));
+                    Field::new(&field.name, dtype)
+                })
+                .collect(),
+        ),
+        DataType::List(inner) => DataType::List(Box::new(truncate_dtype(*inner, depth))),
+        dtype => dtype,
+    }
+}
+
+/// Add the values of the flattened `object` to the buffers of their columns and mark
+/// these buffers in `filled`.
+fn add_flattened(
+    object: &simd_json::borrowed::Object,
+    prefix: Option<&str>,
+    separator: &str,
+    depth: Option<usize>,
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    known_keys: Option<&Schema>,
+    filled: &mut [bool],
+) -> PolarsResult<()> {
+    for (key, value) in object.iter() {
+        let name = match prefix {
+            Some(prefix) => Cow::Owned(format!("{prefix}{separator}{key}")),
+            None => Cow::Borrowed(&**key),
+        };
+        match value {
+            simd_json::BorrowedValue::Object(inner) if depth != Some(0) => add_flattened(
+                inner,
+                Some(&name),
+                separator,
+                depth.map(|d| d - 1),
+                buffers,
+                known_keys,
+                filled,
+            )?,
+            _ => match buffers.get_full_mut(name.as_ref()) {
+                // the first value wins if a key is duplicated
+                Some((i, _, buf)) if !filled[i] => {
+                    buf.add(value)?;
+                    filled[i] = true;
+                }
+                Some(_) => {}
+                None => {
+                    if let Some(known_keys) = known_keys {
+                        polars_ensure!(
+                            known_keys.contains(&name),
+                            ComputeError: "json line has key '{}' that is not in the schema; \
+                            set `ignore_unknown_keys` to ignore it", name
+                        );
+                    }
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Add the values of a json object to the buffers.
+pub(crate) fn parse_value(
+    value: &simd_json::BorrowedValue,
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    known_keys: Option<&Schema>,
+    nesting: &JsonNesting,
+) -> PolarsResult<()> {
+    match value {
+        simd_json::BorrowedValue::Object(value) => match &nesting.flatten_separator {
+            Some(separator) => {
+                let mut filled = vec![false; buffers.len()];
+                add_flattened(
+                    value,
+                    None,
+                    separator,
+                    nesting.max_depth,
+                    buffers,
+                    known_keys,
+                    &mut filled,
+                )?;
+                for (buf, filled) in buffers.values_mut().zip(filled) {
+                    if !filled {
+                        buf.add_null()
+                    }
+                }
+            }
+            None => {
+                if let Some(known_keys) = known_keys {
+                    if let Some(key) = value.keys().find(|key| !known_keys.contains(key)) {
+                        polars_bail!(
+                            ComputeError: "json line has key '{}' that is not in the schema; \
+                            set `ignore_unknown_keys` to ignore it", key
+                        );
+                    }
+                }
+                for (s, inner) in buffers.iter_mut() {
+                    match s.0.map_lookup(value) {
+                        Some(v) => inner.add(v)?,
+                        None => inner.add_null(),
+                    }
+                }
+            }
+        },
+        _ => {
+            buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
+        }
+    };
+    Ok(())
+}
+
 #[inline(always)]
 fn parse_impl(
     bytes: &[u8],
     buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    known_keys: Option<&Schema>,
+    nesting: &JsonNesting,
     line: &mut Vec<u8>,
 ) -> PolarsResult<usize> {
     line.clear();
@@ -259,19 +596,7 @@ fn parse_impl(
         _ => {
             let value: simd_json::BorrowedValue = simd_json::to_borrowed_value(line)
                 .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
-            match value {
-                simd_json::BorrowedValue::Object(value) => {
-                    buffers
-                        .iter_mut()
-                        .for_each(|(s, inner)| match s.0.map_lookup(&value) {
-                            Some(v) => inner.add(v).expect("inner.add(v)"),
-                            None => inner.add_null(),
-                        });
-                }
-                _ => {
-                    buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
-                }
-            };
+            parse_value(&value, buffers, known_keys, nesting)?;
             true
         }
     };
@@ -279,27 +604,208 @@ fn parse_impl(
     Ok(n)
 }
 
-fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> PolarsResult<()> {
+fn parse_lines(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    known_keys: Option<&Schema>,
+    nesting: &JsonNesting,
+) -> PolarsResult<()> {
     let mut buf = vec![];
 
-    let total_bytes = bytes.len();
-    let mut offset = 0;
     // The `RawValue` is a pointer to the original JSON string and does not perform any deserialization.
     // It is used to properly iterate over the lines without re-implementing the splitlines logic when this does the same thing.
-    let mut iter =
+    let iter =
         serde_json::Deserializer::from_slice(bytes).into_iter::<Box<serde_json::value::RawValue>>();
-    while let Some(Ok(value)) = iter.next() {
+    for value in iter {
+        let value = value.map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
         let bytes = value.get().as_bytes();
-        offset += bytes.len();
-        parse_impl(bytes, buffers, &mut buf)?;
+        parse_impl(bytes, buffers, known_keys, nesting, &mut buf)?;
+    }
+    Ok(())
+}
+
+/// Parse the json lines in `bytes` to a [`DataFrame`] with the columns of `schema`.
+/// If `known_keys` is set, keys that are not in it raise an error.
+pub(crate) fn parse_ndjson(
+    bytes: &[u8],
+    schema: &Schema,
+    capacity: usize,
+    ignore_errors: bool,
+    known_keys: Option<&Schema>,
+    nesting: &JsonNesting,
+) -> PolarsResult<DataFrame> {
+    let mut buffers = init_buffers(schema, capacity, ignore_errors)?;
+    parse_lines(bytes, &mut buffers, known_keys, nesting)?;
+    DataFrame::new(
+        buffers
+            .into_values()
+            .map(|buf| buf.into_series())
+            .collect::<PolarsResult<_>>()?,
+    )
+}
+
+/// Parse a json array of objects, or a single json object, to a [`DataFrame`] with a row
+/// per object.
+pub(crate) fn parse_json_objects(
+    bytes: &[u8],
+    infer_schema_len: Option<usize>,
+    nesting: &JsonNesting,
+) -> PolarsResult<DataFrame> {
+    let mut bytes = bytes.to_vec();
+    let value = simd_json::to_borrowed_value(&mut bytes)
+        .map_err(|e| polars_err!(ComputeError: "error parsing json: {}", e))?;
+    let values = match &value {
+        simd_json::BorrowedValue::Array(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+    let mut schema = Schema::new();
+    for value in values.iter().take(infer_schema_len.unwrap_or(usize::MAX)) {
+        if let simd_json::BorrowedValue::Object(object) = value {
+            let columns = object
+                .iter()
+                .map(|(key, value)| Ok((key.to_string(), value_dtype(value)?)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            add_schema_columns(&mut schema, columns, nesting);
+        }
+    }
+
+    let mut buffers = init_buffers(&schema, values.len(), false)?;
+    for value in values {
+        parse_value(value, &mut buffers, None, nesting)?;
+    }
+    DataFrame::new(
+        buffers
+            .into_values()
+            .map(|buf| buf.into_series())
+            .collect::<PolarsResult<_>>()?,
+    )
+}
+
+/// Overwrite the dtypes of the columns of `schema` that are in `overwrite`.
+/// The columns of `overwrite` that are not in `schema` are added.
+pub fn overwrite_schema(schema: &mut Schema, overwrite: &Schema) {
+    for (name, dtype) in overwrite.iter() {
+        schema.with_column(name.clone(), dtype.clone());
+    }
+}
+
+/// The dtype that can hold the values of both dtypes: integers and floats become floats,
+/// nulls take the other dtype, and other mixed dtypes become utf8.
+fn unify_dtypes(left: &DataType, right: &DataType) -> DataType {
+    use DataType::*;
+    match (left, right) {
+        (l, r) if l == r => l.clone(),
+        (Null, dtype) | (dtype, Null) => dtype.clone(),
+        (Int64 | UInt64 | Float64, Int64 | UInt64 | Float64) => Float64,
+        (List(l), List(r)) => List(Box::new(unify_dtypes(l, r))),
+        #[cfg(feature = "dtype-struct")]
+        (Struct(l), Struct(r)) => {
+            let mut fields = l.clone();
+            for field in r {
+                match fields.iter_mut().find(|f| f.name() == field.name()) {
+                    Some(f) => {
+                        let dtype = unify_dtypes(f.data_type(), field.data_type());
+                        f.coerce(dtype)
+                    }
+                    None => fields.push(field.clone()),
+                }
+            }
+            Struct(fields)
+        }
+        _ => Utf8,
+    }
+}
+
+/// Add the columns of a json object to `schema`, unifying the dtypes of the columns that
+/// are already in it, see [`unify_dtypes`].
+pub(crate) fn update_schema(
+    schema: &mut Schema,
+    value: &json_deserializer::Value,
+    nesting: &JsonNesting,
+) -> PolarsResult<()> {
+    if let ArrowDataType::Struct(fields) = json::read::infer(value)? {
+        let columns = fields.into_iter().map(|field| {
+            let dtype = DataType::from(field.data_type());
+            (field.name, dtype)
+        });
+        add_schema_columns(schema, columns, nesting);
     }
-    polars_ensure!(
-        offset == total_bytes,
-        ComputeError: "expected {} bytes, but only parsed {}", total_bytes, offset,
-    );
     Ok(())
 }
 
+/// Add the (flattened) columns to `schema`, unifying the dtypes of the columns that are
+/// already in it.
+fn add_schema_columns(
+    schema: &mut Schema,
+    columns: impl IntoIterator<Item = (String, DataType)>,
+    nesting: &JsonNesting,
+) {
+    let mut flattened = vec![];
+    for (name, dtype) in columns {
+        nesting.add_columns(name, dtype, nesting.max_depth, &mut flattened);
+    }
+    for (name, dtype) in flattened {
+        match schema.get(&name) {
+            Some(current) => {
+                let dtype = unify_dtypes(current, &dtype);
+                schema.set_dtype(&name, dtype);
+            }
+            None => {
+                schema.with_column(name.into(), dtype);
+            }
+        }
+    }
+}
+
+/// The dtype of a parsed json value, the same as the dtype that arrow infers for it.
+fn value_dtype(value: &simd_json::BorrowedValue) -> PolarsResult<DataType> {
+    use simd_json::{BorrowedValue, StaticNode};
+    let dtype = match value {
+        BorrowedValue::Static(StaticNode::Null) => DataType::Null,
+        BorrowedValue::Static(StaticNode::Bool(_)) => DataType::Boolean,
+        BorrowedValue::Static(StaticNode::I64(_) | StaticNode::U64(_)) => DataType::Int64,
+        BorrowedValue::Static(StaticNode::F64(_)) => DataType::Float64,
+        BorrowedValue::String(_) => DataType::Utf8,
+        BorrowedValue::Array(values) => {
+            let mut inner = DataType::Null;
+            for value in values.iter() {
+                inner = unify_dtypes(&inner, &value_dtype(value)?);
+            }
+            DataType::List(Box::new(inner))
+        }
+        #[cfg(feature = "dtype-struct")]
+        BorrowedValue::Object(object) => DataType::Struct(
+            object
+                .iter()
+                .map(|(key, value)| Ok(Field::new(key, value_dtype(value)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        #[cfg(not(feature = "dtype-struct"))]
+        BorrowedValue::Object(_) => polars_bail!(
+            ComputeError: "reading nested json objects requires the 'dtype-struct' feature"
+        ),
+    };
+    Ok(dtype)
+}
+
+/// Infer the schema of the first `infer_schema_len` json lines in `bytes`. The dtypes of a
+/// key are unified over all these lines, see [`unify_dtypes`].
+pub fn infer_schema(
+    bytes: &[u8],
+    infer_schema_len: Option<usize>,
+    nesting: &JsonNesting,
+) -> PolarsResult<Schema> {
+    let mut schema = Schema::new();
+    let iter =
+        serde_json::Deserializer::from_slice(bytes).into_iter::<Box<serde_json::value::RawValue>>();
+    for value in iter.take(infer_schema_len.unwrap_or(usize::MAX)) {
+        let value = value.map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+        let value = json_deserializer::parse(value.get().as_bytes()).map_err(to_compute_err)?;
+        update_schema(&mut schema, &value, nesting)?;
+    }
+    Ok(schema)
+}
+
 /// Find the nearest next line position.
 /// Does not check for new line characters embedded in String fields.
 /// This just looks for `}\n`
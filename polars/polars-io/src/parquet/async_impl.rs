@@ -21,7 +21,9 @@ use polars_core::schema::Schema;
 use super::cloud::{build, CloudLocation, CloudReader};
 use super::mmap;
 use super::mmap::ColumnStore;
+use super::predicates::read_this_row_group;
 use super::read_impl::FetchRowGroups;
+use crate::predicates::PhysicalIoExpr;
 
 pub struct ParquetObjectStore {
     store: Arc<Mutex<Box<dyn ObjectStore>>>,
@@ -144,6 +146,8 @@ pub(crate) struct FetchRowGroupsFromObjectStore {
     projection: Vec<usize>,
     logging: bool,
     schema: ArrowSchema,
+    /// The row groups that may contain rows that pass the predicate.
+    row_groups_to_read: Vec<bool>,
 }
 
 impl FetchRowGroupsFromObjectStore {
@@ -151,10 +155,22 @@ impl FetchRowGroupsFromObjectStore {
         reader: ParquetObjectStore,
         metadata: &FileMetaData,
         projection: &Option<Vec<usize>>,
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+        use_statistics: bool,
     ) -> PolarsResult<Self> {
         let schema = parquet2_read::schema::infer_schema(metadata)?;
         let logging = verbose();
 
+        let row_groups_to_read = (0..metadata.row_groups.len())
+            .map(|rg| {
+                if use_statistics {
+                    read_this_row_group(predicate, metadata, &schema, rg)
+                } else {
+                    Ok(true)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
         let projection = projection
             .to_owned()
             .unwrap_or_else(|| (0usize..schema.fields.len()).collect::<Vec<_>>());
@@ -165,22 +181,22 @@ impl FetchRowGroupsFromObjectStore {
             projection,
             logging,
             schema,
+            row_groups_to_read,
         })
     }
 }
 
 impl FetchRowGroups for FetchRowGroupsFromObjectStore {
     fn fetch_row_groups(&mut self, row_groups: Range<usize>) -> PolarsResult<ColumnStore> {
-        // Fetch the required row groups.
-        let row_groups = &self
-            .row_groups_metadata
-            .get(row_groups.clone())
-            .map_or_else(
-                || Err(polars_err!(
-                    ComputeError: "cannot access slice {0}..{1}", row_groups.start, row_groups.end,
-                )),
-                Ok,
-            )?;
+        polars_ensure!(
+            row_groups.end <= self.row_groups_metadata.len(),
+            ComputeError: "cannot access slice {0}..{1}", row_groups.start, row_groups.end,
+        );
+        // Fetch the required row groups, skipping the ones that the predicate rules out.
+        let row_groups = &row_groups
+            .filter(|rg| self.row_groups_to_read[*rg])
+            .map(|rg| self.row_groups_metadata[rg].clone())
+            .collect::<Vec<_>>();
 
         // Package in the format required by ColumnStore.
         let downloaded =
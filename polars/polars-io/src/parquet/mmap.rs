@@ -1,7 +1,10 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
 use arrow::datatypes::Field;
 use arrow::io::parquet::read::{
     column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
-    PageReader,
+    ColumnDescriptor, DataPageHeader, PageFilter, PageReader,
 };
 #[cfg(feature = "async")]
 use polars_core::datatypes::PlHashMap;
@@ -87,3 +90,61 @@ pub(super) fn to_deserializer<'a>(
 
     column_iter_to_arrays(columns, types, field, Some(chunk_size), num_rows)
 }
+
+/// The number of values in each data page of a column chunk. For a flat column this is the
+/// number of rows. Only the page headers are read.
+pub(super) fn data_page_num_values(
+    column_meta: &ColumnChunkMetaData,
+    chunk: &[u8],
+) -> ArrowResult<Vec<usize>> {
+    let num_values = Arc::new(Mutex::new(vec![]));
+    let pages_filter: PageFilter = {
+        let num_values = num_values.clone();
+        Arc::new(move |_: &ColumnDescriptor, header: &DataPageHeader| {
+            num_values.lock().unwrap().push(header.num_values());
+            false
+        })
+    };
+    let pages = PageReader::new(
+        std::io::Cursor::new(chunk),
+        column_meta,
+        pages_filter,
+        vec![],
+        usize::MAX,
+    );
+    // all data pages are skipped, only a dictionary page is read
+    for page in pages {
+        page?;
+    }
+    let num_values = std::mem::take(&mut *num_values.lock().unwrap());
+    Ok(num_values)
+}
+
+/// Like [`to_deserializer`] for a single column chunk, but only the data pages for which `keep`
+/// is `true` are read. `num_rows` must be the number of rows in those pages.
+pub(super) fn to_filtered_deserializer<'a>(
+    column_meta: &ColumnChunkMetaData,
+    chunk: &'a [u8],
+    field: Field,
+    num_rows: usize,
+    keep: Vec<bool>,
+) -> ArrowResult<ArrayIter<'a>> {
+    let page_idx = AtomicUsize::new(0);
+    let pages_filter: PageFilter = Arc::new(move |_: &ColumnDescriptor, _: &DataPageHeader| {
+        keep[page_idx.fetch_add(1, Ordering::Relaxed)]
+    });
+    let pages = PageReader::new(
+        std::io::Cursor::new(chunk),
+        column_meta,
+        pages_filter,
+        vec![],
+        usize::MAX,
+    );
+    column_iter_to_arrays(
+        vec![BasicDecompressor::new(pages, vec![])],
+        vec![&column_meta.descriptor().descriptor.primitive_type],
+        field,
+        Some(num_rows),
+        num_rows,
+    )
+}
@@ -109,4 +109,76 @@ mod test {
         assert_eq!(df_read.shape(), (3, 2));
         df_read.frame_equal(&expected);
     }
+
+    #[test]
+    fn test_parquet_column_options() -> PolarsResult<()> {
+        use arrow::io::parquet::read::read_metadata;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("id" => ["a", "b", "c"], "value" => [1, 2, 3])?;
+
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_compression(ParquetCompression::Snappy)
+            .with_column_options(
+                "id",
+                ParquetColumnOptions::default()
+                    .with_statistics(false)
+                    .with_dictionary(true),
+            )
+            .with_column_options(
+                "value",
+                ParquetColumnOptions::default().with_compression(ParquetCompression::Uncompressed),
+            )
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let metadata = read_metadata(&mut buf)?;
+        let columns = metadata.row_groups[0].columns();
+        assert!(columns[0].statistics().is_none());
+        assert!(columns[0].dictionary_page_offset().is_some());
+        assert_eq!(format!("{:?}", columns[0].compression()), "Snappy");
+        assert!(columns[1].statistics().is_some());
+        assert!(columns[1].dictionary_page_offset().is_none());
+        assert_eq!(format!("{:?}", columns[1].compression()), "Uncompressed");
+
+        // the dictionary encoding doesn't change the dtype
+        buf.set_position(0);
+        let read = ParquetReader::new(buf).finish()?;
+        assert!(read.frame_equal(&df));
+
+        let res = ParquetWriter::new(Cursor::new(Vec::new()))
+            .with_column_options("unknown", Default::default())
+            .finish(&mut df);
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "cloud")]
+    fn test_read_parquet_from_object_store() -> PolarsResult<()> {
+        use tempdir::TempDir;
+
+        let tempdir = TempDir::new("parquet-object-store")?;
+        let path = tempdir.path().join("data.parquet");
+        let mut df = df!("a" => [1, 2, 3, 4, 5], "b" => ["a", "b", "c", "d", "e"])?;
+        ParquetWriter::new(File::create(&path)?)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let uri = format!("file://{}", path.display());
+        let (schema, num_rows) = ParquetAsyncReader::file_info(&uri, None)?;
+        assert_eq!(schema, df.schema());
+        assert_eq!(num_rows, 5);
+
+        let read = ParquetAsyncReader::from_uri(&uri, None)?.finish()?;
+        assert!(read.frame_equal(&df));
+
+        let read = ParquetAsyncReader::from_uri(&uri, None)?
+            .with_projection(Some(vec![1]))
+            .with_n_rows(Some(3))
+            .finish()?;
+        assert!(read.frame_equal(&df.select(["b"])?.head(Some(3))));
+        Ok(())
+    }
 }
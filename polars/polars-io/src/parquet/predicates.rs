@@ -1,3 +1,6 @@
+use arrow::array::UInt64Array;
+#[cfg(feature = "dtype-struct")]
+use arrow::array::{Array, StructArray};
 use arrow::compute::concatenate::concatenate;
 use arrow::io::parquet::read::statistics::{deserialize, Statistics};
 use arrow::io::parquet::read::RowGroupMetaData;
@@ -15,6 +18,23 @@ use crate::ArrowResult;
 pub struct ColumnStats(Statistics, Field);
 
 impl ColumnStats {
+    /// Statistics of a column in which every value is `value`.
+    ///
+    /// # Panics
+    /// If `value` doesn't have length 1.
+    pub fn from_column_literal(value: Series) -> Self {
+        assert_eq!(value.len(), 1);
+        let null_count = value.null_count() as u64;
+        let arr = value.to_arrow(0);
+        let st = Statistics {
+            null_count: Box::new(UInt64Array::from_slice([null_count])),
+            distinct_count: Box::new(UInt64Array::from_slice([1])),
+            min_value: arr.clone(),
+            max_value: arr,
+        };
+        ColumnStats(st, value.field().into_owned())
+    }
+
     pub fn dtype(&self) -> DataType {
         self.1.data_type().clone()
     }
@@ -84,6 +104,63 @@ impl ColumnStats {
         }
     }
 
+    /// Whether the max statistic may be a truncated prefix of the real max for any of the `values`.
+    ///
+    /// Writers may truncate the statistics of long strings. A truncated min is still a lower bound,
+    /// but a max that is truncated without incrementing its last byte is a prefix of the real max.
+    /// Values that start with such a max can thus still occur in the column, even though they
+    /// compare greater than the max statistic.
+    pub fn max_is_prefix_of_any(&self, values: &Series) -> bool {
+        // the array holds the max of every row group
+        let max = match Series::try_from(("", self.0.max_value.clone())) {
+            Ok(max) => max,
+            Err(_) => return false,
+        };
+        match (max.dtype(), values.dtype()) {
+            (DataType::Utf8, DataType::Utf8) => {
+                let values = values.utf8().unwrap();
+                max.utf8().unwrap().into_iter().flatten().any(|max| {
+                    values
+                        .into_iter()
+                        .any(|v| v.map(|v| v.starts_with(max)).unwrap_or(false))
+                })
+            }
+            #[cfg(feature = "dtype-binary")]
+            (DataType::Binary, DataType::Binary) => {
+                let values = values.binary().unwrap();
+                max.binary().unwrap().into_iter().flatten().any(|max| {
+                    values
+                        .into_iter()
+                        .any(|v| v.map(|v| v.starts_with(max)).unwrap_or(false))
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// The statistics of the field `name` of a struct column.
+    #[cfg(feature = "dtype-struct")]
+    pub fn field(&self, name: &str) -> Option<ColumnStats> {
+        let fields = match self.1.data_type() {
+            DataType::Struct(fields) => fields,
+            _ => return None,
+        };
+        let idx = fields.iter().position(|fld| fld.name().as_str() == name)?;
+        // the statistics of a struct are struct arrays that hold the statistics of the fields
+        let child = |arr: &Box<dyn Array>| {
+            arr.as_any()
+                .downcast_ref::<StructArray>()
+                .map(|arr| arr.values()[idx].clone())
+        };
+        let st = Statistics {
+            null_count: child(&self.0.null_count)?,
+            distinct_count: child(&self.0.distinct_count)?,
+            min_value: child(&self.0.min_value)?,
+            max_value: child(&self.0.max_value)?,
+        };
+        Some(ColumnStats(st, fields[idx].clone()))
+    }
+
     #[cfg(feature = "dtype-binary")]
     fn use_min_max(dtype: DataType) -> bool {
         dtype.is_numeric() || matches!(dtype, DataType::Utf8) || matches!(dtype, DataType::Binary)
@@ -99,16 +176,42 @@ impl ColumnStats {
 pub struct BatchStats {
     schema: Schema,
     stats: Vec<ColumnStats>,
+    num_rows: usize,
 }
 
 impl BatchStats {
+    pub fn new(schema: Schema, stats: Vec<ColumnStats>, num_rows: usize) -> Self {
+        debug_assert_eq!(schema.len(), stats.len());
+        Self {
+            schema,
+            stats,
+            num_rows,
+        }
+    }
+
     pub fn get_stats(&self, column: &str) -> polars_core::error::PolarsResult<&ColumnStats> {
         self.schema.try_index_of(column).map(|i| &self.stats[i])
     }
 
+    /// The statistics of a (nested) field of a struct column.
+    #[cfg(feature = "dtype-struct")]
+    pub fn get_field_stats(&self, column: &str, path: &[&str]) -> Option<ColumnStats> {
+        let (first, rest) = path.split_first()?;
+        let mut st = self.get_stats(column).ok()?.field(first)?;
+        for name in rest {
+            st = st.field(name)?;
+        }
+        Some(st)
+    }
+
     pub fn schema(&self) -> &Schema {
         &self.schema
     }
+
+    /// The number of rows the statistics were collected over.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
 }
 
 /// Collect the statistics in a column chunk.
@@ -131,13 +234,24 @@ pub(crate) fn collect_statistics(
         stats.push(ColumnStats(st, fld.into()));
     }
 
+    let num_rows = match rg {
+        None => md.iter().map(|md| md.num_rows()).sum(),
+        Some(rg) => md[rg].num_rows(),
+    };
+
     Ok(if stats.is_empty() {
         None
     } else {
-        Some(BatchStats { schema, stats })
+        Some(BatchStats {
+            schema,
+            stats,
+            num_rows,
+        })
     })
 }
 
+/// Whether the statistics of row group `rg` may pass the predicate. The `schema` must have all
+/// the fields of the struct columns in the file, as the statistics are collected for all leaves.
 pub(super) fn read_this_row_group(
     predicate: Option<&Arc<dyn PhysicalIoExpr>>,
     file_metadata: &arrow::io::parquet::read::FileMetaData,
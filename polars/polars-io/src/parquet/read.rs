@@ -6,6 +6,10 @@ use arrow::io::parquet::write::FileMetaData;
 #[cfg(feature = "cloud")]
 use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+#[cfg(feature = "cloud")]
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+#[cfg(feature = "cloud")]
+use polars_core::POOL;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -15,8 +19,10 @@ use crate::mmap::MmapBytesReader;
 use crate::parquet::async_impl::FetchRowGroupsFromObjectStore;
 #[cfg(feature = "cloud")]
 use crate::parquet::async_impl::ParquetObjectStore;
+#[cfg(feature = "dtype-struct")]
+use crate::parquet::read_impl::prune_struct_fields;
 use crate::parquet::read_impl::read_parquet;
-pub use crate::parquet::read_impl::BatchedParquetReader;
+pub use crate::parquet::read_impl::{BatchedParquetReader, ParquetReadStats, PrefilterStats};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::RowCount;
@@ -34,6 +40,11 @@ pub enum ParallelStrategy {
     /// This will choose the most occurring unit.
     #[default]
     Auto,
+    /// Decode the columns of the predicate first and only decode the pages of the other columns
+    /// that contain rows that pass the predicate. The columns are decoded in parallel.
+    ///
+    /// Without a predicate this is the same as [`ParallelStrategy::Columns`].
+    Prefiltered,
 }
 
 /// Read Apache parquet format into a DataFrame.
@@ -49,6 +60,8 @@ pub struct ParquetReader<R: Read + Seek> {
     low_memory: bool,
     metadata: Option<FileMetaData>,
     use_statistics: bool,
+    struct_projection: Option<SchemaRef>,
+    stats: Arc<ParquetReadStats>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -61,7 +74,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
     ) -> PolarsResult<DataFrame> {
         // this path takes predicates and parallelism into account
         let metadata = read::read_metadata(&mut self.reader)?;
-        let schema = read::schema::infer_schema(&metadata)?;
+        #[allow(unused_mut)]
+        let mut schema = read::schema::infer_schema(&metadata)?;
+        #[cfg(feature = "dtype-struct")]
+        if let Some(struct_projection) = &self.struct_projection {
+            prune_struct_fields(&mut schema, struct_projection);
+        }
 
         let rechunk = self.rechunk;
         read_parquet(
@@ -74,6 +92,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.use_statistics,
+            &self.stats,
         )
         .map(|mut df| {
             if rechunk {
@@ -122,6 +141,25 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Only read the fields of the struct columns that are in the struct dtypes of this
+    /// [`Schema`]. Columns that are not in the schema are read completely.
+    pub fn with_struct_projection(mut self, struct_projection: Option<SchemaRef>) -> Self {
+        self.struct_projection = struct_projection;
+        self
+    }
+
+    /// Count the decoded row groups and the prefiltered rows in these [`ParquetReadStats`].
+    /// They can be shared with other readers.
+    pub fn with_read_stats(mut self, stats: Arc<ParquetReadStats>) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// The counters of this reader.
+    pub fn read_stats(&self) -> &Arc<ParquetReadStats> {
+        &self.stats
+    }
+
     /// [`Schema`] of the file.
     pub fn schema(&mut self) -> PolarsResult<Schema> {
         let metadata = self.get_metadata()?;
@@ -154,7 +192,8 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
         let metadata = read::read_metadata(&mut self.reader)?;
 
         let row_group_fetcher = Box::new(FetchRowGroupsFromMmapReader::new(Box::new(self.reader))?);
-        BatchedParquetReader::new(
+        #[allow(unused_mut)]
+        let mut reader = BatchedParquetReader::new(
             row_group_fetcher,
             metadata,
             self.n_rows.unwrap_or(usize::MAX),
@@ -162,7 +201,14 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
             self.row_count,
             chunk_size,
             self.use_statistics,
-        )
+            None,
+            self.stats,
+        )?;
+        #[cfg(feature = "dtype-struct")]
+        if let Some(struct_projection) = &self.struct_projection {
+            reader.prune_struct_fields(struct_projection);
+        }
+        Ok(reader)
     }
 }
 
@@ -180,6 +226,8 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             low_memory: false,
             metadata: None,
             use_statistics: true,
+            struct_projection: None,
+            stats: Default::default(),
         }
     }
 
@@ -190,11 +238,16 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
         let metadata = read::read_metadata(&mut self.reader)?;
-        let schema = read::schema::infer_schema(&metadata)?;
+        #[allow(unused_mut)]
+        let mut schema = read::schema::infer_schema(&metadata)?;
 
         if let Some(cols) = &self.columns {
             self.projection = Some(columns_to_projection(cols, &schema)?);
         }
+        #[cfg(feature = "dtype-struct")]
+        if let Some(struct_projection) = &self.struct_projection {
+            prune_struct_fields(&mut schema, struct_projection);
+        }
 
         read_parquet(
             self.reader,
@@ -206,6 +259,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.use_statistics,
+            &self.stats,
         )
         .map(|mut df| {
             if self.rechunk {
@@ -216,8 +270,8 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
     }
 }
 
-/// A Parquet reader on top of the async object_store API. Only the batch reader is implemented since
-/// parquet files on cloud storage tend to be big and slow to access.
+/// A Parquet reader on top of the async object_store API. Only the row groups that pass the
+/// predicate and the projected columns are downloaded.
 #[cfg(feature = "cloud")]
 pub struct ParquetAsyncReader {
     reader: ParquetObjectStore,
@@ -227,6 +281,9 @@ pub struct ParquetAsyncReader {
     row_count: Option<RowCount>,
     low_memory: bool,
     use_statistics: bool,
+    struct_projection: Option<SchemaRef>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    stats: Arc<ParquetReadStats>,
 }
 
 #[cfg(feature = "cloud")]
@@ -243,6 +300,9 @@ impl ParquetAsyncReader {
             row_count: None,
             low_memory: false,
             use_statistics: true,
+            struct_projection: None,
+            predicate: None,
+            stats: Default::default(),
         })
     }
 
@@ -297,6 +357,49 @@ impl ParquetAsyncReader {
         self
     }
 
+    pub fn with_struct_projection(mut self, struct_projection: Option<SchemaRef>) -> Self {
+        self.struct_projection = struct_projection;
+        self
+    }
+
+    /// Count the decoded row groups and the prefiltered rows in these [`ParquetReadStats`].
+    /// They can be shared with other readers.
+    pub fn with_read_stats(mut self, stats: Arc<ParquetReadStats>) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// The counters of this reader.
+    pub fn read_stats(&self) -> &Arc<ParquetReadStats> {
+        &self.stats
+    }
+
+    /// Filter the rows with this predicate. If statistics are used, the row groups that
+    /// cannot match are not downloaded.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// Read the file into a [`DataFrame`], blocking until all row groups are downloaded.
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        let rechunk = self.rechunk;
+        // a single chunk per row group
+        let mut reader = self.batched(usize::MAX)?;
+        let mut dfs = vec![];
+        while let Some(batch) = reader.next_batches(POOL.current_num_threads())? {
+            dfs.extend(batch);
+        }
+        if dfs.is_empty() {
+            return Ok(reader.empty_df());
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+        if rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+
     #[tokio::main(flavor = "current_thread")]
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.to_owned();
@@ -304,8 +407,11 @@ impl ParquetAsyncReader {
             self.reader,
             &metadata,
             &self.projection,
+            self.predicate.as_ref(),
+            self.use_statistics,
         )?);
-        BatchedParquetReader::new(
+        #[allow(unused_mut)]
+        let mut reader = BatchedParquetReader::new(
             row_group_fetcher,
             metadata,
             self.n_rows.unwrap_or(usize::MAX),
@@ -313,6 +419,13 @@ impl ParquetAsyncReader {
             self.row_count,
             chunk_size,
             self.use_statistics,
-        )
+            self.predicate,
+            self.stats,
+        )?;
+        #[cfg(feature = "dtype-struct")]
+        if let Some(struct_projection) = &self.struct_projection {
+            reader.prune_struct_fields(struct_projection);
+        }
+        Ok(reader)
     }
 }
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::convert::TryFrom;
 use std::ops::{Deref, Range};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use arrow::array::new_empty_array;
@@ -19,17 +20,69 @@ use crate::parquet::predicates::read_this_row_group;
 use crate::parquet::{mmap, ParallelStrategy};
 use crate::predicates::{apply_predicate, arrow_schema_to_empty_df, PhysicalIoExpr};
 use crate::prelude::utils::get_reader_bytes;
-use crate::utils::apply_projection;
+use crate::utils::{apply_projection, next_row_count};
 use crate::RowCount;
 
-fn column_idx_to_series(
-    column_i: usize,
-    md: &RowGroupMetaData,
-    remaining_rows: usize,
-    schema: &ArrowSchema,
-    store: &mmap::ColumnStore,
-    chunk_size: usize,
-) -> PolarsResult<Series> {
+/// Counters of the [`ParallelStrategy::Prefiltered`] reads.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PrefilterStats {
+    /// Rows that didn't pass the predicate, their other columns were not materialized.
+    pub rows_skipped: usize,
+    /// Data pages of the columns outside of the predicate that were not decoded.
+    pub pages_skipped: usize,
+}
+
+/// Counters of a parquet reader. They are shared by all threads that decode the file, and
+/// may be shared by multiple readers.
+#[derive(Debug, Default)]
+pub struct ParquetReadStats {
+    row_groups_decoded: AtomicUsize,
+    rows_skipped: AtomicUsize,
+    pages_skipped: AtomicUsize,
+}
+
+impl ParquetReadStats {
+    /// The number of row groups that were decoded. Row groups that are skipped because of
+    /// their statistics are not decoded.
+    pub fn row_groups_decoded(&self) -> usize {
+        self.row_groups_decoded.load(Ordering::Relaxed)
+    }
+
+    /// The counters of the [`ParallelStrategy::Prefiltered`] reads.
+    pub fn prefilter_stats(&self) -> PrefilterStats {
+        PrefilterStats {
+            rows_skipped: self.rows_skipped.load(Ordering::Relaxed),
+            pages_skipped: self.pages_skipped.load(Ordering::Relaxed),
+        }
+    }
+
+    fn add_row_group_decoded(&self) {
+        // test we don't read the parquet file if this env var is set
+        #[cfg(debug_assertions)]
+        {
+            assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err());
+        }
+        self.row_groups_decoded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn add_prefiltered(&self, rows_skipped: usize, pages_skipped: usize) {
+        self.rows_skipped.fetch_add(rows_skipped, Ordering::Relaxed);
+        self.pages_skipped
+            .fetch_add(pages_skipped, Ordering::Relaxed);
+    }
+}
+
+/// Counters are only equal to themselves, as their values change while they are shared.
+impl PartialEq for ParquetReadStats {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for ParquetReadStats {}
+
+/// The field of column `column_i` with the data type that is deserialized.
+fn deserialize_field(schema: &ArrowSchema, column_i: usize) -> ArrowField {
     let mut field = schema.fields[column_i].clone();
 
     match field.data_type {
@@ -42,8 +95,26 @@ fn column_idx_to_series(
         ArrowDataType::List(fld) => field.data_type = ArrowDataType::LargeList(fld),
         _ => {}
     }
+    field
+}
 
-    let columns = mmap_columns(store, md.columns(), &field.name);
+fn column_idx_to_series(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    remaining_rows: usize,
+    schema: &ArrowSchema,
+    store: &mmap::ColumnStore,
+    chunk_size: usize,
+) -> PolarsResult<Series> {
+    let field = deserialize_field(schema, column_i);
+
+    let mut columns = mmap_columns(store, md.columns(), &field.name);
+    // the fields of a struct that are not projected are not read
+    if let ArrowDataType::Struct(_) = field.data_type {
+        columns.retain(|(meta, _)| {
+            is_projected_leaf(&field.data_type, &meta.descriptor().path_in_schema[1..])
+        });
+    }
     let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
 
     if remaining_rows < md.num_rows() {
@@ -53,6 +124,199 @@ fn column_idx_to_series(
     }
 }
 
+/// Read column `column_i` of the row group and filter it with `mask`. For flat columns, the data
+/// pages without any rows that pass the filter are not decoded. Returns the number of skipped pages.
+fn filtered_column_idx_to_series(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    store: &mmap::ColumnStore,
+    mask: &BooleanChunked,
+) -> PolarsResult<(Series, usize)> {
+    let field = deserialize_field(schema, column_i);
+    let columns = mmap_columns(store, md.columns(), &field.name);
+
+    // only the pages of flat columns hold a value per row
+    let is_nested = matches!(
+        field.data_type,
+        ArrowDataType::LargeList(_)
+            | ArrowDataType::FixedSizeList(_, _)
+            | ArrowDataType::Struct(_)
+            | ArrowDataType::Map(_, _)
+    );
+    let page_num_values = match columns.as_slice() {
+        [(column_meta, chunk)] if !is_nested => mmap::data_page_num_values(column_meta, chunk)?,
+        _ => vec![],
+    };
+    if page_num_values.iter().sum::<usize>() != md.num_rows() {
+        let num_rows = md.num_rows();
+        let s = column_idx_to_series(column_i, md, num_rows, schema, store, num_rows)?;
+        return Ok((s.filter(mask)?, 0));
+    }
+    let (column_meta, chunk) = columns[0];
+
+    let mut offset = 0;
+    let mut keep = vec![];
+    let mut page_masks: Option<BooleanChunked> = None;
+    for num_values in page_num_values {
+        let page_mask = mask.slice(offset as i64, num_values);
+        offset += num_values;
+        let read = page_mask.any();
+        if read {
+            match &mut page_masks {
+                Some(page_masks) => page_masks.append(&page_mask),
+                None => page_masks = Some(page_mask),
+            }
+        }
+        keep.push(read);
+    }
+    let pages_skipped = keep.iter().filter(|read| !**read).count();
+
+    let s = match page_masks {
+        Some(page_masks) => {
+            let iter = mmap::to_filtered_deserializer(
+                column_meta,
+                chunk,
+                field.clone(),
+                page_masks.len(),
+                keep,
+            )?;
+            array_iter_to_series(iter, &field, None)?.filter(&page_masks)?
+        }
+        None => Series::try_from((
+            field.name.as_str(),
+            new_empty_array(field.data_type.clone()),
+        ))?,
+    };
+    Ok((s, pages_skipped))
+}
+
+/// Read a row group with the [`ParallelStrategy::Prefiltered`] strategy. The columns of the
+/// predicate are decoded and filtered first, the other columns are decoded with that mask.
+///
+/// Returns `None` if the row group cannot be prefiltered.
+#[allow(clippy::too_many_arguments)]
+fn rg_to_df_prefiltered(
+    store: &mmap::ColumnStore,
+    md: &RowGroupMetaData,
+    remaining_rows: usize,
+    schema: &ArrowSchema,
+    predicate: &dyn PhysicalIoExpr,
+    row_count: Option<&RowCount>,
+    row_count_start: IdxSize,
+    projection: &[usize],
+    stats: &ParquetReadStats,
+) -> PolarsResult<Option<DataFrame>> {
+    let num_rows = md.num_rows();
+    // the limit is applied before the predicate
+    if remaining_rows < num_rows {
+        return Ok(None);
+    }
+    let live_variables = match predicate.live_variables() {
+        Some(live_variables) => live_variables,
+        None => return Ok(None),
+    };
+    let name = |column_i: usize| schema.fields[column_i].name.as_str();
+    let mut live = vec![false; projection.len()];
+    for var in &live_variables {
+        match projection.iter().position(|i| name(*i) == var.as_ref()) {
+            Some(pos) => live[pos] = true,
+            None if row_count.map(|rc| rc.name.as_str()) == Some(var.as_ref()) => {}
+            // e.g. a column that is added after the file is read
+            None => return Ok(None),
+        }
+    }
+    if live.iter().all(|live| *live) {
+        return Ok(None);
+    }
+
+    let live_columns = POOL.install(|| {
+        projection
+            .par_iter()
+            .zip(live.par_iter())
+            .filter(|(_, live)| **live)
+            .map(|(column_i, _)| {
+                column_idx_to_series(*column_i, md, num_rows, schema, store, num_rows)
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    let mut live_df = DataFrame::new_no_checks(live_columns);
+    if let Some(rc) = row_count {
+        live_df.with_row_count_mut(&rc.name, Some(next_row_count(rc.offset, row_count_start)?))?;
+    }
+    let mask = predicate.evaluate(&live_df)?;
+    let mask = mask
+        .bool()
+        .expect("filter predicates was not of type boolean")
+        .rechunk();
+    let live_df = live_df.filter(&mask)?;
+
+    let dead_columns = POOL.install(|| {
+        projection
+            .par_iter()
+            .zip(live.par_iter())
+            .filter(|(_, live)| !**live)
+            .map(|(column_i, _)| filtered_column_idx_to_series(*column_i, md, schema, store, &mask))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    let pages_skipped = dead_columns.iter().map(|(_, skipped)| skipped).sum();
+    stats.add_prefiltered(num_rows - live_df.height(), pages_skipped);
+
+    // restore the order of the projection, the row count comes first
+    let mut dead_columns = dead_columns.into_iter().map(|(s, _)| s);
+    let mut columns = Vec::with_capacity(projection.len() + 1);
+    if let Some(rc) = row_count {
+        columns.push(live_df.column(&rc.name)?.clone());
+    }
+    for (column_i, live) in projection.iter().zip(&live) {
+        if *live {
+            columns.push(live_df.column(name(*column_i))?.clone());
+        } else {
+            columns.push(dead_columns.next().unwrap());
+        }
+    }
+    Ok(Some(DataFrame::new_no_checks(columns)))
+}
+
+/// Whether the parquet leaf column at `path` is part of the (possibly pruned) `data_type`.
+fn is_projected_leaf(data_type: &ArrowDataType, path: &[String]) -> bool {
+    match (data_type, path.first()) {
+        (ArrowDataType::Struct(fields), Some(name)) => fields
+            .iter()
+            .find(|fld| &fld.name == name)
+            .map(|fld| is_projected_leaf(&fld.data_type, &path[1..]))
+            .unwrap_or(false),
+        _ => true,
+    }
+}
+
+/// Only keep the fields of struct columns that are in the struct dtypes of `projection`.
+#[cfg(feature = "dtype-struct")]
+pub(super) fn prune_struct_fields(schema: &mut ArrowSchema, projection: &Schema) {
+    fn prune(data_type: &ArrowDataType, dtype: &DataType) -> ArrowDataType {
+        match (data_type, dtype) {
+            (ArrowDataType::Struct(fields), DataType::Struct(projected)) => ArrowDataType::Struct(
+                fields
+                    .iter()
+                    .filter_map(|fld| {
+                        let projected = projected.iter().find(|p| p.name().as_str() == fld.name)?;
+                        let mut fld = fld.clone();
+                        fld.data_type = prune(&fld.data_type, projected.data_type());
+                        Some(fld)
+                    })
+                    .collect(),
+            ),
+            _ => data_type.clone(),
+        }
+    }
+
+    for field in schema.fields.iter_mut() {
+        if let Some(dtype) = projection.get(&field.name) {
+            field.data_type = prune(&field.data_type, dtype);
+        }
+    }
+}
+
 pub(super) fn array_iter_to_series(
     iter: ArrayIter,
     field: &ArrowField,
@@ -95,11 +359,13 @@ fn rg_to_dfs(
     remaining_rows: &mut usize,
     file_metadata: &FileMetaData,
     schema: &ArrowSchema,
+    stats_schema: &ArrowSchema,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     row_count: Option<RowCount>,
     parallel: ParallelStrategy,
     projection: &[usize],
     use_statistics: bool,
+    stats: &ParquetReadStats,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
 
@@ -107,50 +373,80 @@ fn rg_to_dfs(
         let md = &file_metadata.row_groups[rg];
         let current_row_count = md.num_rows() as IdxSize;
 
-        if use_statistics && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg)? {
+        if use_statistics
+            && !read_this_row_group(predicate.as_ref(), file_metadata, stats_schema, rg)?
+        {
             *previous_row_count += current_row_count;
             continue;
         }
-        // test we don't read the parquet file if this env var is set
-        #[cfg(debug_assertions)]
-        {
-            assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
-        }
-
-        let chunk_size = md.num_rows();
-        let columns = if let ParallelStrategy::Columns = parallel {
-            POOL.install(|| {
-                projection
-                    .par_iter()
-                    .map(|column_i| {
-                        column_idx_to_series(
-                            *column_i,
-                            md,
-                            *remaining_rows,
-                            schema,
-                            store,
-                            chunk_size,
-                        )
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()
-            })?
-        } else {
-            projection
-                .iter()
-                .map(|column_i| {
-                    column_idx_to_series(*column_i, md, *remaining_rows, schema, store, chunk_size)
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
+        stats.add_row_group_decoded();
+
+        let prefiltered = match (parallel, &predicate) {
+            (ParallelStrategy::Prefiltered, Some(predicate)) => rg_to_df_prefiltered(
+                store,
+                md,
+                *remaining_rows,
+                schema,
+                predicate.as_ref(),
+                row_count.as_ref(),
+                *previous_row_count,
+                projection,
+                stats,
+            )?,
+            _ => None,
         };
 
-        *remaining_rows = remaining_rows.saturating_sub(file_metadata.row_groups[rg].num_rows());
+        let df = match prefiltered {
+            Some(df) => df,
+            None => {
+                let chunk_size = md.num_rows();
+                let columns =
+                    if let ParallelStrategy::Columns | ParallelStrategy::Prefiltered = parallel {
+                        POOL.install(|| {
+                            projection
+                                .par_iter()
+                                .map(|column_i| {
+                                    column_idx_to_series(
+                                        *column_i,
+                                        md,
+                                        *remaining_rows,
+                                        schema,
+                                        store,
+                                        chunk_size,
+                                    )
+                                })
+                                .collect::<PolarsResult<Vec<_>>>()
+                        })?
+                    } else {
+                        projection
+                            .iter()
+                            .map(|column_i| {
+                                column_idx_to_series(
+                                    *column_i,
+                                    md,
+                                    *remaining_rows,
+                                    schema,
+                                    store,
+                                    chunk_size,
+                                )
+                            })
+                            .collect::<PolarsResult<Vec<_>>>()?
+                    };
+
+                let mut df = DataFrame::new_no_checks(columns);
+                if let Some(rc) = &row_count {
+                    df.with_row_count_mut(
+                        &rc.name,
+                        Some(next_row_count(rc.offset, *previous_row_count)?),
+                    )?;
+                }
 
-        let mut df = DataFrame::new_no_checks(columns);
-        if let Some(rc) = &row_count {
-            df.with_row_count_mut(&rc.name, Some(*previous_row_count + rc.offset));
-        }
+                apply_predicate(&mut df, predicate.as_deref(), true)?;
+                df
+            }
+        };
 
-        apply_predicate(&mut df, predicate.as_deref(), true)?;
+        *remaining_rows = remaining_rows.saturating_sub(file_metadata.row_groups[rg].num_rows());
 
         *previous_row_count += current_row_count;
         dfs.push(df);
@@ -172,10 +468,12 @@ fn rg_to_dfs_par(
     remaining_rows: &mut usize,
     file_metadata: &FileMetaData,
     schema: &ArrowSchema,
+    stats_schema: &ArrowSchema,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     row_count: Option<RowCount>,
     projection: &[usize],
     use_statistics: bool,
+    stats: &ParquetReadStats,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
     let row_groups = file_metadata
@@ -200,15 +498,16 @@ fn rg_to_dfs_par(
         .map(|(rg_idx, md, local_limit, row_count_start)| {
             if local_limit == 0
                 || use_statistics
-                    && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg_idx)?
+                    && !read_this_row_group(
+                        predicate.as_ref(),
+                        file_metadata,
+                        stats_schema,
+                        rg_idx,
+                    )?
             {
                 return Ok(None);
             }
-            // test we don't read the parquet file if this env var is set
-            #[cfg(debug_assertions)]
-            {
-                assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
-            }
+            stats.add_row_group_decoded();
 
             let chunk_size = md.num_rows();
             let columns = projection
@@ -221,7 +520,10 @@ fn rg_to_dfs_par(
             let mut df = DataFrame::new_no_checks(columns);
 
             if let Some(rc) = &row_count {
-                df.with_row_count_mut(&rc.name, Some(row_count_start as IdxSize + rc.offset));
+                df.with_row_count_mut(
+                    &rc.name,
+                    Some(next_row_count(rc.offset, row_count_start as IdxSize)?),
+                )?;
             }
 
             apply_predicate(&mut df, predicate.as_deref(), false)?;
@@ -243,6 +545,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_count: Option<RowCount>,
     use_statistics: bool,
+    stats: &ParquetReadStats,
 ) -> PolarsResult<DataFrame> {
     let file_metadata = metadata
         .map(Ok)
@@ -265,24 +568,43 @@ pub fn read_parquet<R: MmapBytesReader>(
         parallel = ParallelStrategy::None;
     }
 
+    // the fields that are pruned from the struct columns are needed to collect the statistics
+    let file_schema;
+    let stats_schema = if use_statistics
+        && predicate.is_some()
+        && schema
+            .fields
+            .iter()
+            .any(|fld| matches!(fld.data_type, ArrowDataType::Struct(_)))
+    {
+        file_schema = read::infer_schema(&file_metadata)?;
+        &file_schema
+    } else {
+        schema
+    };
+
     let reader = ReaderBytes::from(&reader);
     let bytes = reader.deref();
     let store = mmap::ColumnStore::Local(bytes);
     let dfs = match parallel {
-        ParallelStrategy::Columns | ParallelStrategy::None => rg_to_dfs(
-            &store,
-            &mut 0,
-            0,
-            row_group_len,
-            &mut limit,
-            &file_metadata,
-            schema,
-            predicate,
-            row_count,
-            parallel,
-            &projection,
-            use_statistics,
-        )?,
+        ParallelStrategy::Columns | ParallelStrategy::None | ParallelStrategy::Prefiltered => {
+            rg_to_dfs(
+                &store,
+                &mut 0,
+                0,
+                row_group_len,
+                &mut limit,
+                &file_metadata,
+                schema,
+                stats_schema,
+                predicate,
+                row_count,
+                parallel,
+                &projection,
+                use_statistics,
+                stats,
+            )?
+        }
         ParallelStrategy::RowGroups => rg_to_dfs_par(
             &store,
             0,
@@ -291,10 +613,12 @@ pub fn read_parquet<R: MmapBytesReader>(
             &mut limit,
             &file_metadata,
             schema,
+            stats_schema,
             predicate,
             row_count,
             &projection,
             use_statistics,
+            stats,
         )?,
         // auto should already be replaced by Columns or RowGroups
         ParallelStrategy::Auto => unimplemented!(),
@@ -350,6 +674,8 @@ pub struct BatchedParquetReader {
     limit: usize,
     projection: Vec<usize>,
     schema: ArrowSchema,
+    /// The schema of the file if the struct fields of `schema` are pruned.
+    stats_schema: Option<ArrowSchema>,
     metadata: FileMetaData,
     row_count: Option<RowCount>,
     rows_read: IdxSize,
@@ -359,6 +685,8 @@ pub struct BatchedParquetReader {
     parallel: ParallelStrategy,
     chunk_size: usize,
     use_statistics: bool,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    stats: Arc<ParquetReadStats>,
 }
 
 impl BatchedParquetReader {
@@ -370,6 +698,8 @@ impl BatchedParquetReader {
         row_count: Option<RowCount>,
         chunk_size: usize,
         use_statistics: bool,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        stats: Arc<ParquetReadStats>,
     ) -> PolarsResult<Self> {
         let schema = read::schema::infer_schema(&metadata)?;
         let n_row_groups = metadata.row_groups.len();
@@ -388,6 +718,7 @@ impl BatchedParquetReader {
             limit,
             projection,
             schema,
+            stats_schema: None,
             metadata,
             row_count,
             rows_read: 0,
@@ -397,9 +728,22 @@ impl BatchedParquetReader {
             parallel,
             chunk_size,
             use_statistics,
+            predicate,
+            stats,
         })
     }
 
+    #[cfg(feature = "dtype-struct")]
+    pub(super) fn prune_struct_fields(&mut self, projection: &Schema) {
+        self.stats_schema.get_or_insert_with(|| self.schema.clone());
+        prune_struct_fields(&mut self.schema, projection)
+    }
+
+    /// An empty [`DataFrame`] with the projected columns of the file.
+    pub(super) fn empty_df(&self) -> DataFrame {
+        arrow_schema_to_empty_df(&apply_projection(&self.schema, &self.projection))
+    }
+
     pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
         // fill up fifo stack
         if self.row_group_offset <= self.n_row_groups && self.chunks_fifo.len() < n {
@@ -418,11 +762,13 @@ impl BatchedParquetReader {
                         &mut self.limit,
                         &self.metadata,
                         &self.schema,
-                        None,
+                        self.stats_schema.as_ref().unwrap_or(&self.schema),
+                        self.predicate.clone(),
                         self.row_count.clone(),
                         ParallelStrategy::Columns,
                         &self.projection,
                         self.use_statistics,
+                        &self.stats,
                     )?;
                     self.row_group_offset += n;
                     dfs
@@ -436,10 +782,12 @@ impl BatchedParquetReader {
                         &mut self.limit,
                         &self.metadata,
                         &self.schema,
-                        None,
+                        self.stats_schema.as_ref().unwrap_or(&self.schema),
+                        self.predicate.clone(),
                         self.row_count.clone(),
                         &self.projection,
                         self.use_statistics,
+                        &self.stats,
                     )?;
                     self.row_group_offset += n;
                     dfs
@@ -2,7 +2,8 @@ use std::io::Write;
 
 use arrow::array::Array;
 use arrow::chunk::Chunk;
-use arrow::datatypes::{DataType as ArrowDataType, PhysicalType};
+use arrow::compute::cast::{cast, CastOptions};
+use arrow::datatypes::{DataType as ArrowDataType, IntegerType, PhysicalType};
 use arrow::error::Error as ArrowError;
 use arrow::io::parquet::read::ParquetError;
 use arrow::io::parquet::write::{self, DynIter, DynStreamingIterator, Encoding, FileWriter, *};
@@ -85,6 +86,37 @@ impl From<ParquetCompression> for CompressionOptions {
     }
 }
 
+/// Write options of a single column. Options that are not set fall back to the options of the
+/// [`ParquetWriter`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnOptions {
+    /// Data page compression of this column.
+    pub compression: Option<ParquetCompression>,
+    /// Compute and write the statistics of this column.
+    pub statistics: Option<bool>,
+    /// Dictionary encode this column. By default only categoricals are dictionary encoded.
+    /// Disabling the dictionary of a categorical column writes it as a string column.
+    pub dictionary: Option<bool>,
+}
+
+impl ParquetColumnOptions {
+    pub fn with_compression(mut self, compression: ParquetCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
+
+    pub fn with_dictionary(mut self, dictionary: bool) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+}
+
 /// Write a DataFrame to parquet format
 ///
 #[must_use]
@@ -100,6 +132,8 @@ pub struct ParquetWriter<W> {
     data_pagesize_limit: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Options that overrule the options above for specific columns.
+    column_options: PlHashMap<String, ParquetColumnOptions>,
 }
 
 impl<W> ParquetWriter<W>
@@ -118,6 +152,7 @@ where
             row_group_size: None,
             data_pagesize_limit: None,
             parallel: true,
+            column_options: Default::default(),
         }
     }
 
@@ -155,6 +190,13 @@ where
         self
     }
 
+    /// Set the compression, statistics and dictionary encoding of a single column.
+    /// The column must exist in the written [`DataFrame`].
+    pub fn with_column_options(mut self, column: &str, options: ParquetColumnOptions) -> Self {
+        self.column_options.insert(column.to_string(), options);
+        self
+    }
+
     fn materialize_options(&self) -> WriteOptions {
         WriteOptions {
             write_statistics: self.statistics,
@@ -165,19 +207,68 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let fields = schema.to_arrow().fields;
+        for name in self.column_options.keys() {
+            polars_ensure!(
+                schema.contains(name),
+                ColumnNotFound: "parquet column options are set for column '{}', which is not in the schema",
+                name
+            );
+        }
+
+        let options = self.materialize_options();
+        let mut column_options = Vec::with_capacity(schema.len());
+        let mut cast_to = Vec::with_capacity(schema.len());
+        let mut fields = Vec::with_capacity(schema.len());
+
+        for (name, dtype) in schema.iter() {
+            let mut field = ArrowField::new(name.as_str(), dtype.to_arrow(), true);
+            let mut cast_type = None;
+            let mut column_option = options;
+
+            if let Some(col_options) = self.column_options.get(name.as_str()) {
+                if let Some(compression) = col_options.compression {
+                    column_option.compression = compression.into();
+                }
+                if let Some(statistics) = col_options.statistics {
+                    column_option.write_statistics = statistics;
+                }
+                match (col_options.dictionary, &field.data_type) {
+                    (Some(false), ArrowDataType::Dictionary(_, values, _)) => {
+                        field.data_type = values.as_ref().clone();
+                        cast_type = Some(field.data_type.clone());
+                    }
+                    (Some(true), ArrowDataType::Dictionary(..)) | (Some(false), _) | (None, _) => {}
+                    (Some(true), data_type) => {
+                        polars_ensure!(
+                            dtype.is_numeric()
+                                || matches!(data_type, ArrowDataType::LargeUtf8 | ArrowDataType::LargeBinary),
+                            InvalidOperation: "cannot dictionary encode column '{}' of dtype {}",
+                            name, dtype
+                        );
+                        cast_type = Some(ArrowDataType::Dictionary(
+                            IntegerType::UInt32,
+                            Box::new(data_type.clone()),
+                            false,
+                        ));
+                    }
+                }
+            }
+            column_options.push(column_option);
+            cast_to.push(cast_type);
+            fields.push(field);
+        }
         let schema = ArrowSchema::from(fields);
 
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
-        let options = self.materialize_options();
+        let encodings = get_encodings(&schema, &cast_to);
         let writer = FileWriter::try_new(self.writer, schema, options)?;
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
-            options,
+            cast_to,
+            options: column_options,
             parallel: self.parallel,
         })
     }
@@ -199,31 +290,54 @@ where
     }
 }
 
+/// Cast the columns of which the dictionary encoding is set to the types they are written with.
+fn cast_batch(
+    batch: Chunk<Box<dyn Array>>,
+    cast_to: &[Option<ArrowDataType>],
+) -> Result<Chunk<Box<dyn Array>>, ArrowError> {
+    if cast_to.iter().all(|dt| dt.is_none()) {
+        return Ok(batch);
+    }
+    let columns = batch
+        .into_arrays()
+        .into_iter()
+        .zip(cast_to)
+        .map(|(arr, cast_type)| match cast_type {
+            Some(data_type) => cast(arr.as_ref(), data_type, CastOptions::default()),
+            None => Ok(arr),
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+    Chunk::try_new(columns)
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
-    options: WriteOptions,
+    cast_to: &'a [Option<ArrowDataType>],
+    options: &'a [WriteOptions],
     parallel: bool,
 ) -> impl Iterator<Item = Result<RowGroupIter<'a, ArrowError>, ArrowError>> + 'a {
     let rb_iter = df.iter_chunks();
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let row_group = cast_batch(batch, cast_to).and_then(|batch| {
+                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel)
+            });
 
             Some(row_group)
         }
     })
 }
 
-fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
+fn get_encodings(schema: &ArrowSchema, cast_to: &[Option<ArrowDataType>]) -> Vec<Vec<Encoding>> {
     schema
         .fields
         .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
+        .zip(cast_to)
+        .map(|(f, cast_type)| transverse(cast_type.as_ref().unwrap_or(&f.data_type), encoding_map))
         .collect()
 }
 
@@ -240,7 +354,8 @@ pub struct BatchedWriter<W: Write> {
     writer: FileWriter<W>,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Vec<Encoding>>,
-    options: WriteOptions,
+    cast_to: Vec<Option<ArrowDataType>>,
+    options: Vec<WriteOptions>,
     parallel: bool,
 }
 
@@ -254,7 +369,8 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
-            self.options,
+            &self.cast_to,
+            &self.options,
             self.parallel,
         );
         for group in row_group_iter {
@@ -274,10 +390,14 @@ fn create_serializer<'a>(
     batch: Chunk<Box<dyn Array>>,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    options: &[WriteOptions],
     parallel: bool,
 ) -> Result<RowGroupIter<'a, ArrowError>, ArrowError> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| {
+        let options = *options;
         let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
 
         encoded_columns
@@ -311,6 +431,7 @@ fn create_serializer<'a>(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(options)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -320,6 +441,7 @@ fn create_serializer<'a>(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(options)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -5,6 +5,11 @@ pub trait PhysicalIoExpr: Send + Sync {
     /// as a predicate mask
     fn evaluate(&self, df: &DataFrame) -> PolarsResult<Series>;
 
+    /// The names of the columns the predicate depends on, `None` if they are not known.
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        None
+    }
+
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
@@ -60,7 +60,8 @@ pub(crate) fn columns_to_projection(
 
         for column in columns.iter() {
             let Some(&i) = column_names.get(column.as_str()) else {
-                let valid_columns: Vec<String> = schema.fields.iter().map(|f| f.name.clone()).collect();
+                let valid_columns: Vec<String> =
+                    schema.fields.iter().map(|f| f.name.clone()).collect();
                 polars_bail!(
                     ColumnNotFound:
                     "unable to find {:?}; valid columns: {:?}", column, valid_columns,
@@ -78,40 +79,60 @@ pub(crate) fn columns_to_projection(
     Ok(prj)
 }
 
+/// The row count `n_read` rows after `previous`.
+pub(crate) fn next_row_count(previous: IdxSize, n_read: IdxSize) -> PolarsResult<IdxSize> {
+    previous.checked_add(n_read).ok_or_else(
+        || polars_err!(ComputeError: "row count overflows {}; use a UInt64 row count", IDX_DTYPE),
+    )
+}
+
 /// Because of threading every row starts from `0` or from `offset`.
 /// We must correct that so that they are monotonically increasing.
-pub(crate) fn update_row_counts(dfs: &mut [(DataFrame, IdxSize)], offset: IdxSize) {
+///
+/// Errors if the row count does not fit in [`IDX_DTYPE`].
+pub(crate) fn update_row_counts(
+    dfs: &mut [(DataFrame, IdxSize)],
+    offset: IdxSize,
+) -> PolarsResult<()> {
     if !dfs.is_empty() {
-        let mut previous = dfs[0].1 + offset;
+        let mut previous = next_row_count(offset, dfs[0].1)?;
         for (df, n_read) in &mut dfs[1..] {
+            // checked before the add, which would wrap
+            let next = next_row_count(previous, *n_read)?;
             if let Some(s) = unsafe { df.get_columns_mut() }.get_mut(0) {
                 *s = &*s + previous;
             }
-            previous += *n_read;
+            previous = next;
         }
     }
+    Ok(())
 }
 
 /// Because of threading every row starts from `0` or from `offset`.
 /// We must correct that so that they are monotonically increasing.
-pub(crate) fn update_row_counts2(dfs: &mut [DataFrame], offset: IdxSize) {
+///
+/// Errors if the row count does not fit in [`IDX_DTYPE`].
+pub(crate) fn update_row_counts2(dfs: &mut [DataFrame], offset: IdxSize) -> PolarsResult<()> {
     if !dfs.is_empty() {
-        let mut previous = dfs[0].height() as IdxSize + offset;
+        let mut previous = next_row_count(offset, dfs[0].height() as IdxSize)?;
         for df in &mut dfs[1..] {
-            let n_read = df.height() as IdxSize;
+            let next = next_row_count(previous, df.height() as IdxSize)?;
             if let Some(s) = unsafe { df.get_columns_mut() }.get_mut(0) {
                 *s = &*s + previous;
             }
-            previous += n_read;
+            previous = next;
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
-    use super::resolve_homedir;
+    use polars_core::prelude::*;
+
+    use super::{resolve_homedir, update_row_counts2};
 
     #[cfg(not(target_os = "windows"))]
     #[test]
@@ -150,4 +171,19 @@ mod tests {
         assert!(resolved[1].is_absolute());
         assert!(resolved[2].is_absolute());
     }
+
+    #[test]
+    fn test_update_row_counts_overflow() -> PolarsResult<()> {
+        let chunk = || df!("rc" => [0 as IdxSize, 1]).unwrap();
+        let mut dfs = vec![chunk(), chunk()];
+        update_row_counts2(&mut dfs, 10)?;
+        assert_eq!(
+            Vec::from(dfs[1].column("rc")?.idx()?),
+            &[Some(12), Some(13)]
+        );
+
+        let mut dfs = vec![chunk(), chunk()];
+        assert!(update_row_counts2(&mut dfs, IdxSize::MAX - 2).is_err());
+        Ok(())
+    }
 }
@@ -1,5 +1,5 @@
 use std::any::Any;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
@@ -45,6 +45,33 @@ impl SinkWriter for polars_io::ipc::BatchedWriter<std::fs::File> {
     }
 }
 
+#[cfg(feature = "ipc_streaming")]
+impl SinkWriter for polars_io::ipc::BatchedIpcStreamWriter<Box<dyn std::io::Write + Send + Sync>> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_writer(
+    file: std::fs::File,
+    options: &ParquetWriteOptions,
+) -> ParquetWriter<std::fs::File> {
+    options.column_options.iter().fold(
+        ParquetWriter::new(file)
+            .with_compression(options.compression)
+            .with_data_pagesize_limit(options.data_pagesize_limit)
+            .with_statistics(options.statistics)
+            .with_row_group_size(options.row_group_size),
+        |writer, (column, column_options)| writer.with_column_options(column, *column_options),
+    )
+}
+
 #[cfg(feature = "parquet")]
 pub struct ParquetSink {}
 #[cfg(feature = "parquet")]
@@ -56,11 +83,7 @@ impl ParquetSink {
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
         let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_pagesize_limit(options.data_pagesize_limit)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
+        let writer = parquet_writer(file, &options)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
@@ -86,6 +109,217 @@ impl ParquetSink {
     }
 }
 
+/// Directory name of partitions with a null key, this is what hive uses.
+#[cfg(feature = "parquet")]
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+#[cfg(feature = "parquet")]
+struct PartitionFile {
+    writer: polars_io::parquet::BatchedWriter<std::fs::File>,
+    n_rows: usize,
+    last_used: usize,
+}
+
+#[cfg(feature = "parquet")]
+struct PartitionedParquetWriter {
+    root: PathBuf,
+    partition_by: Vec<String>,
+    options: ParquetPartitionOptions,
+    file_schema: Schema,
+    open_files: PlHashMap<PathBuf, PartitionFile>,
+    // the number of files written per partition, also counts the closed files
+    file_counts: PlHashMap<PathBuf, usize>,
+    tick: usize,
+}
+
+#[cfg(feature = "parquet")]
+fn escape_partition_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '/' | '\\' | '=' | '%' | ':' | '"' | '*' | '?' | '<' | '>' | '|' | '\n' | '\r' => {
+                out.push_str(&format!("%{:02X}", c as u32))
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(feature = "parquet")]
+impl PartitionedParquetWriter {
+    fn partition_dir(&self, df: &DataFrame) -> PolarsResult<PathBuf> {
+        let mut dir = self.root.clone();
+        for name in &self.partition_by {
+            let s = df.column(name)?.cast(&DataType::Utf8)?;
+            let value = match s.utf8()?.get(0) {
+                Some(value) => escape_partition_value(value),
+                None => HIVE_DEFAULT_PARTITION.to_string(),
+            };
+            dir.push(format!("{name}={value}"));
+        }
+        Ok(dir)
+    }
+
+    fn close_least_recently_used(&mut self) -> PolarsResult<()> {
+        let lru = self
+            .open_files
+            .iter()
+            .min_by_key(|(_, file)| file.last_used)
+            .map(|(dir, _)| dir.clone());
+        if let Some(dir) = lru {
+            let mut file = self.open_files.remove(&dir).unwrap();
+            file.writer.finish()?;
+        }
+        Ok(())
+    }
+
+    fn open_file(&mut self, dir: &Path) -> PolarsResult<PartitionFile> {
+        if self.open_files.len() >= self.options.max_open_files {
+            self.close_least_recently_used()?;
+        }
+        std::fs::create_dir_all(dir)?;
+        let count = self.file_counts.entry(dir.to_path_buf()).or_insert(0);
+        let path = dir.join(format!("part-{count:05}.parquet"));
+        *count += 1;
+
+        let file = std::fs::File::create(path)?;
+        let writer = parquet_writer(file, &self.options.write_options)
+            // we write from the io thread, see `ParquetSink`
+            .set_parallel(false)
+            .batched(&self.file_schema)?;
+        Ok(PartitionFile {
+            writer,
+            n_rows: 0,
+            last_used: 0,
+        })
+    }
+
+    fn write_partition(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        let dir = self.partition_dir(&df)?;
+        if !self.options.include_partition_columns {
+            for name in &self.partition_by {
+                df = df.drop(name)?;
+            }
+        }
+        self.tick += 1;
+
+        while df.height() > 0 {
+            let mut file = match self.open_files.remove(&dir) {
+                Some(file) => file,
+                None => self.open_file(&dir)?,
+            };
+            file.last_used = self.tick;
+
+            let capacity = self
+                .options
+                .max_rows_per_file
+                .map(|max| max.saturating_sub(file.n_rows))
+                .unwrap_or(usize::MAX);
+            let (batch, remainder) = if df.height() > capacity {
+                let remainder = df.slice(capacity as i64, df.height() - capacity);
+                (df.slice(0, capacity), remainder)
+            } else {
+                (df, DataFrame::empty())
+            };
+            if batch.height() > 0 {
+                file.writer.write_batch(&batch)?;
+                file.n_rows += batch.height();
+            }
+            df = remainder;
+
+            // roll over to the next file
+            if matches!(self.options.max_rows_per_file, Some(max) if file.n_rows >= max) {
+                file.writer.finish()?;
+            } else {
+                self.open_files.insert(dir.clone(), file);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl SinkWriter for PartitionedParquetWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        for partition in df.partition_by_stable(self.partition_by.as_slice(), true)? {
+            self.write_partition(partition)?;
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        for (_, mut file) in self.open_files.drain() {
+            file.writer.finish()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "parquet")]
+pub struct PartitionedParquetSink {}
+#[cfg(feature = "parquet")]
+impl PartitionedParquetSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        root: &Path,
+        partition_by: &[String],
+        options: ParquetPartitionOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        polars_ensure!(
+            !partition_by.is_empty(),
+            ComputeError: "expected at least one column to partition by"
+        );
+        for name in partition_by {
+            schema.try_get(name)?;
+        }
+        polars_ensure!(
+            options.max_open_files > 0,
+            ComputeError: "`max_open_files` must be larger than 0"
+        );
+        polars_ensure!(
+            options.max_rows_per_file != Some(0),
+            ComputeError: "`max_rows_per_file` must be larger than 0"
+        );
+        let mut file_schema = schema.clone();
+        if !options.include_partition_columns {
+            for name in partition_by {
+                file_schema.shift_remove(name);
+            }
+        }
+        std::fs::create_dir_all(root)?;
+
+        let maintain_order = options.write_options.maintain_order;
+        let writer = PartitionedParquetWriter {
+            root: root.to_path_buf(),
+            partition_by: partition_by.to_vec(),
+            options,
+            file_schema,
+            open_files: Default::default(),
+            file_counts: Default::default(),
+            tick: 0,
+        };
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
 #[cfg(feature = "ipc")]
 pub struct IpcSink {}
 #[cfg(feature = "ipc")]
@@ -117,6 +351,40 @@ impl IpcSink {
     }
 }
 
+#[cfg(feature = "ipc_streaming")]
+pub struct IpcStreamSink {}
+#[cfg(feature = "ipc_streaming")]
+impl IpcStreamSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        writer: Box<dyn std::io::Write + Send + Sync>,
+        options: IpcWriterOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        let writer = polars_io::ipc::IpcStreamWriter::new(writer)
+            .with_compression(options.compression.map(|c| c.into()))
+            .batched(schema)?;
+
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            options.maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
 #[cfg(any(feature = "parquet", feature = "ipc"))]
 fn init_writer_thread(
     receiver: Receiver<Option<DataChunk>>,
@@ -163,7 +163,7 @@ where
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
 
                 #[cfg(feature = "dtype-categorical")]
-                if matches!(logical_dtype, DataType::Categorical(_)) {
+                if matches!(logical_dtype, DataType::Categorical(_, _)) {
                     return (
                         phys_expr,
                         AggregateFunction::Null(NullAgg::new(logical_dtype)),
@@ -200,7 +200,7 @@ where
 
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
                 #[cfg(feature = "dtype-categorical")]
-                if matches!(logical_dtype, DataType::Categorical(_)) {
+                if matches!(logical_dtype, DataType::Categorical(_, _)) {
                     return (
                         phys_expr,
                         AggregateFunction::Null(NullAgg::new(logical_dtype)),
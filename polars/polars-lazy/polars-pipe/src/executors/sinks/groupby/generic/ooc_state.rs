@@ -63,6 +63,7 @@ impl OocState {
 
     pub(super) fn check_memory_usage(
         &mut self,
+        context: &PExecutionContext,
         spill_schema: &dyn Fn() -> Option<Schema>,
     ) -> PolarsResult<SpillAction> {
         if self.ooc {
@@ -71,7 +72,7 @@ impl OocState {
         let free_frac = self.mem_track.free_memory_fraction_since_start();
         self.count += 1;
 
-        if free_frac < TO_DISK_THRESHOLD {
+        if free_frac < TO_DISK_THRESHOLD || context.memory_limit_exceeded() {
             self.init_ooc(spill_schema)?;
             Ok(SpillAction::Dump)
         } else if free_frac < EARLY_MERGE_THRESHOLD
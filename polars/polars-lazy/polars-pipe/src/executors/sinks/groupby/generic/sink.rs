@@ -91,7 +91,7 @@ impl Sink for GenericGroupby2 {
         // other scenario could be that we must spill to disk
         match self
             .ooc_state
-            .check_memory_usage(&|| self.global_table.get_ooc_dump_schema())?
+            .check_memory_usage(context, &|| self.global_table.get_ooc_dump_schema())?
         {
             SpillAction::None => {}
             SpillAction::EarlyMerge => self.global_table.early_merge(),
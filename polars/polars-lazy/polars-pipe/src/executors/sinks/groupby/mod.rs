@@ -22,7 +22,7 @@ pub(super) fn physical_agg_to_logical(cols: &mut [Series], output_schema: &Schem
         }
         match dtype {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(rev_map) => {
+            DataType::Categorical(rev_map, _) => {
                 if let Some(rev_map) = rev_map {
                     let cats = s.u32().unwrap().clone();
                     // safety:
@@ -196,7 +196,10 @@ impl IOThread {
 impl Drop for IOThread {
     fn drop(&mut self) {
         // we drop the lockfile explicitly as the thread GC will leak.
-        std::fs::remove_file(&self._lockfile.path).unwrap();
+        let _ = std::fs::remove_file(&self._lockfile.path);
+        // The spilled files are not needed anymore. This is also hit on error paths.
+        // If this fails, the GC thread will clean up as there is no lockfile.
+        let _ = std::fs::remove_dir_all(&self.dir);
     }
 }
 
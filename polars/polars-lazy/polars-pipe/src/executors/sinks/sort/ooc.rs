@@ -18,7 +18,7 @@ pub(super) fn read_df(path: &Path) -> PolarsResult<DataFrame> {
 }
 
 pub(super) fn sort_ooc(
-    io_thread: &IOThread,
+    io_thread: IOThread,
     partitions: Series,
     idx: usize,
     descending: bool,
@@ -57,7 +57,7 @@ pub(super) fn sort_ooc(
         })
     })?;
 
-    block_thread_until_io_thread_done(io_thread);
+    block_thread_until_io_thread_done(&io_thread);
 
     let files = std::fs::read_dir(dir)?
         .flat_map(|entry| {
@@ -76,7 +76,7 @@ pub(super) fn sort_ooc(
         })
         .collect::<std::io::Result<Vec<_>>>()?;
 
-    let source = SortSource::new(files, idx, descending, slice);
+    let source = SortSource::new(io_thread, files, idx, descending, slice);
     Ok(FinalizedSink::Source(Box::new(source)))
 }
 
@@ -21,6 +21,9 @@ pub struct SortSink {
     // Stores available memory in the system at the start of this sink.
     // and stores the memory used by this this sink.
     mem_track: MemTracker,
+    // Memory budget in bytes before we go out-of-core.
+    // If not set we determine this from the available memory.
+    memory_budget: Option<usize>,
     // sort in-memory or out-of-core
     ooc: bool,
     // when ooc, we write to disk using an IO thread
@@ -34,7 +37,12 @@ pub struct SortSink {
 }
 
 impl SortSink {
-    pub(crate) fn new(sort_idx: usize, sort_args: SortArguments, schema: SchemaRef) -> Self {
+    pub(crate) fn new(
+        sort_idx: usize,
+        sort_args: SortArguments,
+        schema: SchemaRef,
+        memory_budget: Option<usize>,
+    ) -> Self {
         // for testing purposes
         let ooc = std::env::var(FORCE_OOC_SORT).is_ok();
         let n_morsels_per_sink = morsels_per_sink();
@@ -43,6 +51,7 @@ impl SortSink {
             schema,
             chunks: Default::default(),
             mem_track: MemTracker::new(n_morsels_per_sink),
+            memory_budget,
             ooc,
             io_thread: Default::default(),
             sort_idx,
@@ -70,15 +79,23 @@ impl SortSink {
         Ok(())
     }
 
-    fn store_chunk(&mut self, chunk: DataChunk) -> PolarsResult<()> {
+    fn store_chunk(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<()> {
         if !self.ooc {
             let chunk_bytes = chunk.data.estimated_size();
             let used = self.mem_track.fetch_add(chunk_bytes);
-            let free = self.mem_track.get_available();
 
-            // we need some free memory to be able to sort
-            // so we keep 3x the sort data size before we go out of core
-            if used * 3 > free {
+            let spill = match self.memory_budget {
+                // the used memory is shared between the threads, so all threads
+                // will go out of core once the budget is exceeded
+                Some(budget) => used + chunk_bytes > budget,
+                None => {
+                    let free = self.mem_track.get_available();
+                    // we need some free memory to be able to sort
+                    // so we keep 3x the sort data size before we go out of core
+                    used * 3 > free
+                }
+            };
+            if spill || context.memory_limit_exceeded() {
                 self.init_ooc()?;
             }
         }
@@ -107,8 +124,8 @@ impl SortSink {
 }
 
 impl Sink for SortSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
-        self.store_chunk(chunk)?;
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.store_chunk(context, chunk)?;
 
         if self.ooc {
             self.dump()?;
@@ -133,6 +150,7 @@ impl Sink for SortSink {
             schema: self.schema.clone(),
             chunks: Default::default(),
             mem_track: self.mem_track.clone(),
+            memory_budget: self.memory_budget,
             ooc: self.ooc,
             io_thread: self.io_thread.clone(),
             sort_idx: self.sort_idx,
@@ -143,8 +161,9 @@ impl Sink for SortSink {
 
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         if self.ooc {
-            let lock = self.io_thread.read().unwrap();
-            let io_thread = lock.as_ref().unwrap();
+            // the source takes ownership of the io thread so that the spilled
+            // files are removed once the source is dropped
+            let io_thread = self.io_thread.write().unwrap().take().unwrap();
 
             let dist = Series::from_any_values("", &self.dist_sample, false).unwrap();
             let dist = dist.sort_with(SortOptions {
@@ -153,7 +172,7 @@ impl Sink for SortSink {
                 multithreaded: true,
             });
 
-            block_thread_until_io_thread_done(io_thread);
+            block_thread_until_io_thread_done(&io_thread);
 
             sort_ooc(
                 io_thread,
@@ -59,7 +59,12 @@ pub struct SortSinkMultiple {
 }
 
 impl SortSinkMultiple {
-    pub(crate) fn new(sort_args: SortArguments, schema: &Schema, sort_idx: Vec<usize>) -> Self {
+    pub(crate) fn new(
+        sort_args: SortArguments,
+        schema: &Schema,
+        sort_idx: Vec<usize>,
+        memory_budget: Option<usize>,
+    ) -> Self {
         let mut schema = schema.clone();
         schema.with_column(POLARS_SORT_COLUMN.into(), DataType::Binary);
         let sort_fields = get_sort_fields(&sort_idx, &sort_args);
@@ -75,6 +80,7 @@ impl SortSinkMultiple {
                 slice: sort_args.slice,
             },
             Arc::new(schema),
+            memory_budget,
         ));
 
         SortSinkMultiple {
@@ -5,11 +5,14 @@ use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
 use polars_core::POOL;
 use rayon::prelude::*;
 
+use crate::executors::sinks::io::IOThread;
 use crate::executors::sinks::sort::ooc::read_df;
 use crate::executors::sinks::sort::sink::sort_accumulated;
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
 
 pub struct SortSource {
+    // holding this keeps the spilled files in place
+    _io_thread: IOThread,
     files: std::vec::IntoIter<(u32, PathBuf)>,
     n_threads: usize,
     sort_idx: usize,
@@ -21,6 +24,7 @@ pub struct SortSource {
 
 impl SortSource {
     pub(super) fn new(
+        io_thread: IOThread,
         mut files: Vec<(u32, PathBuf)>,
         sort_idx: usize,
         descending: bool,
@@ -32,6 +36,7 @@ impl SortSource {
         let files = files.into_iter();
 
         Self {
+            _io_thread: io_thread,
             files,
             n_threads,
             sort_idx,
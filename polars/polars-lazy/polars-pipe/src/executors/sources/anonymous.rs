@@ -0,0 +1,65 @@
+use polars_core::POOL;
+use polars_plan::prelude::{AnonymousScan, AnonymousScanOptions, BatchedAnonymousScan};
+
+use super::*;
+use crate::pipeline::determine_chunk_size;
+
+/// Reads an [`AnonymousScan`] that allows streaming in batches.
+pub(crate) struct AnonymousSource {
+    batched_scan: Box<dyn BatchedAnonymousScan>,
+    n_threads: usize,
+    chunk_index: IdxSize,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        options: AnonymousScanOptions,
+        chunk_size: Option<usize>,
+        verbose: bool,
+    ) -> PolarsResult<Self> {
+        let n_cols = options
+            .output_schema
+            .as_ref()
+            .unwrap_or(&options.schema)
+            .len();
+        let n_threads = POOL.current_num_threads();
+        let chunk_size = determine_chunk_size(n_cols, n_threads, chunk_size)?;
+
+        if verbose {
+            eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
+        }
+
+        let batched_scan = function.batched(options, chunk_size)?;
+        Ok(AnonymousSource {
+            batched_scan,
+            n_threads,
+            chunk_index: 0,
+        })
+    }
+}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let batches = self.batched_scan.next_batches(self.n_threads)?;
+        Ok(match batches {
+            None => SourceResult::Finished,
+            Some(batches) => SourceResult::GotMoreData(
+                batches
+                    .into_iter()
+                    .map(|data| {
+                        let out = DataChunk {
+                            chunk_index: self.chunk_index,
+                            data,
+                        };
+                        self.chunk_index += 1;
+                        out
+                    })
+                    .collect(),
+            ),
+        })
+    }
+    fn fmt(&self) -> &str {
+        "anonymous"
+    }
+}
@@ -19,6 +19,10 @@ pub(crate) struct CsvSource {
     batched_reader: Either<*mut BatchedCsvReaderMmap<'static>, *mut BatchedCsvReaderRead<'static>>,
     n_threads: usize,
     chunk_index: IdxSize,
+    // the file path column, if it is projected
+    file_path_column: Option<Series>,
+    // a column that is only read to know the number of rows
+    dummy_column: Option<String>,
 }
 
 impl CsvSource {
@@ -26,9 +30,35 @@ impl CsvSource {
         path: PathBuf,
         schema: SchemaRef,
         options: CsvParserOptions,
+        chunk_size: Option<usize>,
         verbose: bool,
     ) -> PolarsResult<Self> {
+        let mut schema = schema;
         let mut with_columns = options.with_columns;
+        let mut file_path_column = None;
+        let mut dummy_column = None;
+        if let Some(column) = options.file_path_column {
+            // the file path column is not in the file
+            let name = column.name();
+            let mut file_schema = schema.as_ref().clone();
+            file_schema.remove(name);
+            schema = Arc::new(file_schema);
+            match &mut with_columns {
+                Some(columns) => {
+                    if columns.iter().any(|c| c == name) {
+                        file_path_column = Some(column.column.clone());
+                    }
+                    let columns = Arc::make_mut(columns);
+                    columns.retain(|c| c != name);
+                    if columns.is_empty() {
+                        let (first, _) = schema.get_index(0).unwrap();
+                        dummy_column = Some(first.to_string());
+                        columns.push(first.to_string());
+                    }
+                }
+                None => file_path_column = Some(column.column),
+            }
+        }
         let mut projected_len = 0;
         with_columns.as_ref().map(|columns| {
             projected_len = columns.len();
@@ -47,7 +77,7 @@ impl CsvSource {
         };
         // inversely scale the chunk size by the number of threads so that we reduce memory pressure
         // in streaming
-        let chunk_size = determine_chunk_size(n_cols, POOL.current_num_threads())?;
+        let chunk_size = determine_chunk_size(n_cols, POOL.current_num_threads(), chunk_size)?;
 
         if verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
@@ -64,6 +94,7 @@ impl CsvSource {
             .with_columns(with_columns.map(|mut cols| std::mem::take(Arc::make_mut(&mut cols))))
             .low_memory(options.low_memory)
             .with_null_values(options.null_values)
+            .with_ragged_lines(options.ragged_lines)
             .with_encoding(CsvEncoding::LossyUtf8)
             .with_comment_char(options.comment_char)
             .with_quote_char(options.quote_char)
@@ -72,7 +103,18 @@ impl CsvSource {
             .with_rechunk(options.rechunk)
             .with_chunk_size(chunk_size)
             .with_row_count(options.row_count)
-            .with_try_parse_dates(options.try_parse_dates);
+            .with_try_parse_dates(options.try_parse_dates)
+            .with_strict_date_formats(
+                options
+                    .date_formats
+                    .as_ref()
+                    .map_or(false, |date_formats| date_formats.strict),
+            )
+            .with_date_formats(
+                options
+                    .date_formats
+                    .map(|date_formats| date_formats.formats),
+            );
 
         let reader = Box::new(reader);
         let reader = Box::leak(reader) as *mut CsvReader<'static, File>;
@@ -93,6 +135,8 @@ impl CsvSource {
             batched_reader,
             n_threads: POOL.current_num_threads(),
             chunk_index: 0,
+            file_path_column,
+            dummy_column,
         })
     }
 }
@@ -135,15 +179,22 @@ impl Source for CsvSource {
             Some(batches) => SourceResult::GotMoreData(
                 batches
                     .into_iter()
-                    .map(|data| {
+                    .map(|mut data| {
+                        let height = data.height();
+                        if let Some(name) = &self.dummy_column {
+                            data = data.drop(name)?;
+                        }
+                        if let Some(s) = &self.file_path_column {
+                            data.with_column(s.new_from_index(0, height))?;
+                        }
                         let out = DataChunk {
                             chunk_index: self.chunk_index,
                             data,
                         };
                         self.chunk_index += 1;
-                        out
+                        Ok(out)
                     })
-                    .collect(),
+                    .collect::<PolarsResult<_>>()?,
             ),
         })
     }
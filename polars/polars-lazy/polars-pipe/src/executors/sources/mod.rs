@@ -1,16 +1,20 @@
+mod anonymous;
 #[cfg(feature = "csv")]
 mod csv;
 mod frame;
 mod ipc_one_shot;
+mod multiplexer;
 #[cfg(feature = "parquet")]
 mod parquet;
 mod reproject;
 mod union;
 
+pub(crate) use anonymous::*;
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvSource;
 pub(crate) use frame::*;
 pub(crate) use ipc_one_shot::*;
+pub(crate) use multiplexer::*;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::*;
 pub(crate) use reproject::*;
@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::polars_err;
+
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+// A `None` batch indicates that the source is finished.
+type Batch = Option<Vec<DataChunk>>;
+
+struct SharedSource {
+    // `None` if one of the consumers is reading from it.
+    source: Option<Box<dyn Source>>,
+    // batches that are not yet consumed by all consumers
+    buffer: VecDeque<Batch>,
+    // index of the first batch in the buffer
+    offset: usize,
+    // index of the next batch every consumer will read
+    // `None` if the consumer is dropped
+    positions: Vec<Option<usize>>,
+    finished: bool,
+    failed: bool,
+}
+
+impl SharedSource {
+    fn trim(&mut self) {
+        let min_position = self
+            .positions
+            .iter()
+            .flatten()
+            .min()
+            .copied()
+            .unwrap_or(self.offset + self.buffer.len());
+        while self.offset < min_position && self.buffer.pop_front().is_some() {
+            self.offset += 1;
+        }
+    }
+}
+
+/// A source that shares a single source between multiple pipelines.
+///
+/// Every batch of the shared source is cloned to all consumers. The consumers
+/// may not get more than `capacity` batches ahead of the slowest consumer,
+/// so the slowest consumer gates the shared source.
+pub(crate) struct MultiplexSource {
+    shared: Arc<(Mutex<SharedSource>, Condvar)>,
+    consumer: usize,
+    capacity: usize,
+}
+
+impl MultiplexSource {
+    pub(crate) fn new_consumers(
+        source: Box<dyn Source>,
+        n_consumers: usize,
+        capacity: usize,
+    ) -> Vec<Box<dyn Source>> {
+        let shared = SharedSource {
+            source: Some(source),
+            buffer: VecDeque::with_capacity(capacity),
+            offset: 0,
+            positions: vec![Some(0); n_consumers],
+            finished: false,
+            failed: false,
+        };
+        let shared = Arc::new((Mutex::new(shared), Condvar::new()));
+        (0..n_consumers)
+            .map(|consumer| {
+                Box::new(MultiplexSource {
+                    shared: shared.clone(),
+                    consumer,
+                    capacity: std::cmp::max(capacity, 1),
+                }) as Box<dyn Source>
+            })
+            .collect()
+    }
+}
+
+impl Source for MultiplexSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let (lock, cvar) = &*self.shared;
+        let mut shared = lock.lock().unwrap();
+        loop {
+            if shared.failed {
+                return Err(polars_err!(
+                    ComputeError: "the shared source of a multiplexed pipeline failed"
+                ));
+            }
+            let position = shared.positions[self.consumer].unwrap();
+
+            // the batch is already read by another consumer
+            if position < shared.offset + shared.buffer.len() {
+                let batch = shared.buffer[position - shared.offset].clone();
+                shared.positions[self.consumer] = Some(position + 1);
+                shared.trim();
+                cvar.notify_all();
+                return Ok(match batch {
+                    Some(chunks) => SourceResult::GotMoreData(chunks),
+                    None => SourceResult::Finished,
+                });
+            }
+            if shared.finished {
+                return Ok(SourceResult::Finished);
+            }
+
+            // we are the fastest consumer, we read the next batch if the
+            // slowest consumer is not too far behind
+            if shared.buffer.len() < self.capacity {
+                if let Some(mut source) = shared.source.take() {
+                    // don't hold the lock during IO, so that slower consumers
+                    // can still read the buffered batches
+                    drop(shared);
+                    let result = source.get_batches(context);
+                    shared = lock.lock().unwrap();
+                    shared.source = Some(source);
+
+                    match result {
+                        Ok(SourceResult::GotMoreData(chunks)) => {
+                            shared.buffer.push_back(Some(chunks))
+                        }
+                        Ok(SourceResult::Finished) => {
+                            shared.buffer.push_back(None);
+                            shared.finished = true;
+                        }
+                        Err(err) => {
+                            shared.failed = true;
+                            cvar.notify_all();
+                            return Err(err);
+                        }
+                    }
+                    cvar.notify_all();
+                    continue;
+                }
+            }
+            shared = cvar.wait(shared).unwrap();
+        }
+    }
+
+    fn fmt(&self) -> &str {
+        "multiplexer"
+    }
+}
+
+impl Drop for MultiplexSource {
+    fn drop(&mut self) {
+        // a finished consumer should not block the others
+        let (lock, cvar) = &*self.shared;
+        if let Ok(mut shared) = lock.lock() {
+            shared.positions[self.consumer] = None;
+            shared.trim();
+        }
+        cvar.notify_all();
+    }
+}
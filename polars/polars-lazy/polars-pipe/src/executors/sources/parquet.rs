@@ -1,13 +1,16 @@
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use polars_core::cloud::CloudOptions;
 use polars_core::error::PolarsResult;
+use polars_core::prelude::Series;
 use polars_core::schema::*;
 use polars_core::POOL;
 use polars_io::parquet::{BatchedParquetReader, ParquetReader};
 #[cfg(feature = "async")]
 use polars_io::prelude::ParquetAsyncReader;
 use polars_io::{is_cloud_url, SerReader};
+use polars_plan::logical_plan::HivePartitions;
 use polars_plan::prelude::ParquetOptions;
 use polars_utils::IdxSize;
 
@@ -18,6 +21,11 @@ pub struct ParquetSource {
     batched_reader: BatchedParquetReader,
     n_threads: usize,
     chunk_index: IdxSize,
+    // the projected columns that have a single value for the whole file, these are
+    // the partition columns of a hive partitioned file and the file path column
+    constant_columns: Vec<Series>,
+    // a column that is only read to know the number of rows
+    dummy_column: Option<String>,
 }
 
 impl ParquetSource {
@@ -27,9 +35,51 @@ impl ParquetSource {
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
         schema: &Schema,
+        output_schema: Option<SchemaRef>,
+        chunk_size: Option<usize>,
         verbose: bool,
     ) -> PolarsResult<Self> {
-        let projection: Option<Vec<_>> = options.with_columns.map(|with_columns| {
+        let hive_partitions = if options.hive_partitioning {
+            HivePartitions::parse_url(&path)
+        } else {
+            None
+        };
+        let mut constant_columns = hive_partitions
+            .map(|hive| hive.materialize_partition_columns())
+            .unwrap_or_default();
+        if let Some(file_path_column) = &options.file_path_column {
+            constant_columns.push(file_path_column.column.clone());
+        }
+        let mut dummy_column = None;
+        let mut with_columns = options.with_columns;
+        if !constant_columns.is_empty() {
+            let constant_schema: Schema = constant_columns
+                .iter()
+                .map(|s| s.field().into_owned())
+                .collect();
+            if let Some(columns) = &with_columns {
+                constant_columns.retain(|s| columns.iter().any(|name| name == s.name()));
+                // the constant columns are not in the file
+                let mut file_columns = columns
+                    .iter()
+                    .filter(|name| !constant_schema.contains(name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if file_columns.is_empty() {
+                    let (name, _) = schema.get_index(0).unwrap();
+                    dummy_column = Some(name.to_string());
+                    file_columns.push(name.to_string());
+                }
+                with_columns = Some(Arc::new(file_columns));
+            }
+            let schema = output_schema.as_deref().unwrap_or(schema);
+            constant_columns = constant_columns
+                .into_iter()
+                .map(|s| s.cast(schema.get(s.name()).unwrap()))
+                .collect::<PolarsResult<_>>()?;
+        }
+
+        let projection: Option<Vec<_>> = with_columns.map(|with_columns| {
             with_columns
                 .iter()
                 .map(|name| schema.index_of(name).unwrap())
@@ -38,7 +88,7 @@ impl ParquetSource {
 
         let n_cols = projection.as_ref().map(|v| v.len()).unwrap_or(schema.len());
         let n_threads = POOL.current_num_threads();
-        let chunk_size = determine_chunk_size(n_cols, n_threads)?;
+        let chunk_size = determine_chunk_size(n_cols, n_threads, chunk_size)?;
 
         if verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
@@ -54,21 +104,30 @@ impl ParquetSource {
             #[cfg(feature = "async")]
             {
                 let uri = path.to_string_lossy();
-                ParquetAsyncReader::from_uri(&uri, cloud_options.as_ref())?
+                let mut reader = ParquetAsyncReader::from_uri(&uri, cloud_options.as_ref())?;
+                if let Some(stats) = options.read_stats {
+                    reader = reader.with_read_stats(stats);
+                }
+                reader
                     .with_n_rows(options.n_rows)
                     .with_row_count(options.row_count)
                     .with_projection(projection)
                     .use_statistics(options.use_statistics)
+                    .with_struct_projection(output_schema)
                     .batched(chunk_size)?
             }
         } else {
             let file = std::fs::File::open(path).unwrap();
-
-            ParquetReader::new(file)
+            let mut reader = ParquetReader::new(file);
+            if let Some(stats) = options.read_stats {
+                reader = reader.with_read_stats(stats);
+            }
+            reader
                 .with_n_rows(options.n_rows)
                 .with_row_count(options.row_count)
                 .with_projection(projection)
                 .use_statistics(options.use_statistics)
+                .with_struct_projection(output_schema)
                 .batched(chunk_size)?
         };
 
@@ -76,6 +135,8 @@ impl ParquetSource {
             batched_reader,
             n_threads,
             chunk_index: 0,
+            constant_columns,
+            dummy_column,
         })
     }
 }
@@ -88,12 +149,19 @@ impl Source for ParquetSource {
             Some(batches) => SourceResult::GotMoreData(
                 batches
                     .into_iter()
-                    .map(|data| {
+                    .map(|mut data| {
+                        let height = data.height();
+                        if let Some(name) = &self.dummy_column {
+                            data = data.drop(name)?;
+                        }
+                        for s in &self.constant_columns {
+                            data.with_column(s.new_from_index(0, height))?;
+                        }
                         let chunk_index = self.chunk_index;
                         self.chunk_index += 1;
-                        DataChunk { chunk_index, data }
+                        Ok(DataChunk { chunk_index, data })
                     })
-                    .collect(),
+                    .collect::<PolarsResult<_>>()?,
             ),
         })
     }
@@ -1,19 +1,80 @@
-use polars_core::error::PolarsResult;
+use polars_core::error::{polars_ensure, PolarsResult};
+use polars_core::POOL;
+use polars_utils::IdxSize;
+use rayon::prelude::*;
 
-use crate::operators::{PExecutionContext, Source, SourceResult};
+use crate::operators::{
+    DataChunk, Operator, OperatorResult, PExecutionContext, Source, SourceResult,
+};
+use crate::pipeline::morsels_per_sink;
 
 pub struct UnionSource {
     sources: Vec<Box<dyn Source>>,
+    // Operators that only apply to the source at the same index, e.g. the predicate of a scan.
+    // Every source has a chain of operators per thread.
+    operators: Vec<Vec<Vec<Box<dyn Operator>>>>,
     source_index: usize,
+    // the sources all start counting at 0, so we must
+    // assign new indexes to keep the order of the union
+    chunk_index: IdxSize,
 }
 
 impl UnionSource {
-    pub(crate) fn new(sources: Vec<Box<dyn Source>>) -> Self {
+    pub(crate) fn new(
+        sources: Vec<Box<dyn Source>>,
+        operators: Vec<Vec<Box<dyn Operator>>>,
+    ) -> Self {
+        debug_assert_eq!(sources.len(), operators.len());
+        let n_threads = morsels_per_sink();
+        let operators = operators
+            .iter()
+            .map(|ops| {
+                (0..n_threads)
+                    .map(|i| ops.iter().map(|op| op.split(i)).collect())
+                    .collect()
+            })
+            .collect();
+
         Self {
             sources,
+            operators,
             source_index: 0,
+            chunk_index: 0,
+        }
+    }
+
+    fn finish_batch(&mut self, mut chunks: Vec<DataChunk>) -> Vec<DataChunk> {
+        for chunk in &mut chunks {
+            chunk.chunk_index = self.chunk_index;
+            self.chunk_index += 1;
+        }
+        chunks
+    }
+}
+
+fn push_operators(
+    context: &PExecutionContext,
+    operators: &mut [Box<dyn Operator>],
+    chunk: DataChunk,
+) -> PolarsResult<Vec<DataChunk>> {
+    let mut out = vec![];
+    let mut in_process = vec![(0usize, chunk)];
+
+    while let Some((op_i, chunk)) = in_process.pop() {
+        match operators.get_mut(op_i) {
+            None => out.push(chunk),
+            Some(op) => match op.execute(context, &chunk)? {
+                OperatorResult::Finished(chunk) => in_process.push((op_i + 1, chunk)),
+                OperatorResult::HaveMoreOutPut(output_chunk) => {
+                    // first on the stack the next operator call
+                    in_process.push((op_i, chunk));
+                    in_process.push((op_i + 1, output_chunk));
+                }
+                OperatorResult::NeedsNewData => {}
+            },
         }
     }
+    Ok(out)
 }
 
 impl Source for UnionSource {
@@ -24,7 +85,29 @@ impl Source for UnionSource {
             let src = &mut self.sources[self.source_index];
             match src.get_batches(context)? {
                 SourceResult::Finished => self.source_index += 1,
-                SourceResult::GotMoreData(chunks) => return Ok(SourceResult::GotMoreData(chunks)),
+                SourceResult::GotMoreData(chunks) => {
+                    let operators = &mut self.operators[self.source_index];
+                    let chunks = if operators.iter().all(|ops| ops.is_empty()) {
+                        chunks
+                    } else {
+                        // every chunk needs its own operators, the sources should not return
+                        // more chunks than there are threads
+                        polars_ensure!(
+                            chunks.len() <= operators.len(),
+                            ComputeError: "union source got {} chunks, but has operators for {}",
+                            chunks.len(), operators.len()
+                        );
+                        let chunks = POOL.install(|| {
+                            chunks
+                                .into_par_iter()
+                                .zip(operators.par_iter_mut())
+                                .map(|(chunk, ops)| push_operators(context, ops, chunk))
+                                .collect::<PolarsResult<Vec<_>>>()
+                        })?;
+                        chunks.into_iter().flatten().collect()
+                    };
+                    return Ok(SourceResult::GotMoreData(self.finish_batch(chunks)));
+                }
             }
         }
         Ok(SourceResult::Finished)
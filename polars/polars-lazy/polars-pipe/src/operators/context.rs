@@ -1,20 +1,50 @@
 use std::any::Any;
 
+use polars_core::error::PolarsResult;
+use polars_core::mem::MemoryLimit;
+
 pub trait SExecutionContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
+
+    /// Soft limit on the memory usage of the query, see [`polars_core::mem`].
+    fn memory_limit(&self) -> Option<&MemoryLimit> {
+        None
+    }
 }
 
 pub struct PExecutionContext {
     // injected upstream in polars-lazy
     pub(crate) execution_state: Box<dyn SExecutionContext>,
     pub(crate) verbose: bool,
+    // chunk size requested by the query, overrides the heuristic
+    pub(crate) chunk_size: Option<usize>,
 }
 
 impl PExecutionContext {
-    pub(crate) fn new(state: Box<dyn SExecutionContext>, verbose: bool) -> Self {
+    pub(crate) fn new(
+        state: Box<dyn SExecutionContext>,
+        verbose: bool,
+        chunk_size: Option<usize>,
+    ) -> Self {
         PExecutionContext {
             execution_state: state,
             verbose,
+            chunk_size,
+        }
+    }
+
+    /// Whether the query uses more memory than its memory limit.
+    pub(crate) fn memory_limit_exceeded(&self) -> bool {
+        self.execution_state
+            .memory_limit()
+            .map_or(false, |limit| limit.exceeded())
+    }
+
+    /// Raise a `ResourceExhausted` error if the query uses more memory than its memory limit.
+    pub(crate) fn check_memory_limit(&self) -> PolarsResult<()> {
+        match self.execution_state.memory_limit() {
+            Some(limit) => limit.check(),
+            None => Ok(()),
         }
     }
 }
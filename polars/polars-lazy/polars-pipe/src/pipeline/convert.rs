@@ -10,7 +10,7 @@ use crate::executors::sinks::*;
 use crate::executors::{operators, sources};
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{Operator, Sink, Source};
-use crate::pipeline::PipeLine;
+use crate::pipeline::{self, PipeLine};
 
 fn exprs_to_physical<F>(
     exprs: &[Node],
@@ -33,6 +33,7 @@ fn get_source<F>(
     expr_arena: &Arena<AExpr>,
     to_physical: &F,
     push_predicate: bool,
+    chunk_size: Option<usize>,
     verbose: bool,
 ) -> PolarsResult<Box<dyn Source>>
 where
@@ -78,7 +79,8 @@ where
                 let op = Box::new(op) as Box<dyn Operator>;
                 operator_objects.push(op)
             }
-            let src = sources::CsvSource::new(path, file_info.schema, options, verbose)?;
+            let src =
+                sources::CsvSource::new(path, file_info.schema, options, chunk_size, verbose)?;
             Ok(Box::new(src) as Box<dyn Source>)
         }
         #[cfg(feature = "parquet")]
@@ -103,10 +105,29 @@ where
                 options,
                 cloud_options,
                 &file_info.schema,
+                output_schema,
+                chunk_size,
                 verbose,
             )?;
             Ok(Box::new(src) as Box<dyn Source>)
         }
+        AnonymousScan {
+            function,
+            options,
+            predicate,
+            output_schema,
+            ..
+        } => {
+            // add predicate to operators
+            if let (true, Some(predicate)) = (push_predicate, predicate) {
+                let predicate = to_physical(predicate, expr_arena, output_schema.as_ref())?;
+                let op = operators::FilterOperator { predicate };
+                let op = Box::new(op) as Box<dyn Operator>;
+                operator_objects.push(op)
+            }
+            let src = sources::AnonymousSource::new(function, options, chunk_size, verbose)?;
+            Ok(Box::new(src) as Box<dyn Source>)
+        }
         _ => todo!(),
     }
 }
@@ -116,6 +137,7 @@ pub fn get_sink<F>(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     to_physical: &F,
+    sort_memory_budget: Option<usize>,
 ) -> PolarsResult<Box<dyn Sink>>
 where
     F: Fn(Node, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -128,14 +150,31 @@ where
             let input_schema = lp_arena.get(*input).schema(lp_arena);
             match &payload.file_type {
                 #[cfg(feature = "parquet")]
-                FileType::Parquet(options) => {
-                    Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                        as Box<dyn Sink>
-                }
+                FileType::Parquet(options) => Box::new(ParquetSink::new(
+                    path,
+                    options.clone(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn Sink>,
+                #[cfg(feature = "parquet")]
+                FileType::PartitionedParquet {
+                    partition_by,
+                    options,
+                } => Box::new(PartitionedParquetSink::new(
+                    path,
+                    partition_by,
+                    options.clone(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn Sink>,
                 #[cfg(feature = "ipc")]
                 FileType::Ipc(options) => {
                     Box::new(IpcSink::new(path, *options, input_schema.as_ref())?) as Box<dyn Sink>
                 }
+                #[cfg(feature = "ipc_streaming")]
+                FileType::IpcStream { writer, options } => Box::new(IpcStreamSink::new(
+                    writer.take()?,
+                    *options,
+                    input_schema.as_ref(),
+                )?) as Box<dyn Sink>,
             }
         }
         Join {
@@ -199,6 +238,7 @@ where
             args,
         } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            let memory_budget = pipeline::sort_memory_budget(sort_memory_budget)?;
 
             if by_column.len() == 1 {
                 let by_column = aexpr_to_leaf_names_iter(by_column[0], expr_arena)
@@ -206,7 +246,7 @@ where
                     .unwrap();
                 let index = input_schema.try_index_of(by_column.as_ref())?;
 
-                let sort_sink = SortSink::new(index, args.clone(), input_schema);
+                let sort_sink = SortSink::new(index, args.clone(), input_schema, memory_budget);
                 Box::new(sort_sink) as Box<dyn Sink>
             } else {
                 let sort_idx = by_column
@@ -217,7 +257,8 @@ where
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
-                let sort_sink = SortSinkMultiple::new(args.clone(), &input_schema, sort_idx);
+                let sort_sink =
+                    SortSinkMultiple::new(args.clone(), &input_schema, sort_idx, memory_budget);
                 Box::new(sort_sink) as Box<dyn Sink>
             }
         }
@@ -447,6 +488,8 @@ pub fn create_pipeline<F>(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     to_physical: F,
+    chunk_size: Option<usize>,
+    sort_memory_budget: Option<usize>,
     verbose: bool,
 ) -> PolarsResult<PipeLine>
 where
@@ -465,6 +508,7 @@ where
                 expr_arena,
                 &to_physical,
                 true,
+                chunk_size,
                 verbose,
             )?,
             #[cfg(feature = "csv")]
@@ -474,6 +518,7 @@ where
                 expr_arena,
                 &to_physical,
                 true,
+                chunk_size,
                 verbose,
             )?,
             #[cfg(feature = "parquet")]
@@ -483,26 +528,57 @@ where
                 expr_arena,
                 &to_physical,
                 true,
+                chunk_size,
+                verbose,
+            )?,
+            lp @ AnonymousScan { .. } => get_source(
+                lp.clone(),
+                &mut operator_objects,
+                expr_arena,
+                &to_physical,
+                true,
+                chunk_size,
                 verbose,
             )?,
             Union { inputs, .. } => {
-                let sources = inputs
-                    .iter()
-                    .enumerate()
-                    .map(|(i, node)| {
-                        let lp = lp_arena.get(*node);
-                        // only push predicate of first source
-                        get_source(
-                            lp.clone(),
-                            &mut operator_objects,
-                            expr_arena,
-                            &to_physical,
-                            i == 0,
-                            i == 0,
-                        )
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()?;
-                Box::new(sources::UnionSource::new(sources)) as Box<dyn Source>
+                let union_schema = lp_arena.get(*node).schema(lp_arena).into_owned();
+                let mut sources = Vec::with_capacity(inputs.len());
+                let mut source_operators = Vec::with_capacity(inputs.len());
+
+                for (i, node) in inputs.iter().enumerate() {
+                    // rechunks are ignored in the streaming engine
+                    let node = match lp_arena.get(*node) {
+                        MapFunction {
+                            input,
+                            function: FunctionNode::Rechunk,
+                        } => *input,
+                        _ => *node,
+                    };
+                    let lp = lp_arena.get(node);
+                    let input_schema = lp.schema(lp_arena).into_owned();
+
+                    // every input gets its own operators (e.g. the predicate of a scan)
+                    // as they are not shared with the other inputs of the union
+                    let mut operators = vec![];
+                    let src = get_source(
+                        lp.clone(),
+                        &mut operators,
+                        expr_arena,
+                        &to_physical,
+                        true,
+                        chunk_size,
+                        i == 0,
+                    )?;
+                    let src = if needs_reproject(&union_schema, &input_schema, i)? {
+                        Box::new(sources::ReProjectSource::new(union_schema.clone(), src))
+                            as Box<dyn Source>
+                    } else {
+                        src
+                    };
+                    sources.push(src);
+                    source_operators.push(operators);
+                }
+                Box::new(sources::UnionSource::new(sources, source_operators)) as Box<dyn Source>
             }
             lp => {
                 panic!("source {lp:?} not (yet) supported")
@@ -521,7 +597,7 @@ where
             Ok((
                 offset + operator_offset,
                 node,
-                get_sink(node, lp_arena, expr_arena, &to_physical)?,
+                get_sink(node, lp_arena, expr_arena, &to_physical, sort_memory_budget)?,
             ))
         })
         .collect::<PolarsResult<Vec<_>>>()?;
@@ -546,13 +622,51 @@ where
         sink_nodes,
         operator_offset,
         verbose,
-    ))
+    )
+    .with_chunk_size(chunk_size))
+}
+
+/// Check if the input of a union must be re-projected to the schema of the union.
+/// This is the case if the input has the same columns, but in a different order.
+fn needs_reproject(
+    union_schema: &Schema,
+    input_schema: &Schema,
+    input_idx: usize,
+) -> PolarsResult<bool> {
+    for (name, dtype) in union_schema.iter() {
+        match input_schema.get(name) {
+            None => polars_bail!(
+                SchemaMismatch: "column '{}' is missing in input {} of the union", name, input_idx
+            ),
+            Some(input_dtype) => polars_ensure!(
+                input_dtype == dtype,
+                SchemaMismatch: "column '{}' has dtype {} in the first input and dtype {} in input {} of the union",
+                name, dtype, input_dtype, input_idx
+            ),
+        }
+    }
+    if let Some(name) = input_schema
+        .iter_names()
+        .find(|name| union_schema.get(name).is_none())
+    {
+        polars_bail!(
+            SchemaMismatch: "column '{}' of input {} is not in the first input of the union", name, input_idx
+        )
+    }
+    Ok(union_schema
+        .iter_names()
+        .zip(input_schema.iter_names())
+        .any(|(l, r)| l != r))
 }
 
 pub fn swap_join_order(options: &JoinOptions) -> bool {
     matches!(options.how, JoinType::Left)
-        || match (options.rows_left, options.rows_right) {
-            ((Some(left), _), (Some(right), _)) => left > right,
-            ((_, left), (_, right)) => left > right,
+        || match options.build_side {
+            JoinBuildSide::Left => false,
+            JoinBuildSide::Right => true,
+            JoinBuildSide::Auto => match (options.rows_left, options.rows_right) {
+                ((Some(left), _), (Some(right), _)) => left > right,
+                ((_, left), (_, right)) => left > right,
+            },
         }
 }
@@ -2,13 +2,14 @@ use std::collections::VecDeque;
 
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
-use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::prelude::polars_ensure;
+use polars_core::utils::{_check_idx_len, accumulate_dataframes_vertical_unchecked};
 use polars_core::POOL;
 use polars_utils::arena::Node;
 use rayon::prelude::*;
 
 use crate::executors::operators::PlaceHolder;
-use crate::executors::sources::DataFrameSource;
+use crate::executors::sources::{DataFrameSource, MultiplexSource};
 use crate::operators::{
     DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext, SExecutionContext, Sink,
     SinkResult, Source, SourceResult,
@@ -32,6 +33,8 @@ pub struct PipeLine {
     // this is a correction as there may be more `operators` than nodes
     // as during construction, source may have inserted operators
     operator_offset: usize,
+    // chunk size requested by the query, `None` means we use the heuristic
+    chunk_size: Option<usize>,
     verbose: bool,
 }
 
@@ -70,10 +73,17 @@ impl PipeLine {
             sink_nodes,
             rh_sides: vec![],
             operator_offset,
+            chunk_size: None,
             verbose,
         }
     }
 
+    /// Set the chunk size that the operators in this pipeline should strive to.
+    pub fn with_chunk_size(mut self, chunk_size: Option<usize>) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
     /// Create a pipeline only consisting of a single branch that always finishes with a sink
     pub fn new_simple(
         sources: Vec<Box<dyn Source>>,
@@ -92,6 +102,29 @@ impl PipeLine {
         )
     }
 
+    /// Let all pipelines read from the source of the first pipeline.
+    ///
+    /// The shared source is read only once and every batch is sent to all pipelines.
+    /// The pipelines can then run concurrently, where the slowest pipeline determines
+    /// the pace at which the source is read.
+    pub fn multiplex_source(pipelines: &mut [PipeLine]) -> PolarsResult<()> {
+        for pipeline in pipelines.iter() {
+            polars_ensure!(
+                pipeline.sources.len() == 1 && pipeline.rh_sides.is_empty(),
+                ComputeError: "can only multiplex pipelines that have a single source"
+            );
+        }
+        if let Some(first) = pipelines.first_mut() {
+            let source = first.sources.pop().unwrap();
+            // allow the fastest pipeline to be 2 batches ahead of the slowest.
+            let consumers = MultiplexSource::new_consumers(source, pipelines.len(), 2);
+            for (pipeline, source) in pipelines.iter_mut().zip(consumers) {
+                pipeline.set_sources(source)
+            }
+        }
+        Ok(())
+    }
+
     /// Add a parent
     /// This should be in the right order
     pub fn with_rhs(mut self, rhs: PipeLine) -> Self {
@@ -218,6 +251,8 @@ impl PipeLine {
                         operator_start,
                         operator_end,
                     )?;
+                    // the sinks that can go out-of-core have spilled if the limit is exceeded
+                    ec.check_memory_limit()?;
 
                     if results
                         .iter()
@@ -282,7 +317,7 @@ impl PipeLine {
     }
 
     pub fn execute(&mut self, state: Box<dyn SExecutionContext>) -> PolarsResult<DataFrame> {
-        let ec = PExecutionContext::new(state, self.verbose);
+        let ec = PExecutionContext::new(state, self.verbose, self.chunk_size);
 
         if self.verbose {
             self.show();
@@ -358,5 +393,6 @@ fn consume_source(src: &mut dyn Source, context: &PExecutionContext) -> PolarsRe
     while let SourceResult::GotMoreData(batch) = src.get_batches(context)? {
         frames.extend(batch.into_iter().map(|chunk| chunk.data))
     }
+    _check_idx_len(frames.iter().map(|df| df.height()).sum())?;
     Ok(accumulate_dataframes_vertical_unchecked(frames))
 }
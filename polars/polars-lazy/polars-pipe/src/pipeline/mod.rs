@@ -20,12 +20,40 @@ pub(crate) const PARTITION_SIZE: usize = 64;
 // env vars
 pub(crate) static FORCE_OOC_GROUPBY: &str = "POLARS_FORCE_OOC_GROUPBY";
 pub(crate) static FORCE_OOC_SORT: &str = "POLARS_FORCE_OOC_SORT";
+pub(crate) static OOC_SORT_MEMORY_BUDGET: &str = "POLARS_OOC_SORT_MEMORY_BUDGET";
+
+/// Memory budget in bytes of the streaming sort before it goes out-of-core.
+/// If `None` the sort will spill based on the available system memory.
+///
+/// A budget set on the query takes precedence over the
+/// `POLARS_OOC_SORT_MEMORY_BUDGET` env var.
+pub(crate) fn sort_memory_budget(budget: Option<usize>) -> PolarsResult<Option<usize>> {
+    if budget.is_some() {
+        return Ok(budget);
+    }
+    match std::env::var(OOC_SORT_MEMORY_BUDGET) {
+        Ok(val) => val.parse().map(Some).map_err(
+            |_| polars_err!(ComputeError: "could not parse '{}' env var", OOC_SORT_MEMORY_BUDGET),
+        ),
+        Err(_) => Ok(None),
+    }
+}
 
 /// ideal chunk size we strive to have
 /// scale the chunk size depending on the number of
 /// columns. With 10 columns we use a chunk size of 40_000
-pub(crate) fn determine_chunk_size(n_cols: usize, n_threads: usize) -> PolarsResult<usize> {
-    if let Ok(val) = std::env::var("POLARS_STREAMING_CHUNK_SIZE") {
+///
+/// A chunk size set on the query takes precedence over the
+/// `POLARS_STREAMING_CHUNK_SIZE` env var and the heuristic.
+pub(crate) fn determine_chunk_size(
+    n_cols: usize,
+    n_threads: usize,
+    chunk_size: Option<usize>,
+) -> PolarsResult<usize> {
+    if let Some(chunk_size) = chunk_size {
+        polars_ensure!(chunk_size > 0, ComputeError: "streaming chunk size must be larger than 0");
+        Ok(chunk_size)
+    } else if let Ok(val) = std::env::var("POLARS_STREAMING_CHUNK_SIZE") {
         val.parse().map_err(
             |_| polars_err!(ComputeError: "could not parse 'POLARS_STREAMING_CHUNK_SIZE' env var"),
         )
@@ -1,25 +1,43 @@
 use super::function_expr::BinaryFunction;
 use super::*;
-/// Specialized expressions for [`Series`] of [`DataType::Utf8`].
+/// Specialized expressions for [`Series`] of [`DataType::Binary`].
 pub struct BinaryNameSpace(pub(crate) Expr);
 
 impl BinaryNameSpace {
-    /// Check if a binary value contains a literal binary.
-    pub fn contains_literal<S: AsRef<[u8]>>(self, pat: S) -> Expr {
-        let pat = pat.as_ref().into();
+    /// Check if a binary value contains a literal binary, or the binary value in the same row
+    /// of `pat`.
+    pub fn contains_literal(self, pat: Expr) -> Expr {
         self.0
-            .map_private(BinaryFunction::Contains { pat, literal: true }.into())
+            .map_many_private(BinaryFunction::Contains.into(), &[pat], true)
     }
 
     /// Check if a binary value ends with the given sequence.
-    pub fn ends_with<S: AsRef<[u8]>>(self, sub: S) -> Expr {
-        let sub = sub.as_ref().into();
-        self.0.map_private(BinaryFunction::EndsWith(sub).into())
+    pub fn ends_with(self, sub: Expr) -> Expr {
+        self.0
+            .map_many_private(BinaryFunction::EndsWith.into(), &[sub], true)
     }
 
     /// Check if a binary value starts with the given sequence.
-    pub fn starts_with<S: AsRef<[u8]>>(self, sub: S) -> Expr {
-        let sub = sub.as_ref().into();
-        self.0.map_private(BinaryFunction::StartsWith(sub).into())
+    pub fn starts_with(self, sub: Expr) -> Expr {
+        self.0
+            .map_many_private(BinaryFunction::StartsWith.into(), &[sub], true)
+    }
+
+    /// Get the size of the binary values in bytes.
+    pub fn size(self) -> Expr {
+        self.0.map_private(BinaryFunction::Size.into())
+    }
+
+    /// Encode the binary values as hexadecimal strings.
+    #[cfg(feature = "binary_encoding")]
+    pub fn to_hex(self) -> Expr {
+        self.0.map_private(BinaryFunction::HexEncode.into())
+    }
+
+    /// Decode binary values holding hexadecimal text. If `strict`, invalid values are an
+    /// error; otherwise they become null.
+    #[cfg(feature = "binary_encoding")]
+    pub fn from_hex(self, strict: bool) -> Expr {
+        self.0.map_private(BinaryFunction::HexDecode(strict).into())
     }
 }
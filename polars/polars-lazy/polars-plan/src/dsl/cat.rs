@@ -3,15 +3,8 @@ use super::*;
 /// Specialized expressions for Categorical dtypes.
 pub struct CategoricalNameSpace(pub(crate) Expr);
 
-#[derive(Copy, Clone, Debug)]
-pub enum CategoricalOrdering {
-    /// Use the physical categories for sorting
-    Physical,
-    /// Use the string value for sorting
-    Lexical,
-}
-
 impl CategoricalNameSpace {
+    /// Set the ordering used by sorting, `min`/`max` and comparisons of the categorical.
     pub fn set_ordering(self, ordering: CategoricalOrdering) -> Expr {
         let lexical = match ordering {
             CategoricalOrdering::Lexical => true,
@@ -165,7 +165,12 @@ where
 }
 
 pub trait FunctionOutputField: Send + Sync {
-    fn get_field(&self, input_schema: &Schema, cntxt: Context, fields: &[Field]) -> Field;
+    fn get_field(
+        &self,
+        input_schema: &Schema,
+        cntxt: Context,
+        fields: &[Field],
+    ) -> PolarsResult<Field>;
 }
 
 pub type GetOutput = SpecialEq<Arc<dyn FunctionOutputField>>;
@@ -173,7 +178,7 @@ pub type GetOutput = SpecialEq<Arc<dyn FunctionOutputField>>;
 impl Default for GetOutput {
     fn default() -> Self {
         SpecialEq::new(Arc::new(
-            |_input_schema: &Schema, _cntxt: Context, fields: &[Field]| fields[0].clone(),
+            |_input_schema: &Schema, _cntxt: Context, fields: &[Field]| Ok(fields[0].clone()),
         ))
     }
 }
@@ -185,11 +190,19 @@ impl GetOutput {
 
     pub fn from_type(dt: DataType) -> Self {
         SpecialEq::new(Arc::new(move |_: &Schema, _: Context, flds: &[Field]| {
-            Field::new(flds[0].name(), dt.clone())
+            Ok(Field::new(flds[0].name(), dt.clone()))
         }))
     }
 
     pub fn map_field<F: 'static + Fn(&Field) -> Field + Send + Sync>(f: F) -> Self {
+        SpecialEq::new(Arc::new(move |_: &Schema, _: Context, flds: &[Field]| {
+            Ok(f(&flds[0]))
+        }))
+    }
+
+    pub fn try_map_field<F: 'static + Fn(&Field) -> PolarsResult<Field> + Send + Sync>(
+        f: F,
+    ) -> Self {
         SpecialEq::new(Arc::new(move |_: &Schema, _: Context, flds: &[Field]| {
             f(&flds[0])
         }))
@@ -197,7 +210,7 @@ impl GetOutput {
 
     pub fn map_fields<F: 'static + Fn(&[Field]) -> Field + Send + Sync>(f: F) -> Self {
         SpecialEq::new(Arc::new(move |_: &Schema, _: Context, flds: &[Field]| {
-            f(flds)
+            Ok(f(flds))
         }))
     }
 
@@ -206,7 +219,7 @@ impl GetOutput {
             let mut fld = flds[0].clone();
             let new_type = f(fld.data_type());
             fld.coerce(new_type);
-            fld
+            Ok(fld)
         }))
     }
 
@@ -236,16 +249,21 @@ impl GetOutput {
             let dtypes = flds.iter().map(|fld| fld.data_type()).collect::<Vec<_>>();
             let new_type = f(&dtypes);
             fld.coerce(new_type);
-            fld
+            Ok(fld)
         }))
     }
 }
 
 impl<F> FunctionOutputField for F
 where
-    F: Fn(&Schema, Context, &[Field]) -> Field + Send + Sync,
+    F: Fn(&Schema, Context, &[Field]) -> PolarsResult<Field> + Send + Sync,
 {
-    fn get_field(&self, input_schema: &Schema, cntxt: Context, fields: &[Field]) -> Field {
+    fn get_field(
+        &self,
+        input_schema: &Schema,
+        cntxt: Context,
+        fields: &[Field],
+    ) -> PolarsResult<Field> {
         self(input_schema, cntxt, fields)
     }
 }
@@ -379,6 +397,8 @@ pub enum Expr {
     Count,
     /// Take the nth column in the `DataFrame`
     Nth(i64),
+    /// A set of columns that is expanded against the schema
+    Selector(Selector),
     // skipped fields must be last otherwise serde fails in pickle
     #[cfg_attr(feature = "serde", serde(skip))]
     RenameAlias {
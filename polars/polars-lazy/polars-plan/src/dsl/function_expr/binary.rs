@@ -6,39 +6,66 @@ use super::*;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum BinaryFunction {
-    Contains { pat: Vec<u8>, literal: bool },
-    StartsWith(Vec<u8>),
-    EndsWith(Vec<u8>),
+    Contains,
+    StartsWith,
+    EndsWith,
+    Size,
+    #[cfg(feature = "binary_encoding")]
+    HexEncode,
+    #[cfg(feature = "binary_encoding")]
+    HexDecode(bool),
 }
 
 impl Display for BinaryFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use BinaryFunction::*;
         let s = match self {
-            Contains { .. } => "contains",
-            StartsWith(_) => "starts_with",
-            EndsWith(_) => "ends_with",
+            Contains => "contains",
+            StartsWith => "starts_with",
+            EndsWith => "ends_with",
+            Size => "size",
+            #[cfg(feature = "binary_encoding")]
+            HexEncode => "to_hex",
+            #[cfg(feature = "binary_encoding")]
+            HexDecode(_) => "from_hex",
         };
         write!(f, "bin.{s}")
     }
 }
 
-pub(super) fn contains(s: &Series, pat: &[u8], literal: bool) -> PolarsResult<Series> {
+pub(super) fn contains(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].binary()?;
+    let lit = s[1].binary()?;
+    ca.contains_chunked(lit).map(|ca| ca.into_series())
+}
+
+pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].binary()?;
+    let sub = s[1].binary()?;
+    ca.ends_with_chunked(sub).map(|ca| ca.into_series())
+}
+
+pub(super) fn starts_with(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].binary()?;
+    let sub = s[1].binary()?;
+    ca.starts_with_chunked(sub).map(|ca| ca.into_series())
+}
+
+pub(super) fn size(s: &Series) -> PolarsResult<Series> {
     let ca = s.binary()?;
-    if literal {
-        ca.contains_literal(pat).map(|ca| ca.into_series())
-    } else {
-        ca.contains(pat).map(|ca| ca.into_series())
-    }
+    Ok(ca.size().into_series())
 }
 
-pub(super) fn ends_with(s: &Series, sub: &[u8]) -> PolarsResult<Series> {
+#[cfg(feature = "binary_encoding")]
+pub(super) fn hex_encode(s: &Series) -> PolarsResult<Series> {
     let ca = s.binary()?;
-    Ok(ca.ends_with(sub).into_series())
+    Ok(ca.hex_encode())
 }
-pub(super) fn starts_with(s: &Series, sub: &[u8]) -> PolarsResult<Series> {
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn hex_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
     let ca = s.binary()?;
-    Ok(ca.starts_with(sub).into_series())
+    ca.hex_decode(strict).map(|ca| ca.into_series())
 }
 
 impl From<BinaryFunction> for FunctionExpr {
@@ -155,5 +155,12 @@ fn is_duplicated(s: &Series) -> PolarsResult<Series> {
 fn is_in(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     let left = &s[0];
     let other = &s[1];
+    // a list is the membership test of its row
+    if matches!(other.dtype(), DataType::List(_)) {
+        polars_ensure!(
+            left.len() == other.len() || left.len() == 1,
+            ShapeMismatch: "`is_in` with a list: expected {} lists, got {}", left.len(), other.len()
+        );
+    }
     left.is_in(other).map(|ca| Some(ca.into_series()))
 }
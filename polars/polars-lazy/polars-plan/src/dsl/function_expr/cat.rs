@@ -9,7 +9,22 @@ pub enum CategoricalFunction {
 
 impl CategoricalFunction {
     pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
-        mapper.with_dtype(DataType::Boolean)
+        use CategoricalFunction::*;
+        match self {
+            SetOrdering { lexical } => {
+                let ordering = if *lexical {
+                    CategoricalOrdering::Lexical
+                } else {
+                    CategoricalOrdering::Physical
+                };
+                mapper.map_dtype(|dtype| match dtype {
+                    DataType::Categorical(rev_map, _) => {
+                        DataType::Categorical(rev_map.clone(), ordering)
+                    }
+                    dtype => dtype.clone(),
+                })
+            }
+        }
     }
 }
 
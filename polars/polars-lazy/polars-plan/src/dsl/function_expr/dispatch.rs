@@ -22,7 +22,64 @@ pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsRes
 pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResult<Series> {
     Ok(polars_ops::prelude::interpolate(s, method))
 }
+
+#[cfg(feature = "interpolate")]
+pub(super) fn interpolate_by(s: &[Series], method: InterpolationMethod) -> PolarsResult<Series> {
+    polars_ops::prelude::interpolate_by(&s[0], &s[1], method)
+}
+#[cfg(feature = "top_k")]
+pub(super) fn arg_partition(
+    s: &Series,
+    k: usize,
+    descending: bool,
+    sorted: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::arg_partition(s, k, descending, sorted).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "scatter")]
+pub(super) fn scatter(s: &[Series]) -> PolarsResult<Series> {
+    let idx = s[1].cast(&IDX_DTYPE)?;
+    polars_ops::prelude::scatter(&s[0], idx.idx()?, &s[2])
+}
+
 #[cfg(feature = "dot_product")]
 pub(super) fn dot_impl(s: &[Series]) -> PolarsResult<Series> {
-    Ok((&s[0] * &s[1]).sum_as_series())
+    (&s[0] * &s[1]).sum_as_series()
+}
+
+#[cfg(feature = "cut_qcut")]
+pub(super) fn cut(
+    s: &Series,
+    breaks: Vec<f64>,
+    labels: Option<Vec<String>>,
+    left_closed: bool,
+    include_breaks: bool,
+    outer_bins: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::cut(s, breaks, labels, left_closed, include_breaks, outer_bins)
+}
+
+#[cfg(feature = "cut_qcut")]
+pub(super) fn qcut(
+    s: &Series,
+    probs: Vec<f64>,
+    labels: Option<Vec<String>>,
+    left_closed: bool,
+    allow_duplicates: bool,
+    include_breaks: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::qcut(
+        s,
+        probs,
+        labels,
+        left_closed,
+        allow_duplicates,
+        include_breaks,
+    )
+}
+
+#[cfg(feature = "json_encode")]
+pub(super) fn json_encode(s: &Series, encode_nulls: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::json_encode(s, encode_nulls).map(|ca| ca.into_series())
 }
@@ -35,7 +35,7 @@ pub(super) fn fill_null(s: &[Series], super_type: &DataType) -> PolarsResult<Ser
     match series.dtype() {
         #[cfg(feature = "dtype-categorical")]
         // for Categoricals we first need to check if the category already exist
-        DataType::Categorical(Some(rev_map)) => {
+        DataType::Categorical(Some(rev_map), _) => {
             if fill_value.len() == 1 && fill_value.null_count() == 0 {
                 let fill_av = fill_value.get(0).unwrap();
                 let fill_str = fill_av.get_str().unwrap();
@@ -57,14 +57,26 @@ pub(super) fn fill_null(s: &[Series], super_type: &DataType) -> PolarsResult<Ser
 
 pub(super) fn coalesce(s: &mut [Series]) -> PolarsResult<Series> {
     polars_ensure!(!s.is_empty(), NoData: "cannot coalesce empty list");
-    let mut out = s[0].clone();
-    for s in s {
-        if !out.null_count() == 0 {
-            return Ok(out);
+    let len = s.iter().map(|s| s.len()).max().unwrap();
+    let broadcast = |s: &Series| {
+        if s.len() == len {
+            Ok(s.clone())
         } else {
-            let mask = out.is_not_null();
-            out = out.zip_with_same_type(&mask, s)?;
+            polars_ensure!(
+                s.len() == 1,
+                ShapeMismatch: "`coalesce` expected inputs of length {} or 1, got {}", len, s.len()
+            );
+            Ok(s.new_from_index(0, len))
         }
+    };
+    let mut out = broadcast(&s[0])?;
+    for s in &s[1..] {
+        // nothing left to fill
+        if out.null_count() == 0 {
+            break;
+        }
+        let mask = out.is_not_null();
+        out = out.zip_with_same_type(&mask, &broadcast(s)?)?;
     }
     Ok(out)
 }
@@ -43,10 +43,26 @@ pub(super) fn contains(args: &mut [Series]) -> PolarsResult<Option<Series>> {
     let list = &args[0];
     let is_in = &args[1];
 
-    is_in.is_in(list).map(|mut ca| {
-        ca.rename(list.name());
-        Some(ca.into_series())
-    })
+    let out = is_in.is_in(list)?;
+    // `is_in` is null for a null item or a null list, but `contains` tests whether a list
+    // contains a null item, and a null list contains nothing.
+    let has_nulls: BooleanChunked = list
+        .list()?
+        .amortized_iter()
+        .map(|opt_s| opt_s.map(|s| s.as_ref().null_count() > 0) == Some(true))
+        .collect_trusted();
+    let has_nulls = if has_nulls.len() == 1 && out.len() != 1 {
+        has_nulls.new_from_index(0, out.len())
+    } else {
+        has_nulls
+    };
+    let mut ca: BooleanChunked = out
+        .into_iter()
+        .zip(has_nulls.into_no_null_iter())
+        .map(|(contains, has_nulls)| contains.unwrap_or(has_nulls))
+        .collect_trusted();
+    ca.rename(list.name());
+    Ok(Some(ca.into_series()))
 }
 
 fn check_slice_arg_shape(slice_len: usize, ca_len: usize, name: &str) -> PolarsResult<()> {
@@ -83,8 +99,9 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
             let length_ca = length_s.cast(&DataType::Int64)?;
             let length_ca = length_ca.i64().unwrap();
 
-            list_ca
-                .amortized_iter()
+            // Safety:
+            // the items are only used to compute the output of their iteration
+            unsafe { list_ca.amortized_iter() }
                 .zip(length_ca.into_iter())
                 .map(|(opt_s, opt_length)| match (opt_s, opt_length) {
                     (Some(s), Some(length)) => Some(s.as_ref().slice(offset, length as usize)),
@@ -101,8 +118,9 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
                 .unwrap_or(usize::MAX);
             let offset_ca = offset_s.cast(&DataType::Int64)?;
             let offset_ca = offset_ca.i64().unwrap();
-            list_ca
-                .amortized_iter()
+            // Safety:
+            // the items are only used to compute the output of their iteration
+            unsafe { list_ca.amortized_iter() }
                 .zip(offset_ca)
                 .map(|(opt_s, opt_offset)| match (opt_s, opt_offset) {
                     (Some(s), Some(offset)) => Some(s.as_ref().slice(offset, length_slice)),
@@ -120,8 +138,9 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
             let length_ca = length_s.cast(&DataType::Int64)?;
             let length_ca = length_ca.i64().unwrap();
 
-            list_ca
-                .amortized_iter()
+            // Safety:
+            // the items are only used to compute the output of their iteration
+            unsafe { list_ca.amortized_iter() }
                 .zip(offset_ca.into_iter())
                 .zip(length_ca.into_iter())
                 .map(
@@ -239,5 +258,5 @@ pub(super) fn count_match(args: &[Series]) -> PolarsResult<Series> {
 }
 
 pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
-    Ok(s.list()?.lst_sum())
+    s.list()?.lst_sum()
 }
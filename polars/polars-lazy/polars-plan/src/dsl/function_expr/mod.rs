@@ -19,6 +19,7 @@ mod list;
 mod log;
 mod nan;
 mod pow;
+mod registry;
 #[cfg(all(feature = "rolling_window", feature = "moment"))]
 mod rolling;
 #[cfg(feature = "round_series")]
@@ -56,10 +57,11 @@ pub use self::boolean::BooleanFunction;
 pub(crate) use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub(super) use self::datetime::TemporalFunction;
+pub use self::registry::{call_expr_fn, register_expr_fn, unregister_expr_fn, RegisteredFn};
 #[cfg(feature = "strings")]
 pub(crate) use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
-pub(super) use self::struct_::StructFunction;
+pub(crate) use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub(super) use self::trigonometry::TrigonometricFunction;
 use super::*;
@@ -73,10 +75,35 @@ pub enum FunctionExpr {
     Pow,
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "row_hash")]
+    HashStable(u64),
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "search_sorted")]
-    SearchSorted(SearchSortedSide),
+    SearchSorted {
+        side: SearchSortedSide,
+        descending: Option<bool>,
+    },
+    #[cfg(feature = "cut_qcut")]
+    Cut {
+        breaks: Vec<f64>,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        include_breaks: bool,
+        outer_bins: bool,
+    },
+    #[cfg(feature = "cut_qcut")]
+    QCut {
+        probs: Vec<f64>,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        allow_duplicates: bool,
+        include_breaks: bool,
+    },
+    #[cfg(feature = "json_encode")]
+    JsonEncode {
+        encode_nulls: bool,
+    },
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     BinaryExpr(BinaryFunction),
@@ -103,8 +130,8 @@ pub enum FunctionExpr {
     DropNans,
     #[cfg(feature = "round_series")]
     Clip {
-        min: Option<AnyValue<'static>>,
-        max: Option<AnyValue<'static>>,
+        has_min: bool,
+        has_max: bool,
     },
     ListExpr(ListFunction),
     #[cfg(feature = "dtype-struct")]
@@ -114,6 +141,19 @@ pub enum FunctionExpr {
         k: usize,
         descending: bool,
     },
+    #[cfg(feature = "top_k")]
+    TopKBy {
+        k: usize,
+        descending: bool,
+    },
+    #[cfg(feature = "top_k")]
+    ArgPartition {
+        k: usize,
+        descending: bool,
+        sorted: bool,
+    },
+    #[cfg(feature = "scatter")]
+    Scatter,
     Shift(i64),
     Cumcount {
         reverse: bool,
@@ -137,11 +177,15 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     Coalesce,
-    ShrinkType,
+    ShrinkType {
+        shrink_floats: bool,
+    },
     #[cfg(feature = "diff")]
     Diff(i64, NullBehavior),
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
+    #[cfg(feature = "interpolate")]
+    InterpolateBy(InterpolationMethod),
     #[cfg(feature = "dot_product")]
     Dot,
     #[cfg(feature = "log")]
@@ -168,6 +212,7 @@ pub enum FunctionExpr {
     Ceil,
     UpperBound,
     LowerBound,
+    Registered(RegisteredFn),
 }
 
 impl Display for FunctionExpr {
@@ -181,10 +226,18 @@ impl Display for FunctionExpr {
             Pow => "pow",
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            HashStable(_) => "hash_stable",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "search_sorted")]
-            SearchSorted(_) => "search_sorted",
+            SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "cut_qcut")]
+            Cut { .. } => "cut",
+            #[cfg(feature = "cut_qcut")]
+            QCut { .. } => "qcut",
+            #[cfg(feature = "json_encode")]
+            JsonEncode { .. } => "json_encode",
             #[cfg(feature = "strings")]
             StringExpr(s) => return write!(f, "{s}"),
             BinaryExpr(b) => return write!(f, "{b}"),
@@ -202,10 +255,10 @@ impl Display for FunctionExpr {
             ShiftAndFill { .. } => "shift_and_fill",
             DropNans => "drop_nans",
             #[cfg(feature = "round_series")]
-            Clip { min, max } => match (min, max) {
-                (Some(_), Some(_)) => "clip",
-                (None, Some(_)) => "clip_max",
-                (Some(_), None) => "clip_min",
+            Clip { has_min, has_max } => match (has_min, has_max) {
+                (true, true) => "clip",
+                (false, true) => "clip_max",
+                (true, false) => "clip_min",
                 _ => unreachable!(),
             },
             ListExpr(func) => return write!(f, "{func}"),
@@ -213,6 +266,12 @@ impl Display for FunctionExpr {
             StructExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "top_k")]
             TopK { .. } => "top_k",
+            #[cfg(feature = "top_k")]
+            TopKBy { .. } => "top_k_by",
+            #[cfg(feature = "top_k")]
+            ArgPartition { .. } => "arg_partition",
+            #[cfg(feature = "scatter")]
+            Scatter => "scatter",
             Shift(_) => "shift",
             Cumcount { .. } => "cumcount",
             Cumsum { .. } => "cumsum",
@@ -226,11 +285,13 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             Coalesce => "coalesce",
-            ShrinkType => "shrink_dtype",
+            ShrinkType { .. } => "shrink_dtype",
             #[cfg(feature = "diff")]
             Diff(_, _) => "diff",
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
+            #[cfg(feature = "interpolate")]
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "dot_product")]
             Dot => "dot",
             #[cfg(feature = "log")]
@@ -256,6 +317,7 @@ impl Display for FunctionExpr {
             Ceil => "ceil",
             UpperBound => "upper_bound",
             LowerBound => "lower_bound",
+            Registered(func) => return write!(f, "{}", func.name()),
         };
         write!(f, "{s}")
     }
@@ -354,14 +416,50 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             }
+            #[cfg(feature = "row_hash")]
+            HashStable(seed) => {
+                map!(row_hash::hash_stable, seed)
+            }
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
             }
             #[cfg(feature = "search_sorted")]
-            SearchSorted(side) => {
-                map_as_slice!(search_sorted::search_sorted_impl, side)
+            SearchSorted { side, descending } => {
+                map_as_slice!(search_sorted::search_sorted_impl, side, descending)
             }
+            #[cfg(feature = "cut_qcut")]
+            Cut {
+                breaks,
+                labels,
+                left_closed,
+                include_breaks,
+                outer_bins,
+            } => map!(
+                dispatch::cut,
+                breaks.clone(),
+                labels.clone(),
+                left_closed,
+                include_breaks,
+                outer_bins
+            ),
+            #[cfg(feature = "cut_qcut")]
+            QCut {
+                probs,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+            } => map!(
+                dispatch::qcut,
+                probs.clone(),
+                labels.clone(),
+                left_closed,
+                allow_duplicates,
+                include_breaks
+            ),
+            #[cfg(feature = "json_encode")]
+            JsonEncode { encode_nulls } => map!(dispatch::json_encode, encode_nulls),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.into(),
             BinaryExpr(s) => s.into(),
@@ -393,8 +491,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             }
             DropNans => map_owned!(nan::drop_nans),
             #[cfg(feature = "round_series")]
-            Clip { min, max } => {
-                map_owned!(clip::clip, min.clone(), max.clone())
+            Clip { has_min, has_max } => {
+                map_as_slice!(clip::clip, has_min, has_max)
             }
             ListExpr(lf) => {
                 use ListFunction::*;
@@ -417,12 +515,27 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 match sf {
                     FieldByIndex(index) => map!(struct_::get_by_index, index),
                     FieldByName(name) => map!(struct_::get_by_name, name.clone()),
+                    MultipleFields(_) => map!(struct_::multiple_fields),
                 }
             }
             #[cfg(feature = "top_k")]
             TopK { k, descending } => {
                 map!(top_k, k, descending)
             }
+            #[cfg(feature = "top_k")]
+            TopKBy { k, descending } => {
+                map_as_slice!(top_k_by, k, descending)
+            }
+            #[cfg(feature = "top_k")]
+            ArgPartition {
+                k,
+                descending,
+                sorted,
+            } => {
+                map!(dispatch::arg_partition, k, descending, sorted)
+            }
+            #[cfg(feature = "scatter")]
+            Scatter => map_as_slice!(dispatch::scatter),
             Shift(periods) => map!(dispatch::shift, periods),
             Cumcount { reverse } => map!(cum::cumcount, reverse),
             Cumsum { reverse } => map!(cum::cumsum, reverse),
@@ -436,13 +549,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             Coalesce => map_as_slice!(fill_null::coalesce),
-            ShrinkType => map_owned!(shrink_type::shrink),
+            ShrinkType { shrink_floats } => map_owned!(shrink_type::shrink, shrink_floats),
             #[cfg(feature = "diff")]
             Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => {
                 map!(dispatch::interpolate, method)
             }
+            #[cfg(feature = "interpolate")]
+            InterpolateBy(method) => {
+                map_as_slice!(dispatch::interpolate_by, method)
+            }
             #[cfg(feature = "dot_product")]
             Dot => {
                 map_as_slice!(dispatch::dot_impl)
@@ -464,6 +581,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Ceil => map!(round::ceil),
             UpperBound => map!(bounds::upper_bound),
             LowerBound => map!(bounds::lower_bound),
+            Registered(func) => {
+                let f = move |s: &mut [Series]| func.call(s).map(Some);
+                wrap!(f)
+            }
         }
     }
 }
@@ -516,6 +637,7 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "string_from_radix")]
             FromRadix(radix, strict) => map!(strings::from_radix, radix, strict),
             Slice(start, length) => map!(strings::str_slice, start, length),
+            NChars => map!(strings::n_chars),
         }
     }
 }
@@ -524,15 +646,14 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
     fn from(func: BinaryFunction) -> Self {
         use BinaryFunction::*;
         match func {
-            Contains { pat, literal } => {
-                map!(binary::contains, &pat, literal)
-            }
-            EndsWith(sub) => {
-                map!(binary::ends_with, &sub)
-            }
-            StartsWith(sub) => {
-                map!(binary::starts_with, &sub)
-            }
+            Contains => map_as_slice!(binary::contains),
+            EndsWith => map_as_slice!(binary::ends_with),
+            StartsWith => map_as_slice!(binary::starts_with),
+            Size => map!(binary::size),
+            #[cfg(feature = "binary_encoding")]
+            HexEncode => map!(binary::hex_encode),
+            #[cfg(feature = "binary_encoding")]
+            HexDecode(strict) => map!(binary::hex_decode, strict),
         }
     }
 }
@@ -0,0 +1,132 @@
+//! Expression functions that are registered at runtime under a name.
+//!
+//! Plans refer to a registered function by its name, so that they can be serialized and
+//! deserialized again by a process that registered the same function, e.g. from a plugin.
+use std::fmt::{Debug, Formatter};
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::*;
+
+type ExprFn = dyn Fn(&mut [Series]) -> PolarsResult<Series> + Send + Sync;
+type OutputDtypeFn = dyn Fn(&[Field]) -> PolarsResult<DataType> + Send + Sync;
+
+static REGISTRY: Lazy<RwLock<PlHashMap<String, RegisteredFn>>> = Lazy::new(Default::default);
+
+/// A function of the expression registry, see [`register_expr_fn`].
+#[derive(Clone)]
+pub struct RegisteredFn {
+    name: Arc<str>,
+    function: Arc<ExprFn>,
+    output_dtype: Arc<OutputDtypeFn>,
+}
+
+impl RegisteredFn {
+    /// Look up the function that is registered under `name`.
+    pub fn get(name: &str) -> PolarsResult<Self> {
+        REGISTRY.read().unwrap().get(name).cloned().ok_or_else(|| {
+            polars_err!(
+                ComputeError: "no expression function is registered under the name '{}'", name
+            )
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(super) fn call(&self, s: &mut [Series]) -> PolarsResult<Series> {
+        (self.function)(s)
+    }
+
+    pub(super) fn output_dtype(&self, fields: &[Field]) -> PolarsResult<DataType> {
+        (self.output_dtype)(fields)
+    }
+}
+
+impl PartialEq for RegisteredFn {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && Arc::ptr_eq(&self.function, &other.function)
+            && Arc::ptr_eq(&self.output_dtype, &other.output_dtype)
+    }
+}
+
+impl Debug for RegisteredFn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RegisteredFn({})", self.name)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for RegisteredFn {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.name)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RegisteredFn {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        RegisteredFn::get(&name).map_err(de::Error::custom)
+    }
+}
+
+/// Register an expression function under `name`, replacing the function that was registered
+/// under that name before.
+///
+/// `function` receives the evaluated inputs of [`call_expr_fn`] and `output_dtype` maps the
+/// fields of the inputs to the dtype of the output. The output is named after the first input.
+pub fn register_expr_fn<F, O>(name: &str, function: F, output_dtype: O)
+where
+    F: Fn(&mut [Series]) -> PolarsResult<Series> + Send + Sync + 'static,
+    O: Fn(&[Field]) -> PolarsResult<DataType> + Send + Sync + 'static,
+{
+    let registered = RegisteredFn {
+        name: Arc::from(name),
+        function: Arc::new(function),
+        output_dtype: Arc::new(output_dtype),
+    };
+    REGISTRY
+        .write()
+        .unwrap()
+        .insert(name.to_string(), registered);
+}
+
+/// Remove the expression function that is registered under `name`. Returns whether a function
+/// was registered.
+///
+/// Expressions that already call the function keep it.
+pub fn unregister_expr_fn(name: &str) -> bool {
+    REGISTRY.write().unwrap().remove(name).is_some()
+}
+
+/// Call the expression function that is registered under `name` on `inputs`.
+///
+/// The function is applied per group in a groupby context. Fails if no function is registered
+/// under `name` or if `inputs` is empty.
+pub fn call_expr_fn<E: AsRef<[Expr]>>(name: &str, inputs: E) -> PolarsResult<Expr> {
+    let input = inputs.as_ref().to_vec();
+    polars_ensure!(
+        !input.is_empty(),
+        ComputeError: "expression function '{}' needs at least one input", name
+    );
+    Ok(Expr::Function {
+        input,
+        function: FunctionExpr::Registered(RegisteredFn::get(name)?),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyGroups,
+            ..Default::default()
+        },
+    })
+}
@@ -4,3 +4,7 @@ pub(super) fn row_hash(s: &Series, k0: u64, k1: u64, k2: u64, k3: u64) -> Polars
     Ok(s.hash(ahash::RandomState::with_seeds(k0, k1, k2, k3))
         .into_series())
 }
+
+pub(super) fn hash_stable(s: &Series, seed: u64) -> PolarsResult<Series> {
+    polars_ops::prelude::hash_stable(s, seed).map(|ca| ca.into_series())
+}
@@ -17,19 +17,36 @@ impl FunctionExpr {
             Pow => mapper.map_to_float_dtype(),
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
-            Hash(..) => mapper.with_dtype(DataType::UInt64),
+            Hash(..) | HashStable(_) => mapper.with_dtype(DataType::UInt64),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
-            SearchSorted(_) => mapper.with_dtype(IDX_DTYPE),
+            SearchSorted { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "cut_qcut")]
+            Cut { include_breaks, .. } | QCut { include_breaks, .. } => {
+                let cat = DataType::Categorical(None, Default::default());
+                if *include_breaks {
+                    mapper.with_dtype(DataType::Struct(vec![
+                        Field::new(CUT_BREAK_POINT, DataType::Float64),
+                        Field::new(CUT_CATEGORY, cat),
+                    ]))
+                } else {
+                    mapper.with_dtype(cat)
+                }
+            }
+            #[cfg(feature = "json_encode")]
+            JsonEncode { .. } => mapper.with_dtype(DataType::Utf8),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             BinaryExpr(s) => {
                 use BinaryFunction::*;
                 match s {
-                    Contains { .. } | EndsWith(_) | StartsWith(_) => {
-                        mapper.with_dtype(DataType::Boolean)
-                    }
+                    Contains | EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
+                    Size => mapper.with_dtype(DataType::UInt32),
+                    #[cfg(feature = "binary_encoding")]
+                    HexEncode => mapper.with_dtype(DataType::Utf8),
+                    #[cfg(feature = "binary_encoding")]
+                    HexDecode(_) => mapper.with_dtype(DataType::Binary),
                 }
             }
             #[cfg(feature = "temporal")]
@@ -79,7 +96,7 @@ impl FunctionExpr {
             ShiftAndFill { .. } => mapper.with_same_dtype(),
             DropNans => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
-            Clip { .. } => mapper.with_same_dtype(),
+            Clip { .. } => mapper.map_to_supertype(),
             ListExpr(l) => {
                 use ListFunction::*;
                 match l {
@@ -130,10 +147,20 @@ impl FunctionExpr {
                             polars_bail!(StructFieldNotFound: "{}", name.as_ref());
                         }
                     }
+                    MultipleFields(_) => polars_bail!(
+                        ComputeError:
+                        "selecting multiple struct fields is only supported in `select`, `with_columns` and `agg`"
+                    ),
                 }
             }
             #[cfg(feature = "top_k")]
             TopK { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "top_k")]
+            TopKBy { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "top_k")]
+            ArgPartition { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "scatter")]
+            Scatter => mapper.try_map_dtypes(|dtypes| try_get_supertype(dtypes[0], dtypes[2])),
             Shift(..) | Reverse => mapper.with_same_dtype(),
             Boolean(func) => func.get_field(mapper),
             #[cfg(feature = "dtype-categorical")]
@@ -160,7 +187,9 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "interpolate")]
             Interpolate(_) => mapper.with_same_dtype(),
-            ShrinkType => {
+            #[cfg(feature = "interpolate")]
+            InterpolateBy(_) => mapper.map_to_float_dtype(),
+            ShrinkType { shrink_floats } => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
                 // comes in, but if we set the smallest datatype
@@ -170,13 +199,19 @@ impl FunctionExpr {
                 // but we because only the numeric types deviate in
                 // bit size this will likely not lead to issues
                 mapper.map_dtype(|dt| {
-                    if dt.is_numeric() {
-                        if dt.is_float() {
-                            DataType::Float32
-                        } else if dt.is_unsigned() {
-                            DataType::Int8
+                    if dt.is_float() {
+                        // shrunk floats may become integers, so not
+                        // even the kind of the dtype is known upfront
+                        if *shrink_floats {
+                            DataType::Unknown
                         } else {
+                            dt.clone()
+                        }
+                    } else if dt.is_integer() {
+                        if dt.is_unsigned() {
                             DataType::UInt8
+                        } else {
+                            DataType::Int8
                         }
                     } else {
                         dt.clone()
@@ -197,6 +232,7 @@ impl FunctionExpr {
             #[cfg(feature = "round_series")]
             Round { .. } | Floor | Ceil => mapper.with_same_dtype(),
             UpperBound | LowerBound => mapper.with_same_dtype(),
+            Registered(func) => mapper.with_dtype(func.output_dtype(fields)?),
         }
     }
 }
@@ -2,9 +2,16 @@ use polars_ops::prelude::search_sorted;
 
 use super::*;
 
-pub(super) fn search_sorted_impl(s: &mut [Series], side: SearchSortedSide) -> PolarsResult<Series> {
+pub(super) fn search_sorted_impl(
+    s: &mut [Series],
+    side: SearchSortedSide,
+    descending: Option<bool>,
+) -> PolarsResult<Series> {
     let sorted_array = &s[0];
     let search_value = &s[1];
+    // if not given, the order is taken from the sorted flag
+    let descending =
+        descending.unwrap_or_else(|| sorted_array.is_sorted_flag() == IsSorted::Descending);
 
-    search_sorted(sorted_array, search_value, side, false).map(|ca| ca.into_series())
+    search_sorted(sorted_array, search_value, side, descending).map(|ca| ca.into_series())
 }
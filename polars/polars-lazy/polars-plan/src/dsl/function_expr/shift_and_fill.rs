@@ -91,7 +91,7 @@ pub(super) fn shift_and_fill(args: &mut [Series], periods: i64) -> PolarsResult<
         #[cfg(feature = "dtype-struct")]
         Struct(_) => shift_and_fill_with_mask(s, periods, fill_value_s),
         #[cfg(feature = "dtype-categorical")]
-        Categorical(_) => shift_and_fill_with_mask(s, periods, fill_value_s),
+        Categorical(_, _) => shift_and_fill_with_mask(s, periods, fill_value_s),
         dt if dt.is_numeric() || dt.is_logical() => {
             macro_rules! dispatch {
                 ($ca:expr, $periods:expr, $fill_value:expr) => {{
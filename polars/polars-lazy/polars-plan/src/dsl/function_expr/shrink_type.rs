@@ -1,34 +1,80 @@
 use super::*;
 
-pub(super) fn shrink(s: Series) -> PolarsResult<Series> {
-    if s.dtype().is_numeric() {
-        if s.dtype().is_float() {
-            s.cast(&DataType::Float32)
-        } else if s.dtype().is_unsigned() {
-            let max = s.max_as_series().get(0).unwrap().extract::<u64>().unwrap();
-            if max <= u8::MAX as u64 {
-                s.cast(&DataType::UInt8)
-            } else if max <= u16::MAX as u64 {
-                s.cast(&DataType::UInt16)
-            } else if max <= u32::MAX as u64 {
-                s.cast(&DataType::UInt32)
-            } else {
-                Ok(s)
-            }
+fn bit_width(dtype: &DataType) -> usize {
+    use DataType::*;
+    match dtype {
+        Int8 | UInt8 => 8,
+        Int16 | UInt16 => 16,
+        Int32 | UInt32 | Float32 => 32,
+        _ => 64,
+    }
+}
+
+/// The smallest integer dtype that fits `min..=max`, of the same signedness as `dtype`.
+fn smallest_int_dtype(min: f64, max: f64, signed: bool) -> DataType {
+    use DataType::*;
+    if !signed {
+        if max <= u8::MAX as f64 {
+            UInt8
+        } else if max <= u16::MAX as f64 {
+            UInt16
+        } else if max <= u32::MAX as f64 {
+            UInt32
         } else {
-            let min = s.min_as_series().get(0).unwrap().extract::<i64>().unwrap();
-            let max = s.max_as_series().get(0).unwrap().extract::<i64>().unwrap();
+            UInt64
+        }
+    } else if min >= i8::MIN as f64 && max <= i8::MAX as f64 {
+        Int8
+    } else if min >= i16::MIN as f64 && max <= i16::MAX as f64 {
+        Int16
+    } else if min >= i32::MIN as f64 && max <= i32::MAX as f64 {
+        Int32
+    } else {
+        Int64
+    }
+}
+
+/// The smallest dtype that represents all values of a float column exactly: an integer dtype
+/// if all values are integral, otherwise `Float32` if all values survive the round trip.
+fn smallest_float_dtype(s: &Series) -> PolarsResult<DataType> {
+    let s = s.cast(&DataType::Float64)?;
+    let ca = s.f64()?;
+    let integral = ca
+        .into_iter()
+        .flatten()
+        .all(|v| v.is_finite() && v.fract() == 0.0 && v.abs() <= i64::MAX as f64);
+    if integral {
+        return Ok(smallest_int_dtype(
+            ca.min().unwrap(),
+            ca.max().unwrap(),
+            true,
+        ));
+    }
+    let exact = ca
+        .into_iter()
+        .flatten()
+        .all(|v| v.is_nan() || v as f32 as f64 == v);
+    Ok(if exact {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    })
+}
 
-            if min >= i8::MIN as i64 && max <= i8::MAX as i64 {
-                s.cast(&DataType::Int8)
-            } else if min >= i16::MIN as i64 && max <= i16::MAX as i64 {
-                s.cast(&DataType::Int16)
-            } else if min >= i32::MIN as i64 && max <= i32::MAX as i64 {
-                s.cast(&DataType::Int32)
-            } else {
-                Ok(s)
-            }
+pub(super) fn shrink(s: Series, shrink_floats: bool) -> PolarsResult<Series> {
+    // nothing to inspect
+    if s.null_count() == s.len() {
+        return Ok(s);
+    }
+    let dtype = match s.dtype() {
+        DataType::Float32 | DataType::Float64 if shrink_floats => smallest_float_dtype(&s)?,
+        dt if dt.is_integer() => {
+            smallest_int_dtype(s.min().unwrap(), s.max().unwrap(), dt.is_signed())
         }
+        _ => return Ok(s),
+    };
+    if bit_width(&dtype) < bit_width(s.dtype()) {
+        s.cast(&dtype)
     } else {
         Ok(s)
     }
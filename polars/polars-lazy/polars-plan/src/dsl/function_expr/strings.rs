@@ -63,6 +63,7 @@ pub enum StringFunction {
     #[cfg(feature = "string_from_radix")]
     FromRadix(u32, bool),
     Slice(i64, Option<u64>),
+    NChars,
 }
 
 impl StringFunction {
@@ -88,6 +89,7 @@ impl StringFunction {
             }
             #[cfg(feature = "string_from_radix")]
             FromRadix { .. } => mapper.with_dtype(DataType::Int32),
+            NChars => mapper.with_dtype(DataType::UInt32),
         }
     }
 }
@@ -125,6 +127,7 @@ impl Display for StringFunction {
             #[cfg(feature = "string_from_radix")]
             StringFunction::FromRadix { .. } => "from_radix",
             StringFunction::Slice(_, _) => "str_slice",
+            StringFunction::NChars => "n_chars",
         };
 
         write!(f, "str.{s}")
@@ -602,3 +605,8 @@ pub(super) fn str_slice(s: &Series, start: i64, length: Option<u64>) -> PolarsRe
     let ca = s.utf8()?;
     ca.str_slice(start, length).map(|ca| ca.into_series())
 }
+
+pub(super) fn n_chars(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.str_n_chars().into_series())
+}
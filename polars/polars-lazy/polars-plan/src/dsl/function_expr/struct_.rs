@@ -7,6 +7,9 @@ use super::*;
 pub enum StructFunction {
     FieldByIndex(i64),
     FieldByName(Arc<str>),
+    /// Multiple names, a `"*"` wildcard or `^regex$` patterns. This is expanded to
+    /// [`StructFunction::FieldByName`] expressions during projection rewriting.
+    MultipleFields(Arc<[Arc<str>]>),
 }
 
 impl Display for StructFunction {
@@ -15,6 +18,7 @@ impl Display for StructFunction {
         match self {
             StructFunction::FieldByIndex(_) => write!(f, "struct.field_by_name"),
             StructFunction::FieldByName(_) => write!(f, "struct.field_by_index"),
+            StructFunction::MultipleFields(_) => write!(f, "struct.field_by_names"),
         }
     }
 }
@@ -31,3 +35,10 @@ pub(super) fn get_by_name(s: &Series, name: Arc<str>) -> PolarsResult<Series> {
     let ca = s.struct_()?;
     ca.field_by_name(name.as_ref())
 }
+
+pub(super) fn multiple_fields(_s: &Series) -> PolarsResult<Series> {
+    polars_bail!(
+        ComputeError:
+        "selecting multiple struct fields is only supported in `select`, `with_columns` and `agg`"
+    )
+}
@@ -4,12 +4,13 @@
 //!
 use std::ops::{BitAnd, BitOr};
 
+#[cfg(feature = "arange")]
+use polars_arrow::trusted_len::TrustedLen;
 #[cfg(feature = "temporal")]
 use polars_core::export::arrow::temporal_conversions::NANOSECONDS;
 #[cfg(feature = "temporal")]
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
-#[cfg(feature = "dtype-struct")]
-use polars_core::utils::get_supertype;
+use polars_core::utils::{get_supertype, try_get_supertype};
 
 #[cfg(feature = "arg_where")]
 use crate::dsl::function_expr::FunctionExpr;
@@ -19,47 +20,56 @@ use crate::dsl::function_expr::StringFunction;
 use crate::dsl::*;
 use crate::prelude::*;
 
+/// Cast both sides of a binary statistic to their supertype.
+fn cast_to_supertype(a: &Series, b: &Series) -> PolarsResult<(Series, Series)> {
+    let st = try_get_supertype(a.dtype(), b.dtype())?;
+    Ok((a.cast(&st)?, b.cast(&st)?))
+}
+
 /// Compute the covariance between two columns.
-pub fn cov(a: Expr, b: Expr) -> Expr {
+///
+/// Rows where either side is null are excluded and `ddof` is the delta degrees of freedom.
+pub fn cov(a: Expr, b: Expr, ddof: u8) -> Expr {
     let name = "cov";
     let function = move |a: Series, b: Series| {
+        let (a, b) = cast_to_supertype(&a, &b)?;
         let s = match a.dtype() {
             DataType::Float32 => {
                 let ca_a = a.f32().unwrap();
                 let ca_b = b.f32().unwrap();
-                Series::new(name, &[polars_core::functions::cov_f(ca_a, ca_b)])
+                Series::new(name, &[polars_core::functions::cov_f(ca_a, ca_b, ddof)])
             }
             DataType::Float64 => {
                 let ca_a = a.f64().unwrap();
                 let ca_b = b.f64().unwrap();
-                Series::new(name, &[polars_core::functions::cov_f(ca_a, ca_b)])
+                Series::new(name, &[polars_core::functions::cov_f(ca_a, ca_b, ddof)])
             }
             DataType::Int32 => {
                 let ca_a = a.i32().unwrap();
                 let ca_b = b.i32().unwrap();
-                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b)])
+                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b, ddof)])
             }
             DataType::Int64 => {
                 let ca_a = a.i64().unwrap();
                 let ca_b = b.i64().unwrap();
-                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b)])
+                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b, ddof)])
             }
             DataType::UInt32 => {
                 let ca_a = a.u32().unwrap();
                 let ca_b = b.u32().unwrap();
-                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b)])
+                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b, ddof)])
             }
             DataType::UInt64 => {
                 let ca_a = a.u64().unwrap();
                 let ca_b = b.u64().unwrap();
-                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b)])
+                Series::new(name, &[polars_core::functions::cov_i(ca_a, ca_b, ddof)])
             }
             _ => {
                 let a = a.cast(&DataType::Float64)?;
                 let b = b.cast(&DataType::Float64)?;
                 let ca_a = a.f64().unwrap();
                 let ca_b = b.f64().unwrap();
-                Series::new(name, &[polars_core::functions::cov_f(ca_a, ca_b)])
+                Series::new(name, &[polars_core::functions::cov_f(ca_a, ca_b, ddof)])
             }
         };
         Ok(Some(s))
@@ -84,9 +94,12 @@ pub fn cov(a: Expr, b: Expr) -> Expr {
 }
 
 /// Compute the pearson correlation between two columns.
+///
+/// Rows where either side is null are excluded and `ddof` is the delta degrees of freedom.
 pub fn pearson_corr(a: Expr, b: Expr, ddof: u8) -> Expr {
     let name = "pearson_corr";
     let function = move |a: Series, b: Series| {
+        let (a, b) = cast_to_supertype(&a, &b)?;
         let s = match a.dtype() {
             DataType::Float32 => {
                 let ca_a = a.f32().unwrap();
@@ -204,26 +217,19 @@ pub fn spearman_rank_corr(a: Expr, b: Expr, ddof: u8, propagate_nans: bool) -> E
         let a = a.drop_nulls();
         let b = b.drop_nulls();
 
-        let a_idx = a.rank(
-            RankOptions {
-                method: RankMethod::Min,
-                ..Default::default()
-            },
-            None,
-        );
-        let b_idx = b.rank(
-            RankOptions {
-                method: RankMethod::Min,
-                ..Default::default()
-            },
-            None,
-        );
-        let a_idx = a_idx.idx().unwrap();
-        let b_idx = b_idx.idx().unwrap();
+        // ties get their average rank
+        let options = RankOptions {
+            method: RankMethod::Average,
+            ..Default::default()
+        };
+        let a_rank = a.rank(options, None).cast(&DataType::Float64)?;
+        let b_rank = b.rank(options, None).cast(&DataType::Float64)?;
+        let a_rank = a_rank.f64().unwrap();
+        let b_rank = b_rank.f64().unwrap();
 
         Ok(Some(Series::new(
             name,
-            &[polars_core::functions::pearson_corr_i(a_idx, b_idx, ddof)],
+            &[polars_core::functions::pearson_corr_f(a_rank, b_rank, ddof)],
         )))
     };
 
@@ -468,6 +474,134 @@ pub fn arange(start: Expr, end: Expr, step: i64) -> Expr {
     }
 }
 
+/// Number of values in the range `start..end` with the given (non-zero) `step`.
+#[cfg(feature = "arange")]
+fn int_range_len(start: i64, end: i64, step: i64) -> usize {
+    let (start, end, step) = (start as i128, end as i128, step as i128);
+    let span = if step > 0 { end - start } else { start - end };
+    let step = step.abs();
+    if span <= 0 {
+        0
+    } else {
+        ((span + step - 1) / step) as usize
+    }
+}
+
+#[cfg(feature = "arange")]
+fn int_range_iter(start: i64, end: i64, step: i64) -> impl Iterator<Item = i64> + TrustedLen {
+    let len = int_range_len(start, end, step);
+    (0..len).map(move |i| start.wrapping_add((i as i64).wrapping_mul(step)))
+}
+
+/// Create a range of integers from `start` (inclusive) to `end` (exclusive) with the
+/// given `step`, cast to `dtype`.
+///
+/// `start` and `end` must evaluate to a single value; the output has the length of the
+/// range. A `step` of zero or a value that doesn't fit in `dtype` raises an error and a
+/// negative `step` creates a decreasing range.
+#[cfg(feature = "arange")]
+pub fn int_range(start: Expr, end: Expr, step: i64, dtype: DataType) -> Expr {
+    let output_dtype = dtype.clone();
+    let f = move |start: Series, end: Series| {
+        polars_ensure!(step != 0, InvalidOperation: "`int_range` step must not be zero");
+        polars_ensure!(
+            dtype.is_integer(),
+            InvalidOperation: "`int_range` expected an integer dtype, got {}", dtype
+        );
+        polars_ensure!(
+            start.len() == 1 && end.len() == 1,
+            ComputeError:
+            "`int_range` expected `start` and `end` of length 1, got {} and {}",
+            start.len(), end.len()
+        );
+        let start = start.strict_cast(&DataType::Int64)?;
+        let end = end.strict_cast(&DataType::Int64)?;
+        let (start, end) = match (start.i64()?.get(0), end.i64()?.get(0)) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return Ok(Some(Series::full_null("int_range", 1, &dtype))),
+        };
+        let mut ca = Int64Chunked::from_iter_values("int_range", int_range_iter(start, end, step));
+        ca.set_sorted_flag(if step > 0 {
+            IsSorted::Ascending
+        } else {
+            IsSorted::Descending
+        });
+        ca.into_series().strict_cast(&dtype).map(Some)
+    };
+    apply_binary(
+        start,
+        end,
+        f,
+        GetOutput::map_field(move |_| Field::new("int_range", output_dtype.clone())),
+    )
+}
+
+/// Create a list of integers per row, ranging from `start` (inclusive) to `end`
+/// (exclusive) with the given `step`.
+///
+/// `start` and `end` are broadcast if they have length 1. Rows where the range is empty
+/// give an empty list and rows with a null bound give a null. A `step` of zero raises an
+/// error and a negative `step` creates decreasing ranges.
+#[cfg(feature = "arange")]
+pub fn int_ranges(start: Expr, end: Expr, step: i64) -> Expr {
+    let f = move |start: Series, end: Series| {
+        polars_ensure!(step != 0, InvalidOperation: "`int_ranges` step must not be zero");
+        let mut start = start.strict_cast(&DataType::Int64)?;
+        let mut end = end.strict_cast(&DataType::Int64)?;
+        if start.len() != end.len() {
+            if start.len() == 1 {
+                start = start.new_from_index(0, end.len())
+            } else if end.len() == 1 {
+                end = end.new_from_index(0, start.len())
+            } else {
+                polars_bail!(
+                    ShapeMismatch:
+                    "lengths of `start`: {} and `end`: {} cannot be matched in `int_ranges`",
+                    start.len(), end.len()
+                );
+            }
+        }
+        let start = start.i64()?;
+        let end = end.i64()?;
+
+        // compute the number of values up front, so that the builder doesn't reallocate
+        let values_capacity = start
+            .into_iter()
+            .zip(end.into_iter())
+            .try_fold(0usize, |acc, bounds| match bounds {
+                (Some(start), Some(end)) => acc.checked_add(int_range_len(start, end, step)),
+                _ => Some(acc),
+            })
+            .ok_or_else(
+                || polars_err!(ComputeError: "`int_ranges` would produce too many values"),
+            )?;
+
+        let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new(
+            "int_range",
+            start.len(),
+            values_capacity,
+            DataType::Int64,
+        );
+        for bounds in start.into_iter().zip(end.into_iter()) {
+            match bounds {
+                (Some(start), Some(end)) => {
+                    builder.append_iter_values(int_range_iter(start, end, step))
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(Some(builder.finish().into_series()))
+    };
+    apply_binary(
+        start,
+        end,
+        f,
+        GetOutput::map_field(|_| {
+            Field::new("int_range", DataType::List(Box::new(DataType::Int64)))
+        }),
+    )
+}
+
 macro_rules! impl_unit_setter {
     ($fn_name:ident($field:ident)) => {
         #[doc = concat!("Set the ", stringify!($field))]
@@ -1049,6 +1183,12 @@ where
     let exprs = exprs.as_ref().to_vec();
 
     let function = SpecialEq::new(Arc::new(move |series: &mut [Series]| {
+        // cast to the supertype, as promised by the output schema
+        let series = if series.is_empty() {
+            vec![]
+        } else {
+            horizontal_inputs(series, "cumreduce", |dt| dt)?
+        };
         let mut s_iter = series.iter();
 
         match s_iter.next() {
@@ -1178,6 +1318,191 @@ pub fn min_exprs<E: AsRef<[Expr]>>(exprs: E) -> Expr {
     reduce_exprs(func, exprs).alias("min")
 }
 
+/// Cast `series` to `map_dtype` of their supertype and broadcast unit length series to the
+/// longest one.
+fn horizontal_inputs(
+    series: &[Series],
+    name: &str,
+    map_dtype: fn(DataType) -> DataType,
+) -> PolarsResult<Vec<Series>> {
+    polars_ensure!(
+        !series.is_empty(),
+        ComputeError: "`{}` expected at least one input column", name
+    );
+    let mut st = series[0].dtype().clone();
+    for s in &series[1..] {
+        st = try_get_supertype(&st, s.dtype())?;
+    }
+    let dtype = map_dtype(st);
+    let len = series.iter().map(|s| s.len()).max().unwrap();
+    series
+        .iter()
+        .map(|s| {
+            let s = s.cast(&dtype)?;
+            if s.len() == len {
+                Ok(s)
+            } else {
+                polars_ensure!(
+                    s.len() == 1,
+                    ShapeMismatch: "`{}` expected inputs of length {} or 1, got {}", name, len, s.len()
+                );
+                Ok(s.new_from_index(0, len))
+            }
+        })
+        .collect()
+}
+
+/// The output field of a horizontal function: `map_dtype` of the supertype of its inputs.
+fn horizontal_output(name: &'static str, map_dtype: fn(DataType) -> DataType) -> GetOutput {
+    GetOutput::map_fields(move |fields| {
+        let dtype = match fields.split_first() {
+            Some((first, rest)) => {
+                let mut st = first.data_type().clone();
+                for fld in rest {
+                    st = get_supertype(&st, fld.data_type()).unwrap_or(DataType::Unknown);
+                }
+                map_dtype(st)
+            }
+            None => DataType::Null,
+        };
+        Field::new(name, dtype)
+    })
+}
+
+/// Combine `acc` and `s` with the binary kernel `f`.
+///
+/// If `ignore_nulls` is set a null in either side is skipped, so the output is only null if
+/// both sides are null. Otherwise a null in either side makes the output null.
+fn horizontal_combine<F>(
+    acc: &Series,
+    s: &Series,
+    f: &F,
+    ignore_nulls: bool,
+) -> PolarsResult<Series>
+where
+    F: Fn(&Series, &Series) -> PolarsResult<Series>,
+{
+    let out = f(acc, s)?;
+    if ignore_nulls {
+        let out = out.zip_with(&s.is_not_null(), acc)?;
+        s.zip_with(&acc.is_null(), &out)
+    } else {
+        let valid = &acc.is_not_null() & &s.is_not_null();
+        out.zip_with(
+            &valid,
+            &Series::full_null(out.name(), out.len(), out.dtype()),
+        )
+    }
+}
+
+/// Reduce the input columns row wise with repeated applications of the binary kernel `f`.
+fn horizontal_reduce<F, E>(
+    exprs: E,
+    name: &'static str,
+    f: F,
+    ignore_nulls: bool,
+    map_dtype: fn(DataType) -> DataType,
+) -> Expr
+where
+    F: Fn(&Series, &Series) -> PolarsResult<Series> + Send + Sync + 'static,
+    E: AsRef<[Expr]>,
+{
+    let function = SpecialEq::new(Arc::new(move |series: &mut [Series]| {
+        let series = horizontal_inputs(series, name, map_dtype)?;
+        let mut acc = series[0].clone();
+        for s in &series[1..] {
+            acc = horizontal_combine(&acc, s, &f, ignore_nulls)?;
+        }
+        Ok(Some(acc))
+    }) as Arc<dyn SeriesUdf>);
+
+    Expr::AnonymousFunction {
+        input: exprs.as_ref().to_vec(),
+        function,
+        output_type: horizontal_output(name, map_dtype),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyFlat,
+            input_wildcard_expansion: true,
+            fmt_str: name,
+            ..Default::default()
+        },
+    }
+    .alias(name)
+}
+
+/// Create a new column with the sum of the values in each row.
+///
+/// The inputs are cast to their supertype and booleans are summed as integers. If
+/// `ignore_nulls` is set nulls are skipped, otherwise a null makes the sum of that row
+/// null. Zero input columns raise an error.
+pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Expr {
+    let map_dtype = |dt: DataType| match dt {
+        DataType::Boolean => IDX_DTYPE,
+        dt => dt,
+    };
+    horizontal_reduce(exprs, "sum", |a, b| a.try_add(b), ignore_nulls, map_dtype)
+}
+
+/// Create a new column with the minimum value of each row.
+///
+/// The inputs are cast to their supertype. If `ignore_nulls` is set nulls are skipped,
+/// otherwise a null makes the minimum of that row null. Zero input columns raise an error.
+pub fn min_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Expr {
+    let f = |acc: &Series, s: &Series| acc.zip_with(&acc.lt_eq(s)?, s);
+    horizontal_reduce(exprs, "min", f, ignore_nulls, |dt| dt)
+}
+
+/// Create a new column with the maximum value of each row.
+///
+/// The inputs are cast to their supertype. If `ignore_nulls` is set nulls are skipped,
+/// otherwise a null makes the maximum of that row null. Zero input columns raise an error.
+pub fn max_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Expr {
+    let f = |acc: &Series, s: &Series| acc.zip_with(&acc.gt_eq(s)?, s);
+    horizontal_reduce(exprs, "max", f, ignore_nulls, |dt| dt)
+}
+
+/// Create a new column with the mean of the values in each row.
+///
+/// If `ignore_nulls` is set the mean is taken over the non-null values of each row and
+/// is only null if all values are null. Otherwise a null makes the mean of that row null.
+/// Zero input columns raise an error.
+pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Expr {
+    let map_dtype = |dt: DataType| match dt {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let function = SpecialEq::new(Arc::new(move |series: &mut [Series]| {
+        let series = horizontal_inputs(series, "mean", map_dtype)?;
+        let to_count = |s: &Series| s.is_not_null().into_series().cast(s.dtype());
+        let mut sum = series[0].clone();
+        let mut count = to_count(&sum)?;
+        for s in &series[1..] {
+            sum = horizontal_combine(
+                &sum,
+                s,
+                &|a: &Series, b: &Series| a.try_add(b),
+                ignore_nulls,
+            )?;
+            count = count.try_add(&to_count(s)?)?;
+        }
+        // rows without any valid value have a null sum, so the division doesn't produce NaN
+        Ok(Some(&sum / &count))
+    }) as Arc<dyn SeriesUdf>);
+
+    Expr::AnonymousFunction {
+        input: exprs.as_ref().to_vec(),
+        function,
+        output_type: horizontal_output("mean", map_dtype),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyFlat,
+            input_wildcard_expansion: true,
+            fmt_str: "mean",
+            ..Default::default()
+        },
+    }
+    .alias("mean")
+}
+
 /// Create a new column with the the bitwise-or of the elements in each row.
 ///
 /// The name of the resulting column is arbitrary; use [`alias`](Expr::alias) to choose a different name.
@@ -1297,7 +1622,8 @@ pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
 
 /// Folds the expressions from left to right keeping the first non-null values.
 ///
-/// It is an error to provide an empty `exprs`.
+/// The inputs are cast to their supertype and inputs of length 1, such as literals, are
+/// broadcast. It is an error to provide an empty `exprs`.
 pub fn coalesce(exprs: &[Expr]) -> Expr {
     let input = exprs.to_vec();
     Expr::Function {
@@ -55,7 +55,7 @@ impl MetaNameSpace {
 
     pub fn has_multiple_outputs(&self) -> bool {
         self.0.into_iter().any(|e| match e {
-            Expr::Wildcard | Expr::Columns(_) | Expr::DtypeColumn(_) => true,
+            Expr::Wildcard | Expr::Columns(_) | Expr::DtypeColumn(_) | Expr::Selector(_) => true,
             Expr::Column(name) => is_regex_projection(name),
             _ => false,
         })
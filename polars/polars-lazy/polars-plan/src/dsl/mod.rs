@@ -16,8 +16,10 @@ pub mod functions;
 mod list;
 #[cfg(feature = "meta")]
 mod meta;
+mod name;
 pub(crate) mod names;
 mod options;
+mod selector;
 #[cfg(feature = "strings")]
 pub mod string;
 #[cfg(feature = "dtype-struct")]
@@ -39,6 +41,7 @@ use polars_core::series::IsSorted;
 use polars_core::utils::{try_get_supertype, NoNull};
 #[cfg(feature = "rolling_window")]
 use polars_time::series::SeriesOpsTime;
+pub use selector::*;
 
 use crate::constants::MAP_LIST_NAME;
 pub use crate::logical_plan::lit;
@@ -546,11 +549,34 @@ impl Expr {
 
     #[cfg(feature = "search_sorted")]
     /// Find indices where elements should be inserted to maintain order.
+    ///
+    /// Whether this expression is sorted in descending order is taken from its sorted flag.
     pub fn search_sorted<E: Into<Expr>>(self, element: E, side: SearchSortedSide) -> Expr {
-        let element = element.into();
+        self.search_sorted_impl(element.into(), side, None)
+    }
+
+    #[cfg(feature = "search_sorted")]
+    /// Find indices where elements should be inserted to maintain order,
+    /// with this expression sorted in `descending` order.
+    pub fn search_sorted_descending<E: Into<Expr>>(
+        self,
+        element: E,
+        side: SearchSortedSide,
+        descending: bool,
+    ) -> Expr {
+        self.search_sorted_impl(element.into(), side, Some(descending))
+    }
+
+    #[cfg(feature = "search_sorted")]
+    fn search_sorted_impl(
+        self,
+        element: Expr,
+        side: SearchSortedSide,
+        descending: Option<bool>,
+    ) -> Expr {
         Expr::Function {
             input: vec![self, element],
-            function: FunctionExpr::SearchSorted(side),
+            function: FunctionExpr::SearchSorted { side, descending },
             options: FunctionOptions {
                 collect_groups: ApplyOptions::ApplyGroups,
                 auto_explode: true,
@@ -561,6 +587,81 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "cut_qcut")]
+    /// Bin the values into the intervals defined by `breaks`.
+    ///
+    /// Returns a `Categorical` column, or a `Struct` of the upper break point and the bin
+    /// if `include_breaks` is set. Bins are right-closed `(a, b]` unless `left_closed` is set.
+    /// If `outer_bins` is set, values outside the outer breaks are assigned to the
+    /// `(-inf, first]` and `(last, inf]` bins, otherwise they are null.
+    /// NaN and null values are null.
+    pub fn cut(
+        self,
+        breaks: Vec<f64>,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        include_breaks: bool,
+        outer_bins: bool,
+    ) -> Expr {
+        self.map_private(FunctionExpr::Cut {
+            breaks,
+            labels,
+            left_closed,
+            include_breaks,
+            outer_bins,
+        })
+    }
+
+    #[cfg(feature = "cut_qcut")]
+    /// Bin the values into intervals whose breaks are the `probs` quantiles of the values.
+    ///
+    /// Duplicate breaks (from ties in the data) are dropped if `allow_duplicates` is set,
+    /// otherwise they raise an error. In a groupby or window context the quantiles are
+    /// computed per group.
+    pub fn qcut(
+        self,
+        probs: Vec<f64>,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        allow_duplicates: bool,
+        include_breaks: bool,
+    ) -> Expr {
+        self.apply_private(FunctionExpr::QCut {
+            probs,
+            labels,
+            left_closed,
+            allow_duplicates,
+            include_breaks,
+        })
+    }
+
+    #[cfg(feature = "cut_qcut")]
+    /// Bin the values into `n_bins` bins with (about) the same number of values.
+    ///
+    /// See [`Expr::qcut`].
+    pub fn qcut_uniform(
+        self,
+        n_bins: usize,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        allow_duplicates: bool,
+        include_breaks: bool,
+    ) -> Expr {
+        let probs = (1..n_bins).map(|i| i as f64 / n_bins as f64).collect();
+        self.qcut(probs, labels, left_closed, allow_duplicates, include_breaks)
+    }
+
+    #[cfg(feature = "json_encode")]
+    /// Serialize every value to a JSON string. Works for all dtypes, including nested
+    /// `List` and `Struct` values.
+    ///
+    /// Temporal values are serialized as ISO-8601 strings and binary values as base64.
+    /// Null rows are null, unless `encode_nulls` is set, in which case they are the
+    /// string `null`.
+    pub fn json_encode(self, encode_nulls: bool) -> Expr {
+        self.map_private(FunctionExpr::JsonEncode { encode_nulls })
+    }
+
     /// Cast expression to another data type.
     /// Throws an error if conversion had overflows
     pub fn strict_cast(self, data_type: DataType) -> Self {
@@ -607,7 +708,7 @@ impl Expr {
         }
     }
 
-    /// Returns the `k` largest elements.
+    /// Returns the `k` largest elements. Nulls are ignored.
     ///
     /// This has time complexity `O(n + k log(n))`.
     #[cfg(feature = "top_k")]
@@ -618,7 +719,7 @@ impl Expr {
         })
     }
 
-    /// Returns the `k` smallest elements.
+    /// Returns the `k` smallest elements. Nulls are ignored.
     ///
     /// This has time complexity `O(n + k log(n))`.
     #[cfg(feature = "top_k")]
@@ -629,6 +730,64 @@ impl Expr {
         })
     }
 
+    /// Returns the values of this expression in the rows of the `k` largest values of `by`,
+    /// ordered by `by` from largest to smallest.
+    ///
+    /// Rows where `by` is null are ignored.
+    #[cfg(feature = "top_k")]
+    pub fn top_k_by(self, k: usize, by: Expr) -> Self {
+        self.apply_many_private(
+            FunctionExpr::TopKBy {
+                k,
+                descending: false,
+            },
+            &[by],
+            false,
+            false,
+        )
+    }
+
+    /// Returns the values of this expression in the rows of the `k` smallest values of `by`,
+    /// ordered by `by` from smallest to largest.
+    ///
+    /// Rows where `by` is null are ignored.
+    #[cfg(feature = "top_k")]
+    pub fn bottom_k_by(self, k: usize, by: Expr) -> Self {
+        self.apply_many_private(
+            FunctionExpr::TopKBy {
+                k,
+                descending: true,
+            },
+            &[by],
+            false,
+            false,
+        )
+    }
+
+    /// Returns the indices of the `k` smallest elements, or of the `k` largest if
+    /// `descending`. Nulls are ignored.
+    ///
+    /// This avoids a full argsort; the indices are in arbitrary order unless `sorted` is set.
+    /// In a groupby context this gives a list of indices per group.
+    #[cfg(feature = "top_k")]
+    pub fn arg_partition(self, k: usize, descending: bool, sorted: bool) -> Self {
+        self.apply_private(FunctionExpr::ArgPartition {
+            k,
+            descending,
+            sorted,
+        })
+    }
+
+    /// Replace the values at the positions in `idx` by `values`, which is broadcast if it has
+    /// length 1.
+    ///
+    /// The output has the supertype of this expression and `values`. Out of bounds indices
+    /// are an error.
+    #[cfg(feature = "scatter")]
+    pub fn scatter(self, idx: Expr, values: Expr) -> Self {
+        self.map_many_private(FunctionExpr::Scatter, &[idx, values], false)
+    }
+
     /// Reverse column
     pub fn reverse(self) -> Self {
         self.apply_private(FunctionExpr::Reverse)
@@ -808,6 +967,81 @@ impl Expr {
         }
     }
 
+    /// Declare the output dtype of a [`map`](Expr::map) or [`apply`](Expr::apply) closure.
+    ///
+    /// The schema uses `dtype` instead of the `GetOutput` of the closure. The output of the
+    /// closure is checked against `dtype` when it runs and an error naming this expression
+    /// is raised if they differ. On other expressions an error is raised when they run.
+    pub fn returns(self, dtype: DataType) -> Self {
+        self.with_declared_output(Some(dtype), false)
+    }
+
+    /// Declare that an [`apply`](Expr::apply) closure returns a single value per group.
+    ///
+    /// In an aggregation the values are not wrapped in a list. The output of the closure is
+    /// checked when it runs and an error naming this expression is raised if it is not of
+    /// length 1.
+    pub fn returns_scalar(self) -> Self {
+        self.with_declared_output(None, true)
+    }
+
+    fn with_declared_output(self, dtype: Option<DataType>, returns_scalar: bool) -> Self {
+        let expr = self.clone();
+        match self {
+            Self::AnonymousFunction {
+                input,
+                function,
+                output_type,
+                mut options,
+            } => {
+                let output_type = match &dtype {
+                    Some(dtype) => GetOutput::from_type(dtype.clone()),
+                    None => output_type,
+                };
+                options.auto_explode |= returns_scalar;
+
+                let f = move |s: &mut [Series]| {
+                    let out = function.call_udf(s)?;
+                    if let Some(out) = &out {
+                        if let Some(dtype) = &dtype {
+                            polars_ensure!(
+                                out.dtype() == dtype, expr = expr, SchemaMismatch:
+                                "function was declared to return dtype {} but returned {}",
+                                dtype, out.dtype()
+                            );
+                        }
+                        polars_ensure!(
+                            !returns_scalar || out.len() == 1, expr = expr, ShapeMismatch:
+                            "function was declared to return a scalar but returned {} values",
+                            out.len()
+                        );
+                    }
+                    Ok(out)
+                };
+                Self::AnonymousFunction {
+                    input,
+                    function: SpecialEq::new(Arc::new(f)),
+                    output_type,
+                    options,
+                }
+            }
+            Self::Alias(expr, name) => Self::Alias(
+                Box::new((*expr).with_declared_output(dtype, returns_scalar)),
+                name,
+            ),
+            // an expression that raises the error when it runs
+            expr => expr.map(
+                |_| {
+                    Err(polars_err!(
+                        InvalidOperation:
+                        "the output can only be declared on a `map` or `apply` expression"
+                    ))
+                },
+                GetOutput::same_type(),
+            ),
+        }
+    }
+
     pub fn apply_many_private(
         self,
         function_expr: FunctionExpr,
@@ -957,6 +1191,18 @@ impl Expr {
         .with_fmt("forward_fill")
     }
 
+    /// Fill missing values with the given strategy.
+    ///
+    /// In a group context, e.g. in `over()`, every group is filled separately, so forward and
+    /// backward fills don't carry values across groups and their `limit` restarts per group.
+    pub fn fill_null_with_strategy(self, strategy: FillNullStrategy) -> Self {
+        self.apply(
+            move |s: Series| s.fill_null(strategy).map(Some),
+            GetOutput::same_type(),
+        )
+        .with_fmt("fill_null_with_strategy")
+    }
+
     /// Round underlying floating point array to given decimal numbers.
     #[cfg(feature = "round_series")]
     pub fn round(self, decimals: u32) -> Self {
@@ -975,31 +1221,47 @@ impl Expr {
         self.map_private(FunctionExpr::Ceil)
     }
 
-    /// Clip underlying values to a set boundary.
+    /// Clip underlying values between `min` and `max`.
+    ///
+    /// The bounds can be literals or expressions that are evaluated per row; a null bound
+    /// means that row is not bounded on that side. The output has the supertype of the
+    /// values and the bounds.
     #[cfg(feature = "round_series")]
-    pub fn clip(self, min: AnyValue<'_>, max: AnyValue<'_>) -> Self {
-        self.map_private(FunctionExpr::Clip {
-            min: Some(min.into_static().unwrap()),
-            max: Some(max.into_static().unwrap()),
-        })
+    pub fn clip<E: Into<Expr>>(self, min: E, max: E) -> Self {
+        self.map_many_private(
+            FunctionExpr::Clip {
+                has_min: true,
+                has_max: true,
+            },
+            &[min.into(), max.into()],
+            true,
+        )
     }
 
-    /// Clip underlying values to a set boundary.
+    /// Clip underlying values to be at most `max`. See [`Expr::clip`].
     #[cfg(feature = "round_series")]
-    pub fn clip_max(self, max: AnyValue<'_>) -> Self {
-        self.map_private(FunctionExpr::Clip {
-            min: None,
-            max: Some(max.into_static().unwrap()),
-        })
+    pub fn clip_max<E: Into<Expr>>(self, max: E) -> Self {
+        self.map_many_private(
+            FunctionExpr::Clip {
+                has_min: false,
+                has_max: true,
+            },
+            &[max.into()],
+            true,
+        )
     }
 
-    /// Clip underlying values to a set boundary.
+    /// Clip underlying values to be at least `min`. See [`Expr::clip`].
     #[cfg(feature = "round_series")]
-    pub fn clip_min(self, min: AnyValue<'_>) -> Self {
-        self.map_private(FunctionExpr::Clip {
-            min: Some(min.into_static().unwrap()),
-            max: None,
-        })
+    pub fn clip_min<E: Into<Expr>>(self, min: E) -> Self {
+        self.map_many_private(
+            FunctionExpr::Clip {
+                has_min: true,
+                has_max: false,
+            },
+            &[min.into()],
+            true,
+        )
     }
 
     /// Convert all values to their absolute/positive value.
@@ -1220,7 +1482,7 @@ impl Expr {
             let by = &s[1];
             let s = &s[0];
             let by = by.cast(&IDX_DTYPE)?;
-            Ok(Some(s.repeat_by(by.idx()?).into_series()))
+            Ok(Some(s.repeat_by(by.idx()?)?.into_series()))
         };
 
         self.apply_many(
@@ -1232,8 +1494,9 @@ impl Expr {
     }
 
     #[cfg(feature = "repeat_by")]
-    /// Repeat the column `n` times, where `n` is determined by the values in `by`.
-    /// This yields an `Expr` of dtype `List`
+    /// Repeat every value `n` times, where `n` is determined by the values in `by`.
+    /// This yields an `Expr` of dtype `List`. A zero `n` gives an empty list and a null
+    /// `n` a null list. A scalar `by` is broadcast.
     pub fn repeat_by<E: Into<Expr>>(self, by: E) -> Expr {
         self.repeat_by_impl(by.into())
     }
@@ -1349,6 +1612,16 @@ impl Expr {
         self.apply_private(FunctionExpr::Interpolate(method))
     }
 
+    /// Interpolate the null values at the x-coordinates given by `by`, e.g. timestamps, so
+    /// that irregularly spaced samples are interpolated correctly.
+    ///
+    /// `by` must be strictly increasing. Leading and trailing null values stay null. The
+    /// output is `Float32` if this expression is `Float32` and `Float64` otherwise.
+    #[cfg(feature = "interpolate")]
+    pub fn interpolate_by(self, by: Expr, method: InterpolationMethod) -> Expr {
+        self.apply_many_private(FunctionExpr::InterpolateBy(method), &[by], false, false)
+    }
+
     #[cfg(feature = "rolling_window")]
     #[allow(clippy::type_complexity)]
     fn finish_rolling(
@@ -1782,11 +2055,25 @@ impl Expr {
             })
     }
 
-    /// Shrink numeric columns to the minimal required datatype
+    /// Shrink integer columns to the minimal required datatype
     /// needed to fit the extrema of this [`Series`].
     /// This can be used to reduce memory pressure.
+    ///
+    /// The signedness is kept and other columns are returned as is, so this can be used on
+    /// all columns, e.g. `col("*").shrink_dtype()`.
+    ///
+    /// The output dtype depends on the data, so it is only known at runtime; the schema of a
+    /// lazy query reports the smallest dtype of the same kind.
     pub fn shrink_dtype(self) -> Self {
-        self.map_private(FunctionExpr::ShrinkType)
+        self.shrink_dtype_with(false)
+    }
+
+    /// Shrink numeric columns like [`Expr::shrink_dtype`]. If `shrink_floats`, float columns
+    /// are shrunk as well, but only if all values are represented exactly: to the minimal
+    /// integer datatype if all values are integral, otherwise to `Float32` if possible.
+    /// The schema of a lazy query reports such float columns as `Unknown`.
+    pub fn shrink_dtype_with(self, shrink_floats: bool) -> Self {
+        self.map_private(FunctionExpr::ShrinkType { shrink_floats })
     }
 
     /// Check if all boolean values are `true`
@@ -1891,6 +2178,16 @@ impl Expr {
         self.map_private(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "row_hash")]
+    /// Compute the hash of every element with a hash that doesn't change between
+    /// polars versions and platforms, e.g. to persist bucketing keys.
+    ///
+    /// The values are hashed with xxh3 and `seed`, the encoding of every dtype is
+    /// documented on `polars_ops::prelude::hash_stable`.
+    pub fn hash_stable(self, seed: u64) -> Expr {
+        self.map_private(FunctionExpr::HashStable(seed))
+    }
+
     #[cfg(feature = "strings")]
     pub fn str(self) -> string::StringNameSpace {
         string::StringNameSpace(self)
@@ -1919,6 +2216,9 @@ impl Expr {
     pub fn meta(self) -> meta::MetaNameSpace {
         meta::MetaNameSpace(self)
     }
+    pub fn name(self) -> name::ExprNameNameSpace {
+        name::ExprNameNameSpace(self)
+    }
 }
 
 /// Apply a function/closure over multiple columns once the logical plan get executed.
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Specialized expressions for modifying the name of existing expressions.
+///
+/// The names are resolved when the query is built, so they compose with wildcard, regex and
+/// selector expansion.
+pub struct ExprNameNameSpace(pub(crate) Expr);
+
+impl ExprNameNameSpace {
+    /// Keep the original root name, see [`Expr::keep_name`].
+    pub fn keep(self) -> Expr {
+        self.0.keep_name()
+    }
+
+    /// Define an alias by mapping a function over the original root column name.
+    pub fn map<F>(self, function: F) -> Expr
+    where
+        F: Fn(&str) -> PolarsResult<String> + 'static + Send + Sync,
+    {
+        self.0.map_alias(function)
+    }
+
+    /// Add a prefix to the root column name.
+    pub fn prefix(self, prefix: &str) -> Expr {
+        self.0.prefix(prefix)
+    }
+
+    /// Add a suffix to the root column name.
+    pub fn suffix(self, suffix: &str) -> Expr {
+        self.0.suffix(suffix)
+    }
+
+    /// Make the root column name lowercase.
+    pub fn to_lowercase(self) -> Expr {
+        self.map(|name| Ok(name.to_lowercase()))
+    }
+
+    /// Make the root column name uppercase.
+    pub fn to_uppercase(self) -> Expr {
+        self.map(|name| Ok(name.to_uppercase()))
+    }
+}
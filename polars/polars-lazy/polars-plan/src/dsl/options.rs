@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use polars_core::datatypes::DataType;
-use polars_core::prelude::{JoinType, TimeUnit};
+use polars_core::prelude::{JoinBuildSide, JoinType, TimeUnit};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -51,6 +51,8 @@ pub struct JoinOptions {
     /// Holds `(Option<known_size>, estimated_size)`
     pub rows_left: (Option<usize>, usize),
     pub rows_right: (Option<usize>, usize),
+    /// The input that the hash table is built on.
+    pub build_side: JoinBuildSide,
 }
 
 impl Default for JoinOptions {
@@ -63,6 +65,7 @@ impl Default for JoinOptions {
             slice: None,
             rows_left: (None, usize::MAX),
             rows_right: (None, usize::MAX),
+            build_side: JoinBuildSide::Auto,
         }
     }
 }
@@ -0,0 +1,106 @@
+use std::ops::{Add, BitAnd, Not, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+#[cfg(feature = "regex")]
+use crate::logical_plan::projection::is_regex_projection;
+
+/// A set of columns that is resolved against the schema when the query is built.
+///
+/// The roots of a selector are column selecting expressions, e.g. [`col`], [`cols`],
+/// [`dtype_cols`] or [`matches`]. Selectors can be combined with set operations and are
+/// expanded to the selected columns in schema order.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Selector {
+    Add(Box<Selector>, Box<Selector>),
+    Sub(Box<Selector>, Box<Selector>),
+    InterSect(Box<Selector>, Box<Selector>),
+    Not(Box<Selector>),
+    Root(Box<Expr>),
+}
+
+impl Selector {
+    pub fn new(e: Expr) -> Self {
+        Self::Root(Box::new(e))
+    }
+
+    /// The columns selected by `self` or by `other`.
+    pub fn union(self, other: impl Into<Selector>) -> Self {
+        self + other.into()
+    }
+
+    /// The columns selected by `self` but not by `other`.
+    pub fn difference(self, other: impl Into<Selector>) -> Self {
+        self - other.into()
+    }
+
+    /// The columns selected by both `self` and `other`.
+    pub fn intersection(self, other: impl Into<Selector>) -> Self {
+        self & other.into()
+    }
+
+    /// The columns of the schema that are not selected by `self`.
+    pub fn complement(self) -> Self {
+        !self
+    }
+}
+
+impl Add for Selector {
+    type Output = Selector;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Selector::Add(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Sub for Selector {
+    type Output = Selector;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Selector::Sub(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl BitAnd for Selector {
+    type Output = Selector;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Selector::InterSect(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Not for Selector {
+    type Output = Selector;
+
+    fn not(self) -> Self::Output {
+        Selector::Not(Box::new(self))
+    }
+}
+
+impl From<Expr> for Selector {
+    fn from(value: Expr) -> Self {
+        Selector::new(value)
+    }
+}
+
+impl From<Selector> for Expr {
+    fn from(value: Selector) -> Self {
+        Expr::Selector(value)
+    }
+}
+
+/// Select the columns whose name matches the regular expression `pattern`.
+///
+/// A pattern that is not anchored with `^` and `$` matches anywhere in the name.
+#[cfg(feature = "regex")]
+pub fn matches(pattern: &str) -> Selector {
+    let pattern = if is_regex_projection(pattern) {
+        pattern.to_string()
+    } else {
+        format!("^.*(?:{pattern}).*$")
+    };
+    Selector::new(Expr::Column(Arc::from(pattern)))
+}
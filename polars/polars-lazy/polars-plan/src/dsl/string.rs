@@ -414,4 +414,10 @@ impl StringNameSpace {
                 start, length,
             )))
     }
+
+    /// Get the number of characters of the string values.
+    pub fn n_chars(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::NChars))
+    }
 }
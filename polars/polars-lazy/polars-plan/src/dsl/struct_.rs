@@ -1,8 +1,9 @@
 use super::*;
 use crate::dsl::function_expr::StructFunction;
+use crate::logical_plan::projection::is_regex_projection;
 
 /// Specialized expressions for Struct dtypes.
-pub struct StructNameSpace(pub(crate) Expr);
+pub struct StructNameSpace(pub Expr);
 
 impl StructNameSpace {
     pub fn field_by_index(self, index: i64) -> Expr {
@@ -17,7 +18,13 @@ impl StructNameSpace {
     }
 
     /// Retrieve one of the fields of this [`StructChunked`] as a new Series.
+    ///
+    /// A `"*"` wildcard or a `^regex$` pattern selects multiple fields and expands to one
+    /// expression per field, like `col("*")` does.
     pub fn field_by_name(self, name: &str) -> Expr {
+        if name == "*" || is_regex_projection(name) {
+            return self.field_by_names(&[name]);
+        }
         self.0
             .map_private(FunctionExpr::StructExpr(StructFunction::FieldByName(
                 Arc::from(name),
@@ -28,7 +35,30 @@ impl StructNameSpace {
             })
     }
 
-    /// Rename the fields of the [`StructChunked`].
+    /// Retrieve multiple fields of this [`StructChunked`]. This expands to one expression per
+    /// field. The names may contain a `"*"` wildcard or `^regex$` patterns.
+    pub fn field_by_names<S: AsRef<str>>(self, names: &[S]) -> Expr {
+        let names = names
+            .iter()
+            .map(|name| Arc::from(name.as_ref()))
+            .collect::<Arc<[_]>>();
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::MultipleFields(
+                names,
+            )))
+            .with_function_options(|mut options| {
+                options.allow_rename = true;
+                options
+            })
+    }
+
+    /// Serialize every struct to a JSON object. See [`Expr::json_encode`].
+    #[cfg(feature = "json_encode")]
+    pub fn json_encode(self, encode_nulls: bool) -> Expr {
+        self.0.json_encode(encode_nulls)
+    }
+
+    /// Rename the fields of the [`StructChunked`] positionally.
     pub fn rename_fields(self, names: Vec<String>) -> Expr {
         let names = Arc::new(names);
         let names2 = names.clone();
@@ -36,6 +66,10 @@ impl StructNameSpace {
             .map(
                 move |s| {
                     let ca = s.struct_()?;
+                    polars_ensure!(
+                        ca.fields().len() == names.len(),
+                        ShapeMismatch: "expected {} field names, got {}", ca.fields().len(), names.len()
+                    );
                     let fields = ca
                         .fields()
                         .iter()
@@ -10,6 +10,14 @@ pub struct OptState {
     #[cfg(feature = "cse")]
     pub common_subplan_elimination: bool,
     pub streaming: bool,
+    /// Number of rows per chunk the streaming engine strives to. If `None`
+    /// this is determined from the number of columns and threads.
+    pub streaming_chunk_size: Option<usize>,
+    /// Memory budget in bytes of the streaming sort before it spills to disk. If `None`
+    /// this is taken from the `POLARS_OOC_SORT_MEMORY_BUDGET` env var.
+    pub streaming_sort_memory_budget: Option<usize>,
+    /// Soft limit on the tracked memory usage in bytes while the query executes.
+    pub memory_limit: Option<usize>,
 }
 
 impl Default for OptState {
@@ -25,6 +33,33 @@ impl Default for OptState {
             #[cfg(feature = "cse")]
             common_subplan_elimination: true,
             streaming: false,
+            streaming_chunk_size: None,
+            streaming_sort_memory_budget: None,
+            memory_limit: None,
+        }
+    }
+}
+
+impl OptState {
+    /// All optimizations turned off, so that the optimizations under test can be turned on
+    /// one by one.
+    ///
+    /// Note that type coercion is turned off as well, most queries that combine columns
+    /// of different types need it.
+    pub fn default_off() -> Self {
+        OptState {
+            projection_pushdown: false,
+            predicate_pushdown: false,
+            type_coercion: false,
+            simplify_expr: false,
+            slice_pushdown: false,
+            file_caching: false,
+            #[cfg(feature = "cse")]
+            common_subplan_elimination: false,
+            streaming: false,
+            streaming_chunk_size: None,
+            streaming_sort_memory_budget: None,
+            memory_limit: None,
         }
     }
 }
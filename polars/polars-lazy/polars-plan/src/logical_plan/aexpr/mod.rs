@@ -6,6 +6,7 @@ use polars_arrow::prelude::QuantileInterpolOptions;
 use polars_core::prelude::*;
 use polars_core::utils::{get_time_units, try_get_supertype};
 use polars_utils::arena::{Arena, Node};
+pub(crate) use schema::decimal_arithmetic_dtype;
 
 use crate::dsl::function_expr::FunctionExpr;
 use crate::logical_plan::Context;
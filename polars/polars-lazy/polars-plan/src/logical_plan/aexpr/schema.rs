@@ -38,7 +38,7 @@ impl AExpr {
             Column(name) => {
                 let field = schema
                     .get_field(name)
-                    .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name));
+                    .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name; column = name));
 
                 match ctxt {
                     Context::Default => field,
@@ -98,6 +98,9 @@ impl AExpr {
                         let dt = match field.data_type() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            // the sum of decimals may use all 38 digits
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(_, scale) => Some(Decimal(Some(38), *scale)),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -187,7 +190,7 @@ impl AExpr {
                     // default context because `col()` would return a list in aggregation context
                     .map(|node| arena.get(*node).to_field(schema, Context::Default, arena))
                     .collect::<PolarsResult<Vec<_>>>()?;
-                Ok(output_type.get_field(schema, ctxt, &fields))
+                output_type.get_field(schema, ctxt, &fields)
             }
             Function {
                 function, input, ..
@@ -206,6 +209,44 @@ impl AExpr {
     }
 }
 
+#[allow(unused_variables)]
+fn is_decimal(dtype: &DataType) -> bool {
+    #[cfg(feature = "dtype-decimal")]
+    {
+        matches!(dtype, DataType::Decimal(_, _))
+    }
+    #[cfg(not(feature = "dtype-decimal"))]
+    {
+        false
+    }
+}
+
+/// The output dtype of `+ - * /` on decimals and integers, which unlike other arithmetic is
+/// not the supertype of the operands.
+#[allow(unused_variables)]
+pub(crate) fn decimal_arithmetic_dtype(
+    left: &DataType,
+    right: &DataType,
+    op: Operator,
+) -> Option<DataType> {
+    #[cfg(feature = "dtype-decimal")]
+    {
+        use polars_core::series::arithmetic::decimal::{self, DecimalOp};
+        let op = match op {
+            Operator::Plus => DecimalOp::Add,
+            Operator::Minus => DecimalOp::Sub,
+            Operator::Multiply => DecimalOp::Mul,
+            Operator::Divide => DecimalOp::Div,
+            _ => return None,
+        };
+        decimal::decimal_arithmetic_dtype(left, right, op)
+    }
+    #[cfg(not(feature = "dtype-decimal"))]
+    {
+        None
+    }
+}
+
 fn get_arithmetic_field(
     left: Node,
     right: Node,
@@ -235,7 +276,10 @@ fn get_arithmetic_field(
                 // T - T != T if T is a datetime / date
                 (Datetime(tul, _), Datetime(tur, _)) => Duration(get_time_units(tul, &tur)),
                 (Date, Date) => Duration(TimeUnit::Milliseconds),
-                (left, right) => try_get_supertype(left, &right)?,
+                (left, right) => match decimal_arithmetic_dtype(left, &right, op) {
+                    Some(dtype) => dtype,
+                    None => try_get_supertype(left, &right)?,
+                },
             }
         }
         _ => {
@@ -244,17 +288,24 @@ fn get_arithmetic_field(
                 (AExpr::Literal(_), _) => {
                     // literal will be coerced to match right type
                     let right_type = right_ae.get_type(schema, ctxt, arena)?;
-                    left_field.coerce(right_type);
+                    match decimal_arithmetic_dtype(&left_field.dtype, &right_type, op) {
+                        Some(dtype) => left_field.coerce(dtype),
+                        None => left_field.coerce(right_type),
+                    }
                     return Ok(left_field);
                 }
-                (_, AExpr::Literal(_)) => {
+                // decimal arithmetic depends on the precision and scale of both sides
+                (_, AExpr::Literal(_)) if !is_decimal(&left_field.dtype) => {
                     // literal will be coerced to match right type
                     return Ok(left_field);
                 }
                 _ => {}
             }
             let right_type = right_ae.get_type(schema, ctxt, arena)?;
-            try_get_supertype(&left_field.dtype, &right_type)?
+            match decimal_arithmetic_dtype(&left_field.dtype, &right_type, op) {
+                Some(dtype) => dtype,
+                None => try_get_supertype(&left_field.dtype, &right_type)?,
+            }
         }
     };
 
@@ -597,6 +597,25 @@ impl ALogicalPlan {
         container.push_node(input)
     }
 
+    /// The predicate of a filter, or of a scan it is pushed down to.
+    pub(crate) fn get_predicate(&self) -> Option<Node> {
+        use ALogicalPlan::*;
+        match self {
+            Selection { predicate, .. } => Some(*predicate),
+            DataFrameScan { selection, .. } => *selection,
+            #[cfg(feature = "csv")]
+            CsvScan { predicate, .. } => *predicate,
+            #[cfg(feature = "parquet")]
+            ParquetScan { predicate, .. } => *predicate,
+            #[cfg(feature = "ipc")]
+            IpcScan { predicate, .. } => *predicate,
+            AnonymousScan { predicate, .. } => *predicate,
+            #[cfg(feature = "python")]
+            PythonScan { predicate, .. } => *predicate,
+            _ => None,
+        }
+    }
+
     pub fn get_inputs(&self) -> Vec<Node> {
         let mut inputs = Vec::new();
         self.copy_inputs(&mut inputs);
@@ -33,6 +33,28 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// specify if the scan provider can be read in batches by the streaming engine,
+    /// see [`AnonymousScan::batched`]
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
+    /// Creates a reader that produces the dataframe in batches of about `chunk_size` rows.
+    /// Only called if [`AnonymousScan::allows_streaming`] returns `true`.
+    fn batched(
+        &self,
+        _scan_opts: AnonymousScanOptions,
+        _chunk_size: usize,
+    ) -> PolarsResult<Box<dyn BatchedAnonymousScan>> {
+        polars_bail!(ComputeError: "this scan cannot be read in batches");
+    }
+}
+
+/// Produces the dataframe of an [`AnonymousScan`] in batches, see [`AnonymousScan::batched`].
+pub trait BatchedAnonymousScan: Send + Sync {
+    /// Read the next `n` batches. Returns `None` if the scan is exhausted.
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>>;
 }
 
 impl<F> AnonymousScan for F
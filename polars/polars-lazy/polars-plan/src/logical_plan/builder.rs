@@ -1,5 +1,6 @@
 #[cfg(feature = "csv")]
 use std::io::{Read, Seek};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(feature = "parquet")]
 use polars_core::cloud::CloudOptions;
@@ -18,11 +19,13 @@ use polars_io::RowCount;
 use polars_io::{
     csv::utils::{get_reader_bytes, infer_file_schema, is_compressed},
     csv::CsvEncoding,
+    csv::DateFormats,
     csv::NullValues,
+    csv::RaggedLineMode,
 };
 
 use crate::logical_plan::functions::FunctionNode;
-use crate::logical_plan::projection::{is_regex_projection, rewrite_projections};
+use crate::logical_plan::projection::{expand_selector, is_regex_projection, rewrite_projections};
 use crate::logical_plan::schema::{det_join_schema, FileInfo};
 use crate::prelude::*;
 use crate::utils;
@@ -121,6 +124,8 @@ impl LogicalPlanBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        hive_partitioning: bool,
+        read_stats: Option<Arc<polars_io::parquet::ParquetReadStats>>,
     ) -> PolarsResult<Self> {
         use polars_io::{is_cloud_url, SerReader as _};
 
@@ -151,7 +156,23 @@ impl LogicalPlanBuilder {
                 row_estimation: (Some(num_rows), num_rows),
             })
         };
-        let file_info = file_info?;
+        let mut file_info = file_info?;
+
+        // the partition columns are appended to the columns of the file
+        if hive_partitioning {
+            if let Some(hive_partitions) = HivePartitions::parse_url(&path) {
+                let mut schema = (*file_info.schema).clone();
+                for fld in hive_partitions.schema().iter_fields() {
+                    polars_ensure!(
+                        !schema.contains(fld.name()),
+                        Duplicate: "hive partition column {} is also a column of file {}",
+                        fld.name(), path.display()
+                    );
+                    schema.with_column(fld.name().clone(), fld.data_type().clone());
+                }
+                file_info.schema = Arc::new(schema);
+            }
+        }
 
         Ok(LogicalPlan::ParquetScan {
             path,
@@ -165,8 +186,11 @@ impl LogicalPlanBuilder {
                 row_count,
                 rechunk,
                 file_counter: Default::default(),
+                file_path_column: None,
                 low_memory,
                 use_statistics,
+                hive_partitioning,
+                read_stats,
             },
             cloud_options,
         }
@@ -216,12 +240,14 @@ impl LogicalPlanBuilder {
         quote_char: Option<u8>,
         eol_char: u8,
         null_values: Option<NullValues>,
+        ragged_lines: RaggedLineMode,
         infer_schema_length: Option<usize>,
         rechunk: bool,
         skip_rows_after_header: usize,
         encoding: CsvEncoding,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        date_formats: Option<DateFormats>,
     ) -> PolarsResult<Self> {
         let path = path.into();
         let mut file = std::fs::File::open(&path)?;
@@ -250,6 +276,7 @@ impl LogicalPlanBuilder {
             eol_char,
             null_values.as_ref(),
             try_parse_dates,
+            date_formats.as_ref(),
         )?;
 
         let schema = schema.unwrap_or_else(|| Arc::new(inferred_schema));
@@ -277,11 +304,14 @@ impl LogicalPlanBuilder {
                 quote_char,
                 eol_char,
                 null_values,
+                ragged_lines,
                 rechunk,
                 encoding,
                 row_count,
                 try_parse_dates,
+                date_formats,
                 file_counter: Default::default(),
+                file_path_column: None,
             },
             predicate: None,
         }
@@ -289,8 +319,11 @@ impl LogicalPlanBuilder {
     }
 
     pub fn cache(self) -> Self {
+        // clones of the plan share the id, so all their cache nodes read the same cache
+        // the id must be unique as long as any of those clones are alive
+        static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
         let input = Box::new(self.0);
-        let id = input.as_ref() as *const LogicalPlan as usize;
+        let id = CACHE_ID.fetch_add(1, Ordering::Relaxed);
         LogicalPlan::Cache {
             input,
             id,
@@ -422,9 +455,11 @@ impl LogicalPlanBuilder {
     pub fn filter(self, predicate: Expr) -> Self {
         let predicate = if has_expr(&predicate, |e| match e {
             Expr::Column(name) => is_regex_projection(name),
-            Expr::Wildcard | Expr::RenameAlias { .. } | Expr::Columns(_) | Expr::DtypeColumn(_) => {
-                true
-            }
+            Expr::Wildcard
+            | Expr::RenameAlias { .. }
+            | Expr::Columns(_)
+            | Expr::DtypeColumn(_)
+            | Expr::Selector(_) => true,
             _ => false,
         }) {
             let schema = try_delayed!(self.0.schema(), &self.0, into);
@@ -537,7 +572,7 @@ impl LogicalPlanBuilder {
                 let dtype = try_delayed!(
                     current_schema
                         .get(name)
-                        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name)),
+                        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name; column = name)),
                     self.0,
                     into
                 );
@@ -640,6 +675,17 @@ impl LogicalPlanBuilder {
         .into()
     }
 
+    /// Melt with the `id_vars` and `value_vars` of `args` replaced by the columns selected
+    /// by the selectors.
+    pub fn melt_by(self, id_vars: Selector, value_vars: Selector, mut args: MeltArgs) -> Self {
+        let schema = try_delayed!(self.0.schema(), &self.0, into);
+        let id_vars = try_delayed!(expand_selector(&id_vars, &schema), &self.0, into);
+        let value_vars = try_delayed!(expand_selector(&value_vars, &schema), &self.0, into);
+        args.id_vars = id_vars.iter().map(|name| name.as_ref().into()).collect();
+        args.value_vars = value_vars.iter().map(|name| name.as_ref().into()).collect();
+        self.melt(Arc::new(args))
+    }
+
     pub fn distinct(self, options: DistinctOptions) -> Self {
         LogicalPlan::Distinct {
             input: Box::new(self.0),
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
 use std::path::Path;
@@ -13,6 +14,7 @@ fn write_scan<P: Display>(
     n_columns: i64,
     total_columns: usize,
     predicate: &Option<P>,
+    n_rows: Option<usize>,
 ) -> fmt::Result {
     if indent != 0 {
         writeln!(f)?;
@@ -30,15 +32,41 @@ fn write_scan<P: Display>(
     if let Some(predicate) = predicate {
         write!(f, "\n{:indent$}SELECTION: {predicate}", "")?;
     }
+    if let Some(n_rows) = n_rows {
+        write!(f, "\n{:indent$}N_ROWS: {n_rows}", "")?;
+    }
     Ok(())
 }
 
+/// The id of a node, see [`LogicalPlan::describe_with_node_ids`].
+struct NodeIdPrefix(Option<usize>);
+
+impl Display for NodeIdPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(id) => write!(f, "[{id}] "),
+            None => Ok(()),
+        }
+    }
+}
+
+fn write_slice<L: Display>(f: &mut Formatter, offset: i64, len: L) -> fmt::Result {
+    write!(f, "[offset: {offset}, len: {len}]")
+}
+
 impl LogicalPlan {
-    fn _format(&self, f: &mut Formatter, indent: usize) -> fmt::Result {
+    /// If `ids` is set, the first line of every node is prefixed with an id. The ids are
+    /// assigned in pre-order, the same order in which the profiler assigns them.
+    fn _format(&self, f: &mut Formatter, indent: usize, ids: Option<&Cell<usize>>) -> fmt::Result {
         if indent != 0 {
             writeln!(f)?;
         }
         let sub_indent = indent + 2;
+        let node_id = NodeIdPrefix(ids.map(|ids| {
+            let id = ids.get();
+            ids.set(id + 1);
+            id
+        }));
         use LogicalPlan::*;
         match self {
             #[cfg(feature = "python")]
@@ -52,12 +80,13 @@ impl LogicalPlan {
 
                 write_scan(
                     f,
-                    "PYTHON",
+                    &format!("{node_id}PYTHON"),
                     Path::new(""),
                     sub_indent,
                     n_columns,
                     total_columns,
                     &options.predicate,
+                    options.n_rows,
                 )
             }
             AnonymousScan {
@@ -73,25 +102,39 @@ impl LogicalPlan {
                     .unwrap_or(-1);
                 write_scan(
                     f,
-                    options.fmt_str,
+                    &format!("{node_id}{}", options.fmt_str),
                     Path::new(""),
                     sub_indent,
                     n_columns,
                     file_info.schema.len(),
                     predicate,
+                    options.n_rows,
                 )
             }
-            Union { inputs, .. } => {
-                write!(f, "{:indent$}UNION:", "")?;
+            Union { inputs, options } => {
+                write!(f, "{:indent$}{node_id}UNION", "")?;
+                if options.slice {
+                    write_slice(f, options.slice_offset, options.slice_len)?;
+                }
+                write!(f, ":")?;
                 for (i, plan) in inputs.iter().enumerate() {
                     write!(f, "\n{:indent$}PLAN {i}:", "")?;
-                    plan._format(f, sub_indent)?;
+                    plan._format(f, sub_indent, ids)?;
                 }
                 write!(f, "\n{:indent$}END UNION", "")
             }
             Cache { input, id, count } => {
-                write!(f, "{:indent$}CACHE[id: {:x}, count: {}]", "", *id, *count)?;
-                input._format(f, sub_indent)
+                // caches set by `cache()` are kept until the query finishes
+                if *count == usize::MAX {
+                    write!(f, "{:indent$}{node_id}CACHE[id: {:x}]", "", *id)?;
+                } else {
+                    write!(
+                        f,
+                        "{:indent$}{node_id}CACHE[id: {:x}, count: {}]",
+                        "", *id, *count
+                    )?;
+                }
+                input._format(f, sub_indent, ids)
             }
             #[cfg(feature = "parquet")]
             ParquetScan {
@@ -108,12 +151,13 @@ impl LogicalPlan {
                     .unwrap_or(-1);
                 write_scan(
                     f,
-                    "PARQUET",
+                    &format!("{node_id}PARQUET"),
                     path,
                     sub_indent,
                     n_columns,
                     file_info.schema.len(),
                     predicate,
+                    options.n_rows,
                 )
             }
             #[cfg(feature = "ipc")]
@@ -131,17 +175,18 @@ impl LogicalPlan {
                     .unwrap_or(-1);
                 write_scan(
                     f,
-                    "IPC",
+                    &format!("{node_id}IPC"),
                     path,
                     sub_indent,
                     n_columns,
                     file_info.schema.len(),
                     predicate,
+                    options.n_rows,
                 )
             }
             Selection { predicate, input } => {
-                write!(f, "{:indent$}FILTER {predicate:?} FROM", "")?;
-                input._format(f, indent)
+                write!(f, "{:indent$}{node_id}FILTER {predicate:?} FROM", "")?;
+                input._format(f, indent, ids)
             }
             #[cfg(feature = "csv")]
             CsvScan {
@@ -158,12 +203,13 @@ impl LogicalPlan {
                     .unwrap_or(-1);
                 write_scan(
                     f,
-                    "CSV",
+                    &format!("{node_id}CSV"),
                     path,
                     sub_indent,
                     n_columns,
                     file_info.schema.len(),
                     predicate,
+                    options.n_rows,
                 )
             }
             DataFrameScan {
@@ -183,7 +229,7 @@ impl LogicalPlan {
                 };
                 write!(
                     f,
-                    "{:indent$}DF {:?}; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
+                    "{:indent$}{node_id}DF {:?}; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
                     "",
                     schema.iter_names().take(4).collect::<Vec<_>>(),
                     n_columns,
@@ -192,25 +238,26 @@ impl LogicalPlan {
                 )
             }
             Projection { expr, input, .. } => {
-                write!(f, "{:indent$} SELECT {expr:?} FROM", "")?;
-                input._format(f, sub_indent)
+                write!(f, "{:indent$}{node_id} SELECT {expr:?} FROM", "")?;
+                input._format(f, sub_indent, ids)
             }
             LocalProjection { expr, input, .. } => {
-                write!(f, "{:indent$} LOCAL SELECT {expr:?} FROM", "")?;
-                input._format(f, sub_indent)
+                write!(f, "{:indent$}{node_id} LOCAL SELECT {expr:?} FROM", "")?;
+                input._format(f, sub_indent, ids)
             }
             Sort {
                 input, by_column, ..
             } => {
-                write!(f, "{:indent$}SORT BY {by_column:?}", "")?;
-                input._format(f, sub_indent)
+                write!(f, "{:indent$}{node_id}SORT BY {by_column:?}", "")?;
+                input._format(f, sub_indent, ids)
             }
             Aggregate {
                 input, keys, aggs, ..
             } => {
-                write!(f, "{:indent$}AGGREGATE", "")?;
+                write!(f, "{:indent$}{node_id}AGGREGATE", "")?;
                 write!(f, "\n{:indent$}\t{aggs:?} BY {keys:?} FROM", "")?;
-                write!(f, "\n{:indent$}\t{input:?}", "")
+                write!(f, "\n{:indent$}\t", "")?;
+                input._format(f, 0, ids)
             }
             Join {
                 input_left,
@@ -221,49 +268,76 @@ impl LogicalPlan {
                 ..
             } => {
                 let how = &options.how;
-                write!(f, "{:indent$}{how} JOIN:", "")?;
+                write!(f, "{:indent$}{node_id}{how} JOIN", "")?;
+                if let Some((offset, len)) = options.slice {
+                    write_slice(f, offset, len)?;
+                }
+                write!(f, ":")?;
                 write!(f, "\n{:indent$}LEFT PLAN ON: {left_on:?}", "")?;
-                input_left._format(f, sub_indent)?;
+                input_left._format(f, sub_indent, ids)?;
                 write!(f, "\n{:indent$}RIGHT PLAN ON: {right_on:?}", "")?;
-                input_right._format(f, sub_indent)?;
+                input_right._format(f, sub_indent, ids)?;
                 write!(f, "\n{:indent$}END {} JOIN", "", how)
             }
             HStack { input, exprs, .. } => {
-                write!(f, "{:indent$} WITH_COLUMNS:", "",)?;
+                write!(f, "{:indent$}{node_id} WITH_COLUMNS:", "",)?;
                 write!(f, "\n{:indent$} {exprs:?}", "")?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, ids)
             }
             Distinct { input, options } => {
-                write!(f, "{:indent$}UNIQUE BY {:?}", "", options.subset)?;
-                input._format(f, sub_indent)
+                write!(f, "{:indent$}{node_id}UNIQUE BY {:?}", "", options.subset)?;
+                input._format(f, sub_indent, ids)
             }
             Slice { input, offset, len } => {
-                write!(f, "{:indent$}SLICE[offset: {offset}, len: {len}]", "")?;
-                input._format(f, sub_indent)
+                write!(f, "{:indent$}{node_id}SLICE", "")?;
+                write_slice(f, *offset, *len)?;
+                input._format(f, sub_indent, ids)
             }
             MapFunction {
                 input, function, ..
             } => {
                 let function_fmt = format!("{function}");
-                write!(f, "{:indent$}{function_fmt}", "")?;
-                input._format(f, sub_indent)
+                write!(f, "{:indent$}{node_id}{function_fmt}", "")?;
+                input._format(f, sub_indent, ids)
             }
             Error { input, err } => write!(f, "{err:?}\n{input:?}"),
-            ExtContext { input, .. } => {
-                write!(f, "{:indent$}EXTERNAL_CONTEXT", "")?;
-                input._format(f, sub_indent)
+            ExtContext {
+                input, contexts, ..
+            } => {
+                write!(f, "{:indent$}{node_id}EXTERNAL_CONTEXT:", "")?;
+                for (i, plan) in contexts.iter().enumerate() {
+                    write!(f, "\n{:indent$}CONTEXT {i}:", "")?;
+                    plan._format(f, sub_indent, ids)?;
+                }
+                write!(f, "\n{:indent$}INPUT:", "")?;
+                input._format(f, sub_indent, ids)
             }
             FileSink { input, .. } => {
-                write!(f, "{:indent$}FILE_SINK", "")?;
-                input._format(f, sub_indent)
+                write!(f, "{:indent$}{node_id}FILE_SINK", "")?;
+                input._format(f, sub_indent, ids)
+            }
+        }
+    }
+}
+
+impl LogicalPlan {
+    /// Describe the plan with the ids of its nodes, these are the `node_id`s in the profile of
+    /// the query.
+    pub fn describe_with_node_ids(&self) -> String {
+        struct WithNodeIds<'a>(&'a LogicalPlan);
+
+        impl Debug for WithNodeIds<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.0._format(f, 0, Some(&Cell::new(0)))
             }
         }
+        format!("{:?}", WithNodeIds(self))
     }
 }
 
 impl Debug for LogicalPlan {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self._format(f, 0)
+        self._format(f, 0, None)
     }
 }
 
@@ -399,6 +473,7 @@ impl Debug for Expr {
             RenameAlias { expr, .. } => write!(f, "RENAME_ALIAS {expr:?}"),
             Columns(names) => write!(f, "COLUMNS({names:?})"),
             DtypeColumn(dt) => write!(f, "COLUMN OF DTYPE: {dt:?}"),
+            Selector(s) => write!(f, "SELECTOR: {s:?}"),
         }
     }
 }
@@ -29,6 +29,8 @@ pub(super) fn merge_sorted(df: &DataFrame, column: &str) -> PolarsResult<DataFra
         )
     };
 
+    // the halves don't have the sorted flags of the inputs, so the direction of the keys is
+    // determined from their values
     let left = DataFrame::new_no_checks(left_cols);
     let right = DataFrame::new_no_checks(right_cols);
 
@@ -1,3 +1,4 @@
+mod count;
 mod drop;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
@@ -5,11 +6,12 @@ mod rename;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
-use polars_core::IUseStringCache;
+use polars_core::StringCacheHolder;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -39,9 +41,12 @@ pub enum FunctionNode {
         function: Arc<dyn DataFrameUdfMut>,
         schema: SchemaRef,
         original: Option<Arc<LogicalPlan>>,
+        // used for formatting
+        chunk_size: Option<usize>,
     },
     Unnest {
         columns: Arc<[Arc<str>]>,
+        collision: UnnestCollision,
     },
     FastProjection {
         columns: Arc<[Arc<str>]>,
@@ -76,6 +81,15 @@ pub enum FunctionNode {
         args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
+    /// Count the rows of file scans from their metadata, the data itself is not read.
+    /// The input of this node is an empty `DataFrame`.
+    Count {
+        /// Rows of which the count is already known, e.g. from a parquet footer.
+        known_rows: usize,
+        /// Ipc files that still have to be counted.
+        ipc_paths: Arc<[PathBuf]>,
+        name: Arc<str>,
+    },
 }
 
 impl PartialEq for FunctionNode {
@@ -110,7 +124,7 @@ impl FunctionNode {
     pub fn is_streamable(&self) -> bool {
         use FunctionNode::*;
         match self {
-            Rechunk | Pipeline { .. } => false,
+            Rechunk | Pipeline { .. } | Count { .. } => false,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => false,
             DropNulls { .. }
@@ -161,26 +175,40 @@ impl FunctionNode {
             }
             DropNulls { .. } => Ok(Cow::Borrowed(input_schema)),
             Rechunk => Ok(Cow::Borrowed(input_schema)),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                collision: _collision,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
-                    let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
+                    // the parent struct column, the name and the dtype of every output column
+                    let mut columns = Vec::with_capacity(input_schema.len() * 2);
                     for (name, dtype) in input_schema.iter() {
                         if _columns.iter().any(|item| item.as_ref() == name.as_str()) {
                             if let DataType::Struct(flds) = dtype {
-                                for fld in flds {
-                                    new_schema
-                                        .with_column(fld.name().clone(), fld.data_type().clone());
-                                }
+                                columns.extend(flds.iter().map(|fld| {
+                                    (Some(name.as_str()), fld.name().as_str(), fld.data_type())
+                                }));
                             } else {
                                 polars_bail!(
                                     SchemaMismatch: "expected struct dtype, got: `{}`", dtype
                                 );
                             }
                         } else {
-                            new_schema.with_column(name.clone(), dtype.clone());
+                            columns.push((None, name.as_str(), dtype));
                         }
                     }
+                    let names = polars_core::frame::_unnest_names(
+                        columns.iter().map(|(parent, name, _)| (*parent, *name)),
+                        *_collision,
+                    )?;
+                    let new_schema = columns
+                        .iter()
+                        .zip(names)
+                        .filter_map(|((_, _, dtype), name)| {
+                            name.map(|name| Field::new(&name, (*dtype).clone()))
+                        })
+                        .collect::<Schema>();
 
                     Ok(Cow::Owned(Arc::new(new_schema)))
                 }
@@ -195,6 +223,10 @@ impl FunctionNode {
             Drop { names } => drop::drop_schema(input_schema, names),
             Explode { schema, .. } => Ok(Cow::Owned(schema.clone())),
             Melt { schema, .. } => Ok(Cow::Owned(schema.clone())),
+            Count { name, .. } => {
+                let schema = Schema::from_iter([Field::new(name, IDX_DTYPE)]);
+                Ok(Cow::Owned(Arc::new(schema)))
+            }
         }
     }
 
@@ -212,6 +244,7 @@ impl FunctionNode {
             | Drop { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
+            Count { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -230,6 +263,7 @@ impl FunctionNode {
             | Drop { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
+            Count { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -237,7 +271,7 @@ impl FunctionNode {
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<[Arc<str>]> {
         use FunctionNode::*;
         match self {
-            Unnest { columns } => Cow::Borrowed(columns.as_ref()),
+            Unnest { columns, .. } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
@@ -257,10 +291,13 @@ impl FunctionNode {
             }
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column } => merge_sorted(&df, column.as_ref()),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                collision: _collision,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
-                    df.unnest(_columns.as_ref())
+                    df.unnest_with(_columns.as_ref(), *_collision)
                 }
                 #[cfg(not(feature = "dtype-struct"))]
                 {
@@ -271,7 +308,7 @@ impl FunctionNode {
                 // we use a global string cache here as streaming chunks all have different rev maps
                 #[cfg(feature = "dtype-categorical")]
                 {
-                    let _hold = IUseStringCache::new();
+                    let _hold = StringCacheHolder::new();
                     Arc::get_mut(function).unwrap().call_udf(df)
                 }
 
@@ -287,6 +324,11 @@ impl FunctionNode {
                 let args = (**args).clone();
                 df.melt2(args)
             }
+            Count {
+                known_rows,
+                ipc_paths,
+                name,
+            } => count::count_rows(*known_rows, ipc_paths, name),
         }
     }
 }
@@ -313,16 +355,25 @@ impl Display for FunctionNode {
                 fmt_column_delimited(f, subset, "[", "]")
             }
             Rechunk => write!(f, "RECHUNK"),
-            Unnest { columns } => {
+            Unnest { columns, .. } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")
             }
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => write!(f, "MERGE SORTED"),
-            Pipeline { original, .. } => {
+            Pipeline {
+                original,
+                chunk_size,
+                ..
+            } => {
                 if let Some(original) = original {
-                    writeln!(f, "--- PIPELINE")?;
+                    match chunk_size {
+                        Some(chunk_size) => {
+                            writeln!(f, "--- PIPELINE (chunk size: {chunk_size} rows)")?
+                        }
+                        None => writeln!(f, "--- PIPELINE")?,
+                    }
                     write!(f, "{:?}", original.as_ref())?;
                     let indent = 2;
                     writeln!(f, "{:indent$}--- END PIPELINE", "")
@@ -334,6 +385,20 @@ impl Display for FunctionNode {
             Drop { .. } => write!(f, "DROP"),
             Explode { .. } => write!(f, "EXPLODE"),
             Melt { .. } => write!(f, "MELT"),
+            Count {
+                known_rows,
+                ipc_paths,
+                ..
+            } => {
+                write!(f, "FAST_COUNT")?;
+                if !ipc_paths.is_empty() {
+                    write!(f, " of {} ipc files", ipc_paths.len())?;
+                }
+                if *known_rows > 0 {
+                    write!(f, " + {known_rows} known rows")?;
+                }
+                Ok(())
+            }
         }
     }
 }
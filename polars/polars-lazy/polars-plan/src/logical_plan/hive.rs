@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::parquet::predicates::{BatchStats, ColumnStats};
+
+/// Directory name of partitions with a null key, this is what hive uses.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The partition columns of a file in a hive partitioned dataset. These are
+/// parsed from the `key=value` directory components of the path of the file.
+pub struct HivePartitions {
+    partitions: Vec<Series>,
+    /// Statistics of the partition columns, these are used to skip
+    /// files with predicates on the partition columns.
+    stats: BatchStats,
+}
+
+fn unescape_partition_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            let hex = chars.as_str().get(..2);
+            if let Some(c) = hex
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .and_then(char::from_u32)
+            {
+                out.push(c);
+                chars.nth(1);
+                continue;
+            }
+        }
+        out.push(c)
+    }
+    out
+}
+
+/// Parse a partition value to the narrowest of `Int64`, `Float64`, `Date` and `Utf8`.
+fn parse_partition_value(key: &str, value: &str) -> Series {
+    if value == HIVE_DEFAULT_PARTITION {
+        return Series::full_null(key, 1, &DataType::Null);
+    }
+    if let Ok(v) = value.parse::<i64>() {
+        return Series::new(key, [v]);
+    }
+    // don't parse values like `nan` and `inf` as floats
+    if value.bytes().any(|b| b.is_ascii_digit()) {
+        if let Ok(v) = value.parse::<f64>() {
+            return Series::new(key, [v]);
+        }
+    }
+    let value = unescape_partition_value(value);
+    let s = Series::new(key, [value.as_str()]);
+    #[cfg(feature = "dtype-date")]
+    if value.len() == 10 {
+        if let Ok(date) = s.cast(&DataType::Date) {
+            if date.null_count() == 0 {
+                return date;
+            }
+        }
+    }
+    s
+}
+
+impl HivePartitions {
+    /// Parse the hive partitions from the directories of `path`. Returns `None` if
+    /// the path has no `key=value` directory components.
+    pub fn parse_url(path: &Path) -> Option<Self> {
+        let partitions = path
+            .parent()?
+            .components()
+            .filter_map(|component| {
+                let component = component.as_os_str().to_str()?;
+                let (key, value) = component.split_once('=')?;
+                Some(parse_partition_value(key, value))
+            })
+            .collect::<Vec<_>>();
+
+        if partitions.is_empty() {
+            None
+        } else {
+            let schema: Schema = partitions.iter().map(|s| s.field().into_owned()).collect();
+            let stats = partitions
+                .iter()
+                .map(|s| ColumnStats::from_column_literal(s.clone()))
+                .collect();
+            // all rows of the file have the same partition values
+            let stats = BatchStats::new(schema, stats, 1);
+            Some(Self { partitions, stats })
+        }
+    }
+
+    pub fn get_statistics(&self) -> &BatchStats {
+        &self.stats
+    }
+
+    pub fn schema(&self) -> &Schema {
+        self.stats.schema()
+    }
+
+    /// The partition columns with a single value.
+    pub fn materialize_partition_columns(&self) -> Vec<Series> {
+        self.partitions.clone()
+    }
+}
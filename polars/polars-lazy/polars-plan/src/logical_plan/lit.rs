@@ -40,6 +40,9 @@ pub enum LiteralValue {
     Float32(f32),
     /// A 64-bit floating point number.
     Float64(f64),
+    /// A fixed point decimal number: the unscaled value and the scale.
+    #[cfg(feature = "dtype-decimal")]
+    Decimal(i128, usize),
     Range {
         low: i64,
         high: i64,
@@ -80,6 +83,8 @@ impl LiteralValue {
             Int64(v) => AnyValue::Int64(*v),
             Float32(v) => AnyValue::Float32(*v),
             Float64(v) => AnyValue::Float64(*v),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(v, scale) => AnyValue::Decimal(*v, *scale),
             Utf8(v) => AnyValue::Utf8(v),
             #[cfg(feature = "dtype-duration")]
             Duration(v, tu) => AnyValue::Duration(*v, *tu),
@@ -112,6 +117,11 @@ impl LiteralValue {
             LiteralValue::Int64(_) => DataType::Int64,
             LiteralValue::Float32(_) => DataType::Float32,
             LiteralValue::Float64(_) => DataType::Float64,
+            #[cfg(feature = "dtype-decimal")]
+            LiteralValue::Decimal(v, scale) => DataType::Decimal(
+                Some(std::cmp::max(v.unsigned_abs().to_string().len(), *scale)),
+                Some(*scale),
+            ),
             LiteralValue::Utf8(_) => DataType::Utf8,
             LiteralValue::Binary(_) => DataType::Binary,
             LiteralValue::Range { data_type, .. } => data_type.clone(),
@@ -180,6 +190,8 @@ impl TryFrom<AnyValue<'_>> for LiteralValue {
             AnyValue::Int64(i) => Ok(Self::Int64(i)),
             AnyValue::Float32(f) => Ok(Self::Float32(f)),
             AnyValue::Float64(f) => Ok(Self::Float64(f)),
+            #[cfg(feature = "dtype-decimal")]
+            AnyValue::Decimal(v, scale) => Ok(Self::Decimal(v, scale)),
             #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
             AnyValue::Date(v) => Ok(LiteralValue::Date(v)),
             #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
@@ -285,6 +297,56 @@ impl Literal for NaiveDate {
     }
 }
 
+/// A fixed point decimal literal, stored as an unscaled integer and a scale.
+///
+/// ```rust,ignore
+/// let price: Decimal = "10.50".parse()?;
+/// df.lazy().filter(col("price").gt(lit(price)))
+/// ```
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    pub value: i128,
+    pub scale: usize,
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl Decimal {
+    pub fn new(value: i128, scale: usize) -> Self {
+        Self { value, scale }
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl std::str::FromStr for Decimal {
+    type Err = PolarsError;
+
+    /// Parse a decimal like `"-10.50"`, keeping all digits after the point as the scale.
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        let err = || polars_err!(ComputeError: "cannot parse '{}' as decimal", s);
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        polars_ensure!(
+            !(int.is_empty() && frac.is_empty())
+                && int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit())
+                && int.len() + frac.len() <= 38,
+            ComputeError: "cannot parse '{}' as decimal", s
+        );
+        let value = format!("{int}{frac}").parse::<i128>().map_err(|_| err())?;
+        Ok(Self::new(if negative { -value } else { value }, frac.len()))
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl Literal for Decimal {
+    fn lit(self) -> Expr {
+        Expr::Literal(LiteralValue::Decimal(self.value, self.scale))
+    }
+}
+
 impl Literal for Series {
     fn lit(self) -> Expr {
         Expr::Literal(LiteralValue::Series(SpecialEq::new(self)))
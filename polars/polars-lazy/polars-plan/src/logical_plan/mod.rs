@@ -22,6 +22,8 @@ pub(crate) mod conversion;
 pub(crate) mod debug;
 mod format;
 mod functions;
+#[cfg(feature = "parquet")]
+mod hive;
 pub(crate) mod iterator;
 mod lit;
 pub(crate) mod optimizer;
@@ -30,6 +32,8 @@ pub(crate) mod projection;
 #[cfg(feature = "python")]
 mod pyarrow;
 mod schema;
+#[cfg(feature = "serde")]
+mod serialize;
 
 pub use aexpr::*;
 pub use alp::*;
@@ -38,6 +42,8 @@ pub use apply::*;
 pub use builder::*;
 pub use conversion::*;
 pub use functions::*;
+#[cfg(feature = "parquet")]
+pub use hive::HivePartitions;
 pub use iterator::*;
 pub use lit::*;
 pub use optimizer::*;
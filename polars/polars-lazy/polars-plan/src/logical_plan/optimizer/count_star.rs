@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+
+use super::*;
+use crate::logical_plan::functions::FunctionNode;
+use crate::prelude::names::COUNT;
+
+/// Answers a `select(count())` over file scans from the metadata of the files.
+///
+/// The scans may not have a predicate, the number of rows is then taken from the parquet
+/// footers and the headers of the ipc record batches, the data pages are never read.
+/// Other scans, e.g. csv, don't have this metadata and are read as usual.
+pub(super) struct CountStar {}
+
+#[derive(Default)]
+struct CountSources {
+    known_rows: usize,
+    ipc_paths: Vec<PathBuf>,
+}
+
+/// The output name if `node` is a (aliased) `count()`.
+fn count_name(node: Node, expr_arena: &Arena<AExpr>) -> Option<Arc<str>> {
+    match expr_arena.get(node) {
+        AExpr::Count => Some(Arc::from(COUNT)),
+        AExpr::Alias(input, name) if matches!(expr_arena.get(*input), AExpr::Count) => {
+            Some(name.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Gather the scans under `node`, `None` if any of them cannot be counted from its metadata.
+fn collect_sources(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    sources: &mut CountSources,
+) -> Option<()> {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            file_info,
+            predicate: None,
+            options,
+            ..
+        } => {
+            // this is the exact number of rows in the footer
+            let num_rows = file_info.row_estimation.0?;
+            sources.known_rows += options
+                .n_rows
+                .map_or(num_rows, |n_rows| std::cmp::min(n_rows, num_rows));
+            Some(())
+        }
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            path,
+            predicate: None,
+            options,
+            ..
+        } if options.n_rows.is_none() && options.skip_rows == 0 => {
+            sources.ipc_paths.push(path.clone());
+            Some(())
+        }
+        // a glob over files
+        Union { inputs, options } if !options.slice => inputs
+            .iter()
+            .try_for_each(|input| collect_sources(*input, lp_arena, sources)),
+        MapFunction {
+            input,
+            function: FunctionNode::Rechunk,
+        } => collect_sources(*input, lp_arena, sources),
+        _ => None,
+    }
+}
+
+impl OptimizationRule for CountStar {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        match lp_arena.get(node) {
+            ALogicalPlan::Projection { input, expr, .. } if expr.len() == 1 => {
+                let name = count_name(expr[0], expr_arena)?;
+                let mut sources = CountSources::default();
+                collect_sources(*input, lp_arena, &mut sources)?;
+
+                let input = lp_arena.add(ALogicalPlan::DataFrameScan {
+                    df: Arc::new(DataFrame::empty()),
+                    schema: Arc::new(Schema::new()),
+                    output_schema: None,
+                    projection: None,
+                    selection: None,
+                });
+                Some(ALogicalPlan::MapFunction {
+                    input,
+                    function: FunctionNode::Count {
+                        known_rows: sources.known_rows,
+                        ipc_paths: sources.ipc_paths.into(),
+                        name,
+                    },
+                })
+            }
+            _ => None,
+        }
+    }
+}
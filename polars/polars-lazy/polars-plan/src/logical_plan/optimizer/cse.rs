@@ -116,6 +116,25 @@ fn lp_node_equal(a: &ALogicalPlan, b: &ALogicalPlan, expr_arena: &Arena<AExpr>)
                 ..
             },
         ) => Arc::ptr_eq(left_df, right_df),
+        (
+            AnonymousScan {
+                function: function_l,
+                predicate: predicate_l,
+                options: options_l,
+                ..
+            },
+            AnonymousScan {
+                function: function_r,
+                predicate: predicate_r,
+                options: options_r,
+                ..
+            },
+        ) => {
+            // only compare the data pointers, the vtables may differ between codegen units
+            Arc::as_ptr(function_l) as *const () == Arc::as_ptr(function_r) as *const ()
+                && options_l == options_r
+                && predicate_equal(*predicate_l, *predicate_r, expr_arena)
+        }
         #[cfg(feature = "parquet")]
         (
             ParquetScan {
@@ -413,6 +432,28 @@ pub(crate) fn elim_cmn_subplans(
     (root, changed)
 }
 
+/// Eliminate the subplans that are shared between multiple (optimized) plans.
+///
+/// The plans must live in the same arenas. Shared subplans are replaced with
+/// cache nodes that have the same id in every plan, so that they are executed once
+/// if the plans are executed with the same `ExecutionState`.
+pub fn elim_cmn_subplans_across_plans(
+    roots: &[Node],
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    if roots.len() < 2 {
+        return false;
+    }
+    // a temporary union makes every plan a branch that gets its own trail
+    // the union itself is never executed
+    let union = lp_arena.add(ALogicalPlan::Union {
+        inputs: roots.to_vec(),
+        options: Default::default(),
+    });
+    elim_cmn_subplans(union, lp_arena, expr_arena).1
+}
+
 // ensure the file count counters are decremented with the cache counts
 pub(crate) fn decrement_file_counters_by_cache_hits(
     root: Node,
@@ -97,7 +97,7 @@ pub fn collect_fingerprints(
             predicate,
             ..
         } => {
-            let slice = (0, options.n_rows);
+            let slice = (options.skip_rows, options.n_rows);
             let predicate = predicate.map(|node| node_to_expr(node, expr_arena));
             let fp = FileFingerPrint {
                 path: path.clone(),
@@ -173,7 +173,7 @@ pub fn find_column_union_and_fingerprints(
             predicate,
             ..
         } => {
-            let slice = (0, options.n_rows);
+            let slice = (options.skip_rows, options.n_rows);
             let predicate = predicate.map(|node| node_to_expr(node, expr_arena));
             process_with_columns(
                 path,
@@ -373,7 +373,7 @@ impl FileCacher {
                     let finger_print = FileFingerPrint {
                         path,
                         predicate: predicate_expr,
-                        slice: (0, options.n_rows),
+                        slice: (options.skip_rows, options.n_rows),
                     };
 
                     let with_columns = self.extract_columns_and_count(&finger_print);
@@ -1,9 +1,12 @@
+use std::time::Instant;
+
 use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
 
 use crate::prelude::*;
 
 mod cache_states;
+mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
 mod delay_rechunk;
@@ -18,8 +21,11 @@ mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+#[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+mod struct_field_pushdown;
 mod type_coercion;
 
+use count_star::CountStar;
 use delay_rechunk::DelayRechunk;
 use drop_nulls::ReplaceDropNulls;
 use fast_projection::FastProjectionAndCollapse;
@@ -30,8 +36,12 @@ pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, StackOptimizer};
+#[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+use struct_field_pushdown::StructFieldPushDown;
 pub use type_coercion::TypeCoercionRule;
 
+#[cfg(feature = "cse")]
+pub use self::cse::elim_cmn_subplans_across_plans;
 use self::flatten_union::FlattenUnionRule;
 pub use crate::frame::{AllowedOptimizations, OptState};
 
@@ -42,6 +52,9 @@ pub trait Optimize {
 // arbitrary constant to reduce reallocation.
 const HASHMAP_SIZE: usize = 16;
 
+/// The name, start and end of every optimization pass, in the order they ran.
+pub type OptimizerTimings = Vec<(&'static str, Instant, Instant)>;
+
 pub(crate) fn init_hashmap<K, V>(max_len: Option<usize>) -> PlHashMap<K, V> {
     PlHashMap::with_capacity(std::cmp::min(max_len.unwrap_or(HASHMAP_SIZE), HASHMAP_SIZE))
 }
@@ -52,7 +65,14 @@ pub fn optimize(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     scratch: &mut Vec<Node>,
+    mut timings: Option<&mut OptimizerTimings>,
 ) -> PolarsResult<Node> {
+    let mut time_pass = |name: &'static str, start: Instant| {
+        if let Some(timings) = timings.as_deref_mut() {
+            timings.push((name, start, Instant::now()))
+        }
+    };
+
     // get toggle values
     let predicate_pushdown = opt_state.predicate_pushdown;
     let projection_pushdown = opt_state.projection_pushdown;
@@ -77,8 +97,10 @@ pub fn optimize(
 
     #[cfg(feature = "cse")]
     let cse_changed = if cse {
+        let start = Instant::now();
         let (lp, changed) = cse::elim_cmn_subplans(lp_top, lp_arena, expr_arena);
         lp_top = lp;
+        time_pass("common_subplan_elimination", start);
         changed
     } else {
         false
@@ -93,6 +115,7 @@ pub fn optimize(
 
     // should be run before predicate pushdown
     if projection_pushdown {
+        let start = Instant::now();
         let mut projection_pushdown_opt = ProjectionPushDown::new();
         let alp = lp_arena.take(lp_top);
         let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -101,27 +124,34 @@ pub fn optimize(
         if projection_pushdown_opt.has_joins_or_unions && projection_pushdown_opt.has_cache {
             cache_states::set_cache_states(lp_top, lp_arena, expr_arena, scratch, cse_changed);
         }
+        time_pass("projection_pushdown", start);
     }
 
     if predicate_pushdown {
+        let start = Instant::now();
         let predicate_pushdown_opt = PredicatePushDown::default();
         let alp = lp_arena.take(lp_top);
         let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
         lp_arena.replace(lp_top, alp);
+        time_pass("predicate_pushdown", start);
     }
 
     // make sure its before slice pushdown.
     if projection_pushdown {
         rules.push(Box::new(FastProjectionAndCollapse {}));
+        // a `count()` that doesn't need any column of the scans
+        rules.push(Box::new(CountStar {}));
     }
     rules.push(Box::new(DelayRechunk::new()));
 
     if slice_pushdown {
+        let start = Instant::now();
         let slice_pushdown_opt = SlicePushDown::new(streaming);
         let alp = lp_arena.take(lp_top);
         let alp = slice_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
 
         lp_arena.replace(lp_top, alp);
+        time_pass("slice_pushdown", start);
 
         // expressions use the stack optimizer
         rules.push(Box::new(slice_pushdown_opt));
@@ -140,6 +170,7 @@ pub fn optimize(
     // the file fingerprints are finished.
     #[cfg(any(feature = "cse", feature = "parquet", feature = "ipc", feature = "csv"))]
     if agg_scan_projection || cse_changed {
+        let start = Instant::now();
         // we do this so that expressions are simplified created by the pushdown optimizations
         // we must clean up the predicates, because the agg_scan_projection
         // uses them in the hashtable to determine duplicates.
@@ -164,12 +195,20 @@ pub fn optimize(
             // this must run after cse
             cse::decrement_file_counters_by_cache_hits(lp_top, lp_arena, expr_arena, 0, scratch);
         }
+        time_pass("file_caching", start);
     }
 
     rules.push(Box::new(ReplaceDropNulls {}));
     rules.push(Box::new(FlattenUnionRule {}));
+    // must run after the file caching, as the file may be read once for multiple scans
+    #[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+    if projection_pushdown {
+        rules.push(Box::new(StructFieldPushDown {}));
+    }
 
+    let start = Instant::now();
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
+    time_pass("rule_based", start);
 
     // during debug we check if the optimizations have not modified the final schema
     #[cfg(debug_assertions)]
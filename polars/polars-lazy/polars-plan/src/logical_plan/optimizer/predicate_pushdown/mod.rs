@@ -553,6 +553,29 @@ impl PredicatePushDown {
                             Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
 
                         }
+                        #[cfg(feature = "dtype-struct")]
+                        FunctionNode::Unnest { columns, .. } => {
+                            // a field can take the name of an input column when the names collide,
+                            // so the predicates on the names of the fields are done here
+                            let field_names = {
+                                let input_schema = lp_arena.get(lp.get_inputs()[0]).schema(lp_arena);
+                                input_schema
+                                    .iter()
+                                    .filter(|(name, _)| columns.iter().any(|s| s.as_ref() == name.as_str()))
+                                    .filter_map(|(_, dtype)| match dtype {
+                                        DataType::Struct(flds) => Some(flds.iter().map(|fld| fld.name().clone())),
+                                        _ => None,
+                                    })
+                                    .flatten()
+                                    .collect::<PlHashSet<_>>()
+                            };
+                            let condition = |name: Arc<str>| field_names.contains(&*name);
+                            let local_predicates =
+                                transfer_to_local_by_name(expr_arena, &mut acc_predicates, condition);
+
+                            let lp = self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)?;
+                            Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+                        }
                         _ => {
                             self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)
                         }
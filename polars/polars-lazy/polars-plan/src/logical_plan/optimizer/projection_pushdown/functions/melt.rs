@@ -1,9 +1,36 @@
 use super::*;
+use crate::logical_plan::builder::det_melt_schema;
+
+/// Only keep the `id_vars` that are projected by the nodes above and make the
+/// `value_vars` explicit, so that a projected input doesn't change them.
+fn prune_melt_args(
+    args: &Arc<MeltArgs>,
+    acc_projections: &[Node],
+    input_schema: &Schema,
+    expr_arena: &Arena<AExpr>,
+) -> Arc<MeltArgs> {
+    let mut args = (**args).clone();
+    if args.value_vars.is_empty() {
+        args.value_vars = input_schema
+            .iter_names()
+            .filter(|name| !args.id_vars.contains(name))
+            .cloned()
+            .collect();
+    }
+    // if empty: all columns are projected
+    if !acc_projections.is_empty() {
+        let projected = acc_projections
+            .iter()
+            .flat_map(|node| aexpr_to_leaf_names(*node, expr_arena))
+            .collect::<PlHashSet<_>>();
+        args.id_vars
+            .retain(|name| projected.contains(name.as_str()));
+    }
+    Arc::new(args)
+}
 
-#[allow(clippy::too_many_arguments)]
 pub(super) fn process_melt(
     proj_pd: &mut ProjectionPushDown,
-    lp: ALogicalPlan,
     args: &Arc<MeltArgs>,
     input: Node,
     acc_projections: Vec<Node>,
@@ -11,44 +38,46 @@ pub(super) fn process_melt(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<ALogicalPlan> {
-    if args.value_vars.is_empty() {
-        // restart projection pushdown
-        proj_pd.no_pushdown_restart_opt(lp, acc_projections, projections_seen, lp_arena, expr_arena)
-    } else {
-        let (mut acc_projections, mut local_projections, mut projected_names) =
-            split_acc_projections(
-                acc_projections,
-                lp_arena.get(input).schema(lp_arena).as_ref(),
-                expr_arena,
-                false,
-            );
+    let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+    let args = prune_melt_args(args, &acc_projections, &input_schema, expr_arena);
+    let schema = det_melt_schema(&args, &input_schema);
+    let lp = ALogicalPlan::MapFunction {
+        input,
+        function: FunctionNode::Melt {
+            args: args.clone(),
+            schema,
+        },
+    };
+
+    let (mut acc_projections, mut local_projections, mut projected_names) =
+        split_acc_projections(acc_projections, input_schema.as_ref(), expr_arena, false);
 
-        if !local_projections.is_empty() {
-            local_projections.extend_from_slice(&acc_projections);
-        }
+    if !local_projections.is_empty() {
+        local_projections.extend_from_slice(&acc_projections);
+    }
 
-        // make sure that the requested columns are projected
-        args.id_vars.iter().for_each(|name| {
-            add_str_to_accumulated(name, &mut acc_projections, &mut projected_names, expr_arena)
-        });
-        args.value_vars.iter().for_each(|name| {
-            add_str_to_accumulated(name, &mut acc_projections, &mut projected_names, expr_arena)
-        });
+    // the melt only needs the projected id_vars and the value_vars
+    // start from an empty projection, the input may have more columns than the melt uses
+    acc_projections.clear();
+    projected_names.clear();
+    for name in args.id_vars.iter().chain(args.value_vars.iter()) {
+        let node = expr_arena.add(AExpr::Column(Arc::from(name.as_str())));
+        add_expr_to_accumulated(node, &mut acc_projections, &mut projected_names, expr_arena)
+    }
 
-        proj_pd.pushdown_and_assign(
-            input,
-            acc_projections,
-            projected_names,
-            projections_seen,
-            lp_arena,
-            expr_arena,
-        )?;
-        if local_projections.is_empty() {
-            Ok(lp)
-        } else {
-            Ok(ALogicalPlanBuilder::from_lp(lp, expr_arena, lp_arena)
-                .project(local_projections)
-                .build())
-        }
+    proj_pd.pushdown_and_assign(
+        input,
+        acc_projections,
+        projected_names,
+        projections_seen,
+        lp_arena,
+        expr_arena,
+    )?;
+    if local_projections.is_empty() {
+        Ok(lp)
+    } else {
+        Ok(ALogicalPlanBuilder::from_lp(lp, expr_arena, lp_arena)
+            .project(local_projections)
+            .build())
     }
 }
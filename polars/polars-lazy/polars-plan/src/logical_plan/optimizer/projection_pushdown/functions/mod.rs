@@ -45,10 +45,12 @@ pub(super) fn process_functions(
             )?;
             Ok(lp)
         }
-        Explode { columns, .. } => {
+        Explode { columns, schema } => {
+            // the exploded columns determine the number of rows, so they are always needed
             columns.iter().for_each(|name| {
                 add_str_to_accumulated(name, &mut acc_projections, &mut projected_names, expr_arena)
             });
+            let projected = !acc_projections.is_empty();
             proj_pd.pushdown_and_assign(
                 input,
                 acc_projections,
@@ -57,11 +59,26 @@ pub(super) fn process_functions(
                 lp_arena,
                 expr_arena,
             )?;
-            Ok(lp)
+            if projected {
+                // only keep the columns that are still produced by the input
+                let input_schema = lp_arena.get(input).schema(lp_arena);
+                let schema = input_schema
+                    .iter_fields()
+                    .map(|fld| schema.get_field(fld.name()).unwrap_or(fld))
+                    .collect::<Schema>();
+                Ok(ALogicalPlan::MapFunction {
+                    input,
+                    function: Explode {
+                        columns: columns.clone(),
+                        schema: Arc::new(schema),
+                    },
+                })
+            } else {
+                Ok(lp)
+            }
         }
         Melt { args, .. } => process_melt(
             proj_pd,
-            lp,
             args,
             input,
             acc_projections,
@@ -83,6 +100,20 @@ pub(super) fn process_functions(
                         expr_arena,
                     )
                 }
+                #[cfg(feature = "dtype-struct")]
+                if let Unnest { columns, .. } = function {
+                    // the names of the fields depend on the columns they collide with
+                    let input_schema = lp_arena.get(input).schema(lp_arena);
+                    for name in unnest_collisions(&input_schema, columns) {
+                        let node = expr_arena.add(AExpr::Column(name));
+                        add_expr_to_accumulated(
+                            node,
+                            &mut acc_projections,
+                            &mut projected_names,
+                            expr_arena,
+                        )
+                    }
+                }
                 let expands_schema = matches!(function, FunctionNode::Unnest { .. });
 
                 let local_projections = proj_pd.pushdown_and_assign_check_schema(
@@ -121,3 +152,24 @@ pub(super) fn process_functions(
         }
     }
 }
+
+/// The columns of `input_schema` that are not unnested but have the name of a field of one of
+/// the struct `columns`.
+#[cfg(feature = "dtype-struct")]
+fn unnest_collisions(input_schema: &Schema, columns: &[Arc<str>]) -> Vec<Arc<str>> {
+    let is_unnested = |name: &str| columns.iter().any(|col| col.as_ref() == name);
+    let field_names = input_schema
+        .iter()
+        .filter(|(name, _)| is_unnested(name.as_str()))
+        .filter_map(|(_, dtype)| match dtype {
+            DataType::Struct(flds) => Some(flds.iter().map(|fld| fld.name().as_str())),
+            _ => None,
+        })
+        .flatten()
+        .collect::<PlHashSet<_>>();
+    input_schema
+        .iter_names()
+        .filter(|name| !is_unnested(name.as_str()) && field_names.contains(name.as_str()))
+        .map(|name| Arc::from(name.as_str()))
+        .collect()
+}
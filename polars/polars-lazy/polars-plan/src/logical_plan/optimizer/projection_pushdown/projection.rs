@@ -54,6 +54,19 @@ pub(super) fn process_projection(
         add_expr_to_accumulated(*e, &mut acc_projections, &mut projected_names, expr_arena);
     }
 
+    // a projection that doesn't use any column, e.g. `count()`, only needs the columns
+    // of a filter below it. An empty projection would read all columns.
+    if acc_projections.is_empty() && !local_projection.is_empty() {
+        if let ALogicalPlan::Selection { predicate, .. } = lp_arena.get(input) {
+            add_expr_to_accumulated(
+                *predicate,
+                &mut acc_projections,
+                &mut projected_names,
+                expr_arena,
+            );
+        }
+    }
+
     proj_pd.pushdown_and_assign(
         input,
         acc_projections,
@@ -3,11 +3,13 @@ use polars_utils::arena::Arena;
 #[cfg(all(feature = "strings", feature = "concat_str"))]
 use crate::dsl::function_expr::StringFunction;
 use crate::logical_plan::optimizer::stack_opt::OptimizationRule;
+use crate::logical_plan::optimizer::type_coercion::get_schema;
 use crate::logical_plan::*;
 use crate::prelude::function_expr::FunctionExpr;
 
+// integer overflow and division by zero are not folded, these are left to the kernels
 macro_rules! eval_binary_same_type {
-    ($lhs:expr, $operand: tt, $rhs:expr) => {{
+    ($lhs:expr, $operand: tt, $checked: ident, $rhs:expr) => {{
     if let (AExpr::Literal(lit_left), AExpr::Literal(lit_right)) = ($lhs, $rhs) {
         match (lit_left, lit_right) {
             (LiteralValue::Float32(x), LiteralValue::Float32(y)) => {
@@ -18,31 +20,31 @@ macro_rules! eval_binary_same_type {
             }
             #[cfg(feature = "dtype-i8")]
             (LiteralValue::Int8(x), LiteralValue::Int8(y)) => {
-                Some(AExpr::Literal(LiteralValue::Int8(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::Int8(v)))
             }
             #[cfg(feature = "dtype-i16")]
             (LiteralValue::Int16(x), LiteralValue::Int16(y)) => {
-                Some(AExpr::Literal(LiteralValue::Int16(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::Int16(v)))
             }
             (LiteralValue::Int32(x), LiteralValue::Int32(y)) => {
-                Some(AExpr::Literal(LiteralValue::Int32(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::Int32(v)))
             }
             (LiteralValue::Int64(x), LiteralValue::Int64(y)) => {
-                Some(AExpr::Literal(LiteralValue::Int64(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::Int64(v)))
             }
             #[cfg(feature = "dtype-u8")]
             (LiteralValue::UInt8(x), LiteralValue::UInt8(y)) => {
-                Some(AExpr::Literal(LiteralValue::UInt8(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::UInt8(v)))
             }
             #[cfg(feature = "dtype-u16")]
             (LiteralValue::UInt16(x), LiteralValue::UInt16(y)) => {
-                Some(AExpr::Literal(LiteralValue::UInt16(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::UInt16(v)))
             }
             (LiteralValue::UInt32(x), LiteralValue::UInt32(y)) => {
-                Some(AExpr::Literal(LiteralValue::UInt32(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::UInt32(v)))
             }
             (LiteralValue::UInt64(x), LiteralValue::UInt64(y)) => {
-                Some(AExpr::Literal(LiteralValue::UInt64(x $operand y)))
+                x.$checked(*y).map(|v| AExpr::Literal(LiteralValue::UInt64(v)))
             }
             _ => None,
         }
@@ -94,6 +96,9 @@ macro_rules! eval_binary_bool_type {
             (LiteralValue::Boolean(x), LiteralValue::Boolean(y)) => {
                 Some(AExpr::Literal(LiteralValue::Boolean(x $operand y)))
             }
+            (LiteralValue::Utf8(x), LiteralValue::Utf8(y)) => {
+                Some(AExpr::Literal(LiteralValue::Boolean(x $operand y)))
+            }
             _ => None,
         }
     } else {
@@ -103,15 +108,89 @@ macro_rules! eval_binary_bool_type {
     }}
 }
 
+/// The comparison that computes `not(left op right)`. Both sides have `null` semantics, e.g.
+/// `not(null == x)` and `null != x` are both `null`. Orderings are not inverted for floats,
+/// `NaN < x` and `NaN >= x` are both `false`.
+fn negate_comparison(
+    op: Operator,
+    left: Node,
+    right: Node,
+    expr_arena: &Arena<AExpr>,
+    lp_arena: &Arena<ALogicalPlan>,
+    lp_node: Node,
+) -> Option<Operator> {
+    use Operator::*;
+    let negated = match op {
+        Eq => NotEq,
+        NotEq => Eq,
+        Lt => GtEq,
+        GtEq => Lt,
+        Gt => LtEq,
+        LtEq => Gt,
+        _ => return None,
+    };
+    if matches!(op, Eq | NotEq) {
+        return Some(negated);
+    }
+    let schema = get_schema(lp_arena, lp_node);
+    let is_float = |node: Node| {
+        expr_arena
+            .get(node)
+            .get_type(&schema, Context::Default, expr_arena)
+            .map(|dtype| dtype.is_float())
+    };
+    match (is_float(left), is_float(right)) {
+        (Ok(false), Ok(false)) => Some(negated),
+        _ => None,
+    }
+}
+
+/// A predicate that is always `false` removes its input, an empty frame with the same schema
+/// is returned instead. A predicate that is always `true` is dropped.
+fn simplify_literal_predicate(
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    lp_node: Node,
+) -> Option<ALogicalPlan> {
+    use ALogicalPlan::*;
+    let lp = lp_arena.get(lp_node);
+    let predicate = lp.get_predicate()?;
+    match (expr_arena.get(predicate), lp) {
+        (AExpr::Literal(LiteralValue::Boolean(false)), _) => {
+            let schema = lp.schema(lp_arena).into_owned();
+            Some(DataFrameScan {
+                df: Arc::new(DataFrame::from(schema.as_ref())),
+                schema,
+                output_schema: None,
+                projection: None,
+                selection: None,
+            })
+        }
+        (AExpr::Literal(LiteralValue::Boolean(true)), Selection { input, .. }) => {
+            Some(lp_arena.get(*input).clone())
+        }
+        _ => None,
+    }
+}
+
 pub struct SimplifyBooleanRule {}
 
 impl OptimizationRule for SimplifyBooleanRule {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        simplify_literal_predicate(lp_arena, expr_arena, node)
+    }
+
     fn optimize_expr(
         &self,
         expr_arena: &mut Arena<AExpr>,
         expr_node: Node,
-        _: &Arena<ALogicalPlan>,
-        _: Node,
+        lp_arena: &Arena<ALogicalPlan>,
+        lp_node: Node,
     ) -> PolarsResult<Option<AExpr>> {
         let expr = expr_arena.get(expr_node);
         let out = match expr {
@@ -270,6 +349,16 @@ impl OptimizationRule for SimplifyBooleanRule {
                     AExpr::Literal(LiteralValue::Boolean(b)) => {
                         Some(AExpr::Literal(LiteralValue::Boolean(!b)))
                     }
+                    // not(a == b) => a != b
+                    // not(a < b) => a >= b
+                    AExpr::BinaryExpr { left, op, right } => negate_comparison(
+                        *op, *left, *right, expr_arena, lp_arena, lp_node,
+                    )
+                    .map(|op| AExpr::BinaryExpr {
+                        left: *left,
+                        op,
+                        right: *right,
+                    }),
                     _ => None,
                 }
             }
@@ -435,6 +524,62 @@ fn get_string_concat_input(node: Node, expr_arena: &Arena<AExpr>) -> Option<&[No
     }
 }
 
+/// Whether `node` is a numeric literal equal to `value`, casts of literals are not yet inlined
+/// when this runs.
+#[allow(clippy::float_cmp)]
+fn is_literal_number(expr_arena: &Arena<AExpr>, node: Node, value: f64) -> bool {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => lv.to_anyvalue().map_or(false, |av| {
+            av.dtype().is_numeric() && av.extract::<f64>() == Some(value)
+        }),
+        AExpr::Cast {
+            expr, data_type, ..
+        } if data_type.is_numeric() => is_literal_number(expr_arena, *expr, value),
+        _ => false,
+    }
+}
+
+/// `x + 0`, `0 + x`, `x - 0`, `x * 1`, `1 * x` and `x / 1` => `x`
+///
+/// Only if this doesn't change the output dtype, e.g. `int_col + 0.0` is a float, and the
+/// output name is kept.
+#[allow(clippy::too_many_arguments)]
+fn remove_neutral_element(
+    lp_arena: &Arena<ALogicalPlan>,
+    lp_node: Node,
+    expr_arena: &Arena<AExpr>,
+    expr_node: Node,
+    left: Node,
+    op: Operator,
+    right: Node,
+) -> Option<AExpr> {
+    use Operator::*;
+    let is = |node: Node, value: f64| is_literal_number(expr_arena, node, value);
+    let kept = match op {
+        Plus | Minus if is(right, 0.0) => left,
+        Plus if is(left, 0.0) => right,
+        Multiply | Divide | TrueDivide if is(right, 1.0) => left,
+        Multiply if is(left, 1.0) => right,
+        _ => return None,
+    };
+    let schema = get_schema(lp_arena, lp_node);
+    let to_field = |node: Node| {
+        expr_arena
+            .get(node)
+            .to_field(&schema, Context::Default, expr_arena)
+            .ok()
+    };
+    let field = to_field(expr_node)?;
+    let kept_field = to_field(kept)?;
+    if field.data_type() != kept_field.data_type() {
+        None
+    } else if field.name() == kept_field.name() {
+        Some(expr_arena.get(kept).clone())
+    } else {
+        Some(AExpr::Alias(kept, Arc::from(field.name().as_str())))
+    }
+}
+
 pub struct SimplifyExprRule {}
 
 impl OptimizationRule for SimplifyExprRule {
@@ -459,7 +604,7 @@ impl OptimizationRule for SimplifyExprRule {
                 #[allow(clippy::manual_map)]
                 let out = match op {
                     Operator::Plus => {
-                        match eval_binary_same_type!(left_aexpr, +, right_aexpr) {
+                        match eval_binary_same_type!(left_aexpr, +, checked_add, right_aexpr) {
                             Some(new) => Some(new),
                             None => {
                                 // try to replace addition of string columns with `concat_str`
@@ -482,9 +627,15 @@ impl OptimizationRule for SimplifyExprRule {
                             }
                         }
                     }
-                    Operator::Minus => eval_binary_same_type!(left_aexpr, -, right_aexpr),
-                    Operator::Multiply => eval_binary_same_type!(left_aexpr, *, right_aexpr),
-                    Operator::Divide => eval_binary_same_type!(left_aexpr, /, right_aexpr),
+                    Operator::Minus => {
+                        eval_binary_same_type!(left_aexpr, -, checked_sub, right_aexpr)
+                    }
+                    Operator::Multiply => {
+                        eval_binary_same_type!(left_aexpr, *, checked_mul, right_aexpr)
+                    }
+                    Operator::Divide => {
+                        eval_binary_same_type!(left_aexpr, /, checked_div, right_aexpr)
+                    }
                     Operator::TrueDivide => {
                         if let (AExpr::Literal(lit_left), AExpr::Literal(lit_right)) =
                             (left_aexpr, right_aexpr)
@@ -531,7 +682,9 @@ impl OptimizationRule for SimplifyExprRule {
                         }
                     }
                     Operator::FloorDivide => None,
-                    Operator::Modulus => eval_binary_same_type!(left_aexpr, %, right_aexpr),
+                    Operator::Modulus => {
+                        eval_binary_same_type!(left_aexpr, %, checked_rem, right_aexpr)
+                    }
                     Operator::Lt => eval_binary_bool_type!(left_aexpr, <, right_aexpr),
                     Operator::Gt => eval_binary_bool_type!(left_aexpr, >, right_aexpr),
                     Operator::Eq => eval_binary_bool_type!(left_aexpr, ==, right_aexpr),
@@ -546,6 +699,15 @@ impl OptimizationRule for SimplifyExprRule {
                     return Ok(out);
                 }
 
+                // x + 0 => x
+                // x * 1 => x
+                let out = remove_neutral_element(
+                    _lp_arena, _lp_node, expr_arena, expr_node, *left, *op, *right,
+                );
+                if out.is_some() {
+                    return Ok(out);
+                }
+
                 // Null propagation.
                 let left_is_null = matches!(left_aexpr, AExpr::Literal(LiteralValue::Null));
                 let right_is_null = matches!(right_aexpr, AExpr::Literal(LiteralValue::Null));
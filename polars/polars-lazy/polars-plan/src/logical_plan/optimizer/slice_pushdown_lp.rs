@@ -13,6 +13,19 @@ struct State {
     len: IdxSize,
 }
 
+impl State {
+    /// The state that limits an input to the rows that may end up in the slice.
+    /// This is only known for slices that start at a positive offset.
+    fn limit_input(&self) -> Option<State> {
+        (self.offset >= 0).then(|| State {
+            offset: 0,
+            len: IdxSize::try_from(self.offset)
+                .unwrap_or(IdxSize::MAX)
+                .saturating_add(self.len),
+        })
+    }
+}
+
 impl SlicePushDown {
     pub(super) fn new(streaming: bool) -> Self {
         Self { streaming }
@@ -169,8 +182,16 @@ impl SlicePushDown {
                 output_schema,
                 predicate,
                 mut options
-            }, Some(state)) if state.offset == 0 && predicate.is_none() => {
-                options.n_rows = Some(state.len as usize);
+            }, Some(state)) if state.offset >= 0 && predicate.is_none() => {
+                // the slice is relative to the rows that are already skipped
+                let offset = state.offset as usize;
+                // the reader starts the row count after the skipped rows
+                options.skip_rows += offset;
+                let len = state.len as usize;
+                options.n_rows = Some(match options.n_rows {
+                    Some(n_rows) => std::cmp::min(len, n_rows.saturating_sub(offset)),
+                    None => len,
+                });
                 let lp = IpcScan {
                     path,
                     file_info,
@@ -193,6 +214,16 @@ impl SlicePushDown {
             }, Some(state)) if state.offset >= 0 && predicate.is_none() => {
                 options.skip_rows += state.offset as usize;
                 options.n_rows = Some(state.len as usize);
+                // the row count keeps counting from before the slice
+                if let Some(rc) = &mut options.row_count {
+                    rc.offset = IdxSize::try_from(state.offset)
+                        .ok()
+                        .and_then(|offset| rc.offset.checked_add(offset))
+                        .ok_or_else(|| polars_err!(
+                            ComputeError: "row count overflows {}; use a UInt64 row count",
+                            IDX_DTYPE
+                        ))?;
+                }
 
                 let lp = CsvScan {
                     path,
@@ -204,7 +235,16 @@ impl SlicePushDown {
                 Ok(lp)
             }
 
-            (Union {inputs, mut options }, Some(state)) => {
+            (Union {inputs, mut options }, Some(state)) if state.offset >= 0 => {
+                // a single input never has to produce more than `offset + len` rows
+                let input_state = state.limit_input();
+                for &input in &inputs {
+                    let input_lp = lp_arena.take(input);
+                    let input_lp = self.pushdown(input_lp, input_state, lp_arena, expr_arena)?;
+                    lp_arena.replace(input, input_lp);
+                }
+
+                // the union stops executing its inputs once the slice is filled
                 options.slice = true;
                 options.slice_offset = state.offset;
                 options.slice_len = state.len;
@@ -218,13 +258,24 @@ impl SlicePushDown {
                 right_on,
                 mut options
             }, Some(state)) if !self.streaming => {
+                // The inputs can only be limited if every input row produces at least one
+                // output row in input order, so that the first `offset + len` output rows
+                // only depend on the first `offset + len` input rows.
+                // - left joins: every left row is kept, in order of the left table
+                // - cross joins: the output is the left table major, so both sides are bound
+                let (state_left, state_right) = match options.how {
+                    JoinType::Left => (state.limit_input(), None),
+                    JoinType::Cross => (state.limit_input(), state.limit_input()),
+                    _ => (None, None),
+                };
+
                 // first restart optimization in both inputs and get the updated LP
                 let lp_left = lp_arena.take(input_left);
-                let lp_left = self.pushdown(lp_left, None, lp_arena, expr_arena)?;
+                let lp_left = self.pushdown(lp_left, state_left, lp_arena, expr_arena)?;
                 let input_left = lp_arena.add(lp_left);
 
                 let lp_right = lp_arena.take(input_right);
-                let lp_right = self.pushdown(lp_right, None, lp_arena, expr_arena)?;
+                let lp_right = self.pushdown(lp_right, state_right, lp_arena, expr_arena)?;
                 let input_right = lp_arena.add(lp_right);
 
                 // then assign the slice state to the join operation
@@ -282,6 +333,24 @@ impl SlicePushDown {
                     args
                 })
             }
+            (Slice {
+                input,
+                offset,
+                len
+            }, Some(previous_state)) if offset >= 0 && previous_state.offset >= 0 => {
+                // combine both slices into a single slice of the input
+                let alp = lp_arena.take(input);
+                let state = Some(State {
+                    offset: offset + previous_state.offset,
+                    len: std::cmp::min(
+                        previous_state.len,
+                        len.saturating_sub(
+                            IdxSize::try_from(previous_state.offset).unwrap_or(IdxSize::MAX)
+                        )
+                    )
+                });
+                self.pushdown(alp, state, lp_arena, expr_arena)
+            }
             (Slice {
                 input,
                 offset,
@@ -328,6 +397,8 @@ impl SlicePushDown {
             | m @ (Distinct {..}, _)
             | m @ (HStack {..},_)
             | m @ (Aggregate{..},_)
+            // slices with a negative offset need the length of all inputs
+            | m @ (Union{..},_)
             // blocking in streaming
             | m @ (Join{..},_)
             => {
@@ -0,0 +1,257 @@
+use std::sync::Arc;
+
+use super::*;
+use crate::dsl::function_expr::{FunctionExpr, StructFunction};
+
+/// How a struct column of a scan is used by the expressions above the scan.
+enum StructUsage {
+    /// The struct column itself is used, so all fields must be read.
+    Full,
+    /// Only these (nested) field paths are used.
+    Fields(Vec<Vec<Arc<str>>>),
+}
+
+/// If a projection (and the filters/ scan predicate below it) only access fields of a
+/// struct column of a parquet scan, we let the scan only read those fields. The fields of an
+/// unnested struct column are accessed as columns.
+pub(super) struct StructFieldPushDown {}
+
+/// Returns the column and the field path if this expression is a chain of
+/// `struct.field` accesses on a column.
+fn field_path(node: Node, expr_arena: &Arena<AExpr>) -> Option<(Arc<str>, Vec<Arc<str>>)> {
+    match expr_arena.get(node) {
+        AExpr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::FieldByName(name)),
+            ..
+        } if input.len() == 1 => {
+            let (column, mut path) = match expr_arena.get(input[0]) {
+                AExpr::Column(column) => (column.clone(), vec![]),
+                _ => field_path(input[0], expr_arena)?,
+            };
+            path.push(name.clone());
+            Some((column, path))
+        }
+        _ => None,
+    }
+}
+
+fn collect_usage(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    usage: &mut PlHashMap<Arc<str>, StructUsage>,
+) {
+    if let Some((column, path)) = field_path(node, expr_arena) {
+        match usage
+            .entry(column)
+            .or_insert_with(|| StructUsage::Fields(vec![]))
+        {
+            StructUsage::Fields(paths) => paths.push(path),
+            StructUsage::Full => {}
+        }
+        return;
+    }
+    let ae = expr_arena.get(node);
+    if let AExpr::Column(column) = ae {
+        usage.insert(column.clone(), StructUsage::Full);
+        return;
+    }
+    let mut inputs = vec![];
+    ae.nodes(&mut inputs);
+    for input in inputs {
+        collect_usage(input, expr_arena, usage)
+    }
+}
+
+/// Only keep the fields of the struct that are accessed by `paths`.
+fn prune_struct(dtype: &DataType, paths: &[&[Arc<str>]]) -> DataType {
+    match dtype {
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .filter_map(|fld| {
+                    let sub_paths = paths
+                        .iter()
+                        .filter(|path| path[0].as_ref() == fld.name().as_str())
+                        .map(|path| &path[1..])
+                        .collect::<Vec<_>>();
+                    if sub_paths.is_empty() {
+                        None
+                    } else if sub_paths.iter().any(|path| path.is_empty()) {
+                        Some(fld.clone())
+                    } else {
+                        Some(Field::new(
+                            fld.name(),
+                            prune_struct(fld.data_type(), &sub_paths),
+                        ))
+                    }
+                })
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+/// Translate the usage of the output columns of an unnest to the usage of its input columns,
+/// where a used field becomes a field path of its struct column.
+///
+/// The fields whose name collides with another output column are always read, as dropping
+/// them would change the names that the collisions resolve to.
+fn unnest_usage(
+    mut usage: PlHashMap<Arc<str>, StructUsage>,
+    input_schema: &Schema,
+    columns: &[Arc<str>],
+    collision: UnnestCollision,
+) -> Option<PlHashMap<Arc<str>, StructUsage>> {
+    let mut sources = Vec::with_capacity(input_schema.len() * 2);
+    for (name, dtype) in input_schema.iter() {
+        if columns.iter().any(|col| col.as_ref() == name.as_str()) {
+            match dtype {
+                DataType::Struct(flds) => sources.extend(
+                    flds.iter()
+                        .map(|fld| (Some(name.as_str()), fld.name().as_str())),
+                ),
+                _ => return None,
+            }
+        } else {
+            sources.push((None, name.as_str()))
+        }
+    }
+    let names = polars_core::frame::_unnest_names(sources.iter().copied(), collision).ok()?;
+    let mut counts = PlHashMap::with_capacity(sources.len());
+    for (_, name) in &sources {
+        *counts.entry(*name).or_insert(0usize) += 1;
+    }
+
+    let mut new_usage = PlHashMap::with_capacity(usage.len());
+    // the field paths of every unnested struct, and whether one of its fields is used
+    let mut struct_paths: PlHashMap<&str, (Vec<Vec<Arc<str>>>, bool)> = PlHashMap::new();
+    for ((parent, name), out_name) in sources.iter().zip(names) {
+        let used = out_name.and_then(|out_name| usage.remove(out_name.as_str()));
+        match parent {
+            None => {
+                if let Some(used) = used {
+                    new_usage.insert(Arc::from(*name), used);
+                }
+            }
+            Some(parent) => {
+                let (paths, is_used) = struct_paths.entry(*parent).or_default();
+                let field = Arc::<str>::from(*name);
+                match used {
+                    Some(StructUsage::Full) => {
+                        paths.push(vec![field]);
+                        *is_used = true;
+                    }
+                    Some(StructUsage::Fields(field_paths)) => {
+                        paths.extend(
+                            field_paths
+                                .into_iter()
+                                .map(|path| std::iter::once(field.clone()).chain(path).collect()),
+                        );
+                        *is_used = true;
+                    }
+                    None if counts[name] > 1 => paths.push(vec![field]),
+                    None => {}
+                }
+            }
+        }
+    }
+    for (parent, (paths, is_used)) in struct_paths {
+        if is_used {
+            new_usage.insert(Arc::from(parent), StructUsage::Fields(paths));
+        }
+    }
+    Some(new_usage)
+}
+
+impl OptimizationRule for StructFieldPushDown {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        use ALogicalPlan::*;
+        let (exprs, mut input) = match lp_arena.get(node) {
+            Projection { expr, input, .. } => (expr.clone(), *input),
+            LocalProjection { expr, input, .. } => (expr.clone(), *input),
+            _ => return None,
+        };
+
+        let mut usage = PlHashMap::new();
+        for e in exprs {
+            collect_usage(e, expr_arena, &mut usage)
+        }
+        // walk through the filters to the scan
+        let mut path = vec![];
+        loop {
+            match lp_arena.get(input) {
+                Selection {
+                    input: next,
+                    predicate,
+                } => {
+                    collect_usage(*predicate, expr_arena, &mut usage);
+                    path.push(input);
+                    input = *next;
+                }
+                MapFunction {
+                    input: next,
+                    function: FunctionNode::Unnest { columns, collision },
+                } => {
+                    let input_schema = lp_arena.get(*next).schema(lp_arena);
+                    usage = unnest_usage(usage, &input_schema, columns, *collision)?;
+                    path.push(input);
+                    input = *next;
+                }
+                ParquetScan {
+                    predicate, options, ..
+                } => {
+                    // the file is read once for multiple scans
+                    if options.file_counter > 1 {
+                        return None;
+                    }
+                    if let Some(predicate) = predicate {
+                        collect_usage(*predicate, expr_arena, &mut usage);
+                    }
+                    break;
+                }
+                _ => return None,
+            }
+        }
+
+        let mut scan = lp_arena.get(input).clone();
+        if let ParquetScan {
+            file_info,
+            output_schema,
+            ..
+        } = &mut scan
+        {
+            let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+            let mut new_schema = None;
+            for (name, dtype) in schema.iter() {
+                if let (DataType::Struct(_), Some(StructUsage::Fields(paths))) =
+                    (dtype, usage.get(name.as_str()))
+                {
+                    let paths = paths.iter().map(|p| p.as_slice()).collect::<Vec<_>>();
+                    let pruned = prune_struct(dtype, &paths);
+                    if &pruned != dtype {
+                        new_schema
+                            .get_or_insert_with(|| schema.as_ref().clone())
+                            .set_dtype(name, pruned);
+                    }
+                }
+            }
+            *output_schema = Some(Arc::new(new_schema?));
+        }
+
+        // rebuild the plan on top of the new scan
+        let mut new_input = lp_arena.add(scan);
+        for node in path.into_iter().rev() {
+            let lp = lp_arena.get(node);
+            let lp = lp.with_exprs_and_input(lp.get_exprs(), vec![new_input]);
+            new_input = lp_arena.add(lp);
+        }
+        let lp = lp_arena.get(node);
+        Some(lp.with_exprs_and_input(lp.get_exprs(), vec![new_input]))
+    }
+}
@@ -1,6 +1,7 @@
 use polars_utils::matches_any_order;
 
 use super::*;
+use crate::logical_plan::aexpr::decimal_arithmetic_dtype;
 
 macro_rules! unpack {
     ($packed:expr) => {{
@@ -20,7 +21,7 @@ fn compares_cat_to_string(type_left: &DataType, type_right: &DataType, op: Opera
                 type_left,
                 type_right,
                 DataType::Utf8,
-                DataType::Categorical(_)
+                DataType::Categorical(_, _)
             )
     }
     #[cfg(not(feature = "dtype-categorical"))]
@@ -64,7 +65,7 @@ fn is_cat_str_binary(type_left: &DataType, type_right: &DataType) -> bool {
             type_left,
             type_right,
             DataType::Utf8,
-            DataType::Categorical(_)
+            DataType::Categorical(_, _)
         )
     }
     #[cfg(not(feature = "dtype-categorical"))]
@@ -198,10 +199,88 @@ fn err_date_str_compare() -> PolarsResult<()> {
     }
 }
 
+/// Whether `expr_node` is the predicate of `lp_node`, or one of the operands of `&` and `|`
+/// that make up that predicate. In these positions a `null` result filters the same rows as
+/// `false`.
+fn is_in_predicate(
+    expr_arena: &Arena<AExpr>,
+    lp_arena: &Arena<ALogicalPlan>,
+    lp_node: Node,
+    expr_node: Node,
+) -> bool {
+    let mut stack = match lp_arena.get(lp_node).get_predicate() {
+        Some(predicate) => vec![predicate],
+        None => return false,
+    };
+    while let Some(node) = stack.pop() {
+        if node == expr_node {
+            return true;
+        }
+        if let AExpr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::Or,
+            right,
+        } = expr_arena.get(node)
+        {
+            stack.push(*left);
+            stack.push(*right);
+        }
+    }
+    false
+}
+
+/// The outcome of comparing an integer expression with an integer literal that cannot be
+/// represented in the dtype of that expression, e.g. `uint8_col == 300` is never `true`.
+/// Otherwise the whole column would be cast to the supertype of the two.
+///
+/// The comparison of a `null` is `null`, this is only the same as `false` in a predicate.
+fn compare_out_of_range_literal(
+    left: &AExpr,
+    type_left: &DataType,
+    op: Operator,
+    right: &AExpr,
+    type_right: &DataType,
+) -> Option<bool> {
+    use Operator::*;
+    // normalize to `expr <op> literal`
+    let (dtype, lv, op) = match (left, right) {
+        (AExpr::Literal(_), AExpr::Literal(_)) => return None,
+        (_, AExpr::Literal(lv)) => (type_left, lv, op),
+        (AExpr::Literal(lv), _) => {
+            let op = match op {
+                Lt => Gt,
+                LtEq => GtEq,
+                Gt => Lt,
+                GtEq => LtEq,
+                op => op,
+            };
+            (type_right, lv, op)
+        }
+        _ => return None,
+    };
+    if !dtype.is_integer() || lv.is_float() || matches!(lv, LiteralValue::Series(_)) {
+        return None;
+    }
+    let value = lv.to_anyvalue()?;
+    if !value.dtype().is_integer() || dtype.value_within_range(value.clone()) {
+        return None;
+    }
+    // the range of every integer dtype contains zero
+    let above_range = value.extract::<f64>()? > 0.0;
+    match op {
+        Eq => Some(false),
+        NotEq => Some(true),
+        Lt | LtEq => Some(above_range),
+        Gt | GtEq => Some(!above_range),
+        _ => None,
+    }
+}
+
 pub(super) fn process_binary(
     expr_arena: &mut Arena<AExpr>,
     lp_arena: &Arena<ALogicalPlan>,
     lp_node: Node,
+    expr_node: Node,
     node_left: Node,
     op: Operator,
     node_right: Node,
@@ -223,7 +302,7 @@ pub(super) fn process_binary(
             return Ok(None)
         }
         #[cfg(feature = "dtype-categorical")]
-        (Utf8 | Categorical(_), dt, op) | (dt, Utf8 | Categorical(_), op)
+        (Utf8 | Categorical(_, _), dt, op) | (dt, Utf8 | Categorical(_, _), op)
             if op.is_comparison() && dt.is_numeric() =>
         {
             return Ok(None)
@@ -239,11 +318,39 @@ pub(super) fn process_binary(
         (Struct(_), Struct(_), _op) => return Ok(None),
         _ => {}
     }
+    if is_in_predicate(expr_arena, lp_arena, lp_node, expr_node) {
+        match compare_out_of_range_literal(left, &type_left, op, right, &type_right) {
+            Some(false) => return Ok(Some(AExpr::Literal(LiteralValue::Boolean(false)))),
+            // true for all valid values
+            Some(true) => {
+                let input = if matches!(left, AExpr::Literal(_)) {
+                    node_right
+                } else {
+                    node_left
+                };
+                return Ok(Some(AExpr::Function {
+                    input: vec![input],
+                    function: BooleanFunction::IsNotNull.into(),
+                    options: FunctionOptions {
+                        collect_groups: ApplyOptions::ApplyGroups,
+                        ..Default::default()
+                    },
+                }));
+            }
+            None => {}
+        }
+    }
     let compare_cat_to_string = compares_cat_to_string(&type_left, &type_right, op);
     let datetime_arithmetic = is_datetime_arithmetic(&type_left, &type_right, op);
     let list_arithmetic = is_list_arithmetic(&type_left, &type_right, op);
     str_numeric_arithmetic(&type_left, &type_right)?;
 
+    // decimals are not cast to their supertype, as the result of decimal arithmetic depends
+    // on the precision and scale of both sides
+    if decimal_arithmetic_dtype(&type_left, &type_right, op).is_some() {
+        return Ok(None);
+    }
+
     // Special path for list arithmetic
     if list_arithmetic {
         return process_list_arithmetic(
@@ -218,9 +218,9 @@ fn modify_supertype(
         match (type_left, type_right, left, right) {
             // if the we compare a categorical to a literal string we want to cast the literal to categorical
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(_), Utf8, _, AExpr::Literal(_))
-            | (Utf8, Categorical(_), AExpr::Literal(_), _) => {
-                st = Categorical(None);
+            (Categorical(_, _), Utf8, _, AExpr::Literal(_))
+            | (Utf8, Categorical(_, _), AExpr::Literal(_), _) => {
+                st = Categorical(None, Default::default());
             }
             // when then expression literals can have a different list type.
             // so we cast the literal to the other hand side.
@@ -250,7 +250,8 @@ fn get_input(lp_arena: &Arena<ALogicalPlan>, lp_node: Node) -> [Option<Node>; 2]
     inputs
 }
 
-fn get_schema(lp_arena: &Arena<ALogicalPlan>, lp_node: Node) -> Cow<'_, SchemaRef> {
+/// The schema the expressions of `lp_node` are evaluated on.
+pub(super) fn get_schema(lp_arena: &Arena<ALogicalPlan>, lp_node: Node) -> Cow<'_, SchemaRef> {
     match get_input(lp_arena, lp_node) {
         [Some(input), _] => lp_arena.get(input).schema(lp_arena),
         // files don't have an input, so we must take their schema
@@ -330,7 +331,11 @@ impl OptimizationRule for TypeCoercionRule {
                 left: node_left,
                 op,
                 right: node_right,
-            } => return process_binary(expr_arena, lp_arena, lp_node, node_left, op, node_right),
+            } => {
+                return process_binary(
+                    expr_arena, lp_arena, lp_node, expr_node, node_left, op, node_right,
+                )
+            }
             #[cfg(feature = "is_in")]
             AExpr::Function {
                 function: FunctionExpr::Boolean(BooleanFunction::IsIn),
@@ -352,10 +357,10 @@ impl OptimizationRule for TypeCoercionRule {
                     // cast both local and global string cache
                     // note that there might not yet be a rev
                     #[cfg(feature = "dtype-categorical")]
-                    (DataType::Categorical(_), DataType::Utf8) => {
+                    (DataType::Categorical(_, _), DataType::Utf8) => {
                         AExpr::Cast {
                             expr: other_node,
-                            data_type: DataType::Categorical(None),
+                            data_type: DataType::Categorical(None, Default::default()),
                             // does not matter
                             strict: false,
                         }
@@ -364,6 +369,21 @@ impl OptimizationRule for TypeCoercionRule {
                         polars_bail!(ComputeError: "cannot compare {:?} to {:?} type in 'is_in' operation", dt, type_other)
                     }
                     (DataType::List(_), _) | (_, DataType::List(_)) => return Ok(None),
+                    // a literal list is cast up front, the values that don't fit in the
+                    // dtype of the column can never be found and are removed
+                    (a, b) if a.is_integer() && b.is_integer() => {
+                        match expr_arena.get(other_node) {
+                            AExpr::Literal(LiteralValue::Series(s)) => {
+                                let s = narrow_literal_series(s, a)?;
+                                AExpr::Literal(LiteralValue::Series(SpecialEq::new(s)))
+                            }
+                            _ => AExpr::Cast {
+                                expr: other_node,
+                                data_type: type_left,
+                                strict: false,
+                            },
+                        }
+                    }
                     #[cfg(feature = "dtype-struct")]
                     (DataType::Struct(_), _) | (_, DataType::Struct(_)) => return Ok(None),
                     // if right is another type, we cast it to left
@@ -496,6 +516,15 @@ impl OptimizationRule for TypeCoercionRule {
     }
 }
 
+/// Cast the values of a literal to `dtype`, values that don't fit are dropped.
+#[cfg(feature = "is_in")]
+fn narrow_literal_series(s: &Series, dtype: &DataType) -> PolarsResult<Series> {
+    let casted = s.cast(dtype)?;
+    // a value that was valid and is null after the cast didn't fit
+    let fits = &casted.is_not_null() | &s.is_null();
+    casted.filter(&fits)
+}
+
 fn early_escape(type_self: &DataType, type_other: &DataType) -> Option<()> {
     if type_self == type_other
         || matches!(type_self, DataType::Unknown)
@@ -518,7 +547,10 @@ mod test {
     #[test]
     fn test_categorical_utf8() {
         let mut rules: Vec<Box<dyn OptimizationRule>> = vec![Box::new(TypeCoercionRule {})];
-        let schema = Schema::from_iter([Field::new("fruits", DataType::Categorical(None))]);
+        let schema = Schema::from_iter([Field::new(
+            "fruits",
+            DataType::Categorical(None, Default::default()),
+        )]);
 
         let expr = col("fruits").eq(lit("somestr"));
         let out = optimize_expr(expr.clone(), schema.clone(), &mut rules);
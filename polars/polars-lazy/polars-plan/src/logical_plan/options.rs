@@ -1,12 +1,20 @@
-use std::path::PathBuf;
+#[cfg(feature = "parquet")]
+use std::collections::BTreeMap;
+#[cfg(feature = "ipc_streaming")]
+use std::fmt::{Debug, Formatter};
+#[cfg(feature = "ipc_streaming")]
+use std::io::Write;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "ipc_streaming")]
+use std::sync::Mutex;
 
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
-use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::csv::{CsvEncoding, DateFormats, NullValues, RaggedLineMode};
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcCompression;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::ParquetCompression;
+use polars_io::parquet::{ParquetColumnOptions, ParquetCompression, ParquetReadStats};
 use polars_io::RowCount;
 #[cfg(feature = "dynamic_groupby")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
@@ -17,6 +25,87 @@ use crate::prelude::Expr;
 
 pub type FileCount = u32;
 
+/// A column with the path of the scanned file.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "(String, String)", into = "(String, String)")
+)]
+pub struct FilePathColumn {
+    /// The path as a single value column. It is categorical and the scans of the files of
+    /// a glob share the categories, so that the columns of the files can be concatenated.
+    pub column: Series,
+    path: String,
+}
+
+impl FilePathColumn {
+    /// The file path column of the scan of a single file.
+    pub fn new(name: &str, path: &Path) -> PolarsResult<Self> {
+        let path = path.to_string_lossy().into_owned();
+        let column = Series::new(name, [path.as_str()]);
+        #[cfg(feature = "dtype-categorical")]
+        let column = column.cast(&DataType::Categorical(None, Default::default()))?;
+        Ok(Self { column, path })
+    }
+
+    /// The file path columns of the scans of `paths`.
+    pub fn new_for_paths(name: &str, paths: &[PathBuf]) -> PolarsResult<Vec<Self>> {
+        let paths = paths
+            .iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        let column = Series::new(name, &paths);
+        #[cfg(feature = "dtype-categorical")]
+        let column = column.cast(&DataType::Categorical(None, Default::default()))?;
+        Ok(paths
+            .into_iter()
+            .enumerate()
+            .map(|(i, path)| Self {
+                column: column.slice(i as i64, 1),
+                path,
+            })
+            .collect())
+    }
+
+    pub fn name(&self) -> &str {
+        self.column.name()
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.column.dtype()
+    }
+
+    /// The path of the scanned file.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl PartialEq for FilePathColumn {
+    fn eq(&self, other: &Self) -> bool {
+        self.column.name() == other.column.name() && self.column.series_equal(&other.column)
+    }
+}
+
+impl Eq for FilePathColumn {}
+
+#[cfg(feature = "serde")]
+impl From<FilePathColumn> for (String, String) {
+    fn from(value: FilePathColumn) -> Self {
+        (value.column.name().to_string(), value.path)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<(String, String)> for FilePathColumn {
+    type Error = PolarsError;
+
+    fn try_from((name, path): (String, String)) -> PolarsResult<Self> {
+        Self::new(&name, Path::new(&path))
+    }
+}
+
 #[cfg(feature = "csv")]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -33,11 +122,14 @@ pub struct CsvParserOptions {
     pub ignore_errors: bool,
     pub cache: bool,
     pub null_values: Option<NullValues>,
+    pub ragged_lines: RaggedLineMode,
     pub rechunk: bool,
     pub encoding: CsvEncoding,
     pub row_count: Option<RowCount>,
     pub try_parse_dates: bool,
+    pub date_formats: Option<DateFormats>,
     pub file_counter: FileCount,
+    pub file_path_column: Option<FilePathColumn>,
 }
 
 #[cfg(feature = "parquet")]
@@ -53,10 +145,16 @@ pub struct ParquetOptions {
     pub file_counter: FileCount,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Add the `key=value` directories of the path as columns.
+    pub hive_partitioning: bool,
+    pub file_path_column: Option<FilePathColumn>,
+    /// Count the decoded row groups and the prefiltered rows of the scan in these stats.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub read_stats: Option<Arc<ParquetReadStats>>,
 }
 
 #[cfg(feature = "parquet")]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -69,6 +167,37 @@ pub struct ParquetWriteOptions {
     pub data_pagesize_limit: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Compression, statistics and dictionary encoding of specific columns.
+    pub column_options: BTreeMap<String, ParquetColumnOptions>,
+}
+
+#[cfg(feature = "parquet")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetPartitionOptions {
+    /// Options of the parquet files in the partitions.
+    pub write_options: ParquetWriteOptions,
+    /// Start a new file in a partition once a file has this many rows.
+    /// If `None` every partition is written to a single file.
+    pub max_rows_per_file: Option<usize>,
+    /// The maximum number of files that are open at the same time. If more partitions
+    /// are active, the least recently used file is closed.
+    pub max_open_files: usize,
+    /// Also write the partition columns to the files. These columns can otherwise
+    /// be recovered from the `key=value` directories.
+    pub include_partition_columns: bool,
+}
+
+#[cfg(feature = "parquet")]
+impl Default for ParquetPartitionOptions {
+    fn default() -> Self {
+        Self {
+            write_options: Default::default(),
+            max_rows_per_file: None,
+            max_open_files: 64,
+            include_partition_columns: false,
+        }
+    }
 }
 
 #[cfg(feature = "ipc")]
@@ -96,6 +225,8 @@ pub struct IpcScanOptions {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptionsInner {
     pub n_rows: Option<usize>,
+    /// Set by the slice pushdown.
+    pub skip_rows: usize,
     pub with_columns: Option<Arc<Vec<String>>>,
     pub cache: bool,
     pub row_count: Option<RowCount>,
@@ -108,6 +239,7 @@ impl From<IpcScanOptions> for IpcScanOptionsInner {
     fn from(options: IpcScanOptions) -> Self {
         Self {
             n_rows: options.n_rows,
+            skip_rows: 0,
             with_columns: options.with_columns,
             cache: options.cache,
             row_count: options.row_count,
@@ -311,8 +443,49 @@ pub struct FileSinkOptions {
 pub enum FileType {
     #[cfg(feature = "parquet")]
     Parquet(ParquetWriteOptions),
+    /// Write a hive partitioned dataset; `path` is the root directory.
+    #[cfg(feature = "parquet")]
+    PartitionedParquet {
+        partition_by: Vec<String>,
+        options: ParquetPartitionOptions,
+    },
     #[cfg(feature = "ipc")]
     Ipc(IpcWriterOptions),
+    /// Write an Arrow IPC stream to `writer`; `path` is not used.
+    #[cfg(feature = "ipc_streaming")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    IpcStream {
+        writer: SharedWriter,
+        options: IpcWriterOptions,
+    },
+}
+
+/// A writer that is owned by a sink. It is shared so that the plan can be cloned,
+/// the sink takes the writer once the query is executed.
+#[cfg(feature = "ipc_streaming")]
+#[derive(Clone)]
+pub struct SharedWriter(Arc<Mutex<Option<Box<dyn Write + Send + Sync>>>>);
+
+#[cfg(feature = "ipc_streaming")]
+impl SharedWriter {
+    pub fn new<W: Write + Send + Sync + 'static>(writer: W) -> Self {
+        Self(Arc::new(Mutex::new(Some(Box::new(writer)))))
+    }
+
+    pub fn take(&self) -> PolarsResult<Box<dyn Write + Send + Sync>> {
+        self.0
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| polars_err!(ComputeError: "the writer of the sink is already used"))
+    }
+}
+
+#[cfg(feature = "ipc_streaming")]
+impl Debug for SharedWriter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SharedWriter")
+    }
 }
 
 #[cfg(not(any(feature = "parquet", feature = "ipc")))]
@@ -4,6 +4,9 @@ use polars_core::utils::get_supertype;
 
 use super::*;
 use crate::prelude::function_expr::FunctionExpr;
+#[cfg(feature = "dtype-struct")]
+use crate::prelude::function_expr::StructFunction;
+use crate::utils::expr_output_name;
 
 /// This replace the wildcard Expr with a Column Expr. It also removes the Exclude Expr from the
 /// expression chain.
@@ -218,6 +221,108 @@ fn expand_dtypes(
     Ok(())
 }
 
+#[cfg(feature = "dtype-struct")]
+fn is_multiple_struct_fields(e: &Expr) -> bool {
+    matches!(
+        e,
+        Expr::Function {
+            function: FunctionExpr::StructExpr(StructFunction::MultipleFields(_)),
+            ..
+        }
+    )
+}
+
+/// Resolve the struct field `names`, which may contain a `"*"` wildcard or regexes,
+/// against the `fields` of the struct.
+#[cfg(feature = "dtype-struct")]
+fn resolve_struct_fields(names: &[Arc<str>], fields: &[Field]) -> PolarsResult<Vec<Arc<str>>> {
+    let mut out: Vec<Arc<str>> = Vec::with_capacity(names.len());
+    let mut push = |name: &str| {
+        if !out.iter().any(|selected| selected.as_ref() == name) {
+            out.push(Arc::from(name))
+        }
+    };
+    for name in names {
+        if name.as_ref() == "*" {
+            fields.iter().for_each(|fld| push(fld.name()));
+            continue;
+        }
+        #[cfg(feature = "regex")]
+        if is_regex_projection(name) {
+            let re = regex::Regex::new(name).map_err(|_| {
+                polars_err!(ComputeError: "invalid regular expression in struct field: {}", name)
+            })?;
+            fields
+                .iter()
+                .filter(|fld| re.is_match(fld.name()))
+                .for_each(|fld| push(fld.name()));
+            continue;
+        }
+        polars_ensure!(
+            fields.iter().any(|fld| fld.name() == name.as_ref()),
+            StructFieldNotFound: "{}; available fields are: {}",
+            name,
+            fields.iter().map(|fld| fld.name().as_str()).collect::<Vec<_>>().join(", ")
+        );
+        push(name)
+    }
+    Ok(out)
+}
+
+/// replace `struct_().field_by_names(["a", "b"])..` with `struct_().field_by_name("a")..`,
+/// `struct_().field_by_name("b")..`
+#[cfg(feature = "dtype-struct")]
+fn expand_struct_fields(expr: &Expr, result: &mut Vec<Expr>, schema: &Schema) -> PolarsResult<()> {
+    let (input, names) = match expr.into_iter().find(|e| is_multiple_struct_fields(e)) {
+        Some(Expr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::MultipleFields(names)),
+            ..
+        }) => (input, names.clone()),
+        _ => {
+            result.push(expr.clone());
+            return Ok(());
+        }
+    };
+    let mut arena = Arena::with_capacity(8);
+    let fields = match input[0]
+        .to_field_amortized(schema, Context::Default, &mut arena)?
+        .dtype
+    {
+        DataType::Struct(fields) => fields,
+        dt => polars_bail!(SchemaMismatch: "expected a Struct to select fields from, got {}", dt),
+    };
+
+    for name in resolve_struct_fields(&names, &fields)? {
+        let mut is_valid = true;
+        let mut new_expr = expr.clone();
+        new_expr.mutate().apply(|e| {
+            if let Expr::Function {
+                function: FunctionExpr::StructExpr(sf),
+                ..
+            } = e
+            {
+                if let StructFunction::MultipleFields(members) = sf {
+                    // `a.struct.field_by_names([..]) + b.struct.field_by_names([..])`
+                    if *members == names {
+                        *sf = StructFunction::FieldByName(name.clone())
+                    } else {
+                        is_valid = false
+                    }
+                }
+            }
+            // always keep iterating all inputs
+            true
+        });
+        polars_ensure!(
+            is_valid,
+            ComputeError: "expanding more than one selection of struct fields is not allowed"
+        );
+        result.push(new_expr)
+    }
+    Ok(())
+}
+
 // schema is not used if regex not activated
 #[allow(unused_variables)]
 fn prepare_excluded(expr: &Expr, schema: &Schema, keys: &[Expr]) -> PolarsResult<Vec<Arc<str>>> {
@@ -291,6 +396,96 @@ fn prepare_excluded(expr: &Expr, schema: &Schema, keys: &[Expr]) -> PolarsResult
     Ok(exclude)
 }
 
+/// Resolve the columns selected by `s` against the schema.
+fn expand_selector_names(s: &Selector, schema: &Schema) -> PolarsResult<PlHashSet<Arc<str>>> {
+    let names = match s {
+        Selector::Root(e) => {
+            let mut names = PlHashSet::new();
+            for e in rewrite_projections(vec![e.as_ref().clone()], schema, &[])? {
+                match e {
+                    Expr::Column(name) => {
+                        names.insert(name);
+                    }
+                    e => polars_bail!(
+                        ComputeError: "selector roots must select columns, got: {:?}", e
+                    ),
+                }
+            }
+            names
+        }
+        Selector::Add(l, r) => {
+            let mut names = expand_selector_names(l, schema)?;
+            names.extend(expand_selector_names(r, schema)?);
+            names
+        }
+        Selector::Sub(l, r) => {
+            let mut names = expand_selector_names(l, schema)?;
+            for name in expand_selector_names(r, schema)? {
+                names.remove(&name);
+            }
+            names
+        }
+        Selector::InterSect(l, r) => {
+            let l = expand_selector_names(l, schema)?;
+            let r = expand_selector_names(r, schema)?;
+            l.intersection(&r).cloned().collect()
+        }
+        Selector::Not(s) => {
+            let excluded = expand_selector_names(s, schema)?;
+            schema
+                .iter_names()
+                .map(|name| Arc::from(name.as_str()))
+                .filter(|name| !excluded.contains(name))
+                .collect()
+        }
+    };
+    Ok(names)
+}
+
+/// Expand a selector to the names it selects, in schema order.
+pub(crate) fn expand_selector(s: &Selector, schema: &Schema) -> PolarsResult<Vec<Arc<str>>> {
+    let names = expand_selector_names(s, schema)?;
+    Ok(schema
+        .iter_names()
+        .map(|name| Arc::from(name.as_str()))
+        .filter(|name| names.contains(name))
+        .collect())
+}
+
+/// Replace the selectors in `expr` with `Expr::Columns` of the selected names. The names of
+/// an `exclude` and the group keys are removed from the selection.
+fn replace_selector(mut expr: Expr, schema: &Schema, keys: &[Expr]) -> PolarsResult<Expr> {
+    let exclude = prepare_excluded(&expr, schema, keys)?;
+    let mut err = None;
+    expr.mutate().apply(|e| {
+        match e {
+            Expr::Selector(s) => match expand_selector(s, schema) {
+                Ok(names) => {
+                    let names = names
+                        .into_iter()
+                        .filter(|name| !exclude.contains(name))
+                        .map(|name| name.to_string())
+                        .collect();
+                    *e = Expr::Columns(names)
+                }
+                Err(error) => {
+                    err = Some(error);
+                    return false;
+                }
+            },
+            Expr::Exclude(input, _) if has_expr(input, |e| matches!(e, Expr::Selector(_))) => {
+                *e = std::mem::take(input)
+            }
+            _ => {}
+        }
+        true
+    });
+    match err {
+        Some(err) => Err(err),
+        None => Ok(expr),
+    }
+}
+
 // functions can have col(["a", "b"]) or col(Utf8) as inputs
 fn expand_function_inputs(mut expr: Expr, schema: &Schema) -> Expr {
     expr.mutate().apply(|e| match e {
@@ -328,6 +523,26 @@ fn early_supertype(inputs: &[Expr], schema: &Schema) -> Option<DataType> {
     st
 }
 
+fn check_renamed_names(exprs: &[Expr]) -> PolarsResult<()> {
+    let mut names = PlHashMap::with_capacity(exprs.len());
+    let mut duplicates = vec![];
+    for e in exprs {
+        if let Ok(name) = expr_output_name(e) {
+            let count = names.entry(name.clone()).or_insert(0usize);
+            *count += 1;
+            if *count == 2 {
+                duplicates.push(name)
+            }
+        }
+    }
+    polars_ensure!(
+        duplicates.is_empty(),
+        Duplicate: "renaming the expanded expressions results in duplicate output names: {:?}",
+        duplicates
+    );
+    Ok(())
+}
+
 /// In case of single col(*) -> do nothing, no selection is the same as select all
 /// In other cases replace the wildcard with an expression with all columns
 pub(crate) fn rewrite_projections(
@@ -340,6 +555,11 @@ pub(crate) fn rewrite_projections(
     for mut expr in exprs {
         let result_offset = result.len();
 
+        // selectors are resolved to the selected column names first
+        if has_expr(&expr, |e| matches!(e, Expr::Selector(_))) {
+            expr = replace_selector(expr, schema, keys)?;
+        }
+
         // functions can have col(["a", "b"]) or col(Utf8) as inputs
         expr = expand_function_inputs(expr, schema);
 
@@ -347,6 +567,9 @@ pub(crate) fn rewrite_projections(
         let mut has_nth = false;
         let mut has_wildcard = false;
         let mut replace_fill_null_type = false;
+        let mut has_renaming = false;
+        #[cfg(feature = "dtype-struct")]
+        let mut has_struct_fields = false;
 
         // do a single pass and collect all flags at once.
         // supertypes/modification that can be done in place are also don e in that pass
@@ -355,10 +578,13 @@ pub(crate) fn rewrite_projections(
                 Expr::Columns(_) | Expr::DtypeColumn(_) => multiple_columns = true,
                 Expr::Nth(_) => has_nth = true,
                 Expr::Wildcard => has_wildcard = true,
+                Expr::KeepName(_) | Expr::RenameAlias { .. } => has_renaming = true,
                 Expr::Function {
                     function: FunctionExpr::FillNull { .. },
                     ..
                 } => replace_fill_null_type = true,
+                #[cfg(feature = "dtype-struct")]
+                e if is_multiple_struct_fields(e) => has_struct_fields = true,
                 _ => {}
             }
         }
@@ -406,6 +632,21 @@ pub(crate) fn rewrite_projections(
             }
         }
 
+        // the struct fields are expanded after the columns, as the dtype of the
+        // struct is only known once its column is known
+        #[cfg(feature = "dtype-struct")]
+        if has_struct_fields {
+            let expanded = result.drain(result_offset..).collect::<Vec<_>>();
+            for e in &expanded {
+                expand_struct_fields(e, &mut result, schema)?;
+            }
+        }
+
+        // renaming the expanded expressions must not map different columns to the same name
+        if has_renaming {
+            check_renamed_names(&result[result_offset..])?;
+        }
+
         // this is done after all expansion (wildcard, column, dtypes)
         // have been done. This will ensure the conversion to aexpr does
         // not panic because of an unexpected wildcard etc.
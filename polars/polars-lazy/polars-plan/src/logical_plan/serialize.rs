@@ -0,0 +1,127 @@
+use super::*;
+
+fn check_expr(expr: &Expr) -> PolarsResult<()> {
+    for e in expr {
+        match e {
+            Expr::AnonymousFunction { options, .. } => polars_bail!(
+                ComputeError: "cannot serialize the logical plan: expression '{}' applies a rust closure",
+                options.fmt_str
+            ),
+            Expr::RenameAlias { .. } => polars_bail!(
+                ComputeError: "cannot serialize the logical plan: expression '{:?}' renames with a rust closure", e
+            ),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn check_exprs<'a>(exprs: impl IntoIterator<Item = &'a Expr>) -> PolarsResult<()> {
+    exprs.into_iter().try_for_each(check_expr)
+}
+
+impl LogicalPlan {
+    /// Check that this plan can be serialized without losing information.
+    ///
+    /// Nodes that hold rust closures (anonymous scans, `map`/`apply` functions and
+    /// custom groupby functions) are skipped by serde, so they are rejected here with an
+    /// error that names the node.
+    pub fn check_serializable(&self) -> PolarsResult<()> {
+        use LogicalPlan::*;
+        match self {
+            AnonymousScan { options, .. } => polars_bail!(
+                ComputeError: "cannot serialize the logical plan: node '{}' is an anonymous scan",
+                options.fmt_str
+            ),
+            Error { err, .. } => Err(err.take()),
+            #[cfg(feature = "python")]
+            PythonScan { .. } => Ok(()),
+            Selection { input, predicate } => {
+                check_expr(predicate)?;
+                input.check_serializable()
+            }
+            Cache { input, .. } | Distinct { input, .. } | Slice { input, .. } => {
+                input.check_serializable()
+            }
+            #[cfg(feature = "csv")]
+            CsvScan { predicate, .. } => predicate.iter().try_for_each(check_expr),
+            #[cfg(feature = "parquet")]
+            ParquetScan { predicate, .. } => predicate.iter().try_for_each(check_expr),
+            #[cfg(feature = "ipc")]
+            IpcScan { predicate, .. } => predicate.iter().try_for_each(check_expr),
+            DataFrameScan { selection, .. } => selection.iter().try_for_each(check_expr),
+            LocalProjection { expr, input, .. } | Projection { expr, input, .. } => {
+                check_exprs(expr)?;
+                input.check_serializable()
+            }
+            Aggregate {
+                input,
+                keys,
+                aggs,
+                apply,
+                ..
+            } => {
+                polars_ensure!(
+                    apply.is_none(),
+                    ComputeError: "cannot serialize the logical plan: node 'AGGREGATE' applies a custom function to the groups"
+                );
+                check_exprs(keys.iter())?;
+                check_exprs(aggs)?;
+                input.check_serializable()
+            }
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                ..
+            } => {
+                check_exprs(left_on)?;
+                check_exprs(right_on)?;
+                input_left.check_serializable()?;
+                input_right.check_serializable()
+            }
+            HStack { input, exprs, .. } => {
+                check_exprs(exprs)?;
+                input.check_serializable()
+            }
+            Sort {
+                input, by_column, ..
+            } => {
+                check_exprs(by_column)?;
+                input.check_serializable()
+            }
+            MapFunction { input, function } => {
+                match function {
+                    FunctionNode::Opaque { fmt_str, .. } => polars_bail!(
+                        ComputeError: "cannot serialize the logical plan: node '{}' is a map function", fmt_str
+                    ),
+                    FunctionNode::Pipeline { .. } => polars_bail!(
+                        ComputeError: "cannot serialize the logical plan: node '{}' is a pipeline", function
+                    ),
+                    _ => {}
+                }
+                input.check_serializable()
+            }
+            Union { inputs, .. } => inputs.iter().try_for_each(|lp| lp.check_serializable()),
+            ExtContext {
+                input, contexts, ..
+            } => {
+                contexts.iter().try_for_each(|lp| lp.check_serializable())?;
+                input.check_serializable()
+            }
+            #[cfg(feature = "ipc_streaming")]
+            FileSink {
+                payload:
+                    FileSinkOptions {
+                        file_type: FileType::IpcStream { .. },
+                        ..
+                    },
+                ..
+            } => polars_bail!(
+                ComputeError: "cannot serialize the logical plan: node 'SINK' writes to an in-memory writer"
+            ),
+            FileSink { input, .. } => input.check_serializable(),
+        }
+    }
+}
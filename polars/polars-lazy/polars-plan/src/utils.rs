@@ -147,7 +147,7 @@ pub(crate) fn expr_output_name(expr: &Expr) -> PolarsResult<Arc<str>> {
                 ComputeError:
                 "cannot determine output column without a context for this expression"
             ),
-            Expr::Columns(_) | Expr::DtypeColumn(_) => polars_bail!(
+            Expr::Columns(_) | Expr::DtypeColumn(_) | Expr::Selector(_) => polars_bail!(
                 ComputeError:
                 "this expression may produce multiple output names"
             ),
@@ -203,6 +203,28 @@ pub fn expr_to_leaf_column_name(expr: &Expr) -> PolarsResult<Arc<str>> {
     }
 }
 
+/// The root column and the field names if `expr` accesses a (nested) field of a struct column.
+#[cfg(feature = "dtype-struct")]
+pub fn struct_field_path(expr: &Expr) -> Option<(&str, Vec<&str>)> {
+    use crate::dsl::function_expr::StructFunction;
+    match expr {
+        Expr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::FieldByName(name)),
+            ..
+        } if input.len() == 1 => {
+            let (column, mut path) = match &input[0] {
+                Expr::Column(column) => (column.as_ref(), vec![]),
+                e => struct_field_path(e)?,
+            };
+            path.push(name.as_ref());
+            Some((column, path))
+        }
+        Expr::Alias(e, _) => struct_field_path(e),
+        _ => None,
+    }
+}
+
 fn is_leaf_aexpr(ae: &AExpr) -> bool {
     matches!(ae, AExpr::Column(_) | AExpr::Wildcard)
 }
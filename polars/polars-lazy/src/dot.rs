@@ -22,6 +22,8 @@ impl LazyFrame {
                 &mut expr_arena,
                 &mut vec![],
                 true,
+                None,
+                None,
             )?;
             logical_plan = node_to_lp(lp_top, &expr_arena, &mut lp_arena);
         }
@@ -6,6 +6,9 @@ use polars_core::prelude::*;
 pub use polars_plan::dsl::functions::*;
 use rayon::prelude::*;
 
+use crate::physical_plan::executors::Executor;
+use crate::physical_plan::planner::create_physical_plan;
+use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 
 pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
@@ -52,77 +55,197 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
 
 #[cfg(feature = "diagonal_concat")]
 /// Concat [LazyFrame]s diagonally.
-/// Calls [concat] internally.
+/// Calls [concat_with] internally.
 pub fn diag_concat_lf<L: AsRef<[LazyFrame]>>(
     lfs: L,
     rechunk: bool,
     parallel: bool,
 ) -> PolarsResult<LazyFrame> {
-    let lfs = lfs.as_ref().to_vec();
+    let args = UnionArgs {
+        parallel,
+        rechunk,
+        diagonal: true,
+        to_supertypes: false,
+    };
+    concat_with(lfs, args)
+}
+
+/// Concat multiple
+pub fn concat<L: AsRef<[LazyFrame]>>(
+    inputs: L,
+    rechunk: bool,
+    parallel: bool,
+) -> PolarsResult<LazyFrame> {
+    concat_impl(inputs, rechunk, parallel, false)
+}
+
+/// Arguments for [`concat_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct UnionArgs {
+    pub parallel: bool,
+    pub rechunk: bool,
+    /// The output has the union of the columns in the order of their first appearance, and the
+    /// columns that an input misses are filled with nulls.
+    pub diagonal: bool,
+    /// Cast every column to the supertype of its dtypes instead of erroring on mismatching
+    /// dtypes.
+    pub to_supertypes: bool,
+}
+
+impl Default for UnionArgs {
+    fn default() -> Self {
+        Self {
+            parallel: true,
+            rechunk: true,
+            diagonal: false,
+            to_supertypes: false,
+        }
+    }
+}
+
+/// Concat multiple [LazyFrame]s, where the schemas of the inputs are unified when the plan is
+/// built.
+pub fn concat_with<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult<LazyFrame> {
+    let lfs = inputs.as_ref();
+    if !args.diagonal && !args.to_supertypes {
+        return concat(lfs, args.rechunk, args.parallel);
+    }
     let schemas = lfs
         .iter()
         .map(|lf| lf.schema())
         .collect::<PolarsResult<Vec<_>>>()?;
+    let (first, rest) = schemas
+        .split_first()
+        .ok_or_else(|| polars_err!(NoData: "empty container given"))?;
 
-    let upper_bound_width = schemas.iter().map(|sch| sch.len()).sum();
-
-    // Use Vec to preserve order
-    let mut column_names = Vec::with_capacity(upper_bound_width);
-    let mut total_schema = Vec::with_capacity(upper_bound_width);
-
-    for sch in schemas.iter() {
-        sch.iter().for_each(|(name, dtype)| {
-            if !column_names.contains(name) {
-                column_names.push(name.clone());
-                total_schema.push((name.clone(), dtype.clone()));
-            }
-        });
+    // Use the first schema to preserve the order of the columns
+    let mut total_schema = (**first).clone();
+    for schema in rest {
+        polars_ensure!(
+            args.diagonal
+                || (schema.len() == total_schema.len()
+                    && schema.iter_names().all(|name| total_schema.contains(name))),
+            SchemaMismatch: "cannot vertically concat frames with different columns, consider a diagonal concat"
+        );
+        total_schema.union_with(schema, args.to_supertypes)?;
     }
 
-    let lfs_with_all_columns = lfs
-        .into_iter()
-        // Zip Frames with their Schemas
-        .zip(schemas.into_iter())
-        .map(|(mut lf, lf_schema)| {
-            for (name, dtype) in total_schema.iter() {
-                // If a name from Total Schema is not present - append
-                if lf_schema.get_field(name).is_none() {
-                    lf = lf.with_column(NULL.lit().cast(dtype.clone()).alias(name))
-                }
-            }
-
-            // Now, reorder to match schema
-            let reordered_lf = lf.select(
-                column_names
-                    .iter()
-                    .map(|col_name| col(col_name))
-                    .collect::<Vec<Expr>>(),
-            );
-
-            Ok(reordered_lf)
+    let lfs = lfs
+        .iter()
+        .zip(schemas.iter())
+        .map(|(lf, lf_schema)| {
+            let exprs = total_schema
+                .iter()
+                .map(|(name, dtype)| match lf_schema.get(name) {
+                    Some(lf_dtype) if lf_dtype == dtype => col(name),
+                    Some(_) => col(name).cast(dtype.clone()),
+                    None => NULL.lit().cast(dtype.clone()).alias(name),
+                })
+                .collect::<Vec<_>>();
+            lf.clone().select(exprs)
         })
-        .collect::<PolarsResult<Vec<_>>>()?;
+        .collect::<Vec<_>>();
 
-    concat(lfs_with_all_columns, rechunk, parallel)
+    concat(lfs, args.rechunk, args.parallel)
 }
 
-/// Concat multiple
-pub fn concat<L: AsRef<[LazyFrame]>>(
-    inputs: L,
-    rechunk: bool,
-    parallel: bool,
-) -> PolarsResult<LazyFrame> {
-    concat_impl(inputs, rechunk, parallel, false)
+#[cfg(feature = "cse")]
+fn use_cse_across_plans(lfs: &[LazyFrame]) -> bool {
+    lfs.len() > 1
+        && lfs
+            .iter()
+            .all(|lf| lf.opt_state.common_subplan_elimination && !lf.opt_state.streaming)
+}
+
+/// Optimize the plans into the same arenas. If `cse` is set, the subplans they share
+/// are replaced with cache nodes.
+fn optimize_all(
+    lfs: Vec<LazyFrame>,
+    #[allow(unused_variables)] cse: bool,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<(Vec<Node>, Vec<ExecutionState>)> {
+    let mut scratch = vec![];
+    let (roots, states) = lfs
+        .into_iter()
+        .map(|lf| {
+            #[cfg(feature = "cse")]
+            let lf = if cse {
+                // the shared subplans are searched after optimization. Caches or
+                // file caching within a single plan would block that search.
+                let mut lf = lf;
+                lf.opt_state.common_subplan_elimination = false;
+                lf.opt_state.file_caching = false;
+                lf
+            } else {
+                lf
+            };
+            lf.optimize_for_collect(lp_arena, expr_arena, &mut scratch, None)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+    #[cfg(feature = "cse")]
+    if cse {
+        elim_cmn_subplans_across_plans(&roots, lp_arena, expr_arena);
+    }
+    Ok((roots, states))
 }
 
-/// Collect all `LazyFrame` computations.
+/// Collect all `LazyFrame` computations in parallel.
+///
+/// The computations share their cache, so a subplan that is cached with [`LazyFrame::cache`]
+/// is executed only once. If common subplan elimination is enabled on all `LazyFrame`s,
+/// subplans that are shared between them are cached as well.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
     I: IntoParallelIterator<Item = LazyFrame>,
 {
-    let iter = lfs.into_par_iter();
+    let lfs = lfs.into_par_iter().collect::<Vec<_>>();
+    #[cfg(feature = "cse")]
+    let cse = use_cse_across_plans(&lfs);
+    #[cfg(not(feature = "cse"))]
+    let cse = false;
 
-    polars_core::POOL.install(|| iter.map(|lf| lf.collect()).collect())
+    let mut expr_arena = Arena::with_capacity(256);
+    let mut lp_arena = Arena::with_capacity(128);
+    let (roots, mut states) = optimize_all(lfs, cse, &mut lp_arena, &mut expr_arena)?;
+    let physical_plans = roots
+        .into_iter()
+        .map(|root| create_physical_plan(root, &mut lp_arena, &mut expr_arena))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    if let Some((first, rest)) = states.split_first_mut() {
+        rest.iter_mut()
+            .for_each(|state| state.share_df_cache(first));
+    }
+    // a shared cache is computed by the first plan that reaches it,
+    // the other plans wait for it
+    let plans = physical_plans.into_iter().zip(states).collect::<Vec<_>>();
+    polars_core::POOL.install(|| {
+        plans
+            .into_par_iter()
+            .map(|(mut physical_plan, mut state)| physical_plan.execute(&mut state))
+            .collect()
+    })
+}
+
+/// Describe the optimized logical plans of a [`collect_all`] call.
+///
+/// Subplans that are shared between the plans are shown as `CACHE` nodes with the same id.
+pub fn describe_optimized_plans(lfs: &[LazyFrame]) -> PolarsResult<Vec<String>> {
+    #[cfg(feature = "cse")]
+    let cse = use_cse_across_plans(lfs);
+    #[cfg(not(feature = "cse"))]
+    let cse = false;
+
+    let mut expr_arena = Arena::with_capacity(256);
+    let mut lp_arena = Arena::with_capacity(128);
+    let (roots, _) = optimize_all(lfs.to_vec(), cse, &mut lp_arena, &mut expr_arena)?;
+    Ok(roots
+        .into_iter()
+        .map(|root| node_to_lp(root, &expr_arena, &mut lp_arena).describe())
+        .collect())
 }
 
 #[cfg(test)]
@@ -163,4 +286,50 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_concat_with_supertypes() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1i32, 2],
+            "b" => ["a", "b"]
+        ]?;
+        let b = df![
+            "c" => [1.5f64],
+            "a" => [3i64]
+        ]?;
+
+        let args = UnionArgs {
+            to_supertypes: true,
+            ..Default::default()
+        };
+        assert!(concat_with([a.clone().lazy(), b.clone().lazy()], args).is_err());
+
+        // the dtypes of "a" differ
+        let args = UnionArgs {
+            diagonal: true,
+            to_supertypes: false,
+            ..args
+        };
+        let out = concat_with([a.clone().lazy(), b.clone().lazy()], args);
+        assert!(matches!(out, Err(PolarsError::SchemaMismatch(_))));
+
+        let args = UnionArgs {
+            to_supertypes: true,
+            ..args
+        };
+        let lf = concat_with([a.lazy(), b.lazy()], args)?;
+        // the schema is unified when the plan is built
+        assert_eq!(
+            lf.schema()?.iter_dtypes().cloned().collect::<Vec<_>>(),
+            &[DataType::Int64, DataType::Utf8, DataType::Float64]
+        );
+        let expected = df![
+            "a" => [1i64, 2, 3],
+            "b" => [Some("a"), Some("b"), None],
+            "c" => [None, None, Some(1.5)]
+        ]?;
+        assert!(lf.collect()?.frame_equal_missing(&expected));
+
+        Ok(())
+    }
 }
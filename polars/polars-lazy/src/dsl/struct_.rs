@@ -0,0 +1,53 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// The schema of the struct after adding/replacing the fields with `exprs`.
+fn with_fields_dtype(fields: &[Field], exprs: Vec<Expr>) -> PolarsResult<DataType> {
+    let df = DataFrame::new_no_checks(
+        fields
+            .iter()
+            .map(|fld| Series::new_empty(fld.name(), fld.data_type()))
+            .collect(),
+    );
+    let schema = df.lazy().with_columns(exprs).schema()?;
+    Ok(DataType::Struct(schema.iter_fields().collect()))
+}
+
+pub trait StructNameSpaceExtension: Sized {
+    /// Add or replace fields of this struct with the output of `exprs`, like
+    /// [`LazyFrame::with_columns`] scoped to the fields of the struct.
+    ///
+    /// The expressions refer to the fields by name. An output that has the name of an existing
+    /// field replaces that field, other outputs are appended in order.
+    fn with_fields(self, exprs: Vec<Expr>) -> Expr;
+}
+
+impl StructNameSpaceExtension for StructNameSpace {
+    fn with_fields(self, exprs: Vec<Expr>) -> Expr {
+        let exprs2 = exprs.clone();
+        let func = move |s: Series| {
+            let ca = s.struct_()?;
+            let out = DataFrame::new_no_checks(ca.fields().to_vec())
+                .lazy()
+                .with_columns(exprs.clone())
+                .collect()?;
+            StructChunked::new(ca.name(), out.get_columns()).map(|ca| Some(ca.into_series()))
+        };
+
+        self.0
+            .map(
+                func,
+                GetOutput::try_map_field(move |fld| match fld.data_type() {
+                    DataType::Struct(fields) => Ok(Field::new(
+                        fld.name(),
+                        with_fields_dtype(fields, exprs2.clone())?,
+                    )),
+                    dt => polars_bail!(
+                        SchemaMismatch: "expected a struct for 'with_fields', got {}", dt
+                    ),
+                }),
+            )
+            .with_fmt("struct.with_fields")
+    }
+}
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::csv::utils::{get_reader_bytes, infer_file_schema};
-use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::csv::{CsvEncoding, DateFormats, NullValues, RaggedLineMode};
 use polars_io::RowCount;
 
 use crate::frame::LazyFileListReader;
@@ -26,12 +26,17 @@ pub struct LazyCsvReader<'a> {
     eol_char: u8,
     null_values: Option<NullValues>,
     missing_is_null: bool,
+    ragged_lines: RaggedLineMode,
     infer_schema_length: Option<usize>,
     rechunk: bool,
     skip_rows_after_header: usize,
     encoding: CsvEncoding,
     row_count: Option<RowCount>,
+    row_count_per_file: bool,
+    file_path_column: Option<String>,
     try_parse_dates: bool,
+    date_formats: Option<Vec<String>>,
+    strict_date_formats: bool,
 }
 
 #[cfg(feature = "csv")]
@@ -53,12 +58,17 @@ impl<'a> LazyCsvReader<'a> {
             eol_char: b'\n',
             null_values: None,
             missing_is_null: true,
+            ragged_lines: RaggedLineMode::default(),
             infer_schema_length: Some(100),
             rechunk: true,
             skip_rows_after_header: 0,
             encoding: CsvEncoding::Utf8,
             row_count: None,
+            row_count_per_file: false,
+            file_path_column: None,
             try_parse_dates: false,
+            date_formats: None,
+            strict_date_formats: false,
         }
     }
 
@@ -76,6 +86,20 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Restart the `row_count` at its offset for every file of a glob.
+    #[must_use]
+    pub fn with_row_count_per_file(mut self, toggle: bool) -> Self {
+        self.row_count_per_file = toggle;
+        self
+    }
+
+    /// Add a column with this name with the path of the file of every row.
+    #[must_use]
+    pub fn with_file_path_column(mut self, name: Option<&str>) -> Self {
+        self.file_path_column = name.map(|name| name.to_string());
+        self
+    }
+
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -170,6 +194,25 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Set how lines with missing or extra fields are handled.
+    #[must_use]
+    pub fn with_ragged_lines(mut self, ragged_lines: RaggedLineMode) -> Self {
+        self.ragged_lines = ragged_lines;
+        self
+    }
+
+    /// Drop the extra fields of lines that are longer than the schema. If `false`,
+    /// such lines raise an error.
+    #[must_use]
+    pub fn with_truncate_ragged_lines(mut self, toggle: bool) -> Self {
+        self.ragged_lines = if toggle {
+            RaggedLineMode::Truncate
+        } else {
+            RaggedLineMode::PadNull
+        };
+        self
+    }
+
     /// Cache the DataFrame after reading.
     #[must_use]
     pub fn with_cache(mut self, cache: bool) -> Self {
@@ -198,6 +241,30 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Infer and parse the date columns with these `chrono` format strings. Every value may match
+    /// any of the formats. See [`polars_io::csv::CsvReader::with_date_formats`].
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn with_date_formats(mut self, formats: Option<Vec<String>>) -> Self {
+        self.date_formats = formats;
+        self
+    }
+
+    /// Raise an error if a value of a date column doesn't match any of the date formats.
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn with_strict_date_formats(mut self, toggle: bool) -> Self {
+        self.strict_date_formats = toggle;
+        self
+    }
+
+    fn date_formats(&self) -> Option<DateFormats> {
+        self.date_formats.clone().map(|formats| DateFormats {
+            formats,
+            strict: self.strict_date_formats,
+        })
+    }
+
     /// Modify a schema before we run the lazy scanning.
     ///
     /// Important! Run this function latest in the builder!
@@ -231,6 +298,7 @@ impl<'a> LazyCsvReader<'a> {
             self.eol_char,
             None,
             self.try_parse_dates,
+            self.date_formats().as_ref(),
         )?;
         let mut schema = f(schema)?;
 
@@ -247,6 +315,7 @@ impl<'a> LazyCsvReader<'a> {
 
 impl LazyFileListReader for LazyCsvReader<'_> {
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        let date_formats = self.date_formats();
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_csv(
             self.path,
             self.delimiter,
@@ -262,12 +331,14 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.quote_char,
             self.eol_char,
             self.null_values,
+            self.ragged_lines,
             self.infer_schema_length,
             self.rechunk,
             self.skip_rows_after_header,
             self.encoding,
             self.row_count,
             self.try_parse_dates,
+            date_formats,
         )?
         .build()
         .into();
@@ -306,6 +377,18 @@ impl LazyFileListReader for LazyCsvReader<'_> {
         self.row_count.as_ref()
     }
 
+    fn with_row_count(self, row_count: Option<RowCount>) -> Self {
+        LazyCsvReader::with_row_count(self, row_count)
+    }
+
+    fn row_count_per_file(&self) -> bool {
+        self.row_count_per_file
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        self.file_path_column.as_deref()
+    }
+
     fn concat_impl(&self, lfs: Vec<LazyFrame>) -> PolarsResult<LazyFrame> {
         // set to false, as the csv parser has full thread utilization
         concat_impl(&lfs, self.rechunk(), false, true)
@@ -28,6 +28,37 @@ fn polars_glob(pattern: &str, cloud_options: Option<&CloudOptions>) -> PolarsRes
     }
 }
 
+/// Let the scan of a single file add a column with the path of the file.
+fn with_file_path_column(mut lf: LazyFrame, column: FilePathColumn) -> PolarsResult<LazyFrame> {
+    set_file_path_column(&mut lf.logical_plan, column)?;
+    Ok(lf)
+}
+
+fn set_file_path_column(lp: &mut LogicalPlan, column: FilePathColumn) -> PolarsResult<()> {
+    let (file_info, file_path_column) = match lp {
+        // the map that updates the schema of a row count that is added by the scan
+        LogicalPlan::MapFunction { input, .. } => return set_file_path_column(input, column),
+        #[cfg(feature = "csv")]
+        LogicalPlan::CsvScan {
+            file_info, options, ..
+        } => (file_info, &mut options.file_path_column),
+        #[cfg(feature = "parquet")]
+        LogicalPlan::ParquetScan {
+            file_info, options, ..
+        } => (file_info, &mut options.file_path_column),
+        _ => {
+            polars_bail!(InvalidOperation: "a file path column is only supported for csv and parquet scans")
+        }
+    };
+    polars_ensure!(
+        !file_info.schema.contains(column.name()),
+        Duplicate: "file path column {} is also a column of the file", column.name()
+    );
+    Arc::make_mut(&mut file_info.schema).with_column(column.name().into(), column.dtype().clone());
+    *file_path_column = Some(column);
+    Ok(())
+}
+
 /// Reads [LazyFrame] from a filesystem or a cloud storage.
 /// Supports glob patterns.
 ///
@@ -36,37 +67,64 @@ pub trait LazyFileListReader: Clone {
     /// Get the final [LazyFrame].
     fn finish(self) -> PolarsResult<LazyFrame> {
         if let Some(paths) = self.glob()? {
-            let lfs = paths
-                .map(|r| {
-                    let path = r?;
-                    self.clone()
-                        .with_path(path.clone())
-                        .with_rechunk(false)
-                        .finish_no_glob()
-                        .map_err(|e| {
-                            polars_err!(
-                                ComputeError: "error while reading {}: {}", path.display(), e
-                            )
-                        })
-                })
-                .collect::<PolarsResult<Vec<_>>>()?;
-
+            let paths = paths.collect::<PolarsResult<Vec<_>>>()?;
             polars_ensure!(
-                !lfs.is_empty(),
+                !paths.is_empty(),
                 ComputeError: "no matching files found in {}", self.path().display()
             );
 
+            let file_path_columns = self
+                .file_path_column()
+                .map(|name| FilePathColumn::new_for_paths(name, &paths))
+                .transpose()?;
+            let row_count_per_file = self.row_count_per_file();
+            let lfs = paths
+                .iter()
+                .enumerate()
+                .map(|(i, path)| {
+                    let mut reader = self.clone().with_path(path.clone()).with_rechunk(false);
+                    // the row count is added after the files are concatenated
+                    if !row_count_per_file {
+                        reader = reader.with_row_count(None);
+                        polars_ensure!(
+                            reader.row_count().is_none(),
+                            InvalidOperation: "this reader only supports a row count per file"
+                        );
+                    }
+                    let mut lf = reader.finish_no_glob().map_err(|e| {
+                        polars_err!(
+                            ComputeError: "error while reading {}: {}", path.display(), e
+                        )
+                    })?;
+                    if let Some(columns) = &file_path_columns {
+                        lf = with_file_path_column(lf, columns[i].clone())?;
+                    }
+                    Ok(lf)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
             let mut lf = self.concat_impl(lfs)?;
             if let Some(n_rows) = self.n_rows() {
                 lf = lf.slice(0, n_rows as IdxSize)
             };
-            if let Some(rc) = self.row_count() {
-                lf = lf.with_row_count(&rc.name, Some(rc.offset))
+            match self.row_count() {
+                Some(rc) if !row_count_per_file => {
+                    lf = lf.with_row_count(&rc.name, Some(rc.offset))
+                }
+                _ => {}
             };
 
             Ok(lf)
         } else {
-            self.finish_no_glob()
+            let file_path_column = self
+                .file_path_column()
+                .map(|name| FilePathColumn::new(name, self.path()))
+                .transpose()?;
+            let lf = self.finish_no_glob()?;
+            match file_path_column {
+                Some(column) => with_file_path_column(lf, column),
+                None => Ok(lf),
+            }
         }
     }
 
@@ -107,6 +165,23 @@ pub trait LazyFileListReader: Clone {
     /// Add a `row_count` column.
     fn row_count(&self) -> Option<&RowCount>;
 
+    /// Add a `row_count` column. A reader that doesn't implement this keeps its `row_count`,
+    /// so it can only add a `row_count` per file to a glob.
+    #[must_use]
+    fn with_row_count(self, _row_count: Option<RowCount>) -> Self {
+        self
+    }
+
+    /// Whether the `row_count` column of a glob restarts at its offset for every file.
+    fn row_count_per_file(&self) -> bool {
+        false
+    }
+
+    /// Name of a column with the path of the file that a row was read from.
+    fn file_path_column(&self) -> Option<&str> {
+        None
+    }
+
     /// [CloudOptions] used to list files.
     fn cloud_options(&self) -> Option<&CloudOptions> {
         None
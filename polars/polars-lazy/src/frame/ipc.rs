@@ -87,6 +87,11 @@ impl LazyFileListReader for LazyIpcReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.args.row_count = row_count;
+        self
+    }
 }
 
 impl LazyFrame {
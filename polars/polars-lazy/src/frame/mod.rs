@@ -33,19 +33,24 @@ pub use parquet::*;
 use polars_arrow::prelude::QuantileInterpolOptions;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::frame::hash_join::JoinType;
+use polars_core::mem::MemoryLimit;
 use polars_core::prelude::*;
 use polars_io::RowCount;
+#[cfg(all(feature = "streaming", any(feature = "parquet", feature = "ipc")))]
+use polars_pipe::pipeline::PipeLine;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
 use polars_plan::global::FETCH_ROWS;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use polars_plan::logical_plan::collect_fingerprints;
-use polars_plan::logical_plan::optimize;
+use polars_plan::logical_plan::{optimize, OptimizerTimings};
 use polars_plan::utils::expr_to_leaf_column_names;
 use smartstring::alias::String as SmartString;
 
 use crate::physical_plan::executors::Executor;
-use crate::physical_plan::planner::create_physical_plan;
+use crate::physical_plan::planner::{create_physical_plan, create_profiled_physical_plan};
 use crate::physical_plan::state::ExecutionState;
+#[cfg(all(feature = "streaming", any(feature = "parquet", feature = "ipc")))]
+use crate::physical_plan::streaming::create_streaming_pipeline;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
 use crate::prelude::*;
@@ -108,6 +113,25 @@ impl LazyFrame {
         }
     }
 
+    /// Serialize the logical plan of this `LazyFrame` to JSON, so that it can be
+    /// executed elsewhere with [`LazyFrame::deserialize_json`].
+    ///
+    /// Fails if the plan contains rust closures, e.g. `map`/`apply` or anonymous scans.
+    #[cfg(feature = "serde")]
+    pub fn serialize_json(&self) -> PolarsResult<String> {
+        self.logical_plan.check_serializable()?;
+        serde_json::to_string(&self.logical_plan)
+            .map_err(|e| polars_err!(ComputeError: "cannot serialize the logical plan: {}", e))
+    }
+
+    /// Create a `LazyFrame` from a logical plan serialized with [`LazyFrame::serialize_json`].
+    #[cfg(feature = "serde")]
+    pub fn deserialize_json(json: &str) -> PolarsResult<Self> {
+        let lp: LogicalPlan = serde_json::from_str(json)
+            .map_err(|e| polars_err!(ComputeError: "cannot deserialize the logical plan: {}", e))?;
+        Ok(LazyFrame::from(lp))
+    }
+
     /// Set allowed optimizations
     pub fn with_optimizations(mut self, opt_state: OptState) -> Self {
         self.opt_state = opt_state;
@@ -117,16 +141,8 @@ impl LazyFrame {
     /// Turn off all optimizations
     pub fn without_optimizations(self) -> Self {
         self.with_optimizations(OptState {
-            projection_pushdown: false,
-            predicate_pushdown: false,
             type_coercion: true,
-            simplify_expr: false,
-            slice_pushdown: false,
-            // will be toggled by a scan operation such as csv scan or parquet scan
-            file_caching: false,
-            #[cfg(feature = "cse")]
-            common_subplan_elimination: false,
-            streaming: false,
+            ..OptState::default_off()
         })
     }
 
@@ -161,6 +177,13 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle common subplan elimination optimization on or off.
+    /// Shorthand for [`LazyFrame::with_common_subplan_elimination`].
+    #[cfg(feature = "cse")]
+    pub fn with_comm_subplan_elim(self, toggle: bool) -> Self {
+        self.with_common_subplan_elimination(toggle)
+    }
+
     /// Toggle slice pushdown optimization
     pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.slice_pushdown = toggle;
@@ -173,6 +196,38 @@ impl LazyFrame {
         self
     }
 
+    /// Set the number of rows per chunk the streaming engine strives to.
+    ///
+    /// By default this is determined from the number of columns and threads
+    /// (or the `POLARS_STREAMING_CHUNK_SIZE` env var). Very wide frames may
+    /// benefit from smaller chunks.
+    pub fn with_streaming_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.opt_state.streaming_chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Set the memory budget in bytes of the streaming sort. Once the buffered data exceeds
+    /// the budget, the sort spills sorted runs to disk and merges them when it finishes.
+    ///
+    /// By default the budget is taken from the `POLARS_OOC_SORT_MEMORY_BUDGET` env var,
+    /// or the sort spills based on the available system memory.
+    pub fn with_streaming_sort_memory_budget(mut self, budget: usize) -> Self {
+        self.opt_state.streaming_sort_memory_budget = Some(budget);
+        self
+    }
+
+    /// Abort the query with a `ResourceExhausted` error when it uses more than `limit` bytes
+    /// after a node of the plan or a step of a long-running operator has executed. Streaming
+    /// sinks that can go out-of-core spill to disk once the limit is exceeded.
+    ///
+    /// The usage of the query is the memory in use above the usage when the query started, as
+    /// measured by [`polars_core::mem`], which requires its `TrackingAllocator` to be installed
+    /// as the global allocator.
+    pub fn with_memory_limit(mut self, limit: usize) -> Self {
+        self.opt_state.memory_limit = Some(limit);
+        self
+    }
+
     /// Describe the logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -187,9 +242,11 @@ impl LazyFrame {
             &mut expr_arena,
             &mut vec![],
             true,
+            None,
+            None,
         )?;
         let logical_plan = node_to_lp(lp_top, &expr_arena, &mut lp_arena);
-        Ok(logical_plan.describe())
+        Ok(logical_plan.describe_with_node_ids())
     }
 
     /// Add a sort operation to the logical plan.
@@ -423,6 +480,12 @@ impl LazyFrame {
 
     /// Caches the result into a new LazyFrame. This should be used to prevent computations
     /// running multiple times
+    ///
+    /// The input is executed the first time the cache is hit during a query (or a
+    /// [`collect_all`](crate::dsl::collect_all)) and every other branch of the query that
+    /// reads from the cache reuses that result. The cache is dropped once the query finishes.
+    /// The streaming engine doesn't run cached parts of a query, they are executed by the
+    /// default engine.
     pub fn cache(self) -> Self {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().cache().build();
@@ -447,7 +510,7 @@ impl LazyFrame {
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
     ) -> PolarsResult<Node> {
-        self.optimize_with_scratch(lp_arena, expr_arena, &mut vec![], false)
+        self.optimize_with_scratch(lp_arena, expr_arena, &mut vec![], false, None, None)
     }
 
     pub(crate) fn optimize_with_scratch(
@@ -456,6 +519,8 @@ impl LazyFrame {
         expr_arena: &mut Arena<AExpr>,
         scratch: &mut Vec<Node>,
         _fmt: bool,
+        mut timings: Option<&mut OptimizerTimings>,
+        _memory_limit: Option<Arc<MemoryLimit>>,
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
@@ -465,12 +530,32 @@ impl LazyFrame {
             eprintln!("Cannot combine 'streaming' with 'common_subplan_elimination'. CSE will be turned off.");
             opt_state.common_subplan_elimination = false;
         }
-        let lp_top = optimize(self.logical_plan, opt_state, lp_arena, expr_arena, scratch)?;
+        let lp_top = optimize(
+            self.logical_plan,
+            opt_state,
+            lp_arena,
+            expr_arena,
+            scratch,
+            timings.as_deref_mut(),
+        )?;
 
         if streaming {
             #[cfg(feature = "streaming")]
             {
-                insert_streaming_nodes(lp_top, lp_arena, expr_arena, scratch, _fmt)?;
+                let start = std::time::Instant::now();
+                insert_streaming_nodes(
+                    lp_top,
+                    lp_arena,
+                    expr_arena,
+                    scratch,
+                    _fmt,
+                    opt_state.streaming_chunk_size,
+                    opt_state.streaming_sort_memory_budget,
+                    _memory_limit,
+                )?;
+                if let Some(timings) = timings {
+                    timings.push(("streaming", start, std::time::Instant::now()))
+                }
             }
             #[cfg(not(feature = "streaming"))]
             {
@@ -480,23 +565,34 @@ impl LazyFrame {
         Ok(lp_top)
     }
 
-    #[allow(unused_mut)]
-    fn prepare_collect(
-        mut self,
-        check_sink: bool,
-    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool)> {
+    /// Optimize the query into the given arenas and create the state it must be executed with.
+    pub(crate) fn optimize_for_collect(
+        self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        scratch: &mut Vec<Node>,
+        timings: Option<&mut OptimizerTimings>,
+    ) -> PolarsResult<(Node, ExecutionState)> {
         let file_caching = self.opt_state.file_caching;
-        let mut expr_arena = Arena::with_capacity(256);
-        let mut lp_arena = Arena::with_capacity(128);
-        let mut scratch = vec![];
-        let lp_top =
-            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch, false)?;
+        // the usage of the query is measured from here on
+        let memory_limit = self
+            .opt_state
+            .memory_limit
+            .map(|limit| Arc::new(MemoryLimit::new(limit)));
+        let lp_top = self.optimize_with_scratch(
+            lp_arena,
+            expr_arena,
+            scratch,
+            false,
+            timings,
+            memory_limit.clone(),
+        )?;
 
         let finger_prints = if file_caching {
             #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
             {
                 let mut fps = Vec::with_capacity(8);
-                collect_fingerprints(lp_top, &mut fps, &lp_arena, &expr_arena);
+                collect_fingerprints(lp_top, &mut fps, lp_arena, expr_arena);
                 Some(fps)
             }
             #[cfg(not(any(feature = "ipc", feature = "parquet", feature = "csv")))]
@@ -506,6 +602,29 @@ impl LazyFrame {
         } else {
             None
         };
+        let mut state = ExecutionState::with_finger_prints(finger_prints);
+        state.set_memory_limit(memory_limit);
+        Ok((lp_top, state))
+    }
+
+    /// If `profile_timings` is set, the optimization passes are timed and every executor
+    /// records the id of the node it executes.
+    #[allow(unused_mut)]
+    fn prepare_collect(
+        mut self,
+        check_sink: bool,
+        profile_timings: Option<&mut OptimizerTimings>,
+    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool)> {
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let mut scratch = vec![];
+        let profile = profile_timings.is_some();
+        let (lp_top, state) = self.optimize_for_collect(
+            &mut lp_arena,
+            &mut expr_arena,
+            &mut scratch,
+            profile_timings,
+        )?;
 
         // file sink should be replaced
         let no_file_sink = if check_sink {
@@ -513,9 +632,12 @@ impl LazyFrame {
         } else {
             true
         };
-        let physical_plan = create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+        let physical_plan = if profile {
+            create_profiled_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?
+        } else {
+            create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?
+        };
 
-        let state = ExecutionState::with_finger_prints(finger_prints);
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -536,7 +658,7 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> PolarsResult<DataFrame> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false, None)?;
         let out = physical_plan.execute(&mut state);
         #[cfg(debug_assertions)]
         {
@@ -552,10 +674,24 @@ impl LazyFrame {
     /// containing the materialized DataFrame and a DataFrame that contains profiling information
     /// of each node that is executed.
     ///
-    /// The units of the timings are microseconds.
+    /// The profiling information has the columns:
+    /// - `node_id`: the id of the node, as printed by [`LazyFrame::describe_optimized_plan`].
+    ///   This is null for the optimization passes.
+    /// - `node`: the name of the node or the optimization pass.
+    /// - `start` and `end`: the timings in microseconds since the query started.
+    /// - `rows`: the number of rows the node produced.
+    /// - `peak_memory`: the peak of the memory usage while the node ran, in bytes above the
+    ///   usage when it started. This is null unless the `TrackingAllocator` of
+    ///   [`polars_core::mem`] is installed.
+    ///
+    /// The last node, `query`, spans the execution of the whole query, so its `peak_memory` is
+    /// the peak of the query. A part of the query that runs on the streaming engine is a single
+    /// `streaming` node.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
-        state.time_nodes();
+        let query_start = std::time::Instant::now();
+        let mut timings = OptimizerTimings::new();
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false, Some(&mut timings))?;
+        state.time_nodes(query_start, timings);
         let out = physical_plan.execute(&mut state)?;
         let timer_df = state.finish_timer()?;
         Ok((out, timer_df))
@@ -574,7 +710,7 @@ impl LazyFrame {
                 file_type: FileType::Parquet(options),
             },
         };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
+        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true, None)?;
         polars_ensure!(
             is_streaming,
             ComputeError: "cannot run the whole query in a streaming order; \
@@ -584,6 +720,38 @@ impl LazyFrame {
         Ok(())
     }
 
+    /// Stream a query result into a hive partitioned parquet dataset in the directory `path`.
+    /// Every partition is written to a `key=value` subdirectory; null keys are written to the
+    /// `__HIVE_DEFAULT_PARTITION__` directory. This methods will return an error if the query
+    /// cannot be completely done in a streaming fashion.
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet_partitioned(
+        mut self,
+        path: PathBuf,
+        partition_by: &[&str],
+        options: ParquetPartitionOptions,
+    ) -> PolarsResult<()> {
+        self.opt_state.streaming = true;
+        self.logical_plan = LogicalPlan::FileSink {
+            input: Box::new(self.logical_plan),
+            payload: FileSinkOptions {
+                path: Arc::new(path),
+                file_type: FileType::PartitionedParquet {
+                    partition_by: partition_by.iter().map(|s| s.to_string()).collect(),
+                    options,
+                },
+            },
+        };
+        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true, None)?;
+        polars_ensure!(
+            is_streaming,
+            ComputeError: "cannot run the whole query in a streaming order; \
+            use `collect().partition_by()` and write the partitions instead"
+        );
+        let _ = physical_plan.execute(&mut state)?;
+        Ok(())
+    }
+
     /// Stream a query result into an ipc/arrow file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -597,7 +765,7 @@ impl LazyFrame {
                 file_type: FileType::Ipc(options),
             },
         };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
+        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true, None)?;
         polars_ensure!(
             is_streaming,
             ComputeError: "cannot run the whole query in a streaming order; \
@@ -607,6 +775,132 @@ impl LazyFrame {
         Ok(())
     }
 
+    /// Stream a query result as an Arrow IPC stream into `writer`, e.g. stdout or a socket.
+    /// Every batch is written as soon as it is computed, so the consumer can process the
+    /// result incrementally. This methods will return an error if the query cannot be
+    /// completely done in a streaming fashion.
+    #[cfg(feature = "ipc_streaming")]
+    pub fn sink_ipc_stream<W: std::io::Write + Send + Sync + 'static>(
+        mut self,
+        writer: W,
+        options: IpcWriterOptions,
+    ) -> PolarsResult<()> {
+        self.opt_state.streaming = true;
+        self.logical_plan = LogicalPlan::FileSink {
+            input: Box::new(self.logical_plan),
+            payload: FileSinkOptions {
+                path: Default::default(),
+                file_type: FileType::IpcStream {
+                    writer: SharedWriter::new(writer),
+                    options,
+                },
+            },
+        };
+        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true, None)?;
+        polars_ensure!(
+            is_streaming,
+            ComputeError: "cannot run the whole query in a streaming order; \
+            use `collect()` and write the result with an `IpcStreamWriter` instead"
+        );
+        let _ = physical_plan.execute(&mut state)?;
+        Ok(())
+    }
+
+    /// Stream the results of multiple queries that scan the same file into multiple sinks,
+    /// while reading the file only once. The slowest query determines the pace at which the
+    /// file is read.
+    ///
+    /// Every query must scan the same file and must be completely streamable. Because the scan
+    /// is shared, predicates, projections and slices are not pushed down into the scan.
+    #[cfg(all(feature = "streaming", any(feature = "parquet", feature = "ipc")))]
+    pub fn sink_multiple(branches: Vec<(LazyFrame, FileSinkOptions)>) -> PolarsResult<()> {
+        polars_ensure!(
+            !branches.is_empty(),
+            ComputeError: "expected at least one query to sink"
+        );
+        let mut pipelines = Vec::with_capacity(branches.len());
+        let mut memory_limits = Vec::with_capacity(branches.len());
+        let mut finger_print = None;
+
+        for (lf, payload) in branches {
+            let mut opt_state = lf.opt_state;
+            opt_state.streaming = true;
+            // the scan is shared, so it must produce the same data for every query
+            opt_state.predicate_pushdown = false;
+            opt_state.projection_pushdown = false;
+            opt_state.slice_pushdown = false;
+            let lf = LazyFrame::from_logical_plan(
+                LogicalPlan::FileSink {
+                    input: Box::new(lf.logical_plan),
+                    payload,
+                },
+                opt_state,
+            );
+            let chunk_size = opt_state.streaming_chunk_size;
+
+            let mut expr_arena = Arena::with_capacity(256);
+            let mut lp_arena = Arena::with_capacity(128);
+            let lp_top = optimize(
+                lf.logical_plan,
+                opt_state,
+                &mut lp_arena,
+                &mut expr_arena,
+                &mut vec![],
+                None,
+            )?;
+
+            let mut fps = Vec::with_capacity(1);
+            collect_fingerprints(lp_top, &mut fps, &lp_arena, &expr_arena);
+            polars_ensure!(
+                fps.len() == 1,
+                ComputeError: "every query in `sink_multiple` must scan a single file"
+            );
+            let fp = fps.pop();
+            match &finger_print {
+                None => finger_print = fp,
+                Some(first) => polars_ensure!(
+                    Some(first) == fp.as_ref(),
+                    ComputeError: "every query in `sink_multiple` must scan the same file"
+                ),
+            }
+
+            let pipeline = create_streaming_pipeline(
+                lp_top,
+                &mut lp_arena,
+                &mut expr_arena,
+                chunk_size,
+                opt_state.streaming_sort_memory_budget,
+            )?;
+            pipelines.push(pipeline);
+            memory_limits.push(
+                opt_state
+                    .memory_limit
+                    .map(|limit| Arc::new(MemoryLimit::new(limit))),
+            );
+        }
+        PipeLine::multiplex_source(&mut pipelines)?;
+
+        // every pipeline must run concurrently, otherwise the first pipeline
+        // would block on the consumers that don't run yet
+        std::thread::scope(|s| {
+            let handles = pipelines
+                .into_iter()
+                .zip(memory_limits)
+                .map(|(mut pipeline, memory_limit)| {
+                    s.spawn(move || {
+                        let mut state = ExecutionState::new();
+                        state.set_memory_limit(memory_limit);
+                        pipeline.execute(Box::new(state)).map(|_| ())
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<PolarsResult<()>>()
+        })
+    }
+
     /// Filter by some predicate expression.
     ///
     /// # Example
@@ -1088,6 +1382,23 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Unpivot the DataFrame, with the id and value columns given by selectors.
+    ///
+    /// The `id_vars` and `value_vars` of `args` are replaced by the selected columns.
+    pub fn melt_by(
+        self,
+        id_vars: impl Into<Selector>,
+        value_vars: impl Into<Selector>,
+        args: MeltArgs,
+    ) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self
+            .get_plan_builder()
+            .melt_by(id_vars.into(), value_vars.into(), args)
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     /// Limit the DataFrame to the first `n` rows. Note if you don't want the rows to be scanned,
     /// use [fetch](LazyFrame::fetch).
     pub fn limit(self, n: IdxSize) -> LazyFrame {
@@ -1132,6 +1443,11 @@ impl LazyFrame {
 
     /// Add a new column at index 0 that counts the rows.
     ///
+    /// The row count is added by the scan if this is called directly on a csv, ipc or parquet
+    /// scan. The rows are counted before any later slice is applied, so
+    /// `with_row_count(..).slice(2, 3)` yields the row counts `2..5`. Errors if the row count
+    /// overflows [`IDX_DTYPE`], see [`LazyFrame::with_row_count_and_dtype`].
+    ///
     /// # Warning
     /// This can have a negative effect on query performance.
     /// This may for instance block predicate pushdown optimization.
@@ -1202,12 +1518,69 @@ impl LazyFrame {
         )
     }
 
+    /// Add a new column at index 0 that counts the rows, of dtype `UInt32` or `UInt64`.
+    ///
+    /// A row count of [`IDX_DTYPE`] is the same as [`LazyFrame::with_row_count`]. The readers
+    /// only count rows in [`IDX_DTYPE`], so other dtypes are counted after the scan and block
+    /// the pushdown of projections and slices into it. Errors if the row count does not fit in
+    /// `dtype`.
+    pub fn with_row_count_and_dtype(
+        self,
+        name: &str,
+        offset: Option<u64>,
+        dtype: DataType,
+    ) -> LazyFrame {
+        if dtype == IDX_DTYPE {
+            if let Ok(offset) = offset.map(IdxSize::try_from).transpose() {
+                return self.with_row_count(name, offset);
+            }
+        }
+
+        let name2: SmartString = name.into();
+        let dtype2 = dtype.clone();
+        let udf_schema = move |s: &Schema| {
+            // Can't error, index 0 is always in bounds
+            let new = s
+                .new_inserting_at_index(0, name2.clone(), dtype2.clone())
+                .unwrap();
+            Ok(Arc::new(new))
+        };
+
+        let name = name.to_owned();
+        self.map(
+            move |df: DataFrame| df.with_row_count_and_dtype(&name, offset, &dtype),
+            AllowedOptimizations {
+                slice_pushdown: false,
+                predicate_pushdown: false,
+                streaming: false,
+                ..Default::default()
+            },
+            Some(Arc::new(udf_schema)),
+            Some("WITH ROW COUNT"),
+        )
+    }
+
     /// Unnest the given `Struct` columns. This means that the fields of the `Struct` type will be
     /// inserted as columns.
+    ///
+    /// Fails if a field has the same name as another column of the output, see
+    /// [`LazyFrame::unnest_with`] to resolve these.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoIterator<Item = S>, S: AsRef<str>>(self, cols: I) -> Self {
+        self.unnest_with(cols, UnnestCollision::Error)
+    }
+
+    /// Unnest the given `Struct` columns and name the fields that collide with another column of
+    /// the output by `collision`. The names are resolved when the schema of the plan is.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        cols: I,
+        collision: UnnestCollision,
+    ) -> Self {
         self.map_private(FunctionNode::Unnest {
             columns: cols.into_iter().map(|s| Arc::from(s.as_ref())).collect(),
+            collision,
         })
     }
 
@@ -1344,6 +1717,7 @@ pub struct JoinBuilder {
     allow_parallel: bool,
     force_parallel: bool,
     suffix: Option<String>,
+    build_side: JoinBuildSide,
 }
 impl JoinBuilder {
     pub fn new(lf: LazyFrame) -> Self {
@@ -1356,6 +1730,7 @@ impl JoinBuilder {
             allow_parallel: true,
             force_parallel: false,
             suffix: None,
+            build_side: JoinBuildSide::Auto,
         }
     }
 
@@ -1409,6 +1784,13 @@ impl JoinBuilder {
         self
     }
 
+    /// The input to build the hash table on.
+    /// Defaults to [`JoinBuildSide::Auto`], which builds on the input with the fewest rows.
+    pub fn build_side(mut self, build_side: JoinBuildSide) -> Self {
+        self.build_side = build_side;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -1434,6 +1816,7 @@ impl JoinBuilder {
                     force_parallel: self.force_parallel,
                     how: self.how,
                     suffix,
+                    build_side: self.build_side,
                     ..Default::default()
                 },
             )
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
+use polars_io::ndjson_core::ndjson::JsonNesting;
 use polars_io::RowCount;
 
 use super::{LazyFileListReader, LazyFrame, ScanArgsAnonymous};
@@ -12,9 +13,13 @@ pub struct LazyJsonLineReader {
     pub(crate) low_memory: bool,
     pub(crate) rechunk: bool,
     pub(crate) schema: Option<Schema>,
+    pub(crate) schema_overwrite: Option<Schema>,
     pub(crate) row_count: Option<RowCount>,
     pub(crate) infer_schema_length: Option<usize>,
     pub(crate) n_rows: Option<usize>,
+    pub(crate) ignore_errors: bool,
+    pub(crate) ignore_unknown_keys: bool,
+    pub(crate) nesting: JsonNesting,
 }
 
 impl LazyJsonLineReader {
@@ -26,9 +31,13 @@ impl LazyJsonLineReader {
             low_memory: false,
             rechunk: true,
             schema: None,
+            schema_overwrite: None,
             row_count: None,
             infer_schema_length: Some(100),
             n_rows: None,
+            ignore_errors: false,
+            ignore_unknown_keys: true,
+            nesting: JsonNesting::default(),
         }
     }
     /// Add a `row_count` column.
@@ -59,6 +68,48 @@ impl LazyJsonLineReader {
         self
     }
 
+    /// Overwrite the dtypes of these columns in the inferred schema.
+    #[must_use]
+    pub fn with_schema_overwrite(mut self, schema: Schema) -> Self {
+        self.schema_overwrite = Some(schema);
+        self
+    }
+
+    /// Set values that cannot be parsed as the dtype of their column to null instead of
+    /// raising an error.
+    #[must_use]
+    pub fn with_ignore_errors(mut self, ignore: bool) -> Self {
+        self.ignore_errors = ignore;
+        self
+    }
+
+    /// Ignore keys that are not in the schema. If `false`, raise an error when a line
+    /// contains a key that is not in the schema, e.g. a key that only appears after
+    /// the lines used for schema inference.
+    /// The default is `true`.
+    #[must_use]
+    pub fn with_ignore_unknown_keys(mut self, ignore: bool) -> Self {
+        self.ignore_unknown_keys = ignore;
+        self
+    }
+
+    /// Read objects that are nested deeper than `depth` as json strings. With a depth of `0`
+    /// all objects are read as json strings.
+    #[must_use]
+    pub fn with_max_nesting_depth(mut self, depth: Option<usize>) -> Self {
+        self.nesting.max_depth = depth;
+        self
+    }
+
+    /// Flatten nested objects into columns named by joining the keys with `separator`,
+    /// e.g. `{"a": {"b": 1}}` is read into column `a.b` with separator `"."`.
+    /// Objects in arrays are not flattened.
+    #[must_use]
+    pub fn with_flatten_separator(mut self, separator: Option<&str>) -> Self {
+        self.nesting.flatten_separator = separator.map(|s| s.to_string());
+        self
+    }
+
     /// Reduce memory usage in expensive of performance
     #[must_use]
     pub fn low_memory(mut self, toggle: bool) -> Self {
@@ -117,4 +168,8 @@ impl LazyFileListReader for LazyJsonLineReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.row_count.as_ref()
     }
+
+    fn with_row_count(self, row_count: Option<RowCount>) -> Self {
+        LazyJsonLineReader::with_row_count(self, row_count)
+    }
 }
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
-use polars_io::parquet::ParallelStrategy;
+use polars_io::parquet::{ParallelStrategy, ParquetReadStats};
 use polars_io::RowCount;
 
 use crate::prelude::*;
@@ -17,6 +17,15 @@ pub struct ScanArgsParquet {
     pub low_memory: bool,
     pub cloud_options: Option<CloudOptions>,
     pub use_statistics: bool,
+    /// Add the `key=value` directories of the paths as columns.
+    pub hive_partitioning: bool,
+    /// Add a column with this name with the path of the file of every row.
+    pub file_path_column: Option<String>,
+    /// Restart the `row_count` at its offset for every file of a glob.
+    pub row_count_per_file: bool,
+    /// Count the decoded row groups and the prefiltered rows of all scanned files in these
+    /// stats, which can be read after the query has run.
+    pub read_stats: Option<Arc<ParquetReadStats>>,
 }
 
 impl Default for ScanArgsParquet {
@@ -30,10 +39,66 @@ impl Default for ScanArgsParquet {
             low_memory: false,
             cloud_options: None,
             use_statistics: true,
+            hive_partitioning: false,
+            file_path_column: None,
+            row_count_per_file: false,
+            read_stats: None,
         }
     }
 }
 
+/// The files of a hive partitioned dataset must have the same (partition) columns.
+/// A null partition doesn't imply a dtype, so it is cast to the dtype of the other files.
+fn unify_hive_schemas(lfs: Vec<LazyFrame>) -> PolarsResult<Vec<LazyFrame>> {
+    let schemas = lfs
+        .iter()
+        .map(|lf| lf.schema())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut schema = match schemas.first() {
+        Some(schema) => schema.as_ref().clone(),
+        None => return Ok(lfs),
+    };
+    for other in &schemas[1..] {
+        polars_ensure!(
+            schema.iter_names().eq(other.iter_names()),
+            ComputeError: "files of a hive partitioned dataset have conflicting columns: {:?} and {:?}",
+            schema.iter_names().collect::<Vec<_>>(), other.iter_names().collect::<Vec<_>>()
+        );
+        for (name, other_dtype) in other.iter() {
+            let dtype = schema.get(name).unwrap().clone();
+            match (&dtype, other_dtype) {
+                (dtype, other_dtype) if dtype == other_dtype => {}
+                (_, DataType::Null) => {}
+                (DataType::Null, other_dtype) => {
+                    schema.set_dtype(name, other_dtype.clone());
+                }
+                (dtype, other_dtype) => polars_bail!(
+                    ComputeError: "files of a hive partitioned dataset have conflicting dtypes \
+                    for column {}: {} and {}", name, dtype, other_dtype
+                ),
+            }
+        }
+    }
+
+    Ok(lfs
+        .into_iter()
+        .zip(schemas)
+        .map(|(lf, lf_schema)| {
+            let casts = lf_schema
+                .iter()
+                .zip(schema.iter_dtypes())
+                .filter(|((_, dtype), target)| dtype != target)
+                .map(|((name, _), target)| col(name).cast(target.clone()))
+                .collect::<Vec<_>>();
+            if casts.is_empty() {
+                lf
+            } else {
+                lf.with_columns(casts)
+            }
+        })
+        .collect())
+}
+
 #[derive(Clone)]
 struct LazyParquetReader {
     args: ScanArgsParquet,
@@ -60,6 +125,8 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.args.hive_partitioning,
+            self.args.read_stats,
         )?
         .build()
         .into();
@@ -73,6 +140,15 @@ impl LazyFileListReader for LazyParquetReader {
         Ok(lf)
     }
 
+    fn concat_impl(&self, lfs: Vec<LazyFrame>) -> PolarsResult<LazyFrame> {
+        let lfs = if self.args.hive_partitioning {
+            unify_hive_schemas(lfs)?
+        } else {
+            lfs
+        };
+        concat_impl(&lfs, self.rechunk(), true, true)
+    }
+
     fn path(&self) -> &Path {
         self.path.as_path()
     }
@@ -102,6 +178,19 @@ impl LazyFileListReader for LazyParquetReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.args.row_count = row_count;
+        self
+    }
+
+    fn row_count_per_file(&self) -> bool {
+        self.args.row_count_per_file
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        self.args.file_path_column.as_deref()
+    }
 }
 
 impl LazyFrame {
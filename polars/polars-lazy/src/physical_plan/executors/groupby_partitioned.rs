@@ -105,6 +105,7 @@ fn run_partitions(
                     .collect::<PolarsResult<Vec<_>>>()?;
 
                 columns.extend_from_slice(&agg_columns);
+                state.check_memory_limit()?;
 
                 DataFrame::new(columns)
             })
@@ -187,7 +188,7 @@ fn can_run_partitioned(
 
         let (unique_estimate, sampled_method) = match (keys.len(), keys[0].dtype()) {
             #[cfg(feature = "dtype-categorical")]
-            (1, DataType::Categorical(Some(rev_map))) => (rev_map.len(), "known"),
+            (1, DataType::Categorical(Some(rev_map), _)) => (rev_map.len(), "known"),
             _ => {
                 // sqrt(N) is a good sample size as it remains low on large numbers
                 // it is better than taking a fraction as it saturates
@@ -251,6 +252,9 @@ impl PartitionGroupByExec {
             &mut expr_arena,
             &mut vec![],
             false,
+            None,
+            None,
+            state.memory_limit().cloned(),
         )
         .unwrap();
 
@@ -7,6 +7,8 @@ mod groupby_dynamic;
 mod groupby_partitioned;
 mod groupby_rolling;
 mod join;
+mod plan_node;
+mod profiled;
 mod projection;
 #[cfg(feature = "python")]
 mod python_scan;
@@ -36,6 +38,8 @@ pub(super) use self::groupby_partitioned::*;
 #[cfg(feature = "dynamic_groupby")]
 pub(super) use self::groupby_rolling::*;
 pub(super) use self::join::*;
+pub(super) use self::plan_node::*;
+pub(super) use self::profiled::*;
 pub(super) use self::projection::*;
 #[cfg(feature = "python")]
 pub(super) use self::python_scan::*;
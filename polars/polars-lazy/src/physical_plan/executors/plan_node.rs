@@ -0,0 +1,26 @@
+use super::*;
+
+/// Wraps the executor of a node of the plan, so that the errors it raises name that node.
+///
+/// The memory limit of the state is checked after the node has executed.
+pub(crate) struct PlanNodeExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) name: &'static str,
+}
+
+impl Executor for PlanNodeExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let out = self.input.execute(state).and_then(|df| {
+            state.check_memory_limit()?;
+            Ok(df)
+        });
+        out.map_err(|err| {
+            // the innermost node that failed is reported
+            if err.plan_node().is_some() {
+                err
+            } else {
+                err.with_plan_node(self.name)
+            }
+        })
+    }
+}
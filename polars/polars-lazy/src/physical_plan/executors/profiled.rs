@@ -0,0 +1,20 @@
+use super::*;
+
+/// Wraps the executor of a node of the optimized plan when a query is profiled, so that the
+/// timings the executor records and the rows it produces can be related to that node.
+pub(crate) struct ProfiledExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) node_id: usize,
+}
+
+impl Executor for ProfiledExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let prev_node_id = state.node_id.replace(self.node_id);
+        let out = self.input.execute(state);
+        state.node_id = prev_node_id;
+
+        let df = out?;
+        state.record_rows(self.node_id, df.height());
+        Ok(df)
+    }
+}
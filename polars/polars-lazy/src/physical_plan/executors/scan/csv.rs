@@ -13,6 +13,66 @@ pub struct CsvExec {
 
 impl CsvExec {
     fn read(&mut self) -> PolarsResult<DataFrame> {
+        match self.options.file_path_column.take() {
+            Some(file_path_column) => self.read_with_file_path_column(file_path_column),
+            None => self.read_file(),
+        }
+    }
+
+    /// Read the file and add a column with the path of the file.
+    fn read_with_file_path_column(
+        &mut self,
+        file_path_column: FilePathColumn,
+    ) -> PolarsResult<DataFrame> {
+        let name = file_path_column.name();
+        let mut schema = self.schema.as_ref().clone();
+        schema.remove(name);
+        self.schema = Arc::new(schema);
+
+        // the predicate can only be applied once the file path column is added
+        let mut path_predicate = None;
+        let n_rows = self.options.n_rows;
+        if let Some(pred) = &self.predicate {
+            let leaves = expr_to_leaf_column_names(pred.as_expression().unwrap());
+            if leaves.iter().any(|leaf| leaf.as_ref() == name) {
+                path_predicate = self.predicate.take();
+                self.options.n_rows = None;
+            }
+        }
+
+        let mut projected = true;
+        let mut dummy_column = None;
+        if let Some(with_columns) = &mut self.options.with_columns {
+            projected = with_columns.iter().any(|column| column == name);
+            let with_columns = Arc::make_mut(with_columns);
+            with_columns.retain(|column| column != name);
+            // we need a column to know the number of rows of the file
+            if with_columns.is_empty() {
+                let (first, _) = self.schema.get_index(0).unwrap();
+                dummy_column = Some(first.to_string());
+                with_columns.push(first.to_string());
+            }
+        }
+
+        let mut df = self.read_file()?;
+        if let Some(dummy_column) = dummy_column {
+            df = df.drop(&dummy_column)?;
+        }
+        if projected {
+            let height = df.height();
+            df.with_column(file_path_column.column.new_from_index(0, height))?;
+        }
+        if let Some(pred) = path_predicate {
+            let mask = pred.evaluate(&df, &Default::default())?;
+            df = df.filter(mask.bool()?)?;
+            if let Some(n_rows) = n_rows {
+                df = df.head(Some(n_rows));
+            }
+        }
+        Ok(df)
+    }
+
+    fn read_file(&mut self) -> PolarsResult<DataFrame> {
         let mut with_columns = mem::take(&mut self.options.with_columns);
         let mut projected_len = 0;
         with_columns.as_ref().map(|columns| {
@@ -40,6 +100,7 @@ impl CsvExec {
             .with_columns(with_columns.map(|mut cols| std::mem::take(Arc::make_mut(&mut cols))))
             .low_memory(self.options.low_memory)
             .with_null_values(std::mem::take(&mut self.options.null_values))
+            .with_ragged_lines(self.options.ragged_lines)
             .with_predicate(predicate)
             .with_encoding(CsvEncoding::LossyUtf8)
             .with_comment_char(self.options.comment_char)
@@ -49,6 +110,16 @@ impl CsvExec {
             .with_rechunk(self.options.rechunk)
             .with_row_count(std::mem::take(&mut self.options.row_count))
             .with_try_parse_dates(self.options.try_parse_dates)
+            .with_strict_date_formats(
+                self.options
+                    .date_formats
+                    .as_ref()
+                    .map_or(false, |date_formats| date_formats.strict),
+            )
+            .with_date_formats(
+                std::mem::take(&mut self.options.date_formats)
+                    .map(|date_formats| date_formats.formats),
+            )
             .finish()
     }
 }
@@ -20,6 +20,7 @@ impl IpcExec {
         );
         IpcReader::new(file)
             .with_n_rows(n_rows)
+            .with_skip_rows(self.options.skip_rows)
             .with_row_count(std::mem::take(&mut self.options.row_count))
             .set_rechunk(self.options.rechunk)
             .with_projection(projection)
@@ -36,7 +37,7 @@ impl Executor for IpcExec {
                 .predicate
                 .as_ref()
                 .map(|ae| ae.as_expression().unwrap().clone()),
-            slice: (0, self.options.n_rows),
+            slice: (self.options.skip_rows, self.options.n_rows),
         };
 
         let profile_name = if state.has_node_timer() {
@@ -1,21 +1,52 @@
-use polars_core::error::to_compute_err;
+use std::io::{BufRead, Read};
+
+use polars_io::ndjson_core::ndjson::{infer_schema, overwrite_schema, BatchedJsonLineReader};
 
 use super::*;
-use crate::prelude::{AnonymousScan, AnonymousScanOptions, LazyJsonLineReader};
+use crate::prelude::{
+    AnonymousScan, AnonymousScanOptions, BatchedAnonymousScan, LazyJsonLineReader,
+};
+
+impl LazyJsonLineReader {
+    fn reader(
+        &self,
+        scan_opts: &AnonymousScanOptions,
+    ) -> PolarsResult<JsonLineReader<'_, std::fs::File>> {
+        let projection = scan_opts
+            .output_schema
+            .as_ref()
+            .map(|schema| schema.iter_names().map(|name| name.to_string()).collect());
+        let mut reader = JsonLineReader::from_path(&self.path)?;
+        if let Some(depth) = self.nesting.max_depth {
+            reader = reader.max_nesting_depth(depth);
+        }
+        Ok(reader
+            .flatten_separator(self.nesting.flatten_separator.as_deref())
+            .with_projection(projection)
+            .with_rechunk(self.rechunk)
+            .low_memory(self.low_memory)
+            .with_n_rows(scan_opts.n_rows)
+            .with_chunk_size(self.batch_size)
+            .with_ignore_errors(self.ignore_errors)
+            .with_ignore_unknown_keys(self.ignore_unknown_keys))
+    }
+}
+
+struct BatchedJsonLineScan(BatchedJsonLineReader);
+
+impl BatchedAnonymousScan for BatchedJsonLineScan {
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        self.0.next_batches(n)
+    }
+}
 
 impl AnonymousScan for LazyJsonLineReader {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
     fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
-        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
-        JsonLineReader::from_path(&self.path)?
-            .with_schema(&schema)
-            .with_rechunk(self.rechunk)
-            .with_chunk_size(self.batch_size)
-            .low_memory(self.low_memory)
-            .with_n_rows(scan_opts.n_rows)
-            .with_chunk_size(self.batch_size)
+        self.reader(&scan_opts)?
+            .with_schema(&scan_opts.schema)
             .finish()
     }
 
@@ -23,13 +54,41 @@ impl AnonymousScan for LazyJsonLineReader {
         let f = std::fs::File::open(&self.path)?;
         let mut reader = std::io::BufReader::new(f);
 
-        let data_type =
-            arrow_ndjson::read::infer(&mut reader, infer_schema_length).map_err(to_compute_err)?;
-        let schema = Schema::from_iter(StructArray::get_fields(&data_type));
+        let mut bytes = vec![];
+        match infer_schema_length {
+            Some(n) => {
+                for _ in 0..n {
+                    if reader.read_until(b'\n', &mut bytes)? == 0 {
+                        break;
+                    }
+                }
+            }
+            None => {
+                reader.read_to_end(&mut bytes)?;
+            }
+        }
+        let mut schema = infer_schema(&bytes, infer_schema_length, &self.nesting)?;
+        if let Some(overwrite) = &self.schema_overwrite {
+            overwrite_schema(&mut schema, overwrite);
+        }
 
         Ok(schema)
     }
     fn allows_projection_pushdown(&self) -> bool {
         true
     }
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+    fn batched(
+        &self,
+        scan_opts: AnonymousScanOptions,
+        chunk_size: usize,
+    ) -> PolarsResult<Box<dyn BatchedAnonymousScan>> {
+        let reader = self
+            .reader(&scan_opts)?
+            .with_schema(&scan_opts.schema)
+            .batched(chunk_size)?;
+        Ok(Box::new(BatchedJsonLineScan(reader)))
+    }
 }
@@ -1,6 +1,9 @@
 use std::path::PathBuf;
 
 use polars_core::cloud::CloudOptions;
+#[cfg(feature = "async")]
+use polars_io::is_cloud_url;
+use polars_plan::logical_plan::HivePartitions;
 
 use super::*;
 
@@ -8,6 +11,7 @@ use super::*;
 pub struct ParquetExec {
     path: PathBuf,
     schema: SchemaRef,
+    output_schema: Option<SchemaRef>,
     predicate: Option<Arc<dyn PhysicalExpr>>,
     options: ParquetOptions,
     cloud_options: Option<CloudOptions>,
@@ -17,6 +21,7 @@ impl ParquetExec {
     pub(crate) fn new(
         path: PathBuf,
         schema: SchemaRef,
+        output_schema: Option<SchemaRef>,
         predicate: Option<Arc<dyn PhysicalExpr>>,
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
@@ -24,6 +29,7 @@ impl ParquetExec {
         ParquetExec {
             path,
             schema,
+            output_schema,
             predicate,
             options,
             cloud_options,
@@ -31,22 +37,216 @@ impl ParquetExec {
     }
 
     fn read(&mut self) -> PolarsResult<DataFrame> {
+        let hive_partitions = if self.options.hive_partitioning {
+            HivePartitions::parse_url(&self.path)
+        } else {
+            None
+        };
+        if hive_partitions.is_none() && self.options.file_path_column.is_none() {
+            return self.read_file(&self.predicate.clone(), self.options.n_rows);
+        }
+        self.read_with_constant_columns(hive_partitions)
+    }
+
+    /// Read the file and add the columns that have a single value for the whole file, these
+    /// are the hive partition columns and the file path column.
+    fn read_with_constant_columns(
+        &mut self,
+        hive_partitions: Option<HivePartitions>,
+    ) -> PolarsResult<DataFrame> {
+        let mut constant_columns = hive_partitions
+            .as_ref()
+            .map(|hive| hive.materialize_partition_columns())
+            .unwrap_or_default();
+        if let Some(file_path_column) = &self.options.file_path_column {
+            constant_columns.push(file_path_column.column.clone());
+        }
+        let constant_schema: Schema = constant_columns
+            .iter()
+            .map(|s| s.field().into_owned())
+            .collect();
+        let output_schema = self
+            .output_schema
+            .clone()
+            .unwrap_or_else(|| self.schema.clone());
+        let mut predicate = self.predicate.clone();
+        let mut n_rows = self.options.n_rows;
+        let mut constant_predicate = None;
+        if let Some(pred) = &self.predicate {
+            let leaves = expr_to_leaf_column_names(pred.as_expression().unwrap());
+            if leaves.iter().any(|name| constant_schema.contains(name)) {
+                // the statistics of the partitions are sufficient to skip the whole file
+                if let Some(hive) = &hive_partitions {
+                    if leaves.iter().all(|name| hive.schema().contains(name)) {
+                        if let Some(stats_evaluator) = pred.as_stats_evaluator() {
+                            if !stats_evaluator.should_read(hive.get_statistics())? {
+                                return Ok(DataFrame::from(output_schema.as_ref()));
+                            }
+                        }
+                    }
+                }
+                // the predicate can only be applied once the constant columns are added
+                constant_predicate = predicate.take();
+                n_rows = None;
+            }
+        }
+
+        // the constant columns are not in the file
+        let mut file_columns = None;
+        if let Some(with_columns) = &self.options.with_columns {
+            constant_columns.retain(|s| with_columns.iter().any(|name| name == s.name()));
+            file_columns = Some(
+                with_columns
+                    .iter()
+                    .filter(|name| !constant_schema.contains(name))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        let mut df = match file_columns {
+            // only constant columns are projected, the number of rows is in the metadata
+            Some(file_columns) if file_columns.is_empty() && !constant_columns.is_empty() => {
+                let mut height = self.num_rows()?;
+                if let Some(n_rows) = _set_n_rows_for_scan(n_rows) {
+                    height = std::cmp::min(height, n_rows);
+                }
+                let columns = constant_columns
+                    .iter()
+                    .map(|s| s.new_from_index(0, height))
+                    .collect();
+                let mut df = DataFrame::new_no_checks(columns);
+                if let Some(rc) = &self.options.row_count {
+                    df.with_row_count_mut(&rc.name, Some(rc.offset))?;
+                }
+                df
+            }
+            file_columns => {
+                // we need a column to know the number of rows of the file
+                let mut dummy_column = None;
+                if let Some(mut file_columns) = file_columns {
+                    if file_columns.is_empty() {
+                        let (name, _) = self.schema.get_index(0).unwrap();
+                        dummy_column = Some(name.to_string());
+                        file_columns.push(name.to_string());
+                    }
+                    self.options.with_columns = Some(Arc::new(file_columns));
+                }
+                let mut df = self.read_file(&predicate, n_rows)?;
+                if let Some(name) = dummy_column {
+                    df = df.drop(&name)?;
+                }
+                let height = df.height();
+                for s in &constant_columns {
+                    df.with_column(s.new_from_index(0, height))?;
+                }
+                df
+            }
+        };
+        for s in &constant_columns {
+            let dtype = output_schema.get(s.name()).unwrap();
+            if s.dtype() != dtype {
+                let casted = df.column(s.name())?.cast(dtype)?;
+                df.with_column(casted)?;
+            }
+        }
+
+        // restore the order of the schema, columns that are added by the reader come first
+        let mut columns = df
+            .get_column_names()
+            .into_iter()
+            .filter(|name| !output_schema.contains(name))
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        columns.extend(output_schema.iter_names().map(|name| name.to_string()));
+        let mut df = df.select(columns)?;
+
+        if let Some(pred) = constant_predicate {
+            let mask = pred.evaluate(&df, &Default::default())?;
+            df = df.filter(mask.bool()?)?;
+            if let Some(n_rows) = self.options.n_rows {
+                df = df.head(Some(n_rows));
+            }
+        }
+        Ok(df)
+    }
+
+    /// Number of rows in the file, read from the metadata.
+    fn num_rows(&self) -> PolarsResult<usize> {
+        #[cfg(feature = "async")]
+        if is_cloud_url(&self.path) {
+            let uri = self.path.to_string_lossy();
+            let (_, num_rows) = ParquetAsyncReader::file_info(&uri, self.cloud_options.as_ref())?;
+            return Ok(num_rows);
+        }
+        ParquetReader::new(std::fs::File::open(&self.path)?).num_rows()
+    }
+
+    fn read_file(
+        &mut self,
+        predicate: &Option<Arc<dyn PhysicalExpr>>,
+        n_rows: Option<usize>,
+    ) -> PolarsResult<DataFrame> {
+        #[cfg(feature = "async")]
+        if is_cloud_url(&self.path) {
+            return self.read_cloud_file(predicate, n_rows);
+        }
         let (file, projection, n_rows, predicate) = prepare_scan_args(
             &self.path,
-            &self.predicate,
+            predicate,
             &mut self.options.with_columns,
             &mut self.schema,
-            self.options.n_rows,
+            n_rows,
         );
 
-        ParquetReader::new(file)
+        let mut reader = ParquetReader::new(file)
             .with_n_rows(n_rows)
             .read_parallel(self.options.parallel)
             .with_row_count(mem::take(&mut self.options.row_count))
             .set_rechunk(self.options.rechunk)
             .set_low_memory(self.options.low_memory)
             .use_statistics(self.options.use_statistics)
-            ._finish_with_scan_ops(predicate, projection.as_ref().map(|v| v.as_ref()))
+            .with_struct_projection(self.output_schema.clone());
+        if let Some(stats) = &self.options.read_stats {
+            reader = reader.with_read_stats(stats.clone());
+        }
+        reader._finish_with_scan_ops(predicate, projection.as_ref().map(|v| v.as_ref()))
+    }
+
+    /// Read a file from an object store. Only the projected columns of the row groups that
+    /// may pass the predicate are downloaded.
+    #[cfg(feature = "async")]
+    fn read_cloud_file(
+        &mut self,
+        predicate: &Option<Arc<dyn PhysicalExpr>>,
+        n_rows: Option<usize>,
+    ) -> PolarsResult<DataFrame> {
+        let projection = mem::take(&mut self.options.with_columns)
+            .map(|with_columns| {
+                with_columns
+                    .iter()
+                    .map(|name| self.schema.try_index_of(name))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .transpose()?;
+        let predicate = predicate
+            .clone()
+            .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
+
+        let uri = self.path.to_string_lossy();
+        let mut reader = ParquetAsyncReader::from_uri(&uri, self.cloud_options.as_ref())?;
+        if let Some(stats) = &self.options.read_stats {
+            reader = reader.with_read_stats(stats.clone());
+        }
+        reader
+            .with_n_rows(_set_n_rows_for_scan(n_rows))
+            .with_row_count(mem::take(&mut self.options.row_count))
+            .with_projection(projection)
+            .with_predicate(predicate)
+            .set_rechunk(self.options.rechunk)
+            .use_statistics(self.options.use_statistics)
+            .with_struct_projection(self.output_schema.clone())
+            .finish()
     }
 }
 
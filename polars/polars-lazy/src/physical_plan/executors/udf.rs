@@ -16,7 +16,11 @@ impl Executor for UdfExec {
         let df = self.input.execute(state)?;
 
         let profile_name = if state.has_node_timer() {
-            Cow::Owned(format!("{}", self.function))
+            match self.function {
+                // the formatted pipeline contains the whole streaming plan
+                FunctionNode::Pipeline { .. } => Cow::Borrowed("streaming"),
+                _ => Cow::Owned(format!("{}", self.function)),
+            }
         } else {
             Cow::Borrowed("")
         };
@@ -35,40 +35,38 @@ impl Executor for UnionExec {
 
             let mut offset = self.options.slice_offset as usize;
             let mut len = self.options.slice_len as usize;
-            let dfs = inputs
-                .into_iter()
-                .enumerate()
-                .map(|(idx, mut input)| {
-                    let mut state = state.split();
-                    state.branch_idx += idx;
-                    let df = input.execute(&mut state)?;
+            let mut dfs = Vec::with_capacity(inputs.len());
+            for (idx, mut input) in inputs.into_iter().enumerate() {
+                let mut state = state.split();
+                state.branch_idx += idx;
+                let df = input.execute(&mut state)?;
 
-                    if !sliced_path {
-                        return Ok(Some(df));
-                    }
+                if !sliced_path {
+                    dfs.push(df);
+                    continue;
+                }
 
-                    Ok(if offset > df.height() {
-                        offset -= df.height();
-                        None
-                    } else if offset + len > df.height() {
-                        len -= df.height() - offset;
-                        if offset == 0 {
-                            Some(df)
-                        } else {
-                            let out = Some(df.slice(offset as i64, usize::MAX));
-                            offset = 0;
-                            out
-                        }
-                    } else {
-                        let out = Some(df.slice(offset as i64, len));
-                        len = 0;
-                        offset = 0;
-                        out
-                    })
-                })
-                .collect::<PolarsResult<Vec<_>>>()?;
+                if offset >= df.height() {
+                    // the input is skipped entirely, we keep an empty frame for the schema
+                    offset -= df.height();
+                    dfs.push(df.clear());
+                } else {
+                    let df = df.slice(offset as i64, len);
+                    offset = 0;
+                    len -= df.height();
+                    dfs.push(df);
+                }
+
+                // we have all the rows we need, the remaining inputs are not executed
+                if len == 0 {
+                    if state.verbose() {
+                        eprintln!("UNION: slice is filled after input {idx}")
+                    }
+                    break;
+                }
+            }
 
-            concat_df(dfs.iter().flatten())
+            concat_df(&dfs)
         } else {
             if state.verbose() {
                 println!("UNION: union is run in parallel")
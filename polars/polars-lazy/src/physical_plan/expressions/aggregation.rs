@@ -122,8 +122,9 @@ impl PhysicalExpr for AggregationExpr {
                                     counts.into_inner()
                                 }
                                 _ => {
-                                    let counts: NoNull<IdxCa> = list
-                                        .amortized_iter()
+                                    // Safety:
+                                    // only the lengths of the items are kept
+                                    let counts: NoNull<IdxCa> = unsafe { list.amortized_iter() }
                                         .map(|s| {
                                             if let Some(s) = s {
                                                 s.as_ref().len() as IdxSize
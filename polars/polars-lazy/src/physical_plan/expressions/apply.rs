@@ -95,6 +95,7 @@ impl ApplyExpr {
     fn apply_single_group_aware<'a>(
         &self,
         mut ac: AggregationContext<'a>,
+        state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
         let s = ac.series();
 
@@ -132,7 +133,9 @@ impl ApplyExpr {
                             s.rename(&name);
                         }
                         let mut container = [s];
-                        self.function.call_udf(&mut container)
+                        let out = self.function.call_udf(&mut container)?;
+                        state.check_memory_limit()?;
+                        Ok(out)
                     }
                 })
                 .collect::<PolarsResult<_>>()
@@ -168,6 +171,7 @@ impl ApplyExpr {
         &self,
         mut acs: Vec<AggregationContext<'a>>,
         df: &DataFrame,
+        state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
         let mut container = vec![Default::default(); acs.len()];
         let schema = self.get_input_schema(df);
@@ -175,9 +179,11 @@ impl ApplyExpr {
 
         // aggregate representation of the aggregation contexts
         // then unpack the lists and finally create iterators from this list chunked arrays.
+        // Safety:
+        // the groups are cloned into the container of every iteration
         let mut iters = acs
             .iter_mut()
-            .map(|ac| ac.iter_groups(self.pass_name_to_apply))
+            .map(|ac| unsafe { ac.iter_groups(self.pass_name_to_apply) })
             .collect::<Vec<_>>();
 
         // length of the items to iterate over
@@ -202,7 +208,9 @@ impl ApplyExpr {
                         Some(s) => container.push(s.deep_clone()),
                     }
                 }
-                self.function.call_udf(&mut container)
+                let out = self.function.call_udf(&mut container)?;
+                state.check_memory_limit()?;
+                Ok(out)
             })
             .collect::<PolarsResult<_>>()?;
 
@@ -270,7 +278,7 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 }
-                ApplyOptions::ApplyGroups => self.apply_single_group_aware(ac),
+                ApplyOptions::ApplyGroups => self.apply_single_group_aware(ac, state),
                 ApplyOptions::ApplyFlat => self.apply_single_elementwise(ac),
             }
         } else {
@@ -286,13 +294,13 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 }
-                ApplyOptions::ApplyGroups => self.apply_multiple_group_aware(acs, df),
+                ApplyOptions::ApplyGroups => self.apply_multiple_group_aware(acs, df, state),
                 ApplyOptions::ApplyFlat => {
                     if acs
                         .iter()
                         .any(|ac| matches!(ac.agg_state(), AggState::AggregatedList(_)))
                     {
-                        self.apply_multiple_group_aware(acs, df)
+                        self.apply_multiple_group_aware(acs, df, state)
                     } else {
                         apply_multiple_elementwise(acs, self.function.as_ref(), &self.expr)
                     }
@@ -315,6 +323,7 @@ impl PhysicalExpr for ApplyExpr {
 
         match function {
             FunctionExpr::Boolean(BooleanFunction::IsNull) => Some(self),
+            FunctionExpr::Boolean(BooleanFunction::IsNotNull) => Some(self),
             #[cfg(feature = "is_in")]
             FunctionExpr::Boolean(BooleanFunction::IsIn) => Some(self),
             _ => None,
@@ -422,6 +431,17 @@ impl ApplyExpr {
                     None => Ok(true),
                 }
             }
+            FunctionExpr::Boolean(BooleanFunction::IsNotNull) => {
+                let root = expr_to_leaf_column_name(&self.expr)?;
+
+                match stats.get_stats(&root).ok() {
+                    Some(st) => match st.null_count() {
+                        Some(null_count) if null_count == stats.num_rows() => Ok(false),
+                        _ => Ok(true),
+                    },
+                    None => Ok(true),
+                }
+            }
             #[cfg(feature = "is_in")]
             FunctionExpr::Boolean(BooleanFunction::IsIn) => {
                 let root = match expr_to_leaf_column_name(&input[0]) {
@@ -430,7 +450,12 @@ impl ApplyExpr {
                 };
 
                 let input: &Series = match &input[1] {
-                    Expr::Literal(LiteralValue::Series(s)) => s,
+                    // a list is tested per row, its values don't bound the column
+                    Expr::Literal(LiteralValue::Series(s))
+                        if !matches!(s.dtype(), DataType::List(_)) =>
+                    {
+                        s
+                    }
                     _ => return Ok(true),
                 };
 
@@ -446,27 +471,23 @@ impl ApplyExpr {
                             None => return Ok(true),
                         };
 
-                        // all wanted values are smaller than minimum
-                        // don't need to read
-                        if ChunkCompare::<&Series>::lt(input, &min)
-                            .ok()
-                            .map(|ca| ca.all())
-                            == Some(true)
-                        {
-                            return Ok(false);
+                        // null values in the set match the nulls of the column
+                        if input.null_count() > 0 && st.null_count() != Some(0) {
+                            return Ok(true);
+                        }
+                        // the max statistic could be truncated, we cannot use it
+                        if st.max_is_prefix_of_any(input) {
+                            return Ok(true);
                         }
 
-                        // all wanted values are bigger than maximum
+                        // every wanted value is out of the [min, max] range
                         // don't need to read
-                        if ChunkCompare::<&Series>::gt(input, &max)
-                            .ok()
-                            .map(|ca| ca.all())
-                            == Some(true)
-                        {
-                            return Ok(false);
+                        let gt_eq_min = ChunkCompare::<&Series>::gt_eq(input, &min);
+                        let lt_eq_max = ChunkCompare::<&Series>::lt_eq(input, &max);
+                        match (gt_eq_min, lt_eq_max) {
+                            (Ok(gt_eq_min), Ok(lt_eq_max)) => Ok((&gt_eq_min & &lt_eq_max).any()),
+                            _ => Ok(true),
                         }
-
-                        Ok(true)
                     }
                     None => Ok(true),
                 }
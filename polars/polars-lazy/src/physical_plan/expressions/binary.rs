@@ -32,6 +32,13 @@ impl BinaryExpr {
 
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResult<Series> {
+    // decimal arithmetic can overflow, the borrowed path returns that error
+    #[cfg(feature = "dtype-decimal")]
+    if matches!(left.dtype(), DataType::Decimal(_, _))
+        || matches!(right.dtype(), DataType::Decimal(_, _))
+    {
+        return apply_operator(&left, &right, op);
+    }
     match op {
         Operator::Gt => ChunkCompare::<&Series>::gt(&left, &right).map(|ca| ca.into_series()),
         Operator::GtEq => ChunkCompare::<&Series>::gt_eq(&left, &right).map(|ca| ca.into_series()),
@@ -65,10 +72,10 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
         Operator::NotEq => {
             ChunkCompare::<&Series>::not_equal(left, right).map(|ca| ca.into_series())
         }
-        Operator::Plus => Ok(left + right),
-        Operator::Minus => Ok(left - right),
-        Operator::Multiply => Ok(left * right),
-        Operator::Divide => Ok(left / right),
+        Operator::Plus => left.try_add(right),
+        Operator::Minus => left.try_sub(right),
+        Operator::Multiply => left.try_mul(right),
+        Operator::Divide => left.try_div(right),
         Operator::TrueDivide => match left.dtype() {
             Date | Datetime(_, _) | Float32 | Float64 => Ok(left / right),
             _ => Ok(&left.cast(&Float64)? / &right.cast(&Float64)?),
@@ -119,9 +126,9 @@ impl BinaryExpr {
         mut ac_r: AggregationContext<'a>,
     ) -> PolarsResult<AggregationContext<'a>> {
         let name = ac_l.series().name().to_string();
-        let mut ca: ListChunked = ac_l
-            .iter_groups(false)
-            .zip(ac_r.iter_groups(false))
+        // Safety:
+        // the groups are only used to compute the output of their iteration
+        let mut ca: ListChunked = unsafe { ac_l.iter_groups(false).zip(ac_r.iter_groups(false)) }
             .map(|(l, r)| {
                 match (l, r) {
                     (Some(l), Some(r)) => {
@@ -249,11 +256,30 @@ impl PhysicalExpr for BinaryExpr {
 
 #[cfg(feature = "parquet")]
 mod stats {
-    use polars_io::parquet::predicates::BatchStats;
+    use polars_io::parquet::predicates::{BatchStats, ColumnStats};
     use polars_io::predicates::StatsEvaluator;
+    #[cfg(feature = "dtype-struct")]
+    use polars_plan::utils::struct_field_path;
 
     use super::*;
 
+    /// Get the statistics of the column or the struct field that `e` refers to.
+    /// Returns `None` if the struct field has no statistics.
+    #[allow(unused_variables)]
+    fn get_stats<'a>(
+        stats: &'a BatchStats,
+        e: &dyn PhysicalExpr,
+        name: &str,
+        field_stats: &'a mut Option<ColumnStats>,
+    ) -> PolarsResult<Option<&'a ColumnStats>> {
+        #[cfg(feature = "dtype-struct")]
+        if let Some((column, path)) = e.as_expression().and_then(struct_field_path) {
+            *field_stats = stats.get_field_stats(column, &path);
+            return Ok(field_stats.as_ref());
+        }
+        stats.get_stats(name).map(Some)
+    }
+
     fn apply_operator_stats_eq(min_max: &Series, literal: &Series) -> bool {
         // literal is greater than max, don't need to read
         if ChunkCompare::<&Series>::gt(literal, min_max)
@@ -379,6 +405,8 @@ mod stats {
                     Multiply | Divide | TrueDivide | FloorDivide | Modulus | NotEq
                 ),
                 Column(_) | Literal(_) | Alias(_, _) => true,
+                #[cfg(feature = "dtype-struct")]
+                Function { .. } => struct_field_path(e).is_some(),
                 _ => false,
             }) {
                 return Ok(true);
@@ -392,9 +420,9 @@ mod stats {
             {
                 match (fld_l.data_type(), fld_r.data_type()) {
                     #[cfg(feature = "dtype-categorical")]
-                    (DataType::Utf8, DataType::Categorical(_)) => {}
+                    (DataType::Utf8, DataType::Categorical(_, _)) => {}
                     #[cfg(feature = "dtype-categorical")]
-                    (DataType::Categorical(_), DataType::Utf8) => {}
+                    (DataType::Categorical(_, _), DataType::Utf8) => {}
                     (l, r) if l != r => panic!("implementation error: {l:?}, {r:?}"),
                     _ => {}
                 }
@@ -405,25 +433,48 @@ mod stats {
 
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
-                    let l = stats.get_stats(fld_l.name())?;
+                    let mut field_stats = None;
+                    let l =
+                        match get_stats(stats, self.left.as_ref(), fld_l.name(), &mut field_stats)?
+                        {
+                            Some(l) => l,
+                            None => return Ok(true),
+                        };
                     match l.to_min_max() {
                         None => Ok(true),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
                             let lit_s = self.right.evaluate(&dummy, &state).unwrap();
+                            // the max statistic could be truncated, we cannot use it
+                            if l.max_is_prefix_of_any(&lit_s) {
+                                return Ok(true);
+                            }
                             Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
                         }
                     }
                 }
                 (true, false) => {
-                    let r = stats.get_stats(fld_r.name())?;
+                    let mut field_stats = None;
+                    let r = match get_stats(
+                        stats,
+                        self.right.as_ref(),
+                        fld_r.name(),
+                        &mut field_stats,
+                    )? {
+                        Some(r) => r,
+                        None => return Ok(true),
+                    };
                     match r.to_min_max() {
                         None => Ok(true),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
                             let lit_s = self.left.evaluate(&dummy, &state).unwrap();
+                            // the max statistic could be truncated, we cannot use it
+                            if r.max_is_prefix_of_any(&lit_s) {
+                                return Ok(true);
+                            }
                             Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
                         }
                     }
@@ -49,18 +49,16 @@ impl PhysicalExpr for FilterExpr {
         let (mut ac_s, mut ac_predicate) = (ac_s?, ac_predicate?);
 
         if ac_predicate.is_aggregated() || ac_s.is_aggregated() {
-            let preds = ac_predicate.iter_groups(false);
+            // Safety:
+            // the predicates are only used to filter the group of their iteration
+            let preds = unsafe { ac_predicate.iter_groups(false) };
             let s = ac_s.aggregated();
             let ca = s.list()?;
-            let mut out = ca
-                .amortized_iter()
-                .zip(preds)
-                .map(|(opt_s, opt_pred)| match (opt_s, opt_pred) {
+            let out =
+                ca.try_zip_and_apply_amortized(preds, |opt_s, opt_pred| match (opt_s, opt_pred) {
                     (Some(s), Some(pred)) => s.as_ref().filter(pred.as_ref().bool()?).map(Some),
                     _ => Ok(None),
-                })
-                .collect::<PolarsResult<ListChunked>>()?;
-            out.rename(s.name());
+                })?;
             ac_s.with_series(out.into_series(), true, Some(&self.expr))?;
             ac_s.update_groups = WithSeriesLen;
             Ok(ac_s)
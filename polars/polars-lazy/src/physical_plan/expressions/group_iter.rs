@@ -5,7 +5,12 @@ use polars_core::series::unstable::UnstableSeries;
 use super::*;
 
 impl<'a> AggregationContext<'a> {
-    pub(super) fn iter_groups(
+    /// Iterate over the groups, as amortized `UnstableSeries`.
+    ///
+    /// # Safety
+    /// The items must not be kept beyond a single iteration, see
+    /// [`ListChunked::amortized_iter`].
+    pub(super) unsafe fn iter_groups(
         &mut self,
         keep_names: bool,
     ) -> Box<dyn Iterator<Item = Option<UnstableSeries<'_>>> + '_> {
@@ -75,6 +75,15 @@ impl PhysicalExpr for LiteralExpr {
                     InvalidOperation: "datatype `{}` is not supported as range", dt
                 ),
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(v, scale) => {
+                let DataType::Decimal(precision, _) = self.0.get_datatype() else {
+                    unreachable!()
+                };
+                Int128Chunked::full(NAME, *v, 1)
+                    .into_decimal_unchecked(precision, *scale)
+                    .into_series()
+            }
             Utf8(v) => Utf8Chunked::full(NAME, v, 1).into_series(),
             Binary(v) => BinaryChunked::full(NAME, v, 1).into_series(),
             #[cfg(feature = "dtype-datetime")]
@@ -265,11 +265,13 @@ impl<'a> AggregationContext<'a> {
                 });
             }
             _ => {
-                let groups = self
+                let list = self
                     .series()
                     .list()
-                    .expect("impl error, should be a list at this point")
-                    .amortized_iter()
+                    .expect("impl error, should be a list at this point");
+                // Safety:
+                // only the lengths of the items are kept
+                let groups = unsafe { list.amortized_iter() }
                     .map(|s| {
                         if let Some(s) = s {
                             let len = s.as_ref().len() as IdxSize;
@@ -556,6 +558,10 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         self.expr.evaluate(df, &Default::default())
     }
 
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        Some(expr_to_leaf_column_names(self.expr.as_expression()?))
+    }
+
     #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
@@ -2,6 +2,8 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use polars_core::frame::groupby::{GroupsIndicator, GroupsProxy};
+use polars_core::functions::arg_sort_by;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_sort;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use rayon::prelude::*;
@@ -65,16 +67,10 @@ impl PhysicalExpr for SortByExpr {
                 let s_sort_by = self
                     .by
                     .iter()
-                    .map(|e| {
-                        e.evaluate(df, state).map(|s| match s.dtype() {
-                            #[cfg(feature = "dtype-categorical")]
-                            DataType::Categorical(_) => s,
-                            _ => s.to_physical_repr().into_owned(),
-                        })
-                    })
+                    .map(|e| e.evaluate(df, state))
                     .collect::<PolarsResult<Vec<_>>>()?;
 
-                s_sort_by[0].arg_sort_multiple(&s_sort_by[1..], &descending)
+                arg_sort_by(&s_sort_by, &descending)
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         };
@@ -201,7 +197,7 @@ impl PhysicalExpr for SortByExpr {
                         let s = s.flat_naive();
                         match s.dtype() {
                             #[cfg(feature = "dtype-categorical")]
-                            DataType::Categorical(_) => s.into_owned(),
+                            DataType::Categorical(_, _) => s.into_owned(),
                             _ => s.to_physical_repr().into_owned(),
                         }
                     })
@@ -220,36 +216,22 @@ impl PhysicalExpr for SortByExpr {
                 );
                 let groups = ac_sort_by[0].groups();
 
+                // encode all rows once; the groups are then sorted by their encoded rows
+                let rows = _get_rows_encoded_sort(&sort_by_s, &descending, false)?.into_array();
+
                 let groups = groups
                     .par_iter()
                     .map(|indicator| {
-                        let new_idx = match indicator {
-                            GroupsIndicator::Idx((_first, idx)) => {
-                                // Safety:
-                                // Group tuples are always in bounds
-                                let groups = sort_by_s
-                                    .iter()
-                                    .map(|s| unsafe {
-                                        s.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize))
-                                    })
-                                    .collect::<Vec<_>>();
-
-                                let sorted_idx = groups[0]
-                                    .arg_sort_multiple(&groups[1..], &descending)
-                                    .unwrap();
-                                map_sorted_indices_to_group_idx(&sorted_idx, idx)
-                            }
-                            GroupsIndicator::Slice([first, len]) => {
-                                let groups = sort_by_s
-                                    .iter()
-                                    .map(|s| s.slice(first as i64, len as usize))
-                                    .collect::<Vec<_>>();
-                                let sorted_idx = groups[0]
-                                    .arg_sort_multiple(&groups[1..], &descending)
-                                    .unwrap();
-                                map_sorted_indices_to_group_slice(&sorted_idx, first)
-                            }
+                        let mut new_idx = match indicator {
+                            GroupsIndicator::Idx((_first, idx)) => idx.to_vec(),
+                            GroupsIndicator::Slice([first, len]) => (first..first + len).collect(),
                         };
+                        // Safety:
+                        // Group tuples are always in bounds
+                        new_idx.sort_by(|&a, &b| unsafe {
+                            rows.value_unchecked(a as usize)
+                                .cmp(rows.value_unchecked(b as usize))
+                        });
 
                         (new_idx[0], new_idx)
                     })
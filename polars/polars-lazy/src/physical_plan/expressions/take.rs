@@ -177,12 +177,12 @@ impl PhysicalExpr for TakeExpr {
         let s = idx.cast(&DataType::List(Box::new(IDX_DTYPE)))?;
         let idx = s.list().unwrap();
 
-        let mut taken = ac
-            .aggregated()
-            .list()
-            .unwrap()
-            .amortized_iter()
-            .zip(idx.amortized_iter())
+        let aggregated = ac.aggregated();
+        let list = aggregated.list().unwrap();
+        // Safety:
+        // the items are only used to compute the output of their iteration
+        let iter = unsafe { list.amortized_iter().zip(idx.amortized_iter()) };
+        let mut taken = iter
             .map(|(s, idx)| {
                 s.and_then(|s| {
                     idx.map(|idx| {
@@ -51,10 +51,15 @@ fn finish_as_iters<'a>(
     mut ac_falsy: AggregationContext<'a>,
     mut ac_mask: AggregationContext<'a>,
 ) -> PolarsResult<AggregationContext<'a>> {
-    let mut ca: ListChunked = ac_truthy
-        .iter_groups(false)
-        .zip(ac_falsy.iter_groups(false))
-        .zip(ac_mask.iter_groups(false))
+    // Safety:
+    // the groups are only used to compute the output of their iteration
+    let iter = unsafe {
+        ac_truthy
+            .iter_groups(false)
+            .zip(ac_falsy.iter_groups(false))
+            .zip(ac_mask.iter_groups(false))
+    };
+    let mut ca: ListChunked = iter
         .map(|((truthy, falsy), mask)| {
             match (truthy, falsy, mask) {
                 (Some(truthy), Some(falsy), Some(mask)) => Some(
@@ -1,48 +1,99 @@
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+use polars_core::mem::{self, PeakScope};
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
+use polars_plan::logical_plan::OptimizerTimings;
 
 type StartInstant = Instant;
 type EndInstant = Instant;
 
-type Nodes = Vec<String>;
+type Nodes = Vec<(Option<usize>, String)>;
 type Ticks = Vec<(StartInstant, EndInstant)>;
+type Rows = PlHashMap<usize, usize>;
+type PeakMemory = Vec<Option<usize>>;
 
 #[derive(Clone)]
 pub(super) struct NodeTimer {
     query_start: Instant,
-    data: Arc<Mutex<(Nodes, Ticks)>>,
+    execution_start: Instant,
+    data: Arc<Mutex<(Nodes, Ticks, Rows, PeakMemory)>>,
+    // measures the peak memory of the whole execution, if memory is tracked
+    memory: Option<Arc<PeakScope>>,
 }
 
 impl NodeTimer {
-    pub(super) fn new() -> Self {
+    /// The query, including its optimization, started at `query_start`.
+    pub(super) fn new(query_start: Instant, optimizer_timings: OptimizerTimings) -> Self {
+        let mut nodes = Vec::with_capacity(16);
+        let mut ticks = Vec::with_capacity(16);
+        // the physical plan is created at this point, so the optimization is finished
+        let execution_start = Instant::now();
+        nodes.push((None, "optimization".to_string()));
+        ticks.push((query_start, execution_start));
+        for (pass, start, end) in optimizer_timings {
+            nodes.push((None, format!("optimization: {pass}")));
+            ticks.push((start, end));
+        }
+        let peak_memory = vec![None; nodes.len()];
+
         Self {
-            query_start: Instant::now(),
-            data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+            query_start,
+            execution_start,
+            data: Arc::new(Mutex::new((nodes, ticks, Default::default(), peak_memory))),
+            memory: mem::is_tracking().then(|| Arc::new(PeakScope::new())),
         }
     }
 
-    pub(super) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
+    pub(super) fn store(
+        &self,
+        start: StartInstant,
+        end: EndInstant,
+        node_id: Option<usize>,
+        name: String,
+        peak_memory: Option<usize>,
+    ) {
         let mut data = self.data.lock().unwrap();
         let nodes = &mut data.0;
-        nodes.push(name);
+        nodes.push((node_id, name));
         let ticks = &mut data.1;
-        ticks.push((start, end))
+        ticks.push((start, end));
+        data.3.push(peak_memory)
+    }
+
+    pub(super) fn store_rows(&self, node_id: usize, rows: usize) {
+        let mut data = self.data.lock().unwrap();
+        // a node that runs multiple times, e.g. in a partitioned groupby, adds up its rows
+        *data.2.entry(node_id).or_insert(0) += rows;
     }
 
     pub(super) fn finish(self) -> PolarsResult<DataFrame> {
         let mut data = self.data.lock().unwrap();
-        let mut nodes = std::mem::take(&mut data.0);
-        nodes.push("optimization".to_string());
-
-        let mut ticks = std::mem::take(&mut data.1);
-        // first value is end of optimization
-        polars_ensure!(!ticks.is_empty(), ComputeError: "no data to time");
-        let start = ticks[0].0;
-        ticks.push((self.query_start, start));
-        let nodes_s = Series::new("node", nodes);
+        let (nodes, ticks, node_rows, peak_memory) = &mut *data;
+        let mut nodes = std::mem::take(nodes);
+        let mut ticks = std::mem::take(ticks);
+        let mut peak_memory = std::mem::take(peak_memory);
+
+        // the execution of the whole query
+        nodes.push((None, "query".to_string()));
+        ticks.push((self.execution_start, Instant::now()));
+        peak_memory.push(self.memory.as_ref().map(|scope| scope.peak_increase()));
+
+        let mut node_ids: UInt32Chunked = nodes
+            .iter()
+            .map(|(node_id, _)| node_id.map(|id| id as u32))
+            .collect();
+        node_ids.rename("node_id");
+        let mut rows: UInt64Chunked = nodes
+            .iter()
+            .map(|(node_id, _)| node_id.and_then(|id| node_rows.get(&id).map(|rows| *rows as u64)))
+            .collect();
+        rows.rename("rows");
+        let nodes_s = Series::new(
+            "node",
+            nodes.into_iter().map(|(_, name)| name).collect::<Vec<_>>(),
+        );
         let start: NoNull<UInt64Chunked> = ticks
             .iter()
             .map(|(start, _)| (start.duration_since(self.query_start)).as_micros() as u64)
@@ -57,7 +108,20 @@ impl NodeTimer {
         let mut end = end.into_inner();
         end.rename("end");
 
-        DataFrame::new_no_checks(vec![nodes_s, start.into_series(), end.into_series()])
-            .sort(vec!["start"], vec![false])
+        let mut peak_memory: UInt64Chunked = peak_memory
+            .into_iter()
+            .map(|peak| peak.map(|peak| peak as u64))
+            .collect();
+        peak_memory.rename("peak_memory");
+
+        DataFrame::new_no_checks(vec![
+            node_ids.into_series(),
+            nodes_s,
+            start.into_series(),
+            end.into_series(),
+            rows.into_series(),
+            peak_memory.into_series(),
+        ])
+        .sort(vec!["start"], vec![false])
     }
 }
@@ -1,4 +1,5 @@
 use polars_core::prelude::*;
+use polars_ops::frame::JoinArgs;
 
 use super::super::executors::{self, Executor};
 use super::*;
@@ -142,6 +143,65 @@ pub fn create_physical_plan(
     root: Node,
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Box<dyn Executor>> {
+    create_physical_plan_impl(root, lp_arena, expr_arena, None)
+}
+
+/// Give every node of the plan an id in pre-order, the inputs of a node in the order of
+/// [`ALogicalPlan::copy_inputs`]. These are the ids that the optimized plan is formatted with.
+fn node_ids(root: Node, lp_arena: &Arena<ALogicalPlan>) -> PlHashMap<Node, usize> {
+    let mut ids = PlHashMap::new();
+    let mut stack = vec![root];
+    let mut inputs = vec![];
+    let mut id = 0;
+    while let Some(node) = stack.pop() {
+        // a node that is reachable from multiple parents keeps its first id
+        ids.entry(node).or_insert(id);
+        id += 1;
+        lp_arena.get(node).copy_inputs(&mut inputs);
+        // reversed, so that the first input is popped first
+        stack.extend(inputs.drain(..).rev());
+    }
+    ids
+}
+
+/// Create a physical plan of which every executor records the id of its node, so that the
+/// profile can be related to the optimized plan.
+pub(crate) fn create_profiled_physical_plan(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let node_ids = node_ids(root, lp_arena);
+    create_physical_plan_impl(root, lp_arena, expr_arena, Some(&node_ids))
+}
+
+fn create_physical_plan_impl(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    node_ids: Option<&PlHashMap<Node, usize>>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let name = lp_arena.get(root).name();
+    let executor = create_executor(root, lp_arena, expr_arena, node_ids)?;
+    let executor: Box<dyn Executor> = Box::new(executors::PlanNodeExec {
+        input: executor,
+        name,
+    });
+    Ok(match node_ids.and_then(|ids| ids.get(&root)) {
+        Some(&id) => Box::new(executors::ProfiledExec {
+            input: executor,
+            node_id: id,
+        }),
+        None => executor,
+    })
+}
+
+fn create_executor(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    node_ids: Option<&PlHashMap<Node, usize>>,
 ) -> PolarsResult<Box<dyn Executor>> {
     use ALogicalPlan::*;
 
@@ -155,16 +215,16 @@ pub fn create_physical_plan(
         Union { inputs, options } => {
             let inputs = inputs
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| create_physical_plan_impl(node, lp_arena, expr_arena, node_ids))
                 .collect::<PolarsResult<Vec<_>>>()?;
             Ok(Box::new(executors::UnionExec { inputs, options }))
         }
         Slice { input, offset, len } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             Ok(Box::new(executors::SliceExec { input, offset, len }))
         }
         Selection { input, predicate } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             let predicate = create_physical_expr(predicate, Context::Default, expr_arena, None)?;
             Ok(Box::new(executors::FilterExec::new(predicate, input)))
         }
@@ -227,6 +287,7 @@ pub fn create_physical_plan(
             Ok(Box::new(executors::ParquetExec::new(
                 path,
                 file_info.schema,
+                output_schema,
                 predicate,
                 options,
                 cloud_options,
@@ -240,7 +301,7 @@ pub fn create_physical_plan(
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let has_windows = expr.iter().any(|node| has_aexpr_window(*node, expr_arena));
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             let phys_expr = create_physical_expressions(
                 &expr,
                 Context::Default,
@@ -265,7 +326,7 @@ pub fn create_physical_plan(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
 
             let has_windows = expr.iter().any(|node| has_aexpr_window(*node, expr_arena));
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             let phys_expr = create_physical_expressions(
                 &expr,
                 Context::Default,
@@ -327,7 +388,7 @@ pub fn create_physical_plan(
                 expr_arena,
                 Some(input_schema.as_ref()),
             )?;
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             Ok(Box::new(executors::SortExec {
                 input,
                 by_column,
@@ -335,11 +396,11 @@ pub fn create_physical_plan(
             }))
         }
         Cache { input, id, count } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             Ok(Box::new(executors::CacheExec { id, input, count }))
         }
         Distinct { input, options } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             Ok(Box::new(executors::UniqueExec { input, options }))
         }
         Aggregate {
@@ -368,7 +429,7 @@ pub fn create_physical_plan(
             let _slice = options.slice;
             #[cfg(feature = "dynamic_groupby")]
             if let Some(options) = options.dynamic {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
                 return Ok(Box::new(executors::GroupByDynamicExec {
                     input,
                     keys: phys_keys,
@@ -381,7 +442,7 @@ pub fn create_physical_plan(
 
             #[cfg(feature = "dynamic_groupby")]
             if let Some(options) = options.rolling {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
                 return Ok(Box::new(executors::GroupByRollingExec {
                     input,
                     keys: phys_keys,
@@ -402,7 +463,7 @@ pub fn create_physical_plan(
                         false
                     }
                 });
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
                 let keys = keys
                     .iter()
                     .map(|node| node_to_expr(*node, expr_arena))
@@ -424,7 +485,7 @@ pub fn create_physical_plan(
                     aggs,
                 )))
             } else {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
                 Ok(Box::new(executors::GroupByExec::new(
                     input,
                     phys_keys,
@@ -459,27 +520,32 @@ pub fn create_physical_plan(
                 false
             };
 
-            let input_left = create_physical_plan(input_left, lp_arena, expr_arena)?;
-            let input_right = create_physical_plan(input_right, lp_arena, expr_arena)?;
+            let input_left = create_physical_plan_impl(input_left, lp_arena, expr_arena, node_ids)?;
+            let input_right =
+                create_physical_plan_impl(input_right, lp_arena, expr_arena, node_ids)?;
             let left_on =
                 create_physical_expressions(&left_on, Context::Default, expr_arena, None)?;
             let right_on =
                 create_physical_expressions(&right_on, Context::Default, expr_arena, None)?;
+            let args = JoinArgs {
+                how: options.how,
+                suffix: Some(options.suffix.into_owned()),
+                slice: options.slice,
+                build_side: options.build_side,
+            };
             Ok(Box::new(executors::JoinExec::new(
                 input_left,
                 input_right,
-                options.how,
                 left_on,
                 right_on,
                 parallel,
-                options.suffix,
-                options.slice,
+                args,
             )))
         }
         HStack { input, exprs, .. } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let has_windows = exprs.iter().any(|node| has_aexpr_window(*node, expr_arena));
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             let phys_expr = create_physical_expressions(
                 &exprs,
                 Context::Default,
@@ -496,16 +562,16 @@ pub fn create_physical_plan(
         MapFunction {
             input, function, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             Ok(Box::new(executors::UdfExec { input, function }))
         }
         ExtContext {
             input, contexts, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, node_ids)?;
             let contexts = contexts
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| create_physical_plan_impl(node, lp_arena, expr_arena, node_ids))
                 .collect::<PolarsResult<_>>()?;
             Ok(Box::new(executors::ExternalContext { input, contexts }))
         }
@@ -1,15 +1,18 @@
 use std::borrow::Cow;
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::{Mutex, RwLock};
+use std::time::Instant;
 
 use bitflags::bitflags;
 use once_cell::sync::OnceCell;
 use polars_core::config::verbose;
 use polars_core::frame::groupby::GroupsProxy;
 use polars_core::frame::hash_join::JoinOptIds;
+use polars_core::mem;
 use polars_core::prelude::*;
 #[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
 use polars_plan::logical_plan::FileFingerPrint;
+use polars_plan::logical_plan::OptimizerTimings;
 
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use super::file_cache::FileCache;
@@ -78,12 +81,16 @@ pub struct ExecutionState {
     pub(super) flags: AtomicU8,
     pub(super) ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
+    /// The id of the node that is being executed, only set when profiling.
+    pub(super) node_id: Option<usize>,
+    /// Soft limit on the memory usage of the query, see [`polars_core::mem`].
+    memory_limit: Option<Arc<mem::MemoryLimit>>,
 }
 
 impl ExecutionState {
     /// Toggle this to measure execution times.
-    pub(crate) fn time_nodes(&mut self) {
-        self.node_timer = Some(NodeTimer::new())
+    pub(crate) fn time_nodes(&mut self, query_start: Instant, optimizer_timings: OptimizerTimings) {
+        self.node_timer = Some(NodeTimer::new(query_start, optimizer_timings))
     }
     pub(super) fn has_node_timer(&self) -> bool {
         self.node_timer.is_some()
@@ -97,16 +104,24 @@ impl ExecutionState {
         match &self.node_timer {
             None => func(),
             Some(timer) => {
+                let memory = mem::is_tracking().then(mem::PeakScope::new);
                 let start = std::time::Instant::now();
                 let out = func();
                 let end = std::time::Instant::now();
 
-                timer.store(start, end, name.as_ref().to_string());
+                let peak = memory.map(|scope| scope.peak_increase());
+                timer.store(start, end, self.node_id, name.as_ref().to_string(), peak);
                 out
             }
         }
     }
 
+    pub(super) fn record_rows(&self, node_id: usize, rows: usize) {
+        if let Some(timer) = &self.node_timer {
+            timer.store_rows(node_id, rows)
+        }
+    }
+
     /// Partially clones and partially clears state
     pub(super) fn split(&self) -> Self {
         Self {
@@ -120,6 +135,8 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            node_id: self.node_id,
+            memory_limit: self.memory_limit.clone(),
         }
     }
 
@@ -136,6 +153,8 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            node_id: self.node_id,
+            memory_limit: self.memory_limit.clone(),
         }
     }
 
@@ -156,6 +175,8 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            node_id: None,
+            memory_limit: None,
         }
     }
 
@@ -175,8 +196,33 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            node_id: None,
+            memory_limit: None,
         }
     }
+
+    /// Abort the query with a `ResourceExhausted` error when it uses more memory than `limit`
+    /// after a node of the plan or a step of a long-running operator has executed. Streaming
+    /// sinks that can go out-of-core start to spill to disk once the limit is exceeded.
+    ///
+    /// The usage is measured from the creation of the [`mem::MemoryLimit`], which requires
+    /// the [`mem::TrackingAllocator`] to be installed.
+    pub fn set_memory_limit(&mut self, limit: Option<Arc<mem::MemoryLimit>>) {
+        self.memory_limit = limit;
+    }
+
+    /// The memory limit of the query, see [`ExecutionState::set_memory_limit`].
+    pub fn memory_limit(&self) -> Option<&Arc<mem::MemoryLimit>> {
+        self.memory_limit.as_ref()
+    }
+
+    pub(crate) fn check_memory_limit(&self) -> PolarsResult<()> {
+        match &self.memory_limit {
+            Some(limit) => limit.check(),
+            None => Ok(()),
+        }
+    }
+
     pub(crate) fn set_schema(&self, schema: SchemaRef) {
         let mut lock = self.schema_cache.write().unwrap();
         *lock = Some(schema);
@@ -194,6 +240,11 @@ impl ExecutionState {
         lock.clone()
     }
 
+    /// Use the dataframe cache of `other`, so that both states execute a cached plan once.
+    pub(crate) fn share_df_cache(&mut self, other: &ExecutionState) {
+        self.df_cache = other.df_cache.clone();
+    }
+
     pub(crate) fn get_df_cache(&self, key: usize) -> Arc<OnceCell<DataFrame>> {
         let mut guard = self.df_cache.lock().unwrap();
         guard
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use polars_core::config::verbose;
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
+use polars_core::mem::MemoryLimit;
 use polars_core::prelude::*;
 use polars_core::schema::Schema;
 use polars_pipe::expressions::PhysicalPipedExpr;
@@ -116,6 +117,29 @@ fn streamable_join(join_type: &JoinType) -> bool {
     }
 }
 
+/// Every input of a union becomes its own source in the pipeline,
+/// so we can mix scans of different file types.
+fn is_streamable_union_input(node: Node, lp_arena: &Arena<ALogicalPlan>) -> bool {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        #[cfg(feature = "csv")]
+        CsvScan { .. } => true,
+        #[cfg(feature = "parquet")]
+        ParquetScan { .. } => true,
+        DataFrameScan { .. } => true,
+        AnonymousScan { function, .. } => function.allows_streaming(),
+        // rechunks are ignored, but only a single one is unwrapped
+        MapFunction {
+            input,
+            function: FunctionNode::Rechunk,
+        } => {
+            !matches!(lp_arena.get(*input), MapFunction { .. })
+                && is_streamable_union_input(*input, lp_arena)
+        }
+        _ => false,
+    }
+}
+
 // The index of the pipeline tree we are building at this moment
 // if we have a node we cannot do streaming, we have finished that pipeline tree
 // and start a new one.
@@ -146,7 +170,84 @@ pub(crate) fn insert_streaming_nodes(
     expr_arena: &mut Arena<AExpr>,
     scratch: &mut Vec<Node>,
     fmt: bool,
+    chunk_size: Option<usize>,
+    sort_memory_budget: Option<usize>,
+    memory_limit: Option<Arc<MemoryLimit>>,
 ) -> PolarsResult<bool> {
+    let pipelines = create_pipelines(
+        root,
+        lp_arena,
+        expr_arena,
+        scratch,
+        chunk_size,
+        sort_memory_budget,
+    )?;
+    let inserted = !pipelines.is_empty();
+
+    for (latest, pipeline) in pipelines {
+        // the most right latest node should be the root of the pipeline
+        let schema = lp_arena.get(latest).schema(lp_arena).into_owned();
+
+        // keep the original around for formatting purposes
+        let original_lp = if fmt {
+            let original_lp = lp_arena.take(latest);
+            let original_node = lp_arena.add(original_lp);
+            let original_lp = node_to_lp_cloned(original_node, expr_arena, lp_arena);
+            Some(original_lp)
+        } else {
+            None
+        };
+
+        // replace the part of the logical plan with a `MapFunction` that will execute the pipeline.
+        let pipeline_node = get_pipeline_node(
+            lp_arena,
+            pipeline,
+            schema,
+            original_lp,
+            chunk_size,
+            memory_limit.clone(),
+        );
+        lp_arena.replace(latest, pipeline_node);
+    }
+    Ok(inserted)
+}
+
+/// Create the pipeline of a query that must run completely on the streaming engine.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub(crate) fn create_streaming_pipeline(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    chunk_size: Option<usize>,
+    sort_memory_budget: Option<usize>,
+) -> PolarsResult<PipeLine> {
+    let mut pipelines = create_pipelines(
+        root,
+        lp_arena,
+        expr_arena,
+        &mut vec![],
+        chunk_size,
+        sort_memory_budget,
+    )?;
+    match pipelines.pop() {
+        Some((latest, pipeline)) if latest == root && pipelines.is_empty() => Ok(pipeline),
+        _ => polars_bail!(
+            ComputeError: "cannot run the whole query in a streaming order; \
+            use `collect()` and write the result instead"
+        ),
+    }
+}
+
+/// Create the pipelines of the streamable parts of the query.
+/// Returns the pipelines together with the node they should replace.
+fn create_pipelines(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    scratch: &mut Vec<Node>,
+    chunk_size: Option<usize>,
+    sort_memory_budget: Option<usize>,
+) -> PolarsResult<Vec<(Node, PipeLine)>> {
     // this is needed to determine which side of the joins should be
     // traversed first
     set_estimated_row_counts(root, lp_arena, expr_arena, 0);
@@ -260,6 +361,12 @@ pub(crate) fn insert_streaming_nodes(
                     pipeline_trees[current_idx].push(state)
                 }
             }
+            AnonymousScan { function, .. } if function.allows_streaming() => {
+                if state.streamable {
+                    state.sources.push(root);
+                    pipeline_trees[current_idx].push(state)
+                }
+            }
             Join {
                 input_left,
                 input_right,
@@ -304,20 +411,11 @@ pub(crate) fn insert_streaming_nodes(
                 stack.push((input_left, state_left, current_idx));
             }
             // add globbing patterns
-            #[cfg(all(feature = "csv", feature = "parquet"))]
             Union { inputs, .. } => {
                 if state.streamable
-                    && inputs.iter().all(|node| match lp_arena.get(*node) {
-                        ParquetScan { .. } => true,
-                        CsvScan { .. } => true,
-                        MapFunction {
-                            input,
-                            function: FunctionNode::Rechunk,
-                        } => {
-                            matches!(lp_arena.get(*input), ParquetScan { .. } | CsvScan { .. })
-                        }
-                        _ => false,
-                    })
+                    && inputs
+                        .iter()
+                        .all(|node| is_streamable_union_input(*node, lp_arena))
                 {
                     state.sources.push(root);
                     pipeline_trees[current_idx].push(state);
@@ -352,7 +450,7 @@ pub(crate) fn insert_streaming_nodes(
                         #[cfg(feature = "object")]
                         DataType::Object(_) => false,
                         #[cfg(feature = "dtype-categorical")]
-                        DataType::Categorical(_) => string_cache,
+                        DataType::Categorical(_, _) => string_cache,
                         _ => true,
                     }
                 }
@@ -396,7 +494,7 @@ pub(crate) fn insert_streaming_nodes(
             ),
         }
     }
-    let mut inserted = false;
+    let mut out = vec![];
     for tree in pipeline_trees {
         if is_valid_tree(&tree) {
             let mut pipelines = VecDeque::with_capacity(tree.len());
@@ -478,6 +576,8 @@ pub(crate) fn insert_streaming_nodes(
                     lp_arena,
                     expr_arena,
                     to_physical_piped_expr,
+                    chunk_size,
+                    sort_memory_budget,
                     is_verbose,
                 )?;
                 pipelines.push_back(pipeline);
@@ -485,27 +585,11 @@ pub(crate) fn insert_streaming_nodes(
             // some queries only have source/sources and don't have any
             // operators/sink so no latest
             if let Some(latest) = latest {
-                // the most right latest node should be the root of the pipeline
-                let schema = lp_arena.get(latest).schema(lp_arena).into_owned();
-
                 if let Some(mut most_left) = pipelines.pop_front() {
                     while let Some(rhs) = pipelines.pop_front() {
                         most_left = most_left.with_rhs(rhs)
                     }
-                    // keep the original around for formatting purposes
-                    let original_lp = if fmt {
-                        let original_lp = lp_arena.take(latest);
-                        let original_node = lp_arena.add(original_lp);
-                        let original_lp = node_to_lp_cloned(original_node, expr_arena, lp_arena);
-                        Some(original_lp)
-                    } else {
-                        None
-                    };
-
-                    // replace the part of the logical plan with a `MapFunction` that will execute the pipeline.
-                    let pipeline_node = get_pipeline_node(lp_arena, most_left, schema, original_lp);
-                    lp_arena.replace(latest, pipeline_node);
-                    inserted = true;
+                    out.push((latest, most_left));
                 } else {
                     panic!()
                 }
@@ -513,13 +597,17 @@ pub(crate) fn insert_streaming_nodes(
         }
     }
 
-    Ok(inserted)
+    Ok(out)
 }
 
 impl SExecutionContext for ExecutionState {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn memory_limit(&self) -> Option<&MemoryLimit> {
+        ExecutionState::memory_limit(self).map(|limit| limit.as_ref())
+    }
 }
 
 fn get_pipeline_node(
@@ -527,6 +615,8 @@ fn get_pipeline_node(
     mut pipeline: PipeLine,
     schema: SchemaRef,
     original_lp: Option<LogicalPlan>,
+    chunk_size: Option<usize>,
+    memory_limit: Option<Arc<MemoryLimit>>,
 ) -> ALogicalPlan {
     // create a dummy input as the map function will call the input
     // so we just create a scan that returns an empty df
@@ -541,7 +631,8 @@ fn get_pipeline_node(
     ALogicalPlan::MapFunction {
         function: FunctionNode::Pipeline {
             function: Arc::new(move |_df: DataFrame| {
-                let state = ExecutionState::new();
+                let mut state = ExecutionState::new();
+                state.set_memory_limit(memory_limit.clone());
                 if state.verbose() {
                     eprintln!("RUN STREAMING PIPELINE")
                 }
@@ -550,6 +641,7 @@ fn get_pipeline_node(
             }),
             schema,
             original: original_lp.map(Arc::new),
+            chunk_size,
         },
         input: dummy,
     }
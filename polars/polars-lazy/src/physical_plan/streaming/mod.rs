@@ -1,6 +1,8 @@
 mod convert;
 mod tree;
 
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub(crate) use convert::create_streaming_pipeline;
 pub(crate) use convert::insert_streaming_nodes;
 
 type IsSink = bool;
@@ -1,12 +1,19 @@
 pub(crate) use polars_ops::prelude::*;
+#[cfg(feature = "dtype-decimal")]
+pub use polars_plan::logical_plan::Decimal;
 pub use polars_plan::logical_plan::{
-    AnonymousScan, AnonymousScanOptions, Literal, LiteralValue, LogicalPlan, Null, NULL,
+    AnonymousScan, AnonymousScanOptions, BatchedAnonymousScan, Literal, LiteralValue, LogicalPlan,
+    Null, NULL,
 };
 #[cfg(feature = "ipc")]
 pub use polars_plan::prelude::IpcWriterOptions;
-#[cfg(feature = "parquet")]
-pub use polars_plan::prelude::ParquetWriteOptions;
+#[cfg(feature = "ipc_streaming")]
+pub use polars_plan::prelude::SharedWriter;
 pub(crate) use polars_plan::prelude::*;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub use polars_plan::prelude::{FileSinkOptions, FileType};
+#[cfg(feature = "parquet")]
+pub use polars_plan::prelude::{ParquetPartitionOptions, ParquetWriteOptions};
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
 #[cfg(feature = "dynamic_groupby")]
@@ -35,6 +35,78 @@ fn test_pearson_corr() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_cov_corr_null_pairs() -> PolarsResult<()> {
+    use crate::dsl::cov;
+
+    let df = df! {
+        "g" => [1, 1, 1, 1, 2, 2, 2, 2],
+        "a" => [Some(1.0f64), Some(2.0), Some(4.0), Some(3.5), None, Some(7.25), Some(6.0), Some(2.5)],
+        "b" => [Some(2.0f64), None, Some(1.5), Some(3.0), Some(9.0), Some(8.5), Some(6.25), Some(3.0)],
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            cov(col("a"), col("b"), 1).alias("cov"),
+            cov(col("a"), col("b"), 0).alias("cov_0"),
+            pearson_corr(col("a"), col("b"), 1).alias("pearson"),
+        ])
+        .collect()?;
+    let get = |name: &str| out.column(name).unwrap().f64().unwrap().get(0).unwrap();
+    assert!((get("cov") - 5.4229166666666675).abs() < 1e-12);
+    assert!((get("cov_0") - 4.519097222222222).abs() < 1e-12);
+    assert!((get("pearson") - 0.866891386354285).abs() < 1e-12);
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            cov(col("a"), col("b"), 1).alias("cov"),
+            pearson_corr(col("a"), col("b"), 1).alias("pearson"),
+        ])
+        .collect()?;
+    let cov = out.column("cov")?.f64()?;
+    assert!((cov.get(0).unwrap() - 0.04166666666666671).abs() < 1e-12);
+    assert!((cov.get(1).unwrap() - 6.71875).abs() < 1e-12);
+    let pearson = out.column("pearson")?.f64()?;
+    assert!((pearson.get(0).unwrap() - 0.033942211665106574).abs() < 1e-12);
+    assert!((pearson.get(1).unwrap() - 0.9868478342661193).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "rank", feature = "propagate_nans"))]
+fn test_spearman_rank_corr() -> PolarsResult<()> {
+    use crate::dsl::spearman_rank_corr;
+
+    let df = df! {
+        "g" => [1, 1, 1, 1, 2, 2, 2, 2],
+        "a" => [Some(1.0f64), Some(2.0), Some(4.0), Some(3.5), None, Some(7.25), Some(6.0), Some(2.5)],
+        "b" => [Some(2.0f64), None, Some(1.5), Some(3.0), Some(9.0), Some(8.5), Some(6.25), Some(3.0)],
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([spearman_rank_corr(col("a"), col("b"), 1, false).alias("spearman")])
+        .collect()?;
+    let spearman = out.column("spearman")?.f64()?.get(0).unwrap();
+    // the tied values of `b` get their average rank
+    assert!((spearman - 0.6377481392176934).abs() < 1e-12);
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([spearman_rank_corr(col("a"), col("b"), 1, false).alias("spearman")])
+        .collect()?;
+    let spearman = out.column("spearman")?.f64()?;
+    assert!((spearman.get(0).unwrap() + 0.5).abs() < 1e-12);
+    assert!((spearman.get(1).unwrap() - 1.0).abs() < 1e-12);
+    Ok(())
+}
+
 // TODO! fix this we must get a token that prevents resetting the string cache until the plan has
 // finished running. We cannot store a mutexguard in the executionstate because they don't implement
 // send.
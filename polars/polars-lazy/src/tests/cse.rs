@@ -284,3 +284,36 @@ fn test_cse_columns_projections() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_collect_all() -> PolarsResult<()> {
+    let (scan, n_scans) = counting_scan(fruits_cars());
+    let scan = |cse: bool| {
+        scan.clone()
+            .filter(col("A").gt(lit(1)))
+            .with_common_subplan_elimination(cse)
+    };
+
+    let lf = scan(true);
+    let lf1 = lf.clone().select([col("A").sum()]);
+    let lf2 = lf.groupby_stable([col("fruits")]).agg([col("B").sum()]);
+
+    let plans = describe_optimized_plans(&[lf1.clone(), lf2.clone()])?;
+    assert!(plans.iter().all(|plan| plan.contains("CACHE")));
+
+    let out = collect_all(vec![lf1, lf2])?;
+    assert_eq!(n_scans.load(Ordering::Relaxed), 1);
+    assert_eq!(out[0].column("A")?.get(0)?, AnyValue::Int32(14));
+    assert_eq!(out[1].shape(), (2, 2));
+
+    // turning off cse scans once per plan
+    let lf = scan(false);
+    let lf1 = lf.clone().select([col("A").sum()]);
+    let lf2 = lf.groupby_stable([col("fruits")]).agg([col("B").sum()]);
+    let plans = describe_optimized_plans(&[lf1.clone(), lf2.clone()])?;
+    assert!(plans.iter().all(|plan| !plan.contains("CACHE")));
+    collect_all(vec![lf1, lf2])?;
+    assert_eq!(n_scans.load(Ordering::Relaxed), 3);
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use polars_core::frame::explode::MeltArgs;
+use polars_io::parquet::ParquetReadStats;
 use polars_io::RowCount;
 
 use super::*;
@@ -142,6 +144,537 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "is_in")]
+fn test_parquet_statistics_utf8() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_statistics_utf8.parquet");
+    let mut df = df![
+        "id" => (0..100).map(|i| format!("id_{i:03}")).collect::<Vec<_>>(),
+        "maybe" => (0..100).map(|i| (i >= 50).then_some(i)).collect::<Vec<_>>(),
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_statistics(true)
+        .with_row_group_size(Some(10))
+        .finish(&mut df)?;
+
+    let count_decoded = |predicate: Expr| -> PolarsResult<(usize, usize)> {
+        let stats = Arc::new(ParquetReadStats::default());
+        let args = ScanArgsParquet {
+            parallel: ParallelStrategy::None,
+            read_stats: Some(stats.clone()),
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_parquet(&path, args)?
+            .filter(predicate)
+            .collect()?;
+        Ok((out.height(), stats.row_groups_decoded()))
+    };
+
+    assert_eq!(count_decoded(col("id").eq(lit("id_042")))?, (1, 1));
+    assert_eq!(count_decoded(lit("id_042").eq(col("id")))?, (1, 1));
+    assert_eq!(count_decoded(col("id").gt_eq(lit("id_095")))?, (5, 1));
+    assert_eq!(count_decoded(col("id").lt(lit("id_010")))?, (10, 1));
+    assert_eq!(count_decoded(col("id").eq(lit("other")))?, (0, 0));
+    let set = Series::new("", ["id_042", "id_045", "other"]);
+    assert_eq!(count_decoded(col("id").is_in(lit(set)))?, (2, 1));
+    assert_eq!(count_decoded(col("maybe").is_not_null())?, (50, 5));
+    assert_eq!(count_decoded(col("maybe").is_null())?, (50, 5));
+
+    // the max could be a truncated prefix of these values, so the row group must be read
+    assert_eq!(count_decoded(col("id").eq(lit("id_0490")))?, (0, 1));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_parquet_prefiltered() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_prefiltered.parquet");
+    let mut df = df![
+        "id" => (0..1000).collect::<Vec<i64>>(),
+        "value" => (0..1000).map(|i| i as f64 * 0.5).collect::<Vec<_>>(),
+        "name" => (0..1000).map(|i| format!("name_{i}")).collect::<Vec<_>>(),
+    ]?;
+    // a single row group with many small pages
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_data_pagesize_limit(Some(1024))
+        .finish(&mut df)?;
+
+    let stats = Arc::new(ParquetReadStats::default());
+    let read = |parallel: ParallelStrategy| -> PolarsResult<DataFrame> {
+        let args = ScanArgsParquet {
+            parallel,
+            read_stats: Some(stats.clone()),
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(&path, args)?
+            .filter(col("id").lt(lit(100i64)))
+            .collect()
+    };
+    let expected = read(ParallelStrategy::Columns)?;
+
+    assert_eq!(stats.prefilter_stats().rows_skipped, 0);
+    let out = read(ParallelStrategy::Prefiltered)?;
+    let prefiltered = stats.prefilter_stats();
+    assert!(out.frame_equal(&expected));
+    assert_eq!(out.height(), 100);
+    assert_eq!(prefiltered.rows_skipped, 900);
+    assert!(prefiltered.pages_skipped > 0);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+/// The columns of the parquet file that are read by the query.
+fn parquet_scan_columns(q: LazyFrame) -> Vec<String> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    (&lp_arena)
+        .iter(lp)
+        .find_map(|(_, lp)| match lp {
+            ALogicalPlan::ParquetScan { output_schema, .. } => Some(
+                output_schema
+                    .as_ref()
+                    .unwrap()
+                    .iter_names()
+                    .map(|name| name.to_string())
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+#[cfg(all(feature = "ipc", not(target_os = "windows")))]
+fn test_count_star_from_metadata() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let is_fast_count = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        matches!(
+            lp_arena.get(lp),
+            ALogicalPlan::MapFunction {
+                function: FunctionNode::Count { .. },
+                ..
+            }
+        )
+    };
+
+    // the plan has no scan, so no row group is decoded
+    let stats = Arc::new(ParquetReadStats::default());
+    let args = ScanArgsParquet {
+        read_stats: Some(stats.clone()),
+        ..Default::default()
+    };
+    let q = LazyFrame::scan_parquet(GLOB_PARQUET, args)?.select([count()]);
+    assert!(is_fast_count(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(out.column("count")?.idx()?.get(0), Some(54));
+    assert_eq!(stats.row_groups_decoded(), 0);
+
+    let q = LazyFrame::scan_ipc(GLOB_IPC, Default::default())?.select([count().alias("n")]);
+    assert!(is_fast_count(q.clone()));
+    assert_eq!(q.collect()?.column("n")?.idx()?.get(0), Some(54));
+
+    // csv doesn't have the metadata
+    let q = LazyCsvReader::new(FOODS_CSV).finish()?.select([count()]);
+    assert!(!is_fast_count(q.clone()));
+    assert_eq!(q.collect()?.column("count")?.idx()?.get(0), Some(27));
+
+    // with a filter the data is read, but only the columns of the predicate
+    let q = LazyFrame::scan_parquet(FOODS_PARQUET, Default::default())?
+        .filter(col("category").eq(lit("seafood")))
+        .select([count()]);
+    assert!(!is_fast_count(q.clone()));
+    assert_eq!(parquet_scan_columns(q.clone()), ["category"]);
+    assert_eq!(q.collect()?.column("count")?.idx()?.get(0), Some(8));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_projection_pushdown_melt_explode() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_projection_melt_explode.parquet");
+    let mut df = df![
+        "id" => [1, 2, 3],
+        "a" => [1.0, 2.0, 3.0],
+        "b" => [4.0, 5.0, 6.0],
+        "c" => ["x", "y", "z"],
+        "d" => [7, 8, 9],
+    ]?;
+    df.with_column(Series::new(
+        "l",
+        [
+            Series::new("", [1, 2]),
+            Series::new("", [3]),
+            Series::new("", [4, 5, 6]),
+        ],
+    ))?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+    let scan = || LazyFrame::scan_parquet(&path, ScanArgsParquet::default()).unwrap();
+
+    // only the value_vars are read if the id_vars are not used
+    let melt = |value_vars: &[&str]| {
+        scan().melt(MeltArgs {
+            id_vars: vec!["id".into(), "c".into()],
+            value_vars: value_vars.iter().map(|name| (*name).into()).collect(),
+            ..Default::default()
+        })
+    };
+    let q = melt(&["a", "b"]).select([col("variable"), col("value")]);
+    assert_eq!(parquet_scan_columns(q.clone()), ["a", "b"]);
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), ["variable", "value"]);
+    assert_eq!(out.height(), 6);
+    assert_eq!(
+        Vec::from(out.column("value")?.f64()?),
+        [
+            Some(1.0),
+            Some(2.0),
+            Some(3.0),
+            Some(4.0),
+            Some(5.0),
+            Some(6.0)
+        ]
+    );
+
+    // used id_vars are read
+    let q = melt(&["a"]).select([col("id"), col("value")]);
+    assert_eq!(parquet_scan_columns(q.clone()), ["id", "a"]);
+    assert_eq!(q.collect()?.shape(), (3, 2));
+
+    // without value_vars all columns but the id_vars are melted
+    let q = scan()
+        .drop_columns(["l"])
+        .melt(MeltArgs {
+            id_vars: vec!["id".into()],
+            ..Default::default()
+        })
+        .select([col("variable")]);
+    assert_eq!(parquet_scan_columns(q.clone()), ["a", "b", "c", "d"]);
+    assert_eq!(q.collect()?.height(), 12);
+
+    // the exploded column is read even if it isn't projected
+    let q = scan().explode([col("l")]).select([col("c")]);
+    assert_eq!(parquet_scan_columns(q.clone()), ["c", "l"]);
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), ["c"]);
+    assert_eq!(out.height(), 6);
+
+    let q = scan().explode([col("l")]).select([col("l"), col("d")]);
+    assert_eq!(parquet_scan_columns(q.clone()), ["l", "d"]);
+    assert_eq!(q.collect()?.column("l")?.dtype(), &DataType::Int32);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_parquet_projection_pushdown_selectors() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_projection_selectors.parquet");
+    let mut df = df![
+        "id" => [1i64, 2, 3],
+        "sensor_a" => [1.0f64, 2.0, 3.0],
+        "sensor_b" => [4.0f64, 5.0, 6.0],
+        "count" => [7i64, 8, 9],
+        "name" => ["x", "y", "z"],
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+    let scan = || LazyFrame::scan_parquet(&path, ScanArgsParquet::default()).unwrap();
+
+    // the selectors are expanded before the projections are pushed down
+    let numeric =
+        Selector::from(dtype_cols([DataType::Float64, DataType::Int64])) - col("id").into();
+    let q = scan().select([numeric.into()]);
+    assert_eq!(
+        parquet_scan_columns(q.clone()),
+        ["sensor_a", "sensor_b", "count"]
+    );
+    assert_eq!(
+        q.collect()?.get_column_names(),
+        ["sensor_a", "sensor_b", "count"]
+    );
+
+    // excluded columns are not read
+    let q = scan().select([Expr::from(matches("^sensor_.*$")).exclude(["sensor_b"])]);
+    assert_eq!(parquet_scan_columns(q.clone()), ["sensor_a"]);
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), ["sensor_a"]);
+    assert_eq!(
+        Vec::from(out.column("sensor_a")?.f64()?),
+        [Some(1.0), Some(2.0), Some(3.0)]
+    );
+
+    let q = scan().select([matches("^sensor_.*$").complement().into()]);
+    assert_eq!(parquet_scan_columns(q), ["id", "count", "name"]);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_parquet_struct_field_pushdown() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_struct_field_pushdown.parquet");
+    let country = Series::new(
+        "country",
+        (0..100)
+            .map(|i| if i < 50 { "NL" } else { "BE" })
+            .collect::<Vec<_>>(),
+    );
+    let age = Series::new("age", (0..100).collect::<Vec<i32>>());
+    let city = Series::new("city", (0..100).map(|i| i.to_string()).collect::<Vec<_>>());
+    let meta = StructChunked::new("meta", &[country, age, city])?.into_series();
+    let mut df = DataFrame::new(vec![
+        Series::new("id", (0..100).collect::<Vec<i32>>()),
+        meta,
+    ])?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_statistics(true)
+        .with_row_group_size(Some(10))
+        .finish(&mut df)?;
+
+    let stats = Arc::new(ParquetReadStats::default());
+    let args = ScanArgsParquet {
+        parallel: ParallelStrategy::None,
+        read_stats: Some(stats.clone()),
+        ..Default::default()
+    };
+    let q = LazyFrame::scan_parquet(&path, args.clone())?
+        .filter(col("meta").struct_().field_by_name("age").gt_eq(lit(95)))
+        .select([col("meta").struct_().field_by_name("country")]);
+
+    // the scan only projects the leaves that are used
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp_top = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let scan_schema = (&lp_arena)
+        .iter(lp_top)
+        .find_map(|(_, lp)| match lp {
+            ALogicalPlan::ParquetScan { output_schema, .. } => output_schema.clone(),
+            _ => None,
+        })
+        .unwrap();
+    let projected = DataType::Struct(vec![
+        Field::new("country", DataType::Utf8),
+        Field::new("age", DataType::Int32),
+    ]);
+    assert_eq!(scan_schema.get("meta"), Some(&projected));
+
+    // and the reader only decodes those leaves
+    let decoded = ParquetReader::new(std::fs::File::open(&path)?)
+        .with_struct_projection(Some(scan_schema))
+        .finish()?;
+    assert_eq!(decoded.column("meta")?.dtype(), &projected);
+    assert_eq!(decoded.column("id")?.len(), 100);
+
+    let out = q.collect()?;
+    // only the last row group is read
+    assert_eq!(stats.row_groups_decoded(), 1);
+    assert_eq!(out.shape(), (5, 1));
+    assert_eq!(out.column("country")?.utf8()?.get(0), Some("BE"));
+
+    // the whole struct is read if it is used itself
+    let out = LazyFrame::scan_parquet(&path, args)?
+        .filter(col("meta").struct_().field_by_name("age").lt(lit(2)))
+        .select([col("meta")])
+        .collect()?;
+    assert_eq!(out.column("meta")?.struct_()?.fields().len(), 3);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(debug_assertions, feature = "dtype-struct"))]
+fn test_parquet_unnest_field_pushdown() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_unnest_field_pushdown.parquet");
+    let a = StructChunked::new(
+        "a",
+        &[
+            Series::new("x", [1, 2]),
+            Series::new("y", [3, 4]),
+            Series::new("z", [5, 6]),
+        ],
+    )?;
+    let b = StructChunked::new("b", &[Series::new("x", [7, 8])])?;
+    let mut df = DataFrame::new(vec![a.into_series(), b.into_series()])?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let q = LazyFrame::scan_parquet(&path, Default::default())?
+        .unnest_with(["a", "b"], UnnestCollision::Prefix)
+        .filter(col("y").gt(lit(3)))
+        .select([col("b.x")]);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp_top = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let scan_schema = (&lp_arena).iter(lp_top).find_map(|(_, lp)| match lp {
+        ALogicalPlan::ParquetScan { output_schema, .. } => output_schema.clone(),
+        _ => None,
+    });
+    // `z` is not read, the colliding `x` of `a` is kept to resolve the names
+    let a_dtype = scan_schema.unwrap().get("a").cloned();
+    assert_eq!(
+        a_dtype,
+        Some(DataType::Struct(vec![
+            Field::new("x", DataType::Int32),
+            Field::new("y", DataType::Int32),
+        ]))
+    );
+
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), &["b.x"]);
+    assert_eq!(Vec::from(out.column("b.x")?.i32()?), &[Some(8)]);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_hive_partitioning() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_parquet_hive_partitioning");
+    let _ = std::fs::remove_dir_all(&root);
+    for (year, month) in [(2022, "a"), (2022, "b"), (2023, "a")] {
+        let dir = root.join(format!("year={year}/month={month}"));
+        std::fs::create_dir_all(&dir)?;
+        let mut df = df!["value" => [1i32, 2, 3]]?;
+        ParquetWriter::new(std::fs::File::create(dir.join("part-0.parquet"))?).finish(&mut df)?;
+    }
+    let args = ScanArgsParquet {
+        hive_partitioning: true,
+        ..Default::default()
+    };
+    let glob = format!("{}/**/*.parquet", root.display());
+
+    let lf = LazyFrame::scan_parquet(&glob, args.clone())?;
+    let schema = lf.schema()?;
+    assert_eq!(schema.get("year"), Some(&DataType::Int64));
+    assert_eq!(schema.get("month"), Some(&DataType::Utf8));
+    assert_eq!(lf.clone().collect()?.shape(), (9, 3));
+
+    // only the files of the selected partition are read
+    let stats = Arc::new(ParquetReadStats::default());
+    let out = LazyFrame::scan_parquet(
+        &glob,
+        ScanArgsParquet {
+            read_stats: Some(stats.clone()),
+            ..args.clone()
+        },
+    )?
+    .filter(col("year").eq(lit(2023)))
+    .select([col("value"), col("month")])
+    .collect()?;
+    assert_eq!(stats.row_groups_decoded(), 1);
+    assert_eq!(out.get_column_names(), &["value", "month"]);
+    assert_eq!(out.height(), 3);
+
+    // partition columns can be filtered together with file columns
+    let out = lf
+        .clone()
+        .filter(col("month").eq(lit("a")).and(col("value").gt(lit(1))))
+        .collect()?;
+    assert_eq!(out.shape(), (4, 3));
+
+    // only partition columns
+    let out = lf.select([col("year")]).collect()?;
+    assert_eq!(out.column("year")?.i64()?.sum(), Some(2022 * 6 + 2023 * 3));
+
+    // a file with conflicting partitions
+    let dir = root.join("year=2024");
+    std::fs::create_dir_all(&dir)?;
+    let mut df = df!["value" => [1i32]]?;
+    ParquetWriter::new(std::fs::File::create(dir.join("part-0.parquet"))?).finish(&mut df)?;
+    assert!(LazyFrame::scan_parquet(&glob, args).is_err());
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_file_path_column() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_scan_file_path_column");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+    for (i, n) in [2, 3].into_iter().enumerate() {
+        let mut df = df!["value" => (0..n).collect::<Vec<i32>>()]?;
+        ParquetWriter::new(std::fs::File::create(root.join(format!("{i}.parquet")))?)
+            .finish(&mut df)?;
+        CsvWriter::new(std::fs::File::create(root.join(format!("{i}.csv")))?).finish(&mut df)?;
+    }
+
+    let args = ScanArgsParquet {
+        file_path_column: Some("path".into()),
+        row_count: Some(RowCount {
+            name: "row_nr".into(),
+            offset: 0,
+        }),
+        row_count_per_file: true,
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(format!("{}/*.parquet", root.display()), args.clone())?;
+    let out = lf.collect()?;
+    assert_eq!(out.get_column_names(), &["row_nr", "value", "path"]);
+    assert_eq!(
+        Vec::from(out.column("row_nr")?.idx()?),
+        &[Some(0), Some(1), Some(0), Some(1), Some(2)]
+    );
+
+    // counting the rows per file only reads the metadata
+    let stats = Arc::new(ParquetReadStats::default());
+    let args = ScanArgsParquet {
+        read_stats: Some(stats.clone()),
+        ..args
+    };
+    let out = LazyFrame::scan_parquet(format!("{}/*.parquet", root.display()), args)?
+        .groupby_stable([col("path")])
+        .agg([count()])
+        .collect()?;
+    assert_eq!(stats.row_groups_decoded(), 0);
+    assert_eq!(Vec::from(out.column("count")?.idx()?), &[Some(2), Some(3)]);
+    let path = out.column("path")?.cast(&DataType::Utf8)?;
+    assert!(path.utf8()?.get(1).unwrap().ends_with("1.parquet"));
+
+    let out = LazyCsvReader::new(format!("{}/*.csv", root.display()))
+        .with_file_path_column(Some("path"))
+        .with_row_count(Some(RowCount {
+            name: "row_nr".into(),
+            offset: 0,
+        }))
+        .finish()?
+        .filter(
+            col("path")
+                .cast(DataType::Utf8)
+                .str()
+                .ends_with(lit("1.csv")),
+        )
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["row_nr", "value", "path"]);
+    // the row count is over all files
+    assert_eq!(
+        Vec::from(out.column("row_nr")?.idx()?),
+        &[Some(2), Some(3), Some(4)]
+    );
+
+    let out = LazyCsvReader::new(format!("{}/*.csv", root.display()))
+        .with_file_path_column(Some("path"))
+        .with_row_count(Some(RowCount {
+            name: "row_nr".into(),
+            offset: 0,
+        }))
+        .with_row_count_per_file(true)
+        .finish()?
+        .collect()?;
+    // the row count restarts in every file
+    assert_eq!(
+        Vec::from(out.column("row_nr")?.idx()?),
+        &[Some(0), Some(1), Some(0), Some(1), Some(2)]
+    );
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -253,6 +786,107 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_ndjson_schema() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_ndjson_schema.ndjson");
+    std::fs::write(
+        &path,
+        r#"{"a": 1, "b": "x"}
+{"a": 2.5, "b": 10}
+{"a": 3, "b": 20, "c": true}
+"#,
+    )?;
+    let path = path.to_string_lossy().to_string();
+
+    // the dtypes are unified over the inferred lines
+    let out = LazyJsonLineReader::new(path.clone()).finish()?.collect()?;
+    assert_eq!(
+        out.dtypes(),
+        &[DataType::Float64, DataType::Utf8, DataType::Boolean]
+    );
+
+    // keys that only appear after the inferred lines are ignored, or raise
+    let reader = LazyJsonLineReader::new(path.clone()).with_infer_schema_length(Some(2));
+    let out = reader.clone().finish()?.collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert!(reader
+        .with_ignore_unknown_keys(false)
+        .finish()?
+        .collect()
+        .is_err());
+
+    // values that cannot be parsed as the overwritten dtype raise, or become null
+    let reader = LazyJsonLineReader::new(path)
+        .with_schema_overwrite(Schema::from_iter([Field::new("b", DataType::Int64)]));
+    assert!(reader.clone().finish()?.collect().is_err());
+    let lf = reader.with_ignore_errors(true).finish()?;
+    let out = lf.clone().collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i64()?),
+        &[None, Some(10), Some(20)]
+    );
+
+    // the streaming engine reads the file in batches
+    let q = lf.select([col("b"), col("a")]);
+    let expected = q.clone().collect()?;
+    let out = q.with_streaming(true).collect()?;
+    assert!(out.frame_equal_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "temporal"))]
+fn test_scan_csv_date_formats() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_csv_date_formats.csv");
+    std::fs::write(
+        &path,
+        "date,datetime,text\n01.02.2023,01.02.2023 10:30,foo\n2023/03/04,,bar\n",
+    )?;
+    let formats = vec!["%d.%m.%Y".to_string(), "%Y/%m/%d".to_string()];
+
+    // the schema of the scan is the schema of the collected frame
+    let lf = LazyCsvReader::new(&path)
+        .with_date_formats(Some(formats.clone()))
+        .finish()?;
+    let schema = lf.schema()?;
+    assert_eq!(
+        schema.iter_dtypes().cloned().collect::<Vec<_>>(),
+        &[DataType::Date, DataType::Utf8, DataType::Utf8]
+    );
+    let out = lf.clone().collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert_eq!(out.column("date")?.null_count(), 0);
+
+    // the projected date column is parsed with the formats as well
+    let out = lf.clone().select([col("date")]).collect()?;
+    assert_eq!(out.column("date")?.null_count(), 0);
+    let out = lf.with_streaming(true).collect()?;
+    assert_eq!(out.schema(), *schema);
+
+    let formats = vec!["%d.%m.%Y %H:%M".to_string(), "%d.%m.%Y".to_string()];
+    let lf = LazyCsvReader::new(&path)
+        .with_date_formats(Some(formats.clone()))
+        .finish()?;
+    let schema = lf.schema()?;
+    assert_eq!(
+        schema.get("datetime"),
+        Some(&DataType::Datetime(TimeUnit::Microseconds, None))
+    );
+    // "2023/03/04" doesn't match any of the formats
+    assert_eq!(schema.get("date"), Some(&DataType::Utf8));
+    let out = lf.collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert!(LazyCsvReader::new(&path)
+        .with_date_formats(Some(formats))
+        .with_strict_date_formats(true)
+        .finish()
+        .is_err());
+
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -261,6 +895,36 @@ pub fn test_simple_slice() -> PolarsResult<()> {
 
     Ok(())
 }
+#[test]
+#[cfg(feature = "ipc")]
+fn test_ipc_slice_pushdown() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let expected = scan_foods_ipc().collect()?.slice(5, 4);
+
+    for memmap in [true, false] {
+        let args = ScanArgsIpc {
+            memmap,
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_ipc(FOODS_IPC, args)?
+            .slice(5, 4)
+            .collect()?;
+        assert!(out.frame_equal_missing(&expected));
+    }
+
+    // the slice is relative to the rows of the limited scan
+    let args = ScanArgsIpc {
+        n_rows: Some(7),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_ipc(FOODS_IPC, args)?
+        .slice(5, 4)
+        .collect()?;
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
+
 #[test]
 fn test_union_and_agg_projections() -> PolarsResult<()> {
     init_files();
@@ -392,6 +1056,24 @@ fn test_row_count_on_files() -> PolarsResult<()> {
             .collect()?;
         assert!(out.column("calories").is_ok());
         assert_eq!(out.shape(), (27, 1));
+
+        // the rows are counted before a slice that is pushed down to the scan
+        for lf in [
+            LazyCsvReader::new(FOODS_CSV).finish()?,
+            LazyFrame::scan_ipc(FOODS_IPC, Default::default())?,
+        ] {
+            let df = lf
+                .with_row_count("rc", Some(offset))
+                .slice(2, 3)
+                .collect()?;
+            assert_eq!(
+                df.column("rc")?
+                    .idx()?
+                    .into_no_null_iter()
+                    .collect::<Vec<_>>(),
+                (offset + 2..offset + 5).collect::<Vec<_>>()
+            );
+        }
     }
 
     Ok(())
@@ -9,6 +9,8 @@ mod optimization_checks;
 mod predicate_queries;
 mod projection_queries;
 mod queries;
+#[cfg(feature = "serde")]
+mod serde;
 #[cfg(feature = "streaming")]
 mod streaming;
 #[cfg(all(feature = "strings", feature = "cse"))]
@@ -30,6 +32,7 @@ fn load_df() -> DataFrame {
 
 use std::io::Cursor;
 use std::iter::FromIterator;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use optimization_checks::*;
 use polars_core::chunked_array::builder::get_list_builder;
@@ -119,6 +122,23 @@ fn scan_foods_parquet(parallel: bool) -> LazyFrame {
     LazyFrame::scan_parquet(out_path, args).unwrap()
 }
 
+/// An anonymous scan of `df` that counts how often it is executed.
+pub(crate) fn counting_scan(df: DataFrame) -> (LazyFrame, Arc<AtomicUsize>) {
+    let n_scans = Arc::new(AtomicUsize::new(0));
+    let counter = n_scans.clone();
+    let schema = df.schema();
+    let function = Arc::new(move |_scan_opts: AnonymousScanOptions| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Ok(df.clone())
+    });
+    let args = ScanArgsAnonymous {
+        schema: Some(schema),
+        ..ScanArgsAnonymous::default()
+    };
+    let lf = LazyFrame::anonymous_scan(function, args).unwrap();
+    (lf, n_scans)
+}
+
 pub(crate) fn fruits_cars() -> DataFrame {
     df!(
             "A"=> [1, 2, 3, 4, 5],
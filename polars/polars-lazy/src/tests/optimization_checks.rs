@@ -453,6 +453,139 @@ fn test_string_addition_to_concat_str() -> PolarsResult<()> {
 
     Ok(())
 }
+#[test]
+fn test_simplify_expr_neutral_elements_and_negations() -> PolarsResult<()> {
+    let df = df![
+        "x" => [Some(1), None, Some(3)],
+        "y" => [1.0, f64::NAN, 3.0],
+    ]?;
+
+    let q = df.clone().lazy().select([
+        col("x") * lit(1),
+        lit(0) + col("x"),
+        (col("x") + lit(0.0)).alias("float"),
+        col("x").gt(lit(1)).not().alias("not_gt"),
+        col("y").gt(lit(1.0)).not().alias("not_gt_float"),
+        (lit(i32::MAX) + lit(1)).alias("overflow"),
+    ]);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let exprs = lp_arena.get(root).get_exprs();
+    let unaliased = |node: Node| match expr_arena.get(node) {
+        AExpr::Alias(input, _) => expr_arena.get(*input),
+        ae => ae,
+    };
+    assert!(matches!(expr_arena.get(exprs[0]), AExpr::Column(_)));
+    // the output name of the literal is kept
+    assert!(matches!(
+        expr_arena.get(exprs[1]),
+        AExpr::Alias(input, name) if name.as_ref() == "literal"
+            && matches!(expr_arena.get(*input), AExpr::Column(_))
+    ));
+    // this changes the dtype
+    assert!(matches!(unaliased(exprs[2]), AExpr::BinaryExpr { .. }));
+    assert!(matches!(
+        unaliased(exprs[3]),
+        AExpr::BinaryExpr {
+            op: Operator::LtEq,
+            ..
+        }
+    ));
+    // NaN > 1.0 and NaN <= 1.0 are both false
+    assert!(matches!(unaliased(exprs[4]), AExpr::Function { .. }));
+    // overflows are left to the kernels
+    assert!(matches!(unaliased(exprs[5]), AExpr::BinaryExpr { .. }));
+
+    let out = q.drop_columns(["overflow"]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(1), None, Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("not_gt")?.bool()?),
+        &[Some(true), None, Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("not_gt_float")?.bool()?),
+        &[Some(true), Some(true), Some(false)]
+    );
+
+    // a predicate that is always false removes the scan
+    let q = df
+        .lazy()
+        .filter(col("x").gt(lit(2)).and(lit(false)))
+        .select([col("x")]);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(root).all(|(_, lp)| !matches!(
+        lp,
+        ALogicalPlan::DataFrameScan { df, .. } if df.height() > 0
+    )));
+    assert_eq!(q.collect()?.shape(), (0, 1));
+    Ok(())
+}
+
+#[test]
+fn test_compare_out_of_range_literal() -> PolarsResult<()> {
+    let df = df!["x" => [Some(1u8), None, Some(255u8)]]?;
+    let filter = |predicate: Expr| df.clone().lazy().filter(predicate);
+
+    // the literal fits, it is cast instead of the column
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = filter(col("x").eq(lit(255i64))).optimize(&mut lp_arena, &mut expr_arena)?;
+    match lp_arena.get(root) {
+        ALogicalPlan::DataFrameScan {
+            selection: Some(predicate),
+            ..
+        } => assert!(matches!(
+            expr_arena.get(*predicate),
+            AExpr::BinaryExpr { left, .. } if matches!(expr_arena.get(*left), AExpr::Column(_))
+        )),
+        _ => panic!(),
+    }
+    assert_eq!(filter(col("x").eq(lit(255i64))).collect()?.height(), 1);
+
+    // 300 doesn't fit in an u8, the column is not read
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = filter(col("x").eq(lit(300))).optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(matches!(
+        lp_arena.get(root),
+        ALogicalPlan::DataFrameScan { df, selection: None, .. } if df.height() == 0
+    ));
+    assert_eq!(filter(col("x").eq(lit(300))).collect()?.height(), 0);
+    assert_eq!(
+        filter(col("x").gt(lit(300)).or(col("x").eq(lit(1))))
+            .collect()?
+            .height(),
+        1
+    );
+
+    // these hold for all values, but not for the nulls
+    assert_eq!(filter(col("x").lt(lit(300))).collect()?.height(), 2);
+    assert_eq!(filter(col("x").neq(lit(-1))).collect()?.height(), 2);
+    assert_eq!(filter(lit(300).gt(col("x"))).collect()?.height(), 2);
+
+    // outside of a predicate the comparison keeps its nulls
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x").eq(lit(300))])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.bool()?),
+        &[Some(false), None, Some(false)]
+    );
+
+    #[cfg(feature = "is_in")]
+    {
+        let values = Series::new("", [1i64, 300]);
+        let out = filter(col("x").is_in(lit(values))).collect()?;
+        assert_eq!(Vec::from(out.column("x")?.u8()?), &[Some(1)]);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_with_column_prune() -> PolarsResult<()> {
     // don't
@@ -551,3 +684,171 @@ fn test_flatten_unions() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_slice_pushdown_union() -> PolarsResult<()> {
+    let dfs = [
+        df!["a" => [0, 1, 2]]?,
+        df!["a" => [3, 4, 5]]?,
+        df!["a" => [6, 7, 8]]?,
+    ];
+    let all = concat_df(&dfs)?;
+    let union = || {
+        let inputs = dfs.iter().map(|df| df.clone().lazy()).collect::<Vec<_>>();
+        concat(inputs, false, true).unwrap()
+    };
+
+    // slices that start, end or span the boundaries of the inputs
+    for (offset, len) in [
+        (0, 0),
+        (0, 3),
+        (0, 4),
+        (2, 1),
+        (2, 2),
+        (3, 0),
+        (3, 3),
+        (3, 4),
+        (5, 4),
+        (8, 1),
+        (8, 5),
+        (9, 1),
+        (12, 2),
+        (0, 20),
+        (-2, 2),
+        (-4, 3),
+    ] {
+        let out = union().slice(offset, len).collect()?;
+        assert!(
+            out.frame_equal(&all.slice(offset, len as usize)),
+            "slice({offset}, {len})"
+        );
+    }
+
+    // every input only has to produce `offset + len` rows
+    let plan = union().slice(2, 3).describe_optimized_plan()?;
+    assert!(plan.contains("UNION[offset: 2, len: 3]"));
+    assert_eq!(plan.matches("SLICE[offset: 0, len: 5]").count(), 3);
+
+    // the last input is not read once the slice is filled
+    let (last, n_scans) = counting_scan(df!["a" => [9, 10, 11]]?);
+    let out = concat(
+        [dfs[0].clone().lazy(), dfs[1].clone().lazy(), last],
+        false,
+        true,
+    )?
+    .slice(1, 5)
+    .collect()?;
+    let out = out
+        .column("a")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(out, [1, 2, 3, 4, 5]);
+    assert_eq!(n_scans.load(Ordering::Relaxed), 0);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cross_join")]
+fn test_slice_pushdown_join_inputs() -> PolarsResult<()> {
+    let left = df![
+        "a" => [0, 1, 1, 2, 3],
+        "b" => ["a", "b", "c", "d", "e"]
+    ]?;
+    let right = df![
+        "a" => [1, 1, 2, 4],
+        "c" => [10, 11, 12, 13]
+    ]?;
+
+    // only left and cross joins limit their inputs
+    for how in [JoinType::Left, JoinType::Cross, JoinType::Inner] {
+        let join = || {
+            let q = left.clone().lazy();
+            if matches!(how, JoinType::Cross) {
+                q.cross_join(right.clone().lazy())
+            } else {
+                q.join(right.clone().lazy(), [col("a")], [col("a")], how.clone())
+            }
+        };
+        let plan = join().slice(1, 2).describe_optimized_plan()?;
+        assert!(plan.contains("JOIN[offset: 1, len: 2]"));
+        let n_input_slices = plan.matches("SLICE[offset: 0, len: 3]").count();
+        match how {
+            JoinType::Left => assert_eq!(n_input_slices, 1),
+            JoinType::Cross => assert_eq!(n_input_slices, 2),
+            _ => {
+                assert_eq!(n_input_slices, 0);
+                continue;
+            }
+        }
+
+        let all = join().collect()?;
+        // slices that start and end at the exploded rows
+        for (offset, len) in [
+            (0, 1),
+            (1, 2),
+            (2, 2),
+            (3, 4),
+            (4, 3),
+            (6, 2),
+            (0, 30),
+            (18, 5),
+        ] {
+            let out = join().slice(offset, len).collect()?;
+            assert!(
+                out.frame_equal_missing(&all.slice(offset, len as usize)),
+                "{how} join slice({offset}, {len})"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_single_optimization_toggles() -> PolarsResult<()> {
+    let q = || {
+        fruits_cars()
+            .lazy()
+            .select([col("A"), col("B")])
+            .filter(col("A").gt(lit(1)))
+            .slice(0, 2)
+    };
+    // a pushed down predicate is part of the scan
+    let pushed_predicate = |plan: &str| !plan.contains("FILTER");
+
+    // only predicate pushdown
+    let plan = q()
+        .with_optimizations(OptState::default_off())
+        .with_predicate_pushdown(true)
+        .describe_optimized_plan()?;
+    assert!(pushed_predicate(&plan));
+    assert!(plan.contains("SLICE"));
+
+    // everything but predicate pushdown
+    let plan = q()
+        .with_predicate_pushdown(false)
+        .describe_optimized_plan()?;
+    assert!(!pushed_predicate(&plan));
+
+    // the toggles don't change the result
+    let expected = q().collect()?;
+    for opt_state in [
+        OptState::default_off(),
+        OptState {
+            type_coercion: true,
+            ..OptState::default_off()
+        },
+    ] {
+        let out = q().with_optimizations(opt_state).collect()?;
+        assert!(out.frame_equal(&expected));
+    }
+    let out = q()
+        .with_projection_pushdown(false)
+        .with_slice_pushdown(false)
+        .with_simplify_expr(false)
+        .collect()?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
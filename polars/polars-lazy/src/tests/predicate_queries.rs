@@ -39,7 +39,7 @@ fn test_issue_2472() -> PolarsResult<()> {
     ]?;
     let base = df
         .lazy()
-        .with_column(col("group").cast(DataType::Categorical(None)));
+        .with_column(col("group").cast(DataType::Categorical(None, Default::default())));
 
     let extract = col("group")
         .cast(DataType::Utf8)
@@ -269,3 +269,58 @@ fn test_predicate_on_join_suffix_4788() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "is_in")]
+fn test_is_in_list_per_row_predicate() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), Some(2), None, Some(4), Some(5)],
+        "b" => [Some(Series::new("", [1, 3])), Some(Series::new("", [3])), Some(Series::new("", [1])), None, Some(Series::new("", Vec::<i32>::new()))],
+        "c" => [1, 2, 3, 4, 5]
+    ]?;
+
+    let q = df
+        .lazy()
+        .select([col("a"), col("b"), col("c")])
+        .filter(col("a").is_in(col("b")).or(col("c").eq(lit(2))));
+    // the predicate is pushed down to the scan
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("c")?.i32()?), &[Some(1), Some(2)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "is_in")]
+fn test_list_contains_nulls() -> PolarsResult<()> {
+    let df = df![
+        "a" => [
+            Some(Series::new("", [Some(1), None])),
+            Some(Series::new("", [Some(1), Some(2)])),
+            None
+        ]
+    ]?;
+
+    // unlike `is_in`, a null item is contained in the lists with nulls and a null list contains
+    // nothing
+    let out = df
+        .lazy()
+        .select([
+            col("a").arr().contains(lit(1)).alias("one"),
+            col("a")
+                .arr()
+                .contains(lit(NULL).cast(DataType::Int32))
+                .alias("null"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("one")?.bool()?),
+        &[Some(true), Some(true), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("null")?.bool()?),
+        &[Some(true), Some(false), Some(false)]
+    );
+    Ok(())
+}
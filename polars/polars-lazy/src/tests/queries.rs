@@ -698,6 +698,65 @@ fn test_lazy_groupby_apply() {
         .unwrap();
 }
 
+#[test]
+fn test_apply_declared_output() -> PolarsResult<()> {
+    let df = fruits_cars();
+    let len = |s: Series| Ok(Some(Series::new("", &[s.len() as u32])));
+
+    let lf = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("fruits")])
+        .agg([col("cars")
+            .apply(len, GetOutput::same_type())
+            .returns(DataType::UInt32)
+            .returns_scalar()]);
+    assert_eq!(lf.schema()?.get("cars"), Some(&DataType::UInt32));
+    let out = lf.collect()?;
+    // a scalar per group is not wrapped in a list
+    assert_eq!(Vec::from(out.column("cars")?.u32()?), &[Some(3), Some(2)]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("fruits")])
+        .agg([col("cars")
+            .apply(len, GetOutput::same_type())
+            .returns(DataType::Int64)])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::SchemaMismatch(_))));
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("fruits")])
+        .agg([col("cars")
+            .apply(|s| Ok(Some(s)), GetOutput::same_type())
+            .returns_scalar()])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::ShapeMismatch(_))));
+
+    // an alias of the closure is looked through
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("fruits")])
+        .agg([col("cars")
+            .apply(len, GetOutput::same_type())
+            .alias("n")
+            .returns(DataType::UInt32)
+            .returns_scalar()])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("n")?.u32()?), &[Some(3), Some(2)]);
+
+    let out = df
+        .lazy()
+        .select([col("cars").returns(DataType::UInt32)])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_shift_and_fill() {
     let df = df! {
@@ -1349,8 +1408,8 @@ fn test_categorical_addition() -> PolarsResult<()> {
     let out = df
         .lazy()
         .select([
-            col("fruits").cast(DataType::Categorical(None)),
-            col("cars").cast(DataType::Categorical(None)),
+            col("fruits").cast(DataType::Categorical(None, Default::default())),
+            col("cars").cast(DataType::Categorical(None, Default::default())),
         ])
         .select([(col("fruits") + lit(" ") + col("cars")).alias("foo")])
         .collect()?;
@@ -1898,3 +1957,1369 @@ fn test_partitioned_gb_ternary() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cache_branches() -> PolarsResult<()> {
+    let (lf, n_scans) = counting_scan(fruits_cars());
+    let cached = lf.cache();
+    let q = cached.clone().filter(col("A").gt(lit(2))).left_join(
+        cached.clone().filter(col("B").gt(lit(2))),
+        col("fruits"),
+        col("fruits"),
+    );
+
+    let plan = q.describe_optimized_plan()?;
+    assert_eq!(plan.matches("CACHE[id: ").count(), 2);
+
+    let out = q.clone().collect()?;
+    assert_eq!(out.shape(), (4, 7));
+    assert_eq!(n_scans.load(Ordering::Relaxed), 1);
+
+    // the cache is dropped once the query finished
+    q.collect()?;
+    assert_eq!(n_scans.load(Ordering::Relaxed), 2);
+
+    // the queries of `collect_all` share the cache
+    let out = collect_all(vec![
+        cached.clone().select([col("A").sum()]),
+        cached.select([col("B").sum()]),
+    ])?;
+    assert_eq!(out[0].column("A")?.get(0)?, AnyValue::Int32(15));
+    assert_eq!(out[1].column("B")?.get(0)?, AnyValue::Int32(15));
+    assert_eq!(n_scans.load(Ordering::Relaxed), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_profile_node_ids() -> PolarsResult<()> {
+    let q = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .groupby_stable([col("fruits")])
+        .agg([col("B").sum()])
+        .sort("fruits", Default::default());
+
+    let plan = q.describe_optimized_plan()?;
+    let (out, profile) = q.profile()?;
+    assert_eq!(
+        profile.get_column_names(),
+        &["node_id", "node", "start", "end", "rows", "peak_memory"]
+    );
+
+    // the optimization passes are timed
+    let nodes = profile.column("node")?.utf8()?;
+    let nodes = nodes.into_no_null_iter().collect::<Vec<_>>();
+    assert!(nodes.contains(&"optimization"));
+    assert!(nodes.contains(&"optimization: predicate_pushdown"));
+    assert!(nodes.contains(&"optimization: projection_pushdown"));
+
+    // every executed node can be found in the optimized plan
+    let node_ids = profile.column("node_id")?.u32()?;
+    let rows = profile.column("rows")?.u64()?;
+    for ((node, node_id), rows) in nodes.iter().zip(node_ids).zip(rows) {
+        match node_id {
+            Some(node_id) => {
+                assert!(plan.contains(&format!("[{node_id}] ")), "{node}");
+                assert!(rows.is_some(), "{node}");
+            }
+            None => assert!(node.starts_with("optimization") || *node == "query"),
+        }
+    }
+
+    // the whole query is a node, its memory isn't tracked without the `TrackingAllocator`
+    let query = profile.filter(&profile.column("node")?.equal("query")?)?;
+    assert_eq!(query.height(), 1);
+    assert_eq!(query.column("peak_memory")?.null_count(), 1);
+
+    // the sort is the root of the plan
+    assert!(plan.starts_with("[0] SORT BY"));
+    let sort = profile.filter(&node_ids.equal(0))?;
+    assert_eq!(
+        sort.column("rows")?.get(0)?,
+        AnyValue::UInt64(out.height() as u64)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "arg_where")]
+fn test_arg_where() -> PolarsResult<()> {
+    // span multiple 64 bit words and a remainder
+    let values = (0..150).map(|i| i % 3 == 0 || i > 120).collect::<Vec<_>>();
+    let masked = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| if i % 10 == 0 { None } else { Some(*v) })
+        .collect::<Vec<_>>();
+    let df = df![
+        "x" => masked
+    ]?;
+
+    let out = df.clone().lazy().select([arg_where(col("x"))]).collect()?;
+    let out = out.column("x")?;
+    let expected = values
+        .iter()
+        .enumerate()
+        .filter(|(i, v)| **v && i % 10 != 0)
+        .map(|(i, _)| i as IdxSize)
+        .collect::<Vec<_>>();
+    assert_eq!(out.idx()?.into_no_null_iter().collect::<Vec<_>>(), expected);
+    assert_eq!(
+        out.is_sorted_flag(),
+        polars_core::series::IsSorted::Ascending
+    );
+
+    // the bits that follow a sliced mask are not set bits of the mask
+    let all = Series::new("x", vec![true; 140]);
+    for (offset, len) in [(0, 67), (3, 60), (8, 5)] {
+        let df = DataFrame::new(vec![all.slice(offset, len)])?;
+        let out = df.lazy().select([arg_where(col("x"))]).collect()?;
+        assert_eq!(
+            out.column("x")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            (0..len as IdxSize).collect::<Vec<_>>()
+        );
+    }
+
+    let df = df![
+        "g" => ["a", "a", "b", "b", "b"],
+        "x" => [1, -1, -1, 2, 3]
+    ]?;
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([arg_where(col("x").gt(lit(0)))])
+        .collect()?;
+    let out = out.column("x")?.explode()?;
+    assert_eq!(
+        Vec::from(out.idx()?),
+        &[Some(0 as IdxSize), Some(1), Some(2)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cut_qcut")]
+fn test_cut() -> PolarsResult<()> {
+    let df = df![
+        "x" => [Some(-1.0), Some(1.0), Some(2.5), None, Some(f64::NAN), Some(10.0)]
+    ]?;
+    let bins = |e: Expr| -> PolarsResult<Vec<Option<String>>> {
+        let out = df.clone().lazy().select([e]).collect()?;
+        let out = out.column("x")?.cast(&DataType::Utf8)?;
+        Ok(out
+            .utf8()?
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.to_string()))
+            .collect())
+    };
+    let owned = |v: &[Option<&str>]| -> Vec<Option<String>> {
+        v.iter().map(|opt_s| opt_s.map(|s| s.to_string())).collect()
+    };
+
+    // values outside the outer breaks go to the outer bins
+    let out = bins(col("x").cut(vec![5.0, 1.0], None, false, false, true))?;
+    assert_eq!(
+        out,
+        owned(&[
+            Some("(-inf, 1]"),
+            Some("(-inf, 1]"),
+            Some("(1, 5]"),
+            None,
+            None,
+            Some("(5, inf]")
+        ])
+    );
+
+    // or are null
+    let out = bins(col("x").cut(vec![1.0, 5.0], None, false, false, false))?;
+    assert_eq!(out, owned(&[None, None, Some("(1, 5]"), None, None, None]));
+    let out = bins(col("x").cut(vec![1.0, 5.0], None, true, false, false))?;
+    assert_eq!(
+        out,
+        owned(&[None, Some("[1, 5)"), Some("[1, 5)"), None, None, None])
+    );
+
+    let labels = Some(vec!["low".to_string(), "mid".into(), "high".into()]);
+    let out = bins(col("x").cut(vec![1.0, 5.0], labels, false, false, true))?;
+    assert_eq!(
+        out,
+        owned(&[
+            Some("low"),
+            Some("low"),
+            Some("mid"),
+            None,
+            None,
+            Some("high")
+        ])
+    );
+
+    // the schema reports the struct
+    let lf = df
+        .clone()
+        .lazy()
+        .select([col("x").cut(vec![1.0, 5.0], None, false, true, true)]);
+    let expected = DataType::Struct(vec![
+        Field::new("break_point", DataType::Float64),
+        Field::new("category", DataType::Categorical(None, Default::default())),
+    ]);
+    assert_eq!(lf.schema()?.get("x"), Some(&expected));
+    let out = lf.collect()?;
+    let out = out.column("x")?;
+    assert_eq!(out.dtype(), &expected);
+    let break_points = out.struct_()?.field_by_name("break_point")?;
+    assert_eq!(
+        Vec::from(break_points.f64()?),
+        &[
+            Some(1.0),
+            Some(1.0),
+            Some(5.0),
+            None,
+            None,
+            Some(f64::INFINITY)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cut_qcut")]
+fn test_qcut() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "a", "b", "b"],
+        "x" => [1, 1, 1, 2, 10, 30]
+    ]?;
+
+    // the 0.25 and 0.5 quantiles of group "a" are both 1
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("g").eq(lit("a")))
+        .select([col("x").qcut(vec![0.25, 0.5], None, false, false, false)])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x")
+            .qcut_uniform(2, None, false, true, false)
+            .over([col("g")])])
+        .collect()?;
+    // every group has its own local rev-map
+    let out = out.column("x")?.categorical()?;
+    assert_eq!(
+        out.iter_str().collect::<Vec<_>>(),
+        &[
+            Some("(-inf, 1]"),
+            Some("(-inf, 1]"),
+            Some("(-inf, 1]"),
+            Some("(1, inf]"),
+            Some("(-inf, 20]"),
+            Some("(20, inf]")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "search_sorted")]
+fn test_search_sorted() -> PolarsResult<()> {
+    let search = |s: Series, element: Series, side, descending| -> PolarsResult<Vec<IdxSize>> {
+        let df = s.into_frame();
+        let out = df
+            .lazy()
+            .select([match descending {
+                Some(descending) => {
+                    col("a").search_sorted_descending(lit(element), side, descending)
+                }
+                None => col("a").search_sorted(lit(element), side),
+            }])
+            .collect()?;
+        Ok(out.column("a")?.idx()?.into_no_null_iter().collect())
+    };
+    use SearchSortedSide::*;
+
+    // binary search over multiple chunks
+    let mut s = Series::new("a", [1, 2, 2, 3]);
+    s.append(&Series::new("a", [5, 5, 8]))?;
+    assert_eq!(s.n_chunks(), 2);
+    let element = Series::new("", [0, 2, 5, 9]);
+    assert_eq!(
+        search(s.clone(), element.clone(), Left, None)?,
+        &[0, 1, 4, 7]
+    );
+    assert_eq!(
+        search(s.clone(), element.clone(), Right, None)?,
+        &[0, 3, 6, 7]
+    );
+
+    // descending from the sorted flag or given explicitly
+    let mut s = s.reverse();
+    let element = Series::new("", [9, 5, 2, 0]);
+    assert_eq!(
+        search(s.clone(), element.clone(), Left, Some(true))?,
+        &[0, 1, 4, 7]
+    );
+    s.set_sorted_flag(polars_core::series::IsSorted::Descending);
+    assert_eq!(search(s, element, Right, None)?, &[0, 3, 6, 7]);
+
+    // nulls last
+    let s = Series::new("a", [Some(1), Some(3), None]);
+    let element = Series::new("", [None, Some(4)]);
+    assert_eq!(search(s.clone(), element.clone(), Left, None)?, &[2, 2]);
+    assert_eq!(search(s, element, Right, None)?, &[3, 2]);
+
+    // nulls first
+    let s = Series::new("a", [None, None, Some(1), Some(3)]);
+    let element = Series::new("", [None, Some(0), Some(2)]);
+    assert_eq!(search(s.clone(), element.clone(), Left, None)?, &[0, 2, 3]);
+    assert_eq!(search(s, element, Right, None)?, &[2, 2, 3]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "repeat_by")]
+fn test_repeat_by() -> PolarsResult<()> {
+    let df = df![
+        "x" => ["a", "b", "c", "d"],
+        "n" => [Some(2u32), Some(0), None, Some(1)]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x").repeat_by(col("n"))])
+        .collect()?;
+    let out = out.column("x")?;
+    assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Utf8)));
+    let lens = out
+        .list()?
+        .into_iter()
+        .map(|opt_s| opt_s.map(|s| s.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(lens, &[Some(2), Some(0), None, Some(1)]);
+
+    // weighted explode
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x").repeat_by(col("n")).explode()])
+        .collect()?;
+    let x = out.column("x")?.utf8()?.into_iter().collect::<Vec<_>>();
+    assert_eq!(x, &[Some("a"), Some("a"), None, None, Some("d")]);
+
+    let out = df
+        .lazy()
+        .select([col("x").repeat_by(lit(2u32)).explode()])
+        .collect()?;
+    assert_eq!(out.height(), 8);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "round_series")]
+fn test_clip_expr_bounds() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b"],
+        "x" => [Some(1i64), Some(10), None, Some(5)],
+        "lo" => [Some(2i64), Some(2), Some(0), None],
+        "hi" => [Some(3.5f64), None, Some(1.0), Some(4.0)]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("x").clip(col("lo"), col("hi")).alias("clip"),
+            col("x").clip_min(col("lo")).alias("clip_min"),
+            col("x").clip_max(lit(4i64)).alias("clip_max"),
+        ])
+        .collect()?;
+    // the output has the supertype of the inputs
+    let clip = out.column("clip")?;
+    assert_eq!(clip.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(clip.f64()?),
+        &[Some(2.0), Some(10.0), None, Some(4.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("clip_min")?.i64()?),
+        &[Some(2), Some(10), None, Some(5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("clip_max")?.i64()?),
+        &[Some(1), Some(4), None, Some(4)]
+    );
+
+    // per row bounds in a window and in an aggregation
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x")
+            .clip_min(col("lo"))
+            .sum()
+            .over([col("g")])
+            .alias("x")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i64()?),
+        &[Some(12), Some(12), Some(5), Some(5)]
+    );
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("x").clip_min(col("lo")).sum()])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("x")?.i64()?), &[Some(12), Some(5)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-struct", feature = "regex"))]
+fn test_struct_field_selection() -> PolarsResult<()> {
+    let s = StructChunked::new(
+        "s",
+        &[
+            Series::new("pref_a", [1, 2]),
+            Series::new("b", [3, 4]),
+            Series::new("pref_c", [5, 6]),
+        ],
+    )?
+    .into_series();
+    let df = DataFrame::new(vec![s])?;
+
+    let names = |exprs: Vec<Expr>| -> PolarsResult<Vec<String>> {
+        let out = df.clone().lazy().select(exprs).collect()?;
+        Ok(out
+            .get_column_names()
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect())
+    };
+
+    assert_eq!(
+        names(vec![col("s").struct_().field_by_names(&["b", "pref_a"])])?,
+        &["b", "pref_a"]
+    );
+    assert_eq!(
+        names(vec![col("s").struct_().field_by_name("*")])?,
+        &["pref_a", "b", "pref_c"]
+    );
+    assert_eq!(
+        names(vec![col("s").struct_().field_by_name("^pref_.*$")])?,
+        &["pref_a", "pref_c"]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s").struct_().field_by_name("^pref_.*$") * lit(10)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("pref_c")?.i32()?),
+        &[Some(50), Some(60)]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("s").struct_().field_by_names(&["b", "missing"])])
+        .collect();
+    assert!(
+        matches!(out, Err(PolarsError::StructFieldNotFound(msg)) if msg.contains("pref_a, b, pref_c"))
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_struct_with_fields() -> PolarsResult<()> {
+    let s = StructChunked::new("s", &[Series::new("a", [1, 2]), Series::new("b", [3, 4])])?
+        .into_series();
+    let df = DataFrame::new(vec![s])?;
+
+    let lf = df.clone().lazy().select([col("s")
+        .struct_()
+        .with_fields(vec![
+            (col("a") + col("b")).alias("c"),
+            col("a").cast(DataType::Float64),
+        ])
+        .struct_()
+        .field_by_name("c")]);
+    // the new field is known to the schema
+    assert_eq!(lf.schema()?.get("c"), Some(&DataType::Int32));
+    let out = lf.collect()?;
+    assert_eq!(Vec::from(out.column("c")?.i32()?), &[Some(4), Some(6)]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s").struct_().with_fields(vec![
+            (col("a") + col("b")).alias("c"),
+            col("a").cast(DataType::Float64),
+        ])])
+        .collect()?;
+    // existing fields are replaced, new fields appended
+    assert_eq!(
+        out.column("s")?.dtype(),
+        &DataType::Struct(vec![
+            Field::new("a", DataType::Float64),
+            Field::new("b", DataType::Int32),
+            Field::new("c", DataType::Int32),
+        ])
+    );
+
+    // the schema error of a missing field is raised
+    let lf = df
+        .clone()
+        .lazy()
+        .select([col("s").struct_().with_fields(vec![col("z")])]);
+    assert!(lf.schema().is_err());
+
+    let out = df
+        .lazy()
+        .select([col("s")
+            .struct_()
+            .rename_fields(vec!["x".to_string(), "y".to_string()])
+            .struct_()
+            .field_by_name("y")])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("y")?.i32()?), &[Some(3), Some(4)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "json_encode", feature = "dtype-struct"))]
+fn test_json_encode() -> PolarsResult<()> {
+    let s = StructChunked::new(
+        "s",
+        &[
+            Series::new("a", [Some(1), None]),
+            Series::new("b", ["x", "y"]),
+        ],
+    )?
+    .into_series();
+    let df = df![
+        "l" => [Some(Series::new("", [1.5, 2.0])), None]
+    ]?
+    .hstack(&[s])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s").struct_().json_encode(false),
+            col("l").json_encode(false).alias("l"),
+            col("l").json_encode(true).alias("l_nulls"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("s")?.utf8()?),
+        &[Some(r#"{"a":1,"b":"x"}"#), Some(r#"{"a":null,"b":"y"}"#)]
+    );
+    assert_eq!(
+        Vec::from(out.column("l")?.utf8()?),
+        &[Some("[1.5,2]"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("l_nulls")?.utf8()?),
+        &[Some("[1.5,2]"), Some("null")]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "arange")]
+fn test_int_ranges() -> PolarsResult<()> {
+    let df = df![
+        "start" => [Some(0i64), Some(5), Some(3), None],
+        "end" => [Some(3i64), Some(0), Some(3), Some(2)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            int_ranges(col("start"), col("end"), 2).alias("up"),
+            int_ranges(col("start"), col("end"), -2).alias("down"),
+        ])
+        .collect()?;
+    let to_vecs = |s: &Series| -> PolarsResult<Vec<Option<Vec<Option<i64>>>>> {
+        Ok(s.list()?
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| Vec::from(s.i64().unwrap())))
+            .collect())
+    };
+    assert_eq!(
+        to_vecs(out.column("up")?)?,
+        &[
+            Some(vec![Some(0), Some(2)]),
+            Some(vec![]),
+            Some(vec![]),
+            None
+        ]
+    );
+    assert_eq!(
+        to_vecs(out.column("down")?)?,
+        &[
+            Some(vec![]),
+            Some(vec![Some(5), Some(3), Some(1)]),
+            Some(vec![]),
+            None
+        ]
+    );
+    assert!(df
+        .clone()
+        .lazy()
+        .select([int_ranges(col("start"), col("end"), 0)])
+        .collect()
+        .is_err());
+
+    let lf = df
+        .lazy()
+        .select([int_range(lit(4), lit(0), -1, DataType::UInt8).alias("range")]);
+    assert_eq!(lf.schema()?.get("range"), Some(&DataType::UInt8));
+    let out = lf.collect()?;
+    assert_eq!(
+        Vec::from(out.column("range")?.u8()?),
+        &[Some(4), Some(3), Some(2), Some(1)]
+    );
+
+    // values that don't fit in the dtype are an error, not a null
+    assert!(DataFrame::default()
+        .lazy()
+        .select([int_range(lit(250), lit(260), 1, DataType::UInt8)])
+        .collect()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_horizontal_reductions() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1i32), None, Some(5), None],
+        "b" => [Some(2.5f64), Some(3.0), Some(1.0), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            sum_horizontal([col("a"), col("b")], true).alias("sum"),
+            sum_horizontal([col("a"), col("b")], false).alias("sum_poison"),
+            min_horizontal([col("a"), col("b")], true).alias("min"),
+            max_horizontal([col("a"), col("b")], false).alias("max_poison"),
+            mean_horizontal([col("a"), col("b")], true).alias("mean"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.f64()?),
+        &[Some(3.5), Some(3.0), Some(6.0), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("sum_poison")?.f64()?),
+        &[Some(3.5), None, Some(6.0), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("min")?.f64()?),
+        &[Some(1.0), Some(3.0), Some(1.0), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("max_poison")?.f64()?),
+        &[Some(2.5), None, Some(5.0), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(1.75), Some(3.0), Some(3.0), None]
+    );
+
+    let lf = df.lazy().select([sum_horizontal(Vec::<Expr>::new(), true)]);
+    assert!(lf.collect().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_coalesce() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "a" => [Some(1i32), None, None, Some(4)],
+        "b" => [None, Some(2.5f64), None, None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([coalesce(&[col("a"), col("b"), lit(0)]).alias("c")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("c")?.f64()?),
+        &[Some(1.0), Some(2.5), Some(0.0), Some(4.0)]
+    );
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([coalesce(&[col("a"), lit(-1)]).alias("c")])
+        .collect()?;
+    let c = out.column("c")?.explode()?;
+    assert_eq!(Vec::from(c.i32()?), &[Some(1), Some(-1), Some(-1), Some(4)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_selectors() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1i64, 1, 2],
+        "sensor_a" => [1.0f64, 2.0, 3.0],
+        "sensor_b" => [4.0f64, 5.0, 6.0],
+        "count" => [7i64, 8, 9],
+        "name" => ["x", "y", "z"],
+    ]?;
+
+    // all numeric columns except the id column
+    let numeric =
+        Selector::from(dtype_cols([DataType::Float64, DataType::Int64])) - col("id").into();
+    let out = df
+        .clone()
+        .lazy()
+        .select([numeric.clone().into()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["sensor_a", "sensor_b", "count"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([(matches("^sensor_.*$") & numeric.clone())
+            .complement()
+            .into()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["id", "count", "name"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([matches("sensor").union(col("name")).into()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["sensor_a", "sensor_b", "name"]);
+
+    // `exclude` applies to the selected columns
+    let out = df
+        .clone()
+        .lazy()
+        .with_columns([Expr::from(matches("^sensor_.*$")).exclude(["sensor_b"]) * lit(2.0)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("sensor_a")?.f64()?),
+        &[Some(2.0), Some(4.0), Some(6.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sensor_b")?.f64()?),
+        &[Some(4.0), Some(5.0), Some(6.0)]
+    );
+
+    // the group keys are not aggregated
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([Expr::from(Selector::from(col("id")))])
+        .agg([Expr::from(numeric.clone() + col("id").into()).sum()])
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["id", "sensor_a", "sensor_b", "count"]
+    );
+
+    let out = df
+        .lazy()
+        .melt_by(col("id"), matches("^sensor_.*$"), MeltArgs::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["id", "variable", "value"]);
+    assert_eq!(out.height(), 6);
+    Ok(())
+}
+
+#[test]
+fn test_name_namespace() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 2],
+        "B" => [3i32, 4],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            (col("a") * col("B")).name().keep(),
+            col("*").max().name().suffix("_max"),
+            col("*").min().name().prefix("min_"),
+            col("B").name().to_lowercase(),
+            col("a").name().map(|name| Ok(format!("{name}{name}"))),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["a", "a_max", "B_max", "min_a", "min_B", "b", "aa"]
+    );
+
+    // `a` and `B` are both renamed to `x`
+    let out = df
+        .lazy()
+        .select([col("*").name().map(|_| Ok("x".to_string()))])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "top_k")]
+fn test_top_k_by() -> PolarsResult<()> {
+    let df = df![
+        "store" => [1, 1, 1, 1, 2, 2],
+        "product" => ["a", "b", "c", "d", "e", "f"],
+        "sales" => [Some(10), Some(30), None, Some(20), Some(5), Some(7)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("sales").top_k(2)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("sales")?.i32()?),
+        &[Some(30), Some(20)]
+    );
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("store")])
+        .agg([
+            col("product").top_k_by(2, col("sales")).alias("top"),
+            col("product").bottom_k_by(1, col("sales")).alias("bottom"),
+            col("sales").top_k(5).alias("sales"),
+        ])
+        .collect()?;
+    let top = out.column("top")?.explode()?;
+    assert_eq!(
+        Vec::from(top.utf8()?),
+        &[Some("b"), Some("d"), Some("f"), Some("e")]
+    );
+    let bottom = out.column("bottom")?.explode()?;
+    assert_eq!(Vec::from(bottom.utf8()?), &[Some("a"), Some("e")]);
+    // nulls are ignored and k may exceed the group size
+    let sales = out.column("sales")?.explode()?;
+    assert_eq!(
+        Vec::from(sales.i32()?),
+        &[Some(30), Some(20), Some(10), Some(7), Some(5)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "top_k")]
+fn test_arg_partition() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 1, 2, 2],
+        "a" => [Some(10), Some(30), None, Some(20), Some(7), Some(5)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").arg_partition(2, true, true)])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.idx()?), &[Some(1), Some(3)]);
+
+    // indices are relative to the group
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("a").arg_partition(2, false, true)])
+        .collect()?;
+    let a = out.column("a")?;
+    assert_eq!(a.dtype(), &DataType::List(Box::new(IDX_DTYPE)));
+    let a = a.explode()?;
+    assert_eq!(Vec::from(a.idx()?), &[Some(0), Some(3), Some(1), Some(0)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_decimal_arithmetic() -> PolarsResult<()> {
+    let price = Int128Chunked::from_slice("price", &[1050, 999, 2000])
+        .into_decimal_unchecked(Some(5), 2)
+        .into_series();
+    let qty = Series::new("qty", [2i32, 3, 1]);
+    let df = DataFrame::new(vec![price, qty])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("price").gt(lit("10.50".parse::<Decimal>()?)))
+        .select([col("price"), (col("price") * col("qty")).alias("total")])
+        .collect()?;
+    let total = out.column("total")?;
+    assert_eq!(total.dtype(), &DataType::Decimal(Some(16), Some(2)));
+    assert_eq!(total.get(0)?, AnyValue::Decimal(2000, 2));
+
+    let out = df
+        .lazy()
+        .select([col("price").sum(), col("price").max().alias("max")])
+        .collect()?;
+    assert_eq!(out.column("price")?.get(0)?, AnyValue::Decimal(4049, 2));
+    assert_eq!(out.column("max")?.get(0)?, AnyValue::Decimal(2000, 2));
+    Ok(())
+}
+
+#[test]
+fn test_binary_namespace() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(b"hello".as_slice()), None, Some(b"polars")],
+        "sub" => [b"lo".as_slice(), b"x", b"po"],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .binary()
+                .contains_literal(lit(b"l".as_slice()))
+                .alias("contains"),
+            col("a").binary().ends_with(col("sub")).alias("ends_with"),
+            col("a")
+                .binary()
+                .starts_with(col("sub"))
+                .alias("starts_with"),
+            col("a").binary().size().alias("size"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("contains")?.bool()?),
+        &[Some(true), None, Some(true)]
+    );
+    assert_eq!(
+        Vec::from(out.column("ends_with")?.bool()?),
+        &[Some(true), None, Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("starts_with")?.bool()?),
+        &[Some(false), None, Some(true)]
+    );
+    let size = out.column("size")?;
+    assert_eq!(size.dtype(), &DataType::UInt32);
+    assert_eq!(Vec::from(size.u32()?), &[Some(5), None, Some(6)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "binary_encoding")]
+fn test_binary_hex() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(b"hi".as_slice()), None, Some(b"\x00\xff")],
+        "invalid" => [b"6869".as_slice(), b"zz", b"0"],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").binary().to_hex().alias("hex"),
+            col("a")
+                .binary()
+                .to_hex()
+                .cast(DataType::Binary)
+                .binary()
+                .from_hex(true)
+                .alias("round_trip"),
+            col("invalid").binary().from_hex(false),
+        ])
+        .collect()?;
+    let hex = out.column("hex")?;
+    assert_eq!(hex.dtype(), &DataType::Utf8);
+    assert_eq!(Vec::from(hex.utf8()?), &[Some("6869"), None, Some("00ff")]);
+    assert!(out
+        .column("round_trip")?
+        .series_equal_missing(df.column("a")?));
+    assert_eq!(
+        Vec::from(out.column("invalid")?.binary()?),
+        &[Some(b"hi".as_slice()), None, None]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("invalid").binary().from_hex(true)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "scatter")]
+fn test_scatter() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 2, 3, 4],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_columns([col("a")
+            .scatter(lit(Series::new("", [3i64, 0])), lit(0.5))
+            .alias("b")])
+        .collect()?;
+    let b = out.column("b")?;
+    assert_eq!(b.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(b.f64()?),
+        &[Some(0.5), Some(2.0), Some(3.0), Some(0.5)]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("a").scatter(lit(4i64), lit(0))])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "interpolate")]
+fn test_interpolate_by_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "t" => [0i64, 1, 4, 0, 2, 3],
+        "v" => [Some(0i32), None, Some(8), Some(10), None, Some(13)],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("v")
+            .interpolate_by(col("t"), InterpolationMethod::Linear)
+            .over([col("g")])])
+        .collect()?;
+    let v = out.column("v")?;
+    assert_eq!(v.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(v.f64()?),
+        &[
+            Some(0.0),
+            Some(2.0),
+            Some(8.0),
+            Some(10.0),
+            Some(12.0),
+            Some(13.0)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fill_null_with_strategy_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "v" => [Some(1i32), None, None, None, None, Some(2)],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .fill_null_with_strategy(FillNullStrategy::Forward(Some(1)))
+                .over([col("g")])
+                .alias("forward"),
+            col("v")
+                .fill_null_with_strategy(FillNullStrategy::ForwardThenBackward(Some(1)))
+                .over([col("g")])
+                .alias("both"),
+        ])
+        .collect()?;
+    // the fill doesn't carry over to the next group
+    assert_eq!(
+        Vec::from(out.column("forward")?.i32()?),
+        &[Some(1), Some(1), None, None, None, Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("both")?.i32()?),
+        &[Some(1), Some(1), None, None, Some(2), Some(2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_window_chunked_input() -> PolarsResult<()> {
+    let part = |g: [&str; 3], s: [i32; 3], v: [i32; 3]| {
+        df![
+            "g" => g,
+            "s" => s,
+            "v" => v,
+        ]
+    };
+    let mut df = part(["a", "b", "a"], [1, 1, 2], [1, 2, 3])?;
+    df.vstack_mut(&part(["b", "a", "b"], [2, 2, 3], [4, 5, 6])?)?;
+    df.vstack_mut(&part(["a", "a", "b"], [3, 3, 3], [7, 8, 9])?)?;
+    df.try_apply("s", |s| {
+        let mut s = s.clone();
+        s.set_sorted_flag(polars_core::series::IsSorted::Ascending);
+        Ok(s)
+    })?;
+    let q = |df: DataFrame| {
+        df.lazy()
+            .select([
+                // a column
+                col("v").over([col("g")]).alias("column"),
+                // an aggregation that is set by the groups
+                col("v").sum().over([col("g")]).alias("sum"),
+                // a list aggregation that is joined back
+                col("v").list().over([col("g")]).alias("list"),
+                // a map back to the original locations
+                col("v").sort(true).over([col("g")]).alias("map"),
+                // sorted keys
+                col("v").max().over([col("s")]).alias("sorted_key"),
+                // multiple keys
+                col("v")
+                    .list()
+                    .over([col("g"), col("s")])
+                    .alias("multiple_keys"),
+            ])
+            .collect()
+    };
+    let expected = q(df.agg_chunks())?;
+    let out = q(df)?;
+    assert!(out.frame_equal(&expected));
+    // a column over groups is not rechunked
+    assert_eq!(out.column("column")?.n_chunks(), 3);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-i8", feature = "dtype-u16"))]
+fn test_shrink_dtype() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1i64), None, Some(-3)],
+        "b" => [1u64, 300, 2],
+        "c" => [1.0f64, 2.0, 3.0],
+        "d" => [0.5f64, 0.25, 1.0],
+        "e" => ["x", "y", "z"],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_columns([col("*").shrink_dtype()])
+        .collect()?;
+    assert_eq!(
+        out.dtypes(),
+        &[
+            DataType::Int8,
+            DataType::UInt16,
+            DataType::Float64,
+            DataType::Float64,
+            DataType::Utf8
+        ]
+    );
+
+    let lf = df.lazy().with_columns([col("*").shrink_dtype_with(true)]);
+    let schema = lf.schema()?;
+    assert_eq!(schema.get("c"), Some(&DataType::Unknown));
+    assert_eq!(schema.get("d"), Some(&DataType::Unknown));
+    let out = lf.collect()?;
+    assert_eq!(
+        out.dtypes(),
+        &[
+            DataType::Int8,
+            DataType::UInt16,
+            DataType::Int8,
+            DataType::Float32,
+            DataType::Utf8
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("c")?.i8()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unique_keep_none_slice() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 1, 3, 4, 4],
+        "b" => [1, 2, 3, 4, 5, 6]
+    ]?;
+
+    // the slice is pushed down into the distinct and applies to the kept rows
+    let out = df
+        .lazy()
+        .unique_stable(Some(vec!["a".into()]), UniqueKeepStrategy::None)
+        .slice(1, 5)
+        .collect()?;
+    assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(4)]);
+    Ok(())
+}
+
+#[test]
+fn test_error_context_plan_node() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i64, 1 << 40]
+    ]?;
+    let err = df
+        .lazy()
+        .with_column(col("a").strict_cast(DataType::Int32).alias("b"))
+        .collect()
+        .unwrap_err();
+    assert_eq!(err.plan_node(), Some("hstack"));
+
+    // the plan node is added without changing the variant
+    let io_err = |_: DataFrame| -> PolarsResult<DataFrame> {
+        Err(std::io::Error::from(std::io::ErrorKind::NotFound).into())
+    };
+    let err = df!["a" => [1]]?
+        .lazy()
+        .map(io_err, AllowedOptimizations::default(), None, None)
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::Io(_)), "{err:?}");
+    assert!(err.plan_node().is_some());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_unnest_collision_schema() -> PolarsResult<()> {
+    let a = StructChunked::new("a", &[Series::new("x", [1]), Series::new("y", [2])])?;
+    let b = StructChunked::new("b", &[Series::new("x", [3])])?;
+    let df = DataFrame::new(vec![a.into_series(), b.into_series()])?;
+
+    // the collision is found when the schema is resolved
+    let err = df.clone().lazy().unnest(["a", "b"]).schema().unwrap_err();
+    assert_eq!(err.columns(), &["x"]);
+
+    let q = df
+        .lazy()
+        .unnest_with(["a", "b"], UnnestCollision::Prefix)
+        .select([col("b.x"), col("y")]);
+    let schema = q.schema()?;
+    assert_eq!(schema.iter_names().collect::<Vec<_>>(), &["b.x", "y"]);
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("b.x")?.i32()?), &[Some(3)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "merge_sorted")]
+fn test_lazy_merge_sorted() -> PolarsResult<()> {
+    let left = df![
+        "key" => [1, 3, 3, 6],
+        "side" => ["l", "l", "l", "l"]
+    ]?;
+    let right = df![
+        "key" => [2, 3, 7],
+        "side" => ["r", "r", "r"]
+    ]?;
+    let out = left.lazy().merge_sorted(right.lazy(), "key")?.collect()?;
+    assert_eq!(
+        Vec::from(out.column("key")?.i32()?),
+        &[
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(3),
+            Some(3),
+            Some(6),
+            Some(7)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("side")?.utf8()?),
+        &[
+            Some("l"),
+            Some("r"),
+            Some("l"),
+            Some("l"),
+            Some("r"),
+            Some("l"),
+            Some("r")
+        ]
+    );
+    assert_eq!(
+        out.column("key")?.is_sorted_flag(),
+        polars_core::series::IsSorted::Ascending
+    );
+
+    // the sorted flags don't survive the plan, so the direction is taken from the keys
+    let left = df![
+        "key" => [6, 3, 3, 1],
+        "side" => ["l", "l", "l", "l"]
+    ]?
+    .sort(["key"], true)?;
+    let right = df![
+        "key" => [7, 3, 2],
+        "side" => ["r", "r", "r"]
+    ]?
+    .sort(["key"], true)?;
+    let out = left.lazy().merge_sorted(right.lazy(), "key")?.collect()?;
+    assert_eq!(
+        Vec::from(out.column("key")?.i32()?),
+        &[
+            Some(7),
+            Some(6),
+            Some(3),
+            Some(3),
+            Some(3),
+            Some(2),
+            Some(1)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("side")?.utf8()?),
+        &[
+            Some("r"),
+            Some("l"),
+            Some("l"),
+            Some("l"),
+            Some("r"),
+            Some("r"),
+            Some("l")
+        ]
+    );
+    assert_eq!(
+        out.column("key")?.is_sorted_flag(),
+        polars_core::series::IsSorted::Descending
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_when_then_otherwise_struct() -> PolarsResult<()> {
+    let a = StructChunked::new("a", &[Series::new("x", [1i32, 2, 3])])?;
+    let b = StructChunked::new("b", &[Series::new("x", [1.5f64, 2.5, 3.5])])?;
+    let df = DataFrame::new(vec![
+        a.into_series(),
+        b.into_series(),
+        Series::new("cond", [true, false, true]),
+    ])?;
+
+    let out = df
+        .lazy()
+        .select([
+            when(col("cond"))
+                .then(col("a"))
+                .otherwise(col("b"))
+                .alias("both"),
+            when(col("cond"))
+                .then(col("a"))
+                .otherwise(NULL.lit())
+                .alias("null"),
+        ])
+        .collect()?;
+
+    // the branches are coerced to the supertype of their fields
+    let both = out.column("both")?.struct_()?.fields()[0].clone();
+    assert_eq!(Vec::from(both.f64()?), &[Some(1.0), Some(2.5), Some(3.0)]);
+    let null = out.column("null")?.struct_()?.fields()[0].clone();
+    assert_eq!(Vec::from(null.i32()?), &[Some(1), None, Some(3)]);
+    Ok(())
+}
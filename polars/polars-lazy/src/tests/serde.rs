@@ -0,0 +1,125 @@
+use super::*;
+
+fn roundtrip(q: LazyFrame) -> PolarsResult<()> {
+    let json = q.serialize_json()?;
+    let deserialized = LazyFrame::deserialize_json(&json)?;
+    // serializing again should give the same plan
+    assert_eq!(deserialized.serialize_json()?, json);
+
+    let expected = q.collect()?;
+    let out = deserialized.collect()?;
+    assert!(out.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_serde_roundtrip_in_memory() -> PolarsResult<()> {
+    let df = load_df();
+    let other = df![
+        "b" => ["a", "b", "d"],
+        "d" => [10, 20, 30]
+    ]?;
+
+    roundtrip(
+        df.clone()
+            .lazy()
+            .filter(col("a").gt(lit(1)).and(col("b").neq(lit("c"))))
+            .with_columns([(col("a") * col("c")).alias("ac"), col("a").cumsum(false)])
+            .sort("a", SortOptions::default()),
+    )?;
+    roundtrip(df.clone().lazy().groupby_stable([col("b")]).agg([
+        col("a").sum(),
+        col("c").mean().alias("c_mean"),
+        count(),
+    ]))?;
+    roundtrip(
+        df.clone()
+            .lazy()
+            .left_join(other.lazy(), col("b"), col("b"))
+            .select([col("*").exclude(["c"])])
+            .slice(1, 3),
+    )?;
+    roundtrip(
+        df.lazy()
+            .select([
+                col("a").sum().over([col("b")]).alias("sum_over_b"),
+                when(col("a").lt(lit(3)))
+                    .then(lit("small"))
+                    .otherwise(lit("large"))
+                    .alias("size"),
+            ])
+            .unique_stable(None, UniqueKeepStrategy::First),
+    )
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "parquet"))]
+fn test_serde_roundtrip_scans() -> PolarsResult<()> {
+    roundtrip(
+        scan_foods_csv()
+            .filter(col("fats_g").gt(lit(1.0)))
+            .groupby_stable([col("category")])
+            .agg([col("calories").max()]),
+    )?;
+    roundtrip(
+        scan_foods_parquet(false)
+            .select([col("category"), col("sugars_g")])
+            .filter(col("sugars_g").lt(lit(5)))
+            .sort("category", SortOptions::default()),
+    )?;
+
+    // the scan options survive the roundtrip
+    let q = LazyCsvReader::new(FOODS_CSV)
+        .with_n_rows(Some(10))
+        .with_skip_rows(2)
+        .finish()?;
+    roundtrip(q)
+}
+
+#[test]
+fn test_serde_closures_error() {
+    let df = load_df();
+
+    let q = df
+        .clone()
+        .lazy()
+        .map(Ok, AllowedOptimizations::default(), None, Some("my_udf"));
+    let err = q.serialize_json().unwrap_err().to_string();
+    assert!(err.contains("my_udf"), "{err}");
+
+    let q = df
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .select([col("a").map(|s| Ok(Some(s)), GetOutput::same_type())]);
+    let err = q.serialize_json().unwrap_err().to_string();
+    assert!(err.contains("map"), "{err}");
+}
+
+#[test]
+fn test_serde_registered_expr_fn() -> PolarsResult<()> {
+    let name = "test_serde_add_inputs";
+    register_expr_fn(
+        name,
+        |s: &mut [Series]| Ok(&s[0] + &s[1]),
+        |fields: &[Field]| Ok(fields[0].data_type().clone()),
+    );
+    let q = load_df()
+        .lazy()
+        .select([call_expr_fn(name, [col("a"), col("c")])?.alias("sum")]);
+    assert!(q.describe_plan().contains(name));
+    assert_eq!(q.schema()?.get("sum"), Some(&DataType::Int32));
+    let json = q.serialize_json()?;
+    roundtrip(q)?;
+    let out = LazyFrame::deserialize_json(&json)?.collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(2), Some(4), Some(6), Some(8), Some(10)]
+    );
+
+    // a plan that calls a function that is not registered can't be rehydrated
+    assert!(unregister_expr_fn(name));
+    let err = LazyFrame::deserialize_json(&json).unwrap_err().to_string();
+    assert!(err.contains(name), "{err}");
+    assert!(call_expr_fn(name, [col("a")]).is_err());
+    Ok(())
+}
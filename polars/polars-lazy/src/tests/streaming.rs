@@ -115,6 +115,153 @@ fn test_streaming_aggregate_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_chunk_size() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .select([col("sugars_g"), col("calories")])
+        .groupby([col("sugars_g")])
+        .agg([col("calories").sum()])
+        .sort("sugars_g", Default::default());
+
+    let plan = q
+        .clone()
+        .with_streaming(true)
+        .with_streaming_chunk_size(3)
+        .describe_optimized_plan()?;
+    assert!(plan.contains("chunk size: 3 rows"));
+
+    let out = q
+        .clone()
+        .with_streaming(true)
+        .with_streaming_chunk_size(3)
+        .collect()?;
+    let expected = q.with_streaming(false).collect()?;
+    assert_eq!(out, expected);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_union_csv_parquet() -> PolarsResult<()> {
+    let q = concat([get_csv_file(), get_parquet_file()], false, false)?
+        .filter(col("sugars_g").gt(lit(5)))
+        .select([col("category"), col("calories")]);
+
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_union_reproject() -> PolarsResult<()> {
+    let lf_a = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"]
+    ]?
+    .lazy();
+    let lf_b = df![
+        "b" => ["u", "v"],
+        "a" => [4, 5]
+    ]?
+    .lazy();
+
+    let out = concat([lf_a.clone(), lf_b], false, false)?
+        .filter(col("a").gt(lit(1)))
+        .with_streaming(true)
+        .collect()?;
+    let expected = df![
+        "a" => [2, 3, 4, 5],
+        "b" => ["y", "z", "u", "v"]
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    let lf_c = df![
+        "a" => ["4", "5"],
+        "b" => ["u", "v"]
+    ]?
+    .lazy();
+    let err = concat([lf_a, lf_c], false, false)?
+        .filter(col("a").gt(lit(1)))
+        .with_streaming(true)
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("column 'a'"));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_multiple() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_streaming_sink_multiple");
+    std::fs::create_dir_all(&dir)?;
+    let path_filter = dir.join("filter.parquet");
+    let path_groupby = dir.join("groupby.parquet");
+
+    // the branches have a different selectivity, so they consume at a different pace
+    let q_filter = get_parquet_file().filter(col("calories").gt(lit(100)));
+    let q_groupby = get_parquet_file()
+        .groupby([col("category")])
+        .agg([col("sugars_g").sum()]);
+
+    let sink = |path: &std::path::Path| FileSinkOptions {
+        path: Arc::new(path.to_path_buf()),
+        file_type: FileType::Parquet(ParquetWriteOptions::default()),
+    };
+    LazyFrame::sink_multiple(vec![
+        (q_filter.clone(), sink(&path_filter)),
+        (q_groupby.clone(), sink(&path_groupby)),
+    ])?;
+
+    let out = LazyFrame::scan_parquet(&path_filter, Default::default())?.collect()?;
+    assert_eq!(out, q_filter.collect()?);
+
+    let out = LazyFrame::scan_parquet(&path_groupby, Default::default())?
+        .sort("category", Default::default())
+        .collect()?;
+    let expected = q_groupby.sort("category", Default::default()).collect()?;
+    assert_eq!(out, expected);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_parquet_partitioned() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_sink_parquet_partitioned");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let df = df![
+        "key" => [Some("a"), Some("b"), None, Some("a"), Some("a")],
+        "value" => [1, 2, 3, 4, 5]
+    ]?;
+    let options = ParquetPartitionOptions {
+        write_options: ParquetWriteOptions {
+            maintain_order: true,
+            ..Default::default()
+        },
+        max_rows_per_file: Some(2),
+        ..Default::default()
+    };
+    df.lazy()
+        .sink_parquet_partitioned(dir.clone(), &["key"], options)?;
+
+    let read = |path: std::path::PathBuf| {
+        LazyFrame::scan_parquet(path, Default::default())
+            .unwrap()
+            .collect()
+            .unwrap()
+    };
+    // the partition column is not written to the files
+    let out = read(dir.join("key=a/part-00000.parquet"));
+    assert_eq!(out, df!["value" => [1, 4]]?);
+    let out = read(dir.join("key=a/part-00001.parquet"));
+    assert_eq!(out, df!["value" => [5]]?);
+    let out = read(dir.join("key=b/part-00000.parquet"));
+    assert_eq!(out, df!["value" => [2]]?);
+    let out = read(dir.join("key=__HIVE_DEFAULT_PARTITION__/part-00000.parquet"));
+    assert_eq!(out, df!["value" => [3]]?);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_streaming_cross_join() -> PolarsResult<()> {
@@ -266,6 +413,38 @@ fn test_streaming_partial() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_join_build_side() -> PolarsResult<()> {
+    let lf_left = df![
+        "a" => [0, 1, 2, 1],
+        "b" => [0, 1, 2, 3],
+    ]?
+    .lazy();
+    let lf_right = df![
+        "a" => [1, 2, 3],
+        "c" => [4, 5, 6],
+    ]?
+    .lazy();
+
+    for build_side in [
+        JoinBuildSide::Auto,
+        JoinBuildSide::Left,
+        JoinBuildSide::Right,
+    ] {
+        let q = lf_left
+            .clone()
+            .join_builder()
+            .with(lf_right.clone())
+            .on([col("a")])
+            .how(JoinType::Inner)
+            .build_side(build_side)
+            .finish()
+            .sort("b", Default::default());
+        assert_streaming_with_default(q);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_aggregate_join() -> PolarsResult<()> {
     let q = get_parquet_file();
@@ -330,3 +509,127 @@ fn test_streaming_double_left_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_streaming_sink_ipc_stream() -> PolarsResult<()> {
+    use polars_io::ipc::IpcStreamReader;
+
+    let path = std::env::temp_dir().join("polars_test_sink_ipc_stream.arrows");
+    let df = df![
+        "a" => (0..100).collect::<Vec<i32>>(),
+        "b" => (0..100).map(|v| v.to_string()).collect::<Vec<_>>()
+    ]?;
+    let options = IpcWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    df.clone()
+        .lazy()
+        .filter(col("a").gt(lit(9)))
+        .sink_ipc_stream(std::fs::File::create(&path)?, options)?;
+
+    // the stream is read one batch at a time
+    let batches = IpcStreamReader::new(std::fs::File::open(&path)?)
+        .with_columns(Some(vec!["b".into(), "a".into()]))
+        .batched()?
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let out = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+    let expected = df.filter(&df.column("a")?.gt(9)?)?.select(["b", "a"])?;
+    assert!(out.frame_equal(&expected));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_parquet_compression_level() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_sink_parquet_compression_level");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let df = df![
+        "a" => (0..100_000).map(|i| (i * 7919 % 10007).to_string()).collect::<Vec<_>>()
+    ]?;
+    let sink = |level: i32| -> PolarsResult<u64> {
+        let path = dir.join(format!("{level}.parquet"));
+        let options = ParquetWriteOptions {
+            compression: ParquetCompression::Zstd(Some(ZstdLevel::try_new(level)?)),
+            ..Default::default()
+        };
+        df.clone().lazy().sink_parquet(path.clone(), options)?;
+        let out = LazyFrame::scan_parquet(&path, Default::default())?
+            .sort("a", Default::default())
+            .collect()?;
+        assert_eq!(out, df.sort(["a"], false)?);
+        Ok(std::fs::metadata(&path)?.len())
+    };
+    assert!(sink(19)? < sink(1)?);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_cache() -> PolarsResult<()> {
+    // cached parts of the query are executed by the default engine
+    let (lf, n_scans) = counting_scan(fruits_cars());
+    let cached = lf.cache();
+    let q = cached
+        .clone()
+        .filter(col("A").gt(lit(2)))
+        .inner_join(
+            cached.select([col("fruits"), col("B").alias("B_right")]),
+            col("fruits"),
+            col("fruits"),
+        )
+        .sort_by_exprs([col("A"), col("B_right")], [false, false], false);
+
+    assert_streaming_with_default(q);
+    // once for the streaming and once for the default engine
+    assert_eq!(n_scans.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_streaming_profile() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .groupby([col("category")])
+        .agg([col("calories").sum()])
+        .with_streaming(true);
+
+    let plan = q.describe_optimized_plan()?;
+    let (out, profile) = q.profile()?;
+
+    // the streaming part of the query is reported as a single node
+    let streaming = profile.filter(&profile.column("node")?.equal("streaming")?)?;
+    assert_eq!(streaming.height(), 1);
+    let node_id = streaming.column("node_id")?.u32()?.get(0).unwrap();
+    assert!(plan.contains(&format!("[{node_id}] --- PIPELINE")));
+    assert_eq!(
+        streaming.column("rows")?.get(0)?,
+        AnyValue::UInt64(out.height() as u64)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sort_memory_budget() -> PolarsResult<()> {
+    // the row count makes the sort keys unique, so the order is deterministic
+    let q = get_csv_file().with_row_count("idx", None).sort_by_exprs(
+        [col("category"), col("calories"), col("idx")],
+        [true, false, false],
+        true,
+    );
+
+    // a tiny budget forces the sort to spill many times
+    let out = q
+        .clone()
+        .with_streaming(true)
+        .with_streaming_chunk_size(3)
+        .with_streaming_sort_memory_budget(64)
+        .collect()?;
+    let expected = q.with_streaming(false).collect()?;
+    assert_eq!(out, expected);
+    Ok(())
+}
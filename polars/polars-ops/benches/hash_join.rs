@@ -0,0 +1,41 @@
+//! Hash joins of a small and a large input in both orders. The hash table is built on the small
+//! input and split into few partitions, so both orders should take about the same time.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polars_core::prelude::*;
+use polars_ops::prelude::*;
+
+const SMALL: usize = 1_000;
+const LARGE: usize = 100_000_000;
+
+fn frame(len: usize) -> DataFrame {
+    // every key of the small input is found in the large input
+    let key = Int64Chunked::from_iter_values("key", (0..len as i64).map(|i| i % SMALL as i64));
+    let value = Int64Chunked::from_iter_values("value", 0..len as i64);
+    DataFrame::new(vec![key.into_series(), value.into_series()]).unwrap()
+}
+
+fn bench_hash_join(c: &mut Criterion) {
+    let small = frame(SMALL);
+    let large = frame(LARGE);
+
+    let mut group = c.benchmark_group("hash_join");
+    group.sample_size(10);
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Outer] {
+        for (name, left, right) in [("1k x 100M", &small, &large), ("100M x 1k", &large, &small)] {
+            group.bench_with_input(
+                BenchmarkId::new(how.to_string(), name),
+                &(left, right),
+                |b, (left, right)| {
+                    b.iter(|| {
+                        left.join_with_args(right, ["key"], ["key"], JoinArgs::new(how.clone()))
+                            .unwrap()
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_hash_join);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+//! The largest values of a large input, selected by `top_k` and by a full sort followed by a
+//! `head`. `top_k` only keeps `k` values per chunk, so it should be much faster.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polars_core::prelude::*;
+use polars_ops::prelude::*;
+
+const LEN: usize = 100_000_000;
+const K: usize = 10;
+
+fn bench_top_k(c: &mut Criterion) {
+    // a permutation of the values, so the input isn't sorted
+    let s = Int64Chunked::from_iter_values(
+        "values",
+        (0..LEN as u64).map(|i| (i * 2_654_435_761 % LEN as u64) as i64),
+    )
+    .into_series();
+
+    let mut group = c.benchmark_group("top_k");
+    group.sample_size(10);
+    group.bench_with_input(BenchmarkId::new("top_k", K), &s, |b, s| {
+        b.iter(|| s.top_k(K).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("sort_head", K), &s, |b, s| {
+        b.iter(|| s.sort(true).head(Some(K)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_top_k);
+criterion_main!(benches);
@@ -1,19 +1,73 @@
 #[cfg(feature = "binary_encoding")]
 use std::borrow::Cow;
 
+use arrow::array::{Array, BinaryArray, UInt32Array};
+use arrow::buffer::Buffer;
 #[cfg(feature = "binary_encoding")]
 use base64::engine::general_purpose;
 #[cfg(feature = "binary_encoding")]
 use base64::Engine as _;
-use memchr::memmem::find;
+use memchr::memmem::{find, Finder};
+use polars_arrow::trusted_len::PushUnchecked;
+use polars_core::utils::CustomIterTools;
 
 use super::*;
 
+fn binary_size(array: &BinaryArray<i64>) -> ArrayRef {
+    let values = array
+        .offsets()
+        .as_slice()
+        .windows(2)
+        .map(|x| (x[1] - x[0]) as u32);
+    let values: Buffer<_> = Vec::from_trusted_len_iter(values).into();
+    let array = UInt32Array::new(ArrowDataType::UInt32, values, array.validity().cloned());
+    Box::new(array)
+}
+
+/// Apply `f` on the rows of `ca` and `other`, broadcasting `other` if it has a single value.
+/// The result is null if either value is null.
+fn binary_elementwise<F>(
+    ca: &BinaryChunked,
+    other: &BinaryChunked,
+    f: F,
+) -> PolarsResult<BooleanChunked>
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    let mut out: BooleanChunked = match other.len() {
+        1 => match other.get(0) {
+            Some(other) => ca
+                .into_iter()
+                .map(|opt_s| opt_s.map(|s| f(s, other)))
+                .collect(),
+            None => BooleanChunked::full_null(ca.name(), ca.len()),
+        },
+        len => {
+            polars_ensure!(
+                len == ca.len(),
+                ShapeMismatch: "expected a binary value or column of length {}, got length {}",
+                ca.len(), len
+            );
+            ca.into_iter()
+                .zip(other.into_iter())
+                .map(|(opt_s, opt_other)| match (opt_s, opt_other) {
+                    (Some(s), Some(other)) => Some(f(s, other)),
+                    _ => None,
+                })
+                .collect_trusted()
+        }
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
+
 pub trait BinaryNameSpaceImpl: AsBinary {
     /// Check if binary contains given literal
     fn contains(&self, lit: &[u8]) -> PolarsResult<BooleanChunked> {
         let ca = self.as_binary();
-        let f = |s: &[u8]| find(s, lit).is_some();
+        // build the searcher once for all values
+        let finder = Finder::new(lit);
+        let f = |s: &[u8]| finder.find(s).is_some();
         let mut out: BooleanChunked = if !ca.has_validity() {
             ca.into_no_null_iter().map(f).collect()
         } else {
@@ -46,6 +100,33 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         out
     }
 
+    /// Check if binary values contain the value in the same row of `lit`, or its single value.
+    fn contains_chunked(&self, lit: &BinaryChunked) -> PolarsResult<BooleanChunked> {
+        if lit.len() == 1 {
+            if let Some(lit) = lit.get(0) {
+                return self.contains(lit);
+            }
+        }
+        binary_elementwise(self.as_binary(), lit, |s, lit| find(s, lit).is_some())
+    }
+
+    /// Check if binary values end with the value in the same row of `sub`, or its single value.
+    fn ends_with_chunked(&self, sub: &BinaryChunked) -> PolarsResult<BooleanChunked> {
+        binary_elementwise(self.as_binary(), sub, |s, sub| s.ends_with(sub))
+    }
+
+    /// Check if binary values start with the value in the same row of `sub`, or its single
+    /// value.
+    fn starts_with_chunked(&self, sub: &BinaryChunked) -> PolarsResult<BooleanChunked> {
+        binary_elementwise(self.as_binary(), sub, |s, sub| s.starts_with(sub))
+    }
+
+    /// Get the size of the binary values in bytes.
+    fn size(&self) -> UInt32Chunked {
+        let ca = self.as_binary();
+        ca.apply_kernel_cast(&binary_size)
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn hex_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_binary();
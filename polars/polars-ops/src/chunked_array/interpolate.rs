@@ -168,7 +168,7 @@ where
 fn interpolate_nearest(s: &Series) -> Series {
     match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical(_) => s.clone(),
+        DataType::Categorical(_, _) => s.clone(),
         DataType::Binary => s.clone(),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(_) => s.clone(),
@@ -191,7 +191,7 @@ fn interpolate_nearest(s: &Series) -> Series {
 fn interpolate_linear(s: &Series) -> Series {
     match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical(_) => s.clone(),
+        DataType::Categorical(_, _) => s.clone(),
         DataType::Binary => s.clone(),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(_) => s.clone(),
@@ -249,6 +249,75 @@ pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
     }
 }
 
+/// Interpolate the null values of `s` at the x-coordinates given by `by`, so that
+/// irregularly spaced samples are interpolated by their distance instead of by their position.
+///
+/// `by` must be numeric or temporal, without null values and strictly increasing. Leading and
+/// trailing null values are not extrapolated. The output is `Float32` if `s` is `Float32`
+/// and `Float64` otherwise.
+pub fn interpolate_by(
+    s: &Series,
+    by: &Series,
+    method: InterpolationMethod,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "`interpolate_by` operation not supported for dtype `{}`", s.dtype()
+    );
+    polars_ensure!(
+        by.to_physical_repr().dtype().is_numeric(),
+        InvalidOperation: "`interpolate_by` expected a numeric or temporal `by`, got dtype `{}`",
+        by.dtype()
+    );
+    polars_ensure!(
+        s.len() == by.len(),
+        ShapeMismatch: "`interpolate_by` expected `by` of length {}, got {}", s.len(), by.len()
+    );
+    polars_ensure!(
+        by.null_count() == 0,
+        ComputeError: "`interpolate_by` expected `by` without null values"
+    );
+    let by = by.to_physical_repr().cast(&DataType::Float64)?;
+    let x = by.f64()?.rechunk();
+    let x = x.cont_slice()?;
+    polars_ensure!(
+        x.windows(2).all(|w| w[0] < w[1]),
+        ComputeError: "`interpolate_by` expected `by` to be strictly increasing"
+    );
+
+    let out_dtype = match s.dtype() {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let y = s.cast(&DataType::Float64)?;
+    let mut out = y.f64()?.into_iter().collect::<Vec<_>>();
+
+    let mut low = None;
+    for high in 0..out.len() {
+        let Some(y_high) = out[high] else { continue };
+        if let Some(low) = low {
+            let y_low = out[low].unwrap();
+            let (x_low, x_high) = (x[low], x[high]);
+            let gap = low + 1..high;
+            for (y, &x) in out[gap.clone()].iter_mut().zip(&x[gap]) {
+                *y = Some(match method {
+                    InterpolationMethod::Linear => {
+                        y_low + (y_high - y_low) * (x - x_low) / (x_high - x_low)
+                    }
+                    // ties go to the high value, like `interpolate`
+                    InterpolationMethod::Nearest if x_high - x > x - x_low => y_low,
+                    InterpolationMethod::Nearest => y_high,
+                });
+            }
+        }
+        low = Some(high);
+    }
+
+    let mut out = out.into_iter().collect::<Float64Chunked>();
+    out.rename(s.name());
+    out.into_series().cast(&out_dtype)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -337,4 +406,33 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_interpolate_by() -> PolarsResult<()> {
+        let s = Series::new("a", [None, Some(1i32), None, None, Some(7), None]);
+        let by = Series::new("by", [0i64, 1, 2, 5, 7, 8]);
+        let out = interpolate_by(&s, &by, InterpolationMethod::Linear)?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[None, Some(1.0), Some(2.0), Some(5.0), Some(7.0), None]
+        );
+        let out = interpolate_by(&s, &by, InterpolationMethod::Nearest)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[None, Some(1.0), Some(1.0), Some(7.0), Some(7.0), None]
+        );
+
+        let s = Series::new("a", [Some(1.0f32), None, Some(3.0)]);
+        let out = interpolate_by(
+            &s,
+            &Series::new("by", [0i32, 3, 4]),
+            InterpolationMethod::Linear,
+        )?;
+        assert_eq!(Vec::from(out.f32()?), &[Some(1.0), Some(2.5), Some(3.0)]);
+
+        let by = Series::new("by", [0i32, 3, 3]);
+        assert!(interpolate_by(&s, &by, InterpolationMethod::Linear).is_err());
+        Ok(())
+    }
 }
@@ -10,7 +10,7 @@ use polars_core::export::num::ToPrimitive;
 use polars_core::export::num::{NumCast, Signed, Zero};
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
-use polars_core::utils::{try_get_supertype, CustomIterTools};
+use polars_core::utils::try_get_supertype;
 
 use super::*;
 use crate::chunked_array::list::min_max::{list_max_function, list_min_function};
@@ -86,7 +86,7 @@ pub trait ListNameSpaceImpl: AsList {
                     ca.get_values_size() + separator.len() * ca.len(),
                 );
 
-                ca.amortized_iter().for_each(|opt_s| {
+                ca.for_each_amortized(|opt_s| {
                     let opt_val = opt_s.map(|s| {
                         // make sure that we don't write values of previous iteration
                         buf.clear();
@@ -117,69 +117,55 @@ pub trait ListNameSpaceImpl: AsList {
         list_min_function(self.as_list())
     }
 
-    fn lst_sum(&self) -> Series {
-        fn inner(ca: &ListChunked, inner_dtype: &DataType) -> Series {
+    fn lst_sum(&self) -> PolarsResult<Series> {
+        fn inner(ca: &ListChunked, inner_dtype: &DataType) -> PolarsResult<Series> {
             use DataType::*;
             // TODO: add fast path for smaller ints?
             let mut out = match inner_dtype {
                 Boolean => {
-                    let out: IdxCa = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().sum()))
-                        .collect();
+                    let out: IdxCa =
+                        ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().sum()));
                     out.into_series()
                 }
                 UInt32 => {
-                    let out: UInt32Chunked = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().sum()))
-                        .collect();
+                    let out: UInt32Chunked =
+                        ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().sum()));
                     out.into_series()
                 }
                 UInt64 => {
-                    let out: UInt64Chunked = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().sum()))
-                        .collect();
+                    let out: UInt64Chunked =
+                        ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().sum()));
                     out.into_series()
                 }
                 Int32 => {
-                    let out: Int32Chunked = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().sum()))
-                        .collect();
+                    let out: Int32Chunked =
+                        ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().sum()));
                     out.into_series()
                 }
                 Int64 => {
-                    let out: Int64Chunked = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().sum()))
-                        .collect();
+                    let out: Int64Chunked =
+                        ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().sum()));
                     out.into_series()
                 }
                 Float32 => {
-                    let out: Float32Chunked = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().sum()))
-                        .collect();
+                    let out: Float32Chunked =
+                        ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().sum()));
                     out.into_series()
                 }
                 Float64 => {
-                    let out: Float64Chunked = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().sum()))
-                        .collect();
+                    let out: Float64Chunked =
+                        ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().sum()));
                     out.into_series()
                 }
                 // slowest sum_as_series path
                 _ => ca
-                    .apply_amortized(|s| s.as_ref().sum_as_series())
+                    .try_apply_amortized(|s| s.as_ref().sum_as_series())?
                     .explode()
                     .unwrap()
                     .into_series(),
             };
             out.rename(ca.name());
-            out
+            Ok(out)
         }
 
         let ca = self.as_list();
@@ -189,20 +175,16 @@ pub trait ListNameSpaceImpl: AsList {
         };
 
         match ca.inner_dtype() {
-            DataType::Boolean => count_boolean_bits(ca).into_series(),
-            dt if dt.is_numeric() => sum_list_numerical(ca, &dt),
+            DataType::Boolean => Ok(count_boolean_bits(ca).into_series()),
+            dt if dt.is_numeric() => Ok(sum_list_numerical(ca, &dt)),
             dt => inner(ca, &dt),
         }
     }
 
     fn lst_mean(&self) -> Series {
         fn inner(ca: &ListChunked) -> Series {
-            let mut out: Float64Chunked = ca
-                .amortized_iter()
-                .map(|s| s.and_then(|s| s.as_ref().mean()))
-                .collect();
-
-            out.rename(ca.name());
+            let out: Float64Chunked =
+                ca.apply_amortized_generic(|s| s.and_then(|s| s.as_ref().mean()));
             out.into_series()
         }
         use DataType::*;
@@ -212,12 +194,9 @@ pub trait ListNameSpaceImpl: AsList {
         if has_inner_nulls(ca) {
             return match ca.inner_dtype() {
                 Float32 => {
-                    let mut out: Float32Chunked = ca
-                        .amortized_iter()
-                        .map(|s| s.and_then(|s| s.as_ref().mean().map(|v| v as f32)))
-                        .collect();
-
-                    out.rename(ca.name());
+                    let out: Float32Chunked = ca.apply_amortized_generic(|s| {
+                        s.and_then(|s| s.as_ref().mean().map(|v| v as f32))
+                    });
                     out.into_series()
                 }
                 _ => inner(ca),
@@ -254,22 +233,16 @@ pub trait ListNameSpaceImpl: AsList {
 
     fn lst_arg_min(&self) -> IdxCa {
         let ca = self.as_list();
-        let mut out: IdxCa = ca
-            .amortized_iter()
-            .map(|opt_s| opt_s.and_then(|s| s.as_ref().arg_min().map(|idx| idx as IdxSize)))
-            .collect_trusted();
-        out.rename(ca.name());
-        out
+        ca.apply_amortized_generic(|opt_s| {
+            opt_s.and_then(|s| s.as_ref().arg_min().map(|idx| idx as IdxSize))
+        })
     }
 
     fn lst_arg_max(&self) -> IdxCa {
         let ca = self.as_list();
-        let mut out: IdxCa = ca
-            .amortized_iter()
-            .map(|opt_s| opt_s.and_then(|s| s.as_ref().arg_max().map(|idx| idx as IdxSize)))
-            .collect_trusted();
-        out.rename(ca.name());
-        out
+        ca.apply_amortized_generic(|opt_s| {
+            opt_s.and_then(|s| s.as_ref().arg_max().map(|idx| idx as IdxSize))
+        })
     }
 
     #[cfg(feature = "diff")]
@@ -324,42 +297,27 @@ pub trait ListNameSpaceImpl: AsList {
         let index_typed_index = |idx: &Series| {
             let idx = idx.cast(&IDX_DTYPE).unwrap();
             list_ca
-                .amortized_iter()
-                .map(|s| {
+                .try_apply_amortized_generic(|s| {
                     s.map(|s| {
                         let s = s.as_ref();
                         take_series(s, idx.clone(), null_on_oob)
                     })
                     .transpose()
                 })
-                .collect::<PolarsResult<ListChunked>>()
-                .map(|mut ca| {
-                    ca.rename(list_ca.name());
-                    ca.into_series()
-                })
+                .map(|ca: ListChunked| ca.into_series())
         };
 
         use DataType::*;
         match idx.dtype() {
             List(_) => {
                 let idx_ca = idx.list().unwrap();
-                let mut out = list_ca
-                    .amortized_iter()
-                    .zip(idx_ca.into_iter())
-                    .map(|(opt_s, opt_idx)| {
-                        {
-                            match (opt_s, opt_idx) {
-                                (Some(s), Some(idx)) => {
-                                    Some(take_series(s.as_ref(), idx, null_on_oob))
-                                }
-                                _ => None,
-                            }
-                        }
-                        .transpose()
-                    })
-                    .collect::<PolarsResult<ListChunked>>()?;
-                out.rename(list_ca.name());
-
+                let out = list_ca.try_zip_and_apply_amortized(idx_ca, |opt_s, opt_idx| {
+                    match (opt_s, opt_idx) {
+                        (Some(s), Some(idx)) => Some(take_series(s.as_ref(), idx, null_on_oob)),
+                        _ => None,
+                    }
+                    .transpose()
+                })?;
                 Ok(out.into_series())
             }
             UInt32 | UInt64 => index_typed_index(idx),
@@ -368,15 +326,11 @@ pub trait ListNameSpaceImpl: AsList {
                     if min >= 0 {
                         index_typed_index(idx)
                     } else {
-                        let mut out = list_ca
-                            .amortized_iter()
-                            .map(|opt_s| {
-                                opt_s
-                                    .map(|s| take_series(s.as_ref(), idx.clone(), null_on_oob))
-                                    .transpose()
-                            })
-                            .collect::<PolarsResult<ListChunked>>()?;
-                        out.rename(list_ca.name());
+                        let out: ListChunked = list_ca.try_apply_amortized_generic(|opt_s| {
+                            opt_s
+                                .map(|s| take_series(s.as_ref(), idx.clone(), null_on_oob))
+                                .transpose()
+                        })?;
                         Ok(out.into_series())
                     }
                 } else {
@@ -399,14 +353,14 @@ pub trait ListNameSpaceImpl: AsList {
                 DataType::List(inner_type) => {
                     inner_super_type = try_get_supertype(&inner_super_type, inner_type)?;
                     #[cfg(feature = "dtype-categorical")]
-                    if let DataType::Categorical(_) = &inner_super_type {
+                    if let DataType::Categorical(_, _) = &inner_super_type {
                         inner_super_type = merge_dtypes(&inner_super_type, inner_type)?;
                     }
                 }
                 dt => {
                     inner_super_type = try_get_supertype(&inner_super_type, dt)?;
                     #[cfg(feature = "dtype-categorical")]
-                    if let DataType::Categorical(_) = &inner_super_type {
+                    if let DataType::Categorical(_, _) = &inner_super_type {
                         inner_super_type = merge_dtypes(&inner_super_type, dt)?;
                     }
                 }
@@ -477,7 +431,9 @@ pub trait ListNameSpaceImpl: AsList {
             let mut iters = Vec::with_capacity(other_len + 1);
 
             for s in other.iter_mut() {
-                iters.push(s.list()?.amortized_iter())
+                // Safety:
+                // the items are appended to `acc` in the loop below and not kept
+                iters.push(unsafe { s.list()?.amortized_iter() })
             }
             let mut first_iter = ca.into_iter();
             let mut builder = get_list_builder(
@@ -1,10 +1,11 @@
 use std::cmp::Ordering;
 
-use either::Either;
 use polars_arrow::kernels::rolling::compare_fn_nan_max;
 use polars_core::downcast_as_macro_arg_physical;
-use polars_core::prelude::sort::{sort_slice_ascending, sort_slice_descending};
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+
+use crate::series::SeriesSealed;
 
 #[repr(transparent)]
 struct Compare<T>(T);
@@ -31,51 +32,116 @@ impl<T: PartialOrd + IsFloat> Ord for Compare<T> {
     }
 }
 
-fn arg_partition<T: IsFloat + PartialOrd>(v: &mut [T], k: usize, descending: bool) -> &[T] {
-    let (lower, _el, upper) = v.select_nth_unstable_by(k, |a, b| compare_fn_nan_max(a, b));
+/// Keep the `k` largest values of `v`, or the `k` smallest if `descending`, in arbitrary order.
+fn select_k<T, F>(v: &mut Vec<T>, k: usize, descending: bool, cmp: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if k >= v.len() {
+        return;
+    }
     if descending {
-        sort_slice_ascending(lower);
-        lower
+        if k > 0 {
+            v.select_nth_unstable_by(k - 1, cmp);
+        }
+        v.truncate(k);
     } else {
-        sort_slice_descending(upper);
-        upper
+        let offset = v.len() - k;
+        v.select_nth_unstable_by(offset, cmp);
+        v.drain(..offset);
     }
 }
 
+/// Sort `v` largest first, or smallest first if `descending`.
+fn sort_k<T, F>(v: &mut [T], descending: bool, mut cmp: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if descending {
+        v.sort_unstable_by(cmp)
+    } else {
+        v.sort_unstable_by(|a, b| cmp(b, a))
+    }
+}
+
+/// The `k` largest values, largest first, or the `k` smallest values, smallest first, if
+/// `descending`. Nulls are ignored.
+///
+/// Every chunk is reduced to its `k` candidates with a partial select, the candidates of
+/// all chunks are then reduced and sorted.
 fn top_k_impl<T>(ca: &ChunkedArray<T>, k: usize, descending: bool) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
-    ChunkedArray<T>: ChunkSort<T>,
 {
-    if k >= ca.len() {
-        return ca.sort(!descending);
+    let mut candidates = Vec::with_capacity(std::cmp::min(k * ca.chunks().len(), ca.len()));
+    for arr in ca.downcast_iter() {
+        let mut v = if arr.null_count() == 0 {
+            arr.values().to_vec()
+        } else {
+            arr.iter().flatten().copied().collect()
+        };
+        select_k(&mut v, k, descending, compare_fn_nan_max);
+        candidates.extend(v);
     }
+    select_k(&mut candidates, k, descending, compare_fn_nan_max);
+    sort_k(&mut candidates, descending, compare_fn_nan_max);
 
-    // descending is opposite from sort as top-k returns largest
-    let k = if descending {
-        std::cmp::min(k, ca.len())
+    let mut out = ChunkedArray::from_vec(ca.name(), candidates);
+    out.set_sorted_flag(if descending {
+        IsSorted::Ascending
     } else {
-        ca.len().saturating_sub(k + 1)
-    };
+        IsSorted::Descending
+    });
+    out
+}
 
-    match ca.to_vec_null_aware() {
-        Either::Left(mut v) => {
-            let values = arg_partition(&mut v, k, descending);
-            ChunkedArray::from_slice(ca.name(), values)
-        }
-        Either::Right(mut v) => {
-            let values = arg_partition(&mut v, k, descending);
-            let mut out = ChunkedArray::from_iter(values.iter().copied());
-            out.rename(ca.name());
-            out
-        }
+/// The indices of the `k` largest values, or of the `k` smallest values if `descending`.
+/// Nulls are ignored.
+///
+/// If `sorted`, the indices are ordered by their value, largest first or smallest first if
+/// `descending`; otherwise they are in arbitrary order.
+fn top_k_idx_impl<T>(ca: &ChunkedArray<T>, k: usize, descending: bool, sorted: bool) -> IdxCa
+where
+    T: PolarsNumericType,
+{
+    let cmp = |a: &(IdxSize, T::Native), b: &(IdxSize, T::Native)| compare_fn_nan_max(&a.1, &b.1);
+
+    let mut candidates = Vec::with_capacity(std::cmp::min(k * ca.chunks().len(), ca.len()));
+    let mut offset = 0;
+    for arr in ca.downcast_iter() {
+        let mut v = arr
+            .iter()
+            .enumerate()
+            .filter_map(|(i, opt_v)| opt_v.map(|v| ((offset + i) as IdxSize, *v)))
+            .collect::<Vec<_>>();
+        select_k(&mut v, k, descending, cmp);
+        candidates.extend(v);
+        offset += arr.len();
     }
+    select_k(&mut candidates, k, descending, cmp);
+    if sorted {
+        sort_k(&mut candidates, descending, cmp);
+    }
+    IdxCa::from_vec("", candidates.into_iter().map(|(idx, _)| idx).collect())
+}
+
+fn check_numeric(s: &Series, name: &str) -> PolarsResult<()> {
+    // decimals are numeric, but have no kernels
+    let dtype = s.dtype().to_physical();
+    polars_ensure!(
+        dtype.is_integer() || dtype.is_float(),
+        InvalidOperation: "`{}` operation not supported for dtype `{}`", name, s.dtype()
+    );
+    Ok(())
 }
 
+/// The `k` largest values of `s`, largest first, or the `k` smallest values, smallest first,
+/// if `descending`.
+///
+/// Nulls are ignored, so fewer than `k` values are returned if `s` has fewer than `k`
+/// non-null values.
 pub fn top_k(s: &Series, k: usize, descending: bool) -> PolarsResult<Series> {
-    if s.is_empty() {
-        return Ok(s.clone());
-    }
+    check_numeric(s, "top_k")?;
     let dtype = s.dtype();
 
     let s = s.to_physical_repr();
@@ -88,3 +154,128 @@ pub fn top_k(s: &Series, k: usize, descending: bool) -> PolarsResult<Series> {
 
     downcast_as_macro_arg_physical!(&s, dispatch).cast(dtype)
 }
+
+/// The values of `s[0]` in the rows of the `k` largest values of `s[1]`, ordered by `s[1]`
+/// from largest to smallest, or of the `k` smallest values if `descending`.
+///
+/// Rows where `s[1]` is null are ignored.
+pub fn top_k_by(s: &[Series], k: usize, descending: bool) -> PolarsResult<Series> {
+    let (src, by) = (&s[0], &s[1]);
+    check_numeric(by, "top_k_by")?;
+    polars_ensure!(
+        src.len() == by.len(),
+        ShapeMismatch: "`top_k_by` expected `by` of length {}, got {}", src.len(), by.len()
+    );
+    let by = by.to_physical_repr();
+
+    macro_rules! dispatch {
+        ($ca:expr) => {{
+            top_k_idx_impl($ca, k, descending, true)
+        }};
+    }
+
+    let idx = downcast_as_macro_arg_physical!(&by, dispatch);
+    // Safety:
+    // the indices are in bounds of `by`, which has the length of `src`
+    unsafe { src.take_unchecked(&idx) }
+}
+
+/// The indices of the `k` smallest values of `s`, or of the `k` largest values if
+/// `descending`.
+///
+/// Nulls are ignored, so fewer than `k` indices are returned if `s` has fewer than `k`
+/// non-null values. The indices are in arbitrary order, unless `sorted` is set, in which
+/// case they are ordered by their value.
+pub fn arg_partition(s: &Series, k: usize, descending: bool, sorted: bool) -> PolarsResult<IdxCa> {
+    check_numeric(s, "arg_partition")?;
+    let s = s.to_physical_repr();
+
+    macro_rules! dispatch {
+        ($ca:expr) => {{
+            top_k_idx_impl($ca, k, !descending, sorted)
+        }};
+    }
+
+    let mut idx = downcast_as_macro_arg_physical!(&s, dispatch);
+    idx.rename(s.name());
+    Ok(idx)
+}
+
+/// Top-k operations on a [`Series`].
+pub trait TopKSeries: SeriesSealed {
+    /// The `k` largest non-null values, largest first.
+    fn top_k(&self, k: usize) -> PolarsResult<Series> {
+        top_k(self.as_series(), k, false)
+    }
+
+    /// The `k` smallest non-null values, smallest first.
+    fn bottom_k(&self, k: usize) -> PolarsResult<Series> {
+        top_k(self.as_series(), k, true)
+    }
+
+    /// The indices of the `k` smallest non-null values, or of the `k` largest if
+    /// `descending`. See [`arg_partition`].
+    fn arg_partition(&self, k: usize, descending: bool, sorted: bool) -> PolarsResult<IdxCa> {
+        arg_partition(self.as_series(), k, descending, sorted)
+    }
+}
+
+impl TopKSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_top_k() -> PolarsResult<()> {
+        let mut s = Series::new("a", [Some(3i32), None, Some(1), Some(5), Some(4), None]);
+        let tail = s.slice(0, 3);
+        s.append(&tail)?;
+        assert_eq!(s.n_chunks(), 2);
+
+        let out = s.top_k(3)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(5), Some(4), Some(3)]);
+        let out = s.bottom_k(2)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(1)]);
+        // nulls are ignored
+        let out = s.top_k(100)?;
+        assert_eq!(out.len(), 6);
+        assert_eq!(out.null_count(), 0);
+        assert_eq!(s.top_k(0)?.len(), 0);
+
+        let by = Series::new(
+            "by",
+            [Some(2.0f64), Some(9.0), None, Some(f64::NAN), Some(1.0)],
+        );
+        let src = Series::new("src", ["a", "b", "c", "d", "e"]);
+        let out = top_k_by(&[src.clone(), by.clone()], 2, false)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("d"), Some("b")]);
+        let out = top_k_by(&[src, by.clone()], 10, true)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("e"), Some("a"), Some("b"), Some("d")]
+        );
+
+        let idx = s.arg_partition(2, false, false)?.sort(false);
+        assert_eq!(Vec::from(&idx), &[Some(2), Some(8)]);
+        let idx = by.arg_partition(3, false, true)?;
+        assert_eq!(Vec::from(&idx), &[Some(4), Some(0), Some(1)]);
+        let idx = by.arg_partition(2, true, true)?;
+        assert_eq!(Vec::from(&idx), &[Some(3), Some(1)]);
+        assert_eq!(s.arg_partition(100, false, false)?.len(), 6);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_top_k_and_arg_partition_decimal() -> PolarsResult<()> {
+        let s = Series::new("a", [1i64, 2]).cast(&DataType::Decimal(Some(10), Some(2)))?;
+        assert!(matches!(s.top_k(1), Err(PolarsError::InvalidOperation(_))));
+        let src = Series::new("src", ["a", "b"]);
+        let out = top_k_by(&[src, s.clone()], 1, false);
+        assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+        let out = s.arg_partition(1, false, false);
+        assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+        Ok(())
+    }
+}
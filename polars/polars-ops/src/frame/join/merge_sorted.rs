@@ -1,7 +1,20 @@
+use std::cmp::Ordering;
+
+use polars_arrow::data_types::IsFloat;
+use polars_arrow::kernels::rolling::compare_fn_nan_max;
 use polars_arrow::utils::{CustomIterTools, FromTrustedLenIterator};
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::with_match_physical_numeric_polars_type;
 
+/// Merge `left` and `right`, that are both sorted by `left_s` and `right_s`, into a frame that
+/// is sorted by these keys.
+///
+/// The direction of the keys is taken from their sorted flags, or else determined from their
+/// values. The keys are merged in descending order if one of them is sorted descending, and in
+/// ascending order otherwise. Errors if a key is not sorted or if the keys are sorted in different
+/// directions. The nulls are placed where the keys have them, before or after the valid values.
+/// Equal keys take the rows of `left` first.
 pub fn _merge_sorted_dfs(
     left: &DataFrame,
     right: &DataFrame,
@@ -20,7 +33,25 @@ pub fn _merge_sorted_dfs(
         ComputeError: "merge-sort datatype mismatch: {} != {}", dtype_lhs, dtype_rhs
     );
 
-    let merge_indicator = series_to_merge_indicator(left_s, right_s);
+    let descending = match (sort_direction(left_s)?, sort_direction(right_s)?) {
+        (IsSorted::Ascending, IsSorted::Descending)
+        | (IsSorted::Descending, IsSorted::Ascending) => {
+            polars_bail!(ComputeError: "cannot merge keys that are sorted in different directions")
+        }
+        (lhs, rhs) => lhs == IsSorted::Descending || rhs == IsSorted::Descending,
+    };
+    let nulls_last = match (nulls_last(left_s), nulls_last(right_s)) {
+        (Some(lhs), Some(rhs)) => {
+            polars_ensure!(
+                lhs == rhs,
+                ComputeError: "cannot merge keys that have their nulls on different sides"
+            );
+            lhs
+        }
+        (lhs, rhs) => lhs.or(rhs).unwrap_or(false),
+    };
+
+    let merge_indicator = series_to_merge_indicator(left_s, right_s, descending, nulls_last);
     let new_columns = left
         .get_columns()
         .iter()
@@ -32,6 +63,13 @@ pub fn _merge_sorted_dfs(
             let out = merge_series(&lhs_phys, &rhs_phys, &merge_indicator);
             let mut out = out.cast(lhs.dtype()).unwrap();
             out.rename(lhs.name());
+            if lhs.name() == left_s.name() {
+                out.set_sorted_flag(if descending {
+                    IsSorted::Descending
+                } else {
+                    IsSorted::Ascending
+                });
+            }
             out
         })
         .collect();
@@ -39,6 +77,76 @@ pub fn _merge_sorted_dfs(
     Ok(DataFrame::new_no_checks(new_columns))
 }
 
+/// The direction `s` is sorted in, from its sorted flag or else from its values, or
+/// [`IsSorted::Not`] if its valid values are all equal, which are sorted in both directions.
+fn sort_direction(s: &Series) -> PolarsResult<IsSorted> {
+    if s.len() < 2 {
+        return Ok(IsSorted::Not);
+    }
+    match s.is_sorted_flag() {
+        IsSorted::Not => {}
+        flag => return Ok(flag),
+    }
+
+    let s = s.to_physical_repr();
+    let (ascending, descending) = match s.dtype() {
+        DataType::Boolean => sorted_directions(s.bool().unwrap().into_iter()),
+        DataType::Utf8 => sorted_directions(s.utf8().unwrap().into_iter()),
+        DataType::Binary => sorted_directions(s.binary().unwrap().into_iter()),
+        dt => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                sorted_directions(ca.into_iter())
+            })
+        }
+    };
+    match (ascending, descending) {
+        (true, true) => Ok(IsSorted::Not),
+        (true, false) => Ok(IsSorted::Ascending),
+        (false, true) => Ok(IsSorted::Descending),
+        (false, false) => {
+            polars_bail!(ComputeError: "cannot merge key '{}' that is not sorted", s.name())
+        }
+    }
+}
+
+/// Whether the valid values of `iter` are sorted in ascending and in descending order, with NaN
+/// as the largest value.
+fn sorted_directions<T: PartialOrd + IsFloat + Copy>(
+    iter: impl Iterator<Item = Option<T>>,
+) -> (bool, bool) {
+    let mut ascending = true;
+    let mut descending = true;
+    let mut iter = iter.flatten();
+    if let Some(mut prev) = iter.next() {
+        for v in iter {
+            match compare_fn_nan_max(&prev, &v) {
+                Ordering::Less => descending = false,
+                Ordering::Greater => ascending = false,
+                Ordering::Equal => {}
+            }
+            if !ascending && !descending {
+                break;
+            }
+            prev = v;
+        }
+    }
+    (ascending, descending)
+}
+
+/// Whether the nulls of the sorted `s` are after its valid values, or `None` if that is not
+/// known from `s`.
+fn nulls_last(s: &Series) -> Option<bool> {
+    let null_count = s.null_count();
+    if null_count == 0 || null_count == s.len() {
+        None
+    } else {
+        // Safety:
+        // `s` is not empty
+        Some(!matches!(unsafe { s.get_unchecked(0) }, AnyValue::Null))
+    }
+}
+
 fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> Series {
     use DataType::*;
     match lhs.dtype() {
@@ -117,7 +225,12 @@ where
     unsafe { iter.trust_my_length(total_len).collect_trusted() }
 }
 
-fn series_to_merge_indicator(lhs: &Series, rhs: &Series) -> Vec<bool> {
+fn series_to_merge_indicator(
+    lhs: &Series,
+    rhs: &Series,
+    descending: bool,
+    nulls_last: bool,
+) -> Vec<bool> {
     let lhs_s = lhs.to_physical_repr().into_owned();
     let rhs_s = rhs.to_physical_repr().into_owned();
 
@@ -125,97 +238,103 @@ fn series_to_merge_indicator(lhs: &Series, rhs: &Series) -> Vec<bool> {
         DataType::Boolean => {
             let lhs = lhs_s.bool().unwrap();
             let rhs = rhs_s.bool().unwrap();
-            get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter(), |a, b| {
+                take_left(a, b, descending, nulls_last)
+            })
         }
         DataType::Utf8 => {
             let lhs = lhs_s.utf8().unwrap();
             let rhs = rhs_s.utf8().unwrap();
-
-            get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter(), |a, b| {
+                take_left(a, b, descending, nulls_last)
+            })
+        }
+        DataType::Binary => {
+            let lhs = lhs_s.binary().unwrap();
+            let rhs = rhs_s.binary().unwrap();
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter(), |a, b| {
+                take_left(a, b, descending, nulls_last)
+            })
         }
         _ => {
             with_match_physical_numeric_polars_type!(lhs_s.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs_s.as_ref().as_ref().as_ref();
                     let rhs: &ChunkedArray<$T> = rhs_s.as_ref().as_ref().as_ref();
-
-                    get_merge_indicator(lhs.into_iter(), rhs.into_iter())
-
+                    get_merge_indicator(lhs.into_iter(), rhs.into_iter(), |a, b| {
+                        take_left(a, b, descending, nulls_last)
+                    })
             })
         }
     }
 }
 
+/// Whether the value `a` of the left side goes before the value `b` of the right side.
+#[inline]
+fn take_left<T: PartialOrd + IsFloat>(
+    a: &Option<T>,
+    b: &Option<T>,
+    descending: bool,
+    nulls_last: bool,
+) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (None, Some(_)) => !nulls_last,
+        (Some(_), None) => nulls_last,
+        (Some(a), Some(b)) => {
+            let ord = compare_fn_nan_max(a, b);
+            if descending {
+                ord != Ordering::Less
+            } else {
+                ord != Ordering::Greater
+            }
+        }
+    }
+}
+
 // get a boolean values, left: true, right: false
 // that indicate from which side we should take a value
-fn get_merge_indicator<T>(
-    mut a_iter: impl ExactSizeIterator<Item = T>,
-    mut b_iter: impl ExactSizeIterator<Item = T>,
+fn get_merge_indicator<T, F>(
+    a_iter: impl ExactSizeIterator<Item = T>,
+    b_iter: impl ExactSizeIterator<Item = T>,
+    take_left: F,
 ) -> Vec<bool>
 where
-    T: PartialOrd + Default + Copy,
+    F: Fn(&T, &T) -> bool,
 {
     const A_INDICATOR: bool = true;
     const B_INDICATOR: bool = false;
 
-    let a_len = a_iter.size_hint().0;
-    let b_len = b_iter.size_hint().0;
-    if a_len == 0 {
-        return vec![true; b_len];
-    };
-    if b_len == 0 {
-        return vec![false; a_len];
-    }
-
-    let mut current_a = T::default();
-    let cap = a_len + b_len;
-    let mut out = Vec::with_capacity(cap);
-
-    let mut current_b = b_iter.next().unwrap();
-
-    for a in &mut a_iter {
-        current_a = a;
-        if a <= current_b {
-            // safety
-            // we pre-allocated enough
-            out.push(A_INDICATOR);
-            continue;
-        }
-        out.push(B_INDICATOR);
-
-        loop {
-            if let Some(b) = b_iter.next() {
-                current_b = b;
-                if b >= a {
+    let mut out = Vec::with_capacity(a_iter.len() + b_iter.len());
+    let mut a_iter = a_iter.peekable();
+    let mut b_iter = b_iter.peekable();
+    loop {
+        match (a_iter.peek(), b_iter.peek()) {
+            (Some(a), Some(b)) => {
+                if take_left(a, b) {
+                    a_iter.next();
                     out.push(A_INDICATOR);
-                    break;
+                } else {
+                    b_iter.next();
+                    out.push(B_INDICATOR);
                 }
-                out.push(B_INDICATOR);
-                continue;
             }
-            // b is depleted fill with a indicator
-            let remaining = cap - out.len();
-            out.extend(std::iter::repeat(A_INDICATOR).take(remaining));
-            return out;
+            // one side is depleted, take the remainder of the other
+            (Some(_), None) => {
+                out.extend(a_iter.map(|_| A_INDICATOR));
+                return out;
+            }
+            (None, _) => {
+                out.extend(b_iter.map(|_| B_INDICATOR));
+                return out;
+            }
         }
     }
-    if current_a < current_b {
-        out.push(B_INDICATOR);
-    }
-    // check if current value already is added
-    if *out.last().unwrap() == A_INDICATOR {
-        out.push(B_INDICATOR);
-    }
-    // take remaining
-    out.extend(b_iter.map(|_| B_INDICATOR));
-    assert_eq!(out.len(), b_len + a_len);
-
-    out
 }
 
 #[test]
 fn test_merge_sorted() {
-    fn get_merge_indicator_sliced<T: PartialOrd + Default + Copy>(a: &[T], b: &[T]) -> Vec<bool> {
-        get_merge_indicator(a.iter().copied(), b.iter().copied())
+    fn get_merge_indicator_sliced<T: PartialOrd + Copy>(a: &[T], b: &[T]) -> Vec<bool> {
+        get_merge_indicator(a.iter().copied(), b.iter().copied(), |a, b| a <= b)
     }
 
     let a = [1, 2, 4, 6, 9];
@@ -248,3 +367,72 @@ fn test_merge_sorted() {
     let expected = [true, true, true, false, false, false, false];
     assert_eq!(out, expected);
 }
+
+#[test]
+fn test_merge_sorted_dfs() -> PolarsResult<()> {
+    use polars_core::df;
+
+    let left = df!(
+        "key" => [None, Some(1), Some(3), Some(3)],
+        "side" => ["l0", "l1", "l2", "l3"]
+    )?;
+    let right = df!(
+        "key" => [None, Some(2), Some(3), Some(4)],
+        "side" => ["r0", "r1", "r2", "r3"]
+    )?;
+    let out = _merge_sorted_dfs(&left, &right, &left["key"], &right["key"], true)?;
+    assert_eq!(
+        Vec::from(out.column("side")?.utf8()?),
+        &[
+            Some("l0"),
+            Some("r0"),
+            Some("l1"),
+            Some("r1"),
+            Some("l2"),
+            Some("l3"),
+            Some("r2"),
+            Some("r3")
+        ]
+    );
+    assert_eq!(out.column("key")?.is_sorted_flag(), IsSorted::Ascending);
+
+    // descending keys with the nulls last
+    let mut lhs = Series::new("key", [Some(5), Some(1), None]);
+    let mut rhs = Series::new("key", [Some(4), Some(2)]);
+    lhs.set_sorted_flag(IsSorted::Descending);
+    rhs.set_sorted_flag(IsSorted::Descending);
+    let left = DataFrame::new(vec![lhs])?;
+    let right = DataFrame::new(vec![rhs])?;
+    let out = _merge_sorted_dfs(&left, &right, &left["key"], &right["key"], true)?;
+    assert_eq!(
+        Vec::from(out.column("key")?.i32()?),
+        &[Some(5), Some(4), Some(2), Some(1), None]
+    );
+    assert_eq!(out.column("key")?.is_sorted_flag(), IsSorted::Descending);
+
+    // keys that are sorted in different directions
+    let mut rhs = Series::new("key", [Some(2), Some(4)]);
+    rhs.set_sorted_flag(IsSorted::Ascending);
+    let right = DataFrame::new(vec![rhs])?;
+    assert!(_merge_sorted_dfs(&left, &right, &left["key"], &right["key"], true).is_err());
+
+    // the direction is determined from the values of keys without a sorted flag
+    let left = df!("key" => [Some(5), Some(5), Some(1), None])?;
+    let right = df!("key" => [Some(3), Some(3)])?;
+    let out = _merge_sorted_dfs(&left, &right, &left["key"], &right["key"], true)?;
+    assert_eq!(
+        Vec::from(out.column("key")?.i32()?),
+        &[Some(5), Some(5), Some(3), Some(3), Some(1), None]
+    );
+    assert_eq!(out.column("key")?.is_sorted_flag(), IsSorted::Descending);
+
+    // a key that is not sorted
+    let right = df!("key" => [3, 4, 1])?;
+    assert!(_merge_sorted_dfs(&left, &right, &left["key"], &right["key"], true).is_err());
+
+    // an empty side
+    let empty = left.head(Some(0));
+    let out = _merge_sorted_dfs(&empty, &left, &empty["key"], &left["key"], true)?;
+    assert!(out.frame_equal_missing(&left));
+    Ok(())
+}
@@ -1,5 +1,6 @@
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+mod update;
 #[cfg(feature = "chunked_ids")]
 use std::borrow::Cow;
 
@@ -7,15 +8,21 @@ use std::borrow::Cow;
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::frame::hash_join::*;
 use polars_core::prelude::*;
-use polars_core::utils::{_to_physical_and_bit_repr, slice_slice};
+use polars_core::utils::{_check_idx_len, _to_physical_and_bit_repr, slice_slice};
 use polars_core::POOL;
+pub use update::{UpdateArgs, UpdateDuplicates, UpdateStrategy};
 
 use super::*;
 
 macro_rules! det_hash_prone_order {
-    ($self:expr, $other:expr) => {{
-        // The shortest relation will be used to create a hash table.
-        let left_first = $self.len() > $other.len();
+    ($self:expr, $other:expr, $build_side:expr) => {{
+        // The shortest relation will be used to create a hash table, unless the build side is
+        // given.
+        let left_first = match $build_side {
+            JoinBuildSide::Auto => $self.len() > $other.len(),
+            JoinBuildSide::Left => false,
+            JoinBuildSide::Right => true,
+        };
         let a;
         let b;
         if left_first {
@@ -30,6 +37,46 @@ macro_rules! det_hash_prone_order {
     }};
 }
 
+/// The arguments of a join, see [`DataFrameJoinOps::join_with_args`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JoinArgs {
+    pub how: JoinType,
+    /// Suffix to add to the right columns whose names are also in the left `DataFrame`.
+    /// Defaults to `"_right"`.
+    pub suffix: Option<String>,
+    /// Only materialize the `(offset, len)` slice of the output.
+    pub slice: Option<(i64, usize)>,
+    /// The input that the hash table is built on.
+    pub build_side: JoinBuildSide,
+}
+
+impl JoinArgs {
+    pub fn new(how: JoinType) -> Self {
+        Self {
+            how,
+            suffix: None,
+            slice: None,
+            build_side: JoinBuildSide::Auto,
+        }
+    }
+
+    pub fn with_suffix(mut self, suffix: Option<String>) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub fn with_build_side(mut self, build_side: JoinBuildSide) -> Self {
+        self.build_side = build_side;
+        self
+    }
+}
+
+impl From<JoinType> for JoinArgs {
+    fn from(how: JoinType) -> Self {
+        JoinArgs::new(how)
+    }
+}
+
 pub trait DataFrameJoinOps: IntoDf {
     /// Generic join method. Can be used to join on multiple columns.
     ///
@@ -72,47 +119,66 @@ pub trait DataFrameJoinOps: IntoDf {
         how: JoinType,
         suffix: Option<String>,
     ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join_with_args(
+            other,
+            left_on,
+            right_on,
+            JoinArgs::new(how).with_suffix(suffix),
+        )
+    }
+
+    /// Join on the columns `left_on` and `right_on` with the options of `args`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_small_left(small: &DataFrame, large: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     let args = JoinArgs::new(JoinType::Left).with_build_side(JoinBuildSide::Left);
+    ///     small.join_with_args(large, ["key"], ["key"], args)
+    /// }
+    /// ```
+    fn join_with_args<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        args: JoinArgs,
+    ) -> PolarsResult<DataFrame>
     where
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
         let df_left = self.to_df();
         #[cfg(feature = "cross_join")]
-        if let JoinType::Cross = how {
-            return df_left.cross_join(other, suffix.as_deref(), None);
+        if let JoinType::Cross = args.how {
+            return df_left.cross_join(other, args.suffix.as_deref(), args.slice);
         }
         let selected_left = df_left.select_series(left_on)?;
         let selected_right = other.select_series(right_on)?;
-        self._join_impl(
-            other,
-            selected_left,
-            selected_right,
-            how,
-            suffix,
-            None,
-            true,
-            false,
-        )
+        self._join_impl(other, selected_left, selected_right, args, true, false)
     }
 
     #[doc(hidden)]
-    #[allow(clippy::too_many_arguments)]
     fn _join_impl(
         &self,
         other: &DataFrame,
         selected_left: Vec<Series>,
         selected_right: Vec<Series>,
-        how: JoinType,
-        suffix: Option<String>,
-        slice: Option<(i64, usize)>,
+        args: JoinArgs,
         _check_rechunk: bool,
         _verbose: bool,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
 
         #[cfg(feature = "cross_join")]
-        if let JoinType::Cross = how {
-            return left_df.cross_join(other, suffix.as_deref(), slice);
+        if let JoinType::Cross = args.how {
+            return left_df.cross_join(other, args.suffix.as_deref(), args.slice);
         }
 
         #[cfg(feature = "chunked_ids")]
@@ -121,14 +187,14 @@ pub trait DataFrameJoinOps: IntoDf {
             // the others not yet.
             // TODO! change this to other join types once they support chunked-id joins
             if _check_rechunk
-                && !(matches!(how, JoinType::Left)
+                && !(matches!(args.how, JoinType::Left)
                     || std::env::var("POLARS_NO_CHUNKED_JOIN").is_ok())
             {
                 let mut left = Cow::Borrowed(left_df);
                 let mut right = Cow::Borrowed(other);
                 if left_df.should_rechunk() {
                     if _verbose {
-                        eprintln!("{:?} join triggered a rechunk of the left dataframe: {} columns are affected", how, left_df.width());
+                        eprintln!("{:?} join triggered a rechunk of the left dataframe: {} columns are affected", args.how, left_df.width());
                     }
 
                     let mut tmp_left = left_df.clone();
@@ -137,7 +203,7 @@ pub trait DataFrameJoinOps: IntoDf {
                 }
                 if other.should_rechunk() {
                     if _verbose {
-                        eprintln!("{:?} join triggered a rechunk of the right dataframe: {} columns are affected", how, other.width());
+                        eprintln!("{:?} join triggered a rechunk of the right dataframe: {} columns are affected", args.how, other.width());
                     }
                     let mut tmp_right = other.clone();
                     tmp_right.as_single_chunk_par();
@@ -147,14 +213,18 @@ pub trait DataFrameJoinOps: IntoDf {
                     &right,
                     selected_left,
                     selected_right,
-                    how,
-                    suffix,
-                    slice,
+                    args,
                     false,
                     _verbose,
                 );
             }
         }
+        let JoinArgs {
+            how,
+            suffix,
+            slice,
+            build_side,
+        } = args;
 
         polars_ensure!(
             selected_left.len() == selected_right.len(),
@@ -170,7 +240,7 @@ pub trait DataFrameJoinOps: IntoDf {
 
         #[cfg(feature = "dtype-categorical")]
         for (l, r) in selected_left.iter().zip(&selected_right) {
-            _check_categorical_src(l.dtype(), r.dtype())?
+            _check_categorical_src(l.dtype(), r.dtype(), "join")?
         }
 
         // Single keys
@@ -178,15 +248,14 @@ pub trait DataFrameJoinOps: IntoDf {
             let s_left = left_df.column(selected_left[0].name())?;
             let s_right = other.column(selected_right[0].name())?;
             return match how {
-                JoinType::Inner => {
-                    left_df._inner_join_from_series(other, s_left, s_right, suffix, slice, _verbose)
-                }
-                JoinType::Left => {
-                    left_df._left_join_from_series(other, s_left, s_right, suffix, slice, _verbose)
-                }
-                JoinType::Outer => {
-                    left_df._outer_join_from_series(other, s_left, s_right, suffix, slice)
-                }
+                JoinType::Inner => left_df._inner_join_from_series(
+                    other, s_left, s_right, suffix, slice, _verbose, build_side,
+                ),
+                JoinType::Left => left_df._left_join_from_series(
+                    other, s_left, s_right, suffix, slice, _verbose, build_side,
+                ),
+                JoinType::Outer => left_df
+                    ._outer_join_from_series(other, s_left, s_right, suffix, slice, build_side),
                 #[cfg(feature = "semi_anti_join")]
                 JoinType::Anti => left_df._semi_anti_join_from_series(s_left, s_right, slice, true),
                 #[cfg(feature = "semi_anti_join")]
@@ -250,7 +319,7 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Inner => {
                 let left = DataFrame::new_no_checks(selected_left_physical);
                 let right = DataFrame::new_no_checks(selected_right_physical);
-                let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
+                let (mut left, mut right, swap) = det_hash_prone_order!(left, right, build_side);
                 let (join_idx_left, join_idx_right) =
                     _inner_join_multiple_keys(&mut left, &mut right, swap);
                 let mut join_idx_left = &*join_idx_left;
@@ -260,6 +329,7 @@ pub trait DataFrameJoinOps: IntoDf {
                     join_idx_left = slice_slice(join_idx_left, offset, len);
                     join_idx_right = slice_slice(join_idx_right, offset, len);
                 }
+                _check_idx_len(join_idx_left.len())?;
 
                 let (df_left, df_right) = POOL.join(
                     // safety: join indices are known to be in bounds
@@ -288,7 +358,7 @@ pub trait DataFrameJoinOps: IntoDf {
                 let left = DataFrame::new_no_checks(selected_left_physical);
                 let right = DataFrame::new_no_checks(selected_right_physical);
 
-                let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
+                let (mut left, mut right, swap) = det_hash_prone_order!(left, right, build_side);
                 let opt_join_tuples = _outer_join_multiple_keys(&mut left, &mut right, swap);
 
                 let mut opt_join_tuples = &*opt_join_tuples;
@@ -296,6 +366,7 @@ pub trait DataFrameJoinOps: IntoDf {
                 if let Some((offset, len)) = slice {
                     opt_join_tuples = slice_slice(opt_join_tuples, offset, len);
                 }
+                _check_idx_len(opt_join_tuples.len())?;
 
                 // Take the left and right dataframes by join tuples
                 let (df_left, df_right) = POOL.join(
@@ -435,6 +506,60 @@ pub trait DataFrameJoinOps: IntoDf {
     {
         self.join(other, left_on, right_on, JoinType::Outer, None)
     }
+
+    /// Update the values of this DataFrame with the non-null values of `other`, where the rows
+    /// are matched by the key columns `on`.
+    ///
+    /// `how` decides which rows are returned. The columns that are only in `other` are added and
+    /// are null for the rows without a match. The keys of `other` must be unique.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn upsert(df: &DataFrame, changes: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.update(changes, ["id"], UpdateStrategy::Outer)
+    /// }
+    /// ```
+    fn update<I, S>(&self, other: &DataFrame, on: I, how: UpdateStrategy) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.update_with_args(other, on, UpdateArgs::new(how))
+    }
+
+    /// Update the values of this DataFrame with the non-null values of `other`, see
+    /// [`DataFrameJoinOps::update`].
+    fn update_with_args<I, S>(
+        &self,
+        other: &DataFrame,
+        on: I,
+        args: UpdateArgs,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let on = on
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect::<Vec<_>>();
+        update::update_impl(self.to_df(), other, &on, &args)
+    }
+
+    /// Merge two DataFrames that are both sorted by the column `key` into a DataFrame that is
+    /// sorted by `key`, in linear time.
+    ///
+    /// The schemas of both DataFrames must be equal. Equal keys take the rows of `self` first.
+    /// The keys are merged in descending order if one of them has the descending sorted flag,
+    /// and the nulls stay on the side of the valid values where the keys have them.
+    #[cfg(feature = "merge_sorted")]
+    fn merge_sorted(&self, other: &DataFrame, key: &str) -> PolarsResult<DataFrame> {
+        let left = self.to_df();
+        _merge_sorted_dfs(left, other, left.column(key)?, other.column(key)?, true)
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
@@ -443,6 +568,7 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         self.to_df().height()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn _inner_join_from_series(
         &self,
         other: &DataFrame,
@@ -451,12 +577,13 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         verbose: bool,
+        build_side: JoinBuildSide,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
-        _check_categorical_src(s_left.dtype(), s_right.dtype())?;
+        _check_categorical_src(s_left.dtype(), s_right.dtype(), "inner join")?;
         let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose);
+            _sort_or_hash_inner(s_left, s_right, verbose, build_side);
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
@@ -465,6 +592,7 @@ trait DataFrameJoinOpsPrivate: IntoDf {
             join_tuples_left = slice_slice(join_tuples_left, offset, len);
             join_tuples_right = slice_slice(join_tuples_right, offset, len);
         }
+        _check_idx_len(join_tuples_left.len())?;
 
         let (df_left, df_right) = POOL.join(
             // safety: join indices are known to be in bounds
@@ -0,0 +1,247 @@
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+use super::*;
+
+// The names of the helper columns, these are dropped before the result is returned.
+const MATCHED: &str = "__POLARS_UPDATE_MATCHED";
+const SUFFIX: &str = "__POLARS_UPDATE_OTHER";
+
+/// Which rows [`DataFrameJoinOps::update`] returns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum UpdateStrategy {
+    /// All rows of the `DataFrame`, updated where `other` has their key.
+    #[default]
+    Left,
+    /// Only the rows of the `DataFrame` whose key is in `other`.
+    Inner,
+    /// All rows of the `DataFrame`, followed by the rows of `other` whose key is not in the
+    /// `DataFrame` (an upsert).
+    Outer,
+}
+
+/// How [`DataFrameJoinOps::update`] handles keys that occur more than once in `other`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum UpdateDuplicates {
+    /// Raise an error.
+    #[default]
+    Error,
+    /// Update with the last row of every key.
+    Last,
+}
+
+/// The arguments of an update, see [`DataFrameJoinOps::update_with_args`].
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct UpdateArgs {
+    pub how: UpdateStrategy,
+    /// Raise an error if a column of both `DataFrame`s has different dtypes, instead of casting
+    /// both to their supertype.
+    pub strict: bool,
+    pub duplicates: UpdateDuplicates,
+}
+
+impl UpdateArgs {
+    pub fn new(how: UpdateStrategy) -> Self {
+        Self {
+            how,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_duplicates(mut self, duplicates: UpdateDuplicates) -> Self {
+        self.duplicates = duplicates;
+        self
+    }
+}
+
+impl From<UpdateStrategy> for UpdateArgs {
+    fn from(how: UpdateStrategy) -> Self {
+        UpdateArgs::new(how)
+    }
+}
+
+/// Cast the columns that are in both `DataFrame`s to their supertype.
+fn align_dtypes(left: &mut DataFrame, other: &mut DataFrame, strict: bool) -> PolarsResult<()> {
+    for name in left.get_column_names_owned() {
+        let (lhs, rhs) = match (left.column(&name), other.column(&name)) {
+            (Ok(lhs), Ok(rhs)) => (lhs, rhs),
+            _ => continue,
+        };
+        if lhs.dtype() == rhs.dtype() {
+            continue;
+        }
+        polars_ensure!(
+            !strict,
+            SchemaMismatch: "column '{}' has dtype {} but the update has dtype {}",
+            name, lhs.dtype(), rhs.dtype();
+            column = name
+        );
+        let dtype = try_get_supertype(lhs.dtype(), rhs.dtype())?;
+        let lhs = lhs.cast(&dtype)?;
+        let rhs = rhs.cast(&dtype)?;
+        left.with_column(lhs)?;
+        other.with_column(rhs)?;
+    }
+    Ok(())
+}
+
+/// A boolean column of `height` rows that marks the rows that found a match in a left join.
+fn matched_marker(height: usize) -> Series {
+    BooleanChunked::full(MATCHED, true, height).into_series()
+}
+
+pub(super) fn update_impl(
+    left: &DataFrame,
+    other: &DataFrame,
+    on: &[String],
+    args: &UpdateArgs,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        !on.is_empty(),
+        ComputeError: "at least one key is required in an update"
+    );
+    let mut left = left.clone();
+    let mut other = other.clone();
+    // fail on missing keys
+    left.select_series(on)?;
+    other.select_series(on)?;
+    align_dtypes(&mut left, &mut other, args.strict)?;
+
+    let other = match args.duplicates {
+        UpdateDuplicates::Error => {
+            polars_ensure!(
+                !other.select(on)?.is_duplicated()?.any(),
+                ComputeError: "the keys of the update are not unique; \
+                use `UpdateDuplicates::Last` to update with the last row of every key"
+            );
+            other
+        }
+        UpdateDuplicates::Last => other.unique_stable(Some(on), UniqueKeepStrategy::Last, None)?,
+    };
+
+    // a left join keeps the order of the rows
+    let mut marked = other.clone();
+    marked.with_column(matched_marker(other.height()))?;
+    let mut out = left.join(&marked, on, on, JoinType::Left, Some(SUFFIX.to_string()))?;
+
+    // take the non-null values of the update
+    for s in left.get_columns() {
+        let name = s.name();
+        if on.iter().any(|key| key == name) || other.column(name).is_err() {
+            continue;
+        }
+        let updated = out.drop_in_place(&format!("{name}{SUFFIX}"))?;
+        let current = out.column(name)?;
+        let mut merged = updated.zip_with(&updated.is_not_null(), current)?;
+        merged.rename(name);
+        out.with_column(merged)?;
+    }
+
+    let matched = out.drop_in_place(MATCHED)?;
+    match args.how {
+        UpdateStrategy::Left => Ok(out),
+        UpdateStrategy::Inner => out.filter(&matched.is_not_null()),
+        UpdateStrategy::Outer => {
+            // the rows of `other` whose key is not in `left`
+            let mut left_keys = left
+                .select(on)?
+                .unique(None, UniqueKeepStrategy::Any, None)?;
+            left_keys.with_column(matched_marker(left_keys.height()))?;
+            let probe = other.join(&left_keys, on, on, JoinType::Left, None)?;
+            let new_rows = probe.filter(&probe.column(MATCHED)?.is_null())?;
+
+            let new_rows = out
+                .get_columns()
+                .iter()
+                .map(|s| match new_rows.column(s.name()) {
+                    Ok(new) => new.cast(s.dtype()),
+                    Err(_) => Ok(Series::full_null(s.name(), new_rows.height(), s.dtype())),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            out.vstack_mut(&DataFrame::new_no_checks(new_rows))?;
+            Ok(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    fn frames() -> PolarsResult<(DataFrame, DataFrame)> {
+        let df = df!(
+            "key" => [1, 2, 3],
+            "a" => [Some(10), Some(20), Some(30)],
+            "b" => ["x", "y", "z"]
+        )?;
+        let other = df!(
+            "key" => [3, 4, 1],
+            "a" => [Some(31i64), Some(41), None],
+            "c" => [true, false, true]
+        )?;
+        Ok((df, other))
+    }
+
+    #[test]
+    fn test_update_strategies() -> PolarsResult<()> {
+        let (df, other) = frames()?;
+
+        let out = df.update(&other, ["key"], UpdateStrategy::Left)?;
+        assert_eq!(out.get_column_names(), &["key", "a", "b", "c"]);
+        // the dtypes of `a` are cast to their supertype and a null does not overwrite
+        assert_eq!(
+            Vec::from(out.column("a")?.i64()?),
+            &[Some(10), Some(20), Some(31)]
+        );
+        assert_eq!(
+            Vec::from(out.column("c")?.bool()?),
+            &[Some(true), None, Some(true)]
+        );
+
+        let out = df.update(&other, ["key"], UpdateStrategy::Inner)?;
+        assert_eq!(Vec::from(out.column("key")?.i32()?), &[Some(1), Some(3)]);
+
+        let out = df.update(&other, ["key"], UpdateStrategy::Outer)?;
+        assert_eq!(
+            Vec::from(out.column("key")?.i32()?),
+            &[Some(1), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("a")?.i64()?),
+            &[Some(10), Some(20), Some(31), Some(41)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.utf8()?),
+            &[Some("x"), Some("y"), Some("z"), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_strict_and_duplicates() -> PolarsResult<()> {
+        let (df, other) = frames()?;
+        let args = UpdateArgs::new(UpdateStrategy::Left).with_strict(true);
+        let err = df.update_with_args(&other, ["key"], args).unwrap_err();
+        assert_eq!(err.kind(), polars_core::error::ErrorKind::SchemaMismatch);
+
+        let other = df!(
+            "key" => [2, 2],
+            "a" => [21, 22]
+        )?;
+        assert!(df.update(&other, ["key"], UpdateStrategy::Left).is_err());
+        let args = UpdateArgs::new(UpdateStrategy::Left).with_duplicates(UpdateDuplicates::Last);
+        let out = df.update_with_args(&other, ["key"], args)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(10), Some(22), Some(30)]
+        );
+        Ok(())
+    }
+}
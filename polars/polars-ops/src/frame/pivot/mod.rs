@@ -27,7 +27,7 @@ fn restore_logical_type(s: &Series, logical_type: &DataType) -> Series {
     // restore logical type
     match (logical_type, s.dtype()) {
         #[cfg(feature = "dtype-categorical")]
-        (DataType::Categorical(Some(rev_map)), _) => {
+        (DataType::Categorical(Some(rev_map), _), _) => {
             let cats = s.u32().unwrap().clone();
             // safety:
             // the rev-map comes from these categoricals
@@ -73,6 +73,11 @@ fn restore_logical_type(s: &Series, logical_type: &DataType) -> Series {
 
 /// Do a pivot operation based on the group key, a pivot column and an aggregation function on the values column.
 ///
+/// If there are multiple `values` columns, the output columns are named
+/// `{value}{separator}{column}{separator}{column value}`. A combination of `index` and `columns`
+/// that doesn't occur gives a null value. This currently gives the same output as
+/// [`pivot_stable`].
+///
 /// # Note
 /// Polars'/arrow memory is not ideal for transposing operations like pivots.
 /// If you have a relatively large table, consider using a groupby over a pivot.
@@ -112,13 +117,15 @@ where
         &columns,
         agg_fn,
         sort_columns,
-        false,
         separator,
     )
 }
 
 /// Do a pivot operation based on the group key, a pivot column and an aggregation function on the values column.
 ///
+/// The rows and the columns of the output are in the order of their first appearance, unless
+/// `sort_columns` is set.
+///
 /// # Note
 /// Polars'/arrow memory is not ideal for transposing operations like pivots.
 /// If you have a relatively large table, consider using a groupby over a pivot.
@@ -159,7 +166,6 @@ where
         &columns,
         agg_fn,
         sort_columns,
-        true,
         separator,
     )
 }
@@ -177,7 +183,6 @@ fn pivot_impl(
     // aggregation function
     agg_fn: Option<PivotAgg>,
     sort_columns: bool,
-    stable: bool,
     // used as separator/delimiter in generated column names.
     separator: Option<&str>,
 ) -> PolarsResult<DataFrame> {
@@ -192,13 +197,10 @@ fn pivot_impl(
             let mut groupby = index.to_vec();
             groupby.push(column_column_name.clone());
 
+            // the groupby is always stable, so that the rows and the columns of the output
+            // are in the order of their first appearance
             let groups = pivot_df.groupby_stable(groupby)?.take_groups();
 
-            // these are the row locations
-            if !stable {
-                println!("unstable pivot not yet supported, using stable pivot");
-            };
-
             let (col, row) = POOL.join(
                 || positioning::compute_col_idx(pivot_df, column_column_name, &groups),
                 || positioning::compute_row_idx(pivot_df, index, &groups, count),
@@ -4,5 +4,7 @@ pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
-pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
+pub use crate::frame::{
+    DataFrameJoinOps, DataFrameOps, JoinArgs, UpdateArgs, UpdateDuplicates, UpdateStrategy,
+};
 pub use crate::series::*;
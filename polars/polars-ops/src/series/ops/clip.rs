@@ -0,0 +1,88 @@
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+
+fn clip_helper<T, F>(ca: &ChunkedArray<T>, bound: &ChunkedArray<T>, op: F) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    match bound.len() {
+        1 => match bound.get(0) {
+            Some(bound) => ca.apply(|v| op(v, bound)),
+            None => ca.clone(),
+        },
+        _ => {
+            let mut out: ChunkedArray<T> = ca
+                .into_iter()
+                .zip(bound.into_iter())
+                .map(|(opt_v, opt_bound)| match opt_bound {
+                    Some(bound) => opt_v.map(|v| op(v, bound)),
+                    None => opt_v,
+                })
+                .collect_trusted();
+            out.rename(ca.name());
+            out
+        }
+    }
+}
+
+fn clip_min_ca<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    min: &ChunkedArray<T>,
+) -> ChunkedArray<T> {
+    clip_helper(ca, min, |v, min| if v < min { min } else { v })
+}
+
+fn clip_max_ca<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    max: &ChunkedArray<T>,
+) -> ChunkedArray<T> {
+    clip_helper(ca, max, |v, max| if v > max { max } else { v })
+}
+
+fn check_bound(s: &Series, bound: &Series, name: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "`{}` operation not supported for dtype `{}`", name, s.dtype()
+    );
+    polars_ensure!(
+        bound.len() == s.len() || bound.len() == 1,
+        ShapeMismatch: "`{}` expected a bound of length {} or 1, got {}", name, s.len(), bound.len()
+    );
+    Ok(())
+}
+
+/// Clamp every value of `s` to be at least the value of `min` in the same row.
+///
+/// A `min` of length 1 is broadcast and a null `min` means that row has no lower bound.
+/// `min` is cast to the dtype of `s`.
+pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
+    check_bound(s, min, "clip_min")?;
+    let min = min.cast(s.dtype())?;
+    with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        let min: &ChunkedArray<$T> = min.as_ref().as_ref().as_ref();
+        Ok(clip_min_ca(ca, min).into_series())
+    })
+}
+
+/// Clamp every value of `s` to be at most the value of `max` in the same row.
+///
+/// A `max` of length 1 is broadcast and a null `max` means that row has no upper bound.
+/// `max` is cast to the dtype of `s`.
+pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
+    check_bound(s, max, "clip_max")?;
+    let max = max.cast(s.dtype())?;
+    with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        let max: &ChunkedArray<$T> = max.as_ref().as_ref().as_ref();
+        Ok(clip_max_ca(ca, max).into_series())
+    })
+}
+
+/// Clamp every value of `s` between the values of `min` and `max` in the same row.
+///
+/// See [`clip_min`] and [`clip_max`].
+pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
+    clip_max(&clip_min(s, min)?, max)
+}
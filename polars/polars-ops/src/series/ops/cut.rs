@@ -0,0 +1,170 @@
+use polars_core::prelude::*;
+
+/// Name of the struct field holding the upper break point of a bin.
+pub const CUT_BREAK_POINT: &str = "break_point";
+/// Name of the struct field holding the bin a value is assigned to.
+pub const CUT_CATEGORY: &str = "category";
+
+fn bin_label(lower: f64, upper: f64, left_closed: bool) -> String {
+    if left_closed {
+        format!("[{lower}, {upper})")
+    } else {
+        format!("({lower}, {upper}]")
+    }
+}
+
+/// Assign every value of `s` to a bin and return the bin index.
+///
+/// Nulls and NaNs don't belong to any bin.
+fn bin_idx(s: &Series, breaks: &[f64], left_closed: bool, outer_bins: bool) -> PolarsResult<IdxCa> {
+    let s = s.cast(&DataType::Float64)?;
+    let ca = s.f64()?;
+    let n_breaks = breaks.len();
+
+    let out: IdxCa = ca
+        .into_iter()
+        .map(|opt_v| {
+            let v = opt_v.filter(|v| !v.is_nan())?;
+            // number of breaks that lie left of `v`
+            let idx = if left_closed {
+                breaks.partition_point(|brk| *brk <= v)
+            } else {
+                breaks.partition_point(|brk| *brk < v)
+            };
+            if outer_bins {
+                Some(idx as IdxSize)
+            } else if idx == 0 || idx == n_breaks {
+                None
+            } else {
+                Some((idx - 1) as IdxSize)
+            }
+        })
+        .collect();
+    Ok(out)
+}
+
+fn cut_impl(
+    s: &Series,
+    breaks: Vec<f64>,
+    labels: Option<Vec<String>>,
+    left_closed: bool,
+    include_breaks: bool,
+    outer_bins: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        !breaks.iter().any(|brk| brk.is_nan()),
+        ComputeError: "breaks may not contain NaN"
+    );
+    // bin edges; the outer bins reach to infinity
+    let edges = if outer_bins {
+        let mut edges = Vec::with_capacity(breaks.len() + 2);
+        edges.push(f64::NEG_INFINITY);
+        edges.extend_from_slice(&breaks);
+        edges.push(f64::INFINITY);
+        edges
+    } else {
+        polars_ensure!(
+            breaks.len() > 1,
+            ComputeError: "at least two breaks are needed if values outside the outer breaks are null"
+        );
+        breaks.clone()
+    };
+    let n_bins = edges.len() - 1;
+
+    let labels = match labels {
+        Some(labels) => {
+            polars_ensure!(
+                labels.len() == n_bins,
+                ShapeMismatch: "expected {} labels for {} bins, got {}", n_bins, n_bins, labels.len()
+            );
+            labels
+        }
+        None => edges
+            .windows(2)
+            .map(|w| bin_label(w[0], w[1], left_closed))
+            .collect(),
+    };
+
+    let idx = bin_idx(s, &breaks, left_closed, outer_bins)?;
+    // cast the labels before taking so that the categories are ordered by bin
+    let categories =
+        Series::new(CUT_CATEGORY, labels).cast(&DataType::Categorical(None, Default::default()))?;
+    let mut categories = categories.take(&idx)?;
+
+    if include_breaks {
+        let break_points = Series::new(CUT_BREAK_POINT, &edges[1..]).take(&idx)?;
+        StructChunked::new(s.name(), &[break_points, categories]).map(|ca| ca.into_series())
+    } else {
+        categories.rename(s.name());
+        Ok(categories)
+    }
+}
+
+/// Bin the values of `s` into the intervals defined by `breaks`.
+///
+/// The result is a `Categorical` column, or a `Struct` with the upper break point and the
+/// bin if `include_breaks` is set. Bins are right-closed `(a, b]` unless `left_closed` is set.
+/// If `outer_bins` is set, values below the first or above the last break are assigned to the
+/// `(-inf, first]` and `(last, inf]` bins, otherwise they are null. NaNs and nulls are null.
+pub fn cut(
+    s: &Series,
+    mut breaks: Vec<f64>,
+    labels: Option<Vec<String>>,
+    left_closed: bool,
+    include_breaks: bool,
+    outer_bins: bool,
+) -> PolarsResult<Series> {
+    breaks.sort_by(|a, b| a.total_cmp(b));
+    polars_ensure!(
+        breaks.windows(2).all(|w| w[0] != w[1]),
+        Duplicate: "breaks are not unique"
+    );
+    cut_impl(s, breaks, labels, left_closed, include_breaks, outer_bins)
+}
+
+/// Bin the values of `s` into intervals whose breaks are the `probs` quantiles of `s`.
+///
+/// Ties between quantiles (e.g. in skewed data) produce duplicate breaks. These are
+/// dropped if `allow_duplicates` is set and raise an error otherwise.
+/// See [`cut`] for the output.
+pub fn qcut(
+    s: &Series,
+    probs: Vec<f64>,
+    labels: Option<Vec<String>>,
+    left_closed: bool,
+    allow_duplicates: bool,
+    include_breaks: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        probs.iter().all(|p| (0.0..=1.0).contains(p)),
+        ComputeError: "quantiles must be between 0.0 and 1.0"
+    );
+    let s_f64 = s.cast(&DataType::Float64)?;
+    let ca = s_f64.f64()?;
+    // NaNs don't take part in the quantiles, as they don't belong to any bin
+    let not_nan = ca.is_not_nan();
+    let ca = if not_nan.all() {
+        ca.clone()
+    } else {
+        ca.filter(&not_nan)?
+    };
+    // amortize the quantile computation
+    let ca = ca.sort(false);
+
+    let mut breaks = Vec::with_capacity(probs.len());
+    for p in probs {
+        if let Some(brk) = ca.quantile(p, QuantileInterpolOptions::Linear)? {
+            breaks.push(brk)
+        }
+    }
+    breaks.sort_by(|a, b| a.total_cmp(b));
+    if allow_duplicates {
+        breaks.dedup();
+    } else {
+        polars_ensure!(
+            breaks.windows(2).all(|w| w[0] != w[1]),
+            Duplicate: "quantile breaks are not unique; set `allow_duplicates` to drop duplicate breaks"
+        );
+    }
+    cut_impl(s, breaks, labels, left_closed, include_breaks, true)
+}
@@ -0,0 +1,346 @@
+use polars_core::prelude::*;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use super::encoded_dtype;
+
+const NULL: u8 = 0;
+const VALID: u8 = 1;
+
+trait StableBytes: Copy {
+    fn extend_stable_bytes(self, buf: &mut Vec<u8>);
+}
+
+macro_rules! impl_stable_bytes_int {
+    ($($t:ty),*) => {
+        $(
+            impl StableBytes for $t {
+                #[inline]
+                fn extend_stable_bytes(self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_stable_bytes_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl StableBytes for f32 {
+    #[inline]
+    fn extend_stable_bytes(self, buf: &mut Vec<u8>) {
+        let bits = if self.is_nan() {
+            0x7fc0_0000
+        } else if self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        buf.extend_from_slice(&bits.to_le_bytes())
+    }
+}
+
+impl StableBytes for f64 {
+    #[inline]
+    fn extend_stable_bytes(self, buf: &mut Vec<u8>) {
+        let bits = if self.is_nan() {
+            0x7ff8_0000_0000_0000
+        } else if self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        buf.extend_from_slice(&bits.to_le_bytes())
+    }
+}
+
+#[inline]
+fn encode_opt<T: StableBytes>(opt_v: Option<T>, buf: &mut Vec<u8>) {
+    match opt_v {
+        None => buf.push(NULL),
+        Some(v) => {
+            buf.push(VALID);
+            v.extend_stable_bytes(buf)
+        }
+    }
+}
+
+#[inline]
+fn encode_bytes(opt_v: Option<&[u8]>, buf: &mut Vec<u8>) {
+    match opt_v {
+        None => buf.push(NULL),
+        Some(v) => {
+            buf.push(VALID);
+            buf.extend_from_slice(&(v.len() as u64).to_le_bytes());
+            buf.extend_from_slice(v)
+        }
+    }
+}
+
+fn encode_any_value(av: &AnyValue, buf: &mut Vec<u8>) -> PolarsResult<()> {
+    use AnyValue::*;
+    match av {
+        Null => buf.push(NULL),
+        Boolean(v) => encode_opt(Some(*v as u8), buf),
+        UInt8(v) => encode_opt(Some(*v), buf),
+        UInt16(v) => encode_opt(Some(*v), buf),
+        UInt32(v) => encode_opt(Some(*v), buf),
+        UInt64(v) => encode_opt(Some(*v), buf),
+        Int8(v) => encode_opt(Some(*v), buf),
+        Int16(v) => encode_opt(Some(*v), buf),
+        Int32(v) => encode_opt(Some(*v), buf),
+        Int64(v) => encode_opt(Some(*v), buf),
+        Float32(v) => encode_opt(Some(*v), buf),
+        Float64(v) => encode_opt(Some(*v), buf),
+        Utf8(v) => encode_bytes(Some(v.as_bytes()), buf),
+        Utf8Owned(v) => encode_bytes(Some(v.as_bytes()), buf),
+        Binary(v) => encode_bytes(Some(*v), buf),
+        BinaryOwned(v) => encode_bytes(Some(v.as_slice()), buf),
+        List(s) => {
+            buf.push(VALID);
+            buf.extend_from_slice(&(s.len() as u64).to_le_bytes());
+            for i in 0..s.len() {
+                encode_any_value(&s.get(i)?, buf)?;
+            }
+        }
+        #[cfg(feature = "dtype-struct")]
+        Struct(..) => {
+            buf.push(VALID);
+            for av in av._iter_struct_av() {
+                encode_any_value(&av, buf)?;
+            }
+        }
+        av => polars_bail!(opq = hash_stable, av.dtype()),
+    }
+    Ok(())
+}
+
+fn hash_iter<I, F>(name: &str, iter: I, seed: u64, mut encode: F) -> PolarsResult<UInt64Chunked>
+where
+    I: Iterator,
+    F: FnMut(I::Item, &mut Vec<u8>) -> PolarsResult<()>,
+{
+    let mut buf = Vec::new();
+    let values = iter
+        .map(|item| {
+            buf.clear();
+            encode(item, &mut buf)?;
+            Ok(xxh3_64_with_seed(&buf, seed))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(UInt64Chunked::from_vec(name, values))
+}
+
+fn hash_numeric<T>(ca: &ChunkedArray<T>, seed: u64) -> PolarsResult<UInt64Chunked>
+where
+    T: PolarsNumericType,
+    T::Native: StableBytes,
+{
+    hash_iter(ca.name(), ca.into_iter(), seed, |opt_v, buf| {
+        encode_opt(opt_v, buf);
+        Ok(())
+    })
+}
+
+/// Hash every value of `s` with a hash that is stable between polars versions and
+/// platforms.
+///
+/// Every value is encoded into bytes and the bytes are hashed with
+/// [xxh3](https://github.com/Cyan4973/xxHash) (64 bit) and the given seed.
+/// The encoding is:
+///
+/// - A null is the single byte `0`. Every valid value starts with the byte `1`,
+///   followed by the encoding of its value.
+/// - Booleans are a single byte, `0` or `1`.
+/// - Integers and floats are the little-endian bytes of the value in its own width.
+///   `-0.0` is encoded as `0.0` and every `NaN` as the canonical quiet `NaN`.
+/// - `Utf8` and `Binary` values are the length as `u64` (little-endian) followed by the bytes.
+///   `Categorical` values are encoded as their `Utf8` value.
+/// - `Date`, `Datetime`, `Duration` and `Time` are encoded as their physical integer.
+/// - `List` values are the number of elements as `u64` (little-endian) followed by the
+///   encoding of every element.
+/// - `Struct` values are the encoding of every field in order.
+///
+/// This must not change without a major version bump.
+pub fn hash_stable(s: &Series, seed: u64) -> PolarsResult<UInt64Chunked> {
+    let dtype = encoded_dtype(s.dtype(), true);
+    let s = s.cast(&dtype)?;
+    match &dtype {
+        DataType::Boolean => hash_iter(s.name(), s.bool()?.into_iter(), seed, |opt_v, buf| {
+            encode_opt(opt_v.map(|v| v as u8), buf);
+            Ok(())
+        }),
+        DataType::Utf8 => hash_iter(s.name(), s.utf8()?.into_iter(), seed, |opt_v, buf| {
+            encode_bytes(opt_v.map(|v| v.as_bytes()), buf);
+            Ok(())
+        }),
+        DataType::Binary => hash_iter(s.name(), s.binary()?.into_iter(), seed, |opt_v, buf| {
+            encode_bytes(opt_v, buf);
+            Ok(())
+        }),
+        DataType::Int32 => hash_numeric(s.i32()?, seed),
+        DataType::Int64 => hash_numeric(s.i64()?, seed),
+        DataType::UInt32 => hash_numeric(s.u32()?, seed),
+        DataType::UInt64 => hash_numeric(s.u64()?, seed),
+        DataType::Float32 => hash_numeric(s.f32()?, seed),
+        DataType::Float64 => hash_numeric(s.f64()?, seed),
+        _ => {
+            let s = s.rechunk();
+            hash_iter(s.name(), 0..s.len(), seed, |i, buf| {
+                encode_any_value(&s.get(i)?, buf)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hashes(s: &Series, seed: u64) -> Vec<u64> {
+        hash_stable(s, seed).unwrap().into_no_null_iter().collect()
+    }
+
+    // These hashes may never change, the expected values were computed once
+    // from the documented encoding.
+    #[test]
+    fn test_hash_stable_known_answers() {
+        let s = Series::new("", [Some(1i64), None, Some(-1)]);
+        assert_eq!(
+            hashes(&s, 0),
+            [
+                11549681130057114999,
+                14144645293874801883,
+                6027492765397423756
+            ]
+        );
+        assert_eq!(
+            hashes(&s, 42),
+            [
+                5809129806902940634,
+                6697150685477982789,
+                13445046078799839711
+            ]
+        );
+
+        let s = Series::new("", [1i32]);
+        assert_eq!(hashes(&s, 0), [217133016869094146]);
+        let s = Series::new("", [1u32, u32::MAX]);
+        assert_eq!(hashes(&s, 0), [217133016869094146, 15288252545451306623]);
+        let s = Series::new("", [u64::MAX]);
+        assert_eq!(hashes(&s, 0), [6027492765397423756]);
+
+        let s = Series::new("", [0.0f64, -0.0, f64::NAN, -f64::NAN, 1.5]);
+        assert_eq!(
+            hashes(&s, 0),
+            [
+                3547760990396968576,
+                3547760990396968576,
+                7652621394371019991,
+                7652621394371019991,
+                3128000463895791800
+            ]
+        );
+        let s = Series::new("", [1.5f32]);
+        assert_eq!(hashes(&s, 0), [11980943898350826613]);
+
+        let s = Series::new("", [Some(true), Some(false), None]);
+        assert_eq!(
+            hashes(&s, 0),
+            [
+                508203992382727667,
+                12363696039452112576,
+                14144645293874801883
+            ]
+        );
+
+        let s = Series::new("", [Some(""), Some("polars"), None]);
+        let expected = [
+            3547760990396968576,
+            5771211910572433352,
+            14144645293874801883,
+        ];
+        assert_eq!(hashes(&s, 0), expected);
+        let s = s.cast(&DataType::Binary).unwrap();
+        assert_eq!(hashes(&s, 0), expected);
+
+        let s = Series::new(
+            "",
+            [
+                Some(Series::new("", [1i64, 2])),
+                Some(Series::new("", Vec::<i64>::new())),
+                None,
+                Some(Series::new("", [None::<i64>])),
+            ],
+        );
+        assert_eq!(
+            hashes(&s, 0),
+            [
+                8608383014799482693,
+                3547760990396968576,
+                14144645293874801883,
+                9584843614308602879
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-i8",
+        feature = "dtype-i16",
+        feature = "dtype-u8",
+        feature = "dtype-u16"
+    ))]
+    fn test_hash_stable_small_ints() {
+        let s = Series::new("", [1i8, -1]);
+        assert_eq!(hashes(&s, 0), [508203992382727667, 646411226463558372]);
+        let s = Series::new("", [1i16, -1]);
+        assert_eq!(hashes(&s, 0), [17035848665592734127, 11692200362880776048]);
+        let s = Series::new("", [1u8]);
+        assert_eq!(hashes(&s, 0), [508203992382727667]);
+        let s = Series::new("", [1u16]);
+        assert_eq!(hashes(&s, 0), [17035848665592734127]);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration",
+        feature = "dtype-time"
+    ))]
+    fn test_hash_stable_temporal() {
+        let s = Series::new("", [19000i32]).cast(&DataType::Date).unwrap();
+        assert_eq!(hashes(&s, 0), [11770555679862360784]);
+        let s = Series::new("", [1_500i64]);
+        let expected = [2431447407662681599];
+        for dtype in [
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+            DataType::Duration(TimeUnit::Microseconds),
+        ] {
+            assert_eq!(hashes(&s.cast(&dtype).unwrap(), 0), expected);
+        }
+        let s = Series::new("", [3_600_000_000_000i64])
+            .cast(&DataType::Time)
+            .unwrap();
+        assert_eq!(hashes(&s, 0), [1606728134949060858]);
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_hash_stable_struct() {
+        let a = Series::new("a", [Some(1i64), None]);
+        let b = Series::new("b", ["x", "y"]);
+        let s = StructChunked::new("", &[a, b]).unwrap().into_series();
+        assert_eq!(hashes(&s, 0), [2324314207637452675, 2012916716771204488]);
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_hash_stable_categorical() {
+        let s = Series::new("", [Some("polars"), None]);
+        let cat = s
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        assert_eq!(hashes(&cat, 0), [5771211910572433352, 14144645293874801883]);
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(any(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+use arrow::temporal_conversions;
+use base64::engine::general_purpose;
+use base64::Engine as _;
+use polars_core::prelude::*;
+use serde::ser::{Error, SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+use super::encoded_dtype;
+
+/// Serializes an [`AnyValue`] as JSON.
+struct JsonValue<'a>(&'a AnyValue<'a>);
+
+impl Serialize for JsonValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use AnyValue::*;
+        match self.0 {
+            Null => serializer.serialize_none(),
+            Boolean(v) => serializer.serialize_bool(*v),
+            UInt8(v) => serializer.serialize_u8(*v),
+            UInt16(v) => serializer.serialize_u16(*v),
+            UInt32(v) => serializer.serialize_u32(*v),
+            UInt64(v) => serializer.serialize_u64(*v),
+            Int8(v) => serializer.serialize_i8(*v),
+            Int16(v) => serializer.serialize_i16(*v),
+            Int32(v) => serializer.serialize_i32(*v),
+            Int64(v) => serializer.serialize_i64(*v),
+            // non-finite floats are serialized as `null`
+            Float32(v) => serializer.serialize_f32(*v),
+            Float64(v) => serializer.serialize_f64(*v),
+            Utf8(v) => serializer.serialize_str(v),
+            Utf8Owned(v) => serializer.serialize_str(v),
+            Binary(v) => serializer.serialize_str(&general_purpose::STANDARD.encode(v)),
+            BinaryOwned(v) => serializer.serialize_str(&general_purpose::STANDARD.encode(v)),
+            #[cfg(feature = "dtype-date")]
+            Date(v) => {
+                serializer.collect_str(&temporal_conversions::date32_to_date(*v).format("%Y-%m-%d"))
+            }
+            #[cfg(feature = "dtype-datetime")]
+            Datetime(v, tu, tz) => {
+                let ndt = match tu {
+                    TimeUnit::Nanoseconds => temporal_conversions::timestamp_ns_to_datetime(*v),
+                    TimeUnit::Microseconds => temporal_conversions::timestamp_us_to_datetime(*v),
+                    TimeUnit::Milliseconds => temporal_conversions::timestamp_ms_to_datetime(*v),
+                };
+                // timestamps with a time zone are stored in UTC
+                let suffix = if tz.is_some() { "Z" } else { "" };
+                serializer.collect_str(&format_args!(
+                    "{}{suffix}",
+                    ndt.format("%Y-%m-%dT%H:%M:%S%.f")
+                ))
+            }
+            #[cfg(feature = "dtype-duration")]
+            Duration(v, tu) => {
+                use polars_core::export::chrono::Duration;
+                let duration = match tu {
+                    TimeUnit::Nanoseconds => Duration::nanoseconds(*v),
+                    TimeUnit::Microseconds => Duration::microseconds(*v),
+                    TimeUnit::Milliseconds => Duration::milliseconds(*v),
+                };
+                // chrono formats durations as ISO-8601
+                serializer.collect_str(&duration)
+            }
+            #[cfg(feature = "dtype-time")]
+            Time(v) => serializer
+                .collect_str(&temporal_conversions::time64ns_to_time(*v).format("%H:%M:%S%.f")),
+            List(s) => {
+                let mut seq = serializer.serialize_seq(Some(s.len()))?;
+                for i in 0..s.len() {
+                    let av = s.get(i).map_err(S::Error::custom)?;
+                    seq.serialize_element(&JsonValue(&av))?;
+                }
+                seq.end()
+            }
+            #[cfg(feature = "dtype-struct")]
+            Struct(_, _, fields) => serialize_struct(self.0, fields, serializer),
+            #[cfg(feature = "dtype-struct")]
+            StructOwned(payload) => serialize_struct(self.0, &payload.1, serializer),
+            av => Err(S::Error::custom(polars_err!(opq = json_encode, av.dtype()))),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+fn serialize_struct<S: Serializer>(
+    av: &AnyValue,
+    fields: &[Field],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(fields.len()))?;
+    for (fld, av) in fields.iter().zip(av._iter_struct_av()) {
+        map.serialize_entry(fld.name().as_str(), &JsonValue(&av))?;
+    }
+    map.end()
+}
+
+/// Serialize every value of `s` to JSON.
+///
+/// Structs become objects and lists become arrays. Temporal values are serialized as
+/// ISO-8601 strings; datetimes with a time zone are serialized in UTC with a `Z` suffix.
+/// Binary values are serialized as base64 strings and non-finite floats as `null`.
+///
+/// Null rows are null in the output, unless `encode_nulls` is set, in which case they
+/// are serialized as `null`.
+pub fn json_encode(s: &Series, encode_nulls: bool) -> PolarsResult<Utf8Chunked> {
+    let s = s.cast(&encoded_dtype(s.dtype(), false))?.rechunk();
+    let mut buf = Vec::new();
+    let mut builder = Utf8ChunkedBuilder::new(s.name(), s.len(), s.len() * 16);
+    for i in 0..s.len() {
+        match s.get(i)? {
+            AnyValue::Null if !encode_nulls => builder.append_null(),
+            av => {
+                buf.clear();
+                serde_json::to_writer(&mut buf, &JsonValue(&av))
+                    .map_err(|e| polars_err!(ComputeError: "{}", e))?;
+                // serde_json only writes valid utf8
+                builder.append_value(unsafe { std::str::from_utf8_unchecked(&buf) })
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode(s: &Series, encode_nulls: bool) -> Vec<Option<String>> {
+        json_encode(s, encode_nulls)
+            .unwrap()
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_json_encode_scalars() {
+        let s = Series::new("", [Some(1.5f64), None, Some(f64::NAN)]);
+        assert_eq!(
+            encode(&s, false),
+            [Some("1.5".into()), None, Some("null".into())]
+        );
+        assert_eq!(
+            encode(&s, true),
+            [Some("1.5".into()), Some("null".into()), Some("null".into())]
+        );
+
+        let s = Series::new("", ["a\"b\n", "é"]);
+        assert_eq!(
+            encode(&s, false),
+            [Some(r#""a\"b\n""#.into()), Some(r#""é""#.into())]
+        );
+
+        let s = Series::new("", ["polars"]).cast(&DataType::Binary).unwrap();
+        assert_eq!(encode(&s, false), [Some(r#""cG9sYXJz""#.into())]);
+    }
+
+    #[test]
+    fn test_json_encode_nested() {
+        let s = Series::new(
+            "",
+            [
+                Some(Series::new("", [Some(1i32), None])),
+                Some(Series::new("", Vec::<i32>::new())),
+                None,
+            ],
+        );
+        assert_eq!(
+            encode(&s, false),
+            [Some("[1,null]".into()), Some("[]".into()), None]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-struct", feature = "dtype-datetime"))]
+    fn test_json_encode_struct() {
+        let a = Series::new("a", [1i64, 2]);
+        let b = Series::new("b", [Some("x"), None]);
+        let c = Series::new("c", [0i64, 1_500])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let s = StructChunked::new("", &[a, b, c]).unwrap().into_series();
+        assert_eq!(
+            encode(&s, false),
+            [
+                Some(r#"{"a":1,"b":"x","c":"1970-01-01T00:00:00"}"#.into()),
+                Some(r#"{"a":2,"b":null,"c":"1970-01-01T00:00:01.500"}"#.into())
+            ]
+        );
+    }
+}
@@ -75,7 +75,7 @@ pub trait LogSeries: SeriesSealed {
                 let pk = s.as_ref();
 
                 let pk = if normalize {
-                    let sum = pk.sum_as_series();
+                    let sum = pk.sum_as_series().ok()?;
 
                     if sum.get(0).unwrap().extract::<f64>()? != 1.0 {
                         pk / &sum
@@ -3,15 +3,25 @@ mod approx_algo;
 mod approx_unique;
 mod arg_min_max;
 #[cfg(feature = "round_series")]
+mod clip;
+#[cfg(feature = "cut_qcut")]
+mod cut;
+#[cfg(feature = "round_series")]
 mod floor_divide;
+#[cfg(feature = "hash")]
+mod hash_stable;
 #[cfg(feature = "is_first")]
 mod is_first;
 #[cfg(feature = "is_unique")]
 mod is_unique;
+#[cfg(feature = "json_encode")]
+mod json_encode;
 #[cfg(feature = "log")]
 mod log;
 #[cfg(feature = "rolling_window")]
 mod rolling;
+#[cfg(feature = "scatter")]
+mod scatter;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
 #[cfg(feature = "to_dummies")]
@@ -23,16 +33,26 @@ pub use approx_algo::*;
 pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
 #[cfg(feature = "round_series")]
+pub use clip::*;
+#[cfg(feature = "cut_qcut")]
+pub use cut::*;
+#[cfg(feature = "round_series")]
 pub use floor_divide::*;
+#[cfg(feature = "hash")]
+pub use hash_stable::*;
 #[cfg(feature = "is_first")]
 pub use is_first::*;
 #[cfg(feature = "is_unique")]
 pub use is_unique::*;
+#[cfg(feature = "json_encode")]
+pub use json_encode::*;
 #[cfg(feature = "log")]
 pub use log::*;
 use polars_core::prelude::*;
 #[cfg(feature = "rolling_window")]
 pub use rolling::*;
+#[cfg(feature = "scatter")]
+pub use scatter::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
 #[cfg(feature = "to_dummies")]
@@ -48,3 +68,26 @@ impl SeriesSealed for Series {
         self
     }
 }
+
+/// The dtype the values are encoded as by the stable hash and the JSON encoding.
+/// `Categorical` is encoded by its string value and, if `to_physical` is set, the other
+/// logical types by their physical value.
+#[cfg(any(feature = "hash", feature = "json_encode"))]
+fn encoded_dtype(dtype: &DataType, to_physical: bool) -> DataType {
+    use DataType::*;
+    match dtype {
+        Date if to_physical => Int32,
+        Datetime(_, _) | Duration(_) | Time if to_physical => Int64,
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) => Utf8,
+        List(inner) => List(Box::new(encoded_dtype(inner, to_physical))),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => Struct(
+            fields
+                .iter()
+                .map(|fld| Field::new(fld.name(), encoded_dtype(fld.data_type(), to_physical)))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
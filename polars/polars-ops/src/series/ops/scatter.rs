@@ -0,0 +1,235 @@
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::MutableBitmap;
+use polars_core::downcast_as_macro_arg_physical;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+use crate::series::ops::SeriesSealed;
+
+/// The positions to write in a single chunk, as `(position in chunk, index into values)`.
+type ChunkUpdates = Vec<(usize, usize)>;
+
+/// Write the updates into a copy of the only chunk of `piece`.
+fn scatter_numeric<T>(
+    piece: &ChunkedArray<T>,
+    updates: &[(usize, usize)],
+    values: &ChunkedArray<T>,
+) -> Series
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: IntoSeries,
+{
+    let arr = piece.downcast_iter().next().unwrap();
+    let values = values.downcast_iter().next().unwrap();
+    let len = arr.len();
+
+    let mut out = arr.values().to_vec();
+    let mut validity = arr.validity().map(|v| {
+        let mut validity = MutableBitmap::with_capacity(len);
+        validity.extend_from_bitmap(v);
+        validity
+    });
+    for &(i, value_idx) in updates {
+        if values.is_valid(value_idx) {
+            out[i] = values.value(value_idx);
+            if let Some(validity) = validity.as_mut() {
+                validity.set(i, true)
+            }
+        } else {
+            validity
+                .get_or_insert_with(|| {
+                    let mut validity = MutableBitmap::with_capacity(len);
+                    validity.extend_constant(len, true);
+                    validity
+                })
+                .set(i, false)
+        }
+    }
+    let arr = PrimitiveArray::new(
+        T::get_dtype().to_arrow(),
+        out.into(),
+        validity.map(|v| v.into()),
+    );
+    // Safety:
+    // the arrow dtype is the dtype of `T`
+    unsafe { ChunkedArray::<T>::from_chunks(piece.name(), vec![Box::new(arr)]) }.into_series()
+}
+
+/// Take the values of `piece` and replace the positions in `updates` with their values.
+fn scatter_generic(
+    piece: &Series,
+    updates: &[(usize, usize)],
+    values: &Series,
+) -> PolarsResult<Series> {
+    let len = piece.len();
+    let value_idx = updates
+        .iter()
+        .map(|&(_, value_idx)| value_idx as IdxSize)
+        .collect::<Vec<_>>();
+    let mut src = piece.clone();
+    src.append(&values.take(&IdxCa::from_vec("", value_idx))?)?;
+
+    let mut take_idx = (0..len as IdxSize).collect::<Vec<_>>();
+    for (j, &(i, _)) in updates.iter().enumerate() {
+        take_idx[i] = (len + j) as IdxSize;
+    }
+    src.take(&IdxCa::from_vec("", take_idx))
+}
+
+/// Replace the values of `s` at the positions in `idx` by `values`.
+///
+/// `values` must have the length of `idx` or length 1, in which case it is broadcast. The
+/// output has the supertype of `s` and `values`. If an index occurs more than once, the last
+/// write wins.
+///
+/// Only the chunks of `s` that contain a written position are rebuilt; the other chunks are
+/// shared with `s`.
+pub fn scatter(s: &Series, idx: &IdxCa, values: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        idx.null_count() == 0,
+        ComputeError: "scatter indices should not contain null values"
+    );
+    polars_ensure!(
+        values.len() == idx.len() || values.len() == 1,
+        ShapeMismatch: "scatter expected {} values, got {}", idx.len(), values.len()
+    );
+    let len = s.len();
+    if let Some(i) = idx.into_no_null_iter().find(|&i| i as usize >= len) {
+        polars_bail!(
+            ComputeError: "scatter index {} is out of bounds for a series of length {}", i, len
+        );
+    }
+
+    let dtype = try_get_supertype(s.dtype(), values.dtype())?;
+    let s = s.cast(&dtype)?;
+    let values = values.cast(&dtype)?.rechunk();
+    if idx.is_empty() {
+        return Ok(s);
+    }
+
+    let chunk_offsets = s
+        .chunk_lengths()
+        .scan(0, |offset, len| {
+            let chunk_offset = *offset;
+            *offset += len;
+            Some(chunk_offset)
+        })
+        .collect::<Vec<_>>();
+    let mut updates = vec![ChunkUpdates::new(); chunk_offsets.len()];
+    for (j, i) in idx.into_no_null_iter().enumerate() {
+        let i = i as usize;
+        // the last chunk that starts at or before `i`, which skips empty chunks
+        let chunk_idx = chunk_offsets.partition_point(|&offset| offset <= i) - 1;
+        let value_idx = if values.len() == 1 { 0 } else { j };
+        updates[chunk_idx].push((i - chunk_offsets[chunk_idx], value_idx));
+    }
+
+    let mut out: Option<Series> = None;
+    for ((offset, chunk_len), updates) in chunk_offsets.iter().zip(s.chunk_lengths()).zip(&updates)
+    {
+        let piece = s.slice(*offset as i64, chunk_len);
+        let piece = if updates.is_empty() {
+            piece
+        } else if dtype.is_integer() || dtype.is_float() {
+            macro_rules! dispatch {
+                ($ca:expr) => {{
+                    scatter_numeric($ca, updates, values.unpack()?)
+                }};
+            }
+            downcast_as_macro_arg_physical!(piece, dispatch)
+        } else {
+            scatter_generic(&piece, updates, &values)?
+        };
+        match out.as_mut() {
+            Some(out) => {
+                out.append(&piece)?;
+            }
+            None => out = Some(piece),
+        }
+    }
+    Ok(out.unwrap())
+}
+
+pub trait ScatterSeries: SeriesSealed {
+    /// Replace the values at the positions in `idx` by `values`. See [`scatter`].
+    fn scatter(&self, idx: &IdxCa, values: &Series) -> PolarsResult<Series> {
+        scatter(self.as_series(), idx, values)
+    }
+}
+
+impl ScatterSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scatter() -> PolarsResult<()> {
+        let mut s = Series::new("a", [Some(1i32), None, Some(3)]);
+        s.append(&Series::new("a", [4i32, 5, 6]))?;
+        s.append(&Series::new("a", [7i32, 8]))?;
+        assert_eq!(s.n_chunks(), 3);
+
+        let idx = IdxCa::from_slice("", &[1, 5, 4, 1]);
+        let values = Series::new("", [Some(10i32), None, Some(30), Some(40)]);
+        let out = s.scatter(&idx, &values)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[
+                Some(1),
+                Some(40),
+                Some(3),
+                Some(4),
+                Some(30),
+                None,
+                Some(7),
+                Some(8)
+            ]
+        );
+        // the untouched chunk is shared
+        let untouched = |s: &Series| s.i32().unwrap().downcast_iter().nth(2).unwrap().clone();
+        assert_eq!(out.n_chunks(), 3);
+        assert_eq!(
+            untouched(&out).values().as_ptr(),
+            untouched(&s).values().as_ptr()
+        );
+
+        // broadcast with a supertype
+        let out = s.scatter(&IdxCa::from_slice("", &[0, 7]), &Series::new("", [0.5f64]))?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.f64()?.get(0), Some(0.5));
+        assert_eq!(out.f64()?.get(7), Some(0.5));
+        assert_eq!(out.f64()?.get(1), None);
+
+        let s = Series::new("b", ["a", "b", "c"]);
+        let out = s.scatter(
+            &IdxCa::from_slice("", &[2, 0]),
+            &Series::new("", [None, Some("z")]),
+        )?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("z"), Some("b"), None]);
+
+        let err = s
+            .scatter(&IdxCa::from_slice("", &[3]), &Series::new("", ["x"]))
+            .unwrap_err();
+        assert!(err.to_string().contains("index 3"));
+        assert!(s
+            .scatter(
+                &IdxCa::from_slice("", &[0, 1]),
+                &Series::new("", ["x", "y", "z"])
+            )
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_scatter_decimal() -> PolarsResult<()> {
+        let dtype = DataType::Decimal(Some(10), Some(2));
+        let s = Series::new("a", [100i64, 200, 300]).cast(&dtype)?;
+        let values = Series::new("", [None, Some(400i64)]).cast(&dtype)?;
+        let out = s.scatter(&IdxCa::from_slice("", &[2, 0]), &values)?;
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(Vec::from(&**out.decimal()?), &[Some(40000), Some(20000), None]);
+        Ok(())
+    }
+}
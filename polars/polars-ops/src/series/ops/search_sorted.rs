@@ -1,5 +1,4 @@
 use std::cmp::Ordering;
-use std::fmt::Debug;
 
 use arrow::array::{Array, BinaryArray, PrimitiveArray};
 use polars_arrow::kernels::rolling::compare_fn_nan_max;
@@ -35,114 +34,120 @@ impl<'a> GetArray<&'a [u8]> for &'a BinaryArray<i64> {
     }
 }
 
-/// Search the left or right index that still fulfills the requirements.
-fn finish_side<G, I>(
-    side: SearchSortedSide,
-    out: &mut Vec<IdxSize>,
-    mid: IdxSize,
-    arr: G,
+/// Location of the nulls of a sorted column.
+#[derive(Copy, Clone)]
+struct NullPlacement {
+    null_count: usize,
+    nulls_last: bool,
     len: usize,
-) where
-    G: GetArray<I>,
-    I: PartialEq + Debug + Copy,
-{
-    let mut mid = mid;
+}
 
-    // approach the boundary from any side
-    // this is O(n) we could make this binary search later
-    match side {
-        SearchSortedSide::Any => {
-            out.push(mid);
+impl NullPlacement {
+    fn new<T: PolarsDataType>(ca: &ChunkedArray<T>, first_is_null: bool) -> Self {
+        Self {
+            null_count: ca.null_count(),
+            nulls_last: !first_is_null,
+            len: ca.len(),
         }
-        SearchSortedSide::Left => {
-            if mid as usize == len {
-                mid -= 1;
-            }
+    }
 
-            let current = unsafe { arr._get_value_unchecked(mid as usize) };
-            loop {
-                if mid == 0 {
-                    out.push(mid);
-                    break;
-                }
-                mid -= 1;
-                if current != unsafe { arr._get_value_unchecked(mid as usize) } {
-                    out.push(mid + 1);
-                    break;
-                }
-            }
+    /// The insertion index of a null value.
+    fn null_idx(&self, side: SearchSortedSide) -> IdxSize {
+        let idx = match (side, self.nulls_last) {
+            (SearchSortedSide::Right, false) => self.null_count,
+            (_, false) => 0,
+            (SearchSortedSide::Right, true) => self.len,
+            (_, true) => self.len - self.null_count,
+        };
+        idx as IdxSize
+    }
+
+    /// The insertion index of a value that sorts after all valid values.
+    fn end_idx(&self) -> IdxSize {
+        if self.nulls_last {
+            (self.len - self.null_count) as IdxSize
+        } else {
+            self.len as IdxSize
         }
-        SearchSortedSide::Right => {
-            if mid as usize == len {
-                out.push(mid);
-                return;
-            }
-            let current = unsafe { arr._get_value_unchecked(mid as usize) };
-            let bound = (len - 1) as IdxSize;
-            loop {
-                if mid >= bound {
-                    out.push(mid + 1);
-                    break;
-                }
-                mid += 1;
-                if current != unsafe { arr._get_value_unchecked(mid as usize) } {
-                    out.push(mid);
-                    break;
-                }
-            }
+    }
+
+    /// The range of valid values in a chunk of the sorted column.
+    /// The nulls of every chunk are on the same side as the nulls of the column.
+    fn valid_range(&self, arr: &dyn Array) -> (usize, usize) {
+        let null_count = arr.null_count();
+        if self.nulls_last {
+            (0, arr.len() - null_count)
+        } else {
+            (null_count, arr.len())
         }
     }
 }
 
-fn binary_search_array<G, I>(
-    side: SearchSortedSide,
-    out: &mut Vec<IdxSize>,
+/// Returns the first index in `[start, end)` for which `search_value` should be inserted
+/// before the value at that index. `end` is returned if there is no such index.
+fn partition_point<G, I>(
     arr: G,
-    len: usize,
+    start: usize,
+    end: usize,
     search_value: I,
+    side: SearchSortedSide,
     descending: bool,
-) where
+) -> usize
+where
     G: GetArray<I>,
-    I: PartialEq + Debug + Copy + PartialOrd + IsFloat,
+    I: PartialOrd + Copy + IsFloat,
 {
-    let mut size = len as IdxSize;
-    let mut left = 0 as IdxSize;
-    let mut right = size;
-    let current_len = out.len();
+    let mut left = start;
+    let mut right = end;
     while left < right {
-        let mid = left + size / 2;
+        let mid = left + (right - left) / 2;
 
-        // SAFETY: the call is made safe by the following invariants:
-        // - `mid >= 0`
-        // - `mid < size`: `mid` is limited by `[left; right)` bound.
-        let cmp = match unsafe { arr._get_value_unchecked(mid as usize) } {
-            None => Ordering::Less,
-            Some(value) => {
-                if descending {
-                    compare_fn_nan_max(&search_value, &value)
-                } else {
-                    compare_fn_nan_max(&value, &search_value)
-                }
-            }
+        // SAFETY: `mid` is in `[start, end)`, which only contains valid values.
+        let value = unsafe { arr._get_value_unchecked(mid).unwrap_unchecked() };
+        let cmp = if descending {
+            compare_fn_nan_max(&search_value, &value)
+        } else {
+            compare_fn_nan_max(&value, &search_value)
         };
-
-        // The reason why we use if/else control flow rather than match
-        // is because match reorders comparison operations, which is perf sensitive.
-        // This is x86 asm for u8: https://rust.godbolt.org/z/8Y8Pra.
-        if cmp == Ordering::Less {
+        // `Any` resolves to the left side; it is always a valid insertion index.
+        let before = match side {
+            SearchSortedSide::Right => cmp != Ordering::Greater,
+            SearchSortedSide::Left | SearchSortedSide::Any => cmp == Ordering::Less,
+        };
+        if before {
             left = mid + 1;
-        } else if cmp == Ordering::Greater {
-            right = mid;
         } else {
-            finish_side(side, out, mid, arr, len);
-            break;
+            right = mid;
         }
-
-        size = right - left;
     }
-    if out.len() == current_len {
-        out.push(left);
+    left
+}
+
+/// Binary search every chunk in order and correct the local index with the offset of the
+/// chunk. We can stop at the first chunk that doesn't insert `search_value` after its last
+/// valid value.
+fn binary_search_chunks<'a, A, I>(
+    arrs: &'a [&'a A],
+    search_value: I,
+    side: SearchSortedSide,
+    descending: bool,
+    nulls: NullPlacement,
+) -> IdxSize
+where
+    A: Array,
+    &'a A: GetArray<I>,
+    I: PartialOrd + Copy + IsFloat,
+{
+    let mut offset = 0;
+    for arr in arrs {
+        let (start, end) = nulls.valid_range(*arr);
+        let idx = partition_point(*arr, start, end, search_value, side, descending);
+        if idx < end {
+            return (offset + idx) as IdxSize;
+        }
+        offset += arr.len();
     }
+    nulls.end_idx()
 }
 
 fn search_sorted_ca_array<T>(
@@ -154,30 +159,22 @@ fn search_sorted_ca_array<T>(
 where
     T: PolarsNumericType,
 {
-    let ca = ca.rechunk();
-    let arr = ca.downcast_iter().next().unwrap();
+    let arrs = ca.downcast_iter().collect::<Vec<_>>();
+    let nulls = NullPlacement::new(ca, ca.null_count() > 0 && ca.get(0).is_none());
 
     let mut out = Vec::with_capacity(search_values.len());
-
     for search_arr in search_values.downcast_iter() {
         if search_arr.null_count() == 0 {
-            for search_value in search_arr.values_iter() {
-                binary_search_array(side, &mut out, arr, ca.len(), *search_value, descending)
-            }
+            out.extend(search_arr.values_iter().map(|search_value| {
+                binary_search_chunks(&arrs, *search_value, side, descending, nulls)
+            }))
         } else {
-            for opt_v in search_arr.into_iter() {
-                match opt_v {
-                    None => out.push(0),
-                    Some(search_value) => binary_search_array(
-                        side,
-                        &mut out,
-                        arr,
-                        ca.len(),
-                        *search_value,
-                        descending,
-                    ),
+            out.extend(search_arr.into_iter().map(|opt_v| match opt_v {
+                None => nulls.null_idx(side),
+                Some(search_value) => {
+                    binary_search_chunks(&arrs, *search_value, side, descending, nulls)
                 }
-            }
+            }))
         }
     }
     out
@@ -189,30 +186,30 @@ fn search_sorted_bin_array(
     side: SearchSortedSide,
     descending: bool,
 ) -> Vec<IdxSize> {
-    let ca = ca.rechunk();
-    let arr = ca.downcast_iter().next().unwrap();
+    let arrs = ca.downcast_iter().collect::<Vec<_>>();
+    let nulls = NullPlacement::new(ca, ca.null_count() > 0 && ca.get(0).is_none());
 
     let mut out = Vec::with_capacity(search_values.len());
-
     for search_arr in search_values.downcast_iter() {
         if search_arr.null_count() == 0 {
-            for search_value in search_arr.values_iter() {
-                binary_search_array(side, &mut out, arr, ca.len(), search_value, descending)
-            }
+            out.extend(search_arr.values_iter().map(|search_value| {
+                binary_search_chunks(&arrs, search_value, side, descending, nulls)
+            }))
         } else {
-            for opt_v in search_arr.into_iter() {
-                match opt_v {
-                    None => out.push(0),
-                    Some(search_value) => {
-                        binary_search_array(side, &mut out, arr, ca.len(), search_value, descending)
-                    }
+            out.extend(search_arr.into_iter().map(|opt_v| match opt_v {
+                None => nulls.null_idx(side),
+                Some(search_value) => {
+                    binary_search_chunks(&arrs, search_value, side, descending, nulls)
                 }
-            }
+            }))
         }
     }
     out
 }
 
+/// Find the indices where `search_values` should be inserted into the sorted `s` to maintain
+/// its order. The nulls of `s` may be at either end, nulls in `search_values` are placed
+/// next to them.
 pub fn search_sorted(
     s: &Series,
     search_values: &Series,
@@ -1,14 +1,14 @@
 use std::cell::RefCell;
-use std::collections::BTreeSet;
 
 use polars_arrow::error::to_compute_err;
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_lazy::prelude::*;
 use polars_plan::prelude::*;
-use polars_plan::utils::expressions_to_schema;
 use sqlparser::ast::{
     Expr as SqlExpr, FunctionArg, JoinOperator, ObjectName, OrderByExpr, Query, Select, SelectItem,
-    SetExpr, Statement, TableAlias, TableFactor, TableWithJoins, Value as SQLValue,
+    SetExpr, SetOperator, SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins,
+    Value as SQLValue,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
@@ -18,12 +18,84 @@ use crate::table_functions::PolarsTableFunctions;
 
 thread_local! {pub(crate) static TABLES: RefCell<Vec<String>> = RefCell::new(vec![])}
 
+fn strip_alias(e: &Expr) -> &Expr {
+    match e {
+        Expr::Alias(e, _) => e,
+        e => e,
+    }
+}
+
+/// Cast the columns of the operands of a set operation to their supertypes, and name the
+/// columns of `right` after the columns of `left`.
+fn unify_set_operands(
+    op: &SetOperator,
+    left: LazyFrame,
+    right: LazyFrame,
+) -> PolarsResult<(LazyFrame, LazyFrame)> {
+    let left_schema = left.schema()?;
+    let right_schema = right.schema()?;
+    let mismatch = || {
+        let fmt_schema = |schema: &Schema| {
+            schema
+                .iter()
+                .map(|(name, dtype)| format!("{name}: {dtype}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        polars_err!(
+            SchemaMismatch: "{} requires operands with compatible columns, got ({}) and ({})",
+            op, fmt_schema(&left_schema), fmt_schema(&right_schema)
+        )
+    };
+    if left_schema.len() != right_schema.len() {
+        return Err(mismatch());
+    }
+
+    let mut left_projection = Vec::with_capacity(left_schema.len());
+    let mut right_projection = Vec::with_capacity(right_schema.len());
+    for ((name, left_dtype), (right_name, right_dtype)) in
+        left_schema.iter().zip(right_schema.iter())
+    {
+        let dtype = try_get_supertype(left_dtype, right_dtype).map_err(|_| mismatch())?;
+        left_projection.push(col(name).cast(dtype.clone()));
+        right_projection.push(col(right_name).cast(dtype).alias(name));
+    }
+    Ok((left.select(left_projection), right.select(right_projection)))
+}
+
+/// Rename the columns of a CTE to the column list of `WITH name(a, b) AS (...)`.
+fn rename_cte_columns(lf: LazyFrame, alias: &TableAlias) -> PolarsResult<LazyFrame> {
+    let schema = lf.schema()?;
+    polars_ensure!(
+        schema.len() == alias.columns.len(),
+        ComputeError: "CTE '{}' has {} columns, but {} column names are given",
+        alias.name.value, schema.len(), alias.columns.len()
+    );
+    let existing = schema
+        .iter_names()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    let new = alias
+        .columns
+        .iter()
+        .map(|ident| ident.value.clone())
+        .collect::<Vec<_>>();
+    Ok(lf.rename(existing, new))
+}
+
 /// The SQLContext is the main entry point for executing SQL queries.
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) tables: Vec<String>,
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
+    file_access: bool,
+}
+
+impl Default for SQLContext {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SQLContext {
@@ -33,9 +105,16 @@ impl SQLContext {
             table_map: PlHashMap::new(),
             tables: vec![],
             cte_map: RefCell::new(PlHashMap::new()),
+            file_access: true,
         }
     }
 
+    /// Allow or forbid the table functions that read files, such as `read_csv`.
+    /// File access is allowed by default.
+    pub fn allow_file_access(&mut self, allow: bool) {
+        self.file_access = allow;
+    }
+
     /// Register a DataFrame as a table in the SQLContext.
     pub fn register(&mut self, name: &str, lf: LazyFrame) {
         self.table_map.insert(name.to_owned(), lf);
@@ -46,11 +125,12 @@ impl SQLContext {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
+    /// Get a table by name, where a CTE of the current query shadows a registered table.
     fn get_table_from_current_scope(&mut self, name: &str) -> Option<LazyFrame> {
-        if let Some(lf) = self.table_map.get(name) {
+        if let Some(lf) = self.cte_map.borrow().get(name) {
             Some(lf.clone())
         } else {
-            self.cte_map.borrow().get(name).cloned()
+            self.table_map.get(name).cloned()
         }
     }
 }
@@ -62,8 +142,25 @@ impl SQLContext {
         polars_ensure!(ast.len() == 1, ComputeError: "One and only one statement at a time please");
         let res = self.execute_statement(ast.get(0).unwrap());
         // every execution should clear the cte map
-        self.cte_map.borrow_mut().clear();
-        res
+        let ctes = std::mem::take(&mut *self.cte_map.borrow_mut());
+        res.map_err(|e| {
+            let mut shadowed = ctes
+                .keys()
+                .filter(|name| self.table_map.contains_key(*name))
+                .map(|name| format!("'{name}'"))
+                .collect::<Vec<_>>();
+            if shadowed.is_empty() {
+                e
+            } else {
+                shadowed.sort_unstable();
+                e.wrap_msg(&|msg| {
+                    format!(
+                        "{msg}\n\nnote: the CTEs {} shadow registered tables of the same name",
+                        shadowed.join(", ")
+                    )
+                })
+            }
+        })
     }
 
     pub(crate) fn execute_statement(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
@@ -80,15 +177,16 @@ impl SQLContext {
 
     pub(crate) fn execute_query(&mut self, query: &Query) -> PolarsResult<LazyFrame> {
         self.register_ctes(query)?;
-        let mut lf = match &query.body.as_ref() {
-            SetExpr::Select(select_stmt) => self.execute_select(select_stmt)?,
-            SetExpr::Query(query) => self.execute_query(query)?,
-            _ => polars_bail!(ComputeError: "INSERT, UPDATE is not supported"),
+        let lf = match &query.body.as_ref() {
+            // the select orders itself, as ORDER BY may refer to its aggregates
+            SetExpr::Select(select_stmt) => self.execute_select(select_stmt, &query.order_by)?,
+            // ORDER BY and LIMIT apply to the result of a set operation, not to its last operand
+            body => {
+                let lf = self.execute_set_expr(body)?;
+                self.process_order_by(lf, &query.order_by)?
+            }
         };
 
-        if !query.order_by.is_empty() {
-            lf = self.process_order_by(lf, &query.order_by)?;
-        }
         match &query.limit {
             Some(SqlExpr::Value(SQLValue::Number(nrow, _))) => {
                 let nrow = nrow
@@ -103,6 +201,63 @@ impl SQLContext {
         }
     }
 
+    fn execute_set_expr(&mut self, expr: &SetExpr) -> PolarsResult<LazyFrame> {
+        match expr {
+            SetExpr::Select(select_stmt) => self.execute_select(select_stmt, &[]),
+            SetExpr::Query(query) => self.execute_query(query),
+            SetExpr::SetOperation {
+                op,
+                set_quantifier,
+                left,
+                right,
+            } => self.execute_set_operation(op, set_quantifier, left, right),
+            _ => polars_bail!(ComputeError: "INSERT, UPDATE is not supported"),
+        }
+    }
+
+    /// Execute `UNION`, `INTERSECT` or `EXCEPT`, which match the columns of the operands by
+    /// position. The output has the column names of the left operand.
+    fn execute_set_operation(
+        &mut self,
+        op: &SetOperator,
+        set_quantifier: &SetQuantifier,
+        left: &SetExpr,
+        right: &SetExpr,
+    ) -> PolarsResult<LazyFrame> {
+        let left = self.execute_set_expr(left)?;
+        let right = self.execute_set_expr(right)?;
+        let (left, right) = unify_set_operands(op, left, right)?;
+        let all = matches!(set_quantifier, SetQuantifier::All);
+        let on = || {
+            left.schema().map(|schema| {
+                schema
+                    .iter_names()
+                    .map(|name| col(name))
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let lf = match op {
+            SetOperator::Union => concat([left, right], false, true)?,
+            SetOperator::Intersect | SetOperator::Except if all => {
+                polars_bail!(ComputeError: "{} ALL is not supported", op)
+            }
+            SetOperator::Intersect => {
+                let on = on()?;
+                left.join(right, &on, &on, JoinType::Semi)
+            }
+            SetOperator::Except => {
+                let on = on()?;
+                left.join(right, &on, &on, JoinType::Anti)
+            }
+        };
+        if all {
+            Ok(lf)
+        } else {
+            Ok(lf.unique_stable(None, UniqueKeepStrategy::First))
+        }
+    }
+
     fn execute_show_tables(&mut self, _: &Statement) -> PolarsResult<LazyFrame> {
         let tables = Series::new("name", self.tables.clone());
         let df = DataFrame::new(vec![tables])?;
@@ -114,9 +269,18 @@ impl SQLContext {
             if with.recursive {
                 polars_bail!(ComputeError: "Recursive CTEs are not supported")
             }
+            let mut names = PlHashSet::with_capacity(with.cte_tables.len());
             for cte in &with.cte_tables {
-                let cte_name = cte.alias.name.to_string();
-                let cte_lf = self.execute_query(&cte.query)?;
+                let cte_name = cte.alias.name.value.clone();
+                polars_ensure!(
+                    names.insert(cte_name.clone()),
+                    Duplicate: "CTE name '{}' is defined more than once", cte_name
+                );
+                // a CTE can refer to the CTEs that are defined before it
+                let mut cte_lf = self.execute_query(&cte.query)?;
+                if !cte.alias.columns.is_empty() {
+                    cte_lf = rename_cte_columns(cte_lf, &cte.alias)?;
+                }
                 self.register_cte(&cte_name, cte_lf);
             }
         }
@@ -160,7 +324,11 @@ impl SQLContext {
     }
 
     /// execute the 'SELECT' part of the query
-    fn execute_select(&mut self, select_stmt: &Select) -> PolarsResult<LazyFrame> {
+    fn execute_select(
+        &mut self,
+        select_stmt: &Select,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<LazyFrame> {
         // Determine involved dataframe
         // Implicit join require some more work in query parsers, Explicit join are preferred for now.
         let sql_tbl: &TableWithJoins = select_stmt
@@ -213,7 +381,18 @@ impl SQLContext {
                         )),
                         Ok(idx) => Ok(idx),
                     }?;
-                    Ok(projections[idx].clone())
+                    // GROUP BY ordinals are 1-based
+                    let projection = idx
+                        .checked_sub(1)
+                        .and_then(|i| projections.get(i))
+                        .ok_or_else(|| {
+                            polars_err!(
+                                ComputeError:
+                                "groupby error: ordinal {} is out of range for {} selected columns",
+                                idx, projections.len()
+                            )
+                        })?;
+                    Ok(projection.clone())
                 }
                 SqlExpr::Value(_) => Err(polars_err!(
                     ComputeError:
@@ -224,9 +403,21 @@ impl SQLContext {
             .collect::<PolarsResult<_>>()?;
 
         if groupby_keys.is_empty() {
-            Ok(lf.select(projections))
+            polars_ensure!(
+                select_stmt.having.is_none(),
+                ComputeError: "HAVING clause requires a GROUP BY clause"
+            );
+            let lf = lf.select(projections);
+            self.process_order_by(lf, order_by)
         } else {
-            self.process_groupby(lf, contains_wildcard, &groupby_keys, &projections)
+            self.process_groupby(
+                lf,
+                contains_wildcard,
+                &groupby_keys,
+                &projections,
+                select_stmt.having.as_ref(),
+                order_by,
+            )
         }
     }
 
@@ -289,6 +480,10 @@ impl SQLContext {
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.get(0).unwrap().value.as_str();
         let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
+        polars_ensure!(
+            self.file_access,
+            ComputeError: "table function '{}' is not allowed, as file access is disabled", tbl_fn
+        );
         let (tbl_name, lf) = read_fn.execute(args)?;
         let tbl_name = alias
             .as_ref()
@@ -301,11 +496,24 @@ impl SQLContext {
     }
 
     fn process_order_by(&mut self, lf: LazyFrame, ob: &[OrderByExpr]) -> PolarsResult<LazyFrame> {
+        if ob.is_empty() {
+            return Ok(lf);
+        }
+        let (by, descending) = self.parse_order_by(ob, |e| Ok(e))?;
+        Ok(lf.sort_by_exprs(&by, descending, false))
+    }
+
+    /// Parse the ORDER BY expressions, and map them with `resolve`.
+    fn parse_order_by(
+        &mut self,
+        ob: &[OrderByExpr],
+        mut resolve: impl FnMut(Expr) -> PolarsResult<Expr>,
+    ) -> PolarsResult<(Vec<Expr>, Vec<bool>)> {
         let mut by = Vec::with_capacity(ob.len());
         let mut descending = Vec::with_capacity(ob.len());
 
         for ob in ob {
-            by.push(parse_sql_expr(&ob.expr, self)?);
+            by.push(resolve(parse_sql_expr(&ob.expr, self)?)?);
             if let Some(false) = ob.asc {
                 descending.push(true)
             } else {
@@ -316,8 +524,7 @@ impl SQLContext {
                 ComputeError: "nulls first/last is not yet supported",
             );
         }
-
-        Ok(lf.sort_by_exprs(&by, descending, false))
+        Ok((by, descending))
     }
 
     fn process_groupby(
@@ -326,6 +533,8 @@ impl SQLContext {
         contains_wildcard: bool,
         groupby_keys: &[Expr],
         projections: &[Expr],
+        having: Option<&SqlExpr>,
+        order_by: &[OrderByExpr],
     ) -> PolarsResult<LazyFrame> {
         // check groupby and projection due to difference between SQL and polars
         // Return error on wild card, shouldn't process this
@@ -334,46 +543,91 @@ impl SQLContext {
             ComputeError: "groupby error: can't process wildcard in groupby"
         );
         let schema_before = lf.schema()?;
+        let key_names = groupby_keys
+            .iter()
+            .map(|e| Ok(e.to_field(&schema_before, Context::Default)?.name))
+            .collect::<PolarsResult<Vec<_>>>()?;
 
-        let groupby_keys_schema =
-            expressions_to_schema(groupby_keys, &schema_before, Context::Default)?;
-
-        // remove the groupby keys as polars adds those implicitly
+        // Every projection is either a groupby key or an aggregation, which we resolve to its
+        // column in the aggregated frame. The polars groupby adds the keys implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
-        let mut aliases: BTreeSet<&str> = BTreeSet::new();
-
-        for mut e in projections {
-            // if it is a simple expression & has alias,
-            // we must defer the aliasing until after the groupby
-            if e.clone().meta().is_simple_projection() {
-                if let Expr::Alias(expr, name) = e {
-                    aliases.insert(name);
-                    e = expr
+        let mut resolved = Vec::with_capacity(projections.len());
+        let mut final_projection = Vec::with_capacity(projections.len());
+        for e in projections {
+            let name = e.to_field(&schema_before, Context::Default)?.name;
+            let inner = strip_alias(e);
+            let column = match groupby_keys.iter().position(|k| strip_alias(k) == inner) {
+                Some(i) => key_names[i].clone(),
+                None => {
+                    aggregation_projection.push(e.clone());
+                    name.clone()
                 }
-            }
-
-            let field = e.to_field(&schema_before, Context::Default)?;
-            if groupby_keys_schema.get(&field.name).is_none() {
-                aggregation_projection.push(e.clone())
-            }
+            };
+            final_projection.push(col(&column).alias(&name));
+            resolved.push((inner.clone(), name, column));
         }
 
-        let aggregated = lf.groupby(groupby_keys).agg(&aggregation_projection);
-        let projection_schema =
-            expressions_to_schema(projections, &schema_before, Context::Default)?;
-        // a final projection to get the proper order
-        let final_projection = projection_schema
-            .iter_names()
-            .zip(projections)
-            .map(|(name, projection_expr)| {
-                if groupby_keys_schema.get(name).is_some() || aliases.contains(name.as_str()) {
-                    projection_expr.clone()
+        // HAVING and ORDER BY are evaluated on the aggregated frame. They may refer to the
+        // selected expressions, to their aliases and to aggregations that are not selected.
+        let mut n_hidden = 0;
+        let mut resolve = |mut expr: Expr| -> PolarsResult<Expr> {
+            expr.mutate().apply(|e| {
+                let replacement = if let Some((_, _, column)) =
+                    resolved.iter().find(|(inner, _, _)| inner == &*e)
+                {
+                    Some(col(column))
+                } else if let Expr::Column(name) = &*e {
+                    // an alias of the select, source columns take precedence
+                    resolved
+                        .iter()
+                        .find(|(_, alias, _)| {
+                            !schema_before.contains(name) && alias.as_str() == &**name
+                        })
+                        .map(|(_, _, column)| col(column))
+                } else if matches!(e, Expr::Agg(_) | Expr::Count) {
+                    let name = format!("__POLARS_AGG_{n_hidden}");
+                    n_hidden += 1;
+                    aggregation_projection.push(e.clone().alias(&name));
+                    Some(col(&name))
                 } else {
-                    col(name)
+                    None
+                };
+                if let Some(replacement) = replacement {
+                    *e = replacement;
                 }
-            })
-            .collect::<Vec<_>>();
+                true
+            });
+            Ok(expr)
+        };
+        let having = having
+            .map(|e| resolve(parse_sql_expr(e, self)?))
+            .transpose()?;
+        let (by, descending) = self.parse_order_by(order_by, &mut resolve)?;
+
+        let mut aggregated = lf.groupby(groupby_keys).agg(&aggregation_projection);
+        let aggregated_schema = aggregated.schema()?;
+        for (clause, exprs) in [
+            ("HAVING", having.iter().collect::<Vec<_>>()),
+            ("ORDER BY", by.iter().collect()),
+        ] {
+            for e in exprs.into_iter().flat_map(|e| e.into_iter()) {
+                if let Expr::Column(name) = e {
+                    polars_ensure!(
+                        aggregated_schema.contains(name),
+                        ColumnNotFound:
+                        "'{}' in the {} clause is not a groupby key, an aggregation or a selected alias",
+                        name, clause
+                    );
+                }
+            }
+        }
 
+        if let Some(having) = having {
+            aggregated = aggregated.filter(having);
+        }
+        if !by.is_empty() {
+            aggregated = aggregated.sort_by_exprs(&by, descending, false);
+        }
         Ok(aggregated.select(&final_projection))
     }
 }
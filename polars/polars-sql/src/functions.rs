@@ -1,9 +1,13 @@
-use polars_core::prelude::{polars_bail, polars_err, PolarsError, PolarsResult};
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, DataType, PolarsError, PolarsResult, RankMethod,
+    RankOptions, SortOptions, TimeUnit, IDX_DTYPE,
+};
 use polars_lazy::dsl::Expr;
-use polars_plan::dsl::count;
+use polars_plan::dsl::{arg_sort_by, coalesce, count, first, lit, when, StrpTimeOptions};
+use polars_plan::prelude::LiteralValue;
 use sqlparser::ast::{
-    Expr as SqlExpr, Function as SQLFunction, FunctionArg, FunctionArgExpr, Value as SqlValue,
-    WindowSpec,
+    Expr as SqlExpr, Function as SQLFunction, FunctionArg, FunctionArgExpr, UnaryOperator,
+    Value as SqlValue, WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 
 use crate::sql_expr::parse_sql_expr;
@@ -119,6 +123,95 @@ pub(crate) enum PolarsSqlFunctions {
     /// SELECT column_2 from df WHERE ENDS_WITH(column_1, 'a');
     /// ```
     EndsWith,
+    /// SQL 'substr' function
+    /// Returns the characters from a 1-based position, optionally up to a length
+    /// ```sql
+    /// SELECT SUBSTR(column_1, 2, 3) from df;
+    /// ```
+    Substring,
+    /// SQL 'replace' function
+    /// Replaces all occurrences of a substring
+    /// ```sql
+    /// SELECT REPLACE(column_1, 'old', 'new') from df;
+    /// ```
+    Replace,
+    /// SQL 'lpad' function
+    /// Pads the start of the string to a length, or truncates it to that length
+    /// ```sql
+    /// SELECT LPAD(column_1, 5, '0') from df;
+    /// ```
+    LPad,
+    /// SQL 'rpad' function
+    /// Pads the end of the string to a length, or truncates it to that length
+    /// ```sql
+    /// SELECT RPAD(column_1, 5, ' ') from df;
+    /// ```
+    RPad,
+    /// SQL 'split_part' function
+    /// Returns the n-th (1-based) part of the string split on a delimiter, negative
+    /// positions count from the end
+    /// ```sql
+    /// SELECT SPLIT_PART(column_1, ',', 2) from df;
+    /// ```
+    SplitPart,
+    /// SQL 'regexp_like' function
+    /// Returns true if the string matches the regex, with optional flags such as 'i'
+    /// ```sql
+    /// SELECT REGEXP_LIKE(column_1, '^a.*', 'i') from df;
+    /// ```
+    RegexpLike,
+    // ----
+    // Temporal functions
+    // ----
+    /// SQL 'date_trunc' function
+    /// Truncates a date or timestamp to a unit, such as 'day' or 'month'
+    /// ```sql
+    /// SELECT DATE_TRUNC('month', column_1) from df;
+    /// ```
+    DateTrunc,
+    /// SQL 'date_part' function, the function form of `EXTRACT(field FROM column_1)`
+    /// ```sql
+    /// SELECT DATE_PART('year', column_1) from df;
+    /// ```
+    DatePart,
+    /// SQL 'strftime' function
+    /// Formats a date or timestamp as a string
+    /// ```sql
+    /// SELECT STRFTIME(column_1, '%Y-%m-%d') from df;
+    /// ```
+    Strftime,
+    /// SQL 'to_date' function
+    /// Parses a string as a date, with an optional format
+    /// ```sql
+    /// SELECT TO_DATE(column_1, '%d/%m/%Y') from df;
+    /// ```
+    ToDate,
+    /// SQL 'to_timestamp' function
+    /// Parses a string as a timestamp, with an optional format
+    /// ```sql
+    /// SELECT TO_TIMESTAMP(column_1, '%Y-%m-%d %H:%M') from df;
+    /// ```
+    ToTimestamp,
+    // ----
+    // Conditional functions
+    // ----
+    /// SQL 'coalesce' function
+    /// Returns the first non-null value
+    /// ```sql
+    /// SELECT COALESCE(column_1, column_2, 0) from df;
+    /// ```
+    Coalesce,
+    /// SQL 'ifnull' function, `COALESCE` of two values
+    /// ```sql
+    /// SELECT IFNULL(column_1, 0) from df;
+    /// ```
+    IfNull,
+    /// SQL 'nullif' function
+    /// Returns null if both values are equal, otherwise the first value
+    /// ```sql
+    /// SELECT NULLIF(column_1, 0) from df;
+    /// ```
+    NullIf,
     // ----
     // Aggregate functions
     // ----
@@ -171,6 +264,49 @@ pub(crate) enum PolarsSqlFunctions {
     /// ```
     Last,
     // ----
+    // Window functions
+    // ----
+    /// SQL 'row_number' function
+    /// Returns the 1-based position of the row in its window
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    RowNumber,
+    /// SQL 'rank' function
+    /// Returns the rank of the row in its window, with gaps after ties
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    Rank,
+    /// SQL 'dense_rank' function
+    /// Returns the rank of the row in its window, without gaps after ties
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    DenseRank,
+    /// SQL 'lag' function
+    /// Returns the value of the row `n` rows before the current row in its window
+    /// ```sql
+    /// SELECT LAG(column_1, 1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Returns the value of the row `n` rows after the current row in its window
+    /// ```sql
+    /// SELECT LEAD(column_1, 1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    Lead,
+    /// SQL 'first_value' function
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    FirstValue,
+    /// SQL 'last_value' function
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    LastValue,
+    // ----
     // Array functions
     // ----
     /// SQL 'array_length' function
@@ -256,6 +392,21 @@ impl PolarsSqlFunctions {
             "rtrim",
             "starts_with",
             "ends_with",
+            "substr",
+            "substring",
+            "replace",
+            "lpad",
+            "rpad",
+            "split_part",
+            "regexp_like",
+            "date_trunc",
+            "date_part",
+            "strftime",
+            "to_date",
+            "to_timestamp",
+            "coalesce",
+            "ifnull",
+            "nullif",
             "count",
             "sum",
             "min",
@@ -265,6 +416,13 @@ impl PolarsSqlFunctions {
             "variance",
             "first",
             "last",
+            "row_number",
+            "rank",
+            "dense_rank",
+            "lag",
+            "lead",
+            "first_value",
+            "last_value",
             "array_length",
             "array_lower",
             "array_upper",
@@ -309,6 +467,26 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
             "rtrim" => Self::RTrim,
             "starts_with" => Self::StartsWith,
             "ends_with" => Self::EndsWith,
+            "substr" | "substring" => Self::Substring,
+            "replace" => Self::Replace,
+            "lpad" => Self::LPad,
+            "rpad" => Self::RPad,
+            "split_part" => Self::SplitPart,
+            "regexp_like" => Self::RegexpLike,
+            // ----
+            // Temporal functions
+            // ----
+            "date_trunc" => Self::DateTrunc,
+            "date_part" => Self::DatePart,
+            "strftime" => Self::Strftime,
+            "to_date" => Self::ToDate,
+            "to_timestamp" => Self::ToTimestamp,
+            // ----
+            // Conditional functions
+            // ----
+            "coalesce" => Self::Coalesce,
+            "ifnull" => Self::IfNull,
+            "nullif" => Self::NullIf,
             // ----
             // Aggregate functions
             // ----
@@ -322,6 +500,16 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
             "first" => Self::First,
             "last" => Self::Last,
             // ----
+            // Window functions
+            // ----
+            "row_number" => Self::RowNumber,
+            "rank" => Self::Rank,
+            "dense_rank" => Self::DenseRank,
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+            "first_value" => Self::FirstValue,
+            "last_value" => Self::LastValue,
+            // ----
             // Array functions
             // ----
             "array_length" => Self::ArrayLength,
@@ -344,6 +532,9 @@ impl SqlFunctionVisitor<'_> {
         let function = self.func;
 
         let function_name: PolarsSqlFunctions = function.try_into()?;
+        if let Some(window_spec) = &function.over {
+            return self.visit_window_function(function_name, window_spec);
+        }
         use PolarsSqlFunctions::*;
         match function_name {
             // ----
@@ -370,21 +561,152 @@ impl SqlFunctionVisitor<'_> {
             LTrim => match function.args.len() {
                 1 => self.visit_unary(|e| e.str().lstrip(None)),
                 2 => self.visit_binary(|e, s| e.str().lstrip(Some(s))),
-                _ => panic!(
-                    "Invalid number of arguments for LTrim: {}",
-                    function.args.len()
-                ),
+                _ => Err(self.arg_count_error(1, 2)),
             },
             RTrim => match function.args.len() {
                 1 => self.visit_unary(|e| e.str().rstrip(None)),
                 2 => self.visit_binary(|e, s| e.str().rstrip(Some(s))),
-                _ => panic!(
-                    "Invalid number of arguments for RTrim: {}",
-                    function.args.len()
-                ),
+                _ => Err(self.arg_count_error(1, 2)),
             },
             StartsWith => self.visit_binary(|e, s| e.str().starts_with(s)),
             EndsWith => self.visit_binary(|e, s| e.str().ends_with(s)),
+            Substring => {
+                let args = self.visit_args(2, 3)?;
+                let length = if args.len() == 3 {
+                    Some(self.literal_arg(2, "an integer")?)
+                } else {
+                    None
+                };
+                substring(
+                    &self.name(),
+                    args[0].clone(),
+                    self.literal_arg(1, "an integer")?,
+                    length,
+                )
+            }
+            Replace => {
+                let args = self.visit_args(3, 3)?;
+                Ok(args[0]
+                    .clone()
+                    .str()
+                    .replace_all(args[1].clone(), args[2].clone(), true))
+            }
+            LPad | RPad => {
+                let args = self.visit_args(2, 3)?;
+                let length: i64 = self.literal_arg(1, "an integer")?;
+                polars_ensure!(
+                    length >= 0,
+                    ComputeError: "{} expects a non-negative length, got {}", self.name(), length
+                );
+                let fill = if args.len() == 3 {
+                    let fill: String = self.literal_arg(2, "a string")?;
+                    let mut chars = fill.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => c,
+                        _ => polars_bail!(
+                            ComputeError:
+                            "{} expects a single fill character, got '{}'", self.name(), fill
+                        ),
+                    }
+                } else {
+                    ' '
+                };
+                let padded = match function_name {
+                    LPad => args[0].clone().str().rjust(length as usize, fill),
+                    _ => args[0].clone().str().ljust(length as usize, fill),
+                };
+                // longer strings are truncated to the length
+                Ok(padded.str().str_slice(0, Some(length as u64)))
+            }
+            SplitPart => {
+                let args = self.visit_args(3, 3)?;
+                let delimiter: String = self.literal_arg(1, "a string")?;
+                let n: i64 = self.literal_arg(2, "an integer")?;
+                polars_ensure!(
+                    n != 0,
+                    ComputeError: "{} positions are 1-based, got 0", self.name()
+                );
+                let idx = if n > 0 { n - 1 } else { n };
+                Ok(args[0].clone().str().split(&delimiter).arr().get(lit(idx)))
+            }
+            RegexpLike => {
+                let args = self.visit_args(2, 3)?;
+                let pattern = if args.len() == 3 {
+                    let flags: String = self.literal_arg(2, "a string")?;
+                    polars_ensure!(
+                        !flags.is_empty() && flags.chars().all(|c| "imsUx".contains(c)),
+                        ComputeError:
+                        "{} expects regex flags from 'imsUx', got '{}'", self.name(), flags
+                    );
+                    lit(format!("(?{flags})")) + args[1].clone()
+                } else {
+                    args[1].clone()
+                };
+                Ok(args[0].clone().str().contains(pattern, true))
+            }
+            // ----
+            // Temporal functions
+            // ----
+            DateTrunc => {
+                let args = self.visit_args(2, 2)?;
+                let unit: String = self.literal_arg(0, "a string")?;
+                let every = match unit.to_lowercase().as_str() {
+                    "microsecond" | "microseconds" => "1us",
+                    "millisecond" | "milliseconds" => "1ms",
+                    "second" | "seconds" => "1s",
+                    "minute" | "minutes" => "1m",
+                    "hour" | "hours" => "1h",
+                    "day" | "days" => "1d",
+                    "week" | "weeks" => "1w",
+                    "month" | "months" => "1mo",
+                    "quarter" | "quarters" => "3mo",
+                    "year" | "years" => "1y",
+                    _ => polars_bail!(
+                        ComputeError: "{} does not support the unit '{}'", self.name(), unit
+                    ),
+                };
+                Ok(args[1].clone().dt().truncate(every, "0ns"))
+            }
+            DatePart => {
+                let args = self.visit_args(2, 2)?;
+                let field: String = self.literal_arg(0, "a string")?;
+                date_part(&self.name(), args[1].clone(), &field)
+            }
+            Strftime => {
+                let args = self.visit_args(2, 2)?;
+                let format: String = self.literal_arg(1, "a string")?;
+                Ok(args[0].clone().dt().strftime(&format))
+            }
+            ToDate | ToTimestamp => {
+                let args = self.visit_args(1, 2)?;
+                let format = if args.len() == 2 {
+                    Some(self.literal_arg(1, "a string")?)
+                } else {
+                    None
+                };
+                let date_dtype = match function_name {
+                    ToDate => DataType::Date,
+                    _ => DataType::Datetime(TimeUnit::Microseconds, None),
+                };
+                Ok(args[0].clone().str().strptime(StrpTimeOptions {
+                    date_dtype,
+                    format,
+                    strict: true,
+                    exact: true,
+                    ..Default::default()
+                }))
+            }
+            // ----
+            // Conditional functions
+            // ----
+            Coalesce => Ok(coalesce(&self.visit_args(1, usize::MAX)?)),
+            IfNull => Ok(coalesce(&self.visit_args(2, 2)?)),
+            NullIf => {
+                let args = self.visit_args(2, 2)?;
+                Ok(when(args[0].clone().eq(args[1].clone()))
+                    .then(Expr::Literal(LiteralValue::Null))
+                    .otherwise(args[0].clone()))
+            }
             // ----
             // Aggregate functions
             // ----
@@ -398,6 +720,13 @@ impl SqlFunctionVisitor<'_> {
             First => self.visit_unary(Expr::first),
             Last => self.visit_unary(Expr::last),
             // ----
+            // Window functions
+            // ----
+            RowNumber | Rank | DenseRank | Lag | Lead | FirstValue | LastValue => polars_bail!(
+                InvalidOperation:
+                "function `{}` requires an OVER clause", function.name.0[0].value
+            ),
+            // ----
             // Array functions
             // ----
             ArrayLength => self.visit_unary(|e| e.arr().lengths()),
@@ -413,6 +742,56 @@ impl SqlFunctionVisitor<'_> {
         }
     }
 
+    /// The name of the function as written in the query, for error messages.
+    fn name(&self) -> String {
+        self.func.name.to_string().to_uppercase()
+    }
+
+    fn arg_count_error(&self, min: usize, max: usize) -> PolarsError {
+        let expected = match max {
+            _ if max == min => min.to_string(),
+            usize::MAX => format!("at least {min}"),
+            _ => format!("{min} to {max}"),
+        };
+        polars_err!(
+            ComputeError: "{} expects {} arguments, got {}",
+            self.name(), expected, self.func.args.len()
+        )
+    }
+
+    /// Parse the arguments of the function, which must be expressions and of which there
+    /// must be between `min` and `max`.
+    fn visit_args(&self, min: usize, max: usize) -> PolarsResult<Vec<Expr>> {
+        let args = extract_args(self.func);
+        if args.len() < min || args.len() > max {
+            return Err(self.arg_count_error(min, max));
+        }
+        args.into_iter()
+            .map(|arg| match arg {
+                FunctionArgExpr::Expr(sql_expr) => parse_sql_expr(sql_expr, self.ctx),
+                _ => polars_bail!(
+                    ComputeError: "{} does not accept the argument {}", self.name(), arg
+                ),
+            })
+            .collect()
+    }
+
+    /// The argument at `idx`, which must be a literal, such as a format string.
+    fn literal_arg<T: FromSqlExpr>(&self, idx: usize, kind: &str) -> PolarsResult<T> {
+        let args = extract_args(self.func);
+        match args.get(idx) {
+            Some(FunctionArgExpr::Expr(sql_expr)) => {
+                T::from_sql_expr(sql_expr, self.ctx).map_err(|_| {
+                    polars_err!(
+                        ComputeError: "{} expects {} literal as argument {}, got {}",
+                        self.name(), kind, idx + 1, sql_expr
+                    )
+                })
+            }
+            _ => Err(self.arg_count_error(idx + 1, idx + 1)),
+        }
+    }
+
     fn visit_unary(&self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         let function = self.func;
         let args = extract_args(function);
@@ -420,9 +799,7 @@ impl SqlFunctionVisitor<'_> {
             // parse the inner sql expr -- e.g. SUM(a) -> a
             let expr = parse_sql_expr(sql_expr, self.ctx)?;
             // apply the function on the inner expr -- e.g. SUM(a) -> SUM
-            let expr = f(expr);
-            // apply the window spec if present
-            self.apply_window_spec(expr, &function.over)
+            Ok(f(expr))
         } else {
             not_supported_error(function.name.0[0].value.as_str(), &args)
         }
@@ -432,8 +809,7 @@ impl SqlFunctionVisitor<'_> {
         let function = self.func;
         let args = extract_args(function);
         if let FunctionArgExpr::Expr(sql_expr) = args[0] {
-            let expr =
-                self.apply_window_spec(parse_sql_expr(sql_expr, self.ctx)?, &function.over)?;
+            let expr = parse_sql_expr(sql_expr, self.ctx)?;
             if let FunctionArgExpr::Expr(sql_expr) = args[1] {
                 let expr2 = Arg::from_sql_expr(sql_expr, self.ctx)?;
                 Ok(f(expr, expr2))
@@ -454,11 +830,7 @@ impl SqlFunctionVisitor<'_> {
             (0, true) => return not_supported_error("count", &args),
             (1, false) => match args[0] {
                 // count(col)
-                FunctionArgExpr::Expr(sql_expr) => {
-                    let expr = self
-                        .apply_window_spec(parse_sql_expr(sql_expr, self.ctx)?, &self.func.over)?;
-                    expr.count()
-                }
+                FunctionArgExpr::Expr(sql_expr) => parse_sql_expr(sql_expr, self.ctx)?.count(),
                 // count(*)
                 FunctionArgExpr::Wildcard => count(),
                 // count(tbl.*) is not supported
@@ -467,9 +839,7 @@ impl SqlFunctionVisitor<'_> {
             (1, true) => {
                 // count(distinct col)
                 if let FunctionArgExpr::Expr(sql_expr) = args[0] {
-                    let expr = self
-                        .apply_window_spec(parse_sql_expr(sql_expr, self.ctx)?, &self.func.over)?;
-                    expr.n_unique()
+                    parse_sql_expr(sql_expr, self.ctx)?.n_unique()
                 } else {
                     // count(distinct *) or count(distinct tbl.*) is not supported
                     return not_supported_error("count", &args);
@@ -478,25 +848,287 @@ impl SqlFunctionVisitor<'_> {
             _ => return not_supported_error("count", &args),
         })
     }
-    fn apply_window_spec(
+
+    /// Translate a function with an `OVER` clause to a window expression.
+    fn visit_window_function(
         &self,
-        expr: Expr,
-        window_spec: &Option<WindowSpec>,
+        function_name: PolarsSqlFunctions,
+        window_spec: &WindowSpec,
     ) -> PolarsResult<Expr> {
-        Ok(match &window_spec {
-            Some(window_spec) => {
-                // Process for simple window specification, partition by first
-                let partition_by = window_spec
-                    .partition_by
-                    .iter()
-                    .map(|p| parse_sql_expr(p, self.ctx))
-                    .collect::<PolarsResult<Vec<_>>>()?;
-                expr.over(partition_by)
-                // Order by and Row range may not be supported at the moment
+        let function = self.func;
+        let name = function.name.0[0].value.to_lowercase();
+        let window = Window::try_new(window_spec, self.ctx)?;
+        let args = extract_args(function);
+        let arg = |i: usize| match args.get(i) {
+            Some(FunctionArgExpr::Expr(sql_expr)) => parse_sql_expr(sql_expr, self.ctx),
+            _ => not_supported_error(&name, &args),
+        };
+        let running_not_supported = || {
+            polars_err!(
+                InvalidOperation:
+                "function `{}` is not supported with a window frame that ends at the current row",
+                name
+            )
+        };
+
+        use PolarsSqlFunctions::*;
+        let expr = match function_name {
+            Sum | Min | Max | Avg if window.running => {
+                let expr = arg(0)?;
+                window.in_order(expr, |e| {
+                    let running = match function_name {
+                        Sum => e.cumsum(false),
+                        Min => e.cummin(false),
+                        Max => e.cummax(false),
+                        _ => {
+                            e.clone().cumsum(false).cast(DataType::Float64)
+                                / e.is_not_null().cumsum(false).cast(DataType::Float64)
+                        }
+                    };
+                    // null rows keep the aggregate of the rows before them
+                    window.frame_end(running.forward_fill(None))
+                })
+            }
+            Count if window.running => match (args.as_slice(), function.distinct) {
+                ([] | [FunctionArgExpr::Wildcard], false) => {
+                    window.in_order(first(), |e| window.frame_end(e.cumcount(false) + lit(1)))
+                }
+                ([FunctionArgExpr::Expr(_)], false) => {
+                    window.in_order(arg(0)?, |e| window.frame_end(e.is_not_null().cumsum(false)))
+                }
+                _ => return Err(running_not_supported()),
+            },
+            StdDev | Variance if window.running => return Err(running_not_supported()),
+            // the aggregation of the whole partition
+            Sum => self.visit_unary(Expr::sum)?,
+            Min => self.visit_unary(Expr::min)?,
+            Max => self.visit_unary(Expr::max)?,
+            Avg => self.visit_unary(Expr::mean)?,
+            StdDev => self.visit_unary(|e| e.std(1))?,
+            Variance => self.visit_unary(|e| e.var(1))?,
+            Count => self.visit_count()?,
+            First | FirstValue => window.sorted(arg(0)?).first(),
+            // the last row of the frame is the current row or its last peer
+            Last | LastValue if window.running => window.in_order(arg(0)?, |e| window.frame_end(e)),
+            Last | LastValue => window.sorted(arg(0)?).last(),
+            RowNumber => window
+                .in_order(first(), |e| e.cumcount(false) + lit(1))
+                .alias("row_number"),
+            Rank | DenseRank => {
+                polars_ensure!(
+                    window.order_by.len() == 1,
+                    InvalidOperation:
+                    "function `{}` requires exactly one ORDER BY expression in its window", name
+                );
+                let options = RankOptions {
+                    method: match function_name {
+                        Rank => RankMethod::Min,
+                        _ => RankMethod::Dense,
+                    },
+                    descending: window.descending[0],
+                };
+                window.order_by[0].clone().rank(options, None).alias(&name)
+            }
+            Lag | Lead => {
+                let n = match args.len() {
+                    1 => 1,
+                    2 => match args[1] {
+                        FunctionArgExpr::Expr(sql_expr) => i64::from_sql_expr(sql_expr, self.ctx)?,
+                        _ => return not_supported_error(&name, &args),
+                    },
+                    _ => return not_supported_error(&name, &args),
+                };
+                let periods = if matches!(function_name, Lag) { n } else { -n };
+                window.in_order(arg(0)?, |e| e.shift(periods))
             }
-            None => expr,
+            _ => polars_bail!(InvalidOperation: "function `{}` is not a window function", name),
+        };
+        Ok(window.over(expr))
+    }
+}
+
+/// The `OVER (PARTITION BY .. ORDER BY .. <frame>)` clause of a window function.
+struct Window {
+    partition_by: Vec<Expr>,
+    order_by: Vec<Expr>,
+    descending: Vec<bool>,
+    /// Whether the frame ends at the current row, rather than spanning the whole partition.
+    running: bool,
+    /// Whether the frame is in RANGE mode, where it ends at the last peer of the current row:
+    /// the last row with the same ORDER BY values.
+    peers: bool,
+}
+
+impl Window {
+    fn try_new(window_spec: &WindowSpec, ctx: &SQLContext) -> PolarsResult<Self> {
+        let partition_by = window_spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, ctx))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut order_by = Vec::with_capacity(window_spec.order_by.len());
+        let mut descending = Vec::with_capacity(window_spec.order_by.len());
+        for ob in &window_spec.order_by {
+            polars_ensure!(
+                ob.nulls_first.is_none(),
+                ComputeError: "nulls first/last is not yet supported",
+            );
+            order_by.push(parse_sql_expr(&ob.expr, ctx)?);
+            descending.push(ob.asc == Some(false));
+        }
+
+        // the default frame is `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`
+        let (running, peers) = match &window_spec.window_frame {
+            None => (!order_by.is_empty(), true),
+            Some(frame) => match (&frame.start_bound, &frame.end_bound) {
+                (WindowFrameBound::Preceding(None), Some(WindowFrameBound::Following(None))) => {
+                    (false, false)
+                }
+                (WindowFrameBound::Preceding(None), None | Some(WindowFrameBound::CurrentRow)) => {
+                    match frame.units {
+                        WindowFrameUnits::Rows => (true, false),
+                        // without an ORDER BY all rows are peers
+                        WindowFrameUnits::Range => (!order_by.is_empty(), true),
+                        WindowFrameUnits::Groups => polars_bail!(
+                            InvalidOperation: "window frames in GROUPS mode are not supported"
+                        ),
+                    }
+                }
+                _ => polars_bail!(
+                    InvalidOperation:
+                    "window frame {:?} is not supported, only frames from UNBOUNDED PRECEDING to \
+                    UNBOUNDED FOLLOWING or to the CURRENT ROW are", frame
+                ),
+            },
+        };
+        Ok(Self {
+            partition_by,
+            order_by,
+            descending,
+            running,
+            peers,
         })
     }
+
+    /// The values of `expr` in the window order.
+    fn sorted(&self, expr: Expr) -> Expr {
+        if self.order_by.is_empty() {
+            expr
+        } else {
+            expr.take(arg_sort_by(&self.order_by, &self.descending))
+        }
+    }
+
+    /// Evaluate `f` on the values of `expr` in the window order, and return its output in
+    /// the original row order.
+    fn in_order(&self, expr: Expr, f: impl FnOnce(Expr) -> Expr) -> Expr {
+        if self.order_by.is_empty() {
+            f(expr)
+        } else {
+            let idx = arg_sort_by(&self.order_by, &self.descending);
+            f(expr.take(idx.clone())).take(idx.arg_sort(SortOptions::default()))
+        }
+    }
+
+    /// Take the values of a running aggregation `running`, in the window order, at the end of
+    /// the frame of every row. In RANGE mode that is the last peer of the row.
+    fn frame_end(&self, running: Expr) -> Expr {
+        if !self.peers || self.order_by.is_empty() {
+            return running;
+        }
+        let idx = arg_sort_by(&self.order_by, &self.descending);
+        let keys = self
+            .order_by
+            .iter()
+            .map(|e| e.clone().take(idx.clone()))
+            .collect::<Vec<_>>();
+        // only the last row has no next row
+        let is_last_row = keys[0].clone().is_null().shift(-1).is_null();
+        let last_of_peers = keys.into_iter().fold(is_last_row, |acc, key| {
+            let next = key.clone().shift(-1);
+            let differs = when(key.clone().is_null().or(next.clone().is_null()))
+                .then(key.clone().is_null().neq(next.clone().is_null()))
+                .otherwise(key.neq(next));
+            acc.or(differs)
+        });
+        // the peers are adjacent in the window order, their group is the number of groups before
+        let ends = last_of_peers.clone().cast(IDX_DTYPE);
+        let peer_group = ends.clone().cumsum(false) - ends;
+        running.filter(last_of_peers).take(peer_group)
+    }
+
+    fn over(&self, expr: Expr) -> Expr {
+        if self.partition_by.is_empty() {
+            expr
+        } else {
+            expr.over(&self.partition_by)
+        }
+    }
+}
+
+/// SQL `SUBSTRING(expr FROM start FOR length)`, where `start` is 1-based and a start before the
+/// first character shortens the substring.
+pub(crate) fn substring(
+    name: &str,
+    expr: Expr,
+    start: i64,
+    length: Option<i64>,
+) -> PolarsResult<Expr> {
+    if let Some(length) = length {
+        polars_ensure!(
+            length >= 0,
+            ComputeError: "{} expects a non-negative length, got {}", name, length
+        );
+    }
+    let (offset, length) = if start >= 1 {
+        (start - 1, length)
+    } else {
+        (0, length.map(|length| (length + start - 1).max(0)))
+    };
+    Ok(expr
+        .str()
+        .str_slice(offset, length.map(|length| length as u64)))
+}
+
+/// SQL `EXTRACT(field FROM expr)` and `DATE_PART('field', expr)`.
+pub(crate) fn date_part(name: &str, expr: Expr, field: &str) -> PolarsResult<Expr> {
+    let dt = || expr.clone().dt();
+    Ok(match field.to_lowercase().as_str() {
+        "millennium" | "millenium" => (dt().year() - lit(1)) / lit(1000) + lit(1),
+        "century" => (dt().year() - lit(1)) / lit(100) + lit(1),
+        "decade" => dt().year() / lit(10),
+        "year" | "years" => dt().year(),
+        "isoyear" => dt().iso_year(),
+        "quarter" => dt().quarter(),
+        "month" | "months" => dt().month(),
+        "week" | "weeks" => dt().week(),
+        "day" | "days" => dt().day(),
+        // 0 is sunday
+        "dow" | "dayofweek" => dt().weekday() % lit(7),
+        "isodow" => dt().weekday(),
+        "doy" | "dayofyear" => dt().ordinal_day(),
+        "hour" | "hours" => dt().hour(),
+        "minute" | "minutes" => dt().minute(),
+        // the seconds include the fractional seconds
+        "second" | "seconds" => {
+            dt().second().cast(DataType::Float64)
+                + dt().nanosecond().cast(DataType::Float64) / lit(1e9)
+        }
+        "millisecond" | "milliseconds" => {
+            dt().second().cast(DataType::Float64) * lit(1e3)
+                + dt().nanosecond().cast(DataType::Float64) / lit(1e6)
+        }
+        "microsecond" | "microseconds" => {
+            dt().second().cast(DataType::Float64) * lit(1e6)
+                + dt().nanosecond().cast(DataType::Float64) / lit(1e3)
+        }
+        "epoch" => {
+            dt().timestamp(TimeUnit::Microseconds)
+                .cast(DataType::Float64)
+                / lit(1e6)
+        }
+        _ => polars_bail!(ComputeError: "{} does not support the field '{}'", name, field),
+    })
 }
 
 fn not_supported_error(function_name: &str, args: &Vec<&FunctionArgExpr>) -> PolarsResult<Expr> {
@@ -541,6 +1173,24 @@ impl FromSqlExpr for f64 {
     }
 }
 
+impl FromSqlExpr for i64 {
+    fn from_sql_expr(expr: &SqlExpr, ctx: &SQLContext) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        match expr {
+            SqlExpr::Value(SqlValue::Number(s, _)) => s
+                .parse()
+                .map_err(|_| polars_err!(ComputeError: "can't parse integer literal {:?}", s)),
+            SqlExpr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr,
+            } => Ok(-i64::from_sql_expr(expr, ctx)?),
+            _ => polars_bail!(ComputeError: "can't parse integer literal {:?}", expr),
+        }
+    }
+}
+
 impl FromSqlExpr for String {
     fn from_sql_expr(expr: &SqlExpr, _: &SQLContext) -> PolarsResult<Self>
     where
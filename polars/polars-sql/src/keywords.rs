@@ -53,6 +53,8 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::NOT,
         keywords::IN,
         keywords::WITH,
+        keywords::OVER,
+        keywords::PARTITION,
     ];
     keywords.extend_from_slice(sql_keywords);
     keywords
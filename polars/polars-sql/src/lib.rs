@@ -661,6 +661,46 @@ mod test {
         assert!(df_sql.frame_equal(&expected));
     }
 
+    #[test]
+    #[cfg(feature = "csv")]
+    fn read_csv_tbl_func_glob_and_options() -> PolarsResult<()> {
+        let mut context = SQLContext::new();
+        let sql = r#"
+            SELECT *
+            FROM read_csv('../../examples/datasets/foods*.csv')
+            WHERE calories > 100"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let expected = LazyCsvReader::new("../../examples/datasets/foods*.csv")
+            .finish()?
+            .filter(col("calories").gt(lit(100)))
+            .collect()?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let sql = r#"
+            SELECT *
+            FROM read_csv('../../examples/datasets/foods1.csv', has_header => false, skip_rows => 1)"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        assert_eq!(df_sql.height(), 27);
+        assert_eq!(
+            df_sql.get_column_names(),
+            &["column_1", "column_2", "column_3", "column_4"]
+        );
+
+        for sql in [
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', sep => ';')",
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', ';')",
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', delimiter => ';;')",
+        ] {
+            assert!(context.execute(sql).is_err(), "{sql}");
+        }
+
+        context.allow_file_access(false);
+        let sql = "SELECT * FROM read_csv('../../examples/datasets/foods1.csv')";
+        let err = context.execute(sql).unwrap_err();
+        assert!(err.to_string().contains("file access is disabled"));
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "parquet")]
     fn read_parquet_tbl() {
@@ -855,4 +895,267 @@ mod test {
         assert!(s.series_equal(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_ctes() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1, 2, 3, 4],
+            "b" => ["x", "y", "x", "y"],
+        }?;
+        let mut context = SQLContext::new();
+        context.register("df", df.clone().lazy());
+        context.register("big", df.clone().lazy());
+
+        // a CTE can refer to an earlier CTE and shadows a registered table
+        let sql = r#"
+        WITH big AS (SELECT a, b FROM df WHERE a > 1),
+             bigger(c, d) AS (SELECT a, b FROM big WHERE a > 2)
+        SELECT * FROM bigger"#;
+        let out = context.execute(sql)?.collect()?;
+        let expected = df! {
+            "c" => [3, 4],
+            "d" => ["x", "y"],
+        }?;
+        assert!(out.frame_equal(&expected));
+
+        // the CTEs only live for the duration of the query
+        assert_eq!(context.execute("SELECT * FROM big")?.collect()?.height(), 4);
+        assert!(context.execute("SELECT * FROM bigger").is_err());
+
+        let sql = "WITH t AS (SELECT a FROM df), t AS (SELECT b FROM df) SELECT * FROM t";
+        assert!(context.execute(sql).is_err());
+        let sql = "WITH t(x, y, z) AS (SELECT a, b FROM df) SELECT * FROM t";
+        assert!(context.execute(sql).is_err());
+        let sql = "WITH RECURSIVE t AS (SELECT a FROM df) SELECT * FROM t";
+        let err = context.execute(sql).unwrap_err();
+        assert!(err.to_string().contains("Recursive CTEs are not supported"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_having_alias() -> PolarsResult<()> {
+        let df = df! {
+            "k" => ["a", "b", "a", "c", "b", "a"],
+            "x" => [1, 2, 3, 4, 5, 6],
+        }?;
+        let mut context = SQLContext::new();
+        context.register("t", df.lazy());
+        let expected = df! {
+            "k" => ["b", "a"],
+            "s" => [7, 10],
+        }?;
+
+        let sql = "SELECT k, sum(x) AS s FROM t GROUP BY k HAVING sum(x) > 4 ORDER BY s";
+        assert!(context.execute(sql)?.collect()?.frame_equal(&expected));
+        let sql = "SELECT k, sum(x) AS s FROM t GROUP BY 1 HAVING s > 4 ORDER BY s";
+        assert!(context.execute(sql)?.collect()?.frame_equal(&expected));
+
+        // an aggregate that is not selected is computed, but not returned
+        let sql = "SELECT k FROM t GROUP BY k HAVING count(x) > 1 ORDER BY max(x) DESC";
+        let out = context.execute(sql)?.collect()?;
+        assert!(out.frame_equal(&df! { "k" => ["a", "b"] }?));
+
+        let sql = "SELECT k, sum(x) AS s FROM t GROUP BY k HAVING nope > 1";
+        let err = context.execute(sql).unwrap_err();
+        assert!(err.to_string().contains("nope"));
+        assert!(context.execute("SELECT k FROM t GROUP BY 2").is_err());
+        assert!(context.execute("SELECT k FROM t HAVING k > 1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_functions() -> PolarsResult<()> {
+        let df = df! {
+            "k" => ["a", "b", "a", "b", "a"],
+            "t" => [3, 1, 1, 2, 2],
+            "x" => [1, 2, 3, 4, 5],
+        }?;
+        let mut context = SQLContext::new();
+        context.register("df", df.lazy());
+        let sql = r#"
+        SELECT
+            SUM(x) OVER (PARTITION BY k ORDER BY t) AS running,
+            SUM(x) OVER (PARTITION BY k) AS total,
+            ROW_NUMBER() OVER (PARTITION BY k ORDER BY t DESC) AS rn,
+            RANK() OVER (ORDER BY k) AS rank,
+            DENSE_RANK() OVER (ORDER BY k) AS dense_rank,
+            LAG(x) OVER (PARTITION BY k ORDER BY t) AS lag,
+            LEAD(x, 2) OVER (ORDER BY x) AS lead,
+            FIRST_VALUE(x) OVER (PARTITION BY k ORDER BY t) AS first,
+            LAST_VALUE(x) OVER (
+                PARTITION BY k ORDER BY t ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+            ) AS last,
+            LAST_VALUE(x) OVER (PARTITION BY k ORDER BY t) AS current
+        FROM df"#;
+        let out = context.execute(sql)?.collect()?;
+        let expected = df! {
+            "running" => [9, 2, 3, 6, 8],
+            "total" => [9, 6, 9, 6, 9],
+            "rn" => [1i64, 2, 3, 1, 2],
+            "rank" => [1 as IdxSize, 4, 1, 4, 1],
+            "dense_rank" => [1 as IdxSize, 2, 1, 2, 1],
+            "lag" => [Some(5), None, None, Some(2), Some(3)],
+            "lead" => [Some(3), Some(4), Some(5), None, None],
+            "first" => [3, 2, 3, 2, 3],
+            "last" => [1, 4, 1, 4, 1],
+            "current" => [1, 2, 3, 4, 5],
+        }?;
+        assert!(out.frame_equal_missing(&expected), "{out}");
+
+        // in RANGE mode, the frame of a row ends at its last peer
+        let peers = df! {
+            "t" => [2, 1, 1, 3],
+            "x" => [1, 2, 3, 4],
+        }?;
+        context.register("peers", peers.lazy());
+        let sql = r#"
+        SELECT
+            SUM(x) OVER (ORDER BY t) AS range,
+            SUM(x) OVER (ORDER BY t ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS rows,
+            COUNT(*) OVER (ORDER BY t RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS count,
+            LAST_VALUE(x) OVER (ORDER BY t) AS last
+        FROM peers"#;
+        let out = context.execute(sql)?.collect()?;
+        let expected = df! {
+            "range" => [6, 5, 5, 10],
+            "rows" => [6, 2, 5, 10],
+            "count" => [3i64, 2, 2, 4],
+            "last" => [1, 3, 3, 4],
+        }?;
+        assert!(out.frame_equal_missing(&expected), "{out}");
+
+        for sql in [
+            "SELECT SUM(x) OVER (ORDER BY t ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+            "SELECT SUM(x) OVER (ORDER BY t GROUPS UNBOUNDED PRECEDING) FROM df",
+            "SELECT STDDEV(x) OVER (ORDER BY t ROWS UNBOUNDED PRECEDING) FROM df",
+            "SELECT RANK() OVER (PARTITION BY k) FROM df",
+            "SELECT ROW_NUMBER() FROM df",
+        ] {
+            assert!(context.execute(sql).is_err(), "{sql}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_operations() -> PolarsResult<()> {
+        let df1 = df! {
+            "a" => [1, 2, 2, 3],
+            "b" => ["x", "y", "y", "z"],
+        }?;
+        let df2 = df! {
+            "c" => [3.0, 4.0, 2.0],
+            "d" => ["z", "w", "y"],
+        }?;
+        let mut context = SQLContext::new();
+        context.register("df1", df1.lazy());
+        context.register("df2", df2.lazy());
+
+        let sql = "SELECT * FROM df1 UNION ALL SELECT * FROM df2";
+        let out = context.execute(sql)?.collect()?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(out.column("a")?.dtype(), &DataType::Float64);
+        assert_eq!(out.height(), 7);
+
+        // ORDER BY and LIMIT apply to the combined result
+        let sql = "SELECT * FROM df1 UNION SELECT * FROM df2 ORDER BY a DESC LIMIT 3";
+        let out = context.execute(sql)?.collect()?;
+        let expected = df! {
+            "a" => [4.0, 3.0, 2.0],
+            "b" => ["w", "z", "y"],
+        }?;
+        assert!(out.frame_equal(&expected));
+
+        let sql = "SELECT a, b FROM df1 INTERSECT SELECT c, d FROM df2 ORDER BY a";
+        let out = context.execute(sql)?.collect()?;
+        let expected = df! {
+            "a" => [2.0, 3.0],
+            "b" => ["y", "z"],
+        }?;
+        assert!(out.frame_equal(&expected));
+
+        let sql = "SELECT * FROM df1 EXCEPT SELECT * FROM df2";
+        let out = context.execute(sql)?.collect()?;
+        let expected = df! {
+            "a" => [1.0],
+            "b" => ["x"],
+        }?;
+        assert!(out.frame_equal(&expected));
+
+        let sql = "SELECT a FROM df1 UNION SELECT c, d FROM df2";
+        let err = context.execute(sql).unwrap_err();
+        assert!(err.to_string().contains("(a: i32) and (c: f64, d: str)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_and_temporal_functions() -> PolarsResult<()> {
+        let df = df! {
+            "s" => ["hello world", "a,b,c"],
+            "d" => ["2021-03-15", "2022-11-02"],
+            "x" => [Some(1), None],
+            "y" => [1, 2],
+        }?;
+        let mut context = SQLContext::new();
+        context.register("df", df.lazy());
+        let sql = r#"
+        SELECT
+            SUBSTR(s, 2, 3) AS sub,
+            SUBSTRING(s FROM 0 FOR 3) AS sub0,
+            POSITION('o' IN s) AS pos,
+            REPLACE(s, 'o', '0') AS replaced,
+            LPAD(s, 7, '*') AS lpad,
+            RPAD(s, 6) AS rpad,
+            SPLIT_PART(s, ',', 2) AS second,
+            SPLIT_PART(s, ',', -1) AS last,
+            REGEXP_LIKE(s, '^HELLO', 'i') AS hello,
+            DATE_PART('year', TO_DATE(d, '%Y-%m-%d')) AS year,
+            EXTRACT(month FROM TO_DATE(d, '%Y-%m-%d')) AS month,
+            STRFTIME(DATE_TRUNC('month', TO_DATE(d, '%Y-%m-%d')), '%Y-%m-%d') AS month_start,
+            COALESCE(x, y) AS coalesced,
+            IFNULL(x, 0) AS ifnull,
+            NULLIF(y, 2) AS nullif
+        FROM df"#;
+        let out = context.execute(sql)?.collect()?;
+        let expected = df! {
+            "sub" => ["ell", ",b,"],
+            "sub0" => ["he", "a,"],
+            "pos" => [5i64, 0],
+            "replaced" => ["hell0 w0rld", "a,b,c"],
+            "lpad" => ["hello w", "**a,b,c"],
+            "rpad" => ["hello ", "a,b,c "],
+            "second" => [None, Some("b")],
+            "last" => ["hello world", "c"],
+            "hello" => [true, false],
+            "year" => [2021, 2022],
+            "month" => [3u32, 11],
+            "month_start" => ["2021-03-01", "2022-11-01"],
+            "coalesced" => [1, 2],
+            "ifnull" => [1i64, 0],
+            "nullif" => [Some(1), None],
+        }?;
+        assert!(out.frame_equal_missing(&expected), "{out}");
+
+        for (sql, msg) in [
+            (
+                "SELECT LPAD(s) FROM df",
+                "LPAD expects 2 to 3 arguments, got 1",
+            ),
+            (
+                "SELECT SUBSTR(s, 'a') FROM df",
+                "SUBSTR expects an integer literal",
+            ),
+            (
+                "SELECT DATE_PART('fortnight', d) FROM df",
+                "DATE_PART does not support",
+            ),
+            (
+                "SELECT RPAD(s, 3, 'ab') FROM df",
+                "RPAD expects a single fill character",
+            ),
+        ] {
+            let err = context.execute(sql).unwrap_err();
+            assert!(err.to_string().contains(msg), "{err}");
+        }
+        Ok(())
+    }
 }
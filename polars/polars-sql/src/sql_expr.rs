@@ -7,7 +7,7 @@ use sqlparser::ast::{
     UnaryOperator, Value as SqlValue,
 };
 
-use crate::functions::SqlFunctionVisitor;
+use crate::functions::{date_part, substring, FromSqlExpr, SqlFunctionVisitor};
 use crate::SQLContext;
 
 pub(crate) fn map_sql_polars_datatype(data_type: &SQLDataType) -> PolarsResult<DataType> {
@@ -71,6 +71,16 @@ impl SqlExprVisitor<'_> {
                 trim_where,
                 trim_what,
             } => self.visit_trim(expr, trim_where, trim_what),
+            SqlExpr::Substring {
+                expr,
+                substring_from,
+                substring_for,
+                ..
+            } => self.visit_substring(expr, substring_from, substring_for),
+            SqlExpr::Position { expr, r#in } => self.visit_position(expr, r#in),
+            SqlExpr::Extract { field, expr } => {
+                date_part("EXTRACT", self.visit_expr(expr)?, &field.to_string())
+            }
             SqlExpr::IsFalse(expr) => Ok(self.visit_expr(expr)?.eq(lit(false))),
             SqlExpr::IsNotFalse(expr) => Ok(self.visit_expr(expr)?.eq(lit(false)).not()),
             SqlExpr::IsTrue(expr) => Ok(self.visit_expr(expr)?.eq(lit(true))),
@@ -271,6 +281,55 @@ impl SqlExprVisitor<'_> {
         })
     }
 
+    /// Visit a SQL `SUBSTRING` expression
+    ///
+    /// e.g. `SUBSTRING(column FROM 2 FOR 3)` or `SUBSTRING(column, 2, 3)`
+    fn visit_substring(
+        &self,
+        expr: &SqlExpr,
+        substring_from: &Option<Box<SqlExpr>>,
+        substring_for: &Option<Box<SqlExpr>>,
+    ) -> PolarsResult<Expr> {
+        let literal = |e: &SqlExpr| {
+            i64::from_sql_expr(e, self.ctx).map_err(
+                |_| polars_err!(ComputeError: "SUBSTRING expects integer literals, got {}", e),
+            )
+        };
+        let start = substring_from.as_deref().map(literal).transpose()?;
+        let length = substring_for.as_deref().map(literal).transpose()?;
+        substring(
+            "SUBSTRING",
+            self.visit_expr(expr)?,
+            start.unwrap_or(1),
+            length,
+        )
+    }
+
+    /// Visit a SQL `POSITION` expression, the 1-based position of the first occurrence of a
+    /// substring, or 0 if there is none
+    ///
+    /// e.g. `POSITION('a' IN column)`
+    fn visit_position(&self, substr: &SqlExpr, expr: &SqlExpr) -> PolarsResult<Expr> {
+        let substr = String::from_sql_expr(substr, self.ctx).map_err(|_| {
+            polars_err!(ComputeError: "POSITION expects a string literal to search, got {}", substr)
+        })?;
+        let expr = self.visit_expr(expr)?;
+        let position = expr
+            .clone()
+            .str()
+            .split(&substr)
+            .arr()
+            .first()
+            .str()
+            .n_chars()
+            + lit(1);
+        let not_found = expr
+            .clone()
+            .is_not_null()
+            .and(expr.str().contains_literal(lit(substr)).not());
+        Ok(when(not_found).then(lit(0)).otherwise(position))
+    }
+
     /// Visit a SQL `ARRAY_AGG` expression
     fn visit_arr_agg(&self, expr: &ArrayAgg) -> PolarsResult<Expr> {
         let mut base = self.visit_expr(&expr.expr)?;
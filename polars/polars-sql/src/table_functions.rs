@@ -1,17 +1,28 @@
 use std::str::FromStr;
 
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+use polars_core::prelude::polars_ensure;
+#[cfg(feature = "csv")]
+use polars_core::prelude::polars_err;
 use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+use sqlparser::ast::{Expr as SqlExpr, FunctionArg, FunctionArgExpr, Value as SqlValue};
 
 /// Table functions that are supported by Polars
 #[allow(clippy::enum_variant_names)]
 pub(crate) enum PolarsTableFunctions {
-    /// SQL 'read_csv' function
+    /// SQL 'read_csv' function, which accepts the named options `delimiter`, `has_header`,
+    /// `skip_rows` and `infer_schema_length`
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', delimiter => ';', has_header => false)
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
@@ -27,7 +38,7 @@ pub(crate) enum PolarsTableFunctions {
     /// ```
     #[cfg(feature = "ipc")]
     ReadIpc,
-    /// SQL 'read_json' function. *Only ndjson is currently supported.*
+    /// SQL 'read_json' or 'read_ndjson' function. *Only ndjson is currently supported.*
     /// ```sql
     /// SELECT * FROM read_json('path/to/file.json')
     /// ```
@@ -48,71 +59,138 @@ impl FromStr for PolarsTableFunctions {
             #[cfg(feature = "ipc")]
             "read_ipc" => PolarsTableFunctions::ReadIpc,
             #[cfg(feature = "json")]
-            "read_json" => PolarsTableFunctions::ReadJson,
+            "read_json" | "read_ndjson" => PolarsTableFunctions::ReadJson,
             _ => polars_bail!(ComputeError: "'{}' is not a supported table function", s),
         })
     }
 }
 
 impl PolarsTableFunctions {
-    #[allow(unused_variables, unreachable_patterns)]
+    #[allow(unused_variables, unreachable_patterns, unreachable_code)]
     pub(crate) fn execute(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        match self {
+        let (path, options) = match args.split_first() {
+            Some((path, options)) => (get_file_path_from_arg(path)?, options),
+            None => {
+                polars_bail!(ComputeError: "table function expects a path as its first argument")
+            }
+        };
+        let lf = match self {
             #[cfg(feature = "csv")]
-            PolarsTableFunctions::ReadCsv => self.read_csv(args),
+            PolarsTableFunctions::ReadCsv => read_csv(&path, options)?,
             #[cfg(feature = "parquet")]
-            PolarsTableFunctions::ReadParquet => self.read_parquet(args),
+            PolarsTableFunctions::ReadParquet => {
+                no_options("read_parquet", options)?;
+                LazyFrame::scan_parquet(&path, Default::default())?
+            }
             #[cfg(feature = "ipc")]
-            PolarsTableFunctions::ReadIpc => self.read_ipc(args),
+            PolarsTableFunctions::ReadIpc => {
+                no_options("read_ipc", options)?;
+                LazyFrame::scan_ipc(&path, Default::default())?
+            }
             #[cfg(feature = "json")]
-            PolarsTableFunctions::ReadJson => self.read_ndjson(args),
-            _ => unreachable!(),
-        }
-    }
+            PolarsTableFunctions::ReadJson => {
+                use polars_lazy::frame::LazyFileListReader;
+                use polars_lazy::prelude::LazyJsonLineReader;
 
-    #[cfg(feature = "csv")]
-    fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path).finish()?;
+                no_options("read_json", options)?;
+                LazyJsonLineReader::new(path.clone()).finish()?
+            }
+            _ => unreachable!(),
+        };
         Ok((path, lf))
     }
+}
 
-    #[cfg(feature = "parquet")]
-    fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
-        Ok((path, lf))
-    }
+#[cfg(feature = "csv")]
+fn read_csv(path: &str, options: &[FunctionArg]) -> PolarsResult<LazyFrame> {
+    use polars_lazy::frame::LazyFileListReader;
 
-    #[cfg(feature = "ipc")]
-    fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
-        Ok((path, lf))
+    let mut reader = LazyCsvReader::new(path);
+    for (name, value) in named_args(options)? {
+        reader = match name {
+            "delimiter" => {
+                let delimiter = string_arg(name, value)?;
+                polars_ensure!(
+                    delimiter.len() == 1,
+                    ComputeError: "option 'delimiter' expects a single byte, got '{}'", delimiter
+                );
+                reader.with_delimiter(delimiter.as_bytes()[0])
+            }
+            "has_header" => reader.has_header(bool_arg(name, value)?),
+            "skip_rows" => reader.with_skip_rows(usize_arg(name, value)?),
+            "infer_schema_length" => reader.with_infer_schema_length(Some(usize_arg(name, value)?)),
+            _ => polars_bail!(ComputeError: "read_csv has no option '{}'", name),
+        };
     }
-    #[cfg(feature = "json")]
-    fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        use polars_lazy::frame::LazyFileListReader;
-        use polars_lazy::prelude::LazyJsonLineReader;
+    reader.finish()
+}
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
-        Ok((path, lf))
+fn get_file_path_from_arg(arg: &FunctionArg) -> PolarsResult<String> {
+    match arg {
+        FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(
+            SqlValue::SingleQuotedString(s),
+        ))) => Ok(s.to_string()),
+        _ => polars_bail!(
+            ComputeError:
+            "only a single quoted string is accepted as the first parameter; received: {}", arg,
+        ),
     }
+}
 
-    #[allow(dead_code)]
-    fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SqlExpr, Value as SqlValue};
-        match arg {
-            FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(
-                SqlValue::SingleQuotedString(s),
-            ))) => Ok(s.to_string()),
+/// The options after the path, which must be named, as in
+/// `read_csv('file.csv', has_header => false)`.
+#[cfg(feature = "csv")]
+fn named_args(options: &[FunctionArg]) -> PolarsResult<Vec<(&str, &SqlExpr)>> {
+    options
+        .iter()
+        .map(|arg| match arg {
+            FunctionArg::Named {
+                name,
+                arg: FunctionArgExpr::Expr(value),
+            } => Ok((name.value.as_str(), value)),
             _ => polars_bail!(
                 ComputeError:
-                "only a single quoted string is accepted as the first parameter; received: {}", arg,
+                "table function options must be named, as in `name => value`; received: {}", arg,
             ),
-        }
+        })
+        .collect()
+}
+
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "json"))]
+fn no_options(function: &str, options: &[FunctionArg]) -> PolarsResult<()> {
+    polars_ensure!(
+        options.is_empty(),
+        ComputeError: "{} does not accept options; received: {}", function, options[0]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "csv")]
+fn string_arg<'a>(name: &str, value: &'a SqlExpr) -> PolarsResult<&'a str> {
+    match value {
+        SqlExpr::Value(SqlValue::SingleQuotedString(s)) => Ok(s),
+        _ => polars_bail!(ComputeError: "option '{}' expects a string, got {}", name, value),
+    }
+}
+
+#[cfg(feature = "csv")]
+fn bool_arg(name: &str, value: &SqlExpr) -> PolarsResult<bool> {
+    match value {
+        SqlExpr::Value(SqlValue::Boolean(b)) => Ok(*b),
+        _ => polars_bail!(ComputeError: "option '{}' expects a boolean, got {}", name, value),
+    }
+}
+
+#[cfg(feature = "csv")]
+fn usize_arg(name: &str, value: &SqlExpr) -> PolarsResult<usize> {
+    let err = || {
+        polars_err!(
+            ComputeError: "option '{}' expects a non-negative integer, got {}", name, value
+        )
+    };
+    match value {
+        SqlExpr::Value(SqlValue::Number(n, _)) => n.parse().map_err(|_| err()),
+        _ => Err(err()),
     }
 }
 
@@ -128,6 +206,8 @@ impl PolarsTableFunctions {
             "read_ipc",
             #[cfg(feature = "json")]
             "read_json",
+            #[cfg(feature = "json")]
+            "read_ndjson",
         ]
     }
 }
@@ -30,7 +30,7 @@ impl IntoSeriesOps for Series {
             DataType::Float32 => self.f32().unwrap().to_ops(),
             DataType::Float64 => self.f64().unwrap().to_ops(),
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => self.categorical().unwrap().to_ops(),
+            DataType::Categorical(_, _) => self.categorical().unwrap().to_ops(),
             DataType::Boolean => self.bool().unwrap().to_ops(),
             DataType::Utf8 => self.utf8().unwrap().to_ops(),
             #[cfg(feature = "dtype-date")]
@@ -80,11 +80,11 @@
 //! fn lazy_example(mut df_a: LazyFrame, mut df_b: LazyFrame) -> PolarsResult<DataFrame> {
 //!
 //!     let q1 = df_a.with_columns(vec![
-//!         col("a").cast(DataType::Categorical(None)),
+//!         col("a").cast(DataType::Categorical(None, Default::default())),
 //!     ]);
 //!
 //!     let q2 = df_b.with_columns(vec![
-//!         col("b").cast(DataType::Categorical(None))
+//!         col("b").cast(DataType::Categorical(None, Default::default()))
 //!     ]);
 //!     q1.inner_join(q2, col("a"), col("b")).collect()
 //! }
@@ -190,6 +190,8 @@
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
+//!     - `serde-ipc` - Serialize [`DataFrame`]s as Arrow IPC bytes in serde formats with
+//!                 `#[serde(with = "polars::serde::ipc")]`.
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
@@ -250,6 +252,8 @@
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
+//!     - `cut_qcut` - Bin values into categories by break points or quantiles.
+//!     - `json_encode` - Serialize values, including structs and lists, to JSON strings.
 //!     - `date_offset` Add an offset to dates that take months and leap years into account.
 //!     - `trigonometry` Trigonometric functions.
 //!     - `sign` Compute the element-wise sign of a Series.
@@ -321,6 +325,18 @@
 //! [dependencies]
 //! mimalloc = { version = "*", default-features = false }
 //! ```
+//!
+//! #### Memory tracking
+//! Wrap the allocator in [`mem::TrackingAllocator`] to measure the memory that is in use. This
+//! enables the `peak_memory` of `LazyFrame::profile` and the memory limit of
+//! `LazyFrame::with_memory_limit`.
+//! ```ignore
+//! use mimalloc::MiMalloc;
+//! use polars::mem::TrackingAllocator;
+//!
+//! #[global_allocator]
+//! static GLOBAL: TrackingAllocator<MiMalloc> = TrackingAllocator::new(MiMalloc);
+//! ```
 //! ## Config with ENV vars
 //!
 //! * `POLARS_FMT_TABLE_FORMATTING` -> define styling of tables using any of the following options (default = UTF8_FULL_CONDENSED):
@@ -368,6 +384,9 @@
 //! * `POLARS_NO_PARQUET_STATISTICS` -> if set, statistics in parquet files are ignored.
 //! * `POLARS_PANIC_ON_ERR` -> panic instead of returning an Error.
 //! * `POLARS_NO_CHUNKED_JOIN` -> force rechunk before joins.
+//! * `POLARS_STREAMING_CHUNK_SIZE` -> number of rows per chunk in the streaming engine.
+//! * `POLARS_OOC_SORT_MEMORY_BUDGET` -> memory budget in bytes of the streaming sort before it spills to disk,
+//!                                      if it isn't set with `LazyFrame::with_streaming_sort_memory_budget`.
 //!
 //!
 //! ## User Guide
@@ -380,8 +399,10 @@ pub mod prelude;
 #[cfg(feature = "sql")]
 pub mod sql;
 
+#[cfg(feature = "serde")]
+pub use polars_core::serde;
 pub use polars_core::{
-    apply_method_all_arrow_series, chunked_array, datatypes, df, doc, error, frame, functions,
+    apply_method_all_arrow_series, chunked_array, datatypes, df, doc, error, frame, functions, mem,
     series, testing,
 };
 #[cfg(feature = "dtype-categorical")]
@@ -1,6 +1,6 @@
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 #[cfg(feature = "dtype-categorical")]
-use polars_core::{reset_string_cache, IUseStringCache};
+use polars_core::{reset_string_cache, StringCacheHolder};
 
 use super::*;
 
@@ -251,15 +251,19 @@ fn test_join_multiple_columns() {
 #[cfg_attr(miri, ignore)]
 #[cfg(feature = "dtype-categorical")]
 fn test_join_categorical() {
-    let _lock = IUseStringCache::new();
+    let _lock = StringCacheHolder::new();
     let _lock = polars_core::SINGLE_LOCK.lock();
 
     let (mut df_a, mut df_b) = get_dfs();
 
-    df_a.try_apply("b", |s| s.cast(&DataType::Categorical(None)))
-        .unwrap();
-    df_b.try_apply("bar", |s| s.cast(&DataType::Categorical(None)))
-        .unwrap();
+    df_a.try_apply("b", |s| {
+        s.cast(&DataType::Categorical(None, Default::default()))
+    })
+    .unwrap();
+    df_b.try_apply("bar", |s| {
+        s.cast(&DataType::Categorical(None, Default::default()))
+    })
+    .unwrap();
 
     let out = df_a
         .join(&df_b, ["b"], ["bar"], JoinType::Left, None)
@@ -282,21 +286,28 @@ fn test_join_categorical() {
     for jt in [JoinType::Left, JoinType::Inner, JoinType::Outer] {
         let out = df_a.join(&df_b, ["b"], ["bar"], jt, None).unwrap();
         let out = out.column("b").unwrap();
-        assert_eq!(out.dtype(), &DataType::Categorical(None));
+        assert_eq!(
+            out.dtype(),
+            &DataType::Categorical(None, Default::default())
+        );
     }
 
     // Test error when joining on different string cache
     let (mut df_a, mut df_b) = get_dfs();
-    df_a.try_apply("b", |s| s.cast(&DataType::Categorical(None)))
-        .unwrap();
+    df_a.try_apply("b", |s| {
+        s.cast(&DataType::Categorical(None, Default::default()))
+    })
+    .unwrap();
     // create a new cache
     reset_string_cache();
 
     // _sc is needed to ensure we hold the string cache.
-    let _sc = IUseStringCache::new();
+    let _sc = StringCacheHolder::new();
 
-    df_b.try_apply("bar", |s| s.cast(&DataType::Categorical(None)))
-        .unwrap();
+    df_b.try_apply("bar", |s| {
+        s.cast(&DataType::Categorical(None, Default::default()))
+    })
+    .unwrap();
     let out = df_a.join(&df_b, ["b"], ["bar"], JoinType::Left, None);
     assert!(out.is_err());
 }
@@ -614,3 +625,52 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_build_side() -> PolarsResult<()> {
+    let left = df![
+        "key" => [Some(1), Some(2), None, Some(4), Some(2)],
+        "str_key" => [Some("a"), Some("b"), None, Some("d"), Some("b")],
+        "left" => [0, 1, 2, 3, 4],
+    ]?;
+    let mut right = df![
+        "key" => [Some(2), Some(5), Some(2), None, Some(1), Some(2), Some(6)],
+        "str_key" => [Some("b"), Some("e"), Some("b"), None, Some("a"), Some("b"), Some("f")],
+        "right" => [0, 1, 2, 3, 4, 5, 6],
+    ]?;
+    let right_chunked = accumulate_dataframes_vertical(split_df(&mut right, 3)?)?;
+
+    for on in ["key", "str_key"] {
+        for right in [&right, &right_chunked] {
+            for how in [JoinType::Left, JoinType::Inner, JoinType::Outer] {
+                let join = |build_side| {
+                    let args = JoinArgs::new(how.clone()).with_build_side(build_side);
+                    let out = left.join_with_args(right, [on], [on], args)?;
+                    // only a left join defines the order of its output
+                    if how == JoinType::Left {
+                        Ok(out)
+                    } else {
+                        out.sort(["left", "right"], false)
+                    }
+                };
+                let expected = join(JoinBuildSide::Right)?;
+                for build_side in [JoinBuildSide::Auto, JoinBuildSide::Left] {
+                    assert!(join(build_side)?.frame_equal_missing(&expected));
+                }
+            }
+        }
+    }
+
+    // the unmatched left rows of a left join that builds on the left input get a null right side
+    let args = JoinArgs::new(JoinType::Left).with_build_side(JoinBuildSide::Left);
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    let left_idx = out.column("left")?.i32()?;
+    assert_eq!(
+        left_idx.into_no_null_iter().collect::<Vec<_>>(),
+        &[0, 1, 1, 1, 2, 3, 4, 4, 4]
+    );
+    let right_idx = [4, 0, 2, 5, 3, -1, 0, 2, 5].map(|idx| (idx >= 0).then_some(idx));
+    assert_eq!(Vec::from(out.column("right")?.i32()?), &right_idx);
+    Ok(())
+}
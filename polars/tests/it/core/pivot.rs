@@ -124,7 +124,9 @@ fn test_pivot_categorical() -> PolarsResult<()> {
         "B" => [8, 2, 3, 6, 3, 6, 2, 2],
         "C" => ["a", "b", "c", "a", "b", "c", "a", "b"]
     ]?;
-    df.try_apply("C", |s| s.cast(&DataType::Categorical(None)))?;
+    df.try_apply("C", |s| {
+        s.cast(&DataType::Categorical(None, Default::default()))
+    })?;
 
     let out = pivot(&df, ["A"], ["B"], ["C"], true, Some(PivotAgg::Count), None)?;
     assert_eq!(out.get_column_names(), &["B", "a", "b", "c"]);
@@ -245,3 +247,35 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_pivot_multiple_values_expr() -> PolarsResult<()> {
+    let df = df![
+        "store" => ["b", "a", "b", "a", "b", "b"],
+        "year" => [2022, 2021, 2021, 2021, 2022, 2022],
+        "sales" => [1.0, 2.0, 3.0, 4.0, 5.0, 9.0],
+        "qty" => [1, 2, 3, 4, 5, 6],
+    ]?;
+
+    let out = polars::lazy::frame::pivot::pivot(
+        &df,
+        ["sales", "qty"],
+        ["store"],
+        ["year"],
+        false,
+        Some(col("").quantile(lit(0.5), QuantileInterpolOptions::Higher)),
+        Some("|"),
+    )?;
+    // rows and columns are in the order of first appearance, and the store "a" has no sales
+    // in 2022
+    let expected = df![
+        "store" => ["b", "a"],
+        "sales|year|2022" => [Some(5.0), None],
+        "sales|year|2021" => [Some(3.0), Some(4.0)],
+        "qty|year|2022" => [Some(5.0), None],
+        "qty|year|2021" => [Some(3.0), Some(4.0)],
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+    Ok(())
+}
@@ -27,6 +27,47 @@ fn write_csv() {
     assert_eq!("0,22.1\n1,19.9\n2,7.0\n3,2.0\n4,3.0\n", csv);
 }
 
+#[test]
+fn write_csv_quote_style() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 2],
+        "b" => [Some("x,y"), None],
+        "c" => [1.123, 2.5],
+    ]?;
+
+    let write = |df: &mut DataFrame, style: QuoteStyle| -> PolarsResult<String> {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_quote_style(style)
+            .with_column_float_precision("c", 1)
+            .finish(df)?;
+        Ok(String::from_utf8(buf).unwrap())
+    };
+
+    let csv = write(&mut df, QuoteStyle::Necessary)?;
+    assert_eq!(csv, "a,b,c\n1,\"x,y\",1.1\n2,,2.5\n");
+    let csv = write(&mut df, QuoteStyle::Always)?;
+    assert_eq!(
+        csv,
+        "\"a\",\"b\",\"c\"\n\"1\",\"x,y\",\"1.1\"\n\"2\",,\"2.5\"\n"
+    );
+    let csv = write(&mut df, QuoteStyle::NonNumeric)?;
+    assert_eq!(csv, "\"a\",\"b\",\"c\"\n1,\"x,y\",1.1\n2,,2.5\n");
+    assert!(write(&mut df, QuoteStyle::Never).is_err());
+
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => [Some("x,y"), None],
+        "c" => [1.1, 2.5],
+    ]?;
+    for style in [QuoteStyle::Necessary, QuoteStyle::Always] {
+        let csv = write(&mut df, style)?;
+        let out = CsvReader::new(Cursor::new(csv)).finish()?;
+        assert!(out.frame_equal_missing(&expected));
+    }
+    Ok(())
+}
+
 #[test]
 fn test_read_csv_file() {
     let file = std::fs::File::open(FOODS_CSV).unwrap();
@@ -540,6 +581,116 @@ fn test_comment_lines() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_comment_lines_multithreaded_with_units_row() -> PolarsResult<()> {
+    let mut csv = String::from("a,b\n# a comment before the units row\nkg,m\n");
+    for i in 0..10_000 {
+        if i % 3 == 0 {
+            csv.push_str("# a comment, with a different number, of fields\n");
+        }
+        csv.push_str(&format!("{i},{}\n", i * 2));
+    }
+
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_comment_char(Some(b'#'))
+        .with_skip_rows_after_header(1)
+        .with_n_threads(Some(4))
+        .finish()?;
+    assert_eq!(df.shape(), (10_000, 2));
+    assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+    assert_eq!(df.column("a")?.sum::<i64>(), Some((0..10_000).sum()));
+    assert_eq!(
+        df.column("b")?.sum::<i64>(),
+        Some((0..10_000).map(|i| i * 2).sum())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_named_null_values() -> PolarsResult<()> {
+    let csv = r"a,b,c
+1,NA,-999
+-999,x,2
+3,missing,3
+";
+    let null_values = NullValues::Named(vec![
+        ("a".to_string(), "-999".to_string()),
+        ("b".to_string(), "NA".to_string()),
+        ("b".to_string(), "missing".to_string()),
+    ]);
+
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_null_values(Some(null_values.clone()))
+        .finish()?;
+    // the null value is not a value of the column, so `a` is still an integer
+    assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+    let expected = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [None, Some("x"), None],
+        "c" => [-999i64, 2, 3],
+    ]?;
+    assert!(df.frame_equal_missing(&expected));
+
+    // the null values must follow the columns in a different order
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_null_values(Some(null_values.clone()))
+        .with_columns(Some(vec!["c".to_string(), "b".to_string()]))
+        .finish()?;
+    assert!(df.frame_equal_missing(&expected.select(["b", "c"])?));
+
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_null_values(Some(null_values))
+        .with_projection(Some(vec![2, 0]))
+        .finish()?;
+    assert!(df.frame_equal_missing(&expected.select(["a", "c"])?));
+    Ok(())
+}
+
+#[test]
+fn test_infer_schema_full_file() -> PolarsResult<()> {
+    let mut csv = String::from("a,b\n");
+    for i in 0..100_000 {
+        csv.push_str(&format!("{i},{i}\n"));
+    }
+    csv.push_str("1.5,foo\n");
+
+    let mut reader = CsvReader::new(Cursor::new(csv.as_str())).infer_schema(Some(100));
+    let schema = reader.inferred_schema()?;
+    assert_eq!(schema.get("a"), Some(&DataType::Int64));
+
+    let mut reader = CsvReader::new(Cursor::new(csv.as_str())).infer_schema(None);
+    let schema = reader.inferred_schema()?;
+    assert_eq!(schema.get("a"), Some(&DataType::Float64));
+    assert_eq!(schema.get("b"), Some(&DataType::Utf8));
+
+    let df = reader.finish()?;
+    assert_eq!(df.shape(), (100_001, 2));
+    assert_eq!(df.column("a")?.dtype(), &DataType::Float64);
+
+    // the overwritten dtypes are part of the inferred schema
+    let mut reader = CsvReader::new(Cursor::new(csv.as_str()))
+        .infer_schema(Some(100))
+        .with_dtypes_slice(Some(&[DataType::Int32, DataType::Float32]));
+    let schema = reader.inferred_schema()?;
+    assert_eq!(
+        schema.iter_dtypes().cloned().collect::<Vec<_>>(),
+        &[DataType::Int32, DataType::Float32]
+    );
+    let overwrite = Schema::from_iter([Field::new("a", DataType::Int16)]);
+    let mut reader = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_dtypes(Some(Arc::new(overwrite)))
+        .infer_schema(Some(100))
+        .with_ignore_errors(true);
+    let schema = reader.inferred_schema()?;
+    assert_eq!(schema.get("a"), Some(&DataType::Int16));
+    assert_eq!(schema.get("b"), Some(&DataType::Int64));
+
+    // the inferred schema is reused when the file is read
+    let df = reader.finish()?;
+    assert_eq!(df.schema(), *schema);
+    Ok(())
+}
+
 #[test]
 fn test_null_values_argument() -> PolarsResult<()> {
     let csv = r"1,a,foo
@@ -1073,6 +1224,48 @@ fn test_try_parse_dates_3380() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "temporal")]
+fn test_date_formats() -> PolarsResult<()> {
+    let csv = r"date,datetime,text
+01.02.2023,01.02.2023 10:30,foo
+2023/03/04,,bar
+";
+    let formats = vec!["%d.%m.%Y".to_string(), "%Y/%m/%d".to_string()];
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_date_formats(Some(formats))
+        .finish()?;
+    assert_eq!(df.column("date")?.dtype(), &DataType::Date);
+    assert_eq!(df.column("datetime")?.dtype(), &DataType::Utf8);
+    assert_eq!(df.column("text")?.dtype(), &DataType::Utf8);
+    let expected = Series::new(
+        "date",
+        [
+            polars::export::chrono::NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+            polars::export::chrono::NaiveDate::from_ymd_opt(2023, 3, 4).unwrap(),
+        ],
+    );
+    assert!(df.column("date")?.series_equal(&expected));
+
+    let formats = vec!["%d.%m.%Y %H:%M".to_string(), "%d.%m.%Y".to_string()];
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_date_formats(Some(formats.clone()))
+        .finish()?;
+    assert_eq!(
+        df.column("datetime")?.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    assert_eq!(df.column("datetime")?.null_count(), 1);
+
+    // "2023/03/04" doesn't match any format
+    let res = CsvReader::new(Cursor::new(csv))
+        .with_date_formats(Some(formats))
+        .with_strict_date_formats(true)
+        .finish();
+    assert!(res.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     let csv = r#"
@@ -1087,3 +1280,51 @@ fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     assert_eq!(col_2.get(0)?, AnyValue::Float64(4.1));
     Ok(())
 }
+
+#[test]
+fn test_ragged_lines() -> PolarsResult<()> {
+    let mut csv = "a,b,c\n".to_string();
+    for i in 0..10_000 {
+        match i % 1000 {
+            1 => csv.push_str(&format!("{i},{i}\n")),
+            2 => csv.push_str(&format!("{i},{i},{i},{i}\n")),
+            _ => csv.push_str(&format!("{i},{i},{i}\n")),
+        }
+    }
+    let read = |mode| {
+        CsvReader::new(Cursor::new(csv.as_str()))
+            .with_n_threads(Some(4))
+            .with_chunk_size(100)
+            .with_ragged_lines(mode)
+            .finish()
+    };
+
+    let df = read(RaggedLineMode::Truncate)?;
+    assert_eq!(df.shape(), (10_000, 3));
+    assert_eq!(df.column("c")?.null_count(), 10);
+
+    assert!(read(RaggedLineMode::Error).is_err());
+    assert!(read(RaggedLineMode::PadNull).is_err());
+
+    let reader = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_n_threads(Some(4))
+        .with_chunk_size(100)
+        .with_ragged_lines(RaggedLineMode::SkipRow);
+    let skipped_rows = reader.skipped_rows();
+    let df = reader.finish()?;
+    assert_eq!(df.shape(), (9_980, 3));
+    assert_eq!(df.column("c")?.null_count(), 0);
+    assert_eq!(skipped_rows.load(std::sync::atomic::Ordering::Relaxed), 20);
+
+    // short lines are padded with nulls
+    let csv = "a,b,c\n1,2,3\n4,5\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_truncate_ragged_lines(false)
+        .finish()?;
+    assert_eq!(df.column("c")?.null_count(), 1);
+    let res = CsvReader::new(Cursor::new("a,b\n1,2\n3,4,5\n"))
+        .with_truncate_ragged_lines(false)
+        .finish();
+    assert!(res.is_err());
+    Ok(())
+}
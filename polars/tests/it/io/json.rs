@@ -212,3 +212,57 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_read_json_nesting() -> PolarsResult<()> {
+    let jsonlines = r#"{"a": {"b": {"c": 1}}, "l": [{"x": 1}]}
+{"a": {"b": {"c": 2}, "d": "y"}, "l": []}
+"#;
+    let list_dtype = DataType::List(Box::new(DataType::Struct(vec![Field::new(
+        "x",
+        DataType::Int64,
+    )])));
+
+    // deeper objects are read as json strings
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .max_nesting_depth(1)
+        .finish()?;
+    let a_dtype = DataType::Struct(vec![
+        Field::new("b", DataType::Utf8),
+        Field::new("d", DataType::Utf8),
+    ]);
+    assert_eq!(df.dtypes(), &[a_dtype, list_dtype.clone()]);
+    let b = df.column("a")?.struct_()?.field_by_name("b")?;
+    assert_eq!(
+        Vec::from(b.utf8()?),
+        &[Some(r#"{"c":1}"#), Some(r#"{"c":2}"#)]
+    );
+
+    // objects are flattened, but objects in arrays are not
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .flatten_separator(Some("."))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a.b.c", "l", "a.d"]);
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Int64, list_dtype.clone(), DataType::Utf8]
+    );
+    assert_eq!(Vec::from(df.column("a.b.c")?.i64()?), &[Some(1), Some(2)]);
+    assert_eq!(Vec::from(df.column("a.d")?.utf8()?), &[None, Some("y")]);
+
+    // flattening stops at the max nesting depth
+    let json =
+        r#"[{"a": {"b": {"c": 1}}, "l": [{"x": 1}]}, {"a": {"b": {"c": 2}, "d": "y"}, "l": []}]"#;
+    let df = JsonReader::new(Cursor::new(json))
+        .flatten_separator(Some("."))
+        .max_nesting_depth(1)
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a.b", "l", "a.d"]);
+    assert_eq!(df.dtypes(), &[DataType::Utf8, list_dtype, DataType::Utf8]);
+    assert_eq!(
+        Vec::from(df.column("a.b")?.utf8()?),
+        &[Some(r#"{"c":1}"#), Some(r#"{"c":2}"#)]
+    );
+    Ok(())
+}
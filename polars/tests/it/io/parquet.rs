@@ -32,3 +32,78 @@ fn test_scan_parquet_files() -> PolarsResult<()> {
     assert_eq!(df.shape(), (54, 4));
     Ok(())
 }
+
+#[test]
+fn test_parquet_compression_level() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => (0..100_000).map(|i| (i * 7919 % 10007).to_string()).collect::<Vec<_>>(),
+        "b" => (0..100_000).map(|i| i % 113).collect::<Vec<i64>>()
+    }?;
+
+    let mut write = |compression| -> PolarsResult<Vec<u8>> {
+        let mut buf = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_compression(compression)
+            .finish(&mut df)?;
+        Ok(buf.into_inner())
+    };
+    let fast = write(ParquetCompression::Zstd(Some(ZstdLevel::try_new(1)?)))?;
+    let small = write(ParquetCompression::Zstd(Some(ZstdLevel::try_new(19)?)))?;
+    assert!(small.len() < fast.len());
+    let default = write(ParquetCompression::Zstd(None))?;
+    assert!(small.len() <= default.len());
+
+    let read_df = ParquetReader::new(Cursor::new(small)).finish()?;
+    assert!(df.frame_equal(&read_df));
+
+    assert!(ZstdLevel::try_new(23).is_err());
+    assert!(BrotliLevel::try_new(12).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_parquet_decimal_round_trip() -> PolarsResult<()> {
+    use polars::export::arrow::io::parquet::read::read_metadata;
+
+    let decimal = |name: &str, values: &[Option<i128>], precision: usize, scale: usize| {
+        Int128Chunked::from_slice_options(name, values)
+            .into_decimal(Some(precision), scale)
+            .map(|ca| ca.into_series())
+    };
+    let max = 10i128.pow(38) - 1;
+    // the precisions are stored as INT32, INT64 and FIXED_LEN_BYTE_ARRAY
+    let mut df = DataFrame::new(vec![
+        decimal(
+            "d9",
+            &[Some(123_456_789), None, Some(-999_999_999), Some(0)],
+            9,
+            2,
+        )?,
+        decimal(
+            "d18",
+            &[Some(1), Some(-999_999_999_999_999_999), None, Some(42)],
+            18,
+            9,
+        )?,
+        decimal("d38", &[Some(max), Some(-max), Some(-1), None], 38, 9)?,
+    ])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_statistics(true)
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = read_metadata(&mut buf)?;
+    for column in metadata.row_groups[0].columns() {
+        assert!(column.statistics().is_some());
+    }
+
+    buf.set_position(0);
+    let read = ParquetReader::new(buf).finish()?;
+    assert_eq!(read.dtypes(), df.dtypes());
+    assert!(read.frame_equal_missing(&df));
+    assert_eq!(read.column("d38")?.get(1)?, AnyValue::Decimal(-max, 9));
+    Ok(())
+}
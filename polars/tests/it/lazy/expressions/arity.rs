@@ -127,8 +127,8 @@ fn test_when_then_otherwise_cats() -> PolarsResult<()> {
     ]?.lazy();
 
     let out = lf
-        .with_column(col("book").cast(DataType::Categorical(None)))
-        .with_column(col("user").cast(DataType::Categorical(None)))
+        .with_column(col("book").cast(DataType::Categorical(None, Default::default())))
+        .with_column(col("user").cast(DataType::Categorical(None, Default::default())))
         .with_column(
             when(col("book").eq(Null {}.lit()))
                 .then(col("user"))
@@ -127,10 +127,11 @@ fn test_is_in_categorical_3420() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock();
 
     let _: PolarsResult<_> = with_string_cache(|| {
-        let s = Series::new("x", ["a", "b", "c"]).strict_cast(&DataType::Categorical(None))?;
+        let s = Series::new("x", ["a", "b", "c"])
+            .strict_cast(&DataType::Categorical(None, Default::default()))?;
         let out = df
             .lazy()
-            .with_column(col("a").strict_cast(DataType::Categorical(None)))
+            .with_column(col("a").strict_cast(DataType::Categorical(None, Default::default())))
             .filter(col("a").is_in(lit(s).alias("x")))
             .collect()?;
 
@@ -138,7 +139,9 @@ fn test_is_in_categorical_3420() -> PolarsResult<()> {
             "a" => ["a", "b", "c"],
             "b" => [1, 2, 3]
         ]?;
-        expected.try_apply("a", |s| s.cast(&DataType::Categorical(None)))?;
+        expected.try_apply("a", |s| {
+            s.cast(&DataType::Categorical(None, Default::default()))
+        })?;
         assert!(out.frame_equal(&expected));
 
         Ok(())
@@ -0,0 +1,71 @@
+use polars::mem::current_usage;
+use polars::prelude::*;
+
+use super::*;
+
+/// The bytes that `f` allocates and that are still alive in its output.
+fn measure<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = current_usage() as isize;
+    let out = f();
+    let after = current_usage() as isize;
+    (out, (after - before).max(0) as usize)
+}
+
+fn assert_close(estimated: usize, measured: usize) {
+    let ratio = estimated as f64 / measured as f64;
+    assert!(
+        (0.5..=2.0).contains(&ratio),
+        "estimated {estimated} bytes, measured {measured} bytes"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "dtype-struct", feature = "dtype-categorical"))]
+fn test_estimated_size_nested() -> PolarsResult<()> {
+    let _guard = serial();
+    let n = 10_000;
+    let words = (0..n)
+        .map(|i| format!("word-{}", i % 1000))
+        .collect::<Vec<_>>();
+
+    let (utf8, utf8_size) = measure(|| Series::new("utf8", &words));
+    assert_close(utf8.estimated_size(), utf8_size);
+    let (cat, cat_size) = measure(|| {
+        utf8.cast(&DataType::Categorical(None, Default::default()))
+            .unwrap()
+    });
+    assert_close(cat.estimated_size(), cat_size);
+    let (list, list_size) = measure(|| {
+        let lists = (0..n / 10)
+            .map(|i| Series::new("", (0..10).map(|j| i * 10 + j).collect::<Vec<i64>>()))
+            .collect::<Vec<_>>();
+        Series::new("list", &lists)
+    });
+    assert_close(list.estimated_size(), list_size);
+    let (s, struct_size) = measure(|| {
+        let a = Series::new("a", (0..n).map(|i| Some(i as f64)).collect::<Vec<_>>());
+        let b = Series::new("b", &words)
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        StructChunked::new("struct", &[a, b]).unwrap().into_series()
+    });
+    assert_close(s.estimated_size(), struct_size);
+
+    let df = DataFrame::new(vec![utf8, cat, list, s])?;
+    assert_close(
+        df.estimated_size(),
+        utf8_size + cat_size + list_size + struct_size,
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_estimated_size_shared_rev_map() -> PolarsResult<()> {
+    let cat =
+        Series::new("a", ["x", "y", "z"]).cast(&DataType::Categorical(None, Default::default()))?;
+    let df = DataFrame::new(vec![cat.clone(), cat.with_name("b")])?;
+    // the rev-map is shared by both columns and counted once
+    assert!(df.estimated_size() < 2 * cat.estimated_size());
+    Ok(())
+}
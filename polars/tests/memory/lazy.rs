@@ -0,0 +1,123 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use polars::error::ErrorKind;
+use polars::mem::PeakScope;
+use polars::prelude::*;
+use polars_core::series::IsSorted;
+
+use super::*;
+
+#[test]
+fn test_memory_limit() -> PolarsResult<()> {
+    let _guard = serial();
+    let q = df!["a" => [1, 2, 3, 4, 5]]?
+        .lazy()
+        .select([col("a") * lit(2)]);
+
+    // the output of the query is in use
+    let err = q.clone().with_memory_limit(0).collect().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ResourceExhausted);
+    assert!(err.plan_node().is_some());
+
+    // the memory that was in use before the query started doesn't count
+    let before = vec![1u8; 64 << 20];
+    let out = q.with_memory_limit(1 << 20).collect()?;
+    assert_eq!(out.height(), 5);
+    drop(before);
+    Ok(())
+}
+
+#[test]
+fn test_memory_limit_checked_per_group() -> PolarsResult<()> {
+    let _guard = serial();
+    let n_groups = 64;
+    let df = df![
+        "g" => (0..n_groups).collect::<Vec<i32>>(),
+        "a" => (0..n_groups).collect::<Vec<i32>>(),
+    ]?;
+
+    // every group allocates 8MB, the limit is exceeded before all groups are done
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let big = apply_multiple(
+        move |s| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            let n = s[0].len() * (1 << 20);
+            Ok(Some(Series::new("", vec![0i64; n])))
+        },
+        [col("a"), col("g")],
+        GetOutput::from_type(DataType::Int64),
+        false,
+    );
+    let err = df
+        .lazy()
+        .groupby([col("g")])
+        .agg([big])
+        .with_memory_limit(32 << 20)
+        .collect()
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ResourceExhausted);
+    assert!(calls.load(Ordering::Relaxed) < n_groups as usize);
+    Ok(())
+}
+
+#[test]
+fn test_profile_peak_memory() -> PolarsResult<()> {
+    let _guard = serial();
+    let n = 100_000;
+    let q = df!["a" => (0..n).collect::<Vec<i32>>()]?
+        .lazy()
+        .select([col("a") * lit(2)]);
+    let (_, profile) = q.profile()?;
+
+    // the peak of the whole query includes its output
+    let node = profile.column("node")?.utf8()?;
+    let query = node
+        .into_iter()
+        .position(|node| node == Some("query"))
+        .unwrap();
+    let peak = profile.column("peak_memory")?.u64()?.get(query).unwrap();
+    assert!(peak >= n as u64 * std::mem::size_of::<i32>() as u64);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-u8")]
+fn test_window_sorted_chunked_keys_not_rechunked() -> PolarsResult<()> {
+    let _guard = serial();
+    let n_chunks = 16;
+    let chunk_len = 1 << 16;
+    let part = |i: i64| {
+        df![
+            "s" => vec![i; chunk_len],
+            "v" => vec![i as u8; chunk_len],
+        ]
+    };
+    let mut df = part(0)?;
+    for i in 1..n_chunks {
+        df.vstack_mut(&part(i)?)?;
+    }
+    df.try_apply("s", |s| {
+        let mut s = s.clone();
+        s.set_sorted_flag(IsSorted::Ascending);
+        Ok(s)
+    })?;
+    assert_eq!(df.n_chunks(), n_chunks as usize);
+    let key_size = df.column("s")?.estimated_size();
+
+    let scope = PeakScope::new();
+    let out = df
+        .lazy()
+        .select([col("v").max().over([col("s")])])
+        .collect()?;
+    // the window allocates a `u32` pair per row to map the groups back and a `u8` output,
+    // a rechunk of the `i64` keys would add the size of the keys on top of that
+    assert!(
+        scope.peak_increase() < key_size * 3 / 2,
+        "peak increase of {} bytes for {key_size} bytes of keys",
+        scope.peak_increase()
+    );
+    assert_eq!(out.height(), n_chunks as usize * chunk_len);
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! The tests that measure the memory usage, which needs the [`TrackingAllocator`] as the
+//! global allocator. The allocator counts the memory of the whole process, so these tests
+//! don't run concurrently.
+mod estimated_size;
+#[cfg(feature = "lazy")]
+mod lazy;
+
+use std::sync::Mutex;
+
+use polars::mem::TrackingAllocator;
+
+#[global_allocator]
+static ALLOC: TrackingAllocator = TrackingAllocator::system();
+
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Hold the returned guard for the duration of a test.
+pub(crate) fn serial() -> std::sync::MutexGuard<'static, ()> {
+    SERIAL.lock().unwrap_or_else(|err| err.into_inner())
+}
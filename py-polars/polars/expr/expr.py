@@ -2269,11 +2269,11 @@ class Expr:
         ----------
         value
             Value used to fill null values.
-        strategy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+        strategy : {None, 'forward', 'backward', 'forward_then_backward', 'min', 'max', 'mean', 'zero', 'one'}
             Strategy used to fill null values.
         limit
-            Number of consecutive null values to fill when using the 'forward' or
-            'backward' strategy.
+            Number of consecutive null values to fill when using the 'forward',
+            'backward' or 'forward_then_backward' strategy.
 
         Examples
         --------
@@ -2322,10 +2322,13 @@ class Expr:
             raise ValueError("cannot specify both 'value' and 'strategy'.")
         elif value is None and strategy is None:
             raise ValueError("must specify either a fill 'value' or 'strategy'")
-        elif strategy not in ("forward", "backward") and limit is not None:
+        elif (
+            strategy not in ("forward", "backward", "forward_then_backward")
+            and limit is not None
+        ):
             raise ValueError(
                 "can only specify 'limit' when strategy is set to"
-                " 'backward' or 'forward'"
+                " 'backward', 'forward' or 'forward_then_backward'"
             )
 
         if value is not None:
@@ -6840,12 +6843,23 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.set_sorted_flag(descending))
 
-    def shrink_dtype(self) -> Self:
+    def shrink_dtype(self, *, shrink_floats: bool = False) -> Self:
         """
         Shrink numeric columns to the minimal required datatype.
 
         Shrink to the dtype needed to fit the extrema of this [`Series`].
-        This can be used to reduce memory pressure.
+        This can be used to reduce memory pressure. Integer columns keep their
+        signedness and non-numeric columns are returned unchanged.
+
+        The resulting dtype depends on the data, so it is only known once the query
+        is executed.
+
+        Parameters
+        ----------
+        shrink_floats
+            Also shrink float columns, if all values can be represented exactly by
+            an integer dtype or by ``Float32``. The schema of a lazy query reports
+            such float columns as ``Unknown``.
 
         Examples
         --------
@@ -6865,7 +6879,7 @@ class Expr:
         ┌─────┬────────────┬────────────┬──────┬──────┬─────┬──────┬───────┐
         │ a   ┆ b          ┆ c          ┆ d    ┆ e    ┆ f   ┆ g    ┆ h     │
         │ --- ┆ ---        ┆ ---        ┆ ---  ┆ ---  ┆ --- ┆ ---  ┆ ---   │
-        │ i8  ┆ i64        ┆ i32        ┆ i8   ┆ i16  ┆ str ┆ f32  ┆ bool  │
+        │ i8  ┆ i64        ┆ i32        ┆ i8   ┆ i16  ┆ str ┆ f64  ┆ bool  │
         ╞═════╪════════════╪════════════╪══════╪══════╪═════╪══════╪═══════╡
         │ 1   ┆ 1          ┆ -1         ┆ -112 ┆ -112 ┆ a   ┆ 0.1  ┆ true  │
         │ 2   ┆ 2          ┆ 2          ┆ 2    ┆ 2    ┆ b   ┆ 1.32 ┆ null  │
@@ -6873,7 +6887,7 @@ class Expr:
         └─────┴────────────┴────────────┴──────┴──────┴─────┴──────┴───────┘
 
         """
-        return self._from_pyexpr(self._pyexpr.shrink_dtype())
+        return self._from_pyexpr(self._pyexpr.shrink_dtype(shrink_floats))
 
     def map_dict(
         self,
@@ -1594,7 +1594,7 @@ def corr(
         )
 
 
-def cov(a: str | Expr, b: str | Expr) -> Expr:
+def cov(a: str | Expr, b: str | Expr, ddof: int = 1) -> Expr:
     """
     Compute the covariance between two columns/ expressions.
 
@@ -1604,6 +1604,10 @@ def cov(a: str | Expr, b: str | Expr) -> Expr:
         Column name or Expression.
     b
         Column name or Expression.
+    ddof
+        “Delta Degrees of Freedom”: the divisor used in the calculation is N - ddof,
+        where N represents the number of elements.
+        By default ddof is 1.
 
     Examples
     --------
@@ -1623,7 +1627,7 @@ def cov(a: str | Expr, b: str | Expr) -> Expr:
         a = col(a)
     if isinstance(b, str):
         b = col(b)
-    return wrap_expr(pycov(a._pyexpr, b._pyexpr))
+    return wrap_expr(pycov(a._pyexpr, b._pyexpr, ddof))
 
 
 def map(
@@ -2795,6 +2799,7 @@ def collect_all(
         Slice pushdown optimization.
     common_subplan_elimination
         Will try to cache branching subplans that occur on self-joins or unions.
+        Subplans that are shared between the LazyFrames are executed only once.
     streaming
         Run parts of the query in a streaming fashion (this is in an alpha state)
 
@@ -548,6 +548,11 @@ def read_csv_batched(
     ...
 
     """
+    _check_arg_is_1byte("separator", separator, False)
+    _check_arg_is_1byte("comment_char", comment_char, False)
+    _check_arg_is_1byte("quote_char", quote_char, True)
+    _check_arg_is_1byte("eol_char", eol_char, False)
+
     projection, columns = handle_projection_columns(columns)
 
     if isinstance(source, bytes) and len(source) == 0:
@@ -68,9 +68,10 @@ def read_parquet(
     storage_options
         Extra options that make sense for ``fsspec.open()`` or a particular storage
         connection, e.g. host, port, username, password, etc.
-    parallel : {'auto', 'columns', 'row_groups', 'none'}
+    parallel : {'auto', 'columns', 'row_groups', 'none', 'prefiltered'}
         This determines the direction of parallelism. 'auto' will try to determine the
-        optimal direction.
+        optimal direction. 'prefiltered' decodes the columns of a filter first and
+        only decodes the pages of the other columns that contain matching rows.
     row_count_name
         If not None, this will insert a row count column with give name into the
         DataFrame.
@@ -168,6 +169,7 @@ def scan_parquet(
     storage_options: dict[str, Any] | None = None,
     low_memory: bool = False,
     use_statistics: bool = True,
+    hive_partitioning: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from a parquet file or multiple files via glob patterns.
@@ -183,9 +185,10 @@ def scan_parquet(
         Stop reading from parquet file after reading ``n_rows``.
     cache
         Cache the result after reading.
-    parallel : {'auto', 'columns', 'row_groups', 'none'}
+    parallel : {'auto', 'columns', 'row_groups', 'none', 'prefiltered'}
         This determines the direction of parallelism. 'auto' will try to determine the
-        optimal direction.
+        optimal direction. 'prefiltered' decodes the columns of a filter first and
+        only decodes the pages of the other columns that contain matching rows.
     rechunk
         In case of reading multiple files via a glob pattern rechunk the final DataFrame
         into contiguous memory chunks.
@@ -203,6 +206,10 @@ def scan_parquet(
     use_statistics
         Use statistics in the parquet to determine if pages
         can be skipped from reading.
+    hive_partitioning
+        Infer partition columns from the ``key=value`` directories of the paths, e.g.
+        ``data/year=2023/month=1/part-0.parquet``. The partition columns are added to
+        the scanned frame and filters on them skip the files of other partitions.
 
     """
     if isinstance(source, (str, Path)):
@@ -219,4 +226,5 @@ def scan_parquet(
         storage_options=storage_options,
         low_memory=low_memory,
         use_statistics=use_statistics,
+        hive_partitioning=hive_partitioning,
     )
@@ -385,6 +385,7 @@ class LazyFrame:
         storage_options: dict[str, object] | None = None,
         low_memory: bool = False,
         use_statistics: bool = True,
+        hive_partitioning: bool = False,
     ) -> Self:
         """
         Lazily read from a parquet file or multiple files via glob patterns.
@@ -416,6 +417,7 @@ class LazyFrame:
             low_memory,
             cloud_options=storage_options,
             use_statistics=use_statistics,
+            hive_partitioning=hive_partitioning,
         )
         return self
 
@@ -726,7 +728,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ...     }
         ... )
         >>> lf.write_json()
-        '{"DataFrameScan":{"df":{"columns":[{"name":"foo","datatype":"Int64","values":[1,2,3]},{"name":"bar","datatype":"Int64","values":[6,7,8]}]},"schema":{"inner":{"foo":"Int64","bar":"Int64"}},"output_schema":null,"projection":null,"selection":null}}'
+        '{"DataFrameScan":{"df":{"columns":[{"version":1,"name":"foo","datatype":"Int64","values":[1,2,3]},{"version":1,"name":"bar","datatype":"Int64","values":[6,7,8]}]},"schema":{"inner":{"foo":"Int64","bar":"Int64"}},"output_schema":null,"projection":null,"selection":null}}'
 
         """
         if isinstance(file, (str, Path)):
@@ -1401,7 +1403,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         containing the materialized DataFrame and a DataFrame that
         contains profiling information of each node that is executed.
 
-        The units of the timings are microseconds.
+        The profiling information has the columns:
+
+        - ``node_id``: the id of the node, as printed by :func:`LazyFrame.explain`.
+          This is null for the optimization passes.
+        - ``node``: the name of the node or of the optimization pass.
+        - ``start`` and ``end``: the timings in microseconds since the query started.
+        - ``rows``: the number of rows the node produced.
+
+        A part of the query that runs on the streaming engine is reported as a single
+        ``streaming`` node.
 
         Parameters
         ----------
@@ -1451,16 +1462,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
          │ b   ┆ 11  ┆ 10  │
          │ c   ┆ 6   ┆ 1   │
          └─────┴─────┴─────┘,
-         shape: (3, 3)
-         ┌────────────────────────┬───────┬──────┐
-         │ node                   ┆ start ┆ end  │
-         │ ---                    ┆ ---   ┆ ---  │
-         │ str                    ┆ u64   ┆ u64  │
-         ╞════════════════════════╪═══════╪══════╡
-         │ optimization           ┆ 0     ┆ 5    │
-         │ groupby_partitioned(a) ┆ 5     ┆ 470  │
-         │ sort(a)                ┆ 475   ┆ 1964 │
-         └────────────────────────┴───────┴──────┘)
+         shape: (5, 5)
+         ┌─────────┬─────────────────────────────────┬───────┬──────┬──────┐
+         │ node_id ┆ node                            ┆ start ┆ end  ┆ rows │
+         │ ---     ┆ ---                             ┆ ---   ┆ ---  ┆ ---  │
+         │ u32     ┆ str                             ┆ u64   ┆ u64  ┆ u64  │
+         ╞═════════╪═════════════════════════════════╪═══════╪══════╪══════╡
+         │ null    ┆ optimization                    ┆ 0     ┆ 5    ┆ null │
+         │ null    ┆ optimization: predicate_pushdo… ┆ 1     ┆ 2    ┆ null │
+         │ null    ┆ optimization: rule_based        ┆ 2     ┆ 4    ┆ null │
+         │ 1       ┆ groupby_partitioned(a)          ┆ 5     ┆ 470  ┆ 3    │
+         │ 0       ┆ sort(a)                         ┆ 475   ┆ 1964 ┆ 3    │
+         └─────────┴─────────────────────────────────┴───────┴──────┴──────┘)
 
         """
         if no_optimization:
@@ -1890,7 +1903,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         return self
 
     def cache(self) -> Self:
-        """Cache the result once the execution of the physical plan hits this node."""
+        """
+        Cache the result once the execution of the physical plan hits this node.
+
+        Every branch of the query (or of a :func:`collect_all` call) that reads from
+        the cache reuses the result. The cache is dropped once the query finishes.
+
+        """
         return self._from_pyldf(self._ldf.cache())
 
     def clear(self, n: int = 0) -> Self:
@@ -5755,12 +5755,18 @@ class Series:
         """Create a new Series filled with values from the given index."""
         return self._from_pyseries(self._s.new_from_index(index, length))
 
-    def shrink_dtype(self) -> Series:
+    def shrink_dtype(self, *, shrink_floats: bool = False) -> Series:
         """
         Shrink numeric columns to the minimal required datatype.
 
         Shrink to the dtype needed to fit the extrema of this [`Series`].
         This can be used to reduce memory pressure.
+
+        Parameters
+        ----------
+        shrink_floats
+            Also shrink float columns, if all values can be represented exactly by
+            an integer dtype or by ``Float32``.
         """
 
     def get_chunks(self) -> list[Series]:
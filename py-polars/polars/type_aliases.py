@@ -79,13 +79,15 @@ AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
 FillNullStrategy: TypeAlias = Literal[
-    "forward", "backward", "min", "max", "mean", "zero", "one"
+    "forward", "backward", "forward_then_backward", "min", "max", "mean", "zero", "one"
 ]
 FloatFmt: TypeAlias = Literal["full", "mixed"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 NullStrategy: TypeAlias = Literal["ignore", "propagate"]
-ParallelStrategy: TypeAlias = Literal["auto", "columns", "row_groups", "none"]
+ParallelStrategy: TypeAlias = Literal[
+    "auto", "columns", "row_groups", "none", "prefiltered"
+]
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
@@ -322,7 +322,7 @@ impl ToPyObject for Wrap<DataType> {
             }
             #[cfg(feature = "object")]
             DataType::Object(_) => pl.getattr("Object").unwrap().into(),
-            DataType::Categorical(_) => pl.getattr("Categorical").unwrap().into(),
+            DataType::Categorical(_, _) => pl.getattr("Categorical").unwrap().into(),
             DataType::Time => pl.getattr("Time").unwrap().into(),
             DataType::Struct(fields) => {
                 let field_class = pl.getattr("Field").unwrap();
@@ -369,7 +369,7 @@ impl FromPyObject<'_> for Wrap<DataType> {
                     "Utf8" => DataType::Utf8,
                     "Binary" => DataType::Binary,
                     "Boolean" => DataType::Boolean,
-                    "Categorical" => DataType::Categorical(None),
+                    "Categorical" => DataType::Categorical(None, Default::default()),
                     "Date" => DataType::Date,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Time" => DataType::Time,
@@ -1131,9 +1131,10 @@ impl FromPyObject<'_> for Wrap<ParallelStrategy> {
             "columns" => ParallelStrategy::Columns,
             "row_groups" => ParallelStrategy::RowGroups,
             "none" => ParallelStrategy::None,
+            "prefiltered" => ParallelStrategy::Prefiltered,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "parallel must be one of {{'auto', 'columns', 'row_groups', 'none'}}, got {v}",
+                    "parallel must be one of {{'auto', 'columns', 'row_groups', 'none', 'prefiltered'}}, got {v}",
                 )))
             }
         };
@@ -1249,6 +1250,7 @@ pub(crate) fn parse_fill_null_strategy(
     let parsed = match strategy {
         "forward" => FillNullStrategy::Forward(limit),
         "backward" => FillNullStrategy::Backward(limit),
+        "forward_then_backward" => FillNullStrategy::ForwardThenBackward(limit),
         "min" => FillNullStrategy::Min,
         "max" => FillNullStrategy::Max,
         "mean" => FillNullStrategy::Mean,
@@ -1256,7 +1258,7 @@ pub(crate) fn parse_fill_null_strategy(
         "one" => FillNullStrategy::One,
         e => {
             return Err(PyValueError::new_err(format!(
-                "strategy must be one of {{'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}}, got {e}",
+                "strategy must be one of {{'forward', 'backward', 'forward_then_backward', 'min', 'max', 'mean', 'zero', 'one'}}, got {e}",
             )))
         }
     };
@@ -749,7 +749,7 @@ impl PyDataFrame {
                 .get_columns()
                 .iter()
                 .enumerate()
-                .filter(|(_i, s)| matches!(s.dtype(), DataType::Categorical(_)))
+                .filter(|(_i, s)| matches!(s.dtype(), DataType::Categorical(_, _)))
                 .map(|(i, _)| i)
                 .collect::<Vec<_>>();
 
@@ -1148,12 +1148,10 @@ impl PyDataFrame {
     }
 
     pub fn partition_by(&self, by: Vec<String>, maintain_order: bool) -> PyResult<Vec<Self>> {
-        let out = if maintain_order {
-            self.df.partition_by_stable(by)
-        } else {
-            self.df.partition_by(by)
-        }
-        .map_err(PyPolarsErr::from)?;
+        let out = self
+            .df
+            .partition_by(by, maintain_order, true)
+            .map_err(PyPolarsErr::from)?;
         // Safety:
         // Repr mem layout
         Ok(unsafe { std::mem::transmute::<Vec<DataFrame>, Vec<PyDataFrame>>(out) })
@@ -1175,8 +1173,9 @@ impl PyDataFrame {
         self.df.min().into()
     }
 
-    pub fn sum(&self) -> Self {
-        self.df.sum().into()
+    pub fn sum(&self) -> PyResult<Self> {
+        let df = self.df.sum().map_err(PyPolarsErr::from)?;
+        Ok(df.into())
     }
 
     pub fn mean(&self) -> Self {
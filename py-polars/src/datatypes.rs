@@ -58,7 +58,7 @@ impl From<&DataType> for PyDataType {
             DataType::Time => Time,
             #[cfg(feature = "object")]
             DataType::Object(_) => Object,
-            DataType::Categorical(_) => Categorical,
+            DataType::Categorical(_, _) => Categorical,
             DataType::Struct(_) => Struct,
             DataType::Null | DataType::Unknown => {
                 panic!("null or unknown not expected here")
@@ -97,7 +97,7 @@ impl From<PyDataType> for DataType {
             PyDataType::Time => Time,
             #[cfg(feature = "object")]
             PyDataType::Object => Object(OBJECT_NAME),
-            PyDataType::Categorical => Categorical(None),
+            PyDataType::Categorical => Categorical(None, Default::default()),
             PyDataType::Struct => Struct(vec![]),
             PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
         }
@@ -4,7 +4,7 @@ use std::io::Error;
 use polars::prelude::PolarsError;
 use polars_core::error::ArrowError;
 use pyo3::create_exception;
-use pyo3::exceptions::{PyException, PyIOError, PyRuntimeError};
+use pyo3::exceptions::{PyException, PyIOError, PyMemoryError, PyRuntimeError};
 use pyo3::prelude::*;
 use thiserror::Error;
 
@@ -31,7 +31,9 @@ impl std::convert::From<PyPolarsErr> for PyErr {
         use PyPolarsErr::*;
         match &err {
             Polars(err) => match err {
-                PolarsError::ArrowError(err) => ArrowErrorException::new_err(format!("{err:?}")),
+                PolarsError::ArrowError(err) => {
+                    ArrowErrorException::new_err(format!("{:?}", **err))
+                }
                 PolarsError::ColumnNotFound(name) => ColumnNotFoundError::new_err(name.to_string()),
                 PolarsError::ComputeError(err) => ComputeError::new_err(err.to_string()),
                 PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
@@ -40,6 +42,7 @@ impl std::convert::From<PyPolarsErr> for PyErr {
                 }
                 PolarsError::Io(err) => PyIOError::new_err(err.to_string()),
                 PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
+                PolarsError::ResourceExhausted(err) => PyMemoryError::new_err(err.to_string()),
                 PolarsError::SchemaFieldNotFound(name) => {
                     SchemaFieldNotFoundError::new_err(name.to_string())
                 }
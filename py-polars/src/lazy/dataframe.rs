@@ -157,6 +157,10 @@ impl PyLazyFrame {
 
     #[cfg(all(feature = "json", feature = "serde_json"))]
     pub fn write_json(&self, py_f: PyObject) -> PyResult<()> {
+        self.ldf
+            .logical_plan
+            .check_serializable()
+            .map_err(PyPolarsErr::from)?;
         let file = BufWriter::new(get_file_like(py_f, true)?);
         serde_json::to_writer(file, &self.ldf.logical_plan)
             .map_err(|err| PyValueError::new_err(format!("{err:?}")))?;
@@ -308,7 +312,7 @@ impl PyLazyFrame {
     #[staticmethod]
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (path, n_rows, cache, parallel, rechunk, row_count,
-        low_memory, cloud_options, use_statistics)
+        low_memory, cloud_options, use_statistics, hive_partitioning)
     )]
     pub fn new_from_parquet(
         path: String,
@@ -320,6 +324,7 @@ impl PyLazyFrame {
         low_memory: bool,
         cloud_options: Option<PyObject>,
         use_statistics: bool,
+        hive_partitioning: bool,
     ) -> PyResult<Self> {
         let cloud_options = cloud_options
             .map(|po| extract_cloud_options(&path, po))
@@ -334,6 +339,10 @@ impl PyLazyFrame {
             low_memory,
             cloud_options,
             use_statistics,
+            hive_partitioning,
+            file_path_column: None,
+            row_count_per_file: false,
+            read_stats: None,
         };
         let lf = LazyFrame::scan_parquet(path, args).map_err(PyPolarsErr::from)?;
         Ok(lf.into())
@@ -521,6 +530,7 @@ impl PyLazyFrame {
             row_group_size,
             data_pagesize_limit,
             maintain_order,
+            column_options: Default::default(),
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -310,15 +310,7 @@ impl PyExpr {
         limit: FillNullLimit,
     ) -> PyResult<PyExpr> {
         let strat = parse_fill_null_strategy(strategy, limit)?;
-        Ok(self
-            .inner
-            .clone()
-            .apply(
-                move |s| s.fill_null(strat).map(Some),
-                GetOutput::same_type(),
-            )
-            .with_fmt("fill_null_with_strategy")
-            .into())
+        Ok(self.inner.clone().fill_null_with_strategy(strat).into())
     }
 
     pub fn fill_nan(&self, expr: PyExpr) -> PyExpr {
@@ -409,16 +401,20 @@ impl PyExpr {
     pub fn clip(&self, py: Python, min: PyObject, max: PyObject) -> PyExpr {
         let min = min.extract::<Wrap<AnyValue>>(py).unwrap().0;
         let max = max.extract::<Wrap<AnyValue>>(py).unwrap().0;
+        let min = Expr::Literal(LiteralValue::try_from(min).unwrap());
+        let max = Expr::Literal(LiteralValue::try_from(max).unwrap());
         self.clone().inner.clip(min, max).into()
     }
 
     pub fn clip_min(&self, py: Python, min: PyObject) -> PyExpr {
         let min = min.extract::<Wrap<AnyValue>>(py).unwrap().0;
+        let min = Expr::Literal(LiteralValue::try_from(min).unwrap());
         self.clone().inner.clip_min(min).into()
     }
 
     pub fn clip_max(&self, py: Python, max: PyObject) -> PyExpr {
         let max = max.extract::<Wrap<AnyValue>>(py).unwrap().0;
+        let max = Expr::Literal(LiteralValue::try_from(max).unwrap());
         self.clone().inner.clip_max(max).into()
     }
 
@@ -546,8 +542,8 @@ impl PyExpr {
         self.clone().inner.product().into()
     }
 
-    pub fn shrink_dtype(&self) -> PyExpr {
-        self.inner.clone().shrink_dtype().into()
+    pub fn shrink_dtype(&self, shrink_floats: bool) -> PyExpr {
+        self.inner.clone().shrink_dtype_with(shrink_floats).into()
     }
 
     #[pyo3(signature = (format, strict, exact, cache))]
@@ -737,15 +733,23 @@ impl PyExpr {
     }
 
     pub fn binary_contains(&self, lit: Vec<u8>) -> PyExpr {
-        self.inner.clone().binary().contains_literal(lit).into()
+        self.inner
+            .clone()
+            .binary()
+            .contains_literal(dsl::lit(lit))
+            .into()
     }
 
     pub fn binary_ends_with(&self, sub: Vec<u8>) -> PyExpr {
-        self.inner.clone().binary().ends_with(sub).into()
+        self.inner.clone().binary().ends_with(dsl::lit(sub)).into()
     }
 
     pub fn binary_starts_with(&self, sub: Vec<u8>) -> PyExpr {
-        self.inner.clone().binary().starts_with(sub).into()
+        self.inner
+            .clone()
+            .binary()
+            .starts_with(dsl::lit(sub))
+            .into()
     }
 
     pub fn str_hex_encode(&self) -> PyExpr {
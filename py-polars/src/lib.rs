@@ -201,8 +201,8 @@ fn spearman_rank_corr(
 }
 
 #[pyfunction]
-fn cov(a: dsl::PyExpr, b: dsl::PyExpr) -> dsl::PyExpr {
-    polars_rs::lazy::dsl::cov(a.inner, b.inner).into()
+fn cov(a: dsl::PyExpr, b: dsl::PyExpr, ddof: u8) -> dsl::PyExpr {
+    polars_rs::lazy::dsl::cov(a.inner, b.inner, ddof).into()
 }
 
 #[pyfunction]
@@ -477,21 +477,12 @@ fn parquet_schema(py: Python, py_f: PyObject) -> PyResult<PyObject> {
 
 #[pyfunction]
 fn collect_all(lfs: Vec<PyLazyFrame>, py: Python) -> PyResult<Vec<PyDataFrame>> {
-    use polars_core::utils::rayon::prelude::*;
-
     let out = py.allow_threads(|| {
-        polars_core::POOL.install(|| {
-            lfs.par_iter()
-                .map(|lf| {
-                    let df = lf.ldf.clone().collect()?;
-                    Ok(PyDataFrame::new(df))
-                })
-                .collect::<polars_core::error::PolarsResult<Vec<_>>>()
-                .map_err(PyPolarsErr::from)
-        })
-    });
+        polars_lazy::dsl::collect_all(lfs.into_iter().map(|lf| lf.ldf).collect::<Vec<_>>())
+            .map_err(PyPolarsErr::from)
+    })?;
 
-    Ok(out?)
+    Ok(out.into_iter().map(PyDataFrame::new).collect())
 }
 
 #[pyfunction]
@@ -384,7 +384,7 @@ impl PySeries {
 
     pub fn get_fmt(&self, index: usize, str_lengths: usize) -> String {
         let val = format!("{}", self.series.get(index).unwrap());
-        if let DataType::Utf8 | DataType::Categorical(_) = self.series.dtype() {
+        if let DataType::Utf8 | DataType::Categorical(_, _) = self.series.dtype() {
             let v_trunc = &val[..val
                 .char_indices()
                 .take(str_lengths)
@@ -517,6 +517,7 @@ impl PySeries {
         Ok(Wrap(
             self.series
                 .sum_as_series()
+                .map_err(PyPolarsErr::from)?
                 .get(0)
                 .map_err(PyPolarsErr::from)?,
         )
@@ -689,7 +690,7 @@ impl PySeries {
                     DataType::Int64 => PyList::new(py, series.i64().unwrap()),
                     DataType::Float32 => PyList::new(py, series.f32().unwrap()),
                     DataType::Float64 => PyList::new(py, series.f64().unwrap()),
-                    DataType::Categorical(_) => {
+                    DataType::Categorical(_, _) => {
                         PyList::new(py, series.categorical().unwrap().iter_str())
                     }
                     #[cfg(feature = "object")]
@@ -707,7 +708,9 @@ impl PySeries {
                     DataType::List(_) => {
                         let v = PyList::empty(py);
                         let ca = series.list().unwrap();
-                        for opt_s in ca.amortized_iter() {
+                        // Safety:
+                        // the items are converted to python lists in their iteration
+                        for opt_s in unsafe { ca.amortized_iter() } {
                             match opt_s {
                                 None => {
                                     v.append(py.None()).unwrap();
@@ -882,7 +885,7 @@ impl PySeries {
                 DataType::Datetime(_, _)
                     | DataType::Date
                     | DataType::Duration(_)
-                    | DataType::Categorical(_)
+                    | DataType::Categorical(_, _)
                     | DataType::Binary
                     | DataType::Time
             ) || !skip_nulls
@@ -1174,6 +1174,15 @@ def test_batched_csv_reader_no_batches(foods_file_path: Path) -> None:
     assert batches is None
 
 
+def test_csv_multi_byte_comment_char(foods_file_path: Path) -> None:
+    with pytest.raises(ValueError, match="should be a single byte character"):
+        pl.read_csv(foods_file_path, comment_char="§")
+    with pytest.raises(ValueError, match="should be a single byte character"):
+        pl.read_csv_batched(foods_file_path, comment_char="§")
+    with pytest.raises(ValueError, match="should be a single byte character"):
+        pl.scan_csv(foods_file_path, comment_char="§")
+
+
 def test_csv_single_categorical_null() -> None:
     f = io.BytesIO()
     pl.DataFrame(
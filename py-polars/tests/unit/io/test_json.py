@@ -32,7 +32,7 @@ def test_to_from_file(df: pl.DataFrame) -> None:
 def test_write_json_to_string() -> None:
     # Tests if it runs if no arg given
     df = pl.DataFrame({"a": [1, 2, 3]})
-    expected_str = '{"columns":[{"name":"a","datatype":"Int64","values":[1,2,3]}]}'
+    expected_str = '{"columns":[{"version":1,"name":"a","datatype":"Int64","values":[1,2,3]}]}'
     assert df.write_json() == expected_str
 
 
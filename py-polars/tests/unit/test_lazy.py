@@ -43,16 +43,22 @@ def test_lazy() -> None:
     # profile lazyframe operation/plan
     lazy = ldf.groupby("a").agg(pl.implode("b"))
     profiling_info = lazy.profile()
-    # ┌──────────────┬───────┬─────┐
-    # │ node         ┆ start ┆ end │
-    # │ ---          ┆ ---   ┆ --- │
-    # │ str          ┆ u64   ┆ u64 │
-    # ╞══════════════╪═══════╪═════╡
-    # │ optimization ┆ 0     ┆ 69  │
-    # │ groupby(a)   ┆ 69    ┆ 342 │
-    # └──────────────┴───────┴─────┘
+    # ┌─────────┬──────────────┬───────┬─────┬──────┐
+    # │ node_id ┆ node         ┆ start ┆ end ┆ rows │
+    # │ ---     ┆ ---          ┆ ---   ┆ --- ┆ ---  │
+    # │ u32     ┆ str          ┆ u64   ┆ u64 ┆ u64  │
+    # ╞═════════╪══════════════╪═══════╪═════╪══════╡
+    # │ null    ┆ optimization ┆ 0     ┆ 69  ┆ null │
+    # │ ...     ┆ ...          ┆ ...   ┆ ... ┆ ...  │
+    # │ 0       ┆ groupby(a)   ┆ 69    ┆ 342 ┆ 3    │
+    # └─────────┴──────────────┴───────┴─────┴──────┘
     assert len(profiling_info) == 2
-    assert profiling_info[1].columns == ["node", "start", "end"]
+    timings = profiling_info[1]
+    assert timings.columns == ["node_id", "node", "start", "end", "rows"]
+    assert "optimization" in timings["node"].to_list()
+    groupby = timings.filter(pl.col("node_id") == 0)
+    assert groupby["node"][0].startswith("groupby")
+    assert groupby["rows"].to_list() == [3]
 
 
 def test_lazyframe_membership_operator() -> None:
@@ -1071,6 +1077,8 @@ def test_cov(fruits_cars: pl.DataFrame) -> None:
     cov_ab = pl.cov("A", "B")
     assert cast(float, ldf.select(cov_a_b).collect().item()) == -2.5
     assert cast(float, ldf.select(cov_ab).collect().item()) == -2.5
+    cov_ab_0 = pl.cov("A", "B", ddof=0)
+    assert cast(float, ldf.select(cov_ab_0).collect().item()) == -2.0
 
 
 def test_std(fruits_cars: pl.DataFrame) -> None:
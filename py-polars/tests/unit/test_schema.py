@@ -221,7 +221,7 @@ def test_shrink_dtype() -> None:
         pl.Int8,
         pl.Int16,
         pl.Utf8,
-        pl.Float32,
+        pl.Float64,
         pl.Boolean,
     ]
 
@@ -232,11 +232,27 @@ def test_shrink_dtype() -> None:
         "d": [-112, 2, 112],
         "e": [-112, 2, 129],
         "f": ["a", "b", "c"],
-        "g": [0.10000000149011612, 1.3200000524520874, 0.11999999731779099],
+        "g": [0.1, 1.32, 0.12],
         "h": [True, None, False],
     }
 
 
+def test_shrink_dtype_floats() -> None:
+    out = pl.DataFrame(
+        {
+            "a": [1.0, 2.0, None],
+            "b": [0.5, 1.25, -2.0],
+            "c": [0.1, 1.32, 0.12],
+        }
+    ).select(pl.all().shrink_dtype(shrink_floats=True))
+    assert out.dtypes == [pl.Int8, pl.Float32, pl.Float64]
+    assert out.to_dict(False) == {
+        "a": [1, 2, None],
+        "b": [0.5, 1.25, -2.0],
+        "c": [0.1, 1.32, 0.12],
+    }
+
+
 def test_diff_duration_dtype() -> None:
     dates = ["2022-01-01", "2022-01-02", "2022-01-03", "2022-01-03"]
     df = pl.DataFrame({"date": pl.Series(dates).str.strptime(pl.Date, "%Y-%m-%d")})
@@ -245,6 +245,31 @@ def test_ooc_sort(monkeypatch: Any) -> None:
         assert_series_equal(out, s.sort(descending=descending))
 
 
+@pytest.mark.write_disk()
+def test_ooc_sort_memory_budget(monkeypatch: Any) -> None:
+    # a tiny budget forces the sort to spill many times
+    monkeypatch.setenv("POLARS_OOC_SORT_MEMORY_BUDGET", "1024")
+    np.random.seed(0)
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 10, 10_000),
+            "b": np.random.randint(0, 1_000, 10_000),
+            "c": pl.arange(0, 10_000, eager=True),
+        }
+    ).with_columns(
+        pl.when(pl.col("c") % 7 == 0).then(None).otherwise(pl.col("b")).alias("b")
+    )
+
+    for descending in [True, False]:
+        for nulls_last in [True, False]:
+            q = df.lazy().sort(
+                ["a", "b", "c"],
+                descending=[descending, False, False],
+                nulls_last=nulls_last,
+            )
+            assert_frame_equal(q.collect(streaming=True), q.collect())
+
+
 def test_streaming_literal_expansion() -> None:
     df = pl.DataFrame(
         {